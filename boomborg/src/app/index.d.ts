@@ -0,0 +1,17 @@
+import { Equal_refl } from "./pack/std.js";
+import { Bool_true as Bool_true_, Bool_false as Bool_false_, not, and, or, Trueb } from "./pack/std/bool.js";
+import { Opt_none, Opt_some } from "./pack/std/opt.js";
+import { List_nil, List_cons, eq as eq2 } from "./pack/std/list.js";
+import { Bit_zero, Bit_one } from "./pack/std/num.js";
+import { is_zero, is_one, eq as eq3 } from "./pack/std/num/bit.js";
+import { eq as eq4, neq as neq3, minimal_bitlist_plus, minimal_bitlist, succ, pred, parity, add, mul, square, pow, le, ge } from "./pack/std/num/pos.js";
+import { one, eq as eq5, neq as neq4, succ as succ2, pred as pred2, from_bit, extend_right, extend_right_with_bits, from_bitlist, parity as parity2, neg as neg2, add as add2, mul as mul2, square as square2, le as le2, lt as lt2, ge as ge2, gt as gt2 } from "./pack/std/num/nat.js";
+import { eq as eq6, neq as neq5, succ as succ3, pred as pred3, neg as neg3, double as double_, sub_pos, add as add3, sub, mul as mul3, trunc_div_bitlist, trunc_div_pos, trunc_div_nat, trunc_div, le as le3, lt as lt3, gt as gt3 } from "./pack/std/num/int.js";
+import { Time_time } from "./pack/std/time.js";
+import { String_utf8, eq as eq8 } from "./pack/std/string.js";
+import { Entity_unscaled, Entity_scaled, Window_window, Event_keydown, Event_keyup, Event_window_resize, App_app } from "./pack/boomborg.js";
+import { refl_true, identity, ascribe, str_list_contains, window_width, window_height, time_millis, paddle_height, ball_height, paddle_x_margin, paddle_width, left_paddle_x, right_paddle_x, ball_initial_vx, ball_initial_vy, paddle_max_y, clamp_paddle_y, to_real_x, to_real_y, State_state, init, render, tick, handle, app } from "./pack/pong.js";
+import { pos1, pos2, pos3, pos4, pos5, pos6, pos7, pos8, pos9, pos10, pos20, pos30, pos60, pos80, pos100, pos32, pos64, pos65, pos66, pos67, pos68, pos83, pos85, pos87, pos110, pos111, pos112, pos114, pos115, pos119, pos1000, pos5000, pos10k, pos20k, nat1, nat5000, nat10k, int0, int10k, u8_32, u8_65, u8_66, u8_67, u8_68, u8_83, u8_85, u8_87, u8_110, u8_111, u8_112, u8_114, u8_115, u8_119, ch_A, ch_B, ch_C, ch_D, ch_S, ch_U, ch_W, ch_n, ch_o, ch_p, ch_r, ch_s, ch_w, ch_space, background_image_str, paddle_image_str, ball_image_str, right_paddle_up_strs, right_paddle_down_strs, left_paddle_up_strs, left_paddle_down_strs, launch_ball_strs } from "./pack/pong/literals.js";
+
+export declare const pack: { "std": { "Equal": { "refl": typeof Equal_refl }; "bool": { "Bool": { "true": typeof Bool_true_; "false": typeof Bool_false_ }; "not": typeof not; "and": typeof and; "or": typeof or; "Trueb": typeof Trueb }; "opt": { "Opt": { "none": typeof Opt_none; "some": typeof Opt_some } }; "list": { "List": { "nil": typeof List_nil; "cons": typeof List_cons }; "eq": typeof eq2 }; "num": { "Bit": { "zero": typeof Bit_zero; "one": typeof Bit_one }; "bit": { "is_zero": typeof is_zero; "is_one": typeof is_one; "eq": typeof eq3 }; "pos": { "eq": typeof eq4; "neq": typeof neq3; "minimal_bitlist_plus": typeof minimal_bitlist_plus; "minimal_bitlist": typeof minimal_bitlist; "succ": typeof succ; "pred": typeof pred; "parity": typeof parity; "add": typeof add; "mul": typeof mul; "square": typeof square; "pow": typeof pow; "le": typeof le; "ge": typeof ge }; "nat": { "one": typeof one; "eq": typeof eq5; "neq": typeof neq4; "succ": typeof succ2; "pred": typeof pred2; "from_bit": typeof from_bit; "extend_right": typeof extend_right; "extend_right_with_bits": typeof extend_right_with_bits; "from_bitlist": typeof from_bitlist; "parity": typeof parity2; "neg": typeof neg2; "add": typeof add2; "mul": typeof mul2; "square": typeof square2; "le": typeof le2; "lt": typeof lt2; "ge": typeof ge2; "gt": typeof gt2 }; "int": { "eq": typeof eq6; "neq": typeof neq5; "succ": typeof succ3; "pred": typeof pred3; "neg": typeof neg3; "double": typeof double_; "sub_pos": typeof sub_pos; "add": typeof add3; "sub": typeof sub; "mul": typeof mul3; "trunc_div_bitlist": typeof trunc_div_bitlist; "trunc_div_pos": typeof trunc_div_pos; "trunc_div_nat": typeof trunc_div_nat; "trunc_div": typeof trunc_div; "le": typeof le3; "lt": typeof lt3; "gt": typeof gt3 } }; "time": { "Time": { "time": typeof Time_time } }; "string": { "String": { "utf8": typeof String_utf8 }; "eq": typeof eq8 } }; "boomborg": { "Entity": { "unscaled": typeof Entity_unscaled; "scaled": typeof Entity_scaled }; "Window": { "window": typeof Window_window }; "Event": { "keydown": typeof Event_keydown; "keyup": typeof Event_keyup; "window_resize": typeof Event_window_resize }; "App": { "app": typeof App_app } }; "pong": { "refl_true": typeof refl_true; "identity": typeof identity; "ascribe": typeof ascribe; "str_list_contains": typeof str_list_contains; "window_width": typeof window_width; "window_height": typeof window_height; "time_millis": typeof time_millis; "literals": { "pos1": typeof pos1; "pos2": typeof pos2; "pos3": typeof pos3; "pos4": typeof pos4; "pos5": typeof pos5; "pos6": typeof pos6; "pos7": typeof pos7; "pos8": typeof pos8; "pos9": typeof pos9; "pos10": typeof pos10; "pos20": typeof pos20; "pos30": typeof pos30; "pos60": typeof pos60; "pos80": typeof pos80; "pos100": typeof pos100; "pos32": typeof pos32; "pos64": typeof pos64; "pos65": typeof pos65; "pos66": typeof pos66; "pos67": typeof pos67; "pos68": typeof pos68; "pos83": typeof pos83; "pos85": typeof pos85; "pos87": typeof pos87; "pos110": typeof pos110; "pos111": typeof pos111; "pos112": typeof pos112; "pos114": typeof pos114; "pos115": typeof pos115; "pos119": typeof pos119; "pos1000": typeof pos1000; "pos5000": typeof pos5000; "pos10k": typeof pos10k; "pos20k": typeof pos20k; "nat1": typeof nat1; "nat5000": typeof nat5000; "nat10k": typeof nat10k; "int0": typeof int0; "int10k": typeof int10k; "u8_32": typeof u8_32; "u8_65": typeof u8_65; "u8_66": typeof u8_66; "u8_67": typeof u8_67; "u8_68": typeof u8_68; "u8_83": typeof u8_83; "u8_85": typeof u8_85; "u8_87": typeof u8_87; "u8_110": typeof u8_110; "u8_111": typeof u8_111; "u8_112": typeof u8_112; "u8_114": typeof u8_114; "u8_115": typeof u8_115; "u8_119": typeof u8_119; "ch_A": typeof ch_A; "ch_B": typeof ch_B; "ch_C": typeof ch_C; "ch_D": typeof ch_D; "ch_S": typeof ch_S; "ch_U": typeof ch_U; "ch_W": typeof ch_W; "ch_n": typeof ch_n; "ch_o": typeof ch_o; "ch_p": typeof ch_p; "ch_r": typeof ch_r; "ch_s": typeof ch_s; "ch_w": typeof ch_w; "ch_space": typeof ch_space; "background_image_str": typeof background_image_str; "paddle_image_str": typeof paddle_image_str; "ball_image_str": typeof ball_image_str; "right_paddle_up_strs": typeof right_paddle_up_strs; "right_paddle_down_strs": typeof right_paddle_down_strs; "left_paddle_up_strs": typeof left_paddle_up_strs; "left_paddle_down_strs": typeof left_paddle_down_strs; "launch_ball_strs": typeof launch_ball_strs }; "paddle_height": typeof paddle_height; "ball_height": typeof ball_height; "paddle_x_margin": typeof paddle_x_margin; "paddle_width": typeof paddle_width; "left_paddle_x": typeof left_paddle_x; "right_paddle_x": typeof right_paddle_x; "ball_initial_vx": typeof ball_initial_vx; "ball_initial_vy": typeof ball_initial_vy; "paddle_max_y": typeof paddle_max_y; "clamp_paddle_y": typeof clamp_paddle_y; "to_real_x": typeof to_real_x; "to_real_y": typeof to_real_y; "State": { "state": typeof State_state }; "init": typeof init; "render": typeof render; "tick": typeof tick; "handle": typeof handle; "app": typeof app } };
+
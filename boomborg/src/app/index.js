@@ -0,0 +1,18 @@
+import { Equal_refl } from "./pack/std.js";
+import { Bool_true as Bool_true_, Bool_false as Bool_false_, not, and, or, Trueb } from "./pack/std/bool.js";
+import { Opt_none, Opt_some } from "./pack/std/opt.js";
+import { List_nil, List_cons, eq as eq2 } from "./pack/std/list.js";
+import { Bit_zero, Bit_one } from "./pack/std/num.js";
+import { is_zero, is_one, eq as eq3 } from "./pack/std/num/bit.js";
+import { eq as eq4, neq as neq3, minimal_bitlist_plus, minimal_bitlist, succ, pred, parity, add, mul, square, pow, le, ge } from "./pack/std/num/pos.js";
+import { one, eq as eq5, neq as neq4, succ as succ2, pred as pred2, from_bit, extend_right, extend_right_with_bits, from_bitlist, parity as parity2, neg as neg2, add as add2, mul as mul2, square as square2, le as le2, lt as lt2, ge as ge2, gt as gt2 } from "./pack/std/num/nat.js";
+import { eq as eq6, neq as neq5, succ as succ3, pred as pred3, neg as neg3, double as double_, sub_pos, add as add3, sub, mul as mul3, trunc_div_bitlist, trunc_div_pos, trunc_div_nat, trunc_div, le as le3, lt as lt3, gt as gt3 } from "./pack/std/num/int.js";
+import { Time_time } from "./pack/std/time.js";
+import { String_utf8, eq as eq8 } from "./pack/std/string.js";
+import { Entity_unscaled, Entity_scaled, Window_window, Event_keydown, Event_keyup, Event_window_resize, App_app } from "./pack/boomborg.js";
+import { refl_true, identity, ascribe, str_list_contains, window_width, window_height, time_millis, paddle_height, ball_height, paddle_x_margin, paddle_width, left_paddle_x, right_paddle_x, ball_initial_vx, ball_initial_vy, paddle_max_y, clamp_paddle_y, to_real_x, to_real_y, State_state, init, render, tick, handle, app } from "./pack/pong.js";
+import { pos1, pos2, pos3, pos4, pos5, pos6, pos7, pos8, pos9, pos10, pos20, pos30, pos60, pos80, pos100, pos32, pos64, pos65, pos66, pos67, pos68, pos83, pos85, pos87, pos110, pos111, pos112, pos114, pos115, pos119, pos1000, pos5000, pos10k, pos20k, nat1, nat5000, nat10k, int0, int10k, u8_32, u8_65, u8_66, u8_67, u8_68, u8_83, u8_85, u8_87, u8_110, u8_111, u8_112, u8_114, u8_115, u8_119, ch_A, ch_B, ch_C, ch_D, ch_S, ch_U, ch_W, ch_n, ch_o, ch_p, ch_r, ch_s, ch_w, ch_space, background_image_str, paddle_image_str, ball_image_str, right_paddle_up_strs, right_paddle_down_strs, left_paddle_up_strs, left_paddle_down_strs, launch_ball_strs } from "./pack/pong/literals.js";
+
+export const pack = { "std": { "Equal": { "refl": Equal_refl }, "bool": { "Bool": { "true": Bool_true_, "false": Bool_false_ }, "not": not, "and": and, "or": or, "Trueb": Trueb }, "opt": { "Opt": { "none": Opt_none, "some": Opt_some } }, "list": { "List": { "nil": List_nil, "cons": List_cons }, "eq": eq2 }, "num": { "Bit": { "zero": Bit_zero, "one": Bit_one }, "bit": { "is_zero": is_zero, "is_one": is_one, "eq": eq3 }, "pos": { "eq": eq4, "neq": neq3, "minimal_bitlist_plus": minimal_bitlist_plus, "minimal_bitlist": minimal_bitlist, "succ": succ, "pred": pred, "parity": parity, "add": add, "mul": mul, "square": square, "pow": pow, "le": le, "ge": ge }, "nat": { "one": one, "eq": eq5, "neq": neq4, "succ": succ2, "pred": pred2, "from_bit": from_bit, "extend_right": extend_right, "extend_right_with_bits": extend_right_with_bits, "from_bitlist": from_bitlist, "parity": parity2, "neg": neg2, "add": add2, "mul": mul2, "square": square2, "le": le2, "lt": lt2, "ge": ge2, "gt": gt2 }, "int": { "eq": eq6, "neq": neq5, "succ": succ3, "pred": pred3, "neg": neg3, "double": double_, "sub_pos": sub_pos, "add": add3, "sub": sub, "mul": mul3, "trunc_div_bitlist": trunc_div_bitlist, "trunc_div_pos": trunc_div_pos, "trunc_div_nat": trunc_div_nat, "trunc_div": trunc_div, "le": le3, "lt": lt3, "gt": gt3 } }, "time": { "Time": { "time": Time_time } }, "string": { "String": { "utf8": String_utf8 }, "eq": eq8 } }, "boomborg": { "Entity": { "unscaled": Entity_unscaled, "scaled": Entity_scaled }, "Window": { "window": Window_window }, "Event": { "keydown": Event_keydown, "keyup": Event_keyup, "window_resize": Event_window_resize }, "App": { "app": App_app } }, "pong": { "refl_true": refl_true, "identity": identity, "ascribe": ascribe, "str_list_contains": str_list_contains, "window_width": window_width, "window_height": window_height, "time_millis": time_millis, "literals": { "pos1": pos1, "pos2": pos2, "pos3": pos3, "pos4": pos4, "pos5": pos5, "pos6": pos6, "pos7": pos7, "pos8": pos8, "pos9": pos9, "pos10": pos10, "pos20": pos20, "pos30": pos30, "pos60": pos60, "pos80": pos80, "pos100": pos100, "pos32": pos32, "pos64": pos64, "pos65": pos65, "pos66": pos66, "pos67": pos67, "pos68": pos68, "pos83": pos83, "pos85": pos85, "pos87": pos87, "pos110": pos110, "pos111": pos111, "pos112": pos112, "pos114": pos114, "pos115": pos115, "pos119": pos119, "pos1000": pos1000, "pos5000": pos5000, "pos10k": pos10k, "pos20k": pos20k, "nat1": nat1, "nat5000": nat5000, "nat10k": nat10k, "int0": int0, "int10k": int10k, "u8_32": u8_32, "u8_65": u8_65, "u8_66": u8_66, "u8_67": u8_67, "u8_68": u8_68, "u8_83": u8_83, "u8_85": u8_85, "u8_87": u8_87, "u8_110": u8_110, "u8_111": u8_111, "u8_112": u8_112, "u8_114": u8_114, "u8_115": u8_115, "u8_119": u8_119, "ch_A": ch_A, "ch_B": ch_B, "ch_C": ch_C, "ch_D": ch_D, "ch_S": ch_S, "ch_U": ch_U, "ch_W": ch_W, "ch_n": ch_n, "ch_o": ch_o, "ch_p": ch_p, "ch_r": ch_r, "ch_s": ch_s, "ch_w": ch_w, "ch_space": ch_space, "background_image_str": background_image_str, "paddle_image_str": paddle_image_str, "ball_image_str": ball_image_str, "right_paddle_up_strs": right_paddle_up_strs, "right_paddle_down_strs": right_paddle_down_strs, "left_paddle_up_strs": left_paddle_up_strs, "left_paddle_down_strs": left_paddle_down_strs, "launch_ball_strs": launch_ball_strs }, "paddle_height": paddle_height, "ball_height": ball_height, "paddle_x_margin": paddle_x_margin, "paddle_width": paddle_width, "left_paddle_x": left_paddle_x, "right_paddle_x": right_paddle_x, "ball_initial_vx": ball_initial_vx, "ball_initial_vy": ball_initial_vy, "paddle_max_y": paddle_max_y, "clamp_paddle_y": clamp_paddle_y, "to_real_x": to_real_x, "to_real_y": to_real_y, "State": { "state": State_state }, "init": init, "render": render, "tick": tick, "handle": handle, "app": app } };
+
+//# sourceMappingURL=index.js.map
@@ -0,0 +1,35 @@
+import type { Nat } from "./std/num/unsized.js";
+import type { String } from "./std/string.js";
+import type { List } from "./std/list.js";
+import type { Time } from "./std/time.js";
+
+export type Entity = ["unscaled", Nat, Nat, String] | ["scaled", Nat, Nat, Nat, Nat, String];
+
+export declare const Entity: unknown;
+
+export declare const Entity_unscaled: (x: Nat, y: Nat, image: String) => Entity;
+
+export declare const Entity_scaled: (x: Nat, y: Nat, w: Nat, h: Nat, image: String) => Entity;
+
+export type Window = ["window", Nat, Nat];
+
+export declare const Window: unknown;
+
+export declare const Window_window: (w: Nat, h: Nat) => Window;
+
+export type Event = ["keydown", String] | ["keyup", String] | ["window_resize", Window];
+
+export declare const Event: unknown;
+
+export declare const Event_keydown: (key: String) => Event;
+
+export declare const Event_keyup: (key: String) => Event;
+
+export declare const Event_window_resize: (window: Window) => Event;
+
+export type App = ["app", undefined, (state: unknown) => List<Entity>, (state: unknown, time: Time) => unknown, (window: Window, time: Time) => unknown, (state: unknown, event: Event) => unknown];
+
+export declare const App: unknown;
+
+export declare const App_app: <State>(render: (state: State) => List<Entity>, tick: (state: State, time: Time) => State, init: (window: Window, time: Time) => State, handle: (state: State, event: Event) => State) => App;
+
@@ -0,0 +1,37 @@
+export const Entity = { "type_species": "Entity", "type_args": [] };
+
+export const Entity_unscaled = function Entity_unscaled(x, y, image) {
+    return ["unscaled", x, y, image];
+};
+
+export const Entity_scaled = function Entity_scaled(x, y, w, h, image) {
+    return ["scaled", x, y, w, h, image];
+};
+
+export const Window = { "type_species": "Window", "type_args": [] };
+
+export const Window_window = function Window_window(w, h) {
+    return ["window", w, h];
+};
+
+export const Event = { "type_species": "Event", "type_args": [] };
+
+export const Event_keydown = function Event_keydown(key) {
+    return ["keydown", key];
+};
+
+export const Event_keyup = function Event_keyup(key) {
+    return ["keyup", key];
+};
+
+export const Event_window_resize = function Event_window_resize(window) {
+    return ["window_resize", window];
+};
+
+export const App = { "type_species": "App", "type_args": [] };
+
+export const App_app = function App_app(render, tick, init, handle) {
+    return ["app", undefined, render, tick, init, handle];
+};
+
+//# sourceMappingURL=boomborg.js.map
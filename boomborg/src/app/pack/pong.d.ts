@@ -0,0 +1,312 @@
+import type { String } from "./std/string.js";
+import type { List } from "./std/list.js";
+import type { Bool } from "./std/bool.js";
+import type { Window, Entity, Event, App } from "./boomborg.js";
+import type { Nat, Int, Sign, Pos } from "./std/num/unsized.js";
+import type { Time } from "./std/time.js";
+import type { LocalY, LocalX, IntLocalX, IntLocalY } from "./pong/local_coords.js";
+import type { Opt } from "./std/opt.js";
+import type { U8 } from "./std/num/sized.js";
+
+export declare const refl_true: unknown;
+
+export declare const identity: <T>(t: T) => T;
+
+export declare const ascribe: unknown;
+
+export declare const str_list_contains: (strs: List<String>, str: String) => Bool;
+
+export declare const window_width: (window: Window) => Nat;
+
+export declare const window_height: (window: Window) => Nat;
+
+export declare const time_millis: (t: Time) => Nat;
+
+export declare const pos1: Pos;
+
+export declare const pos2: unknown;
+
+export declare const pos3: unknown;
+
+export declare const pos4: unknown;
+
+export declare const pos5: unknown;
+
+export declare const pos6: unknown;
+
+export declare const pos7: unknown;
+
+export declare const pos8: unknown;
+
+export declare const pos9: unknown;
+
+export declare const pos10: unknown;
+
+export declare const pos20: unknown;
+
+export declare const pos30: unknown;
+
+export declare const pos60: unknown;
+
+export declare const pos80: unknown;
+
+export declare const pos100: unknown;
+
+export declare const pos32: unknown;
+
+export declare const pos64: unknown;
+
+export declare const pos65: unknown;
+
+export declare const pos66: unknown;
+
+export declare const pos67: unknown;
+
+export declare const pos68: unknown;
+
+export declare const pos83: unknown;
+
+export declare const pos85: unknown;
+
+export declare const pos87: unknown;
+
+export declare const pos110: unknown;
+
+export declare const pos111: unknown;
+
+export declare const pos112: unknown;
+
+export declare const pos114: unknown;
+
+export declare const pos115: unknown;
+
+export declare const pos119: unknown;
+
+export declare const pos1000: unknown;
+
+export declare const pos5000: unknown;
+
+export declare const pos10k: unknown;
+
+export declare const pos20k: unknown;
+
+export declare const nat1: Nat;
+
+export declare const nat5000: Nat;
+
+export declare const nat10k: Nat;
+
+export declare const int0: Int;
+
+export declare const int10k: Int;
+
+export declare const u8_32: U8;
+
+export declare const u8_65: U8;
+
+export declare const u8_66: U8;
+
+export declare const u8_67: U8;
+
+export declare const u8_68: U8;
+
+export declare const u8_83: U8;
+
+export declare const u8_85: U8;
+
+export declare const u8_87: U8;
+
+export declare const u8_110: U8;
+
+export declare const u8_111: U8;
+
+export declare const u8_112: U8;
+
+export declare const u8_114: U8;
+
+export declare const u8_115: U8;
+
+export declare const u8_119: U8;
+
+export declare const ch_A: unknown;
+
+export declare const ch_B: unknown;
+
+export declare const ch_C: unknown;
+
+export declare const ch_D: unknown;
+
+export declare const ch_S: unknown;
+
+export declare const ch_U: unknown;
+
+export declare const ch_W: unknown;
+
+export declare const ch_n: unknown;
+
+export declare const ch_o: unknown;
+
+export declare const ch_p: unknown;
+
+export declare const ch_r: unknown;
+
+export declare const ch_s: unknown;
+
+export declare const ch_w: unknown;
+
+export declare const ch_space: unknown;
+
+export declare const background_image_str: String;
+
+export declare const paddle_image_str: String;
+
+export declare const ball_image_str: String;
+
+export declare const right_paddle_up_strs: unknown;
+
+export declare const right_paddle_down_strs: unknown;
+
+export declare const left_paddle_up_strs: unknown;
+
+export declare const left_paddle_down_strs: unknown;
+
+export declare const launch_ball_strs: unknown;
+
+export declare const sign_eq: (a: Sign, b: Sign) => Bool;
+
+export declare const opt_sign_eq_some: (opt_sign: Opt<Sign>, sign: Sign) => Bool;
+
+export declare const sign_int: (sign: Sign, i: Int) => Int;
+
+export declare const relu: (n: Int) => Nat;
+
+export declare const nat_to_pos: (n: Nat) => Pos;
+
+export declare const nat_sub: (min: Nat, sub: Nat) => Nat;
+
+export declare const nat_mod: (n: Nat, divisor: Pos) => Nat;
+
+export declare const int_abs: (i: Int) => Nat;
+
+export declare const nat_dist: (a: Nat, b: Nat) => Nat;
+
+export declare const clamp: (args: { "clampee": Nat; "min": Nat; "max": Nat }) => Nat;
+
+export declare const sign_neg_one_exp: (n: Nat) => Sign;
+
+export declare const negate_on_1_bit: (args: { "negatee": Int; "negator": Nat }) => Int;
+
+export declare const negate_on_2_bit: (args: { "negatee": Int; "negator": Nat }) => Int;
+
+export declare const paddle_height: LocalY;
+
+export declare const ball_height: LocalY;
+
+export declare const paddle_x_margin: LocalX;
+
+export declare const paddle_width: LocalX;
+
+export declare const left_paddle_x: unknown;
+
+export declare const right_paddle_x: LocalX;
+
+export declare const ball_initial_vx: IntLocalX;
+
+export declare const ball_initial_vy: IntLocalY;
+
+export declare const paddle_max_y: LocalY;
+
+export declare const clamp_paddle_y: (yi: Int) => LocalY;
+
+export declare const to_real_x: (x: LocalX, window: Window) => Nat;
+
+export declare const to_real_y: (y: LocalY, window: Window) => Nat;
+
+export type State = ["state", { "time": Time; "window": Window; "left_paddle_y": LocalY; "right_paddle_y": LocalY; "ball_center_x": LocalX; "ball_center_y": LocalY; "ball_vx": IntLocalX; "ball_vy": IntLocalY; "left_paddle_vy_sign": Opt<Sign>; "right_paddle_vy_sign": Opt<Sign> }];
+
+export declare const State: unknown;
+
+export declare const State_state: (args: { "time": Time; "window": Window; "left_paddle_y": LocalY; "right_paddle_y": LocalY; "ball_center_x": LocalX; "ball_center_y": LocalY; "ball_vx": IntLocalX; "ball_vy": IntLocalY; "left_paddle_vy_sign": Opt<Sign>; "right_paddle_vy_sign": Opt<Sign> }) => State;
+
+export declare const init: (window: Window, time: Time) => State;
+
+export declare const render_background: (state: State) => Entity;
+
+export declare const render_left_paddle: (state: State) => Entity;
+
+export declare const render_right_paddle: (state: State) => Entity;
+
+export declare const get_ball_width: (state: State) => LocalX;
+
+export declare const render_ball: (state: State) => Entity;
+
+export declare const render: (state: State) => List<Entity>;
+
+export declare const ball_bounce_y_top: (raw: Int) => Int;
+
+export declare const ball_bounce_y: (old_y: LocalY, delta_y: IntLocalY) => LocalY;
+
+export declare const ball_bounce_vy_sign_top: (raw: Int) => Sign;
+
+export declare const ball_bounce_vy_sign: (old_y: LocalY, delta_y: IntLocalY) => Sign;
+
+export type Rect = ["new_", LocalX, LocalY, LocalX, LocalY];
+
+export declare const Rect: unknown;
+
+export declare const Rect_new_: (x: LocalX, y: LocalY, w: LocalX, h: LocalY) => Rect;
+
+export type Circ = ["new_", LocalX, LocalY, LocalX];
+
+export declare const Circ: unknown;
+
+export declare const Circ_new_: (x: LocalX, y: LocalY, r: LocalX) => Circ;
+
+export type HitBox = ["rect", Rect] | ["circ", Circ];
+
+export declare const HitBox: unknown;
+
+export declare const HitBox_rect: (r: Rect) => HitBox;
+
+export declare const HitBox_circ: (c: Circ) => HitBox;
+
+export declare const do_rects_intersect: (a: Rect, b: Rect) => Bool;
+
+export declare const do_rect_circ_intersect: (rect: Rect, circ: Circ) => Bool;
+
+export declare const do_circs_intersect: (a: Circ, b: Circ) => Bool;
+
+export declare const does_intersect: (a: HitBox, b: HitBox) => Bool;
+
+export declare const ball_hitbox: (state: State) => HitBox;
+
+export declare const left_paddle_hitbox: (state: State) => HitBox;
+
+export declare const right_paddle_hitbox: (state: State) => HitBox;
+
+export declare const is_ball_touching_left_paddle: (state: State) => Bool;
+
+export declare const is_ball_touching_right_paddle: (state: State) => Bool;
+
+export declare const tick_assuming_ball_not_reset: (state: State, new_time: Time) => State;
+
+export declare const get_elapsed_millis: (state: State, new_time: Time) => Nat;
+
+export declare const will_ball_go_out_of_x_bounds: (state: State, new_time: Time) => Bool;
+
+export declare const recenter_ball_and_pause: (state: State) => State;
+
+export declare const tick: (state: State, new_time: Time) => State;
+
+export declare const handle_window_resize: (state: State, new_window: Window) => State;
+
+export declare const is_ball_stationary_at_center: (state: State) => Bool;
+
+export declare const handle_keydown: (state: State, key: String) => State;
+
+export declare const handle_keyup: (state: State, key: String) => State;
+
+export declare const handle: (state: State, event: Event) => State;
+
+export declare const app: App;
+
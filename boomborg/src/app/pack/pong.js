@@ -0,0 +1,1019 @@
+import { Equal_refl } from "./std.js";
+import { Bool_true as Bool_true_, Bool_false as Bool_false_, not, or, and } from "./std/bool.js";
+import { eq as eq8, String_utf8 } from "./std/string.js";
+import { LocalY_locy, LocalX_locx, locx_raw, IntLocalX_ilocx, IntLocalY_ilocy, locy_raw, ilocx_raw, ilocy_raw } from "./pong/local_coords.js";
+import { trunc_div_nat, trunc_div, lt as lt3, gt as gt3, neg as neg3, sub, mul as mul3, add as add3, eq as eq6 } from "./std/num/int.js";
+import { Int_nat, Nat_zero, Pos_one, Nat_pos, Sign_pos, Sign_neg } from "./std/num/unsized.js";
+import { mul as mul2, le as le2, ge as ge2, parity as parity2, add as add2, one, gt as gt2, square as square2, eq as eq5 } from "./std/num/nat.js";
+import { Opt_none, Opt_some } from "./std/opt.js";
+import { List_cons, List_nil } from "./std/list.js";
+import { App_app, Entity_scaled } from "./boomborg.js";
+import { add, mul, pow } from "./std/num/pos.js";
+import { U8_u8 } from "./std/num/sized.js";
+
+export const refl_true = Equal_refl(Bool_true_);
+
+export const identity = function _(t) {
+    return t;
+};
+
+export const ascribe = identity;
+
+export const str_list_contains = function str_list_contains(strs, str) {
+    return (function temp_a3(temp_a2) {
+        if ((temp_a2[0] === "nil")) {
+            const _ = temp_a2[1];
+            return Bool_false_;
+        }
+        if ((temp_a2[0] === "cons")) {
+            const _ = temp_a2[1];
+            const car = temp_a2[2];
+            const cdr = temp_a2[3];
+            return (function temp_a5(temp_a4) {
+                if ((temp_a4[0] === "true_")) {
+                    return Bool_true_;
+                }
+                if ((temp_a4[0] === "false_")) {
+                    return str_list_contains(cdr, str);
+                }
+            })(eq8(car, str));
+        }
+    })(strs);
+};
+
+export const window_width = function _(window) {
+    return (function temp_a7(temp_a6) {
+        if ((temp_a6[0] === "window")) {
+            const w = temp_a6[1];
+            const _2 = temp_a6[2];
+            return w;
+        }
+    })(window);
+};
+
+export const window_height = function _(window) {
+    return (function temp_a9(temp_a8) {
+        if ((temp_a8[0] === "window")) {
+            const _2 = temp_a8[1];
+            const h = temp_a8[2];
+            return h;
+        }
+    })(window);
+};
+
+export const time_millis = function _(t) {
+    return (function temp_ab(temp_aa) {
+        if ((temp_aa[0] === "time")) {
+            const millis = temp_aa[1];
+            return millis;
+        }
+    })(t);
+};
+
+export const pos1 = Pos_one;
+
+export const pos2 = add(pos1, pos1);
+
+export const pos3 = add(pos1, pos2);
+
+export const pos4 = add(pos1, pos3);
+
+export const pos5 = add(pos1, pos4);
+
+export const pos6 = add(pos1, pos5);
+
+export const pos7 = add(pos1, pos6);
+
+export const pos8 = add(pos1, pos7);
+
+export const pos9 = add(pos1, pos8);
+
+export const pos10 = add(pos1, pos9);
+
+export const pos20 = mul(pos2, pos10);
+
+export const pos30 = mul(pos3, pos10);
+
+export const pos60 = mul(pos6, pos10);
+
+export const pos80 = mul(pos8, pos10);
+
+export const pos100 = mul(pos10, pos10);
+
+export const pos32 = add(pos2, pos30);
+
+export const pos64 = add(pos4, pos60);
+
+export const pos65 = add(pos5, pos60);
+
+export const pos66 = add(pos6, pos60);
+
+export const pos67 = add(pos7, pos60);
+
+export const pos68 = add(pos8, pos60);
+
+export const pos83 = add(pos3, pos80);
+
+export const pos85 = add(pos5, pos80);
+
+export const pos87 = add(pos7, pos80);
+
+export const pos110 = add(pos10, pos100);
+
+export const pos111 = add(pos1, pos110);
+
+export const pos112 = add(pos1, pos111);
+
+export const pos114 = add(pos4, add(pos10, pos100));
+
+export const pos115 = add(pos5, add(pos10, pos100));
+
+export const pos119 = add(pos9, add(pos10, pos100));
+
+export const pos1000 = pow(pos10, pos3);
+
+export const pos5000 = mul(pos5, pos1000);
+
+export const pos10k = mul(pos10, pos1000);
+
+export const pos20k = mul(pos20, pos1000);
+
+export const nat1 = Nat_pos(Pos_one);
+
+export const nat5000 = Nat_pos(pos5000);
+
+export const nat10k = Nat_pos(pos10k);
+
+export const int0 = Int_nat(Nat_zero);
+
+export const int10k = Int_nat(nat10k);
+
+export const u8_32 = U8_u8(Nat_pos(pos32), refl_true);
+
+export const u8_65 = U8_u8(Nat_pos(pos65), refl_true);
+
+export const u8_66 = U8_u8(Nat_pos(pos66), refl_true);
+
+export const u8_67 = U8_u8(Nat_pos(pos67), refl_true);
+
+export const u8_68 = U8_u8(Nat_pos(pos68), refl_true);
+
+export const u8_83 = U8_u8(Nat_pos(pos83), refl_true);
+
+export const u8_85 = U8_u8(Nat_pos(pos85), refl_true);
+
+export const u8_87 = U8_u8(Nat_pos(pos87), refl_true);
+
+export const u8_110 = U8_u8(Nat_pos(pos110), refl_true);
+
+export const u8_111 = U8_u8(Nat_pos(pos111), refl_true);
+
+export const u8_112 = U8_u8(Nat_pos(pos112), refl_true);
+
+export const u8_114 = U8_u8(Nat_pos(pos114), refl_true);
+
+export const u8_115 = U8_u8(Nat_pos(pos115), refl_true);
+
+export const u8_119 = U8_u8(Nat_pos(pos119), refl_true);
+
+export const ch_A = u8_65;
+
+export const ch_B = u8_66;
+
+export const ch_C = u8_67;
+
+export const ch_D = u8_68;
+
+export const ch_S = u8_83;
+
+export const ch_U = u8_85;
+
+export const ch_W = u8_87;
+
+export const ch_n = u8_110;
+
+export const ch_o = u8_111;
+
+export const ch_p = u8_112;
+
+export const ch_r = u8_114;
+
+export const ch_s = u8_115;
+
+export const ch_w = u8_119;
+
+export const ch_space = u8_32;
+
+export const background_image_str = String_utf8(List_cons(ch_A, List_nil()));
+
+export const paddle_image_str = String_utf8(List_cons(ch_B, List_nil()));
+
+export const ball_image_str = String_utf8(List_cons(ch_C, List_nil()));
+
+export const right_paddle_up_strs = List_cons(String_utf8(List_cons(ch_A, List_cons(ch_r, List_cons(ch_r, List_cons(ch_o, List_cons(ch_w, List_cons(ch_U, List_cons(ch_p, List_nil())))))))), List_nil());
+
+export const right_paddle_down_strs = List_cons(String_utf8(List_cons(ch_A, List_cons(ch_r, List_cons(ch_r, List_cons(ch_o, List_cons(ch_w, List_cons(ch_D, List_cons(ch_o, List_cons(ch_w, List_cons(ch_n, List_nil())))))))))), List_nil());
+
+export const left_paddle_up_strs = List_cons(String_utf8(List_cons(ch_w, List_nil())), List_cons(String_utf8(List_cons(ch_W, List_nil())), List_nil()));
+
+export const left_paddle_down_strs = List_cons(String_utf8(List_cons(ch_s, List_nil())), List_cons(String_utf8(List_cons(ch_S, List_nil())), List_nil()));
+
+export const launch_ball_strs = List_cons(String_utf8(List_cons(ch_space, List_nil())), List_nil());
+
+export const sign_eq = function _(a, b) {
+    return (function temp_ad(temp_ac) {
+        if ((temp_ac[0] === "pos")) {
+            return (function temp_af(temp_ae) {
+                if ((temp_ae[0] === "pos")) {
+                    return Bool_true_;
+                }
+                if ((temp_ae[0] === "neg")) {
+                    return Bool_false_;
+                }
+            })(b);
+        }
+        if ((temp_ac[0] === "neg")) {
+            return (function temp_b1(temp_b0) {
+                if ((temp_b0[0] === "pos")) {
+                    return Bool_false_;
+                }
+                if ((temp_b0[0] === "neg")) {
+                    return Bool_true_;
+                }
+            })(b);
+        }
+    })(a);
+};
+
+export const opt_sign_eq_some = function _(opt_sign, sign2) {
+    return (function temp_b3(temp_b2) {
+        if ((temp_b2[0] === "none")) {
+            const _2 = temp_b2[1];
+            return Bool_false_;
+        }
+        if ((temp_b2[0] === "some")) {
+            const _2 = temp_b2[1];
+            const sign22 = temp_b2[2];
+            return sign_eq(sign2, sign22);
+        }
+    })(opt_sign);
+};
+
+export const sign_int = function _(sign2, i) {
+    return (function temp_b5(temp_b4) {
+        if ((temp_b4[0] === "pos")) {
+            return i;
+        }
+        if ((temp_b4[0] === "neg")) {
+            return neg3(i);
+        }
+    })(sign2);
+};
+
+export const relu = function _(n) {
+    return (function temp_b7(temp_b6) {
+        if ((temp_b6 < 0n)) {
+            const _2 = (0n - temp_b6);
+            return Nat_zero;
+        }
+        if ((temp_b6 >= 0n)) {
+            const nn = temp_b6;
+            return nn;
+        }
+    })(n);
+};
+
+export const nat_to_pos = function _(n) {
+    return (function temp_bd(temp_bc) {
+        if ((temp_bc === 0n)) {
+            return Pos_one;
+        }
+        if ((temp_bc > 0n)) {
+            const p = temp_bc;
+            return p;
+        }
+    })(n);
+};
+
+export const nat_sub = function _(min4, sub2) {
+    return relu(sub(Int_nat(min4), Int_nat(sub2)));
+};
+
+export const nat_mod = function _(n, divisor) {
+    return relu(sub(Int_nat(n), mul3(trunc_div(Int_nat(n), divisor), Int_nat(Nat_pos(divisor)))));
+};
+
+export const int_abs = function _(i) {
+    return (function temp_c1(temp_c0) {
+        if ((temp_c0 < 0n)) {
+            const neg_i = (0n - temp_c0);
+            return Nat_pos(neg_i);
+        }
+        if ((temp_c0 >= 0n)) {
+            const in_ = temp_c0;
+            return in_;
+        }
+    })(i);
+};
+
+export const nat_dist = function _(a, b) {
+    return int_abs(sub(Int_nat(a), Int_nat(b)));
+};
+
+export const clamp = function _({clampee: clampee, min: min4, max: max4}) {
+    return (function temp_c3(temp_c2) {
+        if ((temp_c2[0] === "true_")) {
+            return min4;
+        }
+        if ((temp_c2[0] === "false_")) {
+            return (function temp_c5(temp_c4) {
+                if ((temp_c4[0] === "true_")) {
+                    return max4;
+                }
+                if ((temp_c4[0] === "false_")) {
+                    return clampee;
+                }
+            })(ge2(clampee, max4));
+        }
+    })(le2(clampee, min4));
+};
+
+export const sign_neg_one_exp = function _(n) {
+    return (function temp_c7(temp_c6) {
+        if ((temp_c6[0] === "zero")) {
+            return Sign_pos;
+        }
+        if ((temp_c6[0] === "one")) {
+            return Sign_neg;
+        }
+    })(parity2(n));
+};
+
+export const negate_on_1_bit = function _({negatee: negatee, negator: negator}) {
+    return (function temp_c9(temp_c8) {
+        if ((temp_c8[0] === "zero")) {
+            return negatee;
+        }
+        if ((temp_c8[0] === "one")) {
+            return neg3(negatee);
+        }
+    })(parity2(negator));
+};
+
+export const negate_on_2_bit = function _({negatee: negatee, negator: negator}) {
+    return (function temp_cb(temp_ca) {
+        if ((temp_ca[0] === "zero")) {
+            return negatee;
+        }
+        if ((temp_ca[0] === "one")) {
+            return neg3(negatee);
+        }
+    })(parity2(trunc_div_nat(negator, pos2)));
+};
+
+export const paddle_height = LocalY_locy(trunc_div_nat(nat10k, pos7));
+
+export const ball_height = LocalY_locy(trunc_div_nat(nat10k, pos64));
+
+export const paddle_x_margin = LocalX_locx(trunc_div_nat(nat10k, pos64));
+
+export const paddle_width = LocalX_locx(trunc_div_nat(nat10k, pos64));
+
+export const left_paddle_x = paddle_x_margin;
+
+export const right_paddle_x = LocalX_locx(nat_sub(nat_sub(nat10k, locx_raw(paddle_x_margin)), locx_raw(paddle_width)));
+
+export const ball_initial_vx = IntLocalX_ilocx(trunc_div(Int_nat(nat10k), pos4));
+
+export const ball_initial_vy = IntLocalY_ilocy(trunc_div(Int_nat(nat10k), pos3));
+
+export const paddle_max_y = LocalY_locy(nat_sub(nat10k, locy_raw(paddle_height)));
+
+export const clamp_paddle_y = function _(yi) {
+    return (function temp_cd(temp_cc) {
+        if ((temp_cc[0] === "true_")) {
+            return LocalY_locy(Nat_zero);
+        }
+        if ((temp_cc[0] === "false_")) {
+            return (function temp_cf(temp_ce) {
+                if ((temp_ce[0] === "true_")) {
+                    return paddle_max_y;
+                }
+                if ((temp_ce[0] === "false_")) {
+                    return LocalY_locy(relu(yi));
+                }
+            })(gt3(yi, Int_nat(locy_raw(paddle_max_y))));
+        }
+    })(lt3(yi, int0));
+};
+
+export const to_real_x = function _(x, window) {
+    return (function temp_d1(temp_d0) {
+        if ((temp_d0[0] === "window")) {
+            const window_w = temp_d0[1];
+            const _2 = temp_d0[2];
+            return trunc_div_nat(mul2(locx_raw(x), window_w), pos10k);
+        }
+    })(window);
+};
+
+export const to_real_y = function _(y, window) {
+    return (function temp_d3(temp_d2) {
+        if ((temp_d2[0] === "window")) {
+            const _2 = temp_d2[1];
+            const window_h = temp_d2[2];
+            return trunc_div_nat(mul2(locy_raw(y), window_h), pos10k);
+        }
+    })(window);
+};
+
+export const State = { "type_species": "State", "type_args": [] };
+
+export const State_state = function State_state({time: time, window: window, left_paddle_y: left_paddle_y, right_paddle_y: right_paddle_y, ball_center_x: ball_center_x, ball_center_y: ball_center_y, ball_vx: ball_vx, ball_vy: ball_vy, left_paddle_vy_sign: left_paddle_vy_sign, right_paddle_vy_sign: right_paddle_vy_sign}) {
+    return ["state", { "time": time, "window": window, "left_paddle_y": left_paddle_y, "right_paddle_y": right_paddle_y, "ball_center_x": ball_center_x, "ball_center_y": ball_center_y, "ball_vx": ball_vx, "ball_vy": ball_vy, "left_paddle_vy_sign": left_paddle_vy_sign, "right_paddle_vy_sign": right_paddle_vy_sign }];
+};
+
+export const init = function _(window, time) {
+    return (function temp_d5(temp_d4) {
+        if ((temp_d4[0] === "window")) {
+            const window_w = temp_d4[1];
+            const window_h = temp_d4[2];
+            return State_state({ "time": time, "window": window, "left_paddle_y": LocalY_locy(nat_sub(nat5000, trunc_div_nat(locy_raw(paddle_height), pos2))), "right_paddle_y": LocalY_locy(nat_sub(nat5000, trunc_div_nat(locy_raw(paddle_height), pos2))), "ball_center_x": LocalX_locx(nat5000), "ball_center_y": LocalY_locy(nat5000), "ball_vx": IntLocalX_ilocx(negate_on_1_bit({ "negatee": ilocx_raw(ball_initial_vx), "negator": time_millis(time) })), "ball_vy": IntLocalY_ilocy(negate_on_2_bit({ "negatee": ilocy_raw(ball_initial_vy), "negator": time_millis(time) })), "left_paddle_vy_sign": Opt_none(), "right_paddle_vy_sign": Opt_none() });
+        }
+    })(window);
+};
+
+export const render_background = function _(state) {
+    return (function temp_d7(temp_d6) {
+        if ((temp_d6[0] === "state")) {
+            const window = (temp_d6[1]).window;
+            return (function temp_d9(temp_d8) {
+                if ((temp_d8[0] === "window")) {
+                    const window_w = temp_d8[1];
+                    const window_h = temp_d8[2];
+                    return Entity_scaled(Nat_zero, Nat_zero, window_w, window_h, background_image_str);
+                }
+            })(window);
+        }
+    })(state);
+};
+
+export const render_left_paddle = function _(state) {
+    return (function temp_db(temp_da) {
+        if ((temp_da[0] === "state")) {
+            const window = (temp_da[1]).window;
+            const left_paddle_y = (temp_da[1]).left_paddle_y;
+            return Entity_scaled(to_real_x(left_paddle_x, window), to_real_y(left_paddle_y, window), to_real_x(paddle_width, window), to_real_y(paddle_height, window), paddle_image_str);
+        }
+    })(state);
+};
+
+export const render_right_paddle = function _(state) {
+    return (function temp_dd(temp_dc) {
+        if ((temp_dc[0] === "state")) {
+            const window = (temp_dc[1]).window;
+            const right_paddle_y = (temp_dc[1]).right_paddle_y;
+            return Entity_scaled(to_real_x(right_paddle_x, window), to_real_y(right_paddle_y, window), to_real_x(paddle_width, window), to_real_y(paddle_height, window), paddle_image_str);
+        }
+    })(state);
+};
+
+export const get_ball_width = function _(state) {
+    return (function temp_df(temp_de) {
+        if ((temp_de[0] === "state")) {
+            const window = (temp_de[1]).window;
+            return LocalX_locx(trunc_div_nat(mul2(locy_raw(ball_height), window_height(window)), nat_to_pos(window_width(window))));
+        }
+    })(state);
+};
+
+export const render_ball = function _(state) {
+    return (function temp_e1(temp_e0) {
+        if ((temp_e0[0] === "state")) {
+            const window = (temp_e0[1]).window;
+            const ball_center_x = (temp_e0[1]).ball_center_x;
+            const ball_center_y = (temp_e0[1]).ball_center_y;
+            return Entity_scaled(nat_sub(to_real_x(ball_center_x, window), trunc_div_nat(to_real_x(get_ball_width(state), window), pos2)), nat_sub(to_real_y(ball_center_y, window), trunc_div_nat(to_real_y(ball_height, window), pos2)), to_real_x(get_ball_width(state), window), to_real_y(ball_height, window), ball_image_str);
+        }
+    })(state);
+};
+
+export const render = function _(state) {
+    return List_cons(render_background(state), List_cons(render_left_paddle(state), List_cons(render_right_paddle(state), List_cons(render_ball(state), List_nil()))));
+};
+
+export const ball_bounce_y_top = function _(raw) {
+    return sub(int10k, Int_nat(int_abs(sub(Int_nat(nat_mod(int_abs(raw), pos20k)), int10k))));
+};
+
+export const ball_bounce_y = function _(old_y, delta_y) {
+    return LocalY_locy(relu(add3(ball_bounce_y_top(sub(add3(Int_nat(locy_raw(old_y)), ilocy_raw(delta_y)), Int_nat(trunc_div_nat(locy_raw(ball_height), pos2)))), Int_nat(trunc_div_nat(locy_raw(ball_height), pos2)))));
+};
+
+export const ball_bounce_vy_sign_top = function _(raw) {
+    return sign_neg_one_exp((function temp_e3(temp_e2) {
+        if ((temp_e2 === 0n)) {
+            return Nat_zero;
+        }
+        if ((temp_e2 > 0n)) {
+            const p = temp_e2;
+            return add2(trunc_div_nat(nat_sub(Nat_pos(p), nat1), pos10k), (function temp_e5(temp_e4) {
+                if ((temp_e4 < 0n)) {
+                    const _2 = (0n - temp_e4);
+                    return one;
+                }
+                if ((temp_e4 >= 0n)) {
+                    const _2 = temp_e4;
+                    return Nat_zero;
+                }
+            })(raw));
+        }
+    })(int_abs(raw)));
+};
+
+export const ball_bounce_vy_sign = function _(old_y, delta_y) {
+    return ball_bounce_vy_sign_top(sub(add3(Int_nat(locy_raw(old_y)), ilocy_raw(delta_y)), Int_nat(trunc_div_nat(locy_raw(ball_height), pos2))));
+};
+
+export const Rect = { "type_species": "Rect", "type_args": [] };
+
+export const Rect_new_ = function Rect_new_(x, y, w, h) {
+    return ["new_", x, y, w, h];
+};
+
+export const Circ = { "type_species": "Circ", "type_args": [] };
+
+export const Circ_new_ = function Circ_new_(x, y, r) {
+    return ["new_", x, y, r];
+};
+
+export const HitBox = { "type_species": "HitBox", "type_args": [] };
+
+export const HitBox_rect = function HitBox_rect(r) {
+    return ["rect", r];
+};
+
+export const HitBox_circ = function HitBox_circ(c) {
+    return ["circ", c];
+};
+
+export const do_rects_intersect = function _(a, b) {
+    return (function temp_e7(temp_e6) {
+        if ((temp_e6[0] === "new_")) {
+            const ax = temp_e6[1];
+            const ay = temp_e6[2];
+            const aw = temp_e6[3];
+            const ah = temp_e6[4];
+            return (function temp_e9(temp_e8) {
+                if ((temp_e8[0] === "new_")) {
+                    const bx = temp_e8[1];
+                    const by = temp_e8[2];
+                    const bw = temp_e8[3];
+                    const bh = temp_e8[4];
+                    return not(or(gt2(locx_raw(ax), add2(locx_raw(bx), locx_raw(bw))), or(gt2(locx_raw(bx), add2(locx_raw(ax), locx_raw(aw))), or(gt2(locy_raw(ay), add2(locy_raw(by), locy_raw(bh))), gt2(locy_raw(by), add2(locy_raw(ay), locy_raw(ah)))))));
+                }
+            })(b);
+        }
+    })(a);
+};
+
+export const do_rect_circ_intersect = function _(rect, circ) {
+    return (function temp_eb(temp_ea) {
+        if ((temp_ea[0] === "new_")) {
+            const rx = temp_ea[1];
+            const ry = temp_ea[2];
+            const rw = temp_ea[3];
+            const rh = temp_ea[4];
+            return (function temp_ed(temp_ec) {
+                if ((temp_ec[0] === "new_")) {
+                    const cx = temp_ec[1];
+                    const cy = temp_ec[2];
+                    const cr = temp_ec[3];
+                    return le2(add2(square2(nat_dist(locx_raw(cx), clamp({ "clampee": locx_raw(cx), "min": locx_raw(rx), "max": add2(locx_raw(rx), locx_raw(rw)) }))), square2(nat_dist(locy_raw(cy), clamp({ "clampee": locy_raw(cy), "min": locy_raw(ry), "max": add2(locy_raw(ry), locy_raw(rh)) })))), square2(locx_raw(cr)));
+                }
+            })(circ);
+        }
+    })(rect);
+};
+
+export const do_circs_intersect = function _(a, b) {
+    return (function temp_ef(temp_ee) {
+        if ((temp_ee[0] === "new_")) {
+            const ax = temp_ee[1];
+            const ay = temp_ee[2];
+            const ar = temp_ee[3];
+            return (function temp_f1(temp_f0) {
+                if ((temp_f0[0] === "new_")) {
+                    const bx = temp_f0[1];
+                    const by = temp_f0[2];
+                    const br = temp_f0[3];
+                    return le2(add2(square2(nat_dist(locx_raw(ax), locx_raw(bx))), square2(nat_dist(locy_raw(ay), locy_raw(by)))), square2(add2(locx_raw(ar), locx_raw(br))));
+                }
+            })(b);
+        }
+    })(a);
+};
+
+export const does_intersect = function _(a, b) {
+    return (function temp_f3(temp_f2) {
+        if ((temp_f2[0] === "rect")) {
+            const ar = temp_f2[1];
+            return (function temp_f5(temp_f4) {
+                if ((temp_f4[0] === "rect")) {
+                    const br = temp_f4[1];
+                    return do_rects_intersect(ar, br);
+                }
+                if ((temp_f4[0] === "circ")) {
+                    const bc = temp_f4[1];
+                    return do_rect_circ_intersect(ar, bc);
+                }
+            })(b);
+        }
+        if ((temp_f2[0] === "circ")) {
+            const ac = temp_f2[1];
+            return (function temp_f7(temp_f6) {
+                if ((temp_f6[0] === "rect")) {
+                    const br = temp_f6[1];
+                    return do_rect_circ_intersect(br, ac);
+                }
+                if ((temp_f6[0] === "circ")) {
+                    const bc = temp_f6[1];
+                    return do_circs_intersect(ac, bc);
+                }
+            })(b);
+        }
+    })(a);
+};
+
+export const ball_hitbox = function _(state) {
+    return (function temp_f9(temp_f8) {
+        if ((temp_f8[0] === "state")) {
+            const ball_center_x = (temp_f8[1]).ball_center_x;
+            const ball_center_y = (temp_f8[1]).ball_center_y;
+            return HitBox_rect(Rect_new_(LocalX_locx(nat_sub(locx_raw(ball_center_x), trunc_div_nat(locx_raw(get_ball_width(state)), pos2))), LocalY_locy(nat_sub(locy_raw(ball_center_y), trunc_div_nat(locy_raw(ball_height), pos2))), get_ball_width(state), ball_height));
+        }
+    })(state);
+};
+
+export const left_paddle_hitbox = function _(state) {
+    return (function temp_fb(temp_fa) {
+        if ((temp_fa[0] === "state")) {
+            const left_paddle_y = (temp_fa[1]).left_paddle_y;
+            return HitBox_rect(Rect_new_(left_paddle_x, left_paddle_y, paddle_width, paddle_height));
+        }
+    })(state);
+};
+
+export const right_paddle_hitbox = function _(state) {
+    return (function temp_fd(temp_fc) {
+        if ((temp_fc[0] === "state")) {
+            const right_paddle_y = (temp_fc[1]).right_paddle_y;
+            return HitBox_rect(Rect_new_(right_paddle_x, right_paddle_y, paddle_width, paddle_height));
+        }
+    })(state);
+};
+
+export const is_ball_touching_left_paddle = function _(state) {
+    return does_intersect(ball_hitbox(state), left_paddle_hitbox(state));
+};
+
+export const is_ball_touching_right_paddle = function _(state) {
+    return does_intersect(ball_hitbox(state), right_paddle_hitbox(state));
+};
+
+export const tick_assuming_ball_not_reset = function _(state, new_time) {
+    return (function temp_ff(temp_fe) {
+        if ((temp_fe[0] === "state")) {
+            const window = (temp_fe[1]).window;
+            const left_paddle_vy_sign = (temp_fe[1]).left_paddle_vy_sign;
+            const right_paddle_vy_sign = (temp_fe[1]).right_paddle_vy_sign;
+            const old_time = (temp_fe[1]).time;
+            const old_left_paddle_y = (temp_fe[1]).left_paddle_y;
+            const old_right_paddle_y = (temp_fe[1]).right_paddle_y;
+            const old_ball_center_x = (temp_fe[1]).ball_center_x;
+            const old_ball_center_y = (temp_fe[1]).ball_center_y;
+            const old_ball_vx = (temp_fe[1]).ball_vx;
+            const old_ball_vy = (temp_fe[1]).ball_vy;
+            return (function _2({elapsed_millis: elapsed_millis}) {
+                return State_state({ "window": window, "left_paddle_vy_sign": left_paddle_vy_sign, "right_paddle_vy_sign": right_paddle_vy_sign, "time": new_time, "left_paddle_y": old_left_paddle_y, "right_paddle_y": old_right_paddle_y, "ball_center_x": LocalX_locx(relu(add3(Int_nat(locx_raw(old_ball_center_x)), trunc_div(mul3(ilocx_raw(old_ball_vx), Int_nat(elapsed_millis)), pos1000)))), "ball_center_y": ball_bounce_y(old_ball_center_y, IntLocalY_ilocy(trunc_div(mul3(ilocy_raw(old_ball_vy), Int_nat(elapsed_millis)), pos1000))), "ball_vx": (function temp_101(temp_100) {
+                    if ((temp_100[0] === "true_")) {
+                        return IntLocalX_ilocx(Int_nat(int_abs(ilocx_raw(old_ball_vx))));
+                    }
+                    if ((temp_100[0] === "false_")) {
+                        return (function temp_103(temp_102) {
+                            if ((temp_102[0] === "true_")) {
+                                return IntLocalX_ilocx(neg3(Int_nat(int_abs(ilocx_raw(old_ball_vx)))));
+                            }
+                            if ((temp_102[0] === "false_")) {
+                                return old_ball_vx;
+                            }
+                        })(is_ball_touching_right_paddle(state));
+                    }
+                })(is_ball_touching_left_paddle(state)), "ball_vy": IntLocalY_ilocy(sign_int(ball_bounce_vy_sign(old_ball_center_y, IntLocalY_ilocy(trunc_div(mul3(ilocy_raw(old_ball_vy), Int_nat(elapsed_millis)), pos1000))), ilocy_raw(old_ball_vy))) });
+            })({ "elapsed_millis": nat_sub(time_millis(new_time), time_millis(old_time)) });
+        }
+    })(state);
+};
+
+export const get_elapsed_millis = function _(state, new_time) {
+    return (function temp_105(temp_104) {
+        if ((temp_104[0] === "state")) {
+            const old_time = (temp_104[1]).time;
+            return nat_sub(time_millis(new_time), time_millis(old_time));
+        }
+    })(state);
+};
+
+export const will_ball_go_out_of_x_bounds = function _(state, new_time) {
+    return (function temp_107(temp_106) {
+        if ((temp_106[0] === "state")) {
+            const ball_center_x = (temp_106[1]).ball_center_x;
+            const ball_vx = (temp_106[1]).ball_vx;
+            return (function _2(ball_x) {
+                return or(lt3(ilocx_raw(ball_x), int0), gt3(ilocx_raw(ball_x), int10k));
+            })(IntLocalX_ilocx(add3(Int_nat(locx_raw(ball_center_x)), trunc_div(mul3(ilocx_raw(ball_vx), Int_nat(get_elapsed_millis(state, new_time))), pos1000))));
+        }
+    })(state);
+};
+
+export const recenter_ball_and_pause = function _(state) {
+    return (function temp_109(temp_108) {
+        if ((temp_108[0] === "state")) {
+            const window = (temp_108[1]).window;
+            const time = (temp_108[1]).time;
+            const left_paddle_y = (temp_108[1]).left_paddle_y;
+            const right_paddle_y = (temp_108[1]).right_paddle_y;
+            const ball_center_x = (temp_108[1]).ball_center_x;
+            const ball_center_y = (temp_108[1]).ball_center_y;
+            const ball_vx = (temp_108[1]).ball_vx;
+            const ball_vy = (temp_108[1]).ball_vy;
+            const left_paddle_vy_sign = (temp_108[1]).left_paddle_vy_sign;
+            const right_paddle_vy_sign = (temp_108[1]).right_paddle_vy_sign;
+            return State_state({ "ball_vx": IntLocalX_ilocx(int0), "ball_vy": IntLocalY_ilocy(int0), "ball_center_x": LocalX_locx(nat5000), "ball_center_y": LocalY_locy(nat5000), "window": window, "time": time, "left_paddle_y": left_paddle_y, "right_paddle_y": right_paddle_y, "left_paddle_vy_sign": left_paddle_vy_sign, "right_paddle_vy_sign": right_paddle_vy_sign });
+        }
+    })(state);
+};
+
+export const tick = function _(state, new_time) {
+    return (function temp_10b(temp_10a) {
+        if ((temp_10a[0] === "true_")) {
+            return recenter_ball_and_pause(state);
+        }
+        if ((temp_10a[0] === "false_")) {
+            return tick_assuming_ball_not_reset(state, new_time);
+        }
+    })(will_ball_go_out_of_x_bounds(state, new_time));
+};
+
+export const handle_window_resize = function _(state, new_window) {
+    return (function temp_10d(temp_10c) {
+        if ((temp_10c[0] === "state")) {
+            const old_window = (temp_10c[1]).window;
+            const time = (temp_10c[1]).time;
+            const left_paddle_y = (temp_10c[1]).left_paddle_y;
+            const right_paddle_y = (temp_10c[1]).right_paddle_y;
+            const ball_center_x = (temp_10c[1]).ball_center_x;
+            const ball_center_y = (temp_10c[1]).ball_center_y;
+            const ball_vx = (temp_10c[1]).ball_vx;
+            const ball_vy = (temp_10c[1]).ball_vy;
+            const left_paddle_vy_sign = (temp_10c[1]).left_paddle_vy_sign;
+            const right_paddle_vy_sign = (temp_10c[1]).right_paddle_vy_sign;
+            return State_state({ "window": new_window, "time": time, "left_paddle_y": left_paddle_y, "right_paddle_y": right_paddle_y, "ball_center_x": ball_center_x, "ball_center_y": ball_center_y, "ball_vx": ball_vx, "ball_vy": ball_vy, "left_paddle_vy_sign": left_paddle_vy_sign, "right_paddle_vy_sign": right_paddle_vy_sign });
+        }
+    })(state);
+};
+
+export const is_ball_stationary_at_center = function _(state) {
+    return (function temp_10f(temp_10e) {
+        if ((temp_10e[0] === "state")) {
+            const ball_center_x = (temp_10e[1]).ball_center_x;
+            const ball_center_y = (temp_10e[1]).ball_center_y;
+            const ball_vx = (temp_10e[1]).ball_vx;
+            const ball_vy = (temp_10e[1]).ball_vy;
+            return and(eq6(int0, ilocx_raw(ball_vx)), and(eq6(int0, ilocy_raw(ball_vy)), and(eq5(nat5000, locx_raw(ball_center_x)), eq5(nat5000, locy_raw(ball_center_y)))));
+        }
+    })(state);
+};
+
+export const handle_keydown = function _(state, key) {
+    return (function temp_111(temp_110) {
+        if ((temp_110[0] === "state")) {
+            const time = (temp_110[1]).time;
+            const window = (temp_110[1]).window;
+            const old_left_paddle_y = (temp_110[1]).left_paddle_y;
+            const old_right_paddle_y = (temp_110[1]).right_paddle_y;
+            const ball_center_x = (temp_110[1]).ball_center_x;
+            const ball_center_y = (temp_110[1]).ball_center_y;
+            const ball_vx = (temp_110[1]).ball_vx;
+            const ball_vy = (temp_110[1]).ball_vy;
+            const old_left_paddle_vy_sign = (temp_110[1]).left_paddle_vy_sign;
+            const old_right_paddle_vy_sign = (temp_110[1]).right_paddle_vy_sign;
+            return (function temp_113(temp_112) {
+                if ((temp_112[0] === "true_")) {
+                    return State_state({ "left_paddle_vy_sign": Opt_some(Sign_neg), "left_paddle_y": (function temp_115(temp_114) {
+                        if ((temp_114[0] === "true_")) {
+                            return old_left_paddle_y;
+                        }
+                        if ((temp_114[0] === "false_")) {
+                            return clamp_paddle_y(sub(Int_nat(locy_raw(old_left_paddle_y)), Int_nat(locy_raw(paddle_height))));
+                        }
+                    })(opt_sign_eq_some(old_left_paddle_vy_sign, Sign_neg)), "time": time, "window": window, "right_paddle_y": old_right_paddle_y, "ball_center_x": ball_center_x, "ball_center_y": ball_center_y, "ball_vx": ball_vx, "ball_vy": ball_vy, "right_paddle_vy_sign": old_right_paddle_vy_sign });
+                }
+                if ((temp_112[0] === "false_")) {
+                    return (function temp_117(temp_116) {
+                        if ((temp_116[0] === "true_")) {
+                            return State_state({ "left_paddle_vy_sign": Opt_some(Sign_pos), "left_paddle_y": (function temp_119(temp_118) {
+                                if ((temp_118[0] === "true_")) {
+                                    return old_left_paddle_y;
+                                }
+                                if ((temp_118[0] === "false_")) {
+                                    return clamp_paddle_y(add3(Int_nat(locy_raw(old_left_paddle_y)), Int_nat(locy_raw(paddle_height))));
+                                }
+                            })(opt_sign_eq_some(old_left_paddle_vy_sign, Sign_pos)), "time": time, "window": window, "right_paddle_y": old_right_paddle_y, "ball_center_x": ball_center_x, "ball_center_y": ball_center_y, "ball_vx": ball_vx, "ball_vy": ball_vy, "right_paddle_vy_sign": old_right_paddle_vy_sign });
+                        }
+                        if ((temp_116[0] === "false_")) {
+                            return (function temp_11b(temp_11a) {
+                                if ((temp_11a[0] === "true_")) {
+                                    return State_state({ "right_paddle_vy_sign": Opt_some(Sign_neg), "right_paddle_y": (function temp_11d(temp_11c) {
+                                        if ((temp_11c[0] === "true_")) {
+                                            return old_right_paddle_y;
+                                        }
+                                        if ((temp_11c[0] === "false_")) {
+                                            return clamp_paddle_y(sub(Int_nat(locy_raw(old_right_paddle_y)), Int_nat(locy_raw(paddle_height))));
+                                        }
+                                    })(opt_sign_eq_some(old_right_paddle_vy_sign, Sign_neg)), "time": time, "window": window, "left_paddle_y": old_left_paddle_y, "ball_center_x": ball_center_x, "ball_center_y": ball_center_y, "ball_vx": ball_vx, "ball_vy": ball_vy, "left_paddle_vy_sign": old_left_paddle_vy_sign });
+                                }
+                                if ((temp_11a[0] === "false_")) {
+                                    return (function temp_11f(temp_11e) {
+                                        if ((temp_11e[0] === "true_")) {
+                                            return State_state({ "right_paddle_vy_sign": Opt_some(Sign_pos), "right_paddle_y": (function temp_121(temp_120) {
+                                                if ((temp_120[0] === "true_")) {
+                                                    return old_right_paddle_y;
+                                                }
+                                                if ((temp_120[0] === "false_")) {
+                                                    return clamp_paddle_y(add3(Int_nat(locy_raw(old_right_paddle_y)), Int_nat(locy_raw(paddle_height))));
+                                                }
+                                            })(opt_sign_eq_some(old_right_paddle_vy_sign, Sign_pos)), "time": time, "window": window, "left_paddle_y": old_left_paddle_y, "ball_center_x": ball_center_x, "ball_center_y": ball_center_y, "ball_vx": ball_vx, "ball_vy": ball_vy, "left_paddle_vy_sign": old_left_paddle_vy_sign });
+                                        }
+                                        if ((temp_11e[0] === "false_")) {
+                                            return (function temp_123(temp_122) {
+                                                if ((temp_122[0] === "true_")) {
+                                                    return (function temp_125(temp_124) {
+                                                        if ((temp_124[0] === "true_")) {
+                                                            return State_state({ "ball_vx": IntLocalX_ilocx(negate_on_1_bit({ "negatee": ilocx_raw(ball_initial_vx), "negator": time_millis(time) })), "ball_vy": IntLocalY_ilocy(negate_on_2_bit({ "negatee": ilocy_raw(ball_initial_vy), "negator": time_millis(time) })), "window": window, "time": time, "ball_center_x": ball_center_x, "ball_center_y": ball_center_y, "left_paddle_y": old_left_paddle_y, "right_paddle_y": old_right_paddle_y, "left_paddle_vy_sign": old_left_paddle_vy_sign, "right_paddle_vy_sign": old_right_paddle_vy_sign });
+                                                        }
+                                                        if ((temp_124[0] === "false_")) {
+                                                            return state;
+                                                        }
+                                                    })(is_ball_stationary_at_center(state));
+                                                }
+                                                if ((temp_122[0] === "false_")) {
+                                                    return state;
+                                                }
+                                            })(str_list_contains(launch_ball_strs, key));
+                                        }
+                                    })(str_list_contains(right_paddle_down_strs, key));
+                                }
+                            })(str_list_contains(right_paddle_up_strs, key));
+                        }
+                    })(str_list_contains(left_paddle_down_strs, key));
+                }
+            })(str_list_contains(left_paddle_up_strs, key));
+        }
+    })(state);
+};
+
+export const handle_keyup = function _(state, key) {
+    return (function temp_127(temp_126) {
+        if ((temp_126[0] === "state")) {
+            const time = (temp_126[1]).time;
+            const window = (temp_126[1]).window;
+            const left_paddle_y = (temp_126[1]).left_paddle_y;
+            const right_paddle_y = (temp_126[1]).right_paddle_y;
+            const ball_center_x = (temp_126[1]).ball_center_x;
+            const ball_center_y = (temp_126[1]).ball_center_y;
+            const ball_vx = (temp_126[1]).ball_vx;
+            const ball_vy = (temp_126[1]).ball_vy;
+            const left_paddle_vy_sign = (temp_126[1]).left_paddle_vy_sign;
+            const right_paddle_vy_sign = (temp_126[1]).right_paddle_vy_sign;
+            return (function temp_129(temp_128) {
+                if ((temp_128[0] === "true_")) {
+                    return State_state({ "left_paddle_vy_sign": (function temp_12b(temp_12a) {
+                        if ((temp_12a[0] === "none")) {
+                            const _2 = temp_12a[1];
+                            return left_paddle_vy_sign;
+                        }
+                        if ((temp_12a[0] === "some")) {
+                            const _2 = temp_12a[1];
+                            const some_left_paddle_vy_sign = temp_12a[2];
+                            return (function temp_12d(temp_12c) {
+                                if ((temp_12c[0] === "neg")) {
+                                    return Opt_none();
+                                }
+                                if ((temp_12c[0] === "pos")) {
+                                    return left_paddle_vy_sign;
+                                }
+                            })(ascribe(some_left_paddle_vy_sign));
+                        }
+                    })(left_paddle_vy_sign), "time": time, "window": window, "left_paddle_y": left_paddle_y, "right_paddle_y": right_paddle_y, "ball_center_x": ball_center_x, "ball_center_y": ball_center_y, "ball_vx": ball_vx, "ball_vy": ball_vy, "right_paddle_vy_sign": right_paddle_vy_sign });
+                }
+                if ((temp_128[0] === "false_")) {
+                    return (function temp_12f(temp_12e) {
+                        if ((temp_12e[0] === "true_")) {
+                            return State_state({ "left_paddle_vy_sign": (function temp_131(temp_130) {
+                                if ((temp_130[0] === "none")) {
+                                    const _2 = temp_130[1];
+                                    return left_paddle_vy_sign;
+                                }
+                                if ((temp_130[0] === "some")) {
+                                    const _2 = temp_130[1];
+                                    const some_left_paddle_vy_sign = temp_130[2];
+                                    return (function temp_133(temp_132) {
+                                        if ((temp_132[0] === "pos")) {
+                                            return Opt_none();
+                                        }
+                                        if ((temp_132[0] === "neg")) {
+                                            return left_paddle_vy_sign;
+                                        }
+                                    })(ascribe(some_left_paddle_vy_sign));
+                                }
+                            })(left_paddle_vy_sign), "time": time, "window": window, "left_paddle_y": left_paddle_y, "right_paddle_y": right_paddle_y, "ball_center_x": ball_center_x, "ball_center_y": ball_center_y, "ball_vx": ball_vx, "ball_vy": ball_vy, "right_paddle_vy_sign": right_paddle_vy_sign });
+                        }
+                        if ((temp_12e[0] === "false_")) {
+                            return (function temp_135(temp_134) {
+                                if ((temp_134[0] === "true_")) {
+                                    return State_state({ "right_paddle_vy_sign": (function temp_137(temp_136) {
+                                        if ((temp_136[0] === "none")) {
+                                            const _2 = temp_136[1];
+                                            return right_paddle_vy_sign;
+                                        }
+                                        if ((temp_136[0] === "some")) {
+                                            const _2 = temp_136[1];
+                                            const some_right_paddle_vy_sign = temp_136[2];
+                                            return (function temp_139(temp_138) {
+                                                if ((temp_138[0] === "neg")) {
+                                                    return Opt_none();
+                                                }
+                                                if ((temp_138[0] === "pos")) {
+                                                    return right_paddle_vy_sign;
+                                                }
+                                            })(ascribe(some_right_paddle_vy_sign));
+                                        }
+                                    })(right_paddle_vy_sign), "time": time, "window": window, "left_paddle_y": left_paddle_y, "right_paddle_y": right_paddle_y, "ball_center_x": ball_center_x, "ball_center_y": ball_center_y, "ball_vx": ball_vx, "ball_vy": ball_vy, "left_paddle_vy_sign": left_paddle_vy_sign });
+                                }
+                                if ((temp_134[0] === "false_")) {
+                                    return (function temp_13b(temp_13a) {
+                                        if ((temp_13a[0] === "true_")) {
+                                            return State_state({ "right_paddle_vy_sign": (function temp_13d(temp_13c) {
+                                                if ((temp_13c[0] === "none")) {
+                                                    const _2 = temp_13c[1];
+                                                    return right_paddle_vy_sign;
+                                                }
+                                                if ((temp_13c[0] === "some")) {
+                                                    const _2 = temp_13c[1];
+                                                    const some_right_paddle_vy_sign = temp_13c[2];
+                                                    return (function temp_13f(temp_13e) {
+                                                        if ((temp_13e[0] === "pos")) {
+                                                            return Opt_none();
+                                                        }
+                                                        if ((temp_13e[0] === "neg")) {
+                                                            return right_paddle_vy_sign;
+                                                        }
+                                                    })(ascribe(some_right_paddle_vy_sign));
+                                                }
+                                            })(right_paddle_vy_sign), "time": time, "window": window, "left_paddle_y": left_paddle_y, "right_paddle_y": right_paddle_y, "ball_center_x": ball_center_x, "ball_center_y": ball_center_y, "ball_vx": ball_vx, "ball_vy": ball_vy, "left_paddle_vy_sign": left_paddle_vy_sign });
+                                        }
+                                        if ((temp_13a[0] === "false_")) {
+                                            return state;
+                                        }
+                                    })(str_list_contains(right_paddle_down_strs, key));
+                                }
+                            })(str_list_contains(right_paddle_up_strs, key));
+                        }
+                    })(str_list_contains(left_paddle_down_strs, key));
+                }
+            })(str_list_contains(left_paddle_up_strs, key));
+        }
+    })(state);
+};
+
+export const handle = function _(state, event) {
+    return (function temp_141(temp_140) {
+        if ((temp_140[0] === "window_resize")) {
+            const new_window = temp_140[1];
+            return handle_window_resize(state, new_window);
+        }
+        if ((temp_140[0] === "keyup")) {
+            const key = temp_140[1];
+            return handle_keyup(state, key);
+        }
+        if ((temp_140[0] === "keydown")) {
+            const key = temp_140[1];
+            return handle_keydown(state, key);
+        }
+    })(event);
+};
+
+export const app = App_app(render, tick, init, handle);
+
+//# sourceMappingURL=pong.js.map
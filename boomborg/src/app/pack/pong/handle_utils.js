@@ -0,0 +1,3 @@
+export { handle_window_resize, is_ball_stationary_at_center, handle_keydown, handle_keyup } from "../pong.js";
+
+//# sourceMappingURL=handle_utils.js.map
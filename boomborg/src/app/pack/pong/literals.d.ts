@@ -0,0 +1,2 @@
+export { pos1, pos2, pos3, pos4, pos5, pos6, pos7, pos8, pos9, pos10, pos20, pos30, pos60, pos80, pos100, pos32, pos64, pos65, pos66, pos67, pos68, pos83, pos85, pos87, pos110, pos111, pos112, pos114, pos115, pos119, pos1000, pos5000, pos10k, pos20k, nat1, nat5000, nat10k, int0, int10k, u8_32, u8_65, u8_66, u8_67, u8_68, u8_83, u8_85, u8_87, u8_110, u8_111, u8_112, u8_114, u8_115, u8_119, ch_A, ch_B, ch_C, ch_D, ch_S, ch_U, ch_W, ch_n, ch_o, ch_p, ch_r, ch_s, ch_w, ch_space, background_image_str, paddle_image_str, ball_image_str, right_paddle_up_strs, right_paddle_down_strs, left_paddle_up_strs, left_paddle_down_strs, launch_ball_strs } from "../pong.js";
+
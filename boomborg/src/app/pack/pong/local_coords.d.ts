@@ -0,0 +1,34 @@
+import type { Nat, Int } from "../std/num/unsized.js";
+
+export type LocalX = ["locx", Nat];
+
+export declare const LocalX: unknown;
+
+export declare const LocalX_locx: (x: Nat) => LocalX;
+
+export type LocalY = ["locy", Nat];
+
+export declare const LocalY: unknown;
+
+export declare const LocalY_locy: (y: Nat) => LocalY;
+
+export declare const locx_raw: (lx: LocalX) => Nat;
+
+export declare const locy_raw: (ly: LocalY) => Nat;
+
+export type IntLocalX = ["ilocx", Int];
+
+export declare const IntLocalX: unknown;
+
+export declare const IntLocalX_ilocx: (x: Int) => IntLocalX;
+
+export type IntLocalY = ["ilocy", Int];
+
+export declare const IntLocalY: unknown;
+
+export declare const IntLocalY_ilocy: (y: Int) => IntLocalY;
+
+export declare const ilocx_raw: (lx: IntLocalX) => Int;
+
+export declare const ilocy_raw: (ly: IntLocalY) => Int;
+
@@ -0,0 +1,61 @@
+export const LocalX = { "type_species": "LocalX", "type_args": [] };
+
+export const LocalX_locx = function LocalX_locx(x) {
+    return ["locx", x];
+};
+
+export const LocalY = { "type_species": "LocalY", "type_args": [] };
+
+export const LocalY_locy = function LocalY_locy(y) {
+    return ["locy", y];
+};
+
+export const locx_raw = function _(lx) {
+    return (function temp_9b(temp_9a) {
+        if ((temp_9a[0] === "locx")) {
+            const x = temp_9a[1];
+            return x;
+        }
+    })(lx);
+};
+
+export const locy_raw = function _(ly) {
+    return (function temp_9d(temp_9c) {
+        if ((temp_9c[0] === "locy")) {
+            const y = temp_9c[1];
+            return y;
+        }
+    })(ly);
+};
+
+export const IntLocalX = { "type_species": "IntLocalX", "type_args": [] };
+
+export const IntLocalX_ilocx = function IntLocalX_ilocx(x) {
+    return ["ilocx", x];
+};
+
+export const IntLocalY = { "type_species": "IntLocalY", "type_args": [] };
+
+export const IntLocalY_ilocy = function IntLocalY_ilocy(y) {
+    return ["ilocy", y];
+};
+
+export const ilocx_raw = function _(lx) {
+    return (function temp_9f(temp_9e) {
+        if ((temp_9e[0] === "ilocx")) {
+            const x = temp_9e[1];
+            return x;
+        }
+    })(lx);
+};
+
+export const ilocy_raw = function _(ly) {
+    return (function temp_a1(temp_a0) {
+        if ((temp_a0[0] === "ilocy")) {
+            const y = temp_a0[1];
+            return y;
+        }
+    })(ly);
+};
+
+//# sourceMappingURL=local_coords.js.map
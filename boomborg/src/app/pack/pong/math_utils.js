@@ -0,0 +1,3 @@
+export { sign_eq, opt_sign_eq_some, sign_int, relu, nat_to_pos, nat_sub, nat_mod, int_abs, nat_dist, clamp, sign_neg_one_exp, negate_on_1_bit, negate_on_2_bit } from "../pong.js";
+
+//# sourceMappingURL=math_utils.js.map
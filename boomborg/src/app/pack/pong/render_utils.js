@@ -0,0 +1,3 @@
+export { render_background, render_left_paddle, render_right_paddle, get_ball_width, render_ball } from "../pong.js";
+
+//# sourceMappingURL=render_utils.js.map
@@ -0,0 +1,2 @@
+export { ball_bounce_y_top, ball_bounce_y, ball_bounce_vy_sign_top, ball_bounce_vy_sign, Rect, Rect_new_ as Rect_new, Circ, Circ_new_ as Circ_new, HitBox, HitBox_rect, HitBox_circ, do_rects_intersect, do_rect_circ_intersect, do_circs_intersect, does_intersect, ball_hitbox, left_paddle_hitbox, right_paddle_hitbox, is_ball_touching_left_paddle, is_ball_touching_right_paddle, tick_assuming_ball_not_reset, get_elapsed_millis, will_ball_go_out_of_x_bounds, recenter_ball_and_pause } from "../pong.js";
+
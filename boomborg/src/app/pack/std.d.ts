@@ -0,0 +1,6 @@
+export type Equal<T> = ["refl", undefined, T];
+
+export declare const Equal: unknown;
+
+export declare const Equal_refl: <T>(a: T) => Equal<T>;
+
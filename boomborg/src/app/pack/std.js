@@ -0,0 +1,9 @@
+export const Equal = function Equal(a, b) {
+    return { "type_species": "Equal", "type_args": [undefined, a, b] };
+};
+
+export const Equal_refl = function Equal_refl(a) {
+    return ["refl", undefined, a];
+};
+
+//# sourceMappingURL=std.js.map
@@ -0,0 +1,18 @@
+export type Bool = ["true_"] | ["false_"];
+
+export declare const Bool: unknown;
+
+declare const Bool_true_: Bool;
+
+declare const Bool_false_: Bool;
+
+export declare const not: (a: Bool) => Bool;
+
+export declare const and: (a: Bool, b: Bool) => Bool;
+
+export declare const or: (a: Bool, b: Bool) => Bool;
+
+export declare const Trueb: (a: Bool) => unknown;
+
+export { Bool_true_ as Bool_true, Bool_false_ as Bool_false };
+
@@ -0,0 +1,48 @@
+import { Equal } from "../std.js";
+
+export const Bool = { "type_species": "Bool", "type_args": [] };
+
+const Bool_true_ = ["true_"];
+
+const Bool_false_ = ["false_"];
+
+export const not = function _(a) {
+    return (function temp_1(temp_0) {
+        if ((temp_0[0] === "true_")) {
+            return Bool_false_;
+        }
+        if ((temp_0[0] === "false_")) {
+            return Bool_true_;
+        }
+    })(a);
+};
+
+export const and = function _(a, b) {
+    return (function temp_3(temp_2) {
+        if ((temp_2[0] === "true_")) {
+            return b;
+        }
+        if ((temp_2[0] === "false_")) {
+            return Bool_false_;
+        }
+    })(a);
+};
+
+export const or = function _(a, b) {
+    return (function temp_5(temp_4) {
+        if ((temp_4[0] === "true_")) {
+            return Bool_true_;
+        }
+        if ((temp_4[0] === "false_")) {
+            return b;
+        }
+    })(a);
+};
+
+export const Trueb = function _(a) {
+    return Equal(Bool_true_, a);
+};
+
+export { Bool_true_ as Bool_true, Bool_false_ as Bool_false };
+
+//# sourceMappingURL=bool.js.map
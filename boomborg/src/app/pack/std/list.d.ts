@@ -0,0 +1,14 @@
+import type { Bool } from "./bool.js";
+
+export type List<T> = ["nil", undefined] | ["cons", undefined, T, List<T>];
+
+export declare const List: unknown;
+
+export declare const List_nil: <T>() => List<T>;
+
+export declare const List_cons: <T>(car: T, cdr: List<T>) => List<T>;
+
+declare const eq2: <T>(a: List<T>, b: List<T>, eqf: (ax: T, bx: T) => Bool) => Bool;
+
+export { eq2 as eq };
+
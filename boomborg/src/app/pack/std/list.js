@@ -0,0 +1,61 @@
+import { Bool_true as Bool_true_, Bool_false as Bool_false_ } from "./bool.js";
+
+export const List = function List() {
+    return { "type_species": "List", "type_args": [undefined] };
+};
+
+export const List_nil = function List_nil() {
+    return ["nil", undefined];
+};
+
+export const List_cons = function List_cons(car, cdr) {
+    return ["cons", undefined, car, cdr];
+};
+
+const eq2 = function eq2(a, b, eqf) {
+    return (function temp_d(temp_c) {
+        if ((temp_c[0] === "nil")) {
+            const _ = temp_c[1];
+            return (function temp_f(temp_e) {
+                if ((temp_e[0] === "nil")) {
+                    const _2 = temp_e[1];
+                    return Bool_true_;
+                }
+                if ((temp_e[0] === "cons")) {
+                    const _2 = temp_e[1];
+                    const _3 = temp_e[2];
+                    const _4 = temp_e[3];
+                    return Bool_false_;
+                }
+            })(b);
+        }
+        if ((temp_c[0] === "cons")) {
+            const _ = temp_c[1];
+            const a_car = temp_c[2];
+            const a_cdr = temp_c[3];
+            return (function temp_11(temp_10) {
+                if ((temp_10[0] === "nil")) {
+                    const _2 = temp_10[1];
+                    return Bool_false_;
+                }
+                if ((temp_10[0] === "cons")) {
+                    const _2 = temp_10[1];
+                    const b_car = temp_10[2];
+                    const b_cdr = temp_10[3];
+                    return (function temp_13(temp_12) {
+                        if ((temp_12[0] === "true_")) {
+                            return eq2(a_cdr, b_cdr, eqf);
+                        }
+                        if ((temp_12[0] === "false_")) {
+                            return Bool_false_;
+                        }
+                    })(eqf(a_car, b_car));
+                }
+            })(b);
+        }
+    })(a);
+};
+
+export { eq2 as eq };
+
+//# sourceMappingURL=list.js.map
@@ -0,0 +1,8 @@
+export type Bit = ["zero"] | ["one"];
+
+export declare const Bit: unknown;
+
+export declare const Bit_zero: Bit;
+
+export declare const Bit_one: Bit;
+
@@ -0,0 +1,7 @@
+export const Bit = { "type_species": "Bit", "type_args": [] };
+
+export const Bit_zero = ["zero"];
+
+export const Bit_one = ["one"];
+
+//# sourceMappingURL=num.js.map
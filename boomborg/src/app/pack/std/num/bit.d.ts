@@ -0,0 +1,11 @@
+import type { Bit } from "../num.js";
+import type { Bool } from "../bool.js";
+
+export declare const is_zero: (a: Bit) => Bool;
+
+export declare const is_one: (a: Bit) => Bool;
+
+declare const eq3: (a: Bit, b: Bit) => Bool;
+
+export { eq3 as eq };
+
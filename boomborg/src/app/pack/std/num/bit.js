@@ -0,0 +1,38 @@
+import { Bool_true as Bool_true_, Bool_false as Bool_false_ } from "../bool.js";
+
+export const is_zero = function _(a) {
+    return (function temp_15(temp_14) {
+        if ((temp_14[0] === "zero")) {
+            return Bool_true_;
+        }
+        if ((temp_14[0] === "one")) {
+            return Bool_false_;
+        }
+    })(a);
+};
+
+export const is_one = function _(a) {
+    return (function temp_17(temp_16) {
+        if ((temp_16[0] === "zero")) {
+            return Bool_false_;
+        }
+        if ((temp_16[0] === "one")) {
+            return Bool_true_;
+        }
+    })(a);
+};
+
+const eq3 = function _(a, b) {
+    return (function temp_19(temp_18) {
+        if ((temp_18[0] === "zero")) {
+            return is_zero(b);
+        }
+        if ((temp_18[0] === "one")) {
+            return is_one(b);
+        }
+    })(a);
+};
+
+export { eq3 as eq };
+
+//# sourceMappingURL=bit.js.map
@@ -0,0 +1,41 @@
+import type { Int, Pos, Nat } from "./unsized.js";
+import type { Bool } from "../bool.js";
+import type { Bit } from "../num.js";
+import type { List } from "../list.js";
+
+declare const eq6: (a: Int, b: Int) => Bool;
+
+declare const neq5: (a: Int, b: Int) => Bool;
+
+declare const succ3: (a: Int) => Int;
+
+declare const pred3: (a: Int) => Int;
+
+declare const neg3: (a: Int) => Int;
+
+declare const double_: (a: Int) => Int;
+
+export declare const sub_pos: (a: Pos, b: Pos) => Int;
+
+declare const add3: (a: Int, b: Int) => Int;
+
+export declare const sub: (a: Int, b: Int) => Int;
+
+declare const mul3: (a: Int, b: Int) => Int;
+
+export declare const trunc_div_bitlist: (dividend_left: Nat, dividend_right: List<Bit>, divisor: Pos) => List<Bit>;
+
+export declare const trunc_div_pos: (a: Pos, b: Pos) => Nat;
+
+export declare const trunc_div_nat: (a: Nat, b: Pos) => Nat;
+
+export declare const trunc_div: (a: Int, b: Pos) => Int;
+
+declare const le3: (a: Int, b: Int) => Bool;
+
+declare const lt3: (a: Int, b: Int) => Bool;
+
+declare const gt3: (a: Int, b: Int) => Bool;
+
+export { eq6 as eq, neq5 as neq, succ3 as succ, pred3 as pred, neg3 as neg, double_ as double, add3 as add, mul3 as mul, le3 as le, lt3 as lt, gt3 as gt };
+
@@ -0,0 +1,237 @@
+import { not, Bool_true as Bool_true_, Bool_false as Bool_false_ } from "../bool.js";
+import { neg as neg2, succ as succ2, pred as pred2, extend_right, from_bitlist, le as le2 } from "./nat.js";
+import { pred, succ, minimal_bitlist, ge } from "./pos.js";
+import { Int_nat, Int_neg, Nat_pos, Pos_extend, Nat_zero } from "./unsized.js";
+import { Bit_zero, Bit_one } from "../num.js";
+import { List_nil, List_cons } from "../list.js";
+
+const eq6 = function eq6(a, b) {
+    return ((a === b)
+        ? ["true_"]
+        : ["false_"]
+    );
+};
+
+const neq5 = function _(a, b) {
+    return not(eq6(a, b));
+};
+
+const succ3 = function _(a) {
+    return (function temp_5b(temp_5a) {
+        if ((temp_5a < 0n)) {
+            const neg_a = (0n - temp_5a);
+            return neg2(pred(neg_a));
+        }
+        if ((temp_5a >= 0n)) {
+            const an = temp_5a;
+            return Int_nat(succ2(an));
+        }
+    })(a);
+};
+
+const pred3 = function _(a) {
+    return (function temp_5d(temp_5c) {
+        if ((temp_5c < 0n)) {
+            const neg_a = (0n - temp_5c);
+            return Int_neg(succ(neg_a));
+        }
+        if ((temp_5c >= 0n)) {
+            const an = temp_5c;
+            return pred2(an);
+        }
+    })(a);
+};
+
+const neg3 = function _(a) {
+    return (function temp_65(temp_64) {
+        if ((temp_64 < 0n)) {
+            const neg_a = (0n - temp_64);
+            return Int_nat(Nat_pos(neg_a));
+        }
+        if ((temp_64 >= 0n)) {
+            const an = temp_64;
+            return neg2(an);
+        }
+    })(a);
+};
+
+const double_ = function _(a) {
+    return (function temp_67(temp_66) {
+        if ((temp_66 < 0n)) {
+            const neg_a = (0n - temp_66);
+            return Int_neg(Pos_extend(neg_a, Bit_zero));
+        }
+        if ((temp_66 >= 0n)) {
+            const an = temp_66;
+            return (function temp_69(temp_68) {
+                if ((temp_68 === 0n)) {
+                    return a;
+                }
+                if ((temp_68 > 0n)) {
+                    const ap = temp_68;
+                    return Int_nat(Nat_pos(Pos_extend(ap, Bit_zero)));
+                }
+            })(an);
+        }
+    })(a);
+};
+
+export const sub_pos = function sub_pos(a, b) {
+    return (function temp_6b(temp_6a) {
+        if ((temp_6a === 1n)) {
+            return Int_nat(pred(a));
+        }
+        if ((temp_6a > 1n)) {
+            const b_left = (temp_6a >> 1n);
+            const b_right = (((temp_6a & 1n) === 0n)
+                ? ["zero"]
+                : ["one"]
+            );
+            return (function temp_6d(temp_6c) {
+                if ((temp_6c === 1n)) {
+                    return neg2(pred(b));
+                }
+                if ((temp_6c > 1n)) {
+                    const a_left = (temp_6c >> 1n);
+                    const a_right = (((temp_6c & 1n) === 0n)
+                        ? ["zero"]
+                        : ["one"]
+                    );
+                    return (function temp_6f(temp_6e) {
+                        if ((temp_6e[0] === "zero")) {
+                            return (function temp_71(temp_70) {
+                                if ((temp_70[0] === "zero")) {
+                                    return double_(sub_pos(a_left, b_left));
+                                }
+                                if ((temp_70[0] === "one")) {
+                                    return succ3(double_(sub_pos(a_left, b_left)));
+                                }
+                            })(a_right);
+                        }
+                        if ((temp_6e[0] === "one")) {
+                            return (function temp_73(temp_72) {
+                                if ((temp_72[0] === "one")) {
+                                    return double_(sub_pos(a_left, b_left));
+                                }
+                                if ((temp_72[0] === "zero")) {
+                                    return pred3(double_(sub_pos(a_left, b_left)));
+                                }
+                            })(a_right);
+                        }
+                    })(b_right);
+                }
+            })(a);
+        }
+    })(b);
+};
+
+const add3 = function add3(a, b) {
+    return (a + b);
+};
+
+export const sub = function _(a, b) {
+    return add3(a, neg3(b));
+};
+
+const mul3 = function mul3(a, b) {
+    return (a * b);
+};
+
+export const trunc_div_bitlist = function trunc_div_bitlist(dividend_left, dividend_right, divisor) {
+    return (function temp_75(temp_74) {
+        if ((temp_74[0] === "nil")) {
+            const _ = temp_74[1];
+            return List_nil();
+        }
+        if ((temp_74[0] === "cons")) {
+            const _ = temp_74[1];
+            const car = temp_74[2];
+            const cdr = temp_74[3];
+            return (function temp_77(temp_76) {
+                if ((temp_76 < 0n)) {
+                    const _2 = (0n - temp_76);
+                    return List_cons(Bit_zero, trunc_div_bitlist(extend_right(dividend_left, car), cdr, divisor));
+                }
+                if ((temp_76 >= 0n)) {
+                    const remainder = temp_76;
+                    return List_cons(Bit_one, trunc_div_bitlist(remainder, cdr, divisor));
+                }
+            })(sub(Int_nat(extend_right(dividend_left, car)), Int_nat(Nat_pos(divisor))));
+        }
+    })(dividend_right);
+};
+
+export const trunc_div_pos = function _(a, b) {
+    return from_bitlist(trunc_div_bitlist(Nat_zero, minimal_bitlist(a), b));
+};
+
+export const trunc_div_nat = function _(a, b) {
+    return (function temp_79(temp_78) {
+        if ((temp_78 === 0n)) {
+            return Nat_zero;
+        }
+        if ((temp_78 > 0n)) {
+            const ap = temp_78;
+            return trunc_div_pos(ap, b);
+        }
+    })(a);
+};
+
+export const trunc_div = function _(a, b) {
+    return (function temp_7b(temp_7a) {
+        if ((temp_7a < 0n)) {
+            const neg_a = (0n - temp_7a);
+            return neg2(trunc_div_pos(neg_a, b));
+        }
+        if ((temp_7a >= 0n)) {
+            const an = temp_7a;
+            return Int_nat(trunc_div_nat(an, b));
+        }
+    })(a);
+};
+
+const le3 = function _(a, b) {
+    return (function temp_85(temp_84) {
+        if ((temp_84 < 0n)) {
+            const neg_a = (0n - temp_84);
+            return (function temp_87(temp_86) {
+                if ((temp_86 >= 0n)) {
+                    const _2 = temp_86;
+                    return Bool_true_;
+                }
+                if ((temp_86 < 0n)) {
+                    const neg_b = (0n - temp_86);
+                    return ge(neg_a, neg_b);
+                }
+            })(b);
+        }
+        if ((temp_84 >= 0n)) {
+            const an = temp_84;
+            return (function temp_89(temp_88) {
+                if ((temp_88 < 0n)) {
+                    const _2 = (0n - temp_88);
+                    return Bool_false_;
+                }
+                if ((temp_88 >= 0n)) {
+                    const bn = temp_88;
+                    return le2(an, bn);
+                }
+            })(b);
+        }
+    })(a);
+};
+
+const lt3 = function lt3(a, b) {
+    return ((a < b)
+        ? ["true_"]
+        : ["false_"]
+    );
+};
+
+const gt3 = function _(a, b) {
+    return lt3(b, a);
+};
+
+export { eq6 as eq, neq5 as neq, succ3 as succ, pred3 as pred, neg3 as neg, double_ as double, add3 as add, mul3 as mul, le3 as le, lt3 as lt, gt3 as gt };
+
+//# sourceMappingURL=int.js.map
@@ -0,0 +1,43 @@
+import type { Nat, Int } from "./unsized.js";
+import type { Bool } from "../bool.js";
+import type { Bit } from "../num.js";
+import type { List } from "../list.js";
+
+export declare const one: Nat;
+
+declare const eq5: (a: Nat, b: Nat) => Bool;
+
+declare const neq4: (a: Nat, b: Nat) => Bool;
+
+declare const succ2: (a: Nat) => Nat;
+
+declare const pred2: (a: Nat) => Int;
+
+export declare const from_bit: (a: Bit) => Nat;
+
+export declare const extend_right: (a: Nat, right: Bit) => Nat;
+
+export declare const extend_right_with_bits: (a: Nat, right: List<Bit>) => Nat;
+
+export declare const from_bitlist: (bits: List<Bit>) => Nat;
+
+declare const parity2: (a: Nat) => Bit;
+
+declare const neg2: (a: Nat) => Int;
+
+declare const add2: (a: Nat, b: Nat) => Nat;
+
+declare const mul2: (a: Nat, b: Nat) => Nat;
+
+declare const square2: (a: Nat) => Nat;
+
+declare const le2: (a: Nat, b: Nat) => Bool;
+
+declare const lt2: (a: Nat, b: Nat) => Bool;
+
+declare const ge2: (a: Nat, b: Nat) => Bool;
+
+declare const gt2: (a: Nat, b: Nat) => Bool;
+
+export { eq5 as eq, neq4 as neq, succ2 as succ, pred2 as pred, parity2 as parity, neg2 as neg, add2 as add, mul2 as mul, square2 as square, le2 as le, lt2 as lt, ge2 as ge, gt2 as gt };
+
@@ -0,0 +1,158 @@
+import { Nat_pos, Pos_one, Int_neg, Int_nat, Nat_zero, Pos_extend } from "./unsized.js";
+import { not, Bool_true as Bool_true_, Bool_false as Bool_false_ } from "../bool.js";
+import { succ, pred, parity, le } from "./pos.js";
+import { Bit_zero } from "../num.js";
+
+export const one = Nat_pos(Pos_one);
+
+const eq5 = function eq5(a, b) {
+    return ((a === b)
+        ? ["true_"]
+        : ["false_"]
+    );
+};
+
+const neq4 = function _(a, b) {
+    return not(eq5(a, b));
+};
+
+const succ2 = function succ2(a) {
+    return (function temp_3d(temp_3c) {
+        if ((temp_3c === 0n)) {
+            return one;
+        }
+        if ((temp_3c > 0n)) {
+            const ap = temp_3c;
+            return Nat_pos(succ(ap));
+        }
+    })(a);
+};
+
+const pred2 = function pred2(a) {
+    return (function temp_3f(temp_3e) {
+        if ((temp_3e === 0n)) {
+            return Int_neg(Pos_one);
+        }
+        if ((temp_3e > 0n)) {
+            const ap = temp_3e;
+            return Int_nat(pred(ap));
+        }
+    })(a);
+};
+
+export const from_bit = function _(a) {
+    return (function temp_41(temp_40) {
+        if ((temp_40[0] === "zero")) {
+            return Nat_zero;
+        }
+        if ((temp_40[0] === "one")) {
+            return one;
+        }
+    })(a);
+};
+
+export const extend_right = function _(a, right) {
+    return (function temp_43(temp_42) {
+        if ((temp_42 === 0n)) {
+            return from_bit(right);
+        }
+        if ((temp_42 > 0n)) {
+            const ap = temp_42;
+            return Nat_pos(Pos_extend(ap, right));
+        }
+    })(a);
+};
+
+export const extend_right_with_bits = function extend_right_with_bits(a, right) {
+    return (function temp_45(temp_44) {
+        if ((temp_44[0] === "nil")) {
+            const _ = temp_44[1];
+            return a;
+        }
+        if ((temp_44[0] === "cons")) {
+            const _ = temp_44[1];
+            const car = temp_44[2];
+            const cdr = temp_44[3];
+            return extend_right_with_bits(extend_right(a, car), cdr);
+        }
+    })(right);
+};
+
+export const from_bitlist = function _(bits) {
+    return extend_right_with_bits(Nat_zero, bits);
+};
+
+const parity2 = function _(a) {
+    return (function temp_47(temp_46) {
+        if ((temp_46 === 0n)) {
+            return Bit_zero;
+        }
+        if ((temp_46 > 0n)) {
+            const ap = temp_46;
+            return parity(ap);
+        }
+    })(a);
+};
+
+const neg2 = function _(a) {
+    return (function temp_49(temp_48) {
+        if ((temp_48 === 0n)) {
+            return Int_nat(Nat_zero);
+        }
+        if ((temp_48 > 0n)) {
+            const ap = temp_48;
+            return Int_neg(ap);
+        }
+    })(a);
+};
+
+const add2 = function add2(a, b) {
+    return (a + b);
+};
+
+const mul2 = function mul2(a, b) {
+    return (a * b);
+};
+
+const square2 = function _(a) {
+    return mul2(a, a);
+};
+
+const le2 = function le2(a, b) {
+    return (function temp_4f(temp_4e) {
+        if ((temp_4e === 0n)) {
+            return Bool_true_;
+        }
+        if ((temp_4e > 0n)) {
+            const ap = temp_4e;
+            return (function temp_51(temp_50) {
+                if ((temp_50 === 0n)) {
+                    return Bool_false_;
+                }
+                if ((temp_50 > 0n)) {
+                    const bp = temp_50;
+                    return le(ap, bp);
+                }
+            })(b);
+        }
+    })(a);
+};
+
+const lt2 = function lt2(a, b) {
+    return ((a < b)
+        ? ["true_"]
+        : ["false_"]
+    );
+};
+
+const ge2 = function _(a, b) {
+    return le2(b, a);
+};
+
+const gt2 = function _(a, b) {
+    return lt2(b, a);
+};
+
+export { eq5 as eq, neq4 as neq, succ2 as succ, pred2 as pred, parity2 as parity, neg2 as neg, add2 as add, mul2 as mul, square2 as square, le2 as le, lt2 as lt, ge2 as ge, gt2 as gt };
+
+//# sourceMappingURL=nat.js.map
@@ -0,0 +1,33 @@
+import type { Pos, Nat } from "./unsized.js";
+import type { Bool } from "../bool.js";
+import type { Bit } from "../num.js";
+import type { List } from "../list.js";
+
+declare const eq4: (a: Pos, b: Pos) => Bool;
+
+declare const neq3: (a: Pos, b: Pos) => Bool;
+
+export declare const minimal_bitlist_plus: (a: Pos, right_accumulator: List<Bit>) => List<Bit>;
+
+export declare const minimal_bitlist: (a: Pos) => List<Bit>;
+
+export declare const succ: (a: Pos) => Pos;
+
+export declare const pred: (a: Pos) => Nat;
+
+export declare const parity: (a: Pos) => Bit;
+
+export declare const add: (a: Pos, b: Pos) => Pos;
+
+export declare const mul: (a: Pos, b: Pos) => Pos;
+
+export declare const square: (a: Pos) => Pos;
+
+export declare const pow: (a: Pos, b: Pos) => Pos;
+
+export declare const le: (a: Pos, b: Pos) => Bool;
+
+export declare const ge: (a: Pos, b: Pos) => Bool;
+
+export { eq4 as eq, neq3 as neq };
+
@@ -0,0 +1,190 @@
+import { not, Bool_true as Bool_true_, Bool_false as Bool_false_, and } from "../bool.js";
+import { List_cons, List_nil } from "../list.js";
+import { Bit_one, Bit_zero } from "../num.js";
+import { Pos_extend, Pos_one, Nat_zero, Nat_pos } from "./unsized.js";
+
+const eq4 = function eq4(a, b) {
+    return ((a === b)
+        ? ["true_"]
+        : ["false_"]
+    );
+};
+
+const neq3 = function _(a, b) {
+    return not(eq4(a, b));
+};
+
+export const minimal_bitlist_plus = function minimal_bitlist_plus(a, right_accumulator) {
+    return (function temp_1b(temp_1a) {
+        if ((temp_1a === 1n)) {
+            return List_cons(Bit_one, right_accumulator);
+        }
+        if ((temp_1a > 1n)) {
+            const a_left = (temp_1a >> 1n);
+            const a_right = (((temp_1a & 1n) === 0n)
+                ? ["zero"]
+                : ["one"]
+            );
+            return minimal_bitlist_plus(a_left, List_cons(a_right, right_accumulator));
+        }
+    })(a);
+};
+
+export const minimal_bitlist = function _(a) {
+    return minimal_bitlist_plus(a, List_nil());
+};
+
+export const succ = function succ(a) {
+    return (function temp_1d(temp_1c) {
+        if ((temp_1c === 1n)) {
+            return Pos_extend(Pos_one, Bit_zero);
+        }
+        if ((temp_1c > 1n)) {
+            const a_left = (temp_1c >> 1n);
+            const a_right = (((temp_1c & 1n) === 0n)
+                ? ["zero"]
+                : ["one"]
+            );
+            return (function temp_1f(temp_1e) {
+                if ((temp_1e[0] === "zero")) {
+                    return Pos_extend(a_left, Bit_one);
+                }
+                if ((temp_1e[0] === "one")) {
+                    return Pos_extend(succ(a_left), Bit_zero);
+                }
+            })(a_right);
+        }
+    })(a);
+};
+
+export const pred = function pred(a) {
+    return (function temp_21(temp_20) {
+        if ((temp_20 === 1n)) {
+            return Nat_zero;
+        }
+        if ((temp_20 > 1n)) {
+            const a_left = (temp_20 >> 1n);
+            const a_right = (((temp_20 & 1n) === 0n)
+                ? ["zero"]
+                : ["one"]
+            );
+            return Nat_pos((function temp_23(temp_22) {
+                if ((temp_22[0] === "one")) {
+                    return Pos_extend(a_left, Bit_zero);
+                }
+                if ((temp_22[0] === "zero")) {
+                    return (function temp_25(temp_24) {
+                        if ((temp_24 === 0n)) {
+                            return Pos_one;
+                        }
+                        if ((temp_24 > 0n)) {
+                            const a_left_pred = temp_24;
+                            return Pos_extend(a_left_pred, Bit_one);
+                        }
+                    })(pred(a_left));
+                }
+            })(a_right));
+        }
+    })(a);
+};
+
+export const parity = function _(a) {
+    return (function temp_27(temp_26) {
+        if ((temp_26 === 1n)) {
+            return Bit_one;
+        }
+        if ((temp_26 > 1n)) {
+            const _2 = (temp_26 >> 1n);
+            const right = (((temp_26 & 1n) === 0n)
+                ? ["zero"]
+                : ["one"]
+            );
+            return right;
+        }
+    })(a);
+};
+
+export const add = function add(a, b) {
+    return (a + b);
+};
+
+export const mul = function mul(a, b) {
+    return (a * b);
+};
+
+export const square = function _(a) {
+    return mul(a, a);
+};
+
+export const pow = function pow(a, b) {
+    return (function temp_29(temp_28) {
+        if ((temp_28 === 1n)) {
+            return a;
+        }
+        if ((temp_28 > 1n)) {
+            const b_left = (temp_28 >> 1n);
+            const b_right = (((temp_28 & 1n) === 0n)
+                ? ["zero"]
+                : ["one"]
+            );
+            return (function temp_2b(temp_2a) {
+                if ((temp_2a[0] === "zero")) {
+                    return square(pow(a, b_left));
+                }
+                if ((temp_2a[0] === "one")) {
+                    return mul(a, square(pow(a, b_left)));
+                }
+            })(b_right);
+        }
+    })(b);
+};
+
+export const le = function le(a, b) {
+    return (function temp_2d(temp_2c) {
+        if ((temp_2c === 1n)) {
+            return Bool_true_;
+        }
+        if ((temp_2c > 1n)) {
+            const a_left = (temp_2c >> 1n);
+            const a_right = (((temp_2c & 1n) === 0n)
+                ? ["zero"]
+                : ["one"]
+            );
+            return (function temp_2f(temp_2e) {
+                if ((temp_2e === 1n)) {
+                    return Bool_false_;
+                }
+                if ((temp_2e > 1n)) {
+                    const b_left = (temp_2e >> 1n);
+                    const b_right = (((temp_2e & 1n) === 0n)
+                        ? ["zero"]
+                        : ["one"]
+                    );
+                    return (function temp_31(temp_30) {
+                        if ((temp_30[0] === "zero")) {
+                            return le(a_left, b_left);
+                        }
+                        if ((temp_30[0] === "one")) {
+                            return (function temp_33(temp_32) {
+                                if ((temp_32[0] === "one")) {
+                                    return le(a_left, b_left);
+                                }
+                                if ((temp_32[0] === "zero")) {
+                                    return and(le(a_left, b_left), neq3(a_left, b_left));
+                                }
+                            })(b_right);
+                        }
+                    })(a_right);
+                }
+            })(b);
+        }
+    })(a);
+};
+
+export const ge = function _(a, b) {
+    return le(b, a);
+};
+
+export { eq4 as eq, neq3 as neq };
+
+//# sourceMappingURL=pos.js.map
@@ -0,0 +1,26 @@
+import type { Nat } from "./unsized.js";
+
+export declare const pos_2: unknown;
+
+export declare const pos_3: unknown;
+
+export declare const pos_5: unknown;
+
+export declare const pos_10: unknown;
+
+export declare const pos_50: unknown;
+
+export declare const pos_100: unknown;
+
+export declare const pos_200: unknown;
+
+export declare const pos_255: unknown;
+
+export declare const nat_255: Nat;
+
+export type U8 = ["u8", Nat, unknown];
+
+export declare const U8: unknown;
+
+export declare const U8_u8: (n: Nat, upper: unknown) => U8;
+
@@ -0,0 +1,28 @@
+import { add, mul } from "./pos.js";
+import { Pos_one, Nat_pos } from "./unsized.js";
+
+export const pos_2 = add(Pos_one, Pos_one);
+
+export const pos_3 = add(pos_2, Pos_one);
+
+export const pos_5 = add(pos_2, pos_3);
+
+export const pos_10 = add(pos_5, pos_5);
+
+export const pos_50 = mul(pos_5, pos_10);
+
+export const pos_100 = mul(pos_10, pos_10);
+
+export const pos_200 = mul(pos_100, pos_2);
+
+export const pos_255 = add(pos_200, add(pos_50, pos_5));
+
+export const nat_255 = Nat_pos(pos_255);
+
+export const U8 = { "type_species": "U8", "type_args": [] };
+
+export const U8_u8 = function U8_u8(n, upper) {
+    return ["u8", n, upper];
+};
+
+//# sourceMappingURL=sized.js.map
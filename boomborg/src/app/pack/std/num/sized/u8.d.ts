@@ -0,0 +1,7 @@
+import type { U8 } from "../sized.js";
+import type { Bool } from "../../bool.js";
+
+declare const eq7: (a: U8, b: U8) => Bool;
+
+export { eq7 as eq };
+
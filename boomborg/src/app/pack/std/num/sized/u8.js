@@ -0,0 +1,21 @@
+import { eq as eq5 } from "../nat.js";
+
+const eq7 = function _(a, b) {
+    return (function temp_93(temp_92) {
+        if ((temp_92[0] === "u8")) {
+            const an = temp_92[1];
+            const _2 = temp_92[2];
+            return (function temp_95(temp_94) {
+                if ((temp_94[0] === "u8")) {
+                    const bn = temp_94[1];
+                    const _3 = temp_94[2];
+                    return eq5(an, bn);
+                }
+            })(b);
+        }
+    })(a);
+};
+
+export { eq7 as eq };
+
+//# sourceMappingURL=u8.js.map
@@ -0,0 +1,34 @@
+import type { Bit } from "../num.js";
+
+export type Pos = bigint;
+
+export declare const Pos: unknown;
+
+export declare const Pos_one: Pos;
+
+export declare const Pos_extend: (left: Pos, right: Bit) => Pos;
+
+export type Nat = bigint;
+
+export declare const Nat: unknown;
+
+export declare const Nat_zero: Nat;
+
+export declare const Nat_pos: (p: Pos) => Nat;
+
+export type Int = bigint;
+
+export declare const Int: unknown;
+
+export declare const Int_neg: (n: Pos) => Int;
+
+export declare const Int_nat: (n: Nat) => Int;
+
+export type Sign = ["pos"] | ["neg"];
+
+export declare const Sign: unknown;
+
+export declare const Sign_pos: Sign;
+
+export declare const Sign_neg: Sign;
+
@@ -0,0 +1,36 @@
+export const Pos = { "type_species": "Pos", "type_args": [] };
+
+export const Pos_one = 1n;
+
+export const Pos_extend = function Pos_extend(left, right) {
+    return ((2n * left) + ((right[0] === "one")
+        ? 1n
+        : 0n
+    ));
+};
+
+export const Nat = { "type_species": "Nat", "type_args": [] };
+
+export const Nat_zero = 0n;
+
+export const Nat_pos = function Nat_pos(p) {
+    return p;
+};
+
+export const Int = { "type_species": "Int", "type_args": [] };
+
+export const Int_neg = function Int_neg(n) {
+    return (0n - n);
+};
+
+export const Int_nat = function Int_nat(n) {
+    return n;
+};
+
+export const Sign = { "type_species": "Sign", "type_args": [] };
+
+export const Sign_pos = ["pos"];
+
+export const Sign_neg = ["neg"];
+
+//# sourceMappingURL=unsized.js.map
@@ -0,0 +1,8 @@
+export type Opt<T> = ["none", undefined] | ["some", undefined, T];
+
+export declare const Opt: unknown;
+
+export declare const Opt_none: <T>() => Opt<T>;
+
+export declare const Opt_some: <T>(t: T) => Opt<T>;
+
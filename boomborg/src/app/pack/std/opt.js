@@ -0,0 +1,13 @@
+export const Opt = function Opt() {
+    return { "type_species": "Opt", "type_args": [undefined] };
+};
+
+export const Opt_none = function Opt_none() {
+    return ["none", undefined];
+};
+
+export const Opt_some = function Opt_some(t) {
+    return ["some", undefined, t];
+};
+
+//# sourceMappingURL=opt.js.map
@@ -0,0 +1,14 @@
+import type { U8 } from "./num/sized.js";
+import type { List } from "./list.js";
+import type { Bool } from "./bool.js";
+
+export type String = ["utf8", List<U8>];
+
+export declare const String: unknown;
+
+export declare const String_utf8: (bytes: List<U8>) => String;
+
+declare const eq8: (s1: String, s2: String) => Bool;
+
+export { eq8 as eq };
+
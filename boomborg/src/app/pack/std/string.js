@@ -0,0 +1,26 @@
+import { eq as eq2 } from "./list.js";
+import { eq as eq7 } from "./num/sized/u8.js";
+
+export const String = { "type_species": "String", "type_args": [] };
+
+export const String_utf8 = function String_utf8(bytes) {
+    return ["utf8", bytes];
+};
+
+const eq8 = function _(s1, s2) {
+    return (function temp_97(temp_96) {
+        if ((temp_96[0] === "utf8")) {
+            const s1_bytes = temp_96[1];
+            return (function temp_99(temp_98) {
+                if ((temp_98[0] === "utf8")) {
+                    const s2_bytes = temp_98[1];
+                    return eq2(s1_bytes, s2_bytes, eq7);
+                }
+            })(s2);
+        }
+    })(s1);
+};
+
+export { eq8 as eq };
+
+//# sourceMappingURL=string.js.map
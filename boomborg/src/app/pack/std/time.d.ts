@@ -0,0 +1,8 @@
+import type { Nat } from "./num/unsized.js";
+
+export type Time = ["time", Nat];
+
+export declare const Time: unknown;
+
+export declare const Time_time: (millis_since_epoch: Nat) => Time;
+
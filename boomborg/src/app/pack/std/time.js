@@ -0,0 +1,7 @@
+export const Time = { "type_species": "Time", "type_args": [] };
+
+export const Time_time = function Time_time(millis_since_epoch) {
+    return ["time", millis_since_epoch];
+};
+
+//# sourceMappingURL=time.js.map
@@ -0,0 +1,12 @@
+export declare const Type2: unknown;
+
+export declare const Type1: unknown;
+
+export declare const Type: unknown;
+
+export declare const unreachable: (unreachable_span: string) => never;
+
+export declare const unimplemented: (unimplemented_span: string) => never;
+
+export declare const run_recursion: (generator: unknown, args: unknown) => unknown;
+
@@ -0,0 +1,33 @@
+export const Type2 = { "type_species": "Type2" };
+
+export const Type1 = { "type_species": "Type1" };
+
+export const Type = { "type_species": "Type" };
+
+export const unreachable = function unreachable(unreachable_span) {
+    throw new Error(("Reached supposedly unreachable path. This likely indicates that you passed one or more illegal arguments to one or more of the generated functions. Responsible span: " + unreachable_span));
+};
+
+export const unimplemented = function unimplemented(unimplemented_span) {
+    throw new Error(("This functionality is not implemented. Source span: " + unimplemented_span));
+};
+
+export const run_recursion = function run_recursion(generator, args) {
+    const stack = [(generator.apply)(undefined, args)];
+    let input = undefined;
+    while (true) {
+        const step = ((stack[(stack.length - 1)]).next)(input);
+        if (step.done) {
+            (stack.pop)();
+            if ((stack.length === 0)) {
+                return step.value;
+            }
+            input = step.value;
+            continue;
+        }
+        (stack.push)((generator.apply)(undefined, step.value));
+        input = undefined;
+    }
+};
+
+//# sourceMappingURL=runtime.js.map
@@ -1,9 +1,11 @@
 import "./index.css";
 import reportWebVitals from "./reportWebVitals";
 import { ImageDb, launchBoomborgApp } from "./launch";
-import { app } from "./app";
+import { pack } from "./app";
 import type * as ktypes from "./kantuTypes";
 
+const { app } = pack.pong;
+
 getImageDb().then((imageDb) => {
   console.log("Launching app...");
   launchBoomborgApp(app as ktypes.App<unknown>, imageDb);
@@ -15,39 +15,15 @@ export function toArr<T>(list: ktypes.List<T>): T[] {
 }
 
 export function intToNum(int: ktypes.Int): number {
-  if (int[0] === "neg") {
-    return -posToNum(int[1]);
-  }
-  if (int[0] === "nat") {
-    return natToNum(int[1]);
-  }
-  throw { badInt: int };
+  return Number(int);
 }
 
 export function natToNum(nat: ktypes.Nat): number {
-  if (nat[0] === "zero") {
-    return 0;
-  }
-  if (nat[0] === "pos") {
-    return posToNum(nat[1]);
-  }
-  throw { badNat: nat };
+  return Number(nat);
 }
 
 export function posToNum(pos: ktypes.Pos): number {
-  return parseInt(posToBitString(pos), 2);
-}
-
-export function posToBitString(pos: ktypes.Pos): string {
-  if (pos[0] === "one") {
-    return "1";
-  }
-  if (pos[0] === "extend") {
-    const left = posToBitString(pos[1]);
-    const right = String(bitToNum(pos[2]));
-    return left + right;
-  }
-  throw { badPos: pos };
+  return Number(pos);
 }
 
 export function bitToNum(bit: ktypes.Bit): number {
@@ -61,23 +37,17 @@ export function bitToNum(bit: ktypes.Bit): number {
 }
 
 export function numToNat(n: number): ktypes.Nat {
-  if (n < 0) {
-    throw { cannotConvertNegNumToNat: n };
-  }
-  if (n === 0) {
-    return ["zero"];
+  if (!Number.isInteger(n) || n < 0) {
+    throw { cannotConvertNumToNat: n };
   }
-  return ["pos", numToPos(n)];
+  return BigInt(n);
 }
 
 export function numToPos(n: number): ktypes.Pos {
-  if (!Number.isInteger(n) || n < 0) {
-    throw { cannotConvertNonNumToPos: n };
-  }
-  if (n === 1) {
-    return ["one"];
+  if (!Number.isInteger(n) || n < 1) {
+    throw { cannotConvertNumToPos: n };
   }
-  return ["extend", numToPos(Math.floor(n / 2)), numToBit(n % 2)];
+  return BigInt(n);
 }
 
 export function numToBit(n: number): ktypes.Bit {
@@ -1,30 +1,25 @@
-export type Int = ["neg", Pos] | ["nat", Nat];
-
-export type Nat = ["zero"] | ["pos", Pos];
-
-export type Pos = ["one"] | ["extend", Pos, Bit];
-
-export type Bit = ["zero"] | ["one"];
-
-export type U8 = ["u8", Nat, unknown];
-
-export type Bool = ["true"] | ["false"];
-
-export type Time = ["time", Nat];
-
-export type Window = ["window", Nat, Nat];
-
-export type Entity =
-  | ["unscaled", Nat, Nat, KString]
-  | ["scaled", Nat, Nat, Nat, Nat, KString];
-
-export type KString = ["utf8", List<U8>];
-
-export type List<T> = ["nil", unknown] | ["cons", unknown, T, List<T>];
-
+// The encodings of the Kantu types are generated by `kanc`
+// (see the `.d.ts` files next to the generated JS files),
+// so they stay in sync with the Kantu source.
+export type { Int, Nat, Pos } from "./app/pack/std/num/unsized";
+export type { Bit } from "./app/pack/std/num";
+export type { U8 } from "./app/pack/std/num/sized";
+export type { Bool } from "./app/pack/std/bool";
+export type { Time } from "./app/pack/std/time";
+export type { String as KString } from "./app/pack/std/string";
+export type { List } from "./app/pack/std/list";
+export type { Entity, Window, Event } from "./app/pack/boomborg";
+
+import type { List } from "./app/pack/std/list";
+import type { Time } from "./app/pack/std/time";
+import type { Entity, Window, Event } from "./app/pack/boomborg";
+
+// `App` hides its state type behind a type param,
+// which the generated declarations cannot express,
+// so we describe it by hand.
 export type App<S> = [
   "app",
-  unknown,
+  undefined,
   RenderFn<S>,
   UpdateFn<S>,
   InitFn<S>,
@@ -38,8 +33,3 @@ export type UpdateFn<S> = (state: S, time: Time) => S;
 export type InitFn<S> = (w: Window, time: Time) => S;
 
 export type HandleEventFn<S> = (state: S, event: Event) => S;
-
-export type Event =
-  | ["keyup", KString]
-  | ["keydown", KString]
-  | ["window_resize", Window];
@@ -21,6 +21,11 @@ pub enum BindError {
     TransparencyWasNotAtLeastAsRestrictiveAsVisibility(
         TransparencyWasNotAtLeastAsRestrictiveAsVisibilityError,
     ),
+    RewriteWithoutDesignatedEq(RewriteWithoutDesignatedEqError),
+    DeriveWithoutDesignatedBool(DeriveWithoutDesignatedBoolError),
+    CannotDeriveEqForIndexedVariant(CannotDeriveEqForIndexedVariantError),
+    CannotDeriveEqForField(CannotDeriveEqForFieldError),
+    AmbiguousInfixOperators(AmbiguousInfixOperatorsError),
 }
 
 #[derive(Clone, Debug, PartialEq, Eq, Hash)]
@@ -154,3 +159,65 @@ impl From<TransparencyWasNotAtLeastAsRestrictiveAsVisibilityError> for BindError
         Self::TransparencyWasNotAtLeastAsRestrictiveAsVisibility(error)
     }
 }
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct RewriteWithoutDesignatedEqError {
+    pub rewrite: unbound::Rewrite,
+}
+impl From<RewriteWithoutDesignatedEqError> for BindError {
+    fn from(error: RewriteWithoutDesignatedEqError) -> Self {
+        Self::RewriteWithoutDesignatedEq(error)
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct DeriveWithoutDesignatedBoolError {
+    pub derive: unbound::Derive,
+}
+impl From<DeriveWithoutDesignatedBoolError> for BindError {
+    fn from(error: DeriveWithoutDesignatedBoolError) -> Self {
+        Self::DeriveWithoutDesignatedBool(error)
+    }
+}
+
+/// A derived `eq` can only be generated for a variant
+/// whose return type applies the type to distinct variant params
+/// (e.g., `List(T)` but not `Vec(T, Nat.o)`).
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct CannotDeriveEqForIndexedVariantError {
+    pub derive: unbound::Derive,
+    pub variant_name: unbound::Identifier,
+    pub return_type: unbound::Expression,
+}
+impl From<CannotDeriveEqForIndexedVariantError> for BindError {
+    fn from(error: CannotDeriveEqForIndexedVariantError) -> Self {
+        Self::CannotDeriveEqForIndexedVariant(error)
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct CannotDeriveEqForFieldError {
+    pub derive: unbound::Derive,
+    pub field_name: unbound::Identifier,
+    pub field_type: unbound::Expression,
+}
+impl From<CannotDeriveEqForFieldError> for BindError {
+    fn from(error: CannotDeriveEqForFieldError) -> Self {
+        Self::CannotDeriveEqForField(error)
+    }
+}
+
+/// Two adjacent operators have the same precedence,
+/// but they cannot be chained without parentheses
+/// (i.e., at least one is non-associative, or they associate
+/// in different directions).
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct AmbiguousInfixOperatorsError {
+    pub left_operator: unbound::Identifier,
+    pub right_operator: unbound::Identifier,
+}
+impl From<AmbiguousInfixOperatorsError> for BindError {
+    fn from(error: AmbiguousInfixOperatorsError) -> Self {
+        Self::AmbiguousInfixOperators(error)
+    }
+}
@@ -29,6 +29,15 @@ pub struct TypeStatement {
     pub name: Identifier,
     pub params: Option<NonEmptyParamVec>,
     pub variants: Vec<Variant>,
+    /// `true` if this type was declared by a `struct` statement.
+    /// Values of such a type support field projection
+    /// (e.g., `x.field`) and are equal up to record eta-conversion.
+    pub is_struct: bool,
+    /// `true` if this type was declared by a `trait` statement.
+    /// Every trait is also a struct.
+    /// Values of such a type may be declared as instances,
+    /// which are supplied to instance params implicitly.
+    pub is_trait: bool,
 }
 
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
@@ -61,6 +70,7 @@ impl NonEmptyParamVec {
 #[derive(Clone, Debug, PartialEq, Eq, Hash)]
 pub struct UnlabeledParam {
     pub span: Option<TextSpan>,
+    pub is_instance: bool,
     pub is_dashed: bool,
     pub name: Identifier,
     pub type_: Expression,
@@ -69,10 +79,12 @@ pub struct UnlabeledParam {
 #[derive(Clone, Debug, PartialEq, Eq, Hash)]
 pub struct LabeledParam {
     pub span: Option<TextSpan>,
+    pub is_instance: bool,
     pub label: ParamLabel,
     pub is_dashed: bool,
     pub name: Identifier,
     pub type_: Expression,
+    pub default: Option<Expression>,
 }
 
 #[derive(Clone, Debug, PartialEq, Eq, Hash)]
@@ -105,6 +117,7 @@ pub struct LetStatement {
     pub transparency: Transparency,
     pub name: Identifier,
     pub value: Expression,
+    pub is_instance: bool,
 }
 
 #[derive(Clone, Debug, PartialEq, Eq, Hash)]
@@ -116,6 +129,10 @@ pub enum Expression {
     Match(Box<Match>),
     Forall(Box<Forall>),
     Check(Box<Check>),
+    Rewrite(Box<Rewrite>),
+    Projection(Box<Projection>),
+    Exists(Box<Exists>),
+    Pair(Box<Pair>),
 }
 
 impl Expression {
@@ -128,6 +145,10 @@ impl Expression {
             Expression::Match(match_) => match_.span,
             Expression::Forall(forall) => forall.span,
             Expression::Check(check) => check.span,
+            Expression::Rewrite(rewrite) => rewrite.span,
+            Expression::Projection(projection) => projection.span,
+            Expression::Exists(exists) => exists.span,
+            Expression::Pair(pair) => pair.span,
         }
     }
 }
@@ -169,6 +190,8 @@ pub use crate::data::simplified_ast::UnreservedIdentifierName;
 
 pub use crate::data::simplified_ast::ReservedIdentifierName;
 
+pub use crate::data::simplified_ast::PAIR_VARIANT_NAME;
+
 #[derive(Clone, Debug, PartialEq, Eq, Hash)]
 pub struct Call {
     pub span: Option<TextSpan>,
@@ -216,9 +239,19 @@ pub struct Fun {
 pub struct Match {
     pub span: Option<TextSpan>,
     pub matchee: Expression,
+    pub motive: Option<MatchMotive>,
     pub cases: Vec<MatchCase>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct MatchMotive {
+    pub span: Option<TextSpan>,
+    pub matchee_name: Identifier,
+    pub matchee_type_name: NameExpression,
+    pub index_names: Option<NonEmptyVec<Identifier>>,
+    pub output: Expression,
+}
+
 #[derive(Clone, Debug, PartialEq, Eq, Hash)]
 pub struct MatchCase {
     pub span: Option<TextSpan>,
@@ -281,6 +314,40 @@ pub struct Check {
     pub output: Expression,
 }
 
+/// `eq_type_name` and `eq_refl_name` refer to the
+/// equality type (and its reflexivity variant)
+/// that the pack designated in its `pack.yscl`.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Rewrite {
+    pub span: Option<TextSpan>,
+    pub eq_type_name: NameExpression,
+    pub eq_refl_name: NameExpression,
+    pub proof: Expression,
+    pub body: Expression,
+}
+
+/// A field projection (e.g., `x.field`).
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Projection {
+    pub span: Option<TextSpan>,
+    pub record: Expression,
+    pub field_name: Identifier,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Exists {
+    pub span: Option<TextSpan>,
+    pub param: UnlabeledParam,
+    pub output: Expression,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Pair {
+    pub span: Option<TextSpan>,
+    pub witness: Expression,
+    pub proof: Expression,
+}
+
 #[derive(Clone, Debug, PartialEq, Eq, Hash)]
 pub struct CheckAssertion {
     pub span: Option<TextSpan>,
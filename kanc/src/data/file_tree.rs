@@ -1,12 +1,15 @@
 use crate::data::{file_id::*, unsimplified_ast::IdentifierName};
 
-use rustc_hash::FxHashMap;
+use rustc_hash::{FxHashMap, FxHashSet};
 
 #[derive(Clone, Debug)]
 pub struct FileTree {
     root: FileId,
     children: FxHashMap<FileId, FxHashMap<IdentifierName, FileId>>,
     parents: FxHashMap<FileId, (FileId, IdentifierName)>,
+    /// The modules declared with global visibility
+    /// (i.e., `pub mod` or `pub(*) mod`).
+    globally_visible_mods: FxHashSet<FileId>,
 }
 
 #[derive(Clone, Debug, PartialEq, Eq, Hash)]
@@ -24,6 +27,7 @@ impl FileTree {
             root,
             children: FxHashMap::default(),
             parents: FxHashMap::default(),
+            globally_visible_mods: FxHashSet::default(),
         }
     }
 }
@@ -86,6 +90,24 @@ impl FileTree {
         Ok(())
     }
 
+    pub fn mark_as_globally_visible(&mut self, file_id: FileId) {
+        self.globally_visible_mods.insert(file_id);
+    }
+
+    /// Returns `true` if code outside the pack can name the module
+    /// (i.e., if the module is the root, or if it and all of its
+    /// ancestors except the root are globally visible).
+    pub fn is_publicly_nameable(&self, file_id: FileId) -> bool {
+        let mut current = file_id;
+        while let Some(parent) = self.parent(current) {
+            if !self.globally_visible_mods.contains(&current) {
+                return false;
+            }
+            current = parent;
+        }
+        true
+    }
+
     pub fn is_left_strict_descendant_of_right(&self, left: FileId, right: FileId) -> bool {
         self.is_left_non_strict_descendant_of_right(left, right) && left != right
     }
@@ -16,6 +16,8 @@ pub struct TypeStatement {
     pub name_id: NodeId<Identifier>,
     pub param_list_id: Option<NonEmptyParamListId>,
     pub variant_list_id: Option<NonEmptyListId<NodeId<Variant>>>,
+    pub is_struct: bool,
+    pub is_trait: bool,
 }
 
 pub use crate::data::node_registry::NonEmptyParamListId;
@@ -30,6 +32,7 @@ pub use crate::data::bound_ast::Transparency;
 pub struct UnlabeledParam {
     pub id: NodeId<Self>,
     pub span: Option<TextSpan>,
+    pub is_instance: bool,
     pub is_dashed: bool,
     pub name_id: NodeId<Identifier>,
     pub type_id: ExpressionId,
@@ -39,10 +42,12 @@ pub struct UnlabeledParam {
 pub struct LabeledParam {
     pub id: NodeId<Self>,
     pub span: Option<TextSpan>,
+    pub is_instance: bool,
     pub label_id: ParamLabelId,
     pub is_dashed: bool,
     pub name_id: NodeId<Identifier>,
     pub type_id: ExpressionId,
+    pub default_id: Option<ExpressionId>,
 }
 
 pub use crate::data::node_registry::ParamLabelId;
@@ -73,6 +78,7 @@ pub struct LetStatement {
     pub transparency: Transparency,
     pub name_id: NodeId<Identifier>,
     pub value_id: ExpressionId,
+    pub is_instance: bool,
 }
 
 pub use crate::data::node_registry::ExpressionId;
@@ -101,6 +107,8 @@ pub use crate::data::simplified_ast::UnreservedIdentifierName;
 
 pub use crate::data::simplified_ast::ReservedIdentifierName;
 
+pub use crate::data::simplified_ast::PAIR_VARIANT_NAME;
+
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub struct TodoExpression {
     pub id: NodeId<Self>,
@@ -132,9 +140,20 @@ pub struct Match {
     pub id: NodeId<Self>,
     pub span: Option<TextSpan>,
     pub matchee_id: ExpressionId,
+    pub motive_id: Option<NodeId<MatchMotive>>,
     pub case_list_id: Option<NonEmptyListId<NodeId<MatchCase>>>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct MatchMotive {
+    pub id: NodeId<Self>,
+    pub span: Option<TextSpan>,
+    pub matchee_name_id: NodeId<Identifier>,
+    pub matchee_type_name_id: NodeId<NameExpression>,
+    pub index_name_list_id: Option<NonEmptyListId<NodeId<Identifier>>>,
+    pub output_id: ExpressionId,
+}
+
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub struct MatchCase {
     pub id: NodeId<Self>,
@@ -181,6 +200,40 @@ pub struct Check {
     pub output_id: ExpressionId,
 }
 
+#[derive(Clone, Debug)]
+pub struct Rewrite {
+    pub id: NodeId<Self>,
+    pub span: Option<TextSpan>,
+    pub eq_type_name_id: NodeId<NameExpression>,
+    pub eq_refl_name_id: NodeId<NameExpression>,
+    pub proof_id: ExpressionId,
+    pub body_id: ExpressionId,
+}
+
+#[derive(Clone, Debug)]
+pub struct Projection {
+    pub id: NodeId<Self>,
+    pub span: Option<TextSpan>,
+    pub record_id: ExpressionId,
+    pub field_name_id: NodeId<Identifier>,
+}
+
+#[derive(Clone, Debug)]
+pub struct Exists {
+    pub id: NodeId<Self>,
+    pub span: Option<TextSpan>,
+    pub param_id: NodeId<UnlabeledParam>,
+    pub output_id: ExpressionId,
+}
+
+#[derive(Clone, Debug)]
+pub struct Pair {
+    pub id: NodeId<Self>,
+    pub span: Option<TextSpan>,
+    pub witness_id: ExpressionId,
+    pub proof_id: ExpressionId,
+}
+
 #[derive(Clone, Debug)]
 pub struct CheckAssertion {
     pub id: NodeId<Self>,
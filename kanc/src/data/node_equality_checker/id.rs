@@ -65,6 +65,10 @@ pub enum ExpressionSemanticId {
     Fun(SemanticId<stripped::Fun>),
     Match(SemanticId<stripped::Match>),
     Forall(SemanticId<stripped::Forall>),
+    Projection(SemanticId<stripped::Projection>),
+    Exists(SemanticId<stripped::Exists>),
+    Pair(SemanticId<stripped::Pair>),
+    Rewrite(SemanticId<stripped::Rewrite>),
 }
 
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
@@ -60,6 +60,18 @@ impl IntoSemanticId for ExpressionId {
                 let check = registry.get(id);
                 check.output_id.into_semantic_id(registry, sreg)
             }
+            ExpressionId::Rewrite(id) => {
+                ExpressionSemanticId::Rewrite(id.into_semantic_id(registry, sreg))
+            }
+            ExpressionId::Projection(id) => {
+                ExpressionSemanticId::Projection(id.into_semantic_id(registry, sreg))
+            }
+            ExpressionId::Exists(id) => {
+                ExpressionSemanticId::Exists(id.into_semantic_id(registry, sreg))
+            }
+            ExpressionId::Pair(id) => {
+                ExpressionSemanticId::Pair(id.into_semantic_id(registry, sreg))
+            }
         }
     }
 }
@@ -362,9 +374,17 @@ impl GetIndexInSubregistry for NodeId<LabeledParam> {
 
     fn strip(self, registry: &NodeRegistry, sreg: &mut StrippedRegistry) -> Self::Stripped {
         let param = registry.get(self);
+        let default_id = if sreg.are_defaults_significant {
+            param
+                .default_id
+                .map(|default_id| default_id.into_semantic_id(registry, sreg))
+        } else {
+            None
+        };
         stripped::LabeledParam {
             label_name_id: param.label_identifier_id().into_semantic_id(registry, sreg),
             type_id: param.type_id.into_semantic_id(registry, sreg),
+            default_id,
         }
     }
 }
@@ -553,3 +573,115 @@ impl IntoSemanticId for NodeId<Forall> {
         SemanticId::new(raw)
     }
 }
+
+impl GetIndexInSubregistry for NodeId<Projection> {
+    type Stripped = stripped::Projection;
+
+    fn subregistry_mut(sreg: &mut StrippedRegistry) -> &mut Subregistry<Self> {
+        &mut sreg.projections
+    }
+
+    fn strip(self, registry: &NodeRegistry, sreg: &mut StrippedRegistry) -> Self::Stripped {
+        let projection = registry.get(self);
+        stripped::Projection {
+            record_id: projection.record_id.into_semantic_id(registry, sreg),
+            field_name_id: projection.field_name_id.into_semantic_id(registry, sreg),
+        }
+    }
+}
+impl IntoSemanticId for NodeId<Projection> {
+    type Output = SemanticId<stripped::Projection>;
+
+    fn into_semantic_id(
+        self,
+        registry: &NodeRegistry,
+        sreg: &mut StrippedRegistry,
+    ) -> Self::Output {
+        let raw = self.get_index_in_subregistry(registry, sreg);
+        SemanticId::new(raw)
+    }
+}
+
+impl GetIndexInSubregistry for NodeId<Exists> {
+    type Stripped = stripped::Exists;
+
+    fn subregistry_mut(sreg: &mut StrippedRegistry) -> &mut Subregistry<Self> {
+        &mut sreg.exists
+    }
+
+    fn strip(self, registry: &NodeRegistry, sreg: &mut StrippedRegistry) -> Self::Stripped {
+        let exists = registry.get(self);
+        stripped::Exists {
+            param_id: exists.param_id.into_semantic_id(registry, sreg),
+            output_id: exists.output_id.into_semantic_id(registry, sreg),
+        }
+    }
+}
+impl IntoSemanticId for NodeId<Exists> {
+    type Output = SemanticId<stripped::Exists>;
+
+    fn into_semantic_id(
+        self,
+        registry: &NodeRegistry,
+        sreg: &mut StrippedRegistry,
+    ) -> Self::Output {
+        let raw = self.get_index_in_subregistry(registry, sreg);
+        SemanticId::new(raw)
+    }
+}
+
+impl GetIndexInSubregistry for NodeId<Pair> {
+    type Stripped = stripped::Pair;
+
+    fn subregistry_mut(sreg: &mut StrippedRegistry) -> &mut Subregistry<Self> {
+        &mut sreg.pairs
+    }
+
+    fn strip(self, registry: &NodeRegistry, sreg: &mut StrippedRegistry) -> Self::Stripped {
+        let pair = registry.get(self);
+        stripped::Pair {
+            witness_id: pair.witness_id.into_semantic_id(registry, sreg),
+            proof_id: pair.proof_id.into_semantic_id(registry, sreg),
+        }
+    }
+}
+impl IntoSemanticId for NodeId<Pair> {
+    type Output = SemanticId<stripped::Pair>;
+
+    fn into_semantic_id(
+        self,
+        registry: &NodeRegistry,
+        sreg: &mut StrippedRegistry,
+    ) -> Self::Output {
+        let raw = self.get_index_in_subregistry(registry, sreg);
+        SemanticId::new(raw)
+    }
+}
+
+impl GetIndexInSubregistry for NodeId<Rewrite> {
+    type Stripped = stripped::Rewrite;
+
+    fn subregistry_mut(sreg: &mut StrippedRegistry) -> &mut Subregistry<Self> {
+        &mut sreg.rewrites
+    }
+
+    fn strip(self, registry: &NodeRegistry, sreg: &mut StrippedRegistry) -> Self::Stripped {
+        let rewrite = registry.get(self);
+        stripped::Rewrite {
+            proof_id: rewrite.proof_id.into_semantic_id(registry, sreg),
+            body_id: rewrite.body_id.into_semantic_id(registry, sreg),
+        }
+    }
+}
+impl IntoSemanticId for NodeId<Rewrite> {
+    type Output = SemanticId<stripped::Rewrite>;
+
+    fn into_semantic_id(
+        self,
+        registry: &NodeRegistry,
+        sreg: &mut StrippedRegistry,
+    ) -> Self::Output {
+        let raw = self.get_index_in_subregistry(registry, sreg);
+        SemanticId::new(raw)
+    }
+}
@@ -16,11 +16,18 @@ mod stripped_ast;
 use stripped_ast as stripped;
 
 #[derive(Clone, Debug)]
-pub struct NodeEqualityChecker(StrippedRegistry);
+pub struct NodeEqualityChecker {
+    sreg: StrippedRegistry,
+    /// Like `sreg`, except that labeled param defaults are significant.
+    default_sensitive_sreg: StrippedRegistry,
+}
 
 impl NodeEqualityChecker {
     pub fn new() -> Self {
-        Self(StrippedRegistry::empty())
+        Self {
+            sreg: StrippedRegistry::empty(false),
+            default_sensitive_sreg: StrippedRegistry::empty(true),
+        }
     }
 }
 
@@ -29,7 +36,29 @@ impl NodeEqualityChecker {
     where
         T: IntoSemanticId,
     {
-        a.into_semantic_id(registry, &mut self.0) == b.into_semantic_id(registry, &mut self.0)
+        a.into_semantic_id(registry, &mut self.sreg) == b.into_semantic_id(registry, &mut self.sreg)
+    }
+
+    /// Two nodes have the same semantic ID if and only if
+    /// `eq` would consider them equal.
+    pub fn semantic_id<T>(&mut self, a: T, registry: &NodeRegistry) -> T::Output
+    where
+        T: IntoSemanticId,
+    {
+        a.into_semantic_id(registry, &mut self.sreg)
+    }
+
+    /// Like `semantic_id`, except that nodes whose labeled params
+    /// have different defaults get different IDs.
+    /// `eq` ignores defaults (since they are not part of the type),
+    /// but a call fills in omitted args from its callee's type,
+    /// so anything that substitutes one node for another
+    /// (e.g., a normal form cache) must not ignore them.
+    pub fn default_sensitive_semantic_id<T>(&mut self, a: T, registry: &NodeRegistry) -> T::Output
+    where
+        T: IntoSemanticId,
+    {
+        a.into_semantic_id(registry, &mut self.default_sensitive_sreg)
     }
 }
 
@@ -77,6 +106,8 @@ pub trait GetIndexInSubregistry: Copy + Eq + Hash {
 
 #[derive(Clone, Debug)]
 pub struct StrippedRegistry {
+    are_defaults_significant: bool,
+
     expression_lists: Subregistry<NonEmptyListId<ExpressionId>>,
     unlabeled_param_lists: Subregistry<NonEmptyListId<NodeId<UnlabeledParam>>>,
     labeled_param_lists: Subregistry<NonEmptyListId<NodeId<LabeledParam>>>,
@@ -94,11 +125,17 @@ pub struct StrippedRegistry {
     funs: Subregistry<NodeId<Fun>>,
     matches: Subregistry<NodeId<Match>>,
     foralls: Subregistry<NodeId<Forall>>,
+    projections: Subregistry<NodeId<Projection>>,
+    exists: Subregistry<NodeId<Exists>>,
+    pairs: Subregistry<NodeId<Pair>>,
+    rewrites: Subregistry<NodeId<Rewrite>>,
 }
 
 impl StrippedRegistry {
-    fn empty() -> Self {
+    fn empty(are_defaults_significant: bool) -> Self {
         Self {
+            are_defaults_significant,
+
             expression_lists: Subregistry::empty(),
             unlabeled_param_lists: Subregistry::empty(),
             labeled_param_lists: Subregistry::empty(),
@@ -116,6 +153,10 @@ impl StrippedRegistry {
             funs: Subregistry::empty(),
             matches: Subregistry::empty(),
             foralls: Subregistry::empty(),
+            projections: Subregistry::empty(),
+            exists: Subregistry::empty(),
+            pairs: Subregistry::empty(),
+            rewrites: Subregistry::empty(),
         }
     }
 }
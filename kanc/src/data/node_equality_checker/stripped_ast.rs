@@ -31,8 +31,13 @@ pub struct UnlabeledParam {
 pub struct LabeledParam {
     pub label_name_id: SemanticId<IdentifierName>,
     pub type_id: ExpressionSemanticId,
+    /// This is always `None` unless the registry
+    /// considers defaults to be significant.
+    pub default_id: Option<ExpressionSemanticId>,
 }
 
+/// The motive (if any) is omitted, since it only affects
+/// how the match is type checked, not how it evaluates.
 #[derive(Clone, Debug, PartialEq, Eq, Hash)]
 pub struct Match {
     pub matchee_id: ExpressionSemanticId,
@@ -64,3 +69,31 @@ pub struct Forall {
     pub param_list_id: NonEmptyParamListSemanticId,
     pub output_id: ExpressionSemanticId,
 }
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Projection {
+    pub record_id: ExpressionSemanticId,
+    pub field_name_id: SemanticId<IdentifierName>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Exists {
+    pub param_id: SemanticId<UnlabeledParam>,
+    pub output_id: ExpressionSemanticId,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Pair {
+    pub witness_id: ExpressionSemanticId,
+    pub proof_id: ExpressionSemanticId,
+}
+
+/// The designated `Eq` type and `refl` variant are omitted,
+/// since they are determined by the type of the proof.
+/// A rewrite only survives evaluation if its proof is stuck,
+/// so unlike a check, it is not equal to its body.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Rewrite {
+    pub proof_id: ExpressionSemanticId,
+    pub body_id: ExpressionSemanticId,
+}
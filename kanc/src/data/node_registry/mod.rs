@@ -36,10 +36,15 @@ pub struct NodeRegistry {
     calls: Subregistry<Call>,
     funs: Subregistry<Fun>,
     matches: Subregistry<Match>,
+    match_motives: Subregistry<MatchMotive>,
     match_cases: Subregistry<MatchCase>,
     labeled_match_case_params: Subregistry<LabeledMatchCaseParam>,
     foralls: Subregistry<Forall>,
     checks: Subregistry<Check>,
+    rewrites: Subregistry<Rewrite>,
+    projections: Subregistry<Projection>,
+    exists: Subregistry<Exists>,
+    pairs: Subregistry<Pair>,
     check_assertions: Subregistry<CheckAssertion>,
     symbolically_invalid_expressions: Subregistry<SymbolicallyInvalidExpression>,
     illegal_fun_recursion_expressions: Subregistry<IllegalFunRecursionExpression>,
@@ -70,10 +75,15 @@ impl NodeRegistry {
             calls: Subregistry::new(),
             funs: Subregistry::new(),
             matches: Subregistry::new(),
+            match_motives: Subregistry::new(),
             match_cases: Subregistry::new(),
             labeled_match_case_params: Subregistry::new(),
             foralls: Subregistry::new(),
             checks: Subregistry::new(),
+            rewrites: Subregistry::new(),
+            projections: Subregistry::new(),
+            exists: Subregistry::new(),
+            pairs: Subregistry::new(),
             check_assertions: Subregistry::new(),
             symbolically_invalid_expressions: Subregistry::new(),
             illegal_fun_recursion_expressions: Subregistry::new(),
@@ -109,6 +119,36 @@ impl NodeRegistry {
     {
         T::subregistry(self).get(id)
     }
+
+    /// Replaces the node with id `id` with `item`.
+    /// Afterwards, `self.get(id)` returns `item` (with its id set to `id`).
+    ///
+    /// This is only sound if nothing has observed the replaced node's
+    /// meaning yet (e.g., a call whose omitted instance args are
+    /// filled in before the call is evaluated).
+    pub fn overwrite<T>(&mut self, id: NodeId<T>, item: T)
+    where
+        T: RegisterableNode + SetId,
+        T::Output: Clone + Debug,
+    {
+        T::subregistry_mut(self).overwrite(id, item)
+    }
+
+    /// Returns the level of the largest universe named by
+    /// any registered identifier, or `0` if no universe is named.
+    ///
+    /// Since there are arbitrarily many universes, passes use this
+    /// to decide how many universes to put in their contexts.
+    pub fn max_universe_level(&self) -> usize {
+        self.identifiers
+            .iter()
+            .filter_map(|identifier| match &identifier.name {
+                IdentifierName::Reserved(name) => name.universe_level(),
+                IdentifierName::Standard(_) => None,
+            })
+            .max()
+            .unwrap_or(0)
+    }
 }
 
 impl NodeRegistry {
@@ -173,6 +213,10 @@ impl NodeRegistry {
             ExpressionId::Match(id) => ExpressionRef::Match(self.get(id)),
             ExpressionId::Forall(id) => ExpressionRef::Forall(self.get(id)),
             ExpressionId::Check(id) => ExpressionRef::Check(self.get(id)),
+            ExpressionId::Rewrite(id) => ExpressionRef::Rewrite(self.get(id)),
+            ExpressionId::Projection(id) => ExpressionRef::Projection(self.get(id)),
+            ExpressionId::Exists(id) => ExpressionRef::Exists(self.get(id)),
+            ExpressionId::Pair(id) => ExpressionRef::Pair(self.get(id)),
         }
     }
 }
@@ -186,6 +230,10 @@ pub enum ExpressionRef<'a> {
     Match(&'a Match),
     Forall(&'a Forall),
     Check(&'a Check),
+    Rewrite(&'a Rewrite),
+    Projection(&'a Projection),
+    Exists(&'a Exists),
+    Pair(&'a Pair),
 }
 
 impl ExpressionRef<'_> {
@@ -198,6 +246,10 @@ impl ExpressionRef<'_> {
             ExpressionRef::Match(expr) => ExpressionId::Match(expr.id),
             ExpressionRef::Forall(expr) => ExpressionId::Forall(expr.id),
             ExpressionRef::Check(expr) => ExpressionId::Check(expr.id),
+            ExpressionRef::Rewrite(expr) => ExpressionId::Rewrite(expr.id),
+            ExpressionRef::Projection(expr) => ExpressionId::Projection(expr.id),
+            ExpressionRef::Exists(expr) => ExpressionId::Exists(expr.id),
+            ExpressionRef::Pair(expr) => ExpressionId::Pair(expr.id),
         }
     }
 
@@ -210,6 +262,10 @@ impl ExpressionRef<'_> {
             ExpressionRef::Match(expr) => expr.span,
             ExpressionRef::Forall(expr) => expr.span,
             ExpressionRef::Check(expr) => expr.span,
+            ExpressionRef::Rewrite(expr) => expr.span,
+            ExpressionRef::Projection(expr) => expr.span,
+            ExpressionRef::Exists(expr) => expr.span,
+            ExpressionRef::Pair(expr) => expr.span,
         }
     }
 }
@@ -249,6 +305,10 @@ mod subregistry {
         pub fn get(&self, id: NodeId<T>) -> &T {
             &self.items[id.raw]
         }
+
+        pub fn iter(&self) -> impl Iterator<Item = &T> {
+            self.items.iter()
+        }
     }
 
     impl<T> Subregistry<T>
@@ -267,6 +327,16 @@ mod subregistry {
                 new_id
             }
         }
+
+        pub fn overwrite(&mut self, id: NodeId<T>, mut item: T) {
+            item.set_id(id);
+            let original = self.items[id.raw].remove_id();
+            if self.ids.get(&original) == Some(&id) {
+                self.ids.remove(&original);
+            }
+            self.ids.entry(item.remove_id()).or_insert(id);
+            self.items[id.raw] = item;
+        }
     }
 }
 
@@ -426,6 +496,16 @@ mod registerable_node {
         }
     }
 
+    impl RegisterableNode for MatchMotive {
+        fn subregistry(registry: &NodeRegistry) -> &Subregistry<Self> {
+            &registry.match_motives
+        }
+
+        fn subregistry_mut(registry: &mut NodeRegistry) -> &mut Subregistry<Self> {
+            &mut registry.match_motives
+        }
+    }
+
     impl RegisterableNode for MatchCase {
         fn subregistry(registry: &NodeRegistry) -> &Subregistry<Self> {
             &registry.match_cases
@@ -466,6 +546,46 @@ mod registerable_node {
         }
     }
 
+    impl RegisterableNode for Rewrite {
+        fn subregistry(registry: &NodeRegistry) -> &Subregistry<Self> {
+            &registry.rewrites
+        }
+
+        fn subregistry_mut(registry: &mut NodeRegistry) -> &mut Subregistry<Self> {
+            &mut registry.rewrites
+        }
+    }
+
+    impl RegisterableNode for Projection {
+        fn subregistry(registry: &NodeRegistry) -> &Subregistry<Self> {
+            &registry.projections
+        }
+
+        fn subregistry_mut(registry: &mut NodeRegistry) -> &mut Subregistry<Self> {
+            &mut registry.projections
+        }
+    }
+
+    impl RegisterableNode for Exists {
+        fn subregistry(registry: &NodeRegistry) -> &Subregistry<Self> {
+            &registry.exists
+        }
+
+        fn subregistry_mut(registry: &mut NodeRegistry) -> &mut Subregistry<Self> {
+            &mut registry.exists
+        }
+    }
+
+    impl RegisterableNode for Pair {
+        fn subregistry(registry: &NodeRegistry) -> &Subregistry<Self> {
+            &registry.pairs
+        }
+
+        fn subregistry_mut(registry: &mut NodeRegistry) -> &mut Subregistry<Self> {
+            &mut registry.pairs
+        }
+    }
+
     impl RegisterableNode for CheckAssertion {
         fn subregistry(registry: &NodeRegistry) -> &Subregistry<Self> {
             &registry.check_assertions
@@ -691,6 +811,12 @@ mod set_id {
         }
     }
 
+    impl SetId for MatchMotive {
+        fn set_id(&mut self, id: NodeId<Self>) {
+            self.id = id;
+        }
+    }
+
     impl SetId for MatchCase {
         fn set_id(&mut self, id: NodeId<Self>) {
             self.id = id;
@@ -715,6 +841,30 @@ mod set_id {
         }
     }
 
+    impl SetId for Rewrite {
+        fn set_id(&mut self, id: NodeId<Self>) {
+            self.id = id;
+        }
+    }
+
+    impl SetId for Projection {
+        fn set_id(&mut self, id: NodeId<Self>) {
+            self.id = id;
+        }
+    }
+
+    impl SetId for Exists {
+        fn set_id(&mut self, id: NodeId<Self>) {
+            self.id = id;
+        }
+    }
+
+    impl SetId for Pair {
+        fn set_id(&mut self, id: NodeId<Self>) {
+            self.id = id;
+        }
+    }
+
     impl SetId for CheckAssertion {
         fn set_id(&mut self, id: NodeId<Self>) {
             self.id = id;
@@ -41,6 +41,8 @@ pub struct TypeStatement {
     pub name_id: NodeId<with_id::Identifier>,
     pub param_list_id: Option<NonEmptyParamListId>,
     pub variant_list_id: Option<NonEmptyListId<NodeId<with_id::Variant>>>,
+    pub is_struct: bool,
+    pub is_trait: bool,
 }
 impl RemoveId for with_id::TypeStatement {
     type Output = TypeStatement;
@@ -51,6 +53,8 @@ impl RemoveId for with_id::TypeStatement {
             name_id: self.name_id,
             param_list_id: self.param_list_id,
             variant_list_id: self.variant_list_id,
+            is_struct: self.is_struct,
+            is_trait: self.is_trait,
         }
     }
 }
@@ -64,6 +68,8 @@ impl AddId for TypeStatement {
             name_id: self.name_id,
             param_list_id: self.param_list_id,
             variant_list_id: self.variant_list_id,
+            is_struct: self.is_struct,
+            is_trait: self.is_trait,
         }
     }
 }
@@ -71,6 +77,7 @@ impl AddId for TypeStatement {
 #[derive(Clone, Debug, PartialEq, Eq, Hash)]
 pub struct UnlabeledParam {
     pub span: Option<TextSpan>,
+    pub is_instance: bool,
     pub is_dashed: bool,
     pub name_id: NodeId<with_id::Identifier>,
     pub type_id: ExpressionId,
@@ -80,6 +87,7 @@ impl RemoveId for with_id::UnlabeledParam {
     fn remove_id(&self) -> Self::Output {
         UnlabeledParam {
             span: self.span,
+            is_instance: self.is_instance,
             is_dashed: self.is_dashed,
             name_id: self.name_id,
             type_id: self.type_id,
@@ -92,6 +100,7 @@ impl AddId for UnlabeledParam {
         with_id::UnlabeledParam {
             id,
             span: self.span,
+            is_instance: self.is_instance,
             is_dashed: self.is_dashed,
             name_id: self.name_id,
             type_id: self.type_id,
@@ -102,20 +111,24 @@ impl AddId for UnlabeledParam {
 #[derive(Clone, Debug, PartialEq, Eq, Hash)]
 pub struct LabeledParam {
     pub span: Option<TextSpan>,
+    pub is_instance: bool,
     pub label_id: ParamLabelId,
     pub is_dashed: bool,
     pub name_id: NodeId<with_id::Identifier>,
     pub type_id: ExpressionId,
+    pub default_id: Option<ExpressionId>,
 }
 impl RemoveId for with_id::LabeledParam {
     type Output = LabeledParam;
     fn remove_id(&self) -> Self::Output {
         LabeledParam {
             span: self.span,
+            is_instance: self.is_instance,
             label_id: self.label_id,
             is_dashed: self.is_dashed,
             name_id: self.name_id,
             type_id: self.type_id,
+            default_id: self.default_id,
         }
     }
 }
@@ -125,10 +138,12 @@ impl AddId for LabeledParam {
         with_id::LabeledParam {
             id,
             span: self.span,
+            is_instance: self.is_instance,
             label_id: self.label_id,
             is_dashed: self.is_dashed,
             name_id: self.name_id,
             type_id: self.type_id,
+            default_id: self.default_id,
         }
     }
 }
@@ -171,6 +186,7 @@ pub struct LetStatement {
     pub transparency: Transparency,
     pub name_id: NodeId<with_id::Identifier>,
     pub value_id: ExpressionId,
+    pub is_instance: bool,
 }
 impl RemoveId for with_id::LetStatement {
     type Output = LetStatement;
@@ -181,6 +197,7 @@ impl RemoveId for with_id::LetStatement {
             transparency: self.transparency,
             name_id: self.name_id,
             value_id: self.value_id,
+            is_instance: self.is_instance,
         }
     }
 }
@@ -194,6 +211,7 @@ impl AddId for LetStatement {
             transparency: self.transparency,
             name_id: self.name_id,
             value_id: self.value_id,
+            is_instance: self.is_instance,
         }
     }
 }
@@ -349,6 +367,7 @@ impl AddId for Fun {
 pub struct Match {
     pub span: Option<TextSpan>,
     pub matchee_id: ExpressionId,
+    pub motive_id: Option<NodeId<with_id::MatchMotive>>,
     pub case_list_id: Option<NonEmptyListId<NodeId<with_id::MatchCase>>>,
 }
 impl RemoveId for with_id::Match {
@@ -357,6 +376,7 @@ impl RemoveId for with_id::Match {
         Match {
             span: self.span,
             matchee_id: self.matchee_id,
+            motive_id: self.motive_id,
             case_list_id: self.case_list_id,
         }
     }
@@ -368,11 +388,46 @@ impl AddId for Match {
             id,
             span: self.span,
             matchee_id: self.matchee_id,
+            motive_id: self.motive_id,
             case_list_id: self.case_list_id,
         }
     }
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct MatchMotive {
+    pub span: Option<TextSpan>,
+    pub matchee_name_id: NodeId<with_id::Identifier>,
+    pub matchee_type_name_id: NodeId<with_id::NameExpression>,
+    pub index_name_list_id: Option<NonEmptyListId<NodeId<with_id::Identifier>>>,
+    pub output_id: ExpressionId,
+}
+impl RemoveId for with_id::MatchMotive {
+    type Output = MatchMotive;
+    fn remove_id(&self) -> Self::Output {
+        MatchMotive {
+            span: self.span,
+            matchee_name_id: self.matchee_name_id,
+            matchee_type_name_id: self.matchee_type_name_id,
+            index_name_list_id: self.index_name_list_id,
+            output_id: self.output_id,
+        }
+    }
+}
+impl AddId for MatchMotive {
+    type Output = with_id::MatchMotive;
+    fn add_id(&self, id: NodeId<Self::Output>) -> Self::Output {
+        with_id::MatchMotive {
+            id,
+            span: self.span,
+            matchee_name_id: self.matchee_name_id,
+            matchee_type_name_id: self.matchee_type_name_id,
+            index_name_list_id: self.index_name_list_id,
+            output_id: self.output_id,
+        }
+    }
+}
+
 #[derive(Clone, Debug, PartialEq, Eq, Hash)]
 pub struct MatchCase {
     pub span: Option<TextSpan>,
@@ -488,6 +543,124 @@ impl AddId for Check {
     }
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Rewrite {
+    pub span: Option<TextSpan>,
+    pub eq_type_name_id: NodeId<with_id::NameExpression>,
+    pub eq_refl_name_id: NodeId<with_id::NameExpression>,
+    pub proof_id: ExpressionId,
+    pub body_id: ExpressionId,
+}
+impl RemoveId for with_id::Rewrite {
+    type Output = Rewrite;
+    fn remove_id(&self) -> Self::Output {
+        Rewrite {
+            span: self.span,
+            eq_type_name_id: self.eq_type_name_id,
+            eq_refl_name_id: self.eq_refl_name_id,
+            proof_id: self.proof_id,
+            body_id: self.body_id,
+        }
+    }
+}
+impl AddId for Rewrite {
+    type Output = with_id::Rewrite;
+    fn add_id(&self, id: NodeId<Self::Output>) -> Self::Output {
+        with_id::Rewrite {
+            id,
+            span: self.span,
+            eq_type_name_id: self.eq_type_name_id,
+            eq_refl_name_id: self.eq_refl_name_id,
+            proof_id: self.proof_id,
+            body_id: self.body_id,
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Projection {
+    pub span: Option<TextSpan>,
+    pub record_id: ExpressionId,
+    pub field_name_id: NodeId<with_id::Identifier>,
+}
+impl RemoveId for with_id::Projection {
+    type Output = Projection;
+    fn remove_id(&self) -> Self::Output {
+        Projection {
+            span: self.span,
+            record_id: self.record_id,
+            field_name_id: self.field_name_id,
+        }
+    }
+}
+impl AddId for Projection {
+    type Output = with_id::Projection;
+    fn add_id(&self, id: NodeId<Self::Output>) -> Self::Output {
+        with_id::Projection {
+            id,
+            span: self.span,
+            record_id: self.record_id,
+            field_name_id: self.field_name_id,
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Exists {
+    pub span: Option<TextSpan>,
+    pub param_id: NodeId<with_id::UnlabeledParam>,
+    pub output_id: ExpressionId,
+}
+impl RemoveId for with_id::Exists {
+    type Output = Exists;
+    fn remove_id(&self) -> Self::Output {
+        Exists {
+            span: self.span,
+            param_id: self.param_id,
+            output_id: self.output_id,
+        }
+    }
+}
+impl AddId for Exists {
+    type Output = with_id::Exists;
+    fn add_id(&self, id: NodeId<Self::Output>) -> Self::Output {
+        with_id::Exists {
+            id,
+            span: self.span,
+            param_id: self.param_id,
+            output_id: self.output_id,
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Pair {
+    pub span: Option<TextSpan>,
+    pub witness_id: ExpressionId,
+    pub proof_id: ExpressionId,
+}
+impl RemoveId for with_id::Pair {
+    type Output = Pair;
+    fn remove_id(&self) -> Self::Output {
+        Pair {
+            span: self.span,
+            witness_id: self.witness_id,
+            proof_id: self.proof_id,
+        }
+    }
+}
+impl AddId for Pair {
+    type Output = with_id::Pair;
+    fn add_id(&self, id: NodeId<Self::Output>) -> Self::Output {
+        with_id::Pair {
+            id,
+            span: self.span,
+            witness_id: self.witness_id,
+            proof_id: self.proof_id,
+        }
+    }
+}
+
 #[derive(Clone, Debug, PartialEq, Eq, Hash)]
 pub struct CheckAssertion {
     pub span: Option<TextSpan>,
@@ -177,6 +177,10 @@ pub enum ExpressionId {
     Match(NodeId<Match>),
     Forall(NodeId<Forall>),
     Check(NodeId<Check>),
+    Rewrite(NodeId<Rewrite>),
+    Projection(NodeId<Projection>),
+    Exists(NodeId<Exists>),
+    Pair(NodeId<Pair>),
 }
 
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
@@ -17,7 +17,9 @@ pub enum FileItem {
     UseWildcard(UseWildcardStatement),
     Mod(ModStatement),
     Type(TypeStatement),
+    Struct(StructStatement),
     Let(LetStatement),
+    Notation(NotationStatement),
 }
 
 impl FileItem {
@@ -27,7 +29,9 @@ impl FileItem {
             FileItem::UseWildcard(item) => item.span,
             FileItem::Mod(item) => item.span,
             FileItem::Type(item) => item.span,
+            FileItem::Struct(item) => item.span,
             FileItem::Let(item) => item.span,
+            FileItem::Notation(item) => item.span,
         }
     }
 }
@@ -64,9 +68,39 @@ pub struct TypeStatement {
     pub visibility: Option<PubClause>,
     pub name: Identifier,
     pub params: Option<NonEmptyParamVec>,
+    pub derives: Vec<Derive>,
     pub variants: Vec<Variant>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Derive {
+    pub span: TextSpan,
+    pub kind: DeriveKind,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum DeriveKind {
+    /// Derives a decidable equality function named `eq`.
+    Eq,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct StructStatement {
+    pub span: TextSpan,
+    pub visibility: Option<PubClause>,
+    pub name: Identifier,
+    pub params: Option<NonEmptyParamVec>,
+    pub fields: Vec<StructField>,
+    pub is_trait: bool,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct StructField {
+    pub span: TextSpan,
+    pub name: Identifier,
+    pub type_: Expression,
+}
+
 #[derive(Clone, Debug, PartialEq, Eq, Hash)]
 pub enum NonEmptyParamVec {
     Unlabeled(NonEmptyVec<UnlabeledParam>),
@@ -91,6 +125,7 @@ impl NonEmptyParamVec {
 #[derive(Clone, Debug, PartialEq, Eq, Hash)]
 pub struct UnlabeledParam {
     pub span: TextSpan,
+    pub is_instance: bool,
     pub is_dashed: bool,
     pub name: Identifier,
     pub type_: Expression,
@@ -99,10 +134,12 @@ pub struct UnlabeledParam {
 #[derive(Clone, Debug, PartialEq, Eq, Hash)]
 pub struct LabeledParam {
     pub span: TextSpan,
+    pub is_instance: bool,
     pub label: ParamLabel,
     pub is_dashed: bool,
     pub name: Identifier,
     pub type_: Expression,
+    pub default: Option<Expression>,
 }
 
 pub use crate::data::unsimplified_ast::ParamLabel;
@@ -122,6 +159,36 @@ pub struct LetStatement {
     pub transparency: Option<ParenthesizedModScopeModifier>,
     pub name: Identifier,
     pub value: Expression,
+    pub is_instance: bool,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct NotationStatement {
+    pub span: TextSpan,
+    pub visibility: Option<PubClause>,
+    pub operator: Identifier,
+    pub function: NameExpression,
+    pub precedence: NotationPrecedence,
+    pub associativity: NotationAssociativity,
+}
+
+/// The precedence tiers of a notation, from loosest to tightest.
+/// Thus, the derived `Ord` orders tiers by how tightly they bind.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum NotationPrecedence {
+    Compare,
+    Sum,
+    Product,
+    Power,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum NotationAssociativity {
+    Left,
+    Right,
+    /// The operator cannot be chained with other operators
+    /// of the same precedence without parentheses.
+    None,
 }
 
 pub use crate::data::unsimplified_ast::ParenthesizedModScopeModifier;
@@ -137,6 +204,10 @@ pub enum Expression {
     Match(Box<Match>),
     Forall(Box<Forall>),
     Check(Box<Check>),
+    Rewrite(Box<Rewrite>),
+    Exists(Box<Exists>),
+    Pair(Box<Pair>),
+    InfixChain(Box<InfixChain>),
 }
 
 impl Expression {
@@ -149,6 +220,10 @@ impl Expression {
             Expression::Match(match_) => match_.span,
             Expression::Forall(forall) => forall.span,
             Expression::Check(check) => check.span,
+            Expression::Rewrite(rewrite) => rewrite.span,
+            Expression::Exists(exists) => exists.span,
+            Expression::Pair(pair) => pair.span,
+            Expression::InfixChain(chain) => chain.span,
         }
     }
 }
@@ -167,6 +242,8 @@ pub use crate::data::unsimplified_ast::UnreservedIdentifierName;
 
 pub use crate::data::unsimplified_ast::ReservedIdentifierName;
 
+pub use crate::data::unsimplified_ast::PAIR_VARIANT_NAME;
+
 #[derive(Clone, Debug, PartialEq, Eq, Hash)]
 pub struct Call {
     pub span: TextSpan,
@@ -199,9 +276,19 @@ pub struct Fun {
 pub struct Match {
     pub span: TextSpan,
     pub matchee: Expression,
+    pub motive: Option<MatchMotive>,
     pub cases: Vec<MatchCase>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct MatchMotive {
+    pub span: TextSpan,
+    pub matchee_name: Identifier,
+    pub matchee_type_name: NameExpression,
+    pub index_names: Option<NonEmptyVec<Identifier>>,
+    pub output: Expression,
+}
+
 #[derive(Clone, Debug, PartialEq, Eq, Hash)]
 pub struct MatchCase {
     pub span: TextSpan,
@@ -264,6 +351,50 @@ pub struct Check {
     pub output: Expression,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Rewrite {
+    pub span: TextSpan,
+    pub proof: Expression,
+    pub body: Expression,
+}
+
+/// An `exists` with multiple params is simplified into
+/// nested `exists` expressions, each with exactly one param.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Exists {
+    pub span: TextSpan,
+    pub param: UnlabeledParam,
+    pub output: Expression,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Pair {
+    pub span: TextSpan,
+    pub witness: Expression,
+    pub proof: Expression,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct InfixChain {
+    pub span: TextSpan,
+    pub first_operand: InfixOperand,
+    pub rest: NonEmptyVec<InfixChainLink>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct InfixChainLink {
+    pub operator: Identifier,
+    pub operand: InfixOperand,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct InfixOperand {
+    /// Unlike `expression.span()`, this includes
+    /// any parentheses around the operand.
+    pub span: TextSpan,
+    pub expression: Expression,
+}
+
 #[derive(Clone, Debug, PartialEq, Eq, Hash)]
 pub struct CheckAssertion {
     pub span: TextSpan,
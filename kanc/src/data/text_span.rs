@@ -73,6 +73,26 @@ impl TextCoord {
         }
         None
     }
+
+    /// The inverse of `TextCoord::new`.
+    ///
+    /// Returns `None` if `self` does not refer to a character in `src`.
+    pub fn byte_index(self, src: &str) -> Option<ByteIndex> {
+        let mut line = 1;
+        let mut col = 0;
+        for (j, c) in src.char_indices() {
+            if line == self.line && col == self.col {
+                return Some(ByteIndex(j));
+            }
+            if c == '\n' {
+                line += 1;
+                col = 0;
+            } else {
+                col += 1;
+            }
+        }
+        None
+    }
 }
 
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
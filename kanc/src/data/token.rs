@@ -21,6 +21,7 @@ pub enum TokenKind {
     At,
     Equal,
     Dash,
+    Plus,
     Question,
     Tilde,
     Slash,
@@ -45,10 +46,8 @@ pub enum TokenKind {
     TypeLowerCase,
     Let,
     TypeTitleCase,
-    Type0,
-    Type1,
-    Type2,
-    Type3,
+    /// `Type0`, `Type1`, `Type2`, and so on.
+    NumberedType,
     Fun,
     Match,
     Forall,
@@ -56,11 +55,14 @@ pub enum TokenKind {
     Goal,
     Impossible,
     Todo,
+    Rewrite,
+    Struct,
+    Trait,
+    Instance,
+    Derive,
 
     // Currently unused but reserved for future use.
-    Struct,
     Var,
-    Trait,
 
     Pub,
     Prot,
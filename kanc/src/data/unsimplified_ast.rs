@@ -14,7 +14,9 @@ pub enum FileItem {
     Use(UseStatement),
     Mod(ModStatement),
     Type(TypeStatement),
+    Struct(StructStatement),
     Let(LetStatement),
+    Notation(NotationStatement),
 }
 
 impl FileItem {
@@ -23,7 +25,9 @@ impl FileItem {
             FileItem::Use(use_) => use_.span,
             FileItem::Mod(mod_) => mod_.span,
             FileItem::Type(type_) => type_.span,
+            FileItem::Struct(struct_) => struct_.span,
             FileItem::Let(let_) => let_.span,
+            FileItem::Notation(notation) => notation.span,
         }
     }
 }
@@ -76,15 +80,52 @@ pub struct TypeStatement {
     pub visibility: Option<PubClause>,
     pub name: Identifier,
     pub params: Option<NonEmptyVec<Param>>,
+    pub derive: Option<DeriveClause>,
     pub variants: Vec<Variant>,
 }
 
+/// A clause like `derive(eq)`, which appears between a type's
+/// params and its variants.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct DeriveClause {
+    pub span: TextSpan,
+    pub names: NonEmptyVec<Identifier>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct StructStatement {
+    pub span: TextSpan,
+    pub visibility: Option<PubClause>,
+    pub name: Identifier,
+    pub params: Option<NonEmptyVec<Param>>,
+    pub fields: Vec<StructField>,
+    /// `true` if this was declared with `trait` instead of `struct`.
+    pub is_trait: bool,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct StructField {
+    pub span: TextSpan,
+    pub name: Identifier,
+    pub type_: Expression,
+}
+
 #[derive(Clone, Debug, PartialEq, Eq, Hash)]
 pub struct PubClause {
     pub span: TextSpan,
     pub scope_modifier: Option<ParenthesizedModScopeModifier>,
 }
 
+impl PubClause {
+    /// Returns `true` for `pub` and `pub(*)`.
+    pub fn is_global(&self) -> bool {
+        match &self.scope_modifier {
+            None => true,
+            Some(modifier) => modifier.kind == ModScopeModifierKind::Global,
+        }
+    }
+}
+
 #[derive(Clone, Debug, PartialEq, Eq, Hash)]
 pub struct ParenthesizedModScopeModifier {
     pub span: TextSpan,
@@ -102,10 +143,12 @@ pub enum ModScopeModifierKind {
 #[derive(Clone, Debug, PartialEq, Eq, Hash)]
 pub struct Param {
     pub span: TextSpan,
+    pub is_instance: bool,
     pub label: Option<ParamLabel>,
     pub is_dashed: bool,
     pub name: Identifier,
     pub type_: Expression,
+    pub default: Option<Expression>,
 }
 
 impl Param {
@@ -138,6 +181,24 @@ pub struct LetStatement {
     pub transparency: Option<ParenthesizedModScopeModifier>,
     pub name: Identifier,
     pub value: Expression,
+    /// `true` if this was declared with `instance` instead of `let`.
+    pub is_instance: bool,
+}
+
+/// A statement like `notation (+) = add, sum, left;`,
+/// which lets `a + b` be written instead of `add(a, b)`.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct NotationStatement {
+    pub span: TextSpan,
+    pub visibility: Option<PubClause>,
+    /// The operator is represented as an identifier whose name
+    /// is the operator's token sequence (e.g., `+`).
+    /// Such a name can never be written as a standard identifier,
+    /// so operators never clash with other names.
+    pub operator: Identifier,
+    pub function: Expression,
+    pub precedence: Identifier,
+    pub associativity: Identifier,
 }
 
 #[derive(Clone, Debug, PartialEq, Eq, Hash)]
@@ -150,6 +211,11 @@ pub enum Expression {
     Match(Box<Match>),
     Forall(Box<Forall>),
     Check(Box<Check>),
+    Rewrite(Box<Rewrite>),
+    Exists(Box<Exists>),
+    Pair(Box<Pair>),
+    InfixChain(Box<InfixChain>),
+    Parenthesized(Box<Parenthesized>),
 }
 
 impl Expression {
@@ -163,6 +229,11 @@ impl Expression {
             Expression::Match(match_) => match_.span,
             Expression::Forall(forall) => forall.span,
             Expression::Check(check) => check.span,
+            Expression::Rewrite(rewrite) => rewrite.span,
+            Expression::Exists(exists) => exists.span,
+            Expression::Pair(pair) => pair.span,
+            Expression::InfixChain(chain) => chain.span,
+            Expression::Parenthesized(parenthesized) => parenthesized.span,
         }
     }
 }
@@ -181,10 +252,11 @@ pub enum IdentifierName {
 
 impl IdentifierName {
     pub fn new(s: String) -> Self {
+        if let Some(name) = NumberedTypeName::new(&s) {
+            return IdentifierName::Reserved(ReservedIdentifierName::NumberedType(name));
+        }
         match s.as_str() {
             "Type" => IdentifierName::Reserved(ReservedIdentifierName::TypeTitleCase),
-            "Type1" => IdentifierName::Reserved(ReservedIdentifierName::Type1),
-            "Type2" => IdentifierName::Reserved(ReservedIdentifierName::Type2),
             "_" => IdentifierName::Reserved(ReservedIdentifierName::Underscore),
             "mod" => IdentifierName::Reserved(ReservedIdentifierName::Mod),
             "super" => IdentifierName::Reserved(ReservedIdentifierName::Super),
@@ -206,8 +278,7 @@ impl IdentifierName {
         match &self {
             IdentifierName::Standard(s) => s.raw(),
             IdentifierName::Reserved(ReservedIdentifierName::TypeTitleCase) => "Type",
-            IdentifierName::Reserved(ReservedIdentifierName::Type1) => "Type1",
-            IdentifierName::Reserved(ReservedIdentifierName::Type2) => "Type2",
+            IdentifierName::Reserved(ReservedIdentifierName::NumberedType(name)) => name.raw(),
             IdentifierName::Reserved(ReservedIdentifierName::Underscore) => "_",
             IdentifierName::Reserved(ReservedIdentifierName::Mod) => "mod",
             IdentifierName::Reserved(ReservedIdentifierName::Super) => "super",
@@ -267,11 +338,11 @@ impl PartialEq<UnreservedIdentifierName> for IdentifierName {
     }
 }
 
-#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
 pub enum ReservedIdentifierName {
     TypeTitleCase,
-    Type1,
-    Type2,
+    /// `Type0`, `Type1`, `Type2`, and so on.
+    NumberedType(NumberedTypeName),
     Underscore,
     Mod,
     Super,
@@ -285,6 +356,73 @@ pub enum ReservedIdentifierName {
     Pack,
 }
 
+impl ReservedIdentifierName {
+    /// Returns the name of the universe at `level`.
+    /// Level 0 is named `Type` rather than `Type0`,
+    /// since that is the name users write most often.
+    pub fn universe(level: usize) -> Self {
+        match level {
+            0 => ReservedIdentifierName::TypeTitleCase,
+            _ => ReservedIdentifierName::NumberedType(NumberedTypeName::from_level(level)),
+        }
+    }
+
+    /// Returns the level of the universe this name refers to,
+    /// or `None` if this name does not refer to a universe.
+    /// Both `Type` and `Type0` refer to level 0.
+    pub fn universe_level(&self) -> Option<usize> {
+        match self {
+            ReservedIdentifierName::TypeTitleCase => Some(0),
+            ReservedIdentifierName::NumberedType(name) => Some(name.level()),
+            _ => None,
+        }
+    }
+}
+
+/// The name of a universe, written as `Type` followed by its level
+/// (e.g., `Type0` or `Type12`).
+/// The level is written in decimal, without leading zeros.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct NumberedTypeName {
+    level: usize,
+    raw: String,
+}
+
+impl NumberedTypeName {
+    /// Returns `None` if `s` is not a numbered universe name.
+    pub fn new(s: &str) -> Option<Self> {
+        let digits = s.strip_prefix("Type")?;
+        if digits.is_empty()
+            || !digits.bytes().all(|b| b.is_ascii_digit())
+            || (digits.len() > 1 && digits.starts_with('0'))
+        {
+            return None;
+        }
+        let level = digits.parse().ok()?;
+        Some(Self {
+            level,
+            raw: s.to_string(),
+        })
+    }
+
+    pub fn from_level(level: usize) -> Self {
+        Self {
+            level,
+            raw: format!("Type{level}"),
+        }
+    }
+}
+
+impl NumberedTypeName {
+    pub fn level(&self) -> usize {
+        self.level
+    }
+
+    pub fn raw(&self) -> &str {
+        &self.raw
+    }
+}
+
 impl PartialEq<IdentifierName> for ReservedIdentifierName {
     fn eq(&self, other: &IdentifierName) -> bool {
         match other {
@@ -345,9 +483,24 @@ pub struct Fun {
 pub struct Match {
     pub span: TextSpan,
     pub matchee: Expression,
+    pub motive: Option<MatchMotive>,
     pub cases: Vec<MatchCase>,
 }
 
+/// The `as y: Adt(i1, ..., in) => T` part of
+/// `match x as y: Adt(i1, ..., in) => T { ... }`.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct MatchMotive {
+    pub span: TextSpan,
+    pub matchee_name: Identifier,
+    /// This should be either the name of the matchee's type
+    /// (e.g., `Nat`) or a call to that name whose args are
+    /// all identifiers (e.g., `Eq(_, a, b)`).
+    /// However, this is not verified until the simplification stage.
+    pub matchee_type: Expression,
+    pub output: Expression,
+}
+
 #[derive(Clone, Debug, PartialEq, Eq, Hash)]
 pub struct MatchCase {
     pub span: TextSpan,
@@ -357,6 +510,12 @@ pub struct MatchCase {
     pub output: MatchCaseOutput,
 }
 
+/// The variant name of a match case that destructures
+/// a dependent pair (i.e., `(w, p) => ...`).
+/// Pairs are represented like values of a variant with this name
+/// (e.g., in the generated JavaScript).
+pub const PAIR_VARIANT_NAME: &str = "pair";
+
 #[derive(Clone, Debug, PartialEq, Eq, Hash)]
 pub struct MatchCaseParam {
     pub span: TextSpan,
@@ -393,6 +552,50 @@ pub struct Check {
     pub output: Expression,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Rewrite {
+    pub span: TextSpan,
+    pub proof: Expression,
+    pub body: Expression,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Exists {
+    pub span: TextSpan,
+    pub params: NonEmptyVec<Param>,
+    pub output: Expression,
+}
+
+/// A dependent pair (e.g., `(w, p)`).
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Pair {
+    pub span: TextSpan,
+    pub witness: Expression,
+    pub proof: Expression,
+}
+
+/// Operands separated by infix operators (e.g., `a + b * c`).
+/// The chain is kept flat, since the operators' precedences
+/// are not known until their notations are bound.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct InfixChain {
+    pub span: TextSpan,
+    pub first_operand: Expression,
+    pub rest: NonEmptyVec<InfixChainLink>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct InfixChainLink {
+    pub operator: Identifier,
+    pub operand: Expression,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Parenthesized {
+    pub span: TextSpan,
+    pub expression: Expression,
+}
+
 #[derive(Clone, Debug, PartialEq, Eq, Hash)]
 pub struct CheckAssertion {
     pub span: TextSpan,
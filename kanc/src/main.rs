@@ -1,29 +1,37 @@
 use kanc::{
     data::node_registry::NodeRegistry,
     processing::{
-        bind_type_independent::bind_files,
+        bind_type_independent::{bind_files_with_options, BindOptions},
+        find_unreachable_items::find_unreachable_items,
         generate_code::{
             targets::javascript::{
-                format::{format_file as format_js_file, FormatOptions},
-                JavaScript,
+                format::{format_declaration_file, format_file_with_source_map, FormatOptions},
+                source_map::get_source_map_path,
+                JavaScript, JavaScriptOptions,
             },
             CompileTarget,
         },
         lighten_ast::register_file_items,
         simplify_ast::simplify_file,
         skin::processing::{
-            format::FormatErrorForCli, parse_cli_args::parse_args,
-            read_compiler_options::read_compiler_options, read_kantu_files::read_kantu_files,
+            format::{
+                format_reduction_profile_for_cli, format_reduction_trace_for_cli,
+                format_unreachable_item_counts_for_cli, FormatErrorForCli,
+            },
+            parse_cli_args::parse_args,
+            read_compiler_options::read_compiler_options,
+            read_kantu_files::read_kantu_files,
+            read_source_files::read_source_files,
+            resolve_trace_check_location::resolve_trace_check_location,
             write_target_files::write_target_files,
         },
-        type_check::type_check_file_items,
+        type_check::{type_check_file_items_with_options, TypeCheckOptions},
         validate_fun_recursion::validate_fun_recursion_in_file_items,
         validate_type_positivity::validate_type_positivity_in_file_items,
         validate_variant_return_types::validate_variant_return_types_in_file_items,
     },
 };
 
-use std::path::PathBuf;
 use std::time::Instant;
 
 fn main() -> Result<(), ()> {
@@ -43,13 +51,23 @@ fn main_() -> Result<(), ()> {
     let options = parse_args(&args).print_err(())?;
     let options = read_compiler_options(&options).print_err(())?;
     let (files, file_tree, file_path_map) = read_kantu_files(&options).print_err(())?;
+    let source_files = read_source_files(&options, &file_path_map);
+    let traced_check_span = resolve_trace_check_location(&options, &file_path_map).print_err(())?;
     let files = files
         .into_iter()
         .map(|file| simplify_file(file))
         .collect::<Result<Vec<_>, _>>()
         .print_err(&file_path_map)?;
-    let file_items =
-        bind_files(file_tree.root(), files, &file_tree).print_err((&file_path_map, &file_tree))?;
+    let file_items = bind_files_with_options(
+        file_tree.root(),
+        files,
+        &file_tree,
+        BindOptions {
+            designated_eq: options.designated_eq.clone(),
+            designated_bool: options.designated_bool.clone(),
+        },
+    )
+    .print_err((&file_path_map, &file_tree))?;
     let mut registry = NodeRegistry::empty();
     let file_item_list_id = register_file_items(&mut registry, file_items);
 
@@ -61,28 +79,132 @@ fn main_() -> Result<(), ()> {
     let file_item_list_id =
         validate_type_positivity_in_file_items(&mut registry, file_item_list_id)
             .print_err(&registry)?;
-    let warnings = type_check_file_items(&file_tree, &mut registry, file_item_list_id)
-        .print_err((&options, &file_path_map, &file_tree, &registry))?;
-    let js_file =
-        JavaScript::generate_code(&registry, file_item_list_id.raw()).print_err(&registry)?;
+    let type_check_output = type_check_file_items_with_options(
+        &file_tree,
+        &mut registry,
+        file_item_list_id,
+        TypeCheckOptions {
+            max_reduction_steps: Some(options.max_reduction_steps),
+            traced_check_span,
+            erased_types: options.erased_types.clone(),
+            bigint_types: options.bigint_types.clone(),
+            bigint_functions: options.bigint_functions.clone(),
+            designated_bool: options.designated_bool.clone(),
+            precompute_lets: options.optimize,
+            ..TypeCheckOptions::default()
+        },
+    )
+    .print_err((&options, &file_path_map, &file_tree, &registry))?;
+    let warnings = type_check_output.warnings;
+    let unreachable_items = find_unreachable_items(
+        &registry,
+        &file_tree,
+        file_item_list_id.raw(),
+        &options.entry_points,
+    )
+    .print_err((&file_path_map, &registry))?;
+    let generated_files = JavaScript::generate_code_with_options(
+        &registry,
+        &file_tree,
+        file_item_list_id.raw(),
+        JavaScriptOptions {
+            erasure: type_check_output.erasure,
+            bigint: type_check_output.bigint,
+            stack_safe: options.stack_safe,
+            source_files: source_files.clone(),
+            unreachable_items: unreachable_items.clone(),
+            precomputed_lets: type_check_output.precomputed_lets,
+            inline_small_functions: options.optimize,
+            check_exported_args: options.check_args,
+        },
+    )
+    .print_err(&registry)?;
 
+    let format_options = FormatOptions { indentation: 4 };
     let write_result = write_target_files(
         &options,
-        vec![(
-            PathBuf::from("index.js"),
-            format_js_file(&js_file, &FormatOptions { indentation: 4 }),
-        )],
+        generated_files
+            .js_files
+            .iter()
+            .flat_map(|(path, js_file)| {
+                let (formatted, source_map) =
+                    format_file_with_source_map(js_file, &format_options, path, &source_files);
+                [
+                    (path.clone(), formatted),
+                    (get_source_map_path(path), source_map),
+                ]
+            })
+            .chain(
+                generated_files
+                    .declaration_files
+                    .iter()
+                    .map(|(path, declaration_file)| {
+                        (
+                            path.clone(),
+                            format_declaration_file(declaration_file, &format_options),
+                        )
+                    }),
+            )
+            .collect(),
     );
 
-    if warnings.is_empty() {
+    let warning_displays: Vec<String> = warnings
+        .iter()
+        .map(|warning| warning.format_for_cli(&registry))
+        .chain(
+            generated_files
+                .warnings
+                .iter()
+                .map(|warning| warning.format_for_cli((&file_path_map, &registry))),
+        )
+        .collect();
+    if warning_displays.is_empty() {
         println!("Compiled successfully.");
     } else {
         println!("Compiled with warnings:\n");
-        for warning in &warnings {
-            println!("{}\n", warning.format_for_cli(&registry));
+        for warning_display in &warning_displays {
+            println!("{}\n", warning_display);
         }
     }
 
+    if !options.entry_points.is_empty() {
+        print!(
+            "{}",
+            format_unreachable_item_counts_for_cli(&unreachable_items)
+        );
+    }
+
+    if options.show_normal_form_cache_stats {
+        let stats = type_check_output.normal_form_cache_stats;
+        println!(
+            "Normal form cache: {} hits, {} misses.",
+            stats.hits, stats.misses
+        );
+    }
+
+    if options.profile_eval {
+        print!(
+            "{}",
+            format_reduction_profile_for_cli(
+                &type_check_output.reduction_profile,
+                &registry,
+                &file_path_map
+            )
+        );
+    }
+
+    if options.trace_check.is_some() {
+        print!(
+            "{}",
+            format_reduction_trace_for_cli(
+                type_check_output.reduction_trace.as_ref(),
+                &options,
+                &registry,
+                &file_path_map
+            )
+        );
+    }
+
     match write_result {
         Ok(()) => println!(
             "Successfully wrote output files to {}.",
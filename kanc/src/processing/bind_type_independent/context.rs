@@ -2,6 +2,8 @@ use super::*;
 
 use ub::Identifier;
 
+use rustc_hash::FxHashSet;
+
 #[derive(Debug)]
 pub struct Context<'a, 'b> {
     data: &'a mut ContextData<'b>,
@@ -19,7 +21,11 @@ impl Context<'_, '_> {
 pub struct ContextData<'a> {
     stack: Vec<ContextEntry>,
     graph: DotGraph,
+    /// The levels of the entries that are types.
+    type_levels: FxHashSet<DbLevel>,
     file_tree: &'a FileTree,
+    designated_eq: Option<DesignatedEq>,
+    designated_bool: Option<DesignatedBool>,
 }
 
 #[derive(Clone, Debug)]
@@ -30,7 +36,6 @@ pub enum ContextEntry {
 
 #[derive(Clone, Debug)]
 pub enum AccessibleEntry {
-    Builtin(IdentifierName),
     Local(Identifier),
 }
 
@@ -54,15 +59,16 @@ pub enum NameComponentNotAccessibleErrorKind {
 }
 
 impl ContextData<'_> {
-    pub fn with_builtins(file_tree: &FileTree) -> ContextData {
-        let type1_entry = ContextEntry::Placeholder;
-        let type0_entry = ContextEntry::Accessible(AccessibleEntry::Builtin(
-            IdentifierName::Reserved(ReservedIdentifierName::TypeTitleCase),
-        ));
+    pub fn with_builtins(file_tree: &FileTree, options: BindOptions) -> ContextData {
+        // Universes are not stored in the stack (see `lookup_builtin`),
+        // so the stack starts out empty.
         ContextData {
-            stack: vec![type1_entry, type0_entry],
+            stack: vec![],
             graph: DotGraph::empty(),
+            type_levels: FxHashSet::default(),
             file_tree,
+            designated_eq: options.designated_eq,
+            designated_bool: options.designated_bool,
         }
     }
 }
@@ -81,6 +87,16 @@ impl<'b> ContextData<'b> {
     }
 }
 
+impl Context<'_, '_> {
+    pub fn designated_eq(&self) -> Option<&DesignatedEq> {
+        self.data.designated_eq.as_ref()
+    }
+
+    pub fn designated_bool(&self) -> Option<&DesignatedBool> {
+        self.data.designated_bool.as_ref()
+    }
+}
+
 impl Context<'_, '_> {
     /// Panics if `n > self.len()`.
     pub fn pop_n(&mut self, n: usize) {
@@ -127,6 +143,10 @@ impl ContextData<'_> {
 }
 
 impl Context<'_, '_> {
+    pub fn notation_function_index(&self, function: NotationFunction) -> DbIndex {
+        self.data.notation_function_index(function)
+    }
+
     pub fn index_to_level(&self, level: DbIndex) -> DbLevel {
         self.data.index_to_level(level)
     }
@@ -136,6 +156,22 @@ impl ContextData<'_> {
         DbIndex(self.len() - level.0 - 1)
     }
 
+    /// There can be arbitrarily many universes, so rather than storing them
+    /// in the stack, we pretend they lie below its bottom,
+    /// from smallest to largest.
+    /// Thus, the universe at level `n` is `n` entries below the
+    /// bottom of the stack.
+    fn universe_index(&self, universe_level: usize) -> DbIndex {
+        DbIndex(self.len() + universe_level)
+    }
+
+    fn notation_function_index(&self, function: NotationFunction) -> DbIndex {
+        match function {
+            NotationFunction::LeafItem(level) => self.level_to_index(level),
+            NotationFunction::Universe(universe_level) => self.universe_index(universe_level),
+        }
+    }
+
     fn index_to_level(&self, level: DbIndex) -> DbLevel {
         DbLevel(self.len() - level.0 - 1)
     }
@@ -203,7 +239,14 @@ impl ContextData<'_> {
             .map(|entry| entry.node);
         match lookup_result {
             Ok(DotGraphNode::LeafItem(level)) => Ok(self.level_to_index(level)),
+            Ok(DotGraphNode::Universe(universe_level)) => Ok(self.universe_index(universe_level)),
             Ok(DotGraphNode::Mod(file_id)) => Err(Ok(file_id)),
+            // Operator names cannot appear in name expressions,
+            // so this is only reachable by bypassing the parser.
+            // In that case, the notation stands for its function.
+            Ok(DotGraphNode::Notation { function, .. }) => {
+                Ok(self.notation_function_index(function))
+            }
             Err(err) => Err(Err(err)),
         }
     }
@@ -305,25 +348,16 @@ impl ContextData<'_> {
     }
 
     fn lookup_builtin(&self, component: &IdentifierName) -> Option<DotGraphEntry> {
-        self.stack
-            .iter()
-            .enumerate()
-            .find_map(|(raw_index, entry)| match entry {
-                ContextEntry::Accessible(AccessibleEntry::Builtin(builtin)) => {
-                    if builtin == component {
-                        let level = DbLevel(raw_index);
-                        let def = OwnedSymbolSource::Builtin;
-                        return Some(DotGraphEntry {
-                            node: DotGraphNode::LeafItem(level),
-                            def,
-                            visibility: Visibility(ModScope::Global),
-                            original_visibility: Visibility(ModScope::Global),
-                        });
-                    }
-                    None
-                }
-                _ => None,
-            })
+        let IdentifierName::Reserved(component) = component else {
+            return None;
+        };
+        let universe_level = component.universe_level()?;
+        Some(DotGraphEntry {
+            node: DotGraphNode::Universe(universe_level),
+            def: OwnedSymbolSource::Builtin,
+            visibility: Visibility(ModScope::Global),
+            original_visibility: Visibility(ModScope::Global),
+        })
     }
 
     fn lookup_local_name_component(&self, component: &IdentifierName) -> Option<DotGraphEntry> {
@@ -555,6 +589,16 @@ impl ContextData<'_> {
     }
 }
 
+impl Context<'_, '_> {
+    pub fn mark_as_type(&mut self, level: DbLevel) {
+        self.data.type_levels.insert(level);
+    }
+
+    pub fn is_type(&self, level: DbLevel) -> bool {
+        self.data.type_levels.contains(&level)
+    }
+}
+
 impl Context<'_, '_> {
     pub fn get_edges(&self, node: DotGraphNode) -> Vec<(&IdentifierName, &DotGraphEntry)> {
         self.data.get_edges(node)
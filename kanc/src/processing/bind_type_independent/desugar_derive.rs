@@ -0,0 +1,656 @@
+use super::*;
+
+/// Returns the `eq` function derived for `type_statement`:
+///
+/// ```text
+/// let eq = fun eq(<type params>, -a: Name(<type params>), b: Name(<type params>), <element eqs>): Bool {
+///     match a {
+///         v1(a_x, ...) => match b {
+///             v1(b_x, ...) => <compare a_x to b_x, ...>,
+///             v2(...) => Bool.false,
+///         },
+///         ...
+///     }
+/// };
+/// ```
+///
+/// where `Bool` is the pack's designated boolean type.
+/// Every type param of type `Type` gets an element eq param
+/// (e.g., `eq_T: forall(x: T, y: T) { Bool }`).
+///
+/// A field is compared with the corresponding element eq
+/// (if its type is a type param), a recursive call
+/// (if its type is the type itself), or `F.eq`
+/// (if its type is some other type `F`).
+/// A field whose type mentions an earlier field
+/// (e.g., `v: Vec(n)`) cannot be compared, so it is an error.
+pub fn get_derived_eq_let_statement(
+    context: &Context,
+    type_statement: &ub::TypeStatement,
+    derive: &ub::Derive,
+) -> Result<ub::LetStatement, BindError> {
+    let Some(designated_bool) = context.designated_bool().cloned() else {
+        return Err(DeriveWithoutDesignatedBoolError {
+            derive: derive.clone(),
+        }
+        .into());
+    };
+
+    let mut taken = get_names_declared_by_type_statement(type_statement);
+    let mut fresh = |base: &str| {
+        let name = get_fresh_name(context, &taken, base);
+        taken.push(name.clone());
+        ub::Identifier {
+            span: derive.span,
+            name,
+        }
+    };
+    let fun_name = fresh("eq");
+    let a = fresh("a");
+    let b = fresh("b");
+    let element_eq_param_names = (fresh("x"), fresh("y"));
+    let type_params = type_params_as_unlabeled(type_statement);
+    let element_eq_names = type_params
+        .iter()
+        .map(|param| {
+            if is_universe(&param.type_) {
+                Some(fresh(&format!("eq_{}", param.name.name.src_str())))
+            } else {
+                None
+            }
+        })
+        .collect();
+
+    let deriver = EqDeriver {
+        type_statement,
+        derive,
+        designated_bool,
+        fun_name,
+        a,
+        b,
+        element_eq_param_names,
+        type_params,
+        element_eq_names,
+    };
+    let cases = type_statement
+        .variants
+        .iter()
+        .map(|variant| deriver.get_case(variant, &mut fresh))
+        .collect::<Result<Vec<_>, BindError>>()?;
+    Ok(deriver.get_let_statement(cases))
+}
+
+struct EqDeriver<'a> {
+    type_statement: &'a ub::TypeStatement,
+    derive: &'a ub::Derive,
+    designated_bool: DesignatedBool,
+    fun_name: ub::Identifier,
+    a: ub::Identifier,
+    b: ub::Identifier,
+    /// The param names of each element eq's type.
+    /// These must differ from `a` and `b`, since names cannot be shadowed.
+    element_eq_param_names: (ub::Identifier, ub::Identifier),
+    type_params: Vec<ub::UnlabeledParam>,
+    /// The name of the element eq param for each type param,
+    /// or `None` if the type param is not of type `Type`.
+    element_eq_names: Vec<Option<ub::Identifier>>,
+}
+
+enum FieldComparison {
+    ElementEq(usize),
+    Recursive,
+    OtherTypeEq(ub::NameExpression),
+    Skipped,
+}
+
+impl EqDeriver<'_> {
+    fn get_let_statement(&self, cases: Vec<ub::MatchCase>) -> ub::LetStatement {
+        let span = self.derive.span;
+        let self_type = get_type_expression(
+            &self.type_statement.name,
+            self.type_statement.params.as_ref(),
+            span,
+        );
+        let matchee_params = [
+            ub::UnlabeledParam {
+                span,
+                is_instance: false,
+                is_dashed: true,
+                name: self.a.clone(),
+                type_: self_type.clone(),
+            },
+            ub::UnlabeledParam {
+                span,
+                is_instance: false,
+                is_dashed: false,
+                name: self.b.clone(),
+                type_: self_type,
+            },
+        ];
+        let element_eq_params = self
+            .type_params
+            .iter()
+            .zip(&self.element_eq_names)
+            .filter_map(|(type_param, eq_name)| {
+                eq_name.as_ref().map(|eq_name| ub::UnlabeledParam {
+                    span,
+                    is_instance: false,
+                    is_dashed: false,
+                    name: eq_name.clone(),
+                    type_: self.get_element_eq_type(&type_param.name),
+                })
+            });
+        let params: Vec<ub::UnlabeledParam> = self
+            .type_params
+            .iter()
+            .cloned()
+            .chain(matchee_params)
+            .chain(element_eq_params)
+            .collect();
+        let body = ub::Expression::Match(Box::new(ub::Match {
+            span,
+            matchee: name_expression(vec![self.a.clone()]),
+            motive: None,
+            cases,
+        }));
+        ub::LetStatement {
+            span,
+            visibility: self.type_statement.visibility.clone(),
+            transparency: None,
+            name: ub::Identifier {
+                span,
+                name: IdentifierName::new("eq".to_string()),
+            },
+            value: ub::Expression::Fun(Box::new(ub::Fun {
+                span,
+                name: self.fun_name.clone(),
+                params: ub::NonEmptyParamVec::Unlabeled(
+                    NonEmptyVec::try_from(params).expect("there should be at least two params"),
+                ),
+                return_type: self.get_bool_type(),
+                body,
+            })),
+            is_instance: false,
+        }
+    }
+
+    /// Returns `forall(x: T, y: T) { Bool }`.
+    fn get_element_eq_type(&self, type_param_name: &ub::Identifier) -> ub::Expression {
+        let span = self.derive.span;
+        let element_type = name_expression(vec![type_param_name.clone()]);
+        let param = |name: &ub::Identifier| ub::UnlabeledParam {
+            span,
+            is_instance: false,
+            is_dashed: false,
+            name: name.clone(),
+            type_: element_type.clone(),
+        };
+        ub::Expression::Forall(Box::new(ub::Forall {
+            span,
+            params: ub::NonEmptyParamVec::Unlabeled(NonEmptyVec::from_pushed(
+                vec![param(&self.element_eq_param_names.0)],
+                param(&self.element_eq_param_names.1),
+            )),
+            output: self.get_bool_type(),
+        }))
+    }
+
+    /// Returns the case of the outer match (i.e., the match on `a`)
+    /// for `variant`.
+    fn get_case(
+        &self,
+        variant: &ub::Variant,
+        fresh: &mut impl FnMut(&str) -> ub::Identifier,
+    ) -> Result<ub::MatchCase, BindError> {
+        let span = self.derive.span;
+        let index_names = self.get_index_names(variant)?;
+        let variant_params = variant_params_as_unlabeled(variant);
+
+        let mut a_names = Vec::with_capacity(variant_params.len());
+        let mut b_names = Vec::with_capacity(variant_params.len());
+        let mut comparisons = vec![];
+        let mut earlier_field_names: Vec<&IdentifierName> = vec![];
+        for param in &variant_params {
+            let comparison = if index_names.contains(&param.name.name) {
+                FieldComparison::Skipped
+            } else {
+                let comparison =
+                    self.get_field_comparison(param, &index_names, &earlier_field_names)?;
+                earlier_field_names.push(&param.name.name);
+                comparison
+            };
+            if let FieldComparison::Skipped = comparison {
+                a_names.push(underscore(span));
+                b_names.push(underscore(span));
+                continue;
+            }
+            let a_name = fresh(&format!("a_{}", param.name.name.src_str()));
+            let b_name = fresh(&format!("b_{}", param.name.name.src_str()));
+            comparisons.push(self.get_comparison_expression(comparison, &a_name, &b_name));
+            a_names.push(a_name);
+            b_names.push(b_name);
+        }
+
+        let inner_cases = self
+            .type_statement
+            .variants
+            .iter()
+            .map(|other_variant| {
+                if other_variant.name.name == variant.name.name {
+                    ub::MatchCase {
+                        span,
+                        variant_name: variant.name.clone(),
+                        params: get_case_params(variant, Some(b_names.clone()), span),
+                        output: ub::MatchCaseOutput::Some(self.conjoin(comparisons.clone())),
+                    }
+                } else {
+                    ub::MatchCase {
+                        span,
+                        variant_name: other_variant.name.clone(),
+                        params: get_case_params(other_variant, None, span),
+                        output: ub::MatchCaseOutput::Some(
+                            self.get_bool_variant(&self.designated_bool.false_variant_name),
+                        ),
+                    }
+                }
+            })
+            .collect();
+        Ok(ub::MatchCase {
+            span,
+            variant_name: variant.name.clone(),
+            params: get_case_params(variant, Some(a_names), span),
+            output: ub::MatchCaseOutput::Some(ub::Expression::Match(Box::new(ub::Match {
+                span,
+                matchee: name_expression(vec![self.b.clone()]),
+                motive: None,
+                cases: inner_cases,
+            }))),
+        })
+    }
+
+    /// Returns the names of the variant params that the variant's
+    /// return type passes as the type's args (e.g., `T` in `List(T)`).
+    fn get_index_names(&self, variant: &ub::Variant) -> Result<Vec<IdentifierName>, BindError> {
+        let variant_param_names: Vec<IdentifierName> = variant_params_as_unlabeled(variant)
+            .into_iter()
+            .map(|param| param.name.name)
+            .collect();
+        let index_names = self
+            .get_type_args_as_names(&variant.return_type)
+            .filter(|index_names| {
+                index_names.iter().enumerate().all(|(i, name)| {
+                    variant_param_names.contains(name) && !index_names[..i].contains(name)
+                })
+            });
+        index_names.ok_or_else(|| {
+            CannotDeriveEqForIndexedVariantError {
+                derive: self.derive.clone(),
+                variant_name: variant.name.clone(),
+                return_type: variant.return_type.clone(),
+            }
+            .into()
+        })
+    }
+
+    /// If `type_` is the type applied to single-component names,
+    /// returns those names (ordered like the type params).
+    /// Otherwise, returns `None`.
+    fn get_type_args_as_names(&self, type_: &ub::Expression) -> Option<Vec<IdentifierName>> {
+        let is_type_name = |expression: &ub::Expression| {
+            get_single_component_name(expression) == Some(&self.type_statement.name.name)
+        };
+        match (&self.type_statement.params, type_) {
+            (None, type_) if is_type_name(type_) => Some(vec![]),
+            (Some(ub::NonEmptyParamVec::Unlabeled(params)), ub::Expression::Call(call))
+                if is_type_name(&call.callee) =>
+            {
+                let ub::NonEmptyCallArgVec::Unlabeled(args) = &call.args else {
+                    return None;
+                };
+                if args.len() != params.len() {
+                    return None;
+                }
+                args.iter()
+                    .map(|arg| get_single_component_name(arg).cloned())
+                    .collect()
+            }
+            (Some(ub::NonEmptyParamVec::UniquelyLabeled(params)), ub::Expression::Call(call))
+                if is_type_name(&call.callee) =>
+            {
+                let ub::NonEmptyCallArgVec::UniquelyLabeled(args) = &call.args else {
+                    return None;
+                };
+                if args.len() != params.len() {
+                    return None;
+                }
+                params
+                    .iter()
+                    .map(|param| {
+                        let label = match &param.label {
+                            ub::ParamLabel::Implicit => &param.name.name,
+                            ub::ParamLabel::Explicit(label) => &label.name,
+                        };
+                        args.iter().find_map(|arg| match arg {
+                            ub::LabeledCallArg::Implicit(value) if &value.name == label => {
+                                Some(value.name.clone())
+                            }
+                            ub::LabeledCallArg::Explicit(arg_label, value)
+                                if &arg_label.name == label =>
+                            {
+                                get_single_component_name(value).cloned()
+                            }
+                            _ => None,
+                        })
+                    })
+                    .collect()
+            }
+            _ => None,
+        }
+    }
+
+    fn get_field_comparison(
+        &self,
+        field: &ub::UnlabeledParam,
+        index_names: &[IdentifierName],
+        earlier_field_names: &[&IdentifierName],
+    ) -> Result<FieldComparison, BindError> {
+        // The types of two such fields may differ
+        // (e.g., `v: Vec(n)` in two values with different `n`s),
+        // so we cannot compare them.
+        if mentions_any_name(&field.type_, earlier_field_names) {
+            return Err(self.get_field_error(field));
+        }
+
+        if let Some(type_param_index) = get_single_component_name(&field.type_)
+            .and_then(|name| index_names.iter().position(|index_name| index_name == name))
+        {
+            return if self.element_eq_names[type_param_index].is_some() {
+                Ok(FieldComparison::ElementEq(type_param_index))
+            } else {
+                Err(self.get_field_error(field))
+            };
+        }
+
+        if self.get_type_args_as_names(&field.type_).as_deref() == Some(index_names) {
+            return Ok(FieldComparison::Recursive);
+        }
+
+        match &field.type_ {
+            ub::Expression::Name(name)
+                if !is_universe(&field.type_)
+                    && !index_names.contains(&name.components.first().name) =>
+            {
+                Ok(FieldComparison::OtherTypeEq(name.clone()))
+            }
+            _ => Err(self.get_field_error(field)),
+        }
+    }
+
+    fn get_field_error(&self, field: &ub::UnlabeledParam) -> BindError {
+        CannotDeriveEqForFieldError {
+            derive: self.derive.clone(),
+            field_name: field.name.clone(),
+            field_type: field.type_.clone(),
+        }
+        .into()
+    }
+
+    fn get_comparison_expression(
+        &self,
+        comparison: FieldComparison,
+        a_name: &ub::Identifier,
+        b_name: &ub::Identifier,
+    ) -> ub::Expression {
+        let a_arg = name_expression(vec![a_name.clone()]);
+        let b_arg = name_expression(vec![b_name.clone()]);
+        let (callee, args) = match comparison {
+            FieldComparison::ElementEq(type_param_index) => {
+                let eq_name = self.element_eq_names[type_param_index]
+                    .clone()
+                    .expect("type param should have an element eq");
+                (name_expression(vec![eq_name]), vec![a_arg, b_arg])
+            }
+            FieldComparison::Recursive => {
+                let type_args = self
+                    .type_params
+                    .iter()
+                    .map(|param| name_expression(vec![param.name.clone()]));
+                let element_eq_args = self
+                    .element_eq_names
+                    .iter()
+                    .flatten()
+                    .map(|eq_name| name_expression(vec![eq_name.clone()]));
+                let args = type_args
+                    .chain([a_arg, b_arg])
+                    .chain(element_eq_args)
+                    .collect();
+                (name_expression(vec![self.fun_name.clone()]), args)
+            }
+            FieldComparison::OtherTypeEq(type_name) => {
+                let eq_component = ub::Identifier {
+                    span: type_name.span,
+                    name: IdentifierName::new("eq".to_string()),
+                };
+                let components = type_name
+                    .components
+                    .iter()
+                    .cloned()
+                    .chain(std::iter::once(eq_component))
+                    .collect();
+                (name_expression(components), vec![a_arg, b_arg])
+            }
+            FieldComparison::Skipped => unreachable!("skipped fields should not be compared"),
+        };
+        ub::Expression::Call(Box::new(ub::Call {
+            span: self.derive.span,
+            callee,
+            args: ub::NonEmptyCallArgVec::Unlabeled(
+                NonEmptyVec::try_from(args).expect("there should be at least two args"),
+            ),
+        }))
+    }
+
+    /// Returns an expression that is `true` if and only if
+    /// every comparison is `true`.
+    fn conjoin(&self, comparisons: Vec<ub::Expression>) -> ub::Expression {
+        let span = self.derive.span;
+        let true_name = &self.designated_bool.true_variant_name;
+        let false_name = &self.designated_bool.false_variant_name;
+        comparisons
+            .into_iter()
+            .rev()
+            .reduce(|rest, comparison| {
+                ub::Expression::Match(Box::new(ub::Match {
+                    span,
+                    matchee: comparison,
+                    motive: None,
+                    cases: vec![
+                        ub::MatchCase {
+                            span,
+                            variant_name: ub::Identifier {
+                                span,
+                                name: true_name.clone(),
+                            },
+                            params: None,
+                            output: ub::MatchCaseOutput::Some(rest),
+                        },
+                        ub::MatchCase {
+                            span,
+                            variant_name: ub::Identifier {
+                                span,
+                                name: false_name.clone(),
+                            },
+                            params: None,
+                            output: ub::MatchCaseOutput::Some(self.get_bool_variant(false_name)),
+                        },
+                    ],
+                }))
+            })
+            .unwrap_or_else(|| self.get_bool_variant(true_name))
+    }
+
+    fn get_bool_type(&self) -> ub::Expression {
+        let components = self
+            .designated_bool
+            .type_name_components
+            .iter()
+            .map(|name| ub::Identifier {
+                span: self.derive.span,
+                name: name.clone(),
+            })
+            .collect();
+        name_expression(components)
+    }
+
+    fn get_bool_variant(&self, variant_name: &IdentifierName) -> ub::Expression {
+        let components = self
+            .designated_bool
+            .type_name_components
+            .iter()
+            .chain(std::iter::once(variant_name))
+            .map(|name| ub::Identifier {
+                span: self.derive.span,
+                name: name.clone(),
+            })
+            .collect();
+        name_expression(components)
+    }
+}
+
+/// If `names` is `None`, every param is ignored.
+fn get_case_params(
+    variant: &ub::Variant,
+    names: Option<Vec<ub::Identifier>>,
+    span: TextSpan,
+) -> Option<ub::NonEmptyMatchCaseParamVec> {
+    match &variant.params {
+        None => None,
+        Some(ub::NonEmptyParamVec::Unlabeled(params)) => {
+            let names = names.unwrap_or_else(|| vec![underscore(span); params.len()]);
+            Some(ub::NonEmptyMatchCaseParamVec::Unlabeled(
+                NonEmptyVec::try_from(names).expect("there should be one name per param"),
+            ))
+        }
+        Some(ub::NonEmptyParamVec::UniquelyLabeled(params)) => {
+            let Some(names) = names else {
+                return Some(ub::NonEmptyMatchCaseParamVec::UniquelyLabeled {
+                    params: None,
+                    triple_dot: Some(span),
+                });
+            };
+            let params = params
+                .iter()
+                .zip(names)
+                .map(|(param, name)| ub::LabeledMatchCaseParam {
+                    span,
+                    label: ub::ParamLabel::Explicit(match &param.label {
+                        ub::ParamLabel::Implicit => param.name.clone(),
+                        ub::ParamLabel::Explicit(label) => label.clone(),
+                    }),
+                    name,
+                })
+                .collect::<Vec<_>>();
+            Some(ub::NonEmptyMatchCaseParamVec::UniquelyLabeled {
+                params: Some(
+                    NonEmptyVec::try_from(params).expect("there should be one name per param"),
+                ),
+                triple_dot: None,
+            })
+        }
+    }
+}
+
+fn type_params_as_unlabeled(type_statement: &ub::TypeStatement) -> Vec<ub::UnlabeledParam> {
+    params_as_unlabeled(type_statement.params.as_ref())
+        .into_iter()
+        .map(|param| ub::UnlabeledParam {
+            is_dashed: false,
+            ..param
+        })
+        .collect()
+}
+
+fn variant_params_as_unlabeled(variant: &ub::Variant) -> Vec<ub::UnlabeledParam> {
+    params_as_unlabeled(variant.params.as_ref())
+}
+
+fn params_as_unlabeled(params: Option<&ub::NonEmptyParamVec>) -> Vec<ub::UnlabeledParam> {
+    match params {
+        None => vec![],
+        Some(ub::NonEmptyParamVec::Unlabeled(params)) => params.to_vec(),
+        Some(ub::NonEmptyParamVec::UniquelyLabeled(params)) => params
+            .iter()
+            .map(|param| ub::UnlabeledParam {
+                span: param.span,
+                is_instance: param.is_instance,
+                is_dashed: param.is_dashed,
+                name: param.name.clone(),
+                type_: param.type_.clone(),
+            })
+            .collect(),
+    }
+}
+
+fn get_names_declared_by_type_statement(type_statement: &ub::TypeStatement) -> Vec<IdentifierName> {
+    let type_param_names = type_params_as_unlabeled(type_statement)
+        .into_iter()
+        .map(|param| param.name.name);
+    let variant_names = type_statement.variants.iter().flat_map(|variant| {
+        std::iter::once(variant.name.name.clone()).chain(
+            variant_params_as_unlabeled(variant)
+                .into_iter()
+                .map(|param| param.name.name),
+        )
+    });
+    std::iter::once(type_statement.name.name.clone())
+        .chain(type_param_names)
+        .chain(variant_names)
+        .collect()
+}
+
+/// Returns a name that starts with `base`, and that collides neither
+/// with `taken` nor with any name that is already in scope.
+fn get_fresh_name(context: &Context, taken: &[IdentifierName], base: &str) -> IdentifierName {
+    let mut candidate = base.to_string();
+    loop {
+        let name = IdentifierName::new(candidate.clone());
+        let is_taken = taken.contains(&name) || context.lookup_name(std::iter::once(&name)).is_ok();
+        if !is_taken {
+            return name;
+        }
+        candidate.push('\'');
+    }
+}
+
+/// Returns `true` if `expression` mentions any of `names`.
+fn mentions_any_name(expression: &ub::Expression, names: &[&IdentifierName]) -> bool {
+    // Replacing a mentioned name changes the expression,
+    // so we can reuse the struct desugaring's replacement.
+    let placeholder = underscore(expression.span());
+    replace_field_names_with_projections(expression.clone(), names, &placeholder) != *expression
+}
+
+fn get_single_component_name(expression: &ub::Expression) -> Option<&IdentifierName> {
+    match expression {
+        ub::Expression::Name(name) if name.components.len() == 1 => {
+            Some(&name.components.first().name)
+        }
+        _ => None,
+    }
+}
+
+fn is_universe(expression: &ub::Expression) -> bool {
+    matches!(
+        get_single_component_name(expression),
+        Some(IdentifierName::Reserved(
+            ReservedIdentifierName::TypeTitleCase | ReservedIdentifierName::NumberedType(_)
+        ))
+    )
+}
+
+fn underscore(span: TextSpan) -> ub::Identifier {
+    ub::Identifier {
+        span,
+        name: IdentifierName::Reserved(ReservedIdentifierName::Underscore),
+    }
+}
@@ -0,0 +1,400 @@
+use super::*;
+
+/// Returns the type statement that declares `struct_`'s type
+/// and its single constructor, `new`.
+///
+/// Every struct param becomes a labeled constructor param
+/// (unlabeled params become implicitly labeled),
+/// followed by the fields, which are also implicitly labeled.
+pub fn get_struct_type_statement(struct_: &ub::StructStatement) -> ub::TypeStatement {
+    let struct_params = struct_params_as_labeled(struct_);
+    let field_params = struct_.fields.iter().map(|field| ub::LabeledParam {
+        span: field.span,
+        is_instance: false,
+        label: ub::ParamLabel::Implicit,
+        is_dashed: false,
+        name: field.name.clone(),
+        type_: field.type_.clone(),
+        default: None,
+    });
+    let constructor_params: Vec<ub::LabeledParam> =
+        struct_params.into_iter().chain(field_params).collect();
+    let constructor = ub::Variant {
+        span: struct_.span,
+        name: ub::Identifier {
+            span: struct_.name.span,
+            name: constructor_name(),
+        },
+        params: NonEmptyVec::try_from(constructor_params)
+            .ok()
+            .map(ub::NonEmptyParamVec::UniquelyLabeled),
+        return_type: get_struct_type_expression(struct_),
+    };
+    ub::TypeStatement {
+        span: struct_.span,
+        visibility: struct_.visibility.clone(),
+        name: struct_.name.clone(),
+        params: struct_.params.clone(),
+        derives: vec![],
+        variants: vec![constructor],
+    }
+}
+
+pub fn constructor_name() -> IdentifierName {
+    IdentifierName::new("new".to_string())
+}
+
+fn struct_params_as_labeled(struct_: &ub::StructStatement) -> Vec<ub::LabeledParam> {
+    match &struct_.params {
+        None => vec![],
+        Some(ub::NonEmptyParamVec::Unlabeled(params)) => params
+            .iter()
+            .map(|param| ub::LabeledParam {
+                span: param.span,
+                is_instance: param.is_instance,
+                label: ub::ParamLabel::Implicit,
+                is_dashed: param.is_dashed,
+                name: param.name.clone(),
+                type_: param.type_.clone(),
+                default: None,
+            })
+            .collect(),
+        Some(ub::NonEmptyParamVec::UniquelyLabeled(params)) => params.to_vec(),
+    }
+}
+
+fn get_struct_type_expression(struct_: &ub::StructStatement) -> ub::Expression {
+    get_type_expression(&struct_.name, struct_.params.as_ref(), struct_.span)
+}
+
+/// Returns `Name` if the type has no params,
+/// and `Name(T1, ...)` (or `Name(label1: T1, ...)`) otherwise.
+pub fn get_type_expression(
+    type_name: &ub::Identifier,
+    params: Option<&ub::NonEmptyParamVec>,
+    span: TextSpan,
+) -> ub::Expression {
+    let callee = name_expression(vec![type_name.clone()]);
+    let Some(params) = params else {
+        return callee;
+    };
+    let args = match params {
+        ub::NonEmptyParamVec::Unlabeled(params) => ub::NonEmptyCallArgVec::Unlabeled(
+            params
+                .clone()
+                .into_mapped(|param| name_expression(vec![param.name.clone()])),
+        ),
+        ub::NonEmptyParamVec::UniquelyLabeled(params) => {
+            ub::NonEmptyCallArgVec::UniquelyLabeled(params.clone().into_mapped(|param| {
+                let label = match &param.label {
+                    ub::ParamLabel::Implicit => param.name.clone(),
+                    ub::ParamLabel::Explicit(label) => label.clone(),
+                };
+                ub::LabeledCallArg::Explicit(label, name_expression(vec![param.name.clone()]))
+            }))
+        }
+    };
+    ub::Expression::Call(Box::new(ub::Call { span, callee, args }))
+}
+
+/// Returns the projection function for the field at `field_index`:
+///
+/// ```text
+/// let field = fun field(<struct params>, record: Name(<struct params>)): T { record.field };
+/// ```
+///
+/// where `T` is the field's type, with every mention of an
+/// earlier field `f` replaced by `record.f`.
+/// If the struct is a trait, `record` is an instance param,
+/// so the instance can be supplied implicitly (e.g., `Eq.eq(Nat)`).
+pub fn get_struct_projection_let_statement(
+    struct_: &ub::StructStatement,
+    field_index: usize,
+    record_name: &IdentifierName,
+) -> ub::LetStatement {
+    let field = &struct_.fields[field_index];
+    let record = ub::Identifier {
+        span: field.span,
+        name: record_name.clone(),
+    };
+    let record_type = get_struct_type_expression(struct_);
+    let params = match &struct_.params {
+        None => ub::NonEmptyParamVec::Unlabeled(NonEmptyVec::singleton(ub::UnlabeledParam {
+            span: field.span,
+            is_instance: struct_.is_trait,
+            is_dashed: false,
+            name: record.clone(),
+            type_: record_type,
+        })),
+        Some(ub::NonEmptyParamVec::Unlabeled(params)) => {
+            ub::NonEmptyParamVec::Unlabeled(NonEmptyVec::from_pushed(
+                params.to_vec(),
+                ub::UnlabeledParam {
+                    span: field.span,
+                    is_instance: struct_.is_trait,
+                    is_dashed: false,
+                    name: record.clone(),
+                    type_: record_type,
+                },
+            ))
+        }
+        Some(ub::NonEmptyParamVec::UniquelyLabeled(params)) => {
+            ub::NonEmptyParamVec::UniquelyLabeled(NonEmptyVec::from_pushed(
+                params.to_vec(),
+                ub::LabeledParam {
+                    span: field.span,
+                    is_instance: struct_.is_trait,
+                    label: ub::ParamLabel::Implicit,
+                    is_dashed: false,
+                    name: record.clone(),
+                    type_: record_type,
+                    default: None,
+                },
+            ))
+        }
+    };
+    let earlier_field_names: Vec<&IdentifierName> = struct_.fields[..field_index]
+        .iter()
+        .map(|field| &field.name.name)
+        .collect();
+    let return_type =
+        replace_field_names_with_projections(field.type_.clone(), &earlier_field_names, &record);
+    let body = name_expression(vec![record.clone(), field.name.clone()]);
+    ub::LetStatement {
+        span: field.span,
+        visibility: struct_.visibility.clone(),
+        transparency: None,
+        name: field.name.clone(),
+        value: ub::Expression::Fun(Box::new(ub::Fun {
+            span: field.span,
+            name: field.name.clone(),
+            params,
+            return_type,
+            body,
+        })),
+        is_instance: false,
+    }
+}
+
+/// Returns a name for the projection functions' record param
+/// that does not collide with the struct's name, params, or fields,
+/// nor with any name that is already in scope.
+pub fn get_fresh_record_name(context: &Context, struct_: &ub::StructStatement) -> IdentifierName {
+    let param_names: Vec<IdentifierName> = struct_params_as_labeled(struct_)
+        .into_iter()
+        .map(|param| param.name.name)
+        .collect();
+    let mut taken: Vec<&IdentifierName> = vec![&struct_.name.name];
+    taken.extend(param_names.iter());
+    taken.extend(struct_.fields.iter().map(|field| &field.name.name));
+
+    let mut candidate = "record".to_string();
+    loop {
+        let name = IdentifierName::new(candidate.clone());
+        let is_taken =
+            taken.contains(&&name) || context.lookup_name(std::iter::once(&name)).is_ok();
+        if !is_taken {
+            return name;
+        }
+        candidate.push('\'');
+    }
+}
+
+pub fn name_expression(components: Vec<ub::Identifier>) -> ub::Expression {
+    let components =
+        NonEmptyVec::try_from(components).expect("name should have at least one component");
+    ub::Expression::Name(ub::NameExpression {
+        span: components
+            .first()
+            .span
+            .inclusive_merge(components.last().span),
+        components,
+    })
+}
+
+pub fn replace_field_names_with_projections(
+    expression: ub::Expression,
+    field_names: &[&IdentifierName],
+    record: &ub::Identifier,
+) -> ub::Expression {
+    let replace =
+        |expression| replace_field_names_with_projections(expression, field_names, record);
+    let replace_params = |params| replace_in_params(params, field_names, record);
+    match expression {
+        ub::Expression::Name(name) => {
+            if !field_names.contains(&&name.components.first().name) {
+                return ub::Expression::Name(name);
+            }
+            let components = std::iter::once(record.clone())
+                .chain(name.components.to_vec())
+                .collect::<Vec<_>>();
+            ub::Expression::Name(ub::NameExpression {
+                span: name.span,
+                components: NonEmptyVec::try_from(components)
+                    .expect("components should be non-empty"),
+            })
+        }
+        ub::Expression::Todo(span) => ub::Expression::Todo(span),
+        ub::Expression::Call(call) => {
+            let call = *call;
+            let args = match call.args {
+                ub::NonEmptyCallArgVec::Unlabeled(args) => {
+                    ub::NonEmptyCallArgVec::Unlabeled(args.into_mapped(replace))
+                }
+                ub::NonEmptyCallArgVec::UniquelyLabeled(args) => {
+                    ub::NonEmptyCallArgVec::UniquelyLabeled(args.into_mapped(|arg| match arg {
+                        ub::LabeledCallArg::Implicit(value)
+                            if field_names.contains(&&value.name) =>
+                        {
+                            let replaced = replace(name_expression(vec![value.clone()]));
+                            ub::LabeledCallArg::Explicit(value, replaced)
+                        }
+                        ub::LabeledCallArg::Implicit(value) => ub::LabeledCallArg::Implicit(value),
+                        ub::LabeledCallArg::Explicit(label, value) => {
+                            ub::LabeledCallArg::Explicit(label, replace(value))
+                        }
+                    }))
+                }
+            };
+            ub::Expression::Call(Box::new(ub::Call {
+                span: call.span,
+                callee: replace(call.callee),
+                args,
+            }))
+        }
+        ub::Expression::Fun(fun) => {
+            let fun = *fun;
+            ub::Expression::Fun(Box::new(ub::Fun {
+                span: fun.span,
+                name: fun.name,
+                params: replace_params(fun.params),
+                return_type: replace(fun.return_type),
+                body: replace(fun.body),
+            }))
+        }
+        ub::Expression::Match(match_) => {
+            let match_ = *match_;
+            ub::Expression::Match(Box::new(ub::Match {
+                span: match_.span,
+                matchee: replace(match_.matchee),
+                motive: match_.motive.map(|motive| ub::MatchMotive {
+                    output: replace(motive.output),
+                    ..motive
+                }),
+                cases: match_
+                    .cases
+                    .into_iter()
+                    .map(|case| ub::MatchCase {
+                        output: match case.output {
+                            ub::MatchCaseOutput::Some(output) => {
+                                ub::MatchCaseOutput::Some(replace(output))
+                            }
+                            ub::MatchCaseOutput::ImpossibilityClaim(span) => {
+                                ub::MatchCaseOutput::ImpossibilityClaim(span)
+                            }
+                        },
+                        ..case
+                    })
+                    .collect(),
+            }))
+        }
+        ub::Expression::Forall(forall) => {
+            let forall = *forall;
+            ub::Expression::Forall(Box::new(ub::Forall {
+                span: forall.span,
+                params: replace_params(forall.params),
+                output: replace(forall.output),
+            }))
+        }
+        ub::Expression::Exists(exists) => {
+            let exists = *exists;
+            ub::Expression::Exists(Box::new(ub::Exists {
+                span: exists.span,
+                param: ub::UnlabeledParam {
+                    type_: replace(exists.param.type_),
+                    ..exists.param
+                },
+                output: replace(exists.output),
+            }))
+        }
+        ub::Expression::Pair(pair) => {
+            let pair = *pair;
+            ub::Expression::Pair(Box::new(ub::Pair {
+                span: pair.span,
+                witness: replace(pair.witness),
+                proof: replace(pair.proof),
+            }))
+        }
+        ub::Expression::InfixChain(chain) => {
+            let chain = *chain;
+            let replace_operand = |operand: ub::InfixOperand| ub::InfixOperand {
+                expression: replace(operand.expression),
+                ..operand
+            };
+            ub::Expression::InfixChain(Box::new(ub::InfixChain {
+                span: chain.span,
+                first_operand: replace_operand(chain.first_operand),
+                rest: chain.rest.into_mapped(|link| ub::InfixChainLink {
+                    operator: link.operator,
+                    operand: replace_operand(link.operand),
+                }),
+            }))
+        }
+        ub::Expression::Check(check) => {
+            let check = *check;
+            ub::Expression::Check(Box::new(ub::Check {
+                span: check.span,
+                assertions: check
+                    .assertions
+                    .into_mapped(|assertion| ub::CheckAssertion {
+                        span: assertion.span,
+                        kind: assertion.kind,
+                        left: match assertion.left {
+                            ub::GoalKwOrExpression::Expression(expression) => {
+                                ub::GoalKwOrExpression::Expression(replace(expression))
+                            }
+                            goal_kw => goal_kw,
+                        },
+                        right: match assertion.right {
+                            ub::QuestionMarkOrExpression::Expression(expression) => {
+                                ub::QuestionMarkOrExpression::Expression(replace(expression))
+                            }
+                            question_mark => question_mark,
+                        },
+                    }),
+                output: replace(check.output),
+            }))
+        }
+        ub::Expression::Rewrite(rewrite) => {
+            let rewrite = *rewrite;
+            ub::Expression::Rewrite(Box::new(ub::Rewrite {
+                span: rewrite.span,
+                proof: replace(rewrite.proof),
+                body: replace(rewrite.body),
+            }))
+        }
+    }
+}
+
+fn replace_in_params(
+    params: ub::NonEmptyParamVec,
+    field_names: &[&IdentifierName],
+    record: &ub::Identifier,
+) -> ub::NonEmptyParamVec {
+    let replace =
+        |expression| replace_field_names_with_projections(expression, field_names, record);
+    match params {
+        ub::NonEmptyParamVec::Unlabeled(params) => {
+            ub::NonEmptyParamVec::Unlabeled(params.into_mapped(|param| ub::UnlabeledParam {
+                type_: replace(param.type_),
+                ..param
+            }))
+        }
+        ub::NonEmptyParamVec::UniquelyLabeled(params) => {
+            ub::NonEmptyParamVec::UniquelyLabeled(params.into_mapped(|param| ub::LabeledParam {
+                type_: replace(param.type_),
+                ..param
+            }))
+        }
+    }
+}
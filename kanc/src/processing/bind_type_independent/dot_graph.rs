@@ -21,7 +21,22 @@ pub struct DotGraphEntry {
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
 pub enum DotGraphNode {
     LeafItem(DbLevel),
+    /// The universe at the given universe level.
+    Universe(usize),
     Mod(FileId),
+    /// A notation, which refers to `function`.
+    Notation {
+        function: NotationFunction,
+        precedence: ub::NotationPrecedence,
+        associativity: ub::NotationAssociativity,
+    },
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum NotationFunction {
+    LeafItem(DbLevel),
+    /// The universe at the given universe level.
+    Universe(usize),
 }
 
 impl DotGraph {
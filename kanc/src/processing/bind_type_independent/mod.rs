@@ -14,6 +14,12 @@ pub use crate::data::bind_error::*;
 use context::*;
 mod context;
 
+use desugar_derive::*;
+mod desugar_derive;
+
+use desugar_struct::*;
+mod desugar_struct;
+
 use dot_graph::*;
 mod dot_graph;
 
@@ -28,15 +34,55 @@ struct State<'a> {
     file_tree: &'a FileTree,
 }
 
+#[derive(Clone, Debug, Default)]
+pub struct BindOptions {
+    /// The equality type that `rewrite` expressions rewrite along.
+    /// If this is `None`, `rewrite` expressions are illegal.
+    pub designated_eq: Option<DesignatedEq>,
+    /// The boolean type that derived `eq` functions return.
+    /// If this is `None`, `derive(eq)` is illegal.
+    pub designated_bool: Option<DesignatedBool>,
+}
+
+/// An equality type (and its reflexivity variant)
+/// designated by a pack's `pack.yscl`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct DesignatedEq {
+    /// The name of the type, starting from the pack root
+    /// (e.g., `pack.eq.Eq`).
+    pub type_name_components: NonEmptyVec<IdentifierName>,
+    pub refl_variant_name: IdentifierName,
+}
+
+/// A boolean type (and its two variants)
+/// designated by a pack's `pack.yscl`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct DesignatedBool {
+    /// The name of the type, starting from the pack root
+    /// (e.g., `pack.bool.Bool`).
+    pub type_name_components: NonEmptyVec<IdentifierName>,
+    pub true_variant_name: IdentifierName,
+    pub false_variant_name: IdentifierName,
+}
+
 pub fn bind_files(
+    root_id: FileId,
+    files: Vec<ub::File>,
+    file_tree: &FileTree,
+) -> Result<Vec<FileItem>, BindError> {
+    bind_files_with_options(root_id, files, file_tree, BindOptions::default())
+}
+
+pub fn bind_files_with_options(
     root_id: FileId,
     mut files: Vec<ub::File>,
     file_tree: &FileTree,
+    options: BindOptions,
 ) -> Result<Vec<FileItem>, BindError> {
     let root_file = remove_file_with_id_or_panic(&mut files, root_id);
     let mut state = State {
         out: vec![],
-        context_data: ContextData::with_builtins(file_tree),
+        context_data: ContextData::with_builtins(file_tree, options),
         unchecked_files: files,
         file_tree,
     };
@@ -75,7 +121,11 @@ fn add_items_from_file_item(
         }
         ub::FileItem::Mod(item) => add_mod_to_context(state, item, item_file_id),
         ub::FileItem::Type(item) => add_item_from_type_statement(state, item, item_file_id),
+        ub::FileItem::Struct(item) => add_items_from_struct_statement(state, item, item_file_id),
         ub::FileItem::Let(item) => add_item_from_let_statement(state, item, item_file_id),
+        ub::FileItem::Notation(item) => {
+            add_notation_to_context(&mut state.context_data, item, item_file_id)
+        }
     }
 }
 
@@ -311,7 +361,9 @@ fn get_mod_scope(
             let entry = lookup_name(context, name_components.iter())?;
             match entry.node {
                 DotGraphNode::Mod(mod_id) => Ok(ModScope::Mod(mod_id)),
-                DotGraphNode::LeafItem(_) => Err(BindError::ExpectedModButNameRefersToTerm(
+                DotGraphNode::LeafItem(_)
+                | DotGraphNode::Universe(_)
+                | DotGraphNode::Notation { .. } => Err(BindError::ExpectedModButNameRefersToTerm(
                     ExpectedModButNameRefersToTermError { name_components },
                 )),
             }
@@ -319,6 +371,54 @@ fn get_mod_scope(
     }
 }
 
+/// A notation is added to its mod under its operator's name (e.g., `+`),
+/// so it can be imported and exported like any other item.
+/// Like a `use` statement, a notation cannot be more visible
+/// than its function.
+fn add_notation_to_context(
+    context_data: &mut ContextData,
+    item: ub::NotationStatement,
+    item_file_id: FileId,
+) -> Result<(), BindError> {
+    let context = &mut context_data.create_context_for_mod(item_file_id, None);
+    let visibility = get_visibility(context, item.visibility.as_ref())?;
+    let function_entry = lookup_name(context, item.function.components.raw().iter())?;
+    let function = match function_entry.node {
+        DotGraphNode::LeafItem(level) => NotationFunction::LeafItem(level),
+        DotGraphNode::Universe(universe_level) => NotationFunction::Universe(universe_level),
+        DotGraphNode::Notation { function, .. } => function,
+        DotGraphNode::Mod(_) => {
+            return Err(ExpectedTermButNameRefersToModError {
+                name_components: item.function.components.to_vec(),
+            }
+            .into())
+        }
+    };
+    if !context.is_left_at_least_as_permissive_as_right(function_entry.visibility.0, visibility.0) {
+        return Err(BindError::CannotLeakPrivateName(
+            CannotLeakPrivateNameError {
+                name_component: item.function.components.last().clone(),
+                required_visibility: visibility,
+                actual_visibility: function_entry.visibility,
+            },
+        ));
+    }
+    add_dot_edge(
+        context,
+        DotGraphNode::Mod(item_file_id),
+        &item.operator.name,
+        DotGraphNode::Notation {
+            function,
+            precedence: item.precedence,
+            associativity: item.associativity,
+        },
+        &item.operator,
+        visibility,
+        visibility,
+    )?;
+    Ok(())
+}
+
 fn add_mod_to_context(
     state: &mut State,
     item: ub::ModStatement,
@@ -349,6 +449,8 @@ fn add_mod_to_context(
     Ok(())
 }
 
+/// Each derived function is added to the type's namespace
+/// (e.g., `Name.eq`), like a struct's projection functions.
 fn add_item_from_type_statement(
     state: &mut State,
     item: ub::TypeStatement,
@@ -363,8 +465,57 @@ fn add_item_from_type_statement(
     let context = &mut state
         .context_data
         .create_context_for_mod(item_file_id, Some(visibility));
-    let bound = bind_type_statement(context, item)?;
+    let bound = bind_type_statement(context, item.clone())?;
+
+    let derived = item
+        .derives
+        .iter()
+        .map(|derive| {
+            let let_statement = match derive.kind {
+                ub::DeriveKind::Eq => get_derived_eq_let_statement(context, &item, derive)?,
+            };
+            bind_let_in_type_namespace(context, let_statement, &item.name.name, visibility)
+        })
+        .collect::<Result<Vec<_>, BindError>>()?;
+
     state.out.push(FileItem::Type(bound));
+    state.out.extend(derived.into_iter().map(FileItem::Let));
+    Ok(())
+}
+
+/// A struct is desugared into a type with a single variant (`new`),
+/// followed by one projection function per field.
+/// The projection functions are added to the type's namespace
+/// (e.g., `Name.field`), rather than the mod's.
+fn add_items_from_struct_statement(
+    state: &mut State,
+    item: ub::StructStatement,
+    item_file_id: FileId,
+) -> Result<(), BindError> {
+    let visibility = get_visibility(
+        &mut state
+            .context_data
+            .create_context_for_mod(item_file_id, None),
+        item.visibility.as_ref(),
+    )?;
+    let context = &mut state
+        .context_data
+        .create_context_for_mod(item_file_id, Some(visibility));
+
+    let mut type_statement = bind_type_statement(context, get_struct_type_statement(&item))?;
+    type_statement.is_struct = true;
+    type_statement.is_trait = item.is_trait;
+
+    let record_name = get_fresh_record_name(context, &item);
+    let projections = (0..item.fields.len())
+        .map(|field_index| {
+            let projection = get_struct_projection_let_statement(&item, field_index, &record_name);
+            bind_let_in_type_namespace(context, projection, &item.name.name, visibility)
+        })
+        .collect::<Result<Vec<_>, BindError>>()?;
+
+    state.out.push(FileItem::Type(type_statement));
+    state.out.extend(projections.into_iter().map(FileItem::Let));
     Ok(())
 }
 
@@ -414,6 +565,7 @@ fn bind_type_statement_dirty(
 
     let visibility = get_visibility(context, type_statement.visibility.as_ref())?;
     let type_name = create_name_and_add_to_mod(context, type_statement.name, visibility)?;
+    context.mark_as_type(DbLevel(context.len() - 1));
 
     let variants = type_statement
         .variants
@@ -429,6 +581,8 @@ fn bind_type_statement_dirty(
         name: type_name,
         params,
         variants,
+        is_struct: false,
+        is_trait: false,
     })
 }
 
@@ -470,6 +624,7 @@ fn bind_unlabeled_param_dirty(
     let name = create_local_name_and_add_to_scope(context, param.name)?;
     Ok(UnlabeledParam {
         span: Some(param.span),
+        is_instance: param.is_instance,
         is_dashed: param.is_dashed,
         name,
         type_,
@@ -488,13 +643,19 @@ fn bind_labeled_param_dirty(
     param: ub::LabeledParam,
 ) -> Result<LabeledParam, BindError> {
     let type_ = bind_expression(context, param.type_)?;
+    let default = param
+        .default
+        .map(|default| bind_expression(context, default))
+        .transpose()?;
     let name = create_local_name_and_add_to_scope(context, param.name)?;
     Ok(LabeledParam {
         span: Some(param.span),
+        is_instance: param.is_instance,
         label: param.label.into(),
         is_dashed: param.is_dashed,
         name,
         type_,
+        default,
     })
 }
 
@@ -572,6 +733,58 @@ fn bind_let_statement_dirty(
         transparency,
         name,
         value,
+        is_instance: let_statement.is_instance,
+    })
+}
+
+/// Binds a let statement generated for a type
+/// (e.g., a struct projection or a derived function),
+/// and adds it to the type's namespace.
+fn bind_let_in_type_namespace(
+    context: &mut Context,
+    let_statement: ub::LetStatement,
+    type_name: &IdentifierName,
+    type_visibility: Visibility,
+) -> Result<LetStatement, BindError> {
+    untaint_err(
+        context,
+        (let_statement, type_name, type_visibility),
+        bind_let_in_type_namespace_and_add_dot_target_dirty,
+    )
+}
+
+fn bind_let_in_type_namespace_and_add_dot_target_dirty(
+    context: &mut Context,
+    (let_statement, type_name, type_visibility): (ub::LetStatement, &IdentifierName, Visibility),
+) -> Result<LetStatement, BindError> {
+    let value = bind_expression(context, let_statement.value)?;
+
+    let unbound_let_name = let_statement.name;
+    let name = unbound_let_name.clone().into();
+
+    let type_db_index = context
+        .get_db_index(std::iter::once(type_name))
+        .expect("type_name should already be in the context.");
+    let type_db_level = context.index_to_level(type_db_index);
+    let let_db_level = context.push_placeholder();
+
+    add_dot_edge(
+        context,
+        DotGraphNode::LeafItem(type_db_level),
+        &unbound_let_name.name,
+        DotGraphNode::LeafItem(let_db_level),
+        &unbound_let_name,
+        type_visibility,
+        type_visibility,
+    )?;
+
+    Ok(LetStatement {
+        span: Some(let_statement.span),
+        visibility: type_visibility,
+        transparency: Transparency(type_visibility.0),
+        name,
+        value,
+        is_instance: false,
     })
 }
 
@@ -649,7 +862,11 @@ fn bind_expression_dirty(
         ub::Expression::Fun(fun) => bind_fun_dirty(context, *fun),
         ub::Expression::Match(match_) => bind_match_dirty(context, *match_),
         ub::Expression::Forall(forall) => bind_forall_dirty(context, *forall),
+        ub::Expression::Exists(exists) => bind_exists_dirty(context, *exists),
+        ub::Expression::Pair(pair) => bind_pair_dirty(context, *pair),
         ub::Expression::Check(check) => bind_check_dirty(context, *check),
+        ub::Expression::Rewrite(rewrite) => bind_rewrite_dirty(context, *rewrite),
+        ub::Expression::InfixChain(chain) => bind_infix_chain_dirty(context, *chain),
     }
 }
 
@@ -657,6 +874,10 @@ fn bind_name_expression_dirty(
     context: &mut Context,
     name: ub::NameExpression,
 ) -> Result<Expression, BindError> {
+    if let Some(projection) = try_bind_name_expression_as_projection(context, &name) {
+        return Ok(projection);
+    }
+
     let db_index = get_db_index(context, name.components.as_ref().iter())?;
     Ok(Expression::Name(NameExpression {
         span: Some(name.span),
@@ -665,6 +886,58 @@ fn bind_name_expression_dirty(
     }))
 }
 
+/// If a name `x.f.g` cannot be found, but its prefix `x`
+/// refers to a term (e.g., a local) rather than a type or mod,
+/// then we treat the remaining components as field projections
+/// (i.e., `(x.f).g`).
+fn try_bind_name_expression_as_projection(
+    context: &Context,
+    name: &ub::NameExpression,
+) -> Option<Expression> {
+    let err = context
+        .lookup_name(
+            name.components
+                .as_ref()
+                .iter()
+                .map(|component| &component.name),
+        )
+        .err()?;
+    if err.index == 0 || !matches!(err.kind, NameComponentNotAccessibleErrorKind::NotFound) {
+        return None;
+    }
+
+    let (record_components, field_names) = name.components.split_at(err.index);
+    let record_entry = context
+        .lookup_name(record_components.iter().map(|component| &component.name))
+        .ok()?;
+    let DotGraphNode::LeafItem(record_level) = record_entry.node else {
+        return None;
+    };
+    if context.is_type(record_level) || record_entry.def == OwnedSymbolSource::Builtin {
+        return None;
+    }
+
+    let db_index = get_db_index(context, record_components.iter()).ok()?;
+    let record_span = record_components[0]
+        .span
+        .inclusive_merge(record_components[record_components.len() - 1].span);
+    let mut out = Expression::Name(NameExpression {
+        span: Some(record_span),
+        components: NonEmptyVec::try_from(record_components.to_vec())
+            .expect("record_components should be non-empty")
+            .into_mapped(Into::into),
+        db_index,
+    });
+    for field_name in field_names {
+        out = Expression::Projection(Box::new(Projection {
+            span: Some(record_span.inclusive_merge(field_name.span)),
+            record: out,
+            field_name: field_name.clone().into(),
+        }));
+    }
+    Some(out)
+}
+
 fn bind_call_expression_dirty(
     context: &mut Context,
     call: ub::Call,
@@ -708,6 +981,124 @@ fn bind_labeled_call_arg_dirty(
     }
 }
 
+#[derive(Clone, Debug)]
+struct InfixOperator {
+    identifier: ub::Identifier,
+    function: NameExpression,
+    precedence: ub::NotationPrecedence,
+    associativity: ub::NotationAssociativity,
+}
+
+/// Desugars an infix chain into nested calls to its notations' functions
+/// (e.g., `a + b * c` becomes `add(a, mul(b, c))`).
+/// Each call's span covers its two operands.
+fn bind_infix_chain_dirty(
+    context: &mut Context,
+    chain: ub::InfixChain,
+) -> Result<Expression, BindError> {
+    let mut operands = vec![bind_infix_operand_dirty(context, chain.first_operand)?];
+    let mut operators: Vec<InfixOperator> = vec![];
+    for link in chain.rest {
+        let operator = resolve_infix_operator(context, link.operator)?;
+        while let Some(top) = operators.last() {
+            if !should_reduce_infix_operator(top, &operator)? {
+                break;
+            }
+            reduce_infix_operation(&mut operands, &mut operators);
+        }
+        operators.push(operator);
+        operands.push(bind_infix_operand_dirty(context, link.operand)?);
+    }
+    while !operators.is_empty() {
+        reduce_infix_operation(&mut operands, &mut operators);
+    }
+    let (_, out) = operands
+        .pop()
+        .expect("There should be exactly one operand left after reducing every operator.");
+    Ok(out)
+}
+
+fn bind_infix_operand_dirty(
+    context: &mut Context,
+    operand: ub::InfixOperand,
+) -> Result<(TextSpan, Expression), BindError> {
+    Ok((
+        operand.span,
+        bind_expression_dirty(context, operand.expression)?,
+    ))
+}
+
+fn resolve_infix_operator(
+    context: &Context,
+    identifier: ub::Identifier,
+) -> Result<InfixOperator, BindError> {
+    let entry = lookup_name(context, std::iter::once(&identifier))?;
+    let DotGraphNode::Notation {
+        function,
+        precedence,
+        associativity,
+    } = entry.node
+    else {
+        panic!("Impossible: Operator names should only ever refer to notations.");
+    };
+    let function = NameExpression {
+        span: Some(identifier.span),
+        components: NonEmptyVec::singleton(identifier.clone().into()),
+        db_index: context.notation_function_index(function),
+    };
+    Ok(InfixOperator {
+        identifier,
+        function,
+        precedence,
+        associativity,
+    })
+}
+
+/// Returns whether `top` (the operator to the left of `incoming`)
+/// binds its right operand more tightly than `incoming` binds its left operand.
+fn should_reduce_infix_operator(
+    top: &InfixOperator,
+    incoming: &InfixOperator,
+) -> Result<bool, BindError> {
+    use std::cmp::Ordering;
+
+    match top.precedence.cmp(&incoming.precedence) {
+        Ordering::Greater => Ok(true),
+        Ordering::Less => Ok(false),
+        Ordering::Equal => match (top.associativity, incoming.associativity) {
+            (ub::NotationAssociativity::Left, ub::NotationAssociativity::Left) => Ok(true),
+            (ub::NotationAssociativity::Right, ub::NotationAssociativity::Right) => Ok(false),
+            _ => Err(AmbiguousInfixOperatorsError {
+                left_operator: top.identifier.clone(),
+                right_operator: incoming.identifier.clone(),
+            }
+            .into()),
+        },
+    }
+}
+
+/// Replaces the top operator and the top two operands
+/// with a call.
+fn reduce_infix_operation(
+    operands: &mut Vec<(TextSpan, Expression)>,
+    operators: &mut Vec<InfixOperator>,
+) {
+    let operator = operators.pop().expect("operators should be non-empty");
+    let (right_span, right) = operands
+        .pop()
+        .expect("operands should have a right operand");
+    let (left_span, left) = operands.pop().expect("operands should have a left operand");
+    let span = left_span.inclusive_merge(right_span);
+    operands.push((
+        span,
+        Expression::Call(Box::new(Call {
+            span: Some(span),
+            callee: Expression::Name(operator.function),
+            args: NonEmptyCallArgVec::Unlabeled(NonEmptyVec::from([left, right])),
+        })),
+    ));
+}
+
 fn bind_fun_dirty(context: &mut Context, fun: ub::Fun) -> Result<Expression, BindError> {
     let param_arity = fun.params.len();
     let params = bind_params(context, fun.params)?;
@@ -730,6 +1121,10 @@ fn bind_fun_dirty(context: &mut Context, fun: ub::Fun) -> Result<Expression, Bin
 
 fn bind_match_dirty(context: &mut Context, match_: ub::Match) -> Result<Expression, BindError> {
     let matchee = bind_expression_dirty(context, match_.matchee)?;
+    let motive = match_
+        .motive
+        .map(|motive| bind_match_motive_dirty(context, motive))
+        .transpose()?;
     let cases = match_
         .cases
         .into_iter()
@@ -738,10 +1133,43 @@ fn bind_match_dirty(context: &mut Context, match_: ub::Match) -> Result<Expressi
     Ok(Expression::Match(Box::new(Match {
         span: Some(match_.span),
         matchee,
+        motive,
         cases,
     })))
 }
 
+fn bind_match_motive_dirty(
+    context: &mut Context,
+    motive: ub::MatchMotive,
+) -> Result<MatchMotive, BindError> {
+    let Expression::Name(matchee_type_name) =
+        bind_name_expression_dirty(context, motive.matchee_type_name)?
+    else {
+        panic!("Impossible: Binding a name expression should always return a name expression.");
+    };
+
+    let arity = motive.index_names.len() + 1;
+    let index_names = motive
+        .index_names
+        .map(|index_names| {
+            index_names.try_into_mapped(|index_name| -> Result<_, BindError> {
+                Ok(create_local_name_and_add_to_scope(context, index_name)?)
+            })
+        })
+        .transpose()?;
+    let matchee_name = create_local_name_and_add_to_scope(context, motive.matchee_name)?;
+    let output = bind_expression_dirty(context, motive.output)?;
+
+    context.pop_n(arity);
+    Ok(MatchMotive {
+        span: Some(motive.span),
+        matchee_name,
+        matchee_type_name,
+        index_names,
+        output,
+    })
+}
+
 fn bind_match_case(context: &mut Context, case: ub::MatchCase) -> Result<MatchCase, BindError> {
     let arity = case.params.len();
     let variant_name = case.variant_name.into();
@@ -827,6 +1255,29 @@ fn bind_forall_dirty(context: &mut Context, forall: ub::Forall) -> Result<Expres
     Ok(forall)
 }
 
+fn bind_exists_dirty(context: &mut Context, exists: ub::Exists) -> Result<Expression, BindError> {
+    let param = bind_unlabeled_param(context, exists.param)?;
+    let output = bind_expression_dirty(context, exists.output)?;
+    let exists = Expression::Exists(Box::new(Exists {
+        span: Some(exists.span),
+        param,
+        output,
+    }));
+
+    context.pop_n(1);
+    Ok(exists)
+}
+
+fn bind_pair_dirty(context: &mut Context, pair: ub::Pair) -> Result<Expression, BindError> {
+    let witness = bind_expression_dirty(context, pair.witness)?;
+    let proof = bind_expression_dirty(context, pair.proof)?;
+    Ok(Expression::Pair(Box::new(Pair {
+        span: Some(pair.span),
+        witness,
+        proof,
+    })))
+}
+
 fn bind_check_dirty(context: &mut Context, check: ub::Check) -> Result<Expression, BindError> {
     let assertions = check
         .assertions
@@ -853,6 +1304,52 @@ fn bind_check_assertion_dirty(
     })
 }
 
+fn bind_rewrite_dirty(
+    context: &mut Context,
+    rewrite: ub::Rewrite,
+) -> Result<Expression, BindError> {
+    let Some(designated_eq) = context.designated_eq().cloned() else {
+        return Err(RewriteWithoutDesignatedEqError { rewrite }.into());
+    };
+    let eq_type_name_components = designated_eq.type_name_components.clone();
+    let eq_refl_name_components = {
+        let mut components = designated_eq.type_name_components;
+        components.push(designated_eq.refl_variant_name);
+        components
+    };
+    let eq_type_name = bind_designated_name(context, eq_type_name_components, rewrite.span)?;
+    let eq_refl_name = bind_designated_name(context, eq_refl_name_components, rewrite.span)?;
+    let proof = bind_expression_dirty(context, rewrite.proof)?;
+    let body = bind_expression_dirty(context, rewrite.body)?;
+    Ok(Expression::Rewrite(Box::new(Rewrite {
+        span: Some(rewrite.span),
+        eq_type_name,
+        eq_refl_name,
+        proof,
+        body,
+    })))
+}
+
+/// Binds a name that does not appear in the source code,
+/// but rather was designated by the pack's `pack.yscl`.
+/// Any errors will be reported at `span`.
+fn bind_designated_name(
+    context: &Context,
+    components: NonEmptyVec<IdentifierName>,
+    span: TextSpan,
+) -> Result<NameExpression, BindError> {
+    let components = components.into_mapped(|name| ub::Identifier { span, name });
+    let db_index = get_db_index(context, components.as_ref().iter())?;
+    Ok(NameExpression {
+        span: None,
+        components: components.into_mapped(|component| Identifier {
+            span: None,
+            name: component.name,
+        }),
+        db_index,
+    })
+}
+
 fn bind_goal_kw_or_possibly_invalid_expression(
     context: &mut Context,
     expression: ub::GoalKwOrExpression,
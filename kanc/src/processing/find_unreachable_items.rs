@@ -0,0 +1,330 @@
+use crate::{
+    data::{
+        file_tree::FileTree,
+        light_ast::*,
+        node_registry::{
+            FileItemNodeId, MatchCaseOutputId, NodeId, NodeRegistry, NonEmptyCallArgListId,
+            NonEmptyListId, NonEmptyParamListId,
+        },
+        non_empty_vec::{NonEmptyVec, OptionalNonEmptyVecLen},
+    },
+    processing::type_check::is_path_of_item,
+};
+
+use rustc_hash::{FxHashMap, FxHashSet};
+
+/// The items that cannot be reached from any entry point,
+/// so code generators can omit them.
+/// If this is empty, every item is emitted.
+#[derive(Clone, Debug, Default)]
+pub struct UnreachableItems {
+    items: FxHashSet<FileItemNodeId>,
+}
+
+impl UnreachableItems {
+    pub fn contains(&self, item_id: FileItemNodeId) -> bool {
+        self.items.contains(&item_id)
+    }
+
+    pub fn type_count(&self) -> usize {
+        self.items
+            .iter()
+            .filter(|item_id| matches!(item_id, FileItemNodeId::Type(_)))
+            .count()
+    }
+
+    pub fn let_count(&self) -> usize {
+        self.items
+            .iter()
+            .filter(|item_id| matches!(item_id, FileItemNodeId::Let(_)))
+            .count()
+    }
+}
+
+#[derive(Clone, Debug)]
+pub enum FindUnreachableItemsError {
+    UndeclaredEntryPoint {
+        name_components: NonEmptyVec<IdentifierName>,
+    },
+    TypeEntryPoint {
+        name_components: NonEmptyVec<IdentifierName>,
+        type_id: NodeId<TypeStatement>,
+    },
+}
+
+/// An item is _reachable_ if it is an entry point,
+/// or if a reachable item refers to it.
+/// Every entry point must be a `let`.
+/// If there are no entry points, every item is reachable.
+///
+/// Referring to a type or any of its variants makes
+/// the whole type statement reachable.
+/// References in types (e.g., param types) count,
+/// since generated type declarations refer to them.
+/// References in check assertions and match motives don't count,
+/// since they never appear in generated code.
+pub fn find_unreachable_items(
+    registry: &NodeRegistry,
+    file_tree: &FileTree,
+    file_item_list_id: Option<NonEmptyListId<FileItemNodeId>>,
+    entry_points: &[NonEmptyVec<IdentifierName>],
+) -> Result<UnreachableItems, FindUnreachableItemsError> {
+    if entry_points.is_empty() {
+        return Ok(UnreachableItems::default());
+    }
+
+    let item_ids = registry.get_possibly_empty_list(file_item_list_id);
+    let references = get_item_references(registry, item_ids);
+
+    let mut reachable: FxHashSet<FileItemNodeId> = FxHashSet::default();
+    let mut stack = get_entry_point_ids(registry, file_tree, item_ids, entry_points)?;
+    while let Some(item_id) = stack.pop() {
+        if reachable.insert(item_id) {
+            stack.extend(references[&item_id].iter().copied());
+        }
+    }
+
+    Ok(UnreachableItems {
+        items: item_ids
+            .iter()
+            .copied()
+            .filter(|item_id| !reachable.contains(item_id))
+            .collect(),
+    })
+}
+
+fn get_entry_point_ids(
+    registry: &NodeRegistry,
+    file_tree: &FileTree,
+    item_ids: &[FileItemNodeId],
+    entry_points: &[NonEmptyVec<IdentifierName>],
+) -> Result<Vec<FileItemNodeId>, FindUnreachableItemsError> {
+    entry_points
+        .iter()
+        .map(|name_components| {
+            let item_id = item_ids
+                .iter()
+                .copied()
+                .find(|&item_id| is_item_at_path(registry, file_tree, item_id, name_components))
+                .ok_or_else(|| FindUnreachableItemsError::UndeclaredEntryPoint {
+                    name_components: name_components.clone(),
+                })?;
+            match item_id {
+                FileItemNodeId::Type(type_id) => Err(FindUnreachableItemsError::TypeEntryPoint {
+                    name_components: name_components.clone(),
+                    type_id,
+                }),
+                FileItemNodeId::Let(_) => Ok(item_id),
+            }
+        })
+        .collect()
+}
+
+fn is_item_at_path(
+    registry: &NodeRegistry,
+    file_tree: &FileTree,
+    item_id: FileItemNodeId,
+    path: &NonEmptyVec<IdentifierName>,
+) -> bool {
+    let (span, name_id) = match item_id {
+        FileItemNodeId::Type(type_id) => {
+            let type_statement = registry.get(type_id);
+            (type_statement.span, type_statement.name_id)
+        }
+        FileItemNodeId::Let(let_id) => {
+            let let_statement = registry.get(let_id);
+            (let_statement.span, let_statement.name_id)
+        }
+    };
+    let Some(span) = span else {
+        return false;
+    };
+    is_path_of_item(file_tree, span.file_id, &registry.get(name_id).name, path)
+}
+
+/// Returns the items each item directly refers to
+/// (excluding the item itself).
+fn get_item_references(
+    registry: &NodeRegistry,
+    item_ids: &[FileItemNodeId],
+) -> FxHashMap<FileItemNodeId, Vec<FileItemNodeId>> {
+    // The item that declares each top-level context entry
+    // (excluding the builtins), in order of declaration.
+    // A type statement declares the type, followed by its variants.
+    let mut declarers: Vec<FileItemNodeId> = vec![];
+    let mut out = FxHashMap::default();
+    for &item_id in item_ids {
+        let mut finder = ReferenceFinder {
+            registry,
+            outer_len: declarers.len(),
+            levels: vec![],
+        };
+        match item_id {
+            FileItemNodeId::Type(type_id) => {
+                let type_statement = registry.get(type_id);
+                finder.visit_optional_params(type_statement.param_list_id, finder.outer_len);
+                let variant_ids = registry.get_possibly_empty_list(type_statement.variant_list_id);
+                for (variant_index, &variant_id) in variant_ids.iter().enumerate() {
+                    let variant = registry.get(variant_id);
+                    // The type and the earlier variants are in scope.
+                    let depth = finder.outer_len + 1 + variant_index;
+                    let param_len = finder.visit_optional_params(variant.param_list_id, depth);
+                    finder.visit_expression(variant.return_type_id, depth + param_len);
+                }
+                declarers.extend(std::iter::repeat_n(item_id, 1 + variant_ids.len()));
+            }
+            FileItemNodeId::Let(let_id) => {
+                let let_statement = registry.get(let_id);
+                finder.visit_expression(let_statement.value_id, finder.outer_len);
+                declarers.push(item_id);
+            }
+        }
+
+        let mut references: Vec<FileItemNodeId> = vec![];
+        for level in finder.levels {
+            let declarer = declarers[level];
+            if !references.contains(&declarer) {
+                references.push(declarer);
+            }
+        }
+        out.insert(item_id, references);
+    }
+    out
+}
+
+/// Finds the top-level entries an item refers to.
+///
+/// Each `depth` is the number of entries in the context
+/// (excluding the builtins) at the visited node.
+struct ReferenceFinder<'a> {
+    registry: &'a NodeRegistry,
+    /// The number of top-level entries declared before the item.
+    outer_len: usize,
+    /// The levels (excluding the builtins) of the referenced entries.
+    levels: Vec<usize>,
+}
+
+impl ReferenceFinder<'_> {
+    /// Returns the number of params.
+    fn visit_optional_params(&mut self, id: Option<NonEmptyParamListId>, depth: usize) -> usize {
+        match id {
+            Some(id) => self.visit_params(id, depth),
+            None => 0,
+        }
+    }
+
+    /// Returns the number of params.
+    fn visit_params(&mut self, id: NonEmptyParamListId, depth: usize) -> usize {
+        let registry = self.registry;
+        match id {
+            NonEmptyParamListId::Unlabeled(id) => {
+                let param_ids = registry.get_list(id);
+                for (param_index, &param_id) in param_ids.iter().enumerate() {
+                    let param = registry.get(param_id);
+                    self.visit_expression(param.type_id, depth + param_index);
+                }
+                param_ids.len()
+            }
+            NonEmptyParamListId::UniquelyLabeled(id) => {
+                let param_ids = registry.get_list(id);
+                for (param_index, &param_id) in param_ids.iter().enumerate() {
+                    let param = registry.get(param_id);
+                    self.visit_expression(param.type_id, depth + param_index);
+                    if let Some(default_id) = param.default_id {
+                        self.visit_expression(default_id, depth + param_index);
+                    }
+                }
+                param_ids.len()
+            }
+        }
+    }
+
+    fn visit_expression(&mut self, id: ExpressionId, depth: usize) {
+        let registry = self.registry;
+        match id {
+            ExpressionId::Name(id) => self.visit_name_expression(id, depth),
+            ExpressionId::Todo(_) => {}
+            ExpressionId::Call(id) => {
+                let call = registry.get(id);
+                self.visit_expression(call.callee_id, depth);
+                self.visit_call_args(call.arg_list_id, depth);
+            }
+            ExpressionId::Fun(id) => {
+                let fun = registry.get(id);
+                let param_len = self.visit_params(fun.param_list_id, depth);
+                self.visit_expression(fun.return_type_id, depth + param_len);
+                // The fun's own name is in scope in its body.
+                self.visit_expression(fun.body_id, depth + param_len + 1);
+            }
+            ExpressionId::Match(id) => {
+                let match_ = registry.get(id);
+                self.visit_expression(match_.matchee_id, depth);
+                for &case_id in registry.get_possibly_empty_list(match_.case_list_id) {
+                    let case = registry.get(case_id);
+                    let explicit_len = case.param_list_id.len();
+                    if let MatchCaseOutputId::Some(output_id) = case.output_id {
+                        self.visit_expression(output_id, depth + explicit_len);
+                    }
+                }
+            }
+            ExpressionId::Forall(id) => {
+                let forall = registry.get(id);
+                let param_len = self.visit_params(forall.param_list_id, depth);
+                self.visit_expression(forall.output_id, depth + param_len);
+            }
+            ExpressionId::Check(id) => {
+                let check = registry.get(id);
+                self.visit_expression(check.output_id, depth);
+            }
+            ExpressionId::Rewrite(id) => {
+                let rewrite = registry.get(id);
+                self.visit_expression(rewrite.proof_id, depth);
+                self.visit_expression(rewrite.body_id, depth);
+            }
+            ExpressionId::Projection(id) => {
+                let projection = registry.get(id);
+                self.visit_expression(projection.record_id, depth);
+            }
+            ExpressionId::Exists(id) => {
+                let exists = registry.get(id);
+                let param = registry.get(exists.param_id);
+                self.visit_expression(param.type_id, depth);
+                self.visit_expression(exists.output_id, depth + 1);
+            }
+            ExpressionId::Pair(id) => {
+                let pair = registry.get(id);
+                self.visit_expression(pair.witness_id, depth);
+                self.visit_expression(pair.proof_id, depth);
+            }
+        }
+    }
+
+    fn visit_name_expression(&mut self, id: NodeId<NameExpression>, depth: usize) {
+        let name = self.registry.get(id);
+        // Builtins are below level 0, so the subtraction fails for them.
+        let Some(level) = depth.checked_sub(name.db_index.0 + 1) else {
+            return;
+        };
+        // The entries at or above `outer_len` are either declared by
+        // the item itself, or are local.
+        if level < self.outer_len && !self.levels.contains(&level) {
+            self.levels.push(level);
+        }
+    }
+
+    fn visit_call_args(&mut self, id: NonEmptyCallArgListId, depth: usize) {
+        let registry = self.registry;
+        match id {
+            NonEmptyCallArgListId::Unlabeled(id) => {
+                for &arg_id in registry.get_list(id).iter() {
+                    self.visit_expression(arg_id, depth);
+                }
+            }
+            NonEmptyCallArgListId::UniquelyLabeled(id) => {
+                for arg_id in registry.get_list(id).iter() {
+                    self.visit_expression(arg_id.value_id(), depth);
+                }
+            }
+        }
+    }
+}
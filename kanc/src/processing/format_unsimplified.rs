@@ -35,6 +35,13 @@ pub fn format_expression(
         Expression::Match(match_) => format_match(match_, indent_level, options),
         Expression::Forall(forall) => format_forall(forall, indent_level, options),
         Expression::Check(check) => format_check(check, indent_level, options),
+        Expression::Rewrite(rewrite) => format_rewrite(rewrite, indent_level, options),
+        Expression::Exists(exists) => format_exists(exists, indent_level, options),
+        Expression::Pair(pair) => format_pair(pair, indent_level, options),
+        Expression::InfixChain(chain) => format_infix_chain(chain, indent_level, options),
+        Expression::Parenthesized(parenthesized) => {
+            format_parenthesized(parenthesized, indent_level, options)
+        }
     }
 }
 
@@ -129,12 +136,17 @@ pub fn format_param(param: &Param, indent_level: usize, options: &FormatOptions)
         None => "".to_string(),
     };
     let is_dashed = if param.is_dashed { "-" } else { "" };
+    let default = match &param.default {
+        Some(default) => format!(" = {}", format_expression(default, indent_level, options)),
+        None => "".to_string(),
+    };
     format!(
-        "{}{}{}: {}",
+        "{}{}{}: {}{}",
         label,
         is_dashed,
         format_identifier(&param.name),
-        format_expression(&param.type_, indent_level, options)
+        format_expression(&param.type_, indent_level, options),
+        default
     )
 }
 
@@ -154,14 +166,35 @@ pub fn format_match(match_: &Match, indent_level: usize, options: &FormatOptions
         })
         .collect::<Vec<_>>()
         .join("\n");
+    let motive = match &match_.motive {
+        Some(motive) => format!(" {}", format_match_motive(motive, indent_level, options)),
+        None => "".to_string(),
+    };
     format!(
-        "match {} {{\n{}\n{}}}",
+        "match {}{} {{\n{}\n{}}}",
         try_oneline_with_multi_parens(&matchee, indent_level, options),
+        motive,
         cases,
         &i0
     )
 }
 
+pub fn format_match_motive(
+    motive: &MatchMotive,
+    indent_level: usize,
+    options: &FormatOptions,
+) -> String {
+    let matchee_name = format_identifier(&motive.matchee_name);
+    let matchee_type = format_expression(&motive.matchee_type, indent_level + 1, options);
+    let output = format_expression(&motive.output, indent_level + 1, options);
+    format!(
+        "as {}: {} => {}",
+        matchee_name,
+        try_oneline_with_multi_parens(&matchee_type, indent_level, options),
+        try_oneline_with_multi_parens(&output, indent_level, options)
+    )
+}
+
 fn try_oneline_with_multi_parens(s: &str, indent_level: usize, options: &FormatOptions) -> String {
     let i0 = indent(indent_level, options);
     let i1 = indent(indent_level + 1, options);
@@ -266,6 +299,58 @@ pub fn format_check(check: &Check, indent_level: usize, options: &FormatOptions)
     format!("case {} {{\n{}{}\n{}}}", assertions, &i1, output, &i0,)
 }
 
+pub fn format_rewrite(rewrite: &Rewrite, indent_level: usize, options: &FormatOptions) -> String {
+    let proof = format_expression(&rewrite.proof, indent_level, options);
+    let body = format_expression(&rewrite.body, indent_level, options);
+    format!("rewrite {} in {}", proof, body)
+}
+
+pub fn format_exists(exists: &Exists, indent_level: usize, options: &FormatOptions) -> String {
+    let i0 = indent(indent_level, options);
+    let i1 = indent(indent_level + 1, options);
+    let params = format_params(&exists.params, indent_level + 1, options);
+    let output = format_expression(&exists.output, indent_level + 1, options);
+    format!(
+        "exists (\n{}\n{}) {{\n{}{}\n{}}}",
+        params, &i0, &i1, output, &i0
+    )
+}
+
+pub fn format_pair(pair: &Pair, indent_level: usize, options: &FormatOptions) -> String {
+    let witness = format_expression(&pair.witness, indent_level, options);
+    let proof = format_expression(&pair.proof, indent_level, options);
+    format!("({}, {})", witness, proof)
+}
+
+pub fn format_infix_chain(
+    chain: &InfixChain,
+    indent_level: usize,
+    options: &FormatOptions,
+) -> String {
+    let first_operand = format_expression(&chain.first_operand, indent_level, options);
+    let rest = chain
+        .rest
+        .iter()
+        .map(|link| {
+            format!(
+                " {} {}",
+                format_identifier(&link.operator),
+                format_expression(&link.operand, indent_level, options)
+            )
+        })
+        .collect::<String>();
+    format!("{}{}", first_operand, rest)
+}
+
+pub fn format_parenthesized(
+    parenthesized: &Parenthesized,
+    indent_level: usize,
+    options: &FormatOptions,
+) -> String {
+    let expression = format_expression(&parenthesized.expression, indent_level, options);
+    format!("({})", expression)
+}
+
 pub fn format_check_assertions(
     assertions: &[CheckAssertion],
     indent_level: usize,
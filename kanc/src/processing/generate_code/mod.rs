@@ -1,4 +1,5 @@
 use crate::data::{
+    file_tree::FileTree,
     light_ast::*,
     node_registry::{NodeRegistry, NonEmptyListId},
 };
@@ -12,17 +13,19 @@ pub trait CompileTarget {
 
     fn generate_code_with_options(
         registry: &NodeRegistry,
+        file_tree: &FileTree,
         file_item_list_id: Option<NonEmptyListId<FileItemNodeId>>,
         options: Self::Options,
     ) -> Result<Self::Ok, Self::Error>;
 
     fn generate_code(
         registry: &NodeRegistry,
+        file_tree: &FileTree,
         file_item_list_id: Option<NonEmptyListId<FileItemNodeId>>,
     ) -> Result<Self::Ok, Self::Error>
     where
         Self::Options: Default,
     {
-        Self::generate_code_with_options(registry, file_item_list_id, Default::default())
+        Self::generate_code_with_options(registry, file_tree, file_item_list_id, Default::default())
     }
 }
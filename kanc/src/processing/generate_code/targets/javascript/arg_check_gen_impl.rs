@@ -0,0 +1,1212 @@
+//! Generates the checks of the args passed to exported functions
+//! when `JavaScriptOptions::check_exported_args` is on.
+
+use light::{DbIndex, DbLevel};
+
+use crate::data::file_id::FileId;
+
+use rustc_hash::FxHashMap;
+
+use super::bigint_gen_impl::*;
+use super::code_gen_impl::*;
+use super::*;
+
+const CHECKER_VALUE_NAME: &str = "value";
+const CHECKER_PENDING_NAME: &str = "pending";
+const CHECKER_ENTRY_NAME: &str = "entry";
+const CHECKER_INNER_NAME: &str = "checker";
+const CHECKER_TYPE_ARG_PREFIX: &str = "type_arg";
+const CHECK_RUNNER_NAME: &str = "check_arg";
+const CHECK_RUNNER_PARAM0_NAME: &str = "checker";
+const CHECK_RUNNER_PARAM1_NAME: &str = "value";
+const CHECK_RUNNER_PARAM2_NAME: &str = "arg";
+const CHECK_RUNNER_PARAM3_NAME: &str = "path";
+const FUNCTION_CHECKER_NAME: &str = "check_function";
+
+/// The wrappers generated by `generate_code_for_arg_checks`
+/// throw the error this returns when an arg is malformed:
+/// ```js
+/// const invalid_arg_error = function invalid_arg_error(invalid_arg, invalid_arg_path, invalid_arg_expected) {
+///     return new Error("Invalid " + invalid_arg + ": expected `" + invalid_arg_path + "` to be " + invalid_arg_expected + ".");
+/// };
+/// ```
+pub(super) fn generate_code_for_invalid_arg_error() -> Vec<ConstStatement> {
+    let name = ValidJsIdentifierName(INVALID_ARG_ERROR_NAME.to_string());
+    let string = |s: &str| {
+        Expression::Literal(Literal::String(JsStringLiteral {
+            unescaped: s.to_string(),
+        }))
+    };
+    let param = |s: &str| Expression::Identifier(ValidJsIdentifierName(s.to_string()));
+    let message = [
+        param(INVALID_ARG_ERROR_PARAM0_NAME),
+        string(": expected `"),
+        param(INVALID_ARG_ERROR_PARAM1_NAME),
+        string("` to be "),
+        param(INVALID_ARG_ERROR_PARAM2_NAME),
+        string("."),
+    ]
+    .into_iter()
+    .fold(string("Invalid "), |left, right| {
+        binary_op(left, BinaryOpKind::Plus, right)
+    });
+    vec![ConstStatement {
+        name: name.clone(),
+        value: Expression::Function(Box::new(Function {
+            name,
+            params: Params::Standard(
+                [
+                    INVALID_ARG_ERROR_PARAM0_NAME,
+                    INVALID_ARG_ERROR_PARAM1_NAME,
+                    INVALID_ARG_ERROR_PARAM2_NAME,
+                ]
+                .into_iter()
+                .map(|name| ValidJsIdentifierName(name.to_string()))
+                .collect(),
+            ),
+            body: vec![FunctionStatement::Return(Expression::New(Box::new(Call {
+                callee: Expression::Identifier(ValidJsIdentifierName("Error".to_string())),
+                args: vec![message],
+                span: None,
+            })))],
+            span: None,
+        })),
+    }]
+}
+
+/// An exported `let` bound to a `fun`, which `index.js` wraps
+/// with a function that checks the args before calling it
+/// (see `generate_code_for_arg_checks`).
+#[derive(Clone, Debug)]
+pub(super) struct CheckedFunction {
+    js_name: ValidJsIdentifierName,
+    pub(super) wrapper_js_name: ValidJsIdentifierName,
+    /// The Kantu path of the function (e.g., `pack.nat.double`).
+    path: String,
+    is_labeled: bool,
+    params: Vec<CheckedParam>,
+}
+
+#[derive(Clone, Debug)]
+struct CheckedParam {
+    /// The Kantu name of the param (or its label, if the param is labeled).
+    name: String,
+    /// The arg, in terms of the wrapper's `...wrapped_args`.
+    value: Expression,
+    /// If true, the arg may be omitted, since the param has a default.
+    is_optional: bool,
+    shape: ArgShape,
+}
+
+/// What a checked value must look like.
+/// Values whose shapes are unknown (e.g., types, or values of
+/// types that are params of the checked function) are not checked.
+#[derive(Clone, Debug)]
+enum ArgShape {
+    /// A value of the type statement whose type constructor is at `level`,
+    /// applied to args in the order of the type statement's params.
+    /// An arg is `None` if its values' shape is unknown.
+    Adt {
+        level: DbLevel,
+        args: Vec<Option<ArgShape>>,
+    },
+    Function,
+    /// A value of the type passed to the checker's type param
+    /// at the given position.
+    TypeArg(usize),
+}
+
+/// What a name in a type refers to.
+#[derive(Clone, Copy, Debug)]
+enum ArgShapeName {
+    Level(DbLevel),
+    TypeArg(usize),
+    Unknown,
+}
+
+/// Returns `None` if the `let` is not exported, is not bound to a `fun`,
+/// or has no args whose shapes are known.
+/// The `let` must be the last entry in the context.
+pub(super) fn get_checked_function(
+    registry: &NodeRegistry,
+    file_tree: &FileTree,
+    context: &mut Context,
+    let_id: NodeId<light::LetStatement>,
+    file_id: FileId,
+) -> Option<CheckedFunction> {
+    let let_statement = registry.get(let_id);
+    if !is_exported(file_tree, let_statement.visibility, file_id) {
+        return None;
+    }
+    let light::ExpressionId::Fun(fun_id) = let_statement.value_id else {
+        return None;
+    };
+    let fun = registry.get(fun_id);
+    // The fun's params are pushed right above where the `let` is.
+    let fun_level = context.index_to_level(DbIndex(0));
+    let wrapped_args =
+        || Expression::Identifier(ValidJsIdentifierName(WRAPPED_ARGS_NAME.to_string()));
+
+    let mut params = vec![];
+    let is_labeled = match fun.param_list_id {
+        NonEmptyParamListId::Unlabeled(param_list_id) => {
+            // The fun omits its erased params (see `generate_code_for_let_statement`),
+            // so each arg's position is the number of unerased params before it.
+            let mut unerased_count = 0;
+            for (param_index, &param_id) in registry.get_list(param_list_id).iter().enumerate() {
+                if context.erasure.is_unlabeled_param_erased(param_id) {
+                    continue;
+                }
+                let arg_position = unerased_count;
+                unerased_count += 1;
+                let param = registry.get(param_id);
+                let resolve = |index| resolve_fun_param_type_name(fun_level, param_index, index);
+                let Some(shape) = get_arg_shape(registry, context, param.type_id, &resolve) else {
+                    continue;
+                };
+                params.push(CheckedParam {
+                    name: registry.get(param.name_id).name.src_str().to_string(),
+                    value: binary_op(
+                        wrapped_args(),
+                        BinaryOpKind::Index,
+                        Expression::Literal(Literal::Number(arg_position)),
+                    ),
+                    is_optional: false,
+                    shape,
+                });
+            }
+            false
+        }
+        NonEmptyParamListId::UniquelyLabeled(param_list_id) => {
+            for (param_index, &param_id) in registry.get_list(param_list_id).iter().enumerate() {
+                if context.erasure.is_labeled_param_erased(param_id) {
+                    continue;
+                }
+                let param = registry.get(param_id);
+                let resolve = |index| resolve_fun_param_type_name(fun_level, param_index, index);
+                let Some(shape) = get_arg_shape(registry, context, param.type_id, &resolve) else {
+                    continue;
+                };
+                let label = &registry.get(param.label_identifier_id()).name;
+                params.push(CheckedParam {
+                    name: label.src_str().to_string(),
+                    value: Expression::Dot(Box::new(Dot {
+                        left: binary_op(
+                            wrapped_args(),
+                            BinaryOpKind::Index,
+                            Expression::Literal(Literal::Number(0)),
+                        ),
+                        right: label.preferred_js_name(),
+                    })),
+                    is_optional: param.default_id.is_some(),
+                    shape,
+                });
+            }
+            true
+        }
+    };
+    if params.is_empty() {
+        return None;
+    }
+
+    let js_name = context.js_name(DbIndex(0));
+    let wrapper_js_name =
+        context.reserve_name(ValidJsIdentifierName(format!("{}_checked", js_name.0)));
+    Some(CheckedFunction {
+        js_name,
+        wrapper_js_name,
+        path: get_kantu_path(
+            file_tree,
+            file_id,
+            &registry.get(let_statement.name_id).name,
+        ),
+        is_labeled,
+        params,
+    })
+}
+
+/// Resolves a name in the type of the param at `param_index`
+/// of a fun whose params start at `fun_level`.
+/// Earlier params are unknown, since their values are not known
+/// until the fun is called.
+fn resolve_fun_param_type_name(
+    fun_level: DbLevel,
+    param_index: usize,
+    index: DbIndex,
+) -> ArgShapeName {
+    match (fun_level.0 + param_index).checked_sub(index.0 + 1) {
+        Some(level) if level < fun_level.0 => ArgShapeName::Level(DbLevel(level)),
+        _ => ArgShapeName::Unknown,
+    }
+}
+
+/// Returns `None` if the shape of the type's values is unknown.
+fn get_arg_shape(
+    registry: &NodeRegistry,
+    context: &Context,
+    type_id: light::ExpressionId,
+    resolve: &dyn Fn(DbIndex) -> ArgShapeName,
+) -> Option<ArgShape> {
+    let (callee_id, arg_list_id) = match type_id {
+        light::ExpressionId::Name(_) => (type_id, None),
+        light::ExpressionId::Call(call_id) => {
+            let call = registry.get(call_id);
+            (call.callee_id, Some(call.arg_list_id))
+        }
+        light::ExpressionId::Forall(_) => return Some(ArgShape::Function),
+        _ => return None,
+    };
+    let light::ExpressionId::Name(name_id) = callee_id else {
+        return None;
+    };
+    let level = match resolve(registry.get(name_id).db_index) {
+        ArgShapeName::Level(level) => level,
+        ArgShapeName::TypeArg(position) if arg_list_id.is_none() => {
+            return Some(ArgShape::TypeArg(position));
+        }
+        ArgShapeName::TypeArg(_) | ArgShapeName::Unknown => return None,
+    };
+    let type_statement = registry.get(*context.type_levels.get(&level)?);
+
+    let args = match (type_statement.param_list_id, arg_list_id) {
+        (None, None) => vec![],
+        (
+            Some(NonEmptyParamListId::Unlabeled(_)),
+            Some(NonEmptyCallArgListId::Unlabeled(arg_list_id)),
+        ) => registry
+            .get_list(arg_list_id)
+            .iter()
+            .map(|&arg_id| get_arg_shape(registry, context, arg_id, resolve))
+            .collect(),
+        (
+            Some(NonEmptyParamListId::UniquelyLabeled(param_list_id)),
+            Some(NonEmptyCallArgListId::UniquelyLabeled(arg_list_id)),
+        ) => {
+            let arg_ids = registry.get_list(arg_list_id);
+            registry
+                .get_list(param_list_id)
+                .iter()
+                .map(|&param_id| {
+                    let label = &registry
+                        .get(registry.get(param_id).label_identifier_id())
+                        .name;
+                    let arg_id = arg_ids
+                        .iter()
+                        .find(|arg_id| registry.get(arg_id.label_id()).name == *label)?;
+                    get_arg_shape(registry, context, arg_id.value_id(), resolve)
+                })
+                .collect()
+        }
+        _ => return None,
+    };
+    Some(ArgShape::Adt { level, args })
+}
+
+/// How a checker checks the fields of a variant.
+#[derive(Clone, Debug)]
+struct CheckedVariant {
+    /// The variant's JS tag (i.e., the first item of its values).
+    tag: String,
+    /// If `Some`, the variant's params are labeled,
+    /// so its values hold an object with these keys
+    /// (excluding the erased params).
+    /// Otherwise, its values hold an item per param.
+    labels: Option<Vec<ValidJsIdentifierName>>,
+    /// The fields whose shapes are known, along with
+    /// their paths relative to the value (e.g., `[1]` or `[1].x`).
+    fields: Vec<(Expression, String, ArgShape)>,
+    arity: usize,
+}
+
+/// Returns the variants of the type statement whose type constructor
+/// is at `level`.
+fn get_checked_variants(
+    registry: &NodeRegistry,
+    context: &Context,
+    level: DbLevel,
+) -> Vec<CheckedVariant> {
+    let type_statement = registry.get(context.type_levels[&level]);
+    let type_param_len = type_statement.param_list_id.len();
+    let value = || Expression::Identifier(ValidJsIdentifierName(CHECKER_VALUE_NAME.to_string()));
+    let index = |left: Expression, i: usize| {
+        binary_op(
+            left,
+            BinaryOpKind::Index,
+            Expression::Literal(Literal::Number(i as i32)),
+        )
+    };
+
+    let variant_ids = registry.get_possibly_empty_list(type_statement.variant_list_id);
+    variant_ids
+        .iter()
+        .enumerate()
+        .map(|(variant_index, &variant_id)| {
+            let variant = registry.get(variant_id);
+            // The type and the earlier variants are in scope,
+            // followed by the variant's params.
+            let param_level = level.0 + 1 + variant_index;
+            let arity = variant.param_list_id.len();
+            let type_args = get_variant_param_type_args(
+                registry,
+                variant.return_type_id,
+                param_level + arity,
+                param_level,
+                type_param_len,
+                arity,
+            );
+            let resolve_in_param = |param_index: usize| {
+                let type_args = &type_args;
+                move |index: DbIndex| match (param_level + param_index).checked_sub(index.0 + 1) {
+                    Some(level) if level >= param_level => match type_args[level - param_level] {
+                        Some(position) => ArgShapeName::TypeArg(position),
+                        None => ArgShapeName::Unknown,
+                    },
+                    Some(level) => ArgShapeName::Level(DbLevel(level)),
+                    None => ArgShapeName::Unknown,
+                }
+            };
+
+            let mut labels = None;
+            let mut fields = vec![];
+            match variant.param_list_id {
+                None => {}
+                Some(NonEmptyParamListId::Unlabeled(param_list_id)) => {
+                    for (param_index, &param_id) in
+                        registry.get_list(param_list_id).iter().enumerate()
+                    {
+                        if context.erasure.is_unlabeled_param_erased(param_id) {
+                            continue;
+                        }
+                        let param = registry.get(param_id);
+                        let resolve = resolve_in_param(param_index);
+                        if let Some(shape) =
+                            get_arg_shape(registry, context, param.type_id, &resolve)
+                        {
+                            fields.push((
+                                index(value(), param_index + 1),
+                                format!("[{}]", param_index + 1),
+                                shape,
+                            ));
+                        }
+                    }
+                }
+                Some(NonEmptyParamListId::UniquelyLabeled(param_list_id)) => {
+                    let mut keys = vec![];
+                    for (param_index, &param_id) in
+                        registry.get_list(param_list_id).iter().enumerate()
+                    {
+                        if context.erasure.is_labeled_param_erased(param_id) {
+                            continue;
+                        }
+                        let param = registry.get(param_id);
+                        let key = registry
+                            .get(param.label_identifier_id())
+                            .name
+                            .preferred_js_name();
+                        keys.push(key.clone());
+                        let resolve = resolve_in_param(param_index);
+                        if let Some(shape) =
+                            get_arg_shape(registry, context, param.type_id, &resolve)
+                        {
+                            let path = format!("[1].{}", key.0);
+                            let field = Expression::Dot(Box::new(Dot {
+                                left: index(value(), 1),
+                                right: key,
+                            }));
+                            fields.push((field, path, shape));
+                        }
+                    }
+                    labels = Some(keys);
+                }
+            }
+
+            CheckedVariant {
+                tag: registry.get(variant.name_id).name.preferred_js_name().0,
+                labels,
+                fields,
+                arity,
+            }
+        })
+        .collect()
+}
+
+/// Returns the position of the type arg that each variant param
+/// is passed as in the variant's return type, if any.
+/// For example, the param `T` of `.cons(T: Type; car: T, cdr: List(T)): List(T)`
+/// is passed as the first type arg.
+///
+/// `depth` is the number of entries in the context of the return type,
+/// and the variant's params start at `param_level`.
+fn get_variant_param_type_args(
+    registry: &NodeRegistry,
+    return_type_id: light::ExpressionId,
+    depth: usize,
+    param_level: usize,
+    type_param_len: usize,
+    arity: usize,
+) -> Vec<Option<usize>> {
+    let mut out = vec![None; arity];
+    let light::ExpressionId::Call(call_id) = return_type_id else {
+        return out;
+    };
+    let arg_ids: Vec<light::ExpressionId> = match registry.get(call_id).arg_list_id {
+        NonEmptyCallArgListId::Unlabeled(arg_list_id) => registry.get_list(arg_list_id).to_vec(),
+        // Labeled args may be in any order, so we can't tell
+        // which type param each arg is passed to.
+        NonEmptyCallArgListId::UniquelyLabeled(_) => return out,
+    };
+    for (position, arg_id) in arg_ids.into_iter().enumerate().take(type_param_len) {
+        let light::ExpressionId::Name(name_id) = arg_id else {
+            continue;
+        };
+        let Some(level) = depth.checked_sub(registry.get(name_id).db_index.0 + 1) else {
+            continue;
+        };
+        if level >= param_level && out[level - param_level].is_none() {
+            out[level - param_level] = Some(position);
+        }
+    }
+    out
+}
+
+impl ArgShape {
+    fn for_each_adt_level(&self, f: &mut impl FnMut(DbLevel)) {
+        if let ArgShape::Adt { level, args } = self {
+            f(*level);
+            for arg in args.iter().flatten() {
+                arg.for_each_adt_level(f);
+            }
+        }
+    }
+}
+
+/// A checker of the values of a type statement (see `generate_code_for_checker`).
+#[derive(Clone, Debug)]
+struct Checker {
+    js_name: ValidJsIdentifierName,
+    /// If true, the type statement has params, so the checker
+    /// takes the checkers of the type args, and returns
+    /// the checker of the type.
+    is_generic: bool,
+}
+
+/// The names of the functions that every checked arg is checked with
+/// (see `generate_code_for_check_runner`).
+#[derive(Clone, Debug)]
+struct CheckRunner {
+    js_name: ValidJsIdentifierName,
+    function_checker_js_name: ValidJsIdentifierName,
+}
+
+/// When `JavaScriptOptions::check_exported_args` is true,
+/// `index.js` exports a wrapper for each exported function,
+/// which checks the args before calling the function.
+/// For example, if we have
+/// ```kantu
+/// pub let double = fun _(n: Nat): Nat { ... };
+/// ```
+/// then we emit something like:
+/// ```js
+/// const check_Nat = function check_Nat(value, pending, entry) {
+///     if ((Array.isArray)(value) === false) {
+///         return "a `pack.Nat`";
+///     }
+///     switch (value[0]) {
+///         case "z": { ... return undefined; }
+///         case "s": {
+///             if (value.length !== 2) {
+///                 return "a `pack.Nat`";
+///             }
+///             (pending.push)([check_Nat, value[1], entry, "[1]"]);
+///             return undefined;
+///         }
+///         default: {
+///             return "a `pack.Nat`";
+///         }
+///     }
+/// };
+/// const double_checked = function double_checked(...wrapped_args) {
+///     check_arg(check_Nat, wrapped_args[0], "argument `n` of `pack.double`", "n");
+///     return (double_.apply)(undefined, wrapped_args);
+/// };
+/// ```
+/// This returns the check runner (if any function is checked),
+/// followed by the checkers of the types the checked args may contain,
+/// followed by the wrappers.
+pub(super) fn generate_code_for_arg_checks(
+    registry: &NodeRegistry,
+    file_tree: &FileTree,
+    context: &mut Context,
+    functions: &[CheckedFunction],
+) -> Vec<ConstStatement> {
+    if functions.is_empty() {
+        return vec![];
+    }
+
+    let runner = CheckRunner {
+        js_name: context.reserve_name(ValidJsIdentifierName(CHECK_RUNNER_NAME.to_string())),
+        function_checker_js_name: context
+            .reserve_name(ValidJsIdentifierName(FUNCTION_CHECKER_NAME.to_string())),
+    };
+
+    let mut variants: FxHashMap<DbLevel, Vec<CheckedVariant>> = FxHashMap::default();
+    let mut pending: Vec<DbLevel> = vec![];
+    for param in functions.iter().flat_map(|function| &function.params) {
+        param
+            .shape
+            .for_each_adt_level(&mut |level| pending.push(level));
+    }
+    while let Some(level) = pending.pop() {
+        if variants.contains_key(&level) {
+            continue;
+        }
+        // The fields of BigInt types aren't checked,
+        // since their values are `bigint`s.
+        let checked_variants = if context.bigint.type_(context.type_levels[&level]).is_some() {
+            vec![]
+        } else {
+            get_checked_variants(registry, context, level)
+        };
+        for (_, _, shape) in checked_variants.iter().flat_map(|variant| &variant.fields) {
+            shape.for_each_adt_level(&mut |level| pending.push(level));
+        }
+        variants.insert(level, checked_variants);
+    }
+
+    let mut levels: Vec<DbLevel> = variants.keys().copied().collect();
+    levels.sort();
+    let checkers: FxHashMap<DbLevel, Checker> = levels
+        .iter()
+        .map(|&level| {
+            let type_js_name = context.js_name_at_level(level);
+            let js_name =
+                context.reserve_name(ValidJsIdentifierName(format!("check_{}", type_js_name.0)));
+            let type_statement = registry.get(context.type_levels[&level]);
+            let checker = Checker {
+                js_name,
+                is_generic: type_statement.param_list_id.is_some(),
+            };
+            (level, checker)
+        })
+        .collect();
+
+    let mut out = generate_code_for_check_runner(&runner);
+    out.extend(levels.iter().map(|level| {
+        generate_code_for_checker(
+            registry,
+            file_tree,
+            context,
+            &runner,
+            &checkers,
+            *level,
+            &variants[level],
+        )
+    }));
+    out.extend(
+        functions.iter().map(|function| {
+            generate_code_for_checked_function_wrapper(&runner, &checkers, function)
+        }),
+    );
+    out
+}
+
+/// Checkers don't call each other, since a deeply nested value
+/// would overflow the JS call stack.
+/// Instead, a checker checks the outermost layer of the value,
+/// and pushes an entry for each field onto `pending`.
+/// An entry is an array of the field's checker, the field,
+/// the entry of the value that holds the field, and the path
+/// from that value to the field (e.g., `[1]`).
+/// The check runner pops the entries until none are left,
+/// so the path of a malformed value is only built if we throw:
+/// ```js
+/// const check_function = function check_function(value) {
+///     if ((typeof value) !== "function") {
+///         return "a function";
+///     }
+///     return undefined;
+/// };
+/// const check_arg = function check_arg(checker, value, arg, path) {
+///     const pending = [[checker, value, undefined, path]];
+///     while (true) {
+///         if (pending.length === 0) {
+///             return undefined;
+///         }
+///         const entry = (pending.pop)();
+///         const expected = (entry[0])(entry[1], pending, entry);
+///         if (expected !== undefined) {
+///             let invalid_path = "";
+///             let current = entry;
+///             while (true) {
+///                 if (current === undefined) {
+///                     throw invalid_arg_error(arg, invalid_path, expected);
+///                 }
+///                 invalid_path = current[3] + invalid_path;
+///                 current = current[2];
+///             }
+///         }
+///     }
+/// };
+/// ```
+fn generate_code_for_check_runner(runner: &CheckRunner) -> Vec<ConstStatement> {
+    let name = |s: &str| ValidJsIdentifierName(s.to_string());
+    let identifier = |s: &str| Expression::Identifier(name(s));
+    let number = |n: i32| Expression::Literal(Literal::Number(n));
+    let string = |s: &str| {
+        Expression::Literal(Literal::String(JsStringLiteral {
+            unescaped: s.to_string(),
+        }))
+    };
+    let index = |array: &str, n: i32| binary_op(identifier(array), BinaryOpKind::Index, number(n));
+
+    let function_checker = ConstStatement {
+        name: runner.function_checker_js_name.clone(),
+        value: Expression::Function(Box::new(Function {
+            name: runner.function_checker_js_name.clone(),
+            params: Params::Standard(vec![name(CHECKER_VALUE_NAME)]),
+            body: vec![
+                FunctionStatement::If(IfStatement {
+                    condition: binary_op(
+                        Expression::Typeof(Box::new(identifier(CHECKER_VALUE_NAME))),
+                        BinaryOpKind::NotTripleEqual,
+                        string("function"),
+                    ),
+                    body: vec![FunctionStatement::Return(string("a function"))],
+                }),
+                FunctionStatement::Return(undefined()),
+            ],
+            span: None,
+        })),
+    };
+
+    let throw_with_path = FunctionStatement::Loop(vec![
+        FunctionStatement::If(IfStatement {
+            condition: binary_op(
+                identifier("current"),
+                BinaryOpKind::TripleEqual,
+                undefined(),
+            ),
+            body: vec![generate_code_to_throw_invalid_arg_error(
+                identifier(CHECK_RUNNER_PARAM2_NAME),
+                identifier("invalid_path"),
+                identifier("expected"),
+            )],
+        }),
+        FunctionStatement::Assign(Assignment {
+            name: name("invalid_path"),
+            value: binary_op(
+                index("current", 3),
+                BinaryOpKind::Plus,
+                identifier("invalid_path"),
+            ),
+        }),
+        FunctionStatement::Assign(Assignment {
+            name: name("current"),
+            value: index("current", 2),
+        }),
+    ]);
+    let runner_function = ConstStatement {
+        name: runner.js_name.clone(),
+        value: Expression::Function(Box::new(Function {
+            name: runner.js_name.clone(),
+            params: Params::Standard(
+                [
+                    CHECK_RUNNER_PARAM0_NAME,
+                    CHECK_RUNNER_PARAM1_NAME,
+                    CHECK_RUNNER_PARAM2_NAME,
+                    CHECK_RUNNER_PARAM3_NAME,
+                ]
+                .into_iter()
+                .map(name)
+                .collect(),
+            ),
+            body: vec![
+                FunctionStatement::Const(ConstStatement {
+                    name: name(CHECKER_PENDING_NAME),
+                    value: Expression::Array(Box::new(Array {
+                        items: vec![Expression::Array(Box::new(Array {
+                            items: vec![
+                                identifier(CHECK_RUNNER_PARAM0_NAME),
+                                identifier(CHECK_RUNNER_PARAM1_NAME),
+                                undefined(),
+                                identifier(CHECK_RUNNER_PARAM3_NAME),
+                            ],
+                        }))],
+                    })),
+                }),
+                FunctionStatement::Loop(vec![
+                    FunctionStatement::If(IfStatement {
+                        condition: binary_op(
+                            Expression::Dot(Box::new(Dot {
+                                left: identifier(CHECKER_PENDING_NAME),
+                                right: name("length"),
+                            })),
+                            BinaryOpKind::TripleEqual,
+                            number(0),
+                        ),
+                        body: vec![FunctionStatement::Return(undefined())],
+                    }),
+                    FunctionStatement::Const(ConstStatement {
+                        name: name(CHECKER_ENTRY_NAME),
+                        value: Expression::Call(Box::new(Call {
+                            callee: Expression::Dot(Box::new(Dot {
+                                left: identifier(CHECKER_PENDING_NAME),
+                                right: name("pop"),
+                            })),
+                            args: vec![],
+                            span: None,
+                        })),
+                    }),
+                    FunctionStatement::Const(ConstStatement {
+                        name: name("expected"),
+                        value: Expression::Call(Box::new(Call {
+                            callee: index(CHECKER_ENTRY_NAME, 0),
+                            args: vec![
+                                index(CHECKER_ENTRY_NAME, 1),
+                                identifier(CHECKER_PENDING_NAME),
+                                identifier(CHECKER_ENTRY_NAME),
+                            ],
+                            span: None,
+                        })),
+                    }),
+                    FunctionStatement::If(IfStatement {
+                        condition: binary_op(
+                            identifier("expected"),
+                            BinaryOpKind::NotTripleEqual,
+                            undefined(),
+                        ),
+                        body: vec![
+                            FunctionStatement::Let(ConstStatement {
+                                name: name("invalid_path"),
+                                value: string(""),
+                            }),
+                            FunctionStatement::Let(ConstStatement {
+                                name: name("current"),
+                                value: identifier(CHECKER_ENTRY_NAME),
+                            }),
+                            throw_with_path,
+                        ],
+                    }),
+                ]),
+            ],
+            span: None,
+        })),
+    };
+
+    vec![function_checker, runner_function]
+}
+
+/// Generates a function that takes the value, the array of pending entries,
+/// and the value's entry (see `generate_code_for_check_runner`).
+/// The function returns a description of what the value should be
+/// (e.g., "a `pack.Nat`") if the value is malformed,
+/// and pushes the entries of the value's fields otherwise.
+fn generate_code_for_checker(
+    registry: &NodeRegistry,
+    file_tree: &FileTree,
+    context: &Context,
+    runner: &CheckRunner,
+    checkers: &FxHashMap<DbLevel, Checker>,
+    level: DbLevel,
+    variants: &[CheckedVariant],
+) -> ConstStatement {
+    let type_id = context.type_levels[&level];
+    let type_statement = registry.get(type_id);
+    let type_file_id = get_declaring_file_id(registry, light::FileItemNodeId::Type(type_id))
+        .unwrap_or(file_tree.root());
+    let type_path = get_kantu_path(
+        file_tree,
+        type_file_id,
+        &registry.get(type_statement.name_id).name,
+    );
+    let type_param_len = type_statement.param_list_id.len();
+    let identifier = |s: &str| Expression::Identifier(ValidJsIdentifierName(s.to_string()));
+    let value = || identifier(CHECKER_VALUE_NAME);
+    let number = |n: usize| Expression::Literal(Literal::Number(n as i32));
+    let string = |s: String| Expression::Literal(Literal::String(JsStringLiteral { unescaped: s }));
+    let dot = |left: Expression, right: &str| {
+        Expression::Dot(Box::new(Dot {
+            left,
+            right: ValidJsIdentifierName(right.to_string()),
+        }))
+    };
+    let call = |callee: Expression, args: Vec<Expression>| {
+        Expression::Call(Box::new(Call {
+            callee,
+            args,
+            span: None,
+        }))
+    };
+    let reject_if = |condition: Expression, expected: &str| {
+        FunctionStatement::If(IfStatement {
+            condition,
+            body: vec![FunctionStatement::Return(string(expected.to_string()))],
+        })
+    };
+    // A field of the type applied to the checker's own type args
+    // (e.g., the `cdr` of a `List(T)`) is checked by the checker itself,
+    // so we don't create a new checker for each node.
+    let is_checked_by_inner_checker = |shape: &ArgShape| {
+        let ArgShape::Adt {
+            level: field_level,
+            args,
+        } = shape
+        else {
+            return false;
+        };
+        checkers[&level].is_generic
+            && *field_level == level
+            && args.iter().enumerate().all(|(position, arg)| {
+                matches!(arg, Some(ArgShape::TypeArg(arg_position)) if *arg_position == position)
+            })
+    };
+
+    let body = if let Some(bigint_type) = context.bigint.type_(type_id) {
+        let is_signed = bigint_type
+            .variants
+            .iter()
+            .any(|(_, variant)| matches!(variant, BigIntVariant::Negate));
+        let has_zero = bigint_type.variants.iter().any(|(_, variant)| {
+            matches!(
+                variant,
+                BigIntVariant::Zero | BigIntVariant::Embed { is_positive: false }
+            )
+        });
+        let (min, expected) = match (is_signed, has_zero) {
+            (true, _) => (None, format!("a `{}` (i.e., a `bigint`)", type_path)),
+            (false, true) => (
+                Some(0),
+                format!("a `{}` (i.e., a nonnegative `bigint`)", type_path),
+            ),
+            (false, false) => (
+                Some(1),
+                format!("a `{}` (i.e., a positive `bigint`)", type_path),
+            ),
+        };
+        let mut body = vec![reject_if(
+            binary_op(
+                Expression::Typeof(Box::new(value())),
+                BinaryOpKind::NotTripleEqual,
+                string("bigint".to_string()),
+            ),
+            &expected,
+        )];
+        if let Some(min) = min {
+            body.push(reject_if(
+                binary_op(value(), BinaryOpKind::LessThan, bigint_literal(min)),
+                &expected,
+            ));
+        }
+        body.push(FunctionStatement::Return(undefined()));
+        body
+    } else {
+        let expected = format!("a `{}`", type_path);
+        let cases = variants
+            .iter()
+            .map(|variant| {
+                let item_count = match &variant.labels {
+                    Some(_) => 2,
+                    None => variant.arity + 1,
+                };
+                let mut body = vec![reject_if(
+                    binary_op(
+                        dot(value(), "length"),
+                        BinaryOpKind::NotTripleEqual,
+                        number(item_count),
+                    ),
+                    &expected,
+                )];
+                if let Some(labels) = &variant.labels {
+                    let fields = || binary_op(value(), BinaryOpKind::Index, number(1));
+                    body.push(reject_if(
+                        binary_op(
+                            Expression::Typeof(Box::new(fields())),
+                            BinaryOpKind::NotTripleEqual,
+                            string("object".to_string()),
+                        ),
+                        &expected,
+                    ));
+                    body.push(reject_if(
+                        binary_op(fields(), BinaryOpKind::TripleEqual, identifier("null")),
+                        &expected,
+                    ));
+                    body.push(reject_if(
+                        binary_op(
+                            dot(
+                                call(dot(identifier("Object"), "keys"), vec![fields()]),
+                                "length",
+                            ),
+                            BinaryOpKind::NotTripleEqual,
+                            number(labels.len()),
+                        ),
+                        &expected,
+                    ));
+                    for label in labels {
+                        body.push(reject_if(
+                            binary_op(
+                                dot(fields(), &label.0),
+                                BinaryOpKind::TripleEqual,
+                                undefined(),
+                            ),
+                            &expected,
+                        ));
+                    }
+                }
+                for (field, field_path, shape) in &variant.fields {
+                    let push = |checker: Expression| {
+                        FunctionStatement::Expression(call(
+                            dot(identifier(CHECKER_PENDING_NAME), "push"),
+                            vec![Expression::Array(Box::new(Array {
+                                items: vec![
+                                    checker,
+                                    field.clone(),
+                                    identifier(CHECKER_ENTRY_NAME),
+                                    string(field_path.clone()),
+                                ],
+                            }))],
+                        ))
+                    };
+                    body.push(if is_checked_by_inner_checker(shape) {
+                        push(identifier(CHECKER_INNER_NAME))
+                    } else {
+                        generate_code_to_check_with_checker_of_shape(runner, checkers, shape, push)
+                    });
+                }
+                body.push(FunctionStatement::Return(undefined()));
+                SwitchCase {
+                    test: string(variant.tag.clone()),
+                    body,
+                }
+            })
+            .collect();
+        vec![
+            reject_if(
+                binary_op(
+                    call(dot(identifier("Array"), "isArray"), vec![value()]),
+                    BinaryOpKind::TripleEqual,
+                    Expression::Literal(Literal::Boolean(false)),
+                ),
+                &expected,
+            ),
+            FunctionStatement::Switch(SwitchStatement {
+                discriminant: binary_op(value(), BinaryOpKind::Index, number(0)),
+                cases,
+                default: Some(vec![FunctionStatement::Return(string(expected))]),
+            }),
+        ]
+    };
+
+    let checker = &checkers[&level];
+    let value_params = Params::Standard(
+        [CHECKER_VALUE_NAME, CHECKER_PENDING_NAME, CHECKER_ENTRY_NAME]
+            .into_iter()
+            .map(|name| ValidJsIdentifierName(name.to_string()))
+            .collect(),
+    );
+    let value = if checker.is_generic {
+        let inner = Function {
+            name: ValidJsIdentifierName(CHECKER_INNER_NAME.to_string()),
+            params: value_params,
+            body,
+            span: None,
+        };
+        Function {
+            name: checker.js_name.clone(),
+            params: Params::Standard((0..type_param_len).map(checker_type_arg_js_name).collect()),
+            body: vec![FunctionStatement::Return(Expression::Function(Box::new(
+                inner,
+            )))],
+            span: type_statement.span,
+        }
+    } else {
+        Function {
+            name: checker.js_name.clone(),
+            params: value_params,
+            body,
+            span: type_statement.span,
+        }
+    };
+    ConstStatement {
+        name: checker.js_name.clone(),
+        value: Expression::Function(Box::new(value)),
+    }
+}
+
+fn generate_code_for_checked_function_wrapper(
+    runner: &CheckRunner,
+    checkers: &FxHashMap<DbLevel, Checker>,
+    function: &CheckedFunction,
+) -> ConstStatement {
+    let wrapped_args =
+        || Expression::Identifier(ValidJsIdentifierName(WRAPPED_ARGS_NAME.to_string()));
+    let string = |s: String| Expression::Literal(Literal::String(JsStringLiteral { unescaped: s }));
+
+    let mut body = vec![];
+    if function.is_labeled {
+        let labeled_args = || {
+            binary_op(
+                wrapped_args(),
+                BinaryOpKind::Index,
+                Expression::Literal(Literal::Number(0)),
+            )
+        };
+        let description = || string(format!("labeled arguments of `{}`", function.path));
+        for condition in [
+            binary_op(
+                Expression::Typeof(Box::new(labeled_args())),
+                BinaryOpKind::NotTripleEqual,
+                string("object".to_string()),
+            ),
+            binary_op(
+                labeled_args(),
+                BinaryOpKind::TripleEqual,
+                Expression::Identifier(ValidJsIdentifierName("null".to_string())),
+            ),
+        ] {
+            body.push(FunctionStatement::If(IfStatement {
+                condition,
+                body: vec![generate_code_to_throw_invalid_arg_error(
+                    description(),
+                    string("arguments[0]".to_string()),
+                    string("an object".to_string()),
+                )],
+            }));
+        }
+    }
+    for param in &function.params {
+        let check = generate_code_to_check_with_checker_of_shape(
+            runner,
+            checkers,
+            &param.shape,
+            |checker| {
+                FunctionStatement::Expression(Expression::Call(Box::new(Call {
+                    callee: Expression::Identifier(runner.js_name.clone()),
+                    args: vec![
+                        checker,
+                        param.value.clone(),
+                        string(format!("argument `{}` of `{}`", param.name, function.path)),
+                        string(param.name.clone()),
+                    ],
+                    span: None,
+                })))
+            },
+        );
+        body.push(if param.is_optional {
+            FunctionStatement::If(IfStatement {
+                condition: binary_op(
+                    param.value.clone(),
+                    BinaryOpKind::NotTripleEqual,
+                    undefined(),
+                ),
+                body: vec![check],
+            })
+        } else {
+            check
+        });
+    }
+    body.push(FunctionStatement::Return(Expression::Call(Box::new(
+        Call {
+            callee: Expression::Dot(Box::new(Dot {
+                left: Expression::Identifier(function.js_name.clone()),
+                right: ValidJsIdentifierName("apply".to_string()),
+            })),
+            args: vec![undefined(), wrapped_args()],
+            span: None,
+        },
+    ))));
+
+    ConstStatement {
+        name: function.wrapper_js_name.clone(),
+        value: Expression::Function(Box::new(Function {
+            name: function.wrapper_js_name.clone(),
+            params: Params::Rest(ValidJsIdentifierName(WRAPPED_ARGS_NAME.to_string())),
+            body,
+            span: None,
+        })),
+    }
+}
+
+/// Returns `check(checker)`, where `checker` evaluates to
+/// the checker of values of the shape.
+/// The checker of a type arg is `undefined`
+/// if the shape of the type arg's values is unknown,
+/// so in that case, `check` is skipped.
+fn generate_code_to_check_with_checker_of_shape(
+    runner: &CheckRunner,
+    checkers: &FxHashMap<DbLevel, Checker>,
+    shape: &ArgShape,
+    check: impl FnOnce(Expression) -> FunctionStatement,
+) -> FunctionStatement {
+    match shape {
+        ArgShape::Adt { .. } => check(generate_code_for_checker_of_shape(checkers, shape)),
+        ArgShape::Function => check(Expression::Identifier(
+            runner.function_checker_js_name.clone(),
+        )),
+        ArgShape::TypeArg(position) => {
+            let checker = Expression::Identifier(checker_type_arg_js_name(*position));
+            FunctionStatement::If(IfStatement {
+                condition: binary_op(checker.clone(), BinaryOpKind::NotTripleEqual, undefined()),
+                body: vec![check(checker)],
+            })
+        }
+    }
+}
+
+/// Returns an expression that evaluates to a checker of values of the shape,
+/// or `undefined` if the shape's values cannot be checked by a checker.
+fn generate_code_for_checker_of_shape(
+    checkers: &FxHashMap<DbLevel, Checker>,
+    shape: &ArgShape,
+) -> Expression {
+    match shape {
+        ArgShape::Adt { level, args } => {
+            let checker = &checkers[level];
+            let js_name = Expression::Identifier(checker.js_name.clone());
+            if !checker.is_generic {
+                return js_name;
+            }
+            Expression::Call(Box::new(Call {
+                callee: js_name,
+                args: args
+                    .iter()
+                    .map(|arg| match arg {
+                        Some(arg) => generate_code_for_checker_of_shape(checkers, arg),
+                        None => undefined(),
+                    })
+                    .collect(),
+                span: None,
+            }))
+        }
+        ArgShape::TypeArg(position) => Expression::Identifier(checker_type_arg_js_name(*position)),
+        ArgShape::Function => undefined(),
+    }
+}
+
+fn generate_code_to_throw_invalid_arg_error(
+    arg: Expression,
+    path: Expression,
+    expected: Expression,
+) -> FunctionStatement {
+    FunctionStatement::Throw(Expression::Call(Box::new(Call {
+        callee: Expression::Identifier(ValidJsIdentifierName(INVALID_ARG_ERROR_NAME.to_string())),
+        args: vec![arg, path, expected],
+        span: None,
+    })))
+}
+
+fn checker_type_arg_js_name(position: usize) -> ValidJsIdentifierName {
+    ValidJsIdentifierName(format!("{}{}", CHECKER_TYPE_ARG_PREFIX, position))
+}
+
+/// Returns the Kantu path of an item (e.g., `pack.nat.Nat`).
+fn get_kantu_path(file_tree: &FileTree, file_id: FileId, name: &light::IdentifierName) -> String {
+    let mut components = vec![name.src_str().to_string()];
+    let mut current = file_id;
+    while let Some((parent, label)) = file_tree.parent_and_label(current) {
+        components.push(label.src_str().to_string());
+        current = parent;
+    }
+    components.push(EXPORT_ROOT_NAME.to_string());
+    components.reverse();
+    components.join(".")
+}
@@ -0,0 +1,265 @@
+//! Generates the code for the types that `BigIntAnalysis` designates
+//! as `bigint`s: their variants' values, the matches on them,
+//! and the functions replaced with native operations.
+
+use light::DbIndex;
+
+use super::code_gen_impl::*;
+use super::*;
+
+/// Returns the value of a variant of a BigInt type
+/// (see `BigIntAnalysis`), given its args.
+pub(super) fn generate_code_for_bigint_variant_value(
+    variant: &BigIntVariant,
+    args: Vec<Expression>,
+) -> Expression {
+    let mut args = args.into_iter();
+    let mut next_arg = || {
+        args.next()
+            .expect("A BigInt variant should have an arg for each param.")
+    };
+    match variant {
+        BigIntVariant::Zero => bigint_literal(0),
+        BigIntVariant::One => bigint_literal(1),
+        BigIntVariant::Succ => binary_op(next_arg(), BinaryOpKind::Plus, bigint_literal(1)),
+        BigIntVariant::Extend { bit_variant_names } => {
+            let left = next_arg();
+            let right = next_arg();
+            let right_value = Expression::Ternary(Box::new(Ternary {
+                condition: binary_op(
+                    binary_op(
+                        right,
+                        BinaryOpKind::Index,
+                        Expression::Literal(Literal::Number(0)),
+                    ),
+                    BinaryOpKind::TripleEqual,
+                    Expression::Literal(Literal::String(JsStringLiteral {
+                        unescaped: bit_variant_names[1].preferred_js_name().0,
+                    })),
+                ),
+                true_body: bigint_literal(1),
+                false_body: bigint_literal(0),
+            }));
+            binary_op(
+                binary_op(bigint_literal(2), BinaryOpKind::Times, left),
+                BinaryOpKind::Plus,
+                right_value,
+            )
+        }
+        BigIntVariant::Embed { .. } => next_arg(),
+        BigIntVariant::Negate => binary_op(bigint_literal(0), BinaryOpKind::Minus, next_arg()),
+    }
+}
+
+/// Returns the number a value of a BigInt type represents
+/// (see `BigIntAnalysis`), or `None` if it doesn't fit in an `i128`.
+pub(super) fn get_precomputed_bigint(
+    registry: &NodeRegistry,
+    context: &Context,
+    value: &PrecomputedValue,
+) -> Option<i128> {
+    let bigint_type = context.bigint.type_(value.type_id)?;
+    let variant_name = &registry.get(registry.get(value.variant_id).name_id).name;
+    let arg = |index: usize| value.args.get(index)?.as_ref();
+    match bigint_type.variant(variant_name)? {
+        BigIntVariant::Zero => Some(0),
+        BigIntVariant::One => Some(1),
+        BigIntVariant::Succ => get_precomputed_bigint(registry, context, arg(0)?)?.checked_add(1),
+        BigIntVariant::Extend { bit_variant_names } => {
+            let left = get_precomputed_bigint(registry, context, arg(0)?)?;
+            let bit_name = &registry.get(registry.get(arg(1)?.variant_id).name_id).name;
+            let right = if *bit_name == bit_variant_names[1] {
+                1
+            } else {
+                0
+            };
+            left.checked_mul(2)?.checked_add(right)
+        }
+        BigIntVariant::Embed { .. } => get_precomputed_bigint(registry, context, arg(0)?),
+        BigIntVariant::Negate => get_precomputed_bigint(registry, context, arg(0)?)?.checked_neg(),
+    }
+}
+
+/// Since the type checker verified that the function has type
+/// `forall(a: T, b: T) { ... }` (see `BigIntFunction`),
+/// its args are `bigint`s, so we can replace its body with
+/// the native operation.
+pub(super) fn generate_code_for_bigint_function_let_statement(
+    registry: &NodeRegistry,
+    context: &mut Context,
+    let_id: NodeId<light::LetStatement>,
+    function: &BigIntFunction,
+) -> ConstStatement {
+    let let_statement = registry.get(let_id);
+    let let_statement_name = &registry.get(let_statement.name_id).name;
+    context.try_push_item_name(let_statement_name.preferred_js_name());
+    let let_statement_js_name = context.js_name(DbIndex(0));
+
+    context.try_push_name(ValidJsIdentifierName("a".to_string()));
+    let a = context.js_name(DbIndex(0));
+    context.try_push_name(ValidJsIdentifierName("b".to_string()));
+    let b = context.js_name(DbIndex(0));
+    context.pop_n(2);
+
+    let left = Expression::Identifier(a.clone());
+    let right = Expression::Identifier(b.clone());
+    let return_value = match (function.operation, &function.bool_variant_names) {
+        (BigIntOperation::Add, _) => binary_op(left, BinaryOpKind::Plus, right),
+        (BigIntOperation::Mul, _) => binary_op(left, BinaryOpKind::Times, right),
+        (BigIntOperation::Eq, Some(bool_variant_names)) => generate_code_for_bool_value(
+            binary_op(left, BinaryOpKind::TripleEqual, right),
+            bool_variant_names,
+        ),
+        (BigIntOperation::Lt, Some(bool_variant_names)) => generate_code_for_bool_value(
+            binary_op(left, BinaryOpKind::LessThan, right),
+            bool_variant_names,
+        ),
+        (BigIntOperation::Eq | BigIntOperation::Lt, None) => {
+            unreachable!("BigInt comparisons should always return a bool.")
+        }
+    };
+
+    ConstStatement {
+        name: let_statement_js_name.clone(),
+        value: Expression::Function(Box::new(Function {
+            name: let_statement_js_name,
+            params: Params::Standard(vec![a, b]),
+            body: vec![FunctionStatement::Return(return_value)],
+            span: let_statement.span,
+        })),
+    }
+}
+
+/// Converts a JS boolean to a value of the designated bool type,
+/// given the names of its `true` and `false` variants (in that order).
+fn generate_code_for_bool_value(
+    condition: Expression,
+    bool_variant_names: &[light::IdentifierName; 2],
+) -> Expression {
+    Expression::Ternary(Box::new(Ternary {
+        condition,
+        true_body: nullary_variant_value(&bool_variant_names[0]),
+        false_body: nullary_variant_value(&bool_variant_names[1]),
+    }))
+}
+
+/// Returns the value of a nullary variant with the given name
+/// (e.g., `["true"]`).
+fn nullary_variant_value(variant_name: &light::IdentifierName) -> Expression {
+    Expression::Array(Box::new(Array {
+        items: vec![Expression::Literal(Literal::String(JsStringLiteral {
+            unescaped: variant_name.preferred_js_name().0,
+        }))],
+    }))
+}
+
+pub(super) fn bigint_literal(value: i128) -> Expression {
+    Expression::Literal(Literal::BigInt(value))
+}
+
+/// Like `generate_code_for_match_case`, except the matchee is a `bigint`
+/// (see `BigIntAnalysis`), so we compare it to the bounds of the case's variant,
+/// and compute the case params from it.
+pub(super) fn generate_code_for_bigint_match_case(
+    registry: &NodeRegistry,
+    context: &mut Context,
+    case: &light::MatchCase,
+    matchee_js_name: &ValidJsIdentifierName,
+    bigint_type: &BigIntType,
+) -> Result<IfStatement, CompileToJavaScriptError> {
+    let (condition, mut body) = generate_code_for_bigint_match_case_condition_and_params(
+        registry,
+        context,
+        case,
+        matchee_js_name,
+        bigint_type,
+    );
+    let param_count = body.len();
+    body.push(FunctionStatement::Return(
+        generate_code_for_match_case_output(registry, context, case.output_id)?,
+    ));
+    context.pop_n(param_count);
+
+    Ok(IfStatement { condition, body })
+}
+
+/// Returns the condition under which the `bigint` matchee
+/// belongs to the case's variant, along with the statements
+/// that declare the case params.
+/// The case params are left in the context.
+pub(super) fn generate_code_for_bigint_match_case_condition_and_params(
+    registry: &NodeRegistry,
+    context: &mut Context,
+    case: &light::MatchCase,
+    matchee_js_name: &ValidJsIdentifierName,
+    bigint_type: &BigIntType,
+) -> (Expression, Vec<FunctionStatement>) {
+    let variant_name = &registry.get(case.variant_name_id).name;
+    let variant = bigint_type
+        .variant(variant_name)
+        .expect("The type checker should have verified that every case has a variant.");
+    let matchee = || Expression::Identifier(matchee_js_name.clone());
+    let (condition, param_values) = match variant {
+        BigIntVariant::Zero => (
+            binary_op(matchee(), BinaryOpKind::TripleEqual, bigint_literal(0)),
+            vec![],
+        ),
+        BigIntVariant::One => (
+            binary_op(matchee(), BinaryOpKind::TripleEqual, bigint_literal(1)),
+            vec![],
+        ),
+        BigIntVariant::Succ => (
+            binary_op(matchee(), BinaryOpKind::GreaterThan, bigint_literal(0)),
+            vec![binary_op(matchee(), BinaryOpKind::Minus, bigint_literal(1))],
+        ),
+        BigIntVariant::Extend { bit_variant_names } => {
+            let parity = binary_op(matchee(), BinaryOpKind::BitwiseAnd, bigint_literal(1));
+            let bit = Expression::Ternary(Box::new(Ternary {
+                condition: binary_op(parity, BinaryOpKind::TripleEqual, bigint_literal(0)),
+                true_body: nullary_variant_value(&bit_variant_names[0]),
+                false_body: nullary_variant_value(&bit_variant_names[1]),
+            }));
+            (
+                binary_op(matchee(), BinaryOpKind::GreaterThan, bigint_literal(1)),
+                vec![
+                    binary_op(matchee(), BinaryOpKind::RightShift, bigint_literal(1)),
+                    bit,
+                ],
+            )
+        }
+        BigIntVariant::Embed { is_positive: true } => (
+            binary_op(matchee(), BinaryOpKind::GreaterThan, bigint_literal(0)),
+            vec![matchee()],
+        ),
+        BigIntVariant::Embed { is_positive: false } => (
+            binary_op(
+                matchee(),
+                BinaryOpKind::GreaterThanOrEqual,
+                bigint_literal(0),
+            ),
+            vec![matchee()],
+        ),
+        BigIntVariant::Negate => (
+            binary_op(matchee(), BinaryOpKind::LessThan, bigint_literal(0)),
+            vec![binary_op(bigint_literal(0), BinaryOpKind::Minus, matchee())],
+        ),
+    };
+
+    // BigInt variants only have unlabeled params.
+    let param_ids = match case.param_list_id {
+        Some(NonEmptyMatchCaseParamListId::Unlabeled(param_list_id)) => {
+            registry.get_list(param_list_id).to_vec()
+        }
+        _ => vec![],
+    };
+    let mut param_consts = Vec::with_capacity(param_ids.len());
+    for (param_id, param_value) in param_ids.iter().zip(param_values) {
+        let param_name = &registry.get(*param_id).name;
+        context.try_push_name(param_name.preferred_js_name());
+        param_consts.push(FunctionStatement::Const(ConstStatement {
+            name: context.js_name(DbIndex(0)),
+            value: param_value,
+        }));
+    }
+    (condition, param_consts)
+}
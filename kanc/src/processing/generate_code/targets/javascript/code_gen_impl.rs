@@ -1,71 +1,896 @@
-use light::{DbIndex, DbLevel};
+use light::{DbIndex, DbLevel, ModScope, Visibility};
 
+use crate::data::file_id::FileId;
+
+use rustc_hash::FxHashMap;
+
+use super::arg_check_gen_impl::*;
+use super::bigint_gen_impl::*;
+use super::declaration_gen_impl::*;
+use super::inline_gen_impl::*;
+use super::stack_safe_gen_impl::*;
 use super::*;
 
+/// Returns one file per Kantu module that declares at least one item,
+/// plus a shared runtime file and an `index.js` file.
+/// Each JS file is accompanied by a TypeScript declaration file.
+///
+/// The module `pack` is written to `pack.js`,
+/// and a module `pack.foo.bar` is written to `pack/foo/bar.js`.
+/// Since Kantu module names cannot be `pack`, the index and runtime
+/// files cannot collide with module files.
 pub fn generate_code_with_options(
     registry: &NodeRegistry,
+    file_tree: &FileTree,
     file_item_list_id: Option<NonEmptyListId<FileItemNodeId>>,
-) -> Result<File, CompileToJavaScriptError> {
-    let mut context = Context::new();
+    options: JavaScriptOptions,
+) -> Result<JavaScriptFiles, CompileToJavaScriptError> {
+    let unreachable_items = options.unreachable_items;
+    let check_exported_args = options.check_exported_args;
+    // The output may refer to any universe the registry names
+    // (including universes introduced by the type checker).
+    let max_universe_level = registry.max_universe_level();
+    let mut declaration_context = DeclarationContext::new(
+        options.erasure.clone(),
+        options.bigint.clone(),
+        max_universe_level,
+    );
+    let mut context = Context::new(
+        max_universe_level,
+        file_tree.root(),
+        options.erasure,
+        options.bigint,
+        options.stack_safe,
+        options.source_files,
+        options.precomputed_lets,
+        options.inline_small_functions,
+    );
+    let mut item_codes: Vec<(FileId, Vec<ConstStatement>)> = vec![];
+    let mut item_declarations: Vec<(FileId, Vec<DeclarationItem>)> = vec![];
+    let mut modules: Vec<ModuleReferences> = vec![];
+    let mut exports = ExportTree::default();
+    let mut checked_functions: Vec<CheckedFunction> = vec![];
     let item_ids = registry.get_possibly_empty_list(file_item_list_id);
-    let items = {
-        let mut out = vec![];
-        out.extend(generate_code_for_type1_and_type0_without_adding_to_context());
-        out.extend(generate_code_for_explosion_thrower());
-        out.extend(generate_code_for_todo_error_thrower());
-        for item_id in item_ids {
-            match *item_id {
-                light::FileItemNodeId::Type(type_id) => {
-                    out.extend(generate_code_for_type_statement(
+    for item_id in item_ids {
+        let file_id = get_declaring_file_id(registry, *item_id).unwrap_or(file_tree.root());
+        context.current_module = file_id;
+        let warning_count = context.warnings.len();
+        let is_reachable = !unreachable_items.contains(*item_id);
+        let (consts, declarations) = match *item_id {
+            light::FileItemNodeId::Type(type_id) => {
+                let consts = generate_code_for_type_statement(registry, &mut context, type_id)?;
+                let js_names: Vec<ValidJsIdentifierName> =
+                    consts.iter().map(|const_| const_.name.clone()).collect();
+                let declarations = generate_declarations_for_type_statement(
+                    registry,
+                    &mut declaration_context,
+                    type_id,
+                    file_id,
+                    &js_names,
+                );
+                if is_reachable {
+                    add_type_statement_to_exports(
                         registry,
-                        &mut context,
+                        file_tree,
+                        &context,
+                        &mut exports,
                         type_id,
-                    )?);
+                        file_id,
+                    );
                 }
-                light::FileItemNodeId::Let(let_id) => {
-                    out.push(FileItem::Const(generate_code_for_let_statement(
+                (consts, declarations)
+            }
+            light::FileItemNodeId::Let(let_id) => {
+                let const_ = generate_code_for_let_statement(registry, &mut context, let_id)?;
+                let declaration = generate_declaration_for_let_statement(
+                    registry,
+                    &mut declaration_context,
+                    let_id,
+                    &const_.name,
+                );
+                if is_reachable {
+                    let checked_function = if check_exported_args {
+                        get_checked_function(registry, file_tree, &mut context, let_id, file_id)
+                    } else {
+                        None
+                    };
+                    add_let_statement_to_exports(
                         registry,
-                        &mut context,
+                        file_tree,
+                        &context,
+                        &mut exports,
                         let_id,
-                    )?));
+                        file_id,
+                        checked_function
+                            .as_ref()
+                            .map(|function| function.wrapper_js_name.clone()),
+                    );
+                    checked_functions.extend(checked_function);
                 }
+                (vec![const_], vec![declaration])
+            }
+        };
+        if !is_reachable {
+            context.warnings.truncate(warning_count);
+            context.take_references();
+            declaration_context.take_type_references();
+            continue;
+        }
+        let export_keys = get_export_keys(registry, *item_id);
+        debug_assert_eq!(export_keys.len(), consts.len());
+        let items: Vec<_> = consts
+            .iter()
+            .map(|const_| const_.name.clone())
+            .zip(export_keys)
+            .collect();
+        item_codes.push((file_id, consts));
+        item_declarations.push((file_id, declarations));
+
+        let module = match modules.iter().position(|module| module.file_id == file_id) {
+            Some(index) => &mut modules[index],
+            None => {
+                modules.push(ModuleReferences {
+                    file_id,
+                    items: vec![],
+                    references: vec![],
+                    type_references: vec![],
+                });
+                modules.last_mut().unwrap()
+            }
+        };
+        module.items.extend(items);
+        for reference in context.take_references() {
+            if !module.references.contains(&reference) {
+                module.references.push(reference);
             }
         }
-        out
+        for reference in declaration_context.take_type_references() {
+            if !module.type_references.contains(&reference) {
+                module.type_references.push(reference);
+            }
+        }
+    }
+
+    let arg_checks =
+        generate_code_for_arg_checks(registry, file_tree, &mut context, &checked_functions);
+    let holders = get_holders(file_tree, &modules);
+    let export_names = get_export_names(&modules, &holders);
+    let mut out = JavaScriptFiles {
+        js_files: vec![
+            (
+                PathBuf::from(INDEX_FILE_NAME),
+                generate_index_file(file_tree, &export_names, &exports, arg_checks),
+            ),
+            (
+                PathBuf::from(RUNTIME_FILE_NAME),
+                generate_runtime_file(max_universe_level, check_exported_args),
+            ),
+        ],
+        declaration_files: vec![
+            (
+                get_declaration_file_path(&[INDEX_FILE_NAME.to_string()]),
+                generate_index_declaration_file(file_tree, &export_names, &exports),
+            ),
+            (
+                get_declaration_file_path(&[RUNTIME_FILE_NAME.to_string()]),
+                generate_runtime_declaration_file(max_universe_level, check_exported_args),
+            ),
+        ],
+        warnings: std::mem::take(&mut context.warnings),
     };
-    Ok(File { items })
-}
-
-fn generate_code_for_type1_and_type0_without_adding_to_context() -> Vec<FileItem> {
-    vec![
-        FileItem::Const(ConstStatement {
-            name: ValidJsIdentifierName(TYPE_SPECIES_VALUE__TYPE1.to_string()),
-            value: Expression::Object(Box::new(Object {
-                entries: vec![ObjectEntry {
-                    key: ValidJsIdentifierName(TYPE_SPECIES_KEY.to_string()),
-                    value: Expression::Literal(Literal::String(JsStringLiteral {
-                        unescaped: TYPE_SPECIES_VALUE__TYPE1.to_string(),
-                    })),
-                }],
-            })),
-        }),
-        FileItem::Const(ConstStatement {
-            name: ValidJsIdentifierName(TYPE_SPECIES_VALUE__TYPE0.to_string()),
-            value: Expression::Object(Box::new(Object {
-                entries: vec![ObjectEntry {
-                    key: ValidJsIdentifierName(TYPE_SPECIES_KEY.to_string()),
-                    value: Expression::Literal(Literal::String(JsStringLiteral {
-                        unescaped: TYPE_SPECIES_VALUE__TYPE0.to_string(),
-                    })),
-                }],
-            })),
-        }),
+    for module in &modules {
+        let path = get_module_file_path(file_tree, module.file_id);
+        let (file, declaration_file) = if holders[&module.file_id] == module.file_id {
+            (
+                generate_holder_module_file(
+                    file_tree,
+                    &modules,
+                    &holders,
+                    &export_names,
+                    &item_codes,
+                    module.file_id,
+                ),
+                generate_holder_module_declaration_file(
+                    file_tree,
+                    &modules,
+                    &holders,
+                    &export_names,
+                    &item_declarations,
+                    module.file_id,
+                ),
+            )
+        } else {
+            let file =
+                generate_re_exporting_module_file(file_tree, &holders, &export_names, module);
+            // The JS names of the items are also the names of their declarations,
+            // so we can re-export the declarations the same way.
+            let declaration_file = DeclarationFile {
+                items: file
+                    .items
+                    .iter()
+                    .filter_map(|item| match item {
+                        FileItem::ExportFrom(export) => {
+                            Some(DeclarationItem::ExportFrom(export.clone()))
+                        }
+                        _ => None,
+                    })
+                    .collect(),
+            };
+            (file, declaration_file)
+        };
+        out.js_files.push((path.iter().collect(), file));
+        out.declaration_files
+            .push((get_declaration_file_path(&path), declaration_file));
+    }
+    Ok(out)
+}
+
+#[derive(Clone, Debug)]
+struct ModuleReferences {
+    file_id: FileId,
+    /// The JS name and the export key (see `get_export_keys`)
+    /// of each of the module's items, in declaration order.
+    items: Vec<(ValidJsIdentifierName, ValidJsIdentifierName)>,
+    references: Vec<(NameOrigin, ValidJsIdentifierName)>,
+    /// The types referenced by the module's declarations,
+    /// along with the modules that declare them.
+    /// Unlike `references`, these are erased at runtime,
+    /// so they don't affect which modules hold which items.
+    type_references: Vec<(FileId, ValidJsIdentifierName)>,
+}
+
+pub(super) fn get_declaring_file_id(
+    registry: &NodeRegistry,
+    item_id: FileItemNodeId,
+) -> Option<FileId> {
+    let span = match item_id {
+        light::FileItemNodeId::Type(type_id) => registry.get(type_id).span,
+        light::FileItemNodeId::Let(let_id) => registry.get(let_id).span,
+    };
+    span.map(|span| span.file_id)
+}
+
+/// Returns the keys that the consts generated for the item
+/// are exported under (in the same order as the consts).
+/// A type statement is exported as its type constructor,
+/// followed by its variant constructors (e.g., `Nat_s` for `Nat.s`).
+fn get_export_keys(registry: &NodeRegistry, item_id: FileItemNodeId) -> Vec<ValidJsIdentifierName> {
+    match item_id {
+        light::FileItemNodeId::Type(type_id) => {
+            let type_ = registry.get(type_id);
+            let type_key = registry.get(type_.name_id).name.export_key();
+            let variant_keys = registry
+                .get_possibly_empty_list(type_.variant_list_id)
+                .iter()
+                .map(|variant_id| {
+                    let variant_name = &registry.get(registry.get(*variant_id).name_id).name;
+                    ValidJsIdentifierName(format!("{}_{}", type_key.0, variant_name.export_key().0))
+                })
+                .collect::<Vec<_>>();
+            std::iter::once(type_key).chain(variant_keys).collect()
+        }
+        light::FileItemNodeId::Let(let_id) => {
+            vec![registry.get(registry.get(let_id).name_id).name.export_key()]
+        }
+    }
+}
+
+/// ES modules are evaluated one file at a time,
+/// so if two modules reference each other's items,
+/// then one of them will inevitably be evaluated
+/// while some of the items it depends on are still uninitialized.
+/// To avoid this, all the items of each group of mutually dependent modules
+/// are "held" by a single module (the one with the shortest path)
+/// in the order they were declared.
+/// The other modules in the group merely re-export their items
+/// from the holder.
+///
+/// This returns a map from each module to its holder.
+fn get_holders(file_tree: &FileTree, modules: &[ModuleReferences]) -> FxHashMap<FileId, FileId> {
+    let dependencies: Vec<Vec<usize>> = modules
+        .iter()
+        .map(|module| {
+            let mut out = vec![];
+            for (origin, _) in &module.references {
+                let NameOrigin::Module(file_id) = origin else {
+                    continue;
+                };
+                if let Some(index) = modules.iter().position(|m| m.file_id == *file_id) {
+                    if !out.contains(&index) {
+                        out.push(index);
+                    }
+                }
+            }
+            out
+        })
+        .collect();
+
+    let mut holders = FxHashMap::default();
+    for component in get_strongly_connected_components(&dependencies) {
+        let holder = component
+            .iter()
+            .map(|&index| modules[index].file_id)
+            .min_by_key(|&file_id| get_module_file_path(file_tree, file_id).len())
+            .expect("A strongly connected component should never be empty.");
+        for index in component {
+            holders.insert(modules[index].file_id, holder);
+        }
+    }
+    holders
+}
+
+/// Returns the strongly connected components of the graph
+/// (using Tarjan's algorithm).
+/// The nodes of each component are sorted in ascending order.
+fn get_strongly_connected_components(edges: &[Vec<usize>]) -> Vec<Vec<usize>> {
+    struct State<'a> {
+        edges: &'a [Vec<usize>],
+        next_index: usize,
+        indices: Vec<Option<usize>>,
+        low_links: Vec<usize>,
+        stack: Vec<usize>,
+        is_on_stack: Vec<bool>,
+        components: Vec<Vec<usize>>,
+    }
+
+    fn visit(state: &mut State, node: usize) {
+        state.indices[node] = Some(state.next_index);
+        state.low_links[node] = state.next_index;
+        state.next_index += 1;
+        state.stack.push(node);
+        state.is_on_stack[node] = true;
+
+        for &neighbor in &state.edges[node] {
+            match state.indices[neighbor] {
+                None => {
+                    visit(state, neighbor);
+                    state.low_links[node] = state.low_links[node].min(state.low_links[neighbor]);
+                }
+                Some(neighbor_index) if state.is_on_stack[neighbor] => {
+                    state.low_links[node] = state.low_links[node].min(neighbor_index);
+                }
+                Some(_) => {}
+            }
+        }
+
+        if Some(state.low_links[node]) == state.indices[node] {
+            let mut component = vec![];
+            loop {
+                let member = state.stack.pop().unwrap();
+                state.is_on_stack[member] = false;
+                component.push(member);
+                if member == node {
+                    break;
+                }
+            }
+            component.sort_unstable();
+            state.components.push(component);
+        }
+    }
+
+    let mut state = State {
+        edges,
+        next_index: 0,
+        indices: vec![None; edges.len()],
+        low_links: vec![0; edges.len()],
+        stack: vec![],
+        is_on_stack: vec![false; edges.len()],
+        components: vec![],
+    };
+    for node in 0..edges.len() {
+        if state.indices[node].is_none() {
+            visit(&mut state, node);
+        }
+    }
+    state.components
+}
+
+/// The names the module files export their items under.
+/// Both maps are keyed by the JS names of the items,
+/// which are unique across the pack.
+#[derive(Clone, Debug, Default)]
+struct ExportNames {
+    /// The name that the file of the module declaring the item exports it under.
+    /// This is the item's export key (see `get_export_keys`),
+    /// unless another item of the module already uses it.
+    module: FxHashMap<ValidJsIdentifierName, ValidJsIdentifierName>,
+    /// The name that the file of the holder of the module declaring the item
+    /// exports it under.
+    /// The holder's own items keep their module export names,
+    /// and the items of the other modules in its group
+    /// (which only need to be exported so that their modules can re-export them)
+    /// keep their JS names, unless that would cause a collision.
+    holder: FxHashMap<ValidJsIdentifierName, ValidJsIdentifierName>,
+}
+
+fn get_export_names(
+    modules: &[ModuleReferences],
+    holders: &FxHashMap<FileId, FileId>,
+) -> ExportNames {
+    let mut out = ExportNames::default();
+    let mut holder_taken: FxHashMap<FileId, Vec<ValidJsIdentifierName>> = FxHashMap::default();
+    for module in modules {
+        let mut taken = vec![];
+        for (js_name, key) in &module.items {
+            let name = take_unique_name(&mut taken, key);
+            out.module.insert(js_name.clone(), name.clone());
+            if holders[&module.file_id] == module.file_id {
+                out.holder.insert(js_name.clone(), name);
+            }
+        }
+        if holders[&module.file_id] == module.file_id {
+            holder_taken.insert(module.file_id, taken);
+        }
+    }
+    for module in modules {
+        let holder = holders[&module.file_id];
+        if holder == module.file_id {
+            continue;
+        }
+        let taken = holder_taken
+            .get_mut(&holder)
+            .expect("Every holder should hold its own items.");
+        for (js_name, _) in &module.items {
+            let name = take_unique_name(taken, js_name);
+            out.holder.insert(js_name.clone(), name);
+        }
+    }
+    out
+}
+
+/// Returns `preferred` if it isn't taken,
+/// and otherwise the first of `preferred2`, `preferred3`, ...
+/// that isn't taken.
+/// The returned name is marked as taken.
+fn take_unique_name(
+    taken: &mut Vec<ValidJsIdentifierName>,
+    preferred: &ValidJsIdentifierName,
+) -> ValidJsIdentifierName {
+    let mut name = preferred.clone();
+    let mut i = 2;
+    while taken.contains(&name) {
+        name = ValidJsIdentifierName(format!("{}{}", preferred.0, i));
+        i += 1;
+    }
+    taken.push(name.clone());
+    name
+}
+
+/// Items whose export names differ from their JS names
+/// are declared without `export` and exported at the end of the file
+/// (e.g., `export { eq4 as eq };`).
+fn generate_holder_module_file(
+    file_tree: &FileTree,
+    modules: &[ModuleReferences],
+    holders: &FxHashMap<FileId, FileId>,
+    export_names: &ExportNames,
+    item_codes: &[(FileId, Vec<ConstStatement>)],
+    holder: FileId,
+) -> File {
+    let path = get_module_file_path(file_tree, holder);
+    let mut imports: Vec<(Vec<String>, Vec<AliasedName>)> = vec![];
+    for module in modules {
+        if holders[&module.file_id] != holder {
+            continue;
+        }
+        for (origin, name) in &module.references {
+            let (source_path, imported_name) = match *origin {
+                NameOrigin::Module(file_id) if holders[&file_id] == holder => continue,
+                NameOrigin::Module(file_id) => (
+                    get_module_file_path(file_tree, file_id),
+                    export_names.module[name].clone(),
+                ),
+                NameOrigin::Runtime => (vec![RUNTIME_FILE_NAME.to_string()], name.clone()),
+                // Local names are never recorded as references.
+                NameOrigin::Local => unreachable!(),
+            };
+            add_import(
+                &mut imports,
+                source_path,
+                AliasedName {
+                    name: imported_name,
+                    alias: name.clone(),
+                },
+            );
+        }
+    }
+
+    let mut items: Vec<FileItem> = imports
+        .into_iter()
+        .map(|(source_path, names)| {
+            FileItem::Import(generate_import_statement(&path, &source_path, names))
+        })
+        .collect();
+    let mut renamed_exports = vec![];
+    for (file_id, consts) in item_codes {
+        if holders[file_id] != holder {
+            continue;
+        }
+        for const_ in consts {
+            let export_name = &export_names.holder[&const_.name];
+            if *export_name == const_.name {
+                items.push(FileItem::ExportConst(const_.clone()));
+            } else {
+                items.push(FileItem::Const(const_.clone()));
+                renamed_exports.push(AliasedName {
+                    name: const_.name.clone(),
+                    alias: export_name.clone(),
+                });
+            }
+        }
+    }
+    if !renamed_exports.is_empty() {
+        items.push(FileItem::Export(renamed_exports));
+    }
+    File { items }
+}
+
+fn generate_re_exporting_module_file(
+    file_tree: &FileTree,
+    holders: &FxHashMap<FileId, FileId>,
+    export_names: &ExportNames,
+    module: &ModuleReferences,
+) -> File {
+    let names = module
+        .items
+        .iter()
+        .map(|(js_name, _)| AliasedName {
+            name: export_names.holder[js_name].clone(),
+            alias: export_names.module[js_name].clone(),
+        })
+        .collect();
+    File {
+        items: vec![FileItem::ExportFrom(generate_import_statement(
+            &get_module_file_path(file_tree, module.file_id),
+            &get_module_file_path(file_tree, holders[&module.file_id]),
+            names,
+        ))],
+    }
+}
+
+/// `arg_checks` are the checkers and wrappers
+/// generated by `generate_code_for_arg_checks`.
+fn generate_index_file(
+    file_tree: &FileTree,
+    export_names: &ExportNames,
+    exports: &ExportTree,
+    arg_checks: Vec<ConstStatement>,
+) -> File {
+    let path = vec![INDEX_FILE_NAME.to_string()];
+    let mut imports = get_index_imports(file_tree, export_names, exports);
+    if !arg_checks.is_empty() {
+        let name = ValidJsIdentifierName(INVALID_ARG_ERROR_NAME.to_string());
+        add_import(
+            &mut imports,
+            vec![RUNTIME_FILE_NAME.to_string()],
+            AliasedName {
+                name: name.clone(),
+                alias: name,
+            },
+        );
+    }
+
+    let mut items: Vec<FileItem> = imports
+        .into_iter()
+        .map(|(source_path, names)| {
+            FileItem::Import(generate_import_statement(&path, &source_path, names))
+        })
+        .collect();
+    items.extend(arg_checks.into_iter().map(FileItem::Const));
+    items.push(FileItem::ExportConst(ConstStatement {
+        name: ValidJsIdentifierName(EXPORT_ROOT_NAME.to_string()),
+        value: exports.to_expression(),
+    }));
+    File { items }
+}
+
+/// `invalid_arg_error` is only emitted if `check_exported_args` is true,
+/// since only the arg checks in `index.js` use it.
+fn generate_runtime_file(max_universe_level: usize, check_exported_args: bool) -> File {
+    let mut consts: Vec<ConstStatement> =
+        generate_code_for_universes_without_adding_to_context(max_universe_level)
+            .into_iter()
+            .chain(generate_code_for_explosion_thrower())
+            .chain(generate_code_for_todo_error_thrower())
+            .chain(generate_code_for_recursion_runner())
+            .collect();
+    if check_exported_args {
+        consts.extend(generate_code_for_invalid_arg_error());
+    }
+    File {
+        items: consts.into_iter().map(FileItem::ExportConst).collect(),
+    }
+}
+
+fn generate_holder_module_declaration_file(
+    file_tree: &FileTree,
+    modules: &[ModuleReferences],
+    holders: &FxHashMap<FileId, FileId>,
+    export_names: &ExportNames,
+    item_declarations: &[(FileId, Vec<DeclarationItem>)],
+    holder: FileId,
+) -> DeclarationFile {
+    let path = get_module_file_path(file_tree, holder);
+    let mut imports: Vec<(Vec<String>, Vec<AliasedName>)> = vec![];
+    for module in modules {
+        if holders[&module.file_id] != holder {
+            continue;
+        }
+        for (file_id, name) in &module.type_references {
+            if holders[file_id] == holder {
+                continue;
+            }
+            add_import(
+                &mut imports,
+                get_module_file_path(file_tree, *file_id),
+                AliasedName {
+                    name: export_names.module[name].clone(),
+                    alias: name.clone(),
+                },
+            );
+        }
+    }
+
+    let mut items: Vec<DeclarationItem> = imports
+        .into_iter()
+        .map(|(source_path, names)| {
+            DeclarationItem::ImportType(generate_import_statement(&path, &source_path, names))
+        })
+        .collect();
+    // A type constructor's type alias has the same name as its const,
+    // so a single export specifier exports both.
+    let mut renamed_exports = vec![];
+    for (file_id, declarations) in item_declarations {
+        if holders[file_id] != holder {
+            continue;
+        }
+        for declaration in declarations {
+            let (name, unexported) = match declaration {
+                DeclarationItem::ExportType(alias) => {
+                    (&alias.name, DeclarationItem::Type(alias.clone()))
+                }
+                DeclarationItem::ExportConst(const_) => {
+                    (&const_.name, DeclarationItem::Const(const_.clone()))
+                }
+                _ => {
+                    items.push(declaration.clone());
+                    continue;
+                }
+            };
+            let export_name = &export_names.holder[name];
+            if export_name == name {
+                items.push(declaration.clone());
+                continue;
+            }
+            if let DeclarationItem::ExportConst(_) = declaration {
+                renamed_exports.push(AliasedName {
+                    name: name.clone(),
+                    alias: export_name.clone(),
+                });
+            }
+            items.push(unexported);
+        }
+    }
+    if !renamed_exports.is_empty() {
+        items.push(DeclarationItem::Export(renamed_exports));
+    }
+    DeclarationFile { items }
+}
+
+fn generate_index_declaration_file(
+    file_tree: &FileTree,
+    export_names: &ExportNames,
+    exports: &ExportTree,
+) -> DeclarationFile {
+    let path = vec![INDEX_FILE_NAME.to_string()];
+    let imports = get_index_imports(file_tree, export_names, exports);
+
+    let mut items: Vec<DeclarationItem> = imports
+        .into_iter()
+        .map(|(source_path, names)| {
+            DeclarationItem::Import(generate_import_statement(&path, &source_path, names))
+        })
+        .collect();
+    items.push(DeclarationItem::ExportConst(ConstDeclaration {
+        name: ValidJsIdentifierName(EXPORT_ROOT_NAME.to_string()),
+        type_: exports.to_declaration_type(),
+    }));
+    DeclarationFile { items }
+}
+
+/// See `generate_runtime_file`.
+fn generate_runtime_declaration_file(
+    max_universe_level: usize,
+    check_exported_args: bool,
+) -> DeclarationFile {
+    let universes = (0..=max_universe_level)
+        .rev()
+        .map(|level| ConstDeclaration {
+            name: ValidJsIdentifierName(universe_js_name(level)),
+            type_: TsType::Unknown,
+        });
+    let throwers = [
+        (EXPLOSION_THROWER_NAME, EXPLOSION_THROWER_PARAM0_NAME),
+        (TODO_ERROR_THROWER_NAME, TODO_ERROR_THROWER_PARAM0_NAME),
     ]
+    .into_iter()
+    .map(|(name, param0_name)| ConstDeclaration {
+        name: ValidJsIdentifierName(name.to_string()),
+        type_: TsType::Function(Box::new(FunctionType {
+            generics: vec![],
+            params: TsParams::Standard(vec![(
+                ValidJsIdentifierName(param0_name.to_string()),
+                TsType::String,
+            )]),
+            return_type: TsType::Never,
+        })),
+    });
+    let recursion_runner = ConstDeclaration {
+        name: ValidJsIdentifierName(RECURSION_RUNNER_NAME.to_string()),
+        type_: TsType::Function(Box::new(FunctionType {
+            generics: vec![],
+            params: TsParams::Standard(vec![
+                (
+                    ValidJsIdentifierName(RECURSION_RUNNER_PARAM0_NAME.to_string()),
+                    TsType::Unknown,
+                ),
+                (
+                    ValidJsIdentifierName(RECURSION_RUNNER_PARAM1_NAME.to_string()),
+                    TsType::Unknown,
+                ),
+            ]),
+            return_type: TsType::Unknown,
+        })),
+    };
+    let invalid_arg_error = ConstDeclaration {
+        name: ValidJsIdentifierName(INVALID_ARG_ERROR_NAME.to_string()),
+        type_: TsType::Function(Box::new(FunctionType {
+            generics: vec![],
+            params: TsParams::Standard(
+                [
+                    INVALID_ARG_ERROR_PARAM0_NAME,
+                    INVALID_ARG_ERROR_PARAM1_NAME,
+                    INVALID_ARG_ERROR_PARAM2_NAME,
+                ]
+                .into_iter()
+                .map(|name| (ValidJsIdentifierName(name.to_string()), TsType::String))
+                .collect(),
+            ),
+            return_type: TsType::Reference(Box::new(TypeReference {
+                name: ValidJsIdentifierName("Error".to_string()),
+                args: vec![],
+            })),
+        })),
+    };
+    DeclarationFile {
+        items: universes
+            .chain(throwers)
+            .chain([recursion_runner])
+            .chain(check_exported_args.then_some(invalid_arg_error))
+            .map(DeclarationItem::ExportConst)
+            .collect(),
+    }
 }
 
-fn generate_code_for_explosion_thrower() -> Vec<FileItem> {
+/// Returns the imports that `index.js` (and `index.d.ts`) need
+/// to refer to the leaves of `exports` by their JS names.
+fn get_index_imports(
+    file_tree: &FileTree,
+    export_names: &ExportNames,
+    exports: &ExportTree,
+) -> Vec<(Vec<String>, Vec<AliasedName>)> {
+    let mut imports = vec![];
+    exports.for_each_leaf(&mut |file_id, name| {
+        add_import(
+            &mut imports,
+            get_module_file_path(file_tree, file_id),
+            AliasedName {
+                name: export_names.module[name].clone(),
+                alias: name.clone(),
+            },
+        );
+    });
+    imports
+}
+
+fn add_import(
+    imports: &mut Vec<(Vec<String>, Vec<AliasedName>)>,
+    source_path: Vec<String>,
+    name: AliasedName,
+) {
+    let names = match imports.iter().position(|(path, _)| *path == source_path) {
+        Some(index) => &mut imports[index].1,
+        None => {
+            imports.push((source_path, vec![]));
+            &mut imports.last_mut().unwrap().1
+        }
+    };
+    if !names.contains(&name) {
+        names.push(name);
+    }
+}
+
+fn generate_import_statement(
+    importer_path: &[String],
+    source_path: &[String],
+    names: Vec<AliasedName>,
+) -> ImportStatement {
+    ImportStatement {
+        names,
+        specifier: JsStringLiteral {
+            unescaped: get_relative_import_specifier(importer_path, source_path),
+        },
+    }
+}
+
+/// Returns the path components of the file that a module is written to,
+/// relative to the target directory.
+/// For example, `pack.foo.bar` is written to `["pack", "foo", "bar.js"]`.
+fn get_module_file_path(file_tree: &FileTree, file_id: FileId) -> Vec<String> {
+    let mut labels = vec![];
+    let mut current = file_id;
+    while let Some((parent, label)) = file_tree.parent_and_label(current) {
+        labels.push(label.src_str().to_string());
+        current = parent;
+    }
+    labels.push(EXPORT_ROOT_NAME.to_string());
+    labels.reverse();
+
+    if let Some(last) = labels.last_mut() {
+        last.push_str(".js");
+    }
+    labels
+}
+
+/// Returns the path of the declaration file of the JS file at `js_path`
+/// (e.g., `pack/foo/bar.d.ts` for `pack/foo/bar.js`).
+fn get_declaration_file_path(js_path: &[String]) -> PathBuf {
+    let mut out: PathBuf = js_path.iter().collect();
+    out.set_extension("d.ts");
+    out
+}
+
+fn get_relative_import_specifier(importer_path: &[String], source_path: &[String]) -> String {
+    let importer_dir = &importer_path[..importer_path.len() - 1];
+    let common_len = importer_dir
+        .iter()
+        .zip(source_path)
+        .take_while(|(a, b)| a == b)
+        .count();
+    let mut out = if importer_dir.len() == common_len {
+        "./".to_string()
+    } else {
+        "../".repeat(importer_dir.len() - common_len)
+    };
+    out.push_str(&source_path[common_len..].join("/"));
+    out
+}
+
+fn generate_code_for_universes_without_adding_to_context(
+    max_universe_level: usize,
+) -> Vec<ConstStatement> {
+    (0..=max_universe_level)
+        .rev()
+        .map(|level| {
+            let name = universe_js_name(level);
+            ConstStatement {
+                name: ValidJsIdentifierName(name.clone()),
+                value: Expression::Object(Box::new(Object {
+                    entries: vec![ObjectEntry {
+                        key: ValidJsIdentifierName(TYPE_SPECIES_KEY.to_string()),
+                        value: Expression::Literal(Literal::String(JsStringLiteral {
+                            unescaped: name,
+                        })),
+                    }],
+                })),
+            }
+        })
+        .collect()
+}
+
+fn generate_code_for_explosion_thrower() -> Vec<ConstStatement> {
     let thrower_name = ValidJsIdentifierName(EXPLOSION_THROWER_NAME.to_string());
-    vec![FileItem::Const(ConstStatement {
+    vec![ConstStatement {
         name: thrower_name.clone(),
         value: Expression::Function(Box::new(Function {
             name: thrower_name,
@@ -83,14 +908,16 @@ fn generate_code_for_explosion_thrower() -> Vec<FileItem> {
                         EXPLOSION_THROWER_PARAM0_NAME.to_string(),
                     )),
                 }))],
+                span: None,
             })))],
+            span: None,
         })),
-    })]
+    }]
 }
 
-fn generate_code_for_todo_error_thrower() -> Vec<FileItem> {
+fn generate_code_for_todo_error_thrower() -> Vec<ConstStatement> {
     let thrower_name = ValidJsIdentifierName(TODO_ERROR_THROWER_NAME.to_string());
-    vec![FileItem::Const(ConstStatement {
+    vec![ConstStatement {
         name: thrower_name.clone(),
         value: Expression::Function(Box::new(Function {
             name: thrower_name,
@@ -109,9 +936,11 @@ fn generate_code_for_todo_error_thrower() -> Vec<FileItem> {
                         TODO_ERROR_THROWER_PARAM0_NAME.to_string(),
                     )),
                 }))],
+                span: None,
             })))],
+            span: None,
         })),
-    })]
+    }]
 }
 
 /// This produces a Const for the type constructor,
@@ -140,23 +969,34 @@ fn generate_code_for_type_statement(
     registry: &NodeRegistry,
     context: &mut Context,
     type_id: NodeId<light::TypeStatement>,
-) -> Result<Vec<FileItem>, CompileToJavaScriptError> {
+) -> Result<Vec<ConstStatement>, CompileToJavaScriptError> {
     let type_ = registry.get(type_id);
     let variant_ids = registry.get_possibly_empty_list(type_.variant_list_id);
     let mut out = Vec::with_capacity(variant_ids.len() + 1);
 
     let type_constructor = generate_code_for_type_constructor(registry, context, type_id)?;
-    out.push(FileItem::Const(type_constructor));
+    out.push(type_constructor);
     let type_constructor_js_name = context.js_name(DbIndex(0));
+    let type_constructor_level = context.index_to_level(DbIndex(0));
+    context.type_levels.insert(type_constructor_level, type_id);
+    let bigint_type = context.bigint.type_(type_id).cloned();
 
     for variant_id in variant_ids {
+        let bigint_variant = bigint_type.as_ref().map(|bigint_type| {
+            let variant_name = &registry.get(registry.get(*variant_id).name_id).name;
+            bigint_type
+                .variant(variant_name)
+                .expect("Every variant of a BigInt type should be recorded.")
+                .clone()
+        });
         let variant_constructor = generate_code_for_variant_constructor(
             registry,
             context,
             *variant_id,
             &type_constructor_js_name,
+            bigint_variant.as_ref(),
         )?;
-        out.push(FileItem::Const(variant_constructor));
+        out.push(variant_constructor);
     }
 
     Ok(out)
@@ -174,18 +1014,10 @@ fn generate_code_for_type_constructor(
         context,
         type_.param_list_id,
     )?;
-    context.pop_n(type_.param_list_id.len());
-
-    let type_name = &registry.get(type_.name_id).name;
-    context.try_push_name(type_name.preferred_js_name());
-    let type_js_name = context.js_name(DbIndex(0));
 
     let type_args = match &params {
-        Params::Standard(param_js_names) => Expression::Array(Box::new(Array {
-            items: param_js_names
-                .iter()
-                .map(|param| Expression::Identifier(param.clone()))
-                .collect(),
+        Params::Standard(_) | Params::Rest(_) => Expression::Array(Box::new(Array {
+            items: context.param_values(type_.param_list_id.len()),
         })),
         Params::DestructuredSingleton(entries) => Expression::Object(Box::new(Object {
             entries: entries
@@ -197,6 +1029,12 @@ fn generate_code_for_type_constructor(
                 .collect(),
         })),
     };
+    context.pop_n(type_.param_list_id.len());
+
+    let type_name = &registry.get(type_.name_id).name;
+    context.try_push_item_name(type_name.preferred_js_name());
+    context.set_last_omitted_params(get_omitted_params(registry, context, type_.param_list_id));
+    let type_js_name = context.js_name(DbIndex(0));
 
     let return_value = Expression::Object(Box::new(Object {
         entries: vec![
@@ -219,8 +1057,9 @@ fn generate_code_for_type_constructor(
             name: type_js_name,
             params,
             body: vec![FunctionStatement::Return(return_value)],
+            span: type_.span,
         }
-        .into_return_value_if_simple_nullary(),
+        .into_return_value_if_nullary(type_.param_list_id.is_none()),
     })
 }
 
@@ -230,61 +1069,121 @@ fn generate_code_for_optional_params_and_leave_params_in_context(
     param_list_id: Option<NonEmptyParamListId>,
 ) -> Result<Params, CompileToJavaScriptError> {
     match param_list_id {
-        Some(param_list_id) => {
-            generate_code_for_params_and_leave_params_in_context(registry, context, param_list_id)
-        }
+        Some(param_list_id) => generate_code_for_params_and_leave_params_in_context(
+            registry,
+            context,
+            param_list_id,
+            true,
+        ),
         None => Ok(Params::Standard(vec![])),
     }
 }
 
-fn generate_code_for_params_and_leave_params_in_context(
+/// Erased labeled params are always omitted.
+/// Erased unlabeled params are omitted if `should_omit_erased_params` is true,
+/// in which case every call must omit the corresponding args
+/// (see `ContextEntry::omitted_params`).
+/// Otherwise, they keep their positions (see `ErasureAnalysis`),
+/// unless they're trailing.
+/// We only omit them from functions whose callers are known statically
+/// (i.e., type constructors, variant constructors, and `let`s bound to `fun`s),
+/// since a caller of any other function cannot tell
+/// which params it erased.
+pub(super) fn generate_code_for_params_and_leave_params_in_context(
     registry: &NodeRegistry,
     context: &mut Context,
     param_list_id: NonEmptyParamListId,
+    should_omit_erased_params: bool,
 ) -> Result<Params, CompileToJavaScriptError> {
     Ok(match param_list_id {
         NonEmptyParamListId::Unlabeled(param_list_id) => {
             let param_ids = registry.get_list(param_list_id);
-            let param_js_names = param_ids
-                .iter()
-                .map(|id| {
-                    let param = registry.get(*id);
-                    let param_name = &registry.get(param.name_id).name;
-                    context.try_push_name(param_name.preferred_js_name());
-                    let param_js_name = context.js_name(DbIndex(0));
-                    param_js_name
-                })
-                .collect();
+            let mut param_js_names = Vec::with_capacity(param_ids.len());
+            let mut trailing_erased_count = 0;
+            for &id in param_ids.iter() {
+                let param = registry.get(id);
+                let param_name = &registry.get(param.name_id).name;
+                context.try_push_name(param_name.preferred_js_name());
+                if !context.erasure.is_unlabeled_param_erased(id) {
+                    param_js_names.push(context.js_name(DbIndex(0)));
+                    trailing_erased_count = 0;
+                    continue;
+                }
+                context.mark_last_as_erased();
+                if !should_omit_erased_params {
+                    param_js_names.push(context.js_name(DbIndex(0)));
+                    trailing_erased_count += 1;
+                }
+            }
+            param_js_names.truncate(param_js_names.len() - trailing_erased_count);
             Params::Standard(param_js_names)
         }
         NonEmptyParamListId::UniquelyLabeled(param_list_id) => {
             let param_ids = registry.get_list(param_list_id);
-            let entries = param_ids
-                .iter()
-                .map(|&id| {
-                    let param = registry.get(id);
-                    let param_name = &registry.get(param.name_id).name;
+            let mut entries = Vec::with_capacity(param_ids.len());
+            for &id in param_ids.iter() {
+                let param = registry.get(id);
+                let param_name = &registry.get(param.name_id).name;
+                if context.erasure.is_labeled_param_erased(id) {
                     context.try_push_name(param_name.preferred_js_name());
-                    let param_js_name = context.js_name(DbIndex(0));
+                    context.mark_last_as_erased();
+                    continue;
+                }
 
-                    let param_label_name = &registry.get(param.label_identifier_id()).name;
-                    let param_label_js_name = param_label_name.preferred_js_name();
-                    ObjectDestructureEntry {
-                        in_name: param_label_js_name,
-                        out_name: param_js_name,
-                    }
-                })
-                .collect();
+                // The default must be generated before the param is pushed,
+                // since it can only refer to earlier params.
+                let default = param
+                    .default_id
+                    .map(|default_id| generate_code_for_expression(registry, context, default_id))
+                    .transpose()?;
+                context.try_push_name(param_name.preferred_js_name());
+                let param_js_name = context.js_name(DbIndex(0));
+
+                let param_label_name = &registry.get(param.label_identifier_id()).name;
+                let param_label_js_name = param_label_name.preferred_js_name();
+                entries.push(ObjectDestructureEntry {
+                    in_name: param_label_js_name,
+                    out_name: param_js_name,
+                    default,
+                });
+            }
             Params::DestructuredSingleton(entries)
         }
     })
 }
 
+/// Returns which of the params are erased,
+/// or `None` if the params are labeled or no erased param is followed
+/// by an unerased one
+/// (since then, omitting the erased params is the same as
+/// omitting the trailing ones).
+fn get_omitted_params(
+    registry: &NodeRegistry,
+    context: &Context,
+    param_list_id: Option<NonEmptyParamListId>,
+) -> Option<Vec<bool>> {
+    let Some(NonEmptyParamListId::Unlabeled(param_list_id)) = param_list_id else {
+        return None;
+    };
+    let is_erased: Vec<bool> = registry
+        .get_list(param_list_id)
+        .iter()
+        .map(|id| context.erasure.is_unlabeled_param_erased(*id))
+        .collect();
+    let last_unerased_position = is_erased.iter().rposition(|is_erased| !is_erased)?;
+    is_erased[..last_unerased_position]
+        .contains(&true)
+        .then_some(is_erased)
+}
+
+/// If `bigint_variant` is `Some`, the variant belongs to a BigInt type,
+/// so the constructor returns a `bigint` instead of an array.
 fn generate_code_for_variant_constructor(
     registry: &NodeRegistry,
     context: &mut Context,
     variant_id: NodeId<light::Variant>,
     type_constructor_js_name: &ValidJsIdentifierName,
+    bigint_variant: Option<&BigIntVariant>,
 ) -> Result<ConstStatement, CompileToJavaScriptError> {
     let variant = registry.get(variant_id);
     let arity = variant.param_list_id.len();
@@ -296,10 +1195,9 @@ fn generate_code_for_variant_constructor(
     )?;
 
     let type_args: Vec<Expression> = match &params {
-        Params::Standard(param_js_names) => param_js_names
-            .iter()
-            .map(|param| Expression::Identifier(param.clone()))
-            .collect(),
+        // Erased args are `undefined`, so that the layout of
+        // the variant's fields doesn't depend on erasure.
+        Params::Standard(_) | Params::Rest(_) => context.param_values(arity),
         Params::DestructuredSingleton(entries) => vec![Expression::Object(Box::new(Object {
             entries: entries
                 .iter()
@@ -311,7 +1209,9 @@ fn generate_code_for_variant_constructor(
         }))],
     };
 
-    let return_value = {
+    let return_value = if let Some(bigint_variant) = bigint_variant {
+        generate_code_for_bigint_variant_value(bigint_variant, type_args)
+    } else {
         let mut items = Vec::with_capacity(arity + 1);
         items.push(Expression::Literal(Literal::String(JsStringLiteral {
             // We must use the variant JS name instead of the DB index's JS name
@@ -330,11 +1230,12 @@ fn generate_code_for_variant_constructor(
     context.pop_n(arity);
 
     let variant_name = &registry.get(variant.name_id).name;
-    context.try_push_name(ValidJsIdentifierName(format!(
+    context.try_push_item_name(ValidJsIdentifierName(format!(
         "{}_{}",
         &type_constructor_js_name.0,
         variant_name.preferred_js_name().0,
     )));
+    context.set_last_omitted_params(get_omitted_params(registry, context, variant.param_list_id));
     let variant_symbol_js_name = context.js_name(DbIndex(0));
 
     Ok(ConstStatement {
@@ -343,8 +1244,9 @@ fn generate_code_for_variant_constructor(
             name: variant_symbol_js_name,
             params,
             body: vec![FunctionStatement::Return(return_value)],
+            span: variant.span,
         }
-        .into_return_value_if_simple_nullary(),
+        .into_return_value_if_nullary(variant.param_list_id.is_none()),
     })
 }
 
@@ -353,20 +1255,112 @@ fn generate_code_for_let_statement(
     context: &mut Context,
     let_id: NodeId<light::LetStatement>,
 ) -> Result<ConstStatement, CompileToJavaScriptError> {
+    if let Some(function) = context.bigint.function(let_id).cloned() {
+        return Ok(generate_code_for_bigint_function_let_statement(
+            registry, context, let_id, &function,
+        ));
+    }
+
     let let_statement = registry.get(let_id);
 
-    let value = generate_code_for_expression(registry, context, let_statement.value_id)?;
+    let precomputed_value = context
+        .precomputed_lets
+        .value(let_id)
+        .and_then(|value| generate_code_for_precomputed_value(registry, context, value));
+    // Since every caller of a `let` bound to a `fun` knows the `fun`'s params,
+    // the `fun` can omit its erased params.
+    // An alias of such a `let` omits them too.
+    let (value, omitted_params) = match (
+        precomputed_value,
+        registry.expression_ref(let_statement.value_id),
+    ) {
+        (Some(value), _) => (value, None),
+        (None, ExpressionRef::Fun(fun)) => (
+            generate_code_for_fun(registry, context, fun, true)?,
+            get_omitted_params(registry, context, Some(fun.param_list_id)),
+        ),
+        (None, ExpressionRef::Name(name)) if context.omitted_params(name.db_index).is_some() => {
+            let omitted_params = context.omitted_params(name.db_index).map(<[bool]>::to_vec);
+            (context.reference(name.db_index), omitted_params)
+        }
+        (None, _) => (
+            generate_code_for_expression(registry, context, let_statement.value_id)?,
+            None,
+        ),
+    };
 
     let let_statement_name = &registry.get(let_statement.name_id).name;
-    context.try_push_name(let_statement_name.preferred_js_name());
+    context.try_push_item_name(let_statement_name.preferred_js_name());
+    context.set_last_omitted_params(omitted_params);
     let let_statement_js_name = context.js_name(DbIndex(0));
+    record_let_statement_value_if_inlinable(context, &value);
     Ok(ConstStatement {
         name: let_statement_js_name,
         value,
     })
 }
 
-fn generate_code_for_expression(
+/// Returns a literal that evaluates to the value,
+/// or `None` if a BigInt in the value is too large.
+fn generate_code_for_precomputed_value(
+    registry: &NodeRegistry,
+    context: &Context,
+    value: &PrecomputedValue,
+) -> Option<Expression> {
+    if context.bigint.type_(value.type_id).is_some() {
+        let number = get_precomputed_bigint(registry, context, value)?;
+        return Some(if number < 0 {
+            binary_op(
+                bigint_literal(0),
+                BinaryOpKind::Minus,
+                bigint_literal(-number),
+            )
+        } else {
+            bigint_literal(number)
+        });
+    }
+
+    // The layout must match the one built by the variant's constructor
+    // (see `generate_code_for_variant_constructor`).
+    let variant = registry.get(value.variant_id);
+    let mut items = vec![Expression::Literal(Literal::String(JsStringLiteral {
+        unescaped: registry.get(variant.name_id).name.preferred_js_name().0,
+    }))];
+    match variant.param_list_id {
+        None | Some(NonEmptyParamListId::Unlabeled(_)) => {
+            for arg in &value.args {
+                items.push(match arg {
+                    Some(arg) => generate_code_for_precomputed_value(registry, context, arg)?,
+                    None => undefined(),
+                });
+            }
+        }
+        Some(NonEmptyParamListId::UniquelyLabeled(param_list_id)) => {
+            let mut entries = vec![];
+            for (&param_id, arg) in registry.get_list(param_list_id).iter().zip(&value.args) {
+                let Some(arg) = arg else {
+                    continue;
+                };
+                let param = registry.get(param_id);
+                entries.push(ObjectEntry {
+                    key: registry
+                        .get(param.label_identifier_id())
+                        .name
+                        .preferred_js_name(),
+                    value: generate_code_for_precomputed_value(registry, context, arg)?,
+                });
+            }
+            items.push(Expression::Object(Box::new(Object { entries })));
+        }
+    }
+    Some(Expression::Array(Box::new(Array { items })))
+}
+
+pub(super) fn binary_op(left: Expression, op: BinaryOpKind, right: Expression) -> Expression {
+    Expression::BinaryOp(Box::new(BinaryOp { op, left, right }))
+}
+
+pub(super) fn generate_code_for_expression(
     registry: &NodeRegistry,
     context: &mut Context,
     id: light::ExpressionId,
@@ -376,12 +1370,22 @@ fn generate_code_for_expression(
         ExpressionRef::Name(name) => generate_code_for_name_expression(registry, context, name),
         ExpressionRef::Todo(todo) => generate_code_for_todo_expression(registry, context, todo),
         ExpressionRef::Call(call) => generate_code_for_call(registry, context, call),
-        ExpressionRef::Fun(fun) => generate_code_for_fun(registry, context, fun),
+        ExpressionRef::Fun(fun) => generate_code_for_fun(registry, context, fun, false),
         ExpressionRef::Match(match_) => generate_code_for_match(registry, context, match_),
         ExpressionRef::Forall(forall) => generate_code_for_forall(registry, context, forall),
         ExpressionRef::Check(check) => {
             generate_code_for_expression(registry, context, check.output_id)
         }
+        // Since a rewrite only changes the type of its body,
+        // we can compile it away.
+        ExpressionRef::Rewrite(rewrite) => {
+            generate_code_for_expression(registry, context, rewrite.body_id)
+        }
+        ExpressionRef::Projection(projection) => {
+            generate_code_for_projection(registry, context, projection)
+        }
+        ExpressionRef::Exists(exists) => generate_code_for_exists(registry, context, exists),
+        ExpressionRef::Pair(pair) => generate_code_for_pair(registry, context, pair),
     }
 }
 
@@ -390,48 +1394,193 @@ fn generate_code_for_name_expression(
     context: &mut Context,
     name: &light::NameExpression,
 ) -> Result<Expression, CompileToJavaScriptError> {
-    let identifier_name = context.js_name(name.db_index);
-    Ok(Expression::Identifier(identifier_name))
+    Ok(generate_code_for_value_reference(context, name.db_index))
+}
+
+/// Like `Context::reference`, except that if the entry is a function
+/// that omits erased params (see `ContextEntry::omitted_params`),
+/// this wraps it in a function that takes every param (except the
+/// trailing erased ones), since whoever calls the value
+/// doesn't know which params it omits.
+///
+/// For example, if `List_cons` omits its `T` param, it's wrapped in
+/// ```js
+/// function temp_0(temp_1, temp_2, temp_3) {
+///     return List_cons(temp_2, temp_3);
+/// }
+/// ```
+fn generate_code_for_value_reference(context: &mut Context, index: DbIndex) -> Expression {
+    let Some(omitted_params) = context.omitted_params(index).map(<[bool]>::to_vec) else {
+        return context.reference(index);
+    };
+    let callee = context.reference(index);
+    let name = context.get_disposable_name();
+    let last_kept_position = omitted_params
+        .iter()
+        .rposition(|is_omitted| !is_omitted)
+        .expect("A function that omits params should keep at least one param.");
+    let mut params = Vec::with_capacity(last_kept_position + 1);
+    let mut args = Vec::with_capacity(last_kept_position + 1);
+    for &is_omitted in &omitted_params[..=last_kept_position] {
+        let param = context.get_disposable_name();
+        if !is_omitted {
+            args.push(Expression::Identifier(param.clone()));
+        }
+        params.push(param);
+    }
+    Expression::Function(Box::new(Function {
+        name,
+        params: Params::Standard(params),
+        body: vec![FunctionStatement::Return(Expression::Call(Box::new(
+            Call {
+                callee,
+                args,
+                span: None,
+            },
+        )))],
+        span: None,
+    }))
+}
+
+/// A struct value is a variant with labeled args
+/// (i.e., `["new", { field: value, ... }]`),
+/// so we read the field from the args object.
+fn generate_code_for_projection(
+    registry: &NodeRegistry,
+    context: &mut Context,
+    projection: &light::Projection,
+) -> Result<Expression, CompileToJavaScriptError> {
+    let record = generate_code_for_expression(registry, context, projection.record_id)?;
+    if is_undefined(&record) {
+        return Ok(record);
+    }
+    let field_name = &registry.get(projection.field_name_id).name;
+    Ok(Expression::Dot(Box::new(Dot {
+        left: Expression::BinaryOp(Box::new(BinaryOp {
+            left: record,
+            op: BinaryOpKind::Index,
+            right: Expression::Literal(Literal::Number(1)),
+        })),
+        right: field_name.preferred_js_name(),
+    })))
+}
+
+pub(super) fn undefined() -> Expression {
+    Expression::Identifier(ValidJsIdentifierName(UNDEFINED_NAME.to_string()))
+}
+
+fn is_undefined(expression: &Expression) -> bool {
+    matches!(expression, Expression::Identifier(name) if name.0 == UNDEFINED_NAME)
 }
 
 fn generate_code_for_todo_expression(
     _registry: &NodeRegistry,
-    _context: &mut Context,
+    context: &mut Context,
     todo: &light::TodoExpression,
 ) -> Result<Expression, CompileToJavaScriptError> {
+    let location = context.format_span_start(todo.span);
     Ok(Expression::Call(Box::new(Call {
-        callee: Expression::Identifier(ValidJsIdentifierName(TODO_ERROR_THROWER_NAME.to_string())),
+        callee: Expression::Identifier(context.reference_runtime(TODO_ERROR_THROWER_NAME)),
         args: vec![Expression::Literal(Literal::String(JsStringLiteral {
-            unescaped: todo
-                .span
-                .map(format_text_span)
-                .unwrap_or_else(|| "<No span found>".to_string()),
+            unescaped: location,
         }))],
+        span: todo.span,
     })))
 }
 
-fn format_text_span(span: TextSpan) -> String {
-    format!("{:?}", span)
-}
-
 fn generate_code_for_call(
     registry: &NodeRegistry,
     context: &mut Context,
     call: &light::Call,
 ) -> Result<Expression, CompileToJavaScriptError> {
-    let callee = generate_code_for_expression(registry, context, call.callee_id)?;
-    let args = match call.arg_list_id {
+    let callee = match registry.expression_ref(call.callee_id) {
+        // Since the args are passed the way the callee expects
+        // (see `generate_code_for_call_args`),
+        // we don't need `generate_code_for_value_reference`.
+        ExpressionRef::Name(name) => context.reference(name.db_index),
+        _ => generate_code_for_expression(registry, context, call.callee_id)?,
+    };
+    // Only erased functions (which return erased values) are `undefined`.
+    if is_undefined(&callee) {
+        return Ok(callee);
+    }
+    let args = generate_code_for_call_args(registry, context, call)?;
+    if let Some(inlined) = try_inline_call(registry, context, call, &args) {
+        return Ok(inlined);
+    }
+    // See `generate_code_for_stack_safe_fun`.
+    let is_yielding_self_call = is_self_call(registry, context, call)
+        && context
+            .recursion
+            .as_ref()
+            .map(|recursion| recursion.is_generator)
+            .unwrap_or(false);
+    if is_yielding_self_call {
+        return Ok(Expression::Yield(Box::new(Yield {
+            argument: Expression::Array(Box::new(Array { items: args })),
+            is_delegate: false,
+        })));
+    }
+    Ok(Expression::Call(Box::new(Call {
+        callee,
+        args,
+        span: call.span,
+    })))
+}
+
+/// Unlabeled args are passed positionally,
+/// and labeled args are passed as a single object.
+/// Erased args are omitted if the callee omits the corresponding params
+/// (see `ContextEntry::omitted_params`).
+/// Otherwise, they keep their positions (see `ErasureAnalysis`),
+/// unless they're trailing.
+pub(super) fn generate_code_for_call_args(
+    registry: &NodeRegistry,
+    context: &mut Context,
+    call: &light::Call,
+) -> Result<Vec<Expression>, CompileToJavaScriptError> {
+    let erased_args = context.erasure.erased_args(call.id).cloned();
+    Ok(match call.arg_list_id {
         NonEmptyCallArgListId::Unlabeled(arg_list_id) => {
             let arg_ids = registry.get_list(arg_list_id);
-            arg_ids
-                .iter()
-                .map(|arg_id| generate_code_for_expression(registry, context, *arg_id))
-                .collect::<Result<Vec<_>, _>>()?
+            let is_erased = match erased_args {
+                Some(ErasedArgs::Unlabeled(is_erased)) if is_erased.len() == arg_ids.len() => {
+                    is_erased
+                }
+                _ => vec![false; arg_ids.len()],
+            };
+            let omitted_params = match registry.expression_ref(call.callee_id) {
+                ExpressionRef::Name(name) => context.omitted_params(name.db_index),
+                _ => None,
+            }
+            .map(<[bool]>::to_vec)
+            .unwrap_or_default();
+            let mut args = Vec::with_capacity(arg_ids.len());
+            let mut trailing_erased_count = 0;
+            for (position, (arg_id, is_erased)) in arg_ids.iter().zip(&is_erased).enumerate() {
+                if omitted_params.get(position).copied().unwrap_or(false) {
+                    continue;
+                }
+                if *is_erased {
+                    args.push(undefined());
+                    trailing_erased_count += 1;
+                } else {
+                    args.push(generate_code_for_expression(registry, context, *arg_id)?);
+                    trailing_erased_count = 0;
+                }
+            }
+            args.truncate(args.len() - trailing_erased_count);
+            args
         }
         NonEmptyCallArgListId::UniquelyLabeled(arg_list_id) => {
+            let erased_labels = match erased_args {
+                Some(ErasedArgs::Labeled(erased_labels)) => erased_labels,
+                _ => vec![],
+            };
             let arg_ids = registry.get_list(arg_list_id);
             let entries = arg_ids
                 .iter()
+                .filter(|arg_id| !erased_labels.contains(&registry.get(arg_id.label_id()).name))
                 .map(|arg_id| {
                     Ok(match arg_id {
                         LabeledCallArgId::Implicit {
@@ -440,7 +1589,7 @@ fn generate_code_for_call(
                             value_id: _,
                         } => {
                             let key = registry.get(*label_id).name.preferred_js_name();
-                            let value = Expression::Identifier(context.js_name(*db_index));
+                            let value = generate_code_for_value_reference(context, *db_index);
                             ObjectEntry { key, value }
                         }
                         LabeledCallArgId::Explicit { label_id, value_id } => {
@@ -453,37 +1602,104 @@ fn generate_code_for_call(
                 .collect::<Result<Vec<_>, _>>()?;
             vec![Expression::Object(Box::new(Object { entries }))]
         }
-    };
-    Ok(Expression::Call(Box::new(Call { callee, args })))
+    })
 }
 
+/// See `generate_code_for_params_and_leave_params_in_context`
+/// for the meaning of `should_omit_erased_params`.
 fn generate_code_for_fun(
     registry: &NodeRegistry,
     context: &mut Context,
     fun: &light::Fun,
+    should_omit_erased_params: bool,
 ) -> Result<Expression, CompileToJavaScriptError> {
+    if context.stack_safe {
+        return generate_code_for_stack_safe_fun(registry, context, fun, should_omit_erased_params);
+    }
+
     let param_arity = fun.param_list_id.len();
-    let params =
-        generate_code_for_params_and_leave_params_in_context(registry, context, fun.param_list_id)?;
-    let fun_js_name = {
-        let fun_name = &registry.get(fun.name_id).name;
-        context.try_push_name(fun_name.preferred_js_name());
-        context.js_name(DbIndex(0))
-    };
+    let params = generate_code_for_params_and_leave_params_in_context(
+        registry,
+        context,
+        fun.param_list_id,
+        should_omit_erased_params,
+    )?;
+    let fun_js_name = push_fun_name(registry, context, fun, should_omit_erased_params);
     let return_value = generate_code_for_expression(registry, context, fun.body_id)?;
     context.pop_n(param_arity + 1);
     Ok(Expression::Function(Box::new(Function {
         name: fun_js_name,
         params,
         body: vec![FunctionStatement::Return(return_value)],
+        span: fun.span,
     })))
 }
 
+/// Pushes the name of the fun (which is in scope in its body)
+/// and returns its JS name.
+pub(super) fn push_fun_name(
+    registry: &NodeRegistry,
+    context: &mut Context,
+    fun: &light::Fun,
+    should_omit_erased_params: bool,
+) -> ValidJsIdentifierName {
+    let fun_name = &registry.get(fun.name_id).name;
+    context.try_push_name(fun_name.preferred_js_name());
+    if should_omit_erased_params {
+        context.set_last_omitted_params(get_omitted_params(
+            registry,
+            context,
+            Some(fun.param_list_id),
+        ));
+    }
+    context.js_name(DbIndex(0))
+}
+
 fn generate_code_for_match(
     registry: &NodeRegistry,
     context: &mut Context,
     match_: &light::Match,
 ) -> Result<Expression, CompileToJavaScriptError> {
+    if context.erasure.is_match_erased(match_.id) {
+        return generate_code_for_erased_match(registry, context, match_);
+    }
+    if context.stack_safe {
+        // Since the match isn't in tail position,
+        // we wrap its statements in an immediately invoked function.
+        // If we're in a generator, the function must be a generator too,
+        // so that self calls in the match can yield.
+        let fun_temp_name = context.get_disposable_name();
+        let body = generate_code_for_match_statements(registry, context, match_, false)?;
+        let function = Box::new(Function {
+            name: fun_temp_name,
+            params: Params::Standard(vec![]),
+            body,
+            span: match_.span,
+        });
+        let is_generator = context
+            .recursion
+            .as_ref()
+            .map(|recursion| recursion.is_generator)
+            .unwrap_or(false);
+        return Ok(if is_generator {
+            Expression::Yield(Box::new(Yield {
+                argument: Expression::Call(Box::new(Call {
+                    callee: Expression::GeneratorFunction(function),
+                    args: vec![],
+                    span: match_.span,
+                })),
+                is_delegate: true,
+            }))
+        } else {
+            Expression::Call(Box::new(Call {
+                callee: Expression::Function(function),
+                args: vec![],
+                span: match_.span,
+            }))
+        });
+    }
+    let bigint_type = context.bigint.match_type(match_.id).cloned();
+
     let matchee_temp_name = context.get_disposable_name();
     let fun_temp_name = context.get_disposable_name();
 
@@ -493,7 +1709,16 @@ fn generate_code_for_match(
         .iter()
         .map(|case_id| {
             let case = registry.get(*case_id);
-            generate_code_for_match_case(registry, context, case, &matchee_temp_name)
+            match &bigint_type {
+                Some(bigint_type) => generate_code_for_bigint_match_case(
+                    registry,
+                    context,
+                    case,
+                    &matchee_temp_name,
+                    bigint_type,
+                ),
+                None => generate_code_for_match_case(registry, context, case, &matchee_temp_name),
+            }
         })
         .collect::<Result<Vec<_>, _>>()?;
 
@@ -502,11 +1727,40 @@ fn generate_code_for_match(
             name: fun_temp_name,
             params: Params::Standard(vec![matchee_temp_name.clone()]),
             body: cases.into_iter().map(FunctionStatement::If).collect(),
+            span: match_.span,
         })),
         args: vec![matchee],
+        span: match_.span,
     })))
 }
 
+/// Since the matchee is erased, there's nothing to inspect at runtime.
+/// However, an erased match has at most one case,
+/// so we can just return that case's output
+/// (with the case params erased).
+fn generate_code_for_erased_match(
+    registry: &NodeRegistry,
+    context: &mut Context,
+    match_: &light::Match,
+) -> Result<Expression, CompileToJavaScriptError> {
+    let case_ids = registry.get_possibly_empty_list(match_.case_list_id);
+    let Some(case_id) = case_ids.first() else {
+        return Ok(generate_code_to_throw_explosion(context, match_.span));
+    };
+    let case = registry.get(*case_id);
+    let explicit_arity = case
+        .param_list_id
+        .map(|list_id| list_id.explicit_len())
+        .unwrap_or(0);
+    for _ in 0..explicit_arity {
+        context.try_push_name(ValidJsIdentifierName(UNDEFINED_NAME.to_string()));
+        context.mark_last_as_erased();
+    }
+    let output = generate_code_for_match_case_output(registry, context, case.output_id)?;
+    context.pop_n(explicit_arity);
+    Ok(output)
+}
+
 fn generate_code_for_match_case(
     registry: &NodeRegistry,
     context: &mut Context,
@@ -533,61 +1787,8 @@ fn generate_code_for_match_case(
             .param_list_id
             .map(|list_id| list_id.explicit_len())
             .unwrap_or(0);
-        let mut body = Vec::with_capacity(explicit_arity + 1);
-
-        match case.param_list_id {
-            None => {}
-            Some(NonEmptyMatchCaseParamListId::Unlabeled(param_list_id)) => {
-                for (param_index, param_id) in
-                    registry.get_list(param_list_id).iter().copied().enumerate()
-                {
-                    let param_name = &registry.get(param_id).name;
-                    context.try_push_name(param_name.preferred_js_name());
-                    let param_js_name = context.js_name(DbIndex(0));
-                    let field_index = i32::try_from(1 + param_index)
-                        .expect("The param index should not be absurdly large.");
-                    let param_value = Expression::BinaryOp(Box::new(BinaryOp {
-                        left: Expression::Identifier(matchee_js_name.clone()),
-                        op: BinaryOpKind::Index,
-                        right: Expression::Literal(Literal::Number(field_index)),
-                    }));
-                    body.push(FunctionStatement::Const(ConstStatement {
-                        name: param_js_name,
-                        value: param_value,
-                    }));
-                }
-            }
-            Some(NonEmptyMatchCaseParamListId::UniquelyLabeled {
-                param_list_id,
-                triple_dot: _,
-            }) => {
-                let args_obj = Expression::BinaryOp(Box::new(BinaryOp {
-                    left: Expression::Identifier(matchee_js_name.clone()),
-                    op: BinaryOpKind::Index,
-                    right: Expression::Literal(Literal::Number(1)),
-                }));
-                for param_id in registry
-                    .get_possibly_empty_list(param_list_id)
-                    .iter()
-                    .copied()
-                {
-                    let param_name_id = registry.get(param_id).name_id;
-                    let param_name = &registry.get(param_name_id).name;
-                    context.try_push_name(param_name.preferred_js_name());
-                    let param_js_name = context.js_name(DbIndex(0));
-                    let param_label_id = registry.get(param_id).label_identifier_id();
-                    let param_label_name = &registry.get(param_label_id).name;
-                    let param_value = Expression::Dot(Box::new(Dot {
-                        left: args_obj.clone(),
-                        right: param_label_name.preferred_js_name(),
-                    }));
-                    body.push(FunctionStatement::Const(ConstStatement {
-                        name: param_js_name,
-                        value: param_value,
-                    }));
-                }
-            }
-        }
+        let mut body =
+            generate_code_for_match_case_params(registry, context, case, matchee_js_name);
 
         body.push(FunctionStatement::Return(
             generate_code_for_match_case_output(registry, context, case.output_id)?,
@@ -601,7 +1802,79 @@ fn generate_code_for_match_case(
     Ok(IfStatement { condition, body })
 }
 
-fn generate_code_for_match_case_output(
+/// Returns the statements that declare the case params,
+/// which are read from the matchee's fields.
+/// The case params are left in the context.
+pub(super) fn generate_code_for_match_case_params(
+    registry: &NodeRegistry,
+    context: &mut Context,
+    case: &light::MatchCase,
+    matchee_js_name: &ValidJsIdentifierName,
+) -> Vec<FunctionStatement> {
+    let explicit_arity = case
+        .param_list_id
+        .map(|list_id| list_id.explicit_len())
+        .unwrap_or(0);
+    let mut body = Vec::with_capacity(explicit_arity + 1);
+
+    match case.param_list_id {
+        None => {}
+        Some(NonEmptyMatchCaseParamListId::Unlabeled(param_list_id)) => {
+            for (param_index, param_id) in
+                registry.get_list(param_list_id).iter().copied().enumerate()
+            {
+                let param_name = &registry.get(param_id).name;
+                context.try_push_name(param_name.preferred_js_name());
+                let param_js_name = context.js_name(DbIndex(0));
+                let field_index = i32::try_from(1 + param_index)
+                    .expect("The param index should not be absurdly large.");
+                let param_value = Expression::BinaryOp(Box::new(BinaryOp {
+                    left: Expression::Identifier(matchee_js_name.clone()),
+                    op: BinaryOpKind::Index,
+                    right: Expression::Literal(Literal::Number(field_index)),
+                }));
+                body.push(FunctionStatement::Const(ConstStatement {
+                    name: param_js_name,
+                    value: param_value,
+                }));
+            }
+        }
+        Some(NonEmptyMatchCaseParamListId::UniquelyLabeled {
+            param_list_id,
+            triple_dot: _,
+        }) => {
+            let args_obj = Expression::BinaryOp(Box::new(BinaryOp {
+                left: Expression::Identifier(matchee_js_name.clone()),
+                op: BinaryOpKind::Index,
+                right: Expression::Literal(Literal::Number(1)),
+            }));
+            for param_id in registry
+                .get_possibly_empty_list(param_list_id)
+                .iter()
+                .copied()
+            {
+                let param_name_id = registry.get(param_id).name_id;
+                let param_name = &registry.get(param_name_id).name;
+                context.try_push_name(param_name.preferred_js_name());
+                let param_js_name = context.js_name(DbIndex(0));
+                let param_label_id = registry.get(param_id).label_identifier_id();
+                let param_label_name = &registry.get(param_label_id).name;
+                let param_value = Expression::Dot(Box::new(Dot {
+                    left: args_obj.clone(),
+                    right: param_label_name.preferred_js_name(),
+                }));
+                body.push(FunctionStatement::Const(ConstStatement {
+                    name: param_js_name,
+                    value: param_value,
+                }));
+            }
+        }
+    }
+
+    body
+}
+
+pub(super) fn generate_code_for_match_case_output(
     registry: &NodeRegistry,
     context: &mut Context,
     id: MatchCaseOutputId,
@@ -609,19 +1882,22 @@ fn generate_code_for_match_case_output(
     match id {
         MatchCaseOutputId::Some(id) => generate_code_for_expression(registry, context, id),
         MatchCaseOutputId::ImpossibilityClaim(kw_span) => {
-            Ok(generate_code_to_throw_explosion(kw_span))
+            Ok(generate_code_to_throw_explosion(context, kw_span))
         }
     }
 }
 
-fn generate_code_to_throw_explosion(responsible_span: Option<TextSpan>) -> Expression {
+pub(super) fn generate_code_to_throw_explosion(
+    context: &mut Context,
+    responsible_span: Option<TextSpan>,
+) -> Expression {
+    let location = context.format_span_start(responsible_span);
     Expression::Call(Box::new(Call {
-        callee: Expression::Identifier(ValidJsIdentifierName(EXPLOSION_THROWER_NAME.to_string())),
+        callee: Expression::Identifier(context.reference_runtime(EXPLOSION_THROWER_NAME)),
         args: vec![Expression::Literal(Literal::String(JsStringLiteral {
-            unescaped: responsible_span
-                .map(format_text_span)
-                .unwrap_or_else(|| "<No span found>".to_string()),
+            unescaped: location,
         }))],
+        span: responsible_span,
     }))
 }
 
@@ -640,30 +1916,88 @@ fn generate_code_for_forall(
     })))
 }
 
+fn generate_code_for_exists(
+    _registry: &NodeRegistry,
+    _context: &mut Context,
+    _name: &light::Exists,
+) -> Result<Expression, CompileToJavaScriptError> {
+    Ok(Expression::Object(Box::new(Object {
+        entries: vec![ObjectEntry {
+            key: ValidJsIdentifierName(TYPE_SPECIES_KEY.to_string()),
+            value: Expression::Literal(Literal::String(JsStringLiteral {
+                unescaped: TYPE_SPECIES_VALUE__EXISTS.to_string(),
+            })),
+        }],
+    })))
+}
+
+/// A pair is represented the same way as a variant value
+/// with two args, so that pair match cases can be compiled
+/// like any other match case.
+fn generate_code_for_pair(
+    registry: &NodeRegistry,
+    context: &mut Context,
+    pair: &light::Pair,
+) -> Result<Expression, CompileToJavaScriptError> {
+    let witness = generate_code_for_expression(registry, context, pair.witness_id)?;
+    let proof = generate_code_for_expression(registry, context, pair.proof_id)?;
+    Ok(Expression::Array(Box::new(Array {
+        items: vec![
+            Expression::Literal(Literal::String(JsStringLiteral {
+                unescaped: bijectively_sanitize_js_identifier_name(light::PAIR_VARIANT_NAME).0,
+            })),
+            witness,
+            proof,
+        ],
+    })))
+}
+
 const TYPE_SPECIES_KEY: &str = "type_species";
-const TYPE_SPECIES_VALUE__TYPE1: &str = "Type1";
-const TYPE_SPECIES_VALUE__TYPE0: &str = "Type";
 const TYPE_SPECIES_VALUE__FORALL: &str = "forall";
+const TYPE_SPECIES_VALUE__EXISTS: &str = "exists";
+
+/// Returns the name of the universe at `level`, which is used both as
+/// the name of the universe's constant and as its type species.
+fn universe_js_name(level: usize) -> String {
+    light::IdentifierName::Reserved(light::ReservedIdentifierName::universe(level))
+        .src_str()
+        .to_string()
+}
 const TYPE_ARGS_KEY: &str = "type_args";
+pub(super) const UNDEFINED_NAME: &str = "undefined";
+
 const EXPLOSION_THROWER_NAME: &str = "unreachable";
 const EXPLOSION_THROWER_PARAM0_NAME: &str = "unreachable_span";
 const TODO_ERROR_THROWER_NAME: &str = "unimplemented";
 const TODO_ERROR_THROWER_PARAM0_NAME: &str = "unimplemented_span";
+pub(super) const RECURSION_RUNNER_NAME: &str = "run_recursion";
+pub(super) const RECURSION_RUNNER_PARAM0_NAME: &str = "generator";
+pub(super) const RECURSION_RUNNER_PARAM1_NAME: &str = "args";
+pub(super) const INVALID_ARG_ERROR_NAME: &str = "invalid_arg_error";
+pub(super) const INVALID_ARG_ERROR_PARAM0_NAME: &str = "invalid_arg";
+pub(super) const INVALID_ARG_ERROR_PARAM1_NAME: &str = "invalid_arg_path";
+pub(super) const INVALID_ARG_ERROR_PARAM2_NAME: &str = "invalid_arg_expected";
+pub(super) const WRAPPED_ARGS_NAME: &str = "wrapped_args";
 const DISPOSABLE_NAME_PREFIX: &str = "temp";
+pub(super) const EXPORT_ROOT_NAME: &str = "pack";
+const INDEX_FILE_NAME: &str = "index.js";
+const RUNTIME_FILE_NAME: &str = "runtime.js";
 
 impl Function {
     /// A "simple" function is one that has a body that has a return statement
     /// and no other statements.
     /// This method returns the return value of the function if it is a simple
-    /// function with zero parameters, otherwise it returns the function itself.
+    /// function and `is_nullary` is true, otherwise it returns the function itself.
     ///
     /// Invocations of nullary type constructors and variant constructors
     /// are represented as identifiers, not calls.
     /// Thus, when we're declaring these constructors, we must make sure
-    /// to declare non-nullary constructors as values rather than nullary
+    /// to declare nullary constructors as values rather than nullary
     /// functions.
-    fn into_return_value_if_simple_nullary(self) -> Expression {
-        if self.params.is_empty() && matches!(&self.body[..], [FunctionStatement::Return(_)]) {
+    /// Note that a constructor whose params are all erased has zero JS params,
+    /// but it is still invoked with a call, so it is not nullary.
+    fn into_return_value_if_nullary(self, is_nullary: bool) -> Expression {
+        if is_nullary && matches!(&self.body[..], [FunctionStatement::Return(_)]) {
             match self.body.into_iter().next() {
                 Some(FunctionStatement::Return(value)) => value,
                 _ => unreachable!(),
@@ -675,12 +2009,29 @@ impl Function {
 }
 
 impl light::IdentifierName {
-    fn preferred_js_name(&self) -> ValidJsIdentifierName {
+    pub(super) fn preferred_js_name(&self) -> ValidJsIdentifierName {
         bijectively_sanitize_js_identifier_name(self.src_str())
     }
+
+    fn export_key(&self) -> ValidJsIdentifierName {
+        ValidJsIdentifierName(bijectively_escape_js_identifier_chars(self.src_str()))
+    }
 }
 
-fn bijectively_sanitize_js_identifier_name(s: &str) -> ValidJsIdentifierName {
+pub(super) fn bijectively_sanitize_js_identifier_name(s: &str) -> ValidJsIdentifierName {
+    let mut out = bijectively_escape_js_identifier_chars(s);
+
+    while is_js_reserved_word(&out) {
+        out.push('_');
+    }
+
+    ValidJsIdentifierName(out)
+}
+
+/// Unlike `bijectively_sanitize_js_identifier_name`,
+/// this does not append underscores to reserved words,
+/// so it should only be used for property names.
+fn bijectively_escape_js_identifier_chars(s: &str) -> String {
     let mut out = String::new();
 
     // The first character cannot be a digit
@@ -715,11 +2066,206 @@ fn bijectively_sanitize_js_identifier_name(s: &str) -> ValidJsIdentifierName {
         }
     }
 
-    while is_js_reserved_word(&out) {
-        out.push('_');
+    out
+}
+
+/// The tree of globally visible items, keyed by their
+/// (escaped) Kantu names.
+/// The root of the tree corresponds to the `pack` module,
+/// and is exported as `pack`, so an item declared
+/// as `pub let app` in `pack.pong` can be accessed by JS code
+/// as `pack.pong.app`.
+///
+/// The exported names are derived solely from module paths,
+/// so they do not depend on the disposable names
+/// that `Context` assigns.
+#[derive(Clone, Debug, Default)]
+struct ExportTree {
+    entries: Vec<(ValidJsIdentifierName, ExportTreeNode)>,
+}
+
+#[derive(Clone, Debug)]
+enum ExportTreeNode {
+    Leaf {
+        file_id: FileId,
+        js_name: ValidJsIdentifierName,
+        /// The function that checks the args before calling the item
+        /// (see `generate_code_for_arg_checks`), if any.
+        /// This is exported instead of the item.
+        wrapper_js_name: Option<ValidJsIdentifierName>,
+    },
+    Branch(ExportTree),
+}
+
+impl ExportTree {
+    fn insert(
+        &mut self,
+        path: &[ValidJsIdentifierName],
+        key: ValidJsIdentifierName,
+        node: ExportTreeNode,
+    ) {
+        match path.split_first() {
+            None => self.entries.push((key, node)),
+            Some((first, rest)) => self.branch_mut(first).insert(rest, key, node),
+        }
     }
 
-    ValidJsIdentifierName(out)
+    fn branch_mut(&mut self, key: &ValidJsIdentifierName) -> &mut ExportTree {
+        let index = match self.entries.iter().position(|(k, _)| k == key) {
+            Some(index) => index,
+            None => {
+                self.entries
+                    .push((key.clone(), ExportTreeNode::Branch(ExportTree::default())));
+                self.entries.len() - 1
+            }
+        };
+        match &mut self.entries[index].1 {
+            ExportTreeNode::Branch(branch) => branch,
+            // The binder guarantees that a module cannot have
+            // a submodule and an item with the same name.
+            ExportTreeNode::Leaf { .. } => unreachable!(),
+        }
+    }
+
+    fn for_each_leaf(&self, f: &mut impl FnMut(FileId, &ValidJsIdentifierName)) {
+        for (_, node) in &self.entries {
+            match node {
+                ExportTreeNode::Leaf {
+                    file_id, js_name, ..
+                } => f(*file_id, js_name),
+                ExportTreeNode::Branch(branch) => branch.for_each_leaf(f),
+            }
+        }
+    }
+
+    fn to_expression(&self) -> Expression {
+        Expression::Object(Box::new(Object {
+            entries: self
+                .entries
+                .iter()
+                .map(|(key, node)| ObjectEntry {
+                    key: key.clone(),
+                    value: match node {
+                        ExportTreeNode::Leaf {
+                            js_name,
+                            wrapper_js_name,
+                            ..
+                        } => Expression::Identifier(
+                            wrapper_js_name.as_ref().unwrap_or(js_name).clone(),
+                        ),
+                        ExportTreeNode::Branch(branch) => branch.to_expression(),
+                    },
+                })
+                .collect(),
+        }))
+    }
+
+    fn to_declaration_type(&self) -> TsType {
+        TsType::Object(
+            self.entries
+                .iter()
+                .map(|(key, node)| ObjectTypeEntry {
+                    key: key.clone(),
+                    is_optional: false,
+                    value: match node {
+                        ExportTreeNode::Leaf { js_name, .. } => TsType::Typeof(js_name.clone()),
+                        ExportTreeNode::Branch(branch) => branch.to_declaration_type(),
+                    },
+                })
+                .collect(),
+        )
+    }
+}
+
+/// Type constructors are exported as an object
+/// containing their variant constructors
+/// (e.g., `pack.nat.Nat.s`).
+/// The type constructor itself is not exported,
+/// since it only exists to represent types at runtime.
+fn add_type_statement_to_exports(
+    registry: &NodeRegistry,
+    file_tree: &FileTree,
+    context: &Context,
+    exports: &mut ExportTree,
+    type_id: NodeId<light::TypeStatement>,
+    file_id: FileId,
+) {
+    let type_ = registry.get(type_id);
+    if !is_exported(file_tree, type_.visibility, file_id) {
+        return;
+    }
+    let path = get_export_path_of_module(file_tree, file_id);
+
+    let variant_ids = registry.get_possibly_empty_list(type_.variant_list_id);
+    let variants = ExportTree {
+        entries: variant_ids
+            .iter()
+            .enumerate()
+            .map(|(i, variant_id)| {
+                let variant = registry.get(*variant_id);
+                let variant_js_name = context.js_name(DbIndex(variant_ids.len() - 1 - i));
+                (
+                    registry.get(variant.name_id).name.export_key(),
+                    ExportTreeNode::Leaf {
+                        file_id,
+                        js_name: variant_js_name,
+                        wrapper_js_name: None,
+                    },
+                )
+            })
+            .collect(),
+    };
+    exports.insert(
+        &path,
+        registry.get(type_.name_id).name.export_key(),
+        ExportTreeNode::Branch(variants),
+    );
+}
+
+fn add_let_statement_to_exports(
+    registry: &NodeRegistry,
+    file_tree: &FileTree,
+    context: &Context,
+    exports: &mut ExportTree,
+    let_id: NodeId<light::LetStatement>,
+    file_id: FileId,
+    wrapper_js_name: Option<ValidJsIdentifierName>,
+) {
+    let let_statement = registry.get(let_id);
+    if !is_exported(file_tree, let_statement.visibility, file_id) {
+        return;
+    }
+    let path = get_export_path_of_module(file_tree, file_id);
+    exports.insert(
+        &path,
+        registry.get(let_statement.name_id).name.export_key(),
+        ExportTreeNode::Leaf {
+            file_id,
+            js_name: context.js_name(DbIndex(0)),
+            wrapper_js_name,
+        },
+    );
+}
+
+/// Returns whether an item with the given visibility,
+/// declared in the given module, is exported from `index.js`.
+/// An item is only exported if code outside the pack can name it,
+/// so an item in a private module is not exported
+/// (even if it is reachable through a `pub use`).
+pub(super) fn is_exported(file_tree: &FileTree, visibility: Visibility, file_id: FileId) -> bool {
+    visibility == Visibility(ModScope::Global) && file_tree.is_publicly_nameable(file_id)
+}
+
+/// Returns the keys leading from `pack` to the given module.
+fn get_export_path_of_module(file_tree: &FileTree, file_id: FileId) -> Vec<ValidJsIdentifierName> {
+    let mut current = file_id;
+    let mut path = vec![];
+    while let Some((parent, label)) = file_tree.parent_and_label(current) {
+        path.push(label.export_key());
+        current = parent;
+    }
+    path.reverse();
+    path
 }
 
 fn is_js_reserved_word(s: &str) -> bool {
@@ -801,52 +2347,197 @@ fn is_js_reserved_word(s: &str) -> bool {
 }
 
 #[derive(Clone, Debug)]
-struct Context {
+pub(super) struct Context {
     stack: Vec<ContextEntry>,
     other_reserved_names: Vec<ValidJsIdentifierName>,
+    /// The module that declares the item we're currently generating code for.
+    pub(super) current_module: FileId,
+    /// The non-local names referenced since the last call to `take_references`.
+    pub(super) references: Vec<(NameOrigin, ValidJsIdentifierName)>,
+    pub(super) erasure: ErasureAnalysis,
+    pub(super) bigint: BigIntAnalysis,
+    /// If true, funs are compiled with `generate_code_for_stack_safe_fun`.
+    stack_safe: bool,
+    /// The fun whose body we're currently generating code for,
+    /// if `stack_safe` is true.
+    pub(super) recursion: Option<RecursionFrame>,
+    /// The warnings emitted so far.
+    /// Since the code of a fun may be generated more than once
+    /// (e.g., when it's inlined), each warning is only recorded once.
+    pub(super) warnings: Vec<CompileToJavaScriptWarning>,
+    source_files: SourceFiles,
+    precomputed_lets: PrecomputedLets,
+    /// If true, the `let`s bound to small functions are recorded
+    /// in `inlinable_functions`.
+    pub(super) inline_small_functions: bool,
+    /// The functions that calls may be replaced with,
+    /// keyed by the levels of their `let` statements.
+    pub(super) inlinable_functions: FxHashMap<DbLevel, InlinableFunction>,
+    /// The type statements, keyed by the levels of their type constructors.
+    pub(super) type_levels: FxHashMap<DbLevel, NodeId<light::TypeStatement>>,
 }
 
 #[derive(Clone, Debug)]
 struct ContextEntry {
     js_name: ValidJsIdentifierName,
+    origin: NameOrigin,
+    /// Erased params are not passed at runtime,
+    /// so references to them are `undefined`.
+    is_erased: bool,
+    /// If `Some`, the entry is a function that omits the erased
+    /// unlabeled params marked `true`
+    /// (see `generate_code_for_params_and_leave_params_in_context`),
+    /// so calls to it must omit the corresponding args.
+    omitted_params: Option<Vec<bool>>,
+}
+
+/// Where a name is declared.
+/// Since every item has a unique JS name,
+/// a module can import items from any number of other modules
+/// (under their JS names) without collisions.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(super) enum NameOrigin {
+    Local,
+    Runtime,
+    Module(FileId),
 }
 
 impl Context {
-    fn new() -> Self {
+    #[allow(clippy::too_many_arguments)]
+    fn new(
+        max_universe_level: usize,
+        root: FileId,
+        erasure: ErasureAnalysis,
+        bigint: BigIntAnalysis,
+        stack_safe: bool,
+        source_files: SourceFiles,
+        precomputed_lets: PrecomputedLets,
+        inline_small_functions: bool,
+    ) -> Self {
         Self {
-            stack: vec![
-                ContextEntry {
-                    js_name: ValidJsIdentifierName(TYPE_SPECIES_VALUE__TYPE1.to_string()),
-                },
-                ContextEntry {
-                    js_name: ValidJsIdentifierName(TYPE_SPECIES_VALUE__TYPE0.to_string()),
-                },
-            ],
+            stack: (0..=max_universe_level)
+                .rev()
+                .map(|level| ContextEntry {
+                    js_name: ValidJsIdentifierName(universe_js_name(level)),
+                    origin: NameOrigin::Runtime,
+                    is_erased: false,
+                    omitted_params: None,
+                })
+                .collect(),
             other_reserved_names: vec![
                 ValidJsIdentifierName(EXPLOSION_THROWER_NAME.to_string()),
                 ValidJsIdentifierName(EXPLOSION_THROWER_PARAM0_NAME.to_string()),
                 ValidJsIdentifierName(TODO_ERROR_THROWER_NAME.to_string()),
                 ValidJsIdentifierName(TODO_ERROR_THROWER_PARAM0_NAME.to_string()),
+                ValidJsIdentifierName(RECURSION_RUNNER_NAME.to_string()),
+                ValidJsIdentifierName(INVALID_ARG_ERROR_NAME.to_string()),
+                ValidJsIdentifierName(INVALID_ARG_ERROR_PARAM0_NAME.to_string()),
+                ValidJsIdentifierName(INVALID_ARG_ERROR_PARAM1_NAME.to_string()),
+                ValidJsIdentifierName(INVALID_ARG_ERROR_PARAM2_NAME.to_string()),
+                ValidJsIdentifierName(WRAPPED_ARGS_NAME.to_string()),
+                // `index.js` refers to these globals in arg checkers,
+                // so no import may shadow them.
+                ValidJsIdentifierName("Array".to_string()),
+                ValidJsIdentifierName("Object".to_string()),
+                ValidJsIdentifierName(EXPORT_ROOT_NAME.to_string()),
             ],
+            current_module: root,
+            references: vec![],
+            erasure,
+            bigint,
+            stack_safe,
+            recursion: None,
+            warnings: vec![],
+            source_files,
+            precomputed_lets,
+            inline_small_functions,
+            inlinable_functions: FxHashMap::default(),
+            type_levels: FxHashMap::default(),
         }
     }
 }
 
 impl Context {
-    fn index_to_level(&self, index: DbIndex) -> DbLevel {
+    pub(super) fn index_to_level(&self, index: DbIndex) -> DbLevel {
         DbLevel(self.stack.len() - index.0 - 1)
     }
 }
 
 impl Context {
-    fn js_name(&self, index: DbIndex) -> ValidJsIdentifierName {
-        let level = self.index_to_level(index);
+    pub(super) fn js_name(&self, index: DbIndex) -> ValidJsIdentifierName {
+        self.js_name_at_level(self.index_to_level(index))
+    }
+
+    pub(super) fn js_name_at_level(&self, level: DbLevel) -> ValidJsIdentifierName {
         self.stack[level.0].js_name.clone()
     }
 
-    fn try_push_name(&mut self, preferred: ValidJsIdentifierName) {
+    /// Returns an expression that evaluates to the entry at `index`,
+    /// and records the reference if the entry is not local.
+    fn reference(&mut self, index: DbIndex) -> Expression {
+        let level = self.index_to_level(index);
+        let entry = &self.stack[level.0];
+        if entry.is_erased {
+            return undefined();
+        }
+        if entry.origin != NameOrigin::Local {
+            let reference = (entry.origin, entry.js_name.clone());
+            if !self.references.contains(&reference) {
+                self.references.push(reference);
+            }
+        }
+        Expression::Identifier(entry.js_name.clone())
+    }
+
+    /// Returns the values of the last `n` entries,
+    /// which must be params.
+    fn param_values(&self, n: usize) -> Vec<Expression> {
+        self.stack[self.stack.len() - n..]
+            .iter()
+            .map(|entry| {
+                if entry.is_erased {
+                    undefined()
+                } else {
+                    Expression::Identifier(entry.js_name.clone())
+                }
+            })
+            .collect()
+    }
+
+    /// Displays the location of the span's start in runtime error messages.
+    fn format_span_start(&self, span: Option<TextSpan>) -> String {
+        span.and_then(|span| format_span_start(&self.source_files, span))
+            .unwrap_or_else(|| "<No span found>".to_string())
+    }
+
+    pub(super) fn reference_runtime(&mut self, name: &str) -> ValidJsIdentifierName {
+        let reference = (NameOrigin::Runtime, ValidJsIdentifierName(name.to_string()));
+        if !self.references.contains(&reference) {
+            self.references.push(reference.clone());
+        }
+        reference.1
+    }
+
+    fn take_references(&mut self) -> Vec<(NameOrigin, ValidJsIdentifierName)> {
+        std::mem::take(&mut self.references)
+    }
+
+    pub(super) fn try_push_name(&mut self, preferred: ValidJsIdentifierName) {
+        self.try_push_name_with_origin(preferred, NameOrigin::Local);
+    }
+
+    pub(super) fn try_push_item_name(&mut self, preferred: ValidJsIdentifierName) {
+        self.try_push_name_with_origin(preferred, NameOrigin::Module(self.current_module));
+    }
+
+    fn try_push_name_with_origin(&mut self, preferred: ValidJsIdentifierName, origin: NameOrigin) {
         if !self.contains(&preferred) {
-            self.push(ContextEntry { js_name: preferred });
+            self.push(ContextEntry {
+                js_name: preferred,
+                origin,
+                is_erased: false,
+                omitted_params: None,
+            });
             return;
         }
 
@@ -854,14 +2545,35 @@ impl Context {
         loop {
             let name = ValidJsIdentifierName(format!("{}{}", preferred.0, i));
             if !self.contains(&name) {
-                self.push(ContextEntry { js_name: name });
+                self.push(ContextEntry {
+                    js_name: name,
+                    origin,
+                    is_erased: false,
+                    omitted_params: None,
+                });
                 return;
             }
             i += 1;
         }
     }
 
-    fn get_disposable_name(&mut self) -> ValidJsIdentifierName {
+    /// Returns a name that no entry or other reserved name uses,
+    /// and reserves it.
+    pub(super) fn reserve_name(
+        &mut self,
+        preferred: ValidJsIdentifierName,
+    ) -> ValidJsIdentifierName {
+        let mut name = preferred.clone();
+        let mut i = 2;
+        while self.contains(&name) {
+            name = ValidJsIdentifierName(format!("{}{}", preferred.0, i));
+            i += 1;
+        }
+        self.other_reserved_names.push(name.clone());
+        name
+    }
+
+    pub(super) fn get_disposable_name(&mut self) -> ValidJsIdentifierName {
         let mut i = 0;
         loop {
             let name = ValidJsIdentifierName(format!("{}_{:x}", DISPOSABLE_NAME_PREFIX, i));
@@ -882,7 +2594,30 @@ impl Context {
         self.stack.push(entry);
     }
 
-    fn pop_n(&mut self, n: usize) {
+    pub(super) fn mark_last_as_erased(&mut self) {
+        self.stack
+            .last_mut()
+            .expect("The context should not be empty.")
+            .is_erased = true;
+    }
+
+    /// Records that the last entry is a function that omits
+    /// the given params, if it omits any non-trailing ones
+    /// (see `get_omitted_params`).
+    pub(super) fn set_last_omitted_params(&mut self, omitted_params: Option<Vec<bool>>) {
+        self.stack
+            .last_mut()
+            .expect("The context should not be empty.")
+            .omitted_params = omitted_params;
+    }
+
+    pub(super) fn omitted_params(&self, index: DbIndex) -> Option<&[bool]> {
+        self.stack[self.index_to_level(index).0]
+            .omitted_params
+            .as_deref()
+    }
+
+    pub(super) fn pop_n(&mut self, n: usize) {
         self.stack.truncate(self.stack.len() - n);
     }
 }
@@ -0,0 +1,706 @@
+//! Generates the TypeScript declarations of the items
+//! that `code_gen_impl` generates JavaScript for.
+//!
+//! At the time of writing, the type checker doesn't store its results,
+//! so the declarations are derived syntactically from the
+//! type annotations in the source.
+//! Anything that cannot be expressed this way
+//! (e.g., the indices of a dependent type) is declared as `unknown`.
+
+use light::DbIndex;
+
+use crate::data::file_id::FileId;
+
+use super::code_gen_impl::bijectively_sanitize_js_identifier_name;
+use super::*;
+
+#[derive(Clone, Debug)]
+pub(super) struct DeclarationContext {
+    stack: Vec<DeclarationEntry>,
+    /// The types referenced since the last call to `take_type_references`,
+    /// along with the modules that declare them.
+    type_references: Vec<(FileId, ValidJsIdentifierName)>,
+    erasure: ErasureAnalysis,
+    bigint: BigIntAnalysis,
+}
+
+#[derive(Clone, Debug)]
+struct DeclarationEntry {
+    ts_name: ValidJsIdentifierName,
+    kind: DeclarationEntryKind,
+}
+
+#[derive(Clone, Debug)]
+enum DeclarationEntryKind {
+    Universe,
+    /// A type statement. The type is declared as a type alias
+    /// whose name is the JS name of its type constructor.
+    Type {
+        file_id: FileId,
+        generics: Vec<Generic>,
+    },
+    /// A variant constructor of the type statement
+    /// at `type_position` (counting from the bottom of the stack).
+    Variant {
+        type_position: usize,
+    },
+    /// A param that corresponds to a TypeScript generic
+    /// (whose name is the entry's `ts_name`).
+    TypeVariable,
+    /// Anything we cannot describe, which we translate to `unknown`.
+    Other,
+}
+
+/// A type param that is translated to a generic of the type alias.
+#[derive(Clone, Debug)]
+struct Generic {
+    name: ValidJsIdentifierName,
+    position: GenericPosition,
+}
+
+#[derive(Clone, Debug)]
+enum GenericPosition {
+    Index(usize),
+    Label(light::IdentifierName),
+}
+
+impl DeclarationContext {
+    pub(super) fn new(
+        erasure: ErasureAnalysis,
+        bigint: BigIntAnalysis,
+        max_universe_level: usize,
+    ) -> Self {
+        Self {
+            stack: (0..=max_universe_level)
+                .rev()
+                .map(|_| DeclarationEntry {
+                    ts_name: ValidJsIdentifierName(String::new()),
+                    kind: DeclarationEntryKind::Universe,
+                })
+                .collect(),
+            type_references: vec![],
+            erasure,
+            bigint,
+        }
+    }
+
+    pub(super) fn take_type_references(&mut self) -> Vec<(FileId, ValidJsIdentifierName)> {
+        std::mem::take(&mut self.type_references)
+    }
+
+    fn get(&self, index: DbIndex) -> &DeclarationEntry {
+        &self.stack[self.stack.len() - index.0 - 1]
+    }
+
+    fn push(&mut self, ts_name: ValidJsIdentifierName, kind: DeclarationEntryKind) {
+        self.stack.push(DeclarationEntry { ts_name, kind });
+    }
+
+    fn push_other(&mut self) {
+        self.push(
+            ValidJsIdentifierName(String::new()),
+            DeclarationEntryKind::Other,
+        );
+    }
+
+    /// Pushes a type variable with a name that doesn't shadow
+    /// any type alias or type variable in scope,
+    /// and returns the name.
+    fn push_type_variable(&mut self, preferred: ValidJsIdentifierName) -> ValidJsIdentifierName {
+        let mut name = preferred.clone();
+        let mut i = 2;
+        while self.contains(&name) || is_ts_reserved_type_name(&name.0) {
+            name = ValidJsIdentifierName(format!("{}{}", preferred.0, i));
+            i += 1;
+        }
+        self.push(name.clone(), DeclarationEntryKind::TypeVariable);
+        name
+    }
+
+    fn contains(&self, name: &ValidJsIdentifierName) -> bool {
+        self.stack.iter().any(|entry| entry.ts_name == *name)
+    }
+
+    fn pop_n(&mut self, n: usize) {
+        self.stack.truncate(self.stack.len() - n);
+    }
+}
+
+fn is_ts_reserved_type_name(s: &str) -> bool {
+    [
+        "any", "bigint", "boolean", "never", "number", "object", "string", "symbol", "unknown",
+    ]
+    .contains(&s)
+}
+
+/// Returns the declarations of a type statement, given the JS names
+/// of its type constructor and variant constructors (in that order).
+///
+/// For example,
+/// ```kantu
+/// type List(T: Type) {
+///     nil(T: Type): List(T),
+///     cons(T: Type, car: T, cdr: List(T)): List(T),
+/// }
+/// ```
+/// is declared as
+/// ```typescript
+/// export type List<T> = ["nil", unknown] | ["cons", unknown, T, List<T>];
+/// export declare const List: unknown;
+/// export declare const List_nil: <T>(T: unknown) => List<T>;
+/// export declare const List_cons: <T>(T: unknown, car: T, cdr: List<T>) => List<T>;
+/// ```
+/// if nothing is erased.
+/// If the `T` params are erased, they're omitted from the
+/// constructors' params, and their fields' types are `undefined` instead.
+///
+/// A variant param becomes a generic of the type alias
+/// only if it is passed as a type param
+/// (that is itself a generic) in the variant's return type.
+/// Otherwise, it's unknown.
+pub(super) fn generate_declarations_for_type_statement(
+    registry: &NodeRegistry,
+    context: &mut DeclarationContext,
+    type_id: NodeId<light::TypeStatement>,
+    file_id: FileId,
+    js_names: &[ValidJsIdentifierName],
+) -> Vec<DeclarationItem> {
+    let type_ = registry.get(type_id);
+    let type_js_name = js_names[0].clone();
+
+    let generics = {
+        let params = get_param_infos(registry, &context.erasure, type_.param_list_id);
+        let mut generics = vec![];
+        for (index, param) in params.iter().enumerate() {
+            if is_universe(registry, context, param.type_id) {
+                let preferred = registry.get(param.name_id).name.preferred_js_name();
+                generics.push(Generic {
+                    name: context.push_type_variable(preferred),
+                    position: match param.label_id {
+                        Some(label_id) => {
+                            GenericPosition::Label(registry.get(label_id).name.clone())
+                        }
+                        None => GenericPosition::Index(index),
+                    },
+                });
+            } else {
+                context.push_other();
+            }
+        }
+        context.pop_n(params.len());
+        generics
+    };
+    let generic_names = generics
+        .iter()
+        .map(|generic| generic.name.clone())
+        .collect();
+
+    let type_position = context.stack.len();
+    context.push(
+        type_js_name.clone(),
+        DeclarationEntryKind::Type { file_id, generics },
+    );
+
+    let variant_ids = registry.get_possibly_empty_list(type_.variant_list_id);
+    let mut variant_types = Vec::with_capacity(variant_ids.len());
+    let mut variant_constructor_declarations = Vec::with_capacity(variant_ids.len());
+    for (variant_id, variant_js_name) in variant_ids.iter().zip(&js_names[1..]) {
+        let variant = registry.get(*variant_id);
+        let params = get_param_infos(registry, &context.erasure, variant.param_list_id);
+        let tag = TsType::StringLiteral(JsStringLiteral {
+            unescaped: registry.get(variant.name_id).name.preferred_js_name().0,
+        });
+
+        let type_variables =
+            get_variant_param_type_variables(registry, context, variant, params.len());
+        let (_, param_types) = translate_params_and_leave_params_in_context(
+            registry,
+            context,
+            &params,
+            TypeVariableMode::Given(&type_variables),
+        );
+        context.pop_n(params.len());
+        let mut variant_type = vec![tag];
+        match translate_param_list(registry, &params, param_types.clone(), false) {
+            // Unlike the constructor's params, the fields include
+            // the trailing erased params.
+            TsParams::Standard(_) => {
+                variant_type.extend(param_types);
+            }
+            TsParams::DestructuredSingleton(entries) => {
+                variant_type.push(TsType::Object(
+                    entries
+                        .into_iter()
+                        // The constructor fills in the defaults,
+                        // so every field is present at runtime.
+                        .map(|entry| ObjectTypeEntry {
+                            is_optional: false,
+                            ..entry
+                        })
+                        .collect(),
+                ));
+            }
+        }
+        variant_types.push(TsType::Tuple(variant_type));
+
+        let (generics, param_types) = translate_params_and_leave_params_in_context(
+            registry,
+            context,
+            &params,
+            TypeVariableMode::UniverseTypedParams,
+        );
+        let return_type = translate_type(registry, context, variant.return_type_id);
+        context.pop_n(params.len());
+        let constructor_type = if params.is_empty() {
+            return_type
+        } else {
+            TsType::Function(Box::new(FunctionType {
+                generics,
+                params: translate_param_list(registry, &params, param_types, true),
+                return_type,
+            }))
+        };
+        variant_constructor_declarations.push(DeclarationItem::ExportConst(ConstDeclaration {
+            name: variant_js_name.clone(),
+            type_: constructor_type,
+        }));
+
+        context.push(
+            ValidJsIdentifierName(String::new()),
+            DeclarationEntryKind::Variant { type_position },
+        );
+    }
+
+    let mut out = Vec::with_capacity(variant_ids.len() + 2);
+    out.push(DeclarationItem::ExportType(TypeAlias {
+        name: type_js_name.clone(),
+        generics: generic_names,
+        value: if context.bigint.type_(type_id).is_some() {
+            TsType::BigInt
+        } else {
+            TsType::Union(variant_types)
+        },
+    }));
+    // Type constructors only exist to represent types at runtime,
+    // so there's nothing useful we can say about them.
+    out.push(DeclarationItem::ExportConst(ConstDeclaration {
+        name: type_js_name,
+        type_: TsType::Unknown,
+    }));
+    out.extend(variant_constructor_declarations);
+    out
+}
+
+/// Returns, for each of the variant's params, the generic
+/// of the type alias that the param corresponds to (if any).
+///
+/// This should be called before the params are pushed.
+fn get_variant_param_type_variables(
+    registry: &NodeRegistry,
+    context: &DeclarationContext,
+    variant: &light::Variant,
+    arity: usize,
+) -> Vec<Option<ValidJsIdentifierName>> {
+    let mut out = vec![None; arity];
+    let ExpressionRef::Call(call) = registry.expression_ref(variant.return_type_id) else {
+        return out;
+    };
+    let ExpressionRef::Name(callee) = registry.expression_ref(call.callee_id) else {
+        return out;
+    };
+    // The return type is in the scope of the variant params,
+    // but the context doesn't contain them yet.
+    let Some(callee_index) = callee.db_index.0.checked_sub(arity) else {
+        return out;
+    };
+    let callee = context.get(DbIndex(callee_index));
+    let DeclarationEntryKind::Type { generics, .. } = &callee.kind else {
+        return out;
+    };
+    for generic in generics {
+        let Some(arg_id) = get_type_arg_id(registry, call.arg_list_id, &generic.position) else {
+            continue;
+        };
+        if let ExpressionRef::Name(arg) = registry.expression_ref(arg_id) {
+            if arg.db_index.0 < arity {
+                out[arity - 1 - arg.db_index.0] = Some(generic.name.clone());
+            }
+        }
+    }
+    out
+}
+
+/// Returns the declaration of a let statement.
+/// Functions are declared with their signatures,
+/// variant applications (e.g., `Point.new(x: Nat.o, y: Nat.o)`)
+/// are declared with their type (if it has no generics),
+/// and everything else is declared as `unknown`.
+pub(super) fn generate_declaration_for_let_statement(
+    registry: &NodeRegistry,
+    context: &mut DeclarationContext,
+    let_id: NodeId<light::LetStatement>,
+    js_name: &ValidJsIdentifierName,
+) -> DeclarationItem {
+    let let_statement = registry.get(let_id);
+    let type_ = match registry.expression_ref(let_statement.value_id) {
+        ExpressionRef::Fun(fun) => translate_fun_signature(registry, context, fun),
+        _ => translate_type_of_variant_application(registry, context, let_statement.value_id),
+    };
+    context.push_other();
+    DeclarationItem::ExportConst(ConstDeclaration {
+        name: js_name.clone(),
+        type_,
+    })
+}
+
+/// Returns `unknown` if the expression is not a variant application,
+/// or if the variant's type has generics
+/// (since we don't infer the generics' args).
+fn translate_type_of_variant_application(
+    registry: &NodeRegistry,
+    context: &mut DeclarationContext,
+    id: light::ExpressionId,
+) -> TsType {
+    let callee_id = match registry.expression_ref(id) {
+        ExpressionRef::Call(call) => call.callee_id,
+        _ => id,
+    };
+    let ExpressionRef::Name(callee) = registry.expression_ref(callee_id) else {
+        return TsType::Unknown;
+    };
+    let DeclarationEntryKind::Variant { type_position } = context.get(callee.db_index).kind else {
+        return TsType::Unknown;
+    };
+    let type_index = DbIndex(context.stack.len() - type_position - 1);
+    translate_name(context, type_index)
+}
+
+fn translate_fun_signature(
+    registry: &NodeRegistry,
+    context: &mut DeclarationContext,
+    fun: &light::Fun,
+) -> TsType {
+    let params = get_param_infos(registry, &context.erasure, Some(fun.param_list_id));
+    let (generics, param_types) = translate_params_and_leave_params_in_context(
+        registry,
+        context,
+        &params,
+        TypeVariableMode::UniverseTypedParams,
+    );
+    // The fun's own name is not in scope in its return type.
+    let return_type = translate_type(registry, context, fun.return_type_id);
+    context.pop_n(params.len());
+    TsType::Function(Box::new(FunctionType {
+        generics,
+        params: translate_param_list(registry, &params, param_types, true),
+        return_type,
+    }))
+}
+
+/// The parts of a labeled or unlabeled param
+/// that are relevant to its declaration.
+#[derive(Clone, Debug)]
+struct ParamInfo {
+    name_id: NodeId<light::Identifier>,
+    /// `None` if the param is unlabeled.
+    label_id: Option<NodeId<light::Identifier>>,
+    type_id: light::ExpressionId,
+    has_default: bool,
+    is_erased: bool,
+}
+
+fn get_param_infos(
+    registry: &NodeRegistry,
+    erasure: &ErasureAnalysis,
+    param_list_id: Option<NonEmptyParamListId>,
+) -> Vec<ParamInfo> {
+    match param_list_id {
+        None => vec![],
+        Some(NonEmptyParamListId::Unlabeled(param_list_id)) => registry
+            .get_list(param_list_id)
+            .iter()
+            .map(|&id| {
+                let param = registry.get(id);
+                ParamInfo {
+                    name_id: param.name_id,
+                    label_id: None,
+                    type_id: param.type_id,
+                    has_default: false,
+                    is_erased: erasure.is_unlabeled_param_erased(id),
+                }
+            })
+            .collect(),
+        Some(NonEmptyParamListId::UniquelyLabeled(param_list_id)) => registry
+            .get_list(param_list_id)
+            .iter()
+            .map(|&id| {
+                let param = registry.get(id);
+                ParamInfo {
+                    name_id: param.name_id,
+                    label_id: Some(param.label_identifier_id()),
+                    type_id: param.type_id,
+                    has_default: param.default_id.is_some(),
+                    is_erased: erasure.is_labeled_param_erased(id),
+                }
+            })
+            .collect(),
+    }
+}
+
+#[derive(Clone, Copy, Debug)]
+enum TypeVariableMode<'a> {
+    /// The type variable (if any) of each param is given.
+    Given(&'a [Option<ValidJsIdentifierName>]),
+    /// Every param whose type is a universe becomes a new type variable.
+    UniverseTypedParams,
+}
+
+/// Returns the generics introduced by the params (if any)
+/// and the type of each param.
+fn translate_params_and_leave_params_in_context(
+    registry: &NodeRegistry,
+    context: &mut DeclarationContext,
+    params: &[ParamInfo],
+    mode: TypeVariableMode,
+) -> (Vec<ValidJsIdentifierName>, Vec<TsType>) {
+    let mut generics = vec![];
+    let mut param_types = Vec::with_capacity(params.len());
+    for (index, param) in params.iter().enumerate() {
+        // Erased args are `undefined` (or omitted) at runtime.
+        param_types.push(if param.is_erased {
+            TsType::Undefined
+        } else {
+            translate_type(registry, context, param.type_id)
+        });
+        match mode {
+            TypeVariableMode::Given(type_variables) => match &type_variables[index] {
+                Some(name) => context.push(name.clone(), DeclarationEntryKind::TypeVariable),
+                None => context.push_other(),
+            },
+            TypeVariableMode::UniverseTypedParams => {
+                if is_universe(registry, context, param.type_id) {
+                    let preferred = registry.get(param.name_id).name.preferred_js_name();
+                    generics.push(context.push_type_variable(preferred));
+                } else {
+                    context.push_other();
+                }
+            }
+        }
+    }
+    (generics, param_types)
+}
+
+/// Erased labeled params are always omitted.
+/// Erased unlabeled params are omitted if `should_omit_erased_params` is true
+/// (see `generate_code_for_params_and_leave_params_in_context`),
+/// and otherwise only the trailing ones are omitted.
+fn translate_param_list(
+    registry: &NodeRegistry,
+    params: &[ParamInfo],
+    param_types: Vec<TsType>,
+    should_omit_erased_params: bool,
+) -> TsParams {
+    let is_labeled = params.first().is_some_and(|param| param.label_id.is_some());
+    if is_labeled {
+        TsParams::DestructuredSingleton(
+            params
+                .iter()
+                .zip(param_types)
+                .filter(|(param, _)| !param.is_erased)
+                .map(|(param, param_type)| ObjectTypeEntry {
+                    key: registry
+                        .get(param.label_id.expect("All params should be labeled."))
+                        .name
+                        .preferred_js_name(),
+                    is_optional: param.has_default,
+                    value: param_type,
+                })
+                .collect(),
+        )
+    } else {
+        // TypeScript forbids duplicate param names,
+        // but Kantu allows them (e.g., `_`).
+        let mut names: Vec<ValidJsIdentifierName> = Vec::with_capacity(params.len());
+        for param in params {
+            let preferred = registry.get(param.name_id).name.preferred_js_name();
+            let mut name = preferred.clone();
+            let mut i = 2;
+            while names.contains(&name) {
+                name = ValidJsIdentifierName(format!("{}{}", preferred.0, i));
+                i += 1;
+            }
+            names.push(name);
+        }
+        let trailing_erased_count = params
+            .iter()
+            .rev()
+            .take_while(|param| param.is_erased)
+            .count();
+        TsParams::Standard(
+            names
+                .into_iter()
+                .zip(param_types)
+                .zip(params)
+                .take(params.len() - trailing_erased_count)
+                .filter(|(_, param)| !(should_omit_erased_params && param.is_erased))
+                .map(|(entry, _)| entry)
+                .collect(),
+        )
+    }
+}
+
+fn is_universe(
+    registry: &NodeRegistry,
+    context: &DeclarationContext,
+    id: light::ExpressionId,
+) -> bool {
+    match registry.expression_ref(id) {
+        ExpressionRef::Name(name) => matches!(
+            context.get(name.db_index).kind,
+            DeclarationEntryKind::Universe
+        ),
+        _ => false,
+    }
+}
+
+fn translate_type(
+    registry: &NodeRegistry,
+    context: &mut DeclarationContext,
+    id: light::ExpressionId,
+) -> TsType {
+    match registry.expression_ref(id) {
+        ExpressionRef::Name(name) => translate_name(context, name.db_index),
+        ExpressionRef::Call(call) => translate_call(registry, context, call),
+        ExpressionRef::Forall(forall) => {
+            let params = get_param_infos(registry, &context.erasure, Some(forall.param_list_id));
+            let (generics, param_types) = translate_params_and_leave_params_in_context(
+                registry,
+                context,
+                &params,
+                TypeVariableMode::UniverseTypedParams,
+            );
+            let return_type = translate_type(registry, context, forall.output_id);
+            context.pop_n(params.len());
+            // Since we don't know how a function value was defined,
+            // we assume it keeps its erased params' positions.
+            TsType::Function(Box::new(FunctionType {
+                generics,
+                params: translate_param_list(registry, &params, param_types, false),
+                return_type,
+            }))
+        }
+        // Pairs are represented like variants with two args
+        // (see `generate_code_for_pair`).
+        ExpressionRef::Exists(exists) => {
+            let param = registry.get(exists.param_id);
+            let witness_type = translate_type(registry, context, param.type_id);
+            context.push_other();
+            let proof_type = translate_type(registry, context, exists.output_id);
+            context.pop_n(1);
+            TsType::Tuple(vec![
+                TsType::StringLiteral(JsStringLiteral {
+                    unescaped: bijectively_sanitize_js_identifier_name(light::PAIR_VARIANT_NAME).0,
+                }),
+                witness_type,
+                proof_type,
+            ])
+        }
+        ExpressionRef::Check(check) => translate_type(registry, context, check.output_id),
+        ExpressionRef::Todo(_)
+        | ExpressionRef::Fun(_)
+        | ExpressionRef::Match(_)
+        | ExpressionRef::Rewrite(_)
+        | ExpressionRef::Projection(_)
+        | ExpressionRef::Pair(_) => TsType::Unknown,
+    }
+}
+
+fn translate_name(context: &mut DeclarationContext, index: DbIndex) -> TsType {
+    let entry = context.get(index);
+    match &entry.kind {
+        DeclarationEntryKind::Type { file_id, generics } if generics.is_empty() => {
+            let reference = (*file_id, entry.ts_name.clone());
+            let out = TsType::Reference(Box::new(TypeReference {
+                name: reference.1.clone(),
+                args: vec![],
+            }));
+            if !context.type_references.contains(&reference) {
+                context.type_references.push(reference);
+            }
+            out
+        }
+        DeclarationEntryKind::TypeVariable => TsType::Reference(Box::new(TypeReference {
+            name: entry.ts_name.clone(),
+            args: vec![],
+        })),
+        DeclarationEntryKind::Type { .. }
+        | DeclarationEntryKind::Universe
+        | DeclarationEntryKind::Variant { .. }
+        | DeclarationEntryKind::Other => TsType::Unknown,
+    }
+}
+
+fn translate_call(
+    registry: &NodeRegistry,
+    context: &mut DeclarationContext,
+    call: &light::Call,
+) -> TsType {
+    let ExpressionRef::Name(callee) = registry.expression_ref(call.callee_id) else {
+        return TsType::Unknown;
+    };
+    let entry = context.get(callee.db_index);
+    let DeclarationEntryKind::Type { file_id, generics } = &entry.kind else {
+        return TsType::Unknown;
+    };
+    let reference = (*file_id, entry.ts_name.clone());
+    let arg_ids: Vec<Option<light::ExpressionId>> = generics
+        .iter()
+        .map(|generic| get_type_arg_id(registry, call.arg_list_id, &generic.position))
+        .collect();
+
+    let args = arg_ids
+        .into_iter()
+        .map(|arg_id| match arg_id {
+            Some(arg_id) => translate_type(registry, context, arg_id),
+            None => TsType::Unknown,
+        })
+        .collect();
+    if !context.type_references.contains(&reference) {
+        context.type_references.push(reference.clone());
+    }
+    TsType::Reference(Box::new(TypeReference {
+        name: reference.1,
+        args,
+    }))
+}
+
+fn get_type_arg_id(
+    registry: &NodeRegistry,
+    arg_list_id: NonEmptyCallArgListId,
+    position: &GenericPosition,
+) -> Option<light::ExpressionId> {
+    match (arg_list_id, position) {
+        (NonEmptyCallArgListId::Unlabeled(arg_list_id), GenericPosition::Index(index)) => {
+            registry.get_list(arg_list_id).get(*index).copied()
+        }
+        (NonEmptyCallArgListId::UniquelyLabeled(arg_list_id), GenericPosition::Label(label)) => {
+            registry
+                .get_list(arg_list_id)
+                .iter()
+                .find_map(|arg_id| match *arg_id {
+                    LabeledCallArgId::Implicit {
+                        label_id, value_id, ..
+                    } if registry.get(label_id).name == *label => {
+                        Some(light::ExpressionId::Name(value_id))
+                    }
+                    LabeledCallArgId::Explicit { label_id, value_id }
+                        if registry.get(label_id).name == *label =>
+                    {
+                        Some(value_id)
+                    }
+                    _ => None,
+                })
+        }
+        _ => None,
+    }
+}
@@ -11,17 +11,88 @@ pub fn format_file(file: &File, options: &FormatOptions) -> String {
     w.finish()
 }
 
+/// Returns the formatted file and its source map.
+/// `path` is the path of the file, relative to the target directory.
+/// The formatted file ends with a comment that points to the source map,
+/// so the source map should be written to `get_source_map_path(path)`.
+pub fn format_file_with_source_map(
+    file: &File,
+    options: &FormatOptions,
+    path: &Path,
+    source_files: &SourceFiles,
+) -> (String, String) {
+    let mut w = Writer::with_options(options);
+    write_file(&mut w, file, options);
+    let (mut formatted, mappings) = w.finish_with_mappings();
+    let source_map = generate_source_map(path, &formatted, &mappings, source_files);
+    if let Some(source_map_name) = get_source_map_path(path).file_name() {
+        formatted.push_str("//# sourceMappingURL=");
+        formatted.push_str(&source_map_name.to_string_lossy());
+        formatted.push('\n');
+    }
+    (formatted, source_map)
+}
+
 fn write_file(out: &mut Writer, file: &File, options: &FormatOptions) {
-    for item in &file.items {
+    for (i, item) in file.items.iter().enumerate() {
         write_file_item(out, item, options);
-        out.push_str("\n\n");
+        let is_followed_by_import = matches!(
+            (item, file.items.get(i + 1)),
+            (FileItem::Import(_), Some(FileItem::Import(_)))
+        );
+        if is_followed_by_import {
+            out.push('\n');
+        } else {
+            out.push_str("\n\n");
+        }
     }
 }
 
 fn write_file_item(out: &mut Writer, item: &FileItem, options: &FormatOptions) {
     match item {
+        FileItem::Import(import) => {
+            out.push_str("import ");
+            write_import_clause(out, import);
+        }
+        FileItem::ExportFrom(import) => {
+            out.push_str("export ");
+            write_import_clause(out, import);
+        }
         FileItem::Const(const_) => write_const_statement(out, const_, options),
+        FileItem::ExportConst(const_) => {
+            out.push_str("export ");
+            write_const_statement(out, const_, options);
+        }
+        FileItem::Export(names) => {
+            out.push_str("export ");
+            write_aliased_names(out, names);
+            out.push(';');
+        }
+    }
+}
+
+/// Writes `{ names } from "specifier";`.
+fn write_import_clause(out: &mut Writer, import: &ImportStatement) {
+    write_aliased_names(out, &import.names);
+    out.push_str(" from ");
+    write_literal(out, &Literal::String(import.specifier.clone()));
+    out.push(';');
+}
+
+/// Writes `{ name0 as alias0, name1, ... }`.
+fn write_aliased_names(out: &mut Writer, names: &[AliasedName]) {
+    out.push_str("{ ");
+    for (i, name) in names.iter().enumerate() {
+        if i > 0 {
+            out.push_str(", ");
+        }
+        out.push_str(&name.name.0);
+        if name.alias != name.name {
+            out.push_str(" as ");
+            out.push_str(&name.alias.0);
+        }
     }
+    out.push_str(" }");
 }
 
 fn write_const_statement(out: &mut Writer, const_: &ConstStatement, options: &FormatOptions) {
@@ -39,6 +110,17 @@ fn write_expression(out: &mut Writer, expression: &Expression, options: &FormatO
         Expression::Call(call) => write_call(out, call, options),
         Expression::New(call) => write_new_call(out, call, options),
         Expression::Function(function) => write_simple_function(out, function, options),
+        Expression::GeneratorFunction(function) => {
+            out.add_mapping(function.span);
+            out.push_str("function* ");
+            write_function_name_params_and_body(out, function, options);
+        }
+        Expression::Yield(yield_) => write_yield(out, yield_, options),
+        Expression::Typeof(argument) => {
+            out.push_str("(typeof ");
+            write_expression(out, argument, options);
+            out.push(')');
+        }
         Expression::BinaryOp(binary_op) => write_binary_op(out, binary_op, options),
         Expression::Dot(dot) => write_dot(out, dot, options),
         Expression::Ternary(ternary) => write_ternary(out, ternary, options),
@@ -54,6 +136,10 @@ fn write_literal(out: &mut Writer, literal: &Literal) {
             false => "false",
         }),
         Literal::Number(number) => out.push_str(&number.to_string()),
+        Literal::BigInt(number) => {
+            out.push_str(&number.to_string());
+            out.push('n');
+        }
         Literal::String(JsStringLiteral { unescaped }) => {
             out.push_str("\"");
             out.push_str(&escape_string_contents(unescaped));
@@ -84,6 +170,7 @@ fn escape_string_contents(unescaped: &str) -> String {
 }
 
 fn write_call(out: &mut Writer, call: &Call, options: &FormatOptions) {
+    out.add_mapping(call.span);
     if let Expression::Identifier(_) = call.callee {
         write_expression(out, &call.callee, options);
     } else {
@@ -103,6 +190,7 @@ fn write_call(out: &mut Writer, call: &Call, options: &FormatOptions) {
 }
 
 fn write_new_call(out: &mut Writer, call: &Call, options: &FormatOptions) {
+    out.add_mapping(call.span);
     out.push_str("new ");
     if let Expression::Identifier(_) = call.callee {
         write_expression(out, &call.callee, options);
@@ -123,33 +211,63 @@ fn write_new_call(out: &mut Writer, call: &Call, options: &FormatOptions) {
 }
 
 fn write_simple_function(out: &mut Writer, function: &Function, options: &FormatOptions) {
+    out.add_mapping(function.span);
     out.push_str("function ");
+    write_function_name_params_and_body(out, function, options);
+}
+
+fn write_function_name_params_and_body(
+    out: &mut Writer,
+    function: &Function,
+    options: &FormatOptions,
+) {
     out.push_str(&function.name.0);
     write_params(out, &function.params, options);
-    out.push_str(" {");
+    out.push(' ');
+    write_block(out, &function.body, options);
+}
 
-    if function.body.is_empty() {
-        out.push_str("}");
+/// Writes `{ statements }`, with one statement per line.
+fn write_block(out: &mut Writer, statements: &[FunctionStatement], options: &FormatOptions) {
+    out.push('{');
+
+    if statements.is_empty() {
+        out.push('}');
     } else {
-        out.push_str("\n");
+        out.push('\n');
         out.increase_indentation_level();
-        for statement in &function.body {
+        for statement in statements {
             out.indent();
             write_function_statement(out, statement, options);
-            out.push_str("\n");
+            out.push('\n');
         }
         out.decrease_indentation_level();
         out.indent();
-        out.push_str("}");
+        out.push('}');
     }
 }
 
+fn write_yield(out: &mut Writer, yield_: &Yield, options: &FormatOptions) {
+    out.push_str(if yield_.is_delegate {
+        "(yield* "
+    } else {
+        "(yield "
+    });
+    write_expression(out, &yield_.argument, options);
+    out.push(')');
+}
+
 fn write_params(out: &mut Writer, params: &Params, options: &FormatOptions) {
     match params {
         Params::Standard(params) => write_standard_params(out, params, options),
         Params::DestructuredSingleton(params) => {
             write_destructured_singleton_param(out, params, options)
         }
+        Params::Rest(name) => {
+            out.push_str("(...");
+            out.push_str(&name.0);
+            out.push(')');
+        }
     }
 }
 
@@ -167,9 +285,19 @@ fn write_standard_params(out: &mut Writer, params: &[ValidJsIdentifierName], _:
 fn write_destructured_singleton_param(
     out: &mut Writer,
     params: &[ObjectDestructureEntry],
-    _: &FormatOptions,
+    options: &FormatOptions,
+) {
+    out.push('(');
+    write_object_pattern(out, params, options);
+    out.push(')');
+}
+
+fn write_object_pattern(
+    out: &mut Writer,
+    params: &[ObjectDestructureEntry],
+    options: &FormatOptions,
 ) {
-    out.push_str("({");
+    out.push('{');
     for (i, param) in params.iter().enumerate() {
         if i > 0 {
             out.push_str(", ");
@@ -177,8 +305,12 @@ fn write_destructured_singleton_param(
         out.push_str(&param.in_name.0);
         out.push_str(": ");
         out.push_str(&param.out_name.0);
+        if let Some(default) = &param.default {
+            out.push_str(" = ");
+            write_expression(out, default, options);
+        }
     }
-    out.push_str("})");
+    out.push('}');
 }
 
 fn write_function_statement(
@@ -188,7 +320,33 @@ fn write_function_statement(
 ) {
     match statement {
         FunctionStatement::Const(const_) => write_const_statement(out, const_, options),
+        FunctionStatement::ConstDestructure(destructure) => {
+            write_destructure_statement(out, destructure, options)
+        }
+        FunctionStatement::Let(let_) => {
+            out.push_str("let ");
+            out.push_str(&let_.name.0);
+            out.push_str(" = ");
+            write_expression(out, &let_.value, options);
+            out.push(';');
+        }
+        FunctionStatement::Assign(assignment) => {
+            out.push_str(&assignment.name.0);
+            out.push_str(" = ");
+            write_expression(out, &assignment.value, options);
+            out.push(';');
+        }
+        FunctionStatement::Expression(expression) => {
+            write_expression(out, expression, options);
+            out.push(';');
+        }
         FunctionStatement::If(if_) => write_if_statement(out, if_, options),
+        FunctionStatement::Switch(switch) => write_switch_statement(out, switch, options),
+        FunctionStatement::Loop(body) => {
+            out.push_str("while (true) ");
+            write_block(out, body, options);
+        }
+        FunctionStatement::Continue => out.push_str("continue;"),
         FunctionStatement::Return(return_value) => {
             write_return_statement(out, return_value, options)
         }
@@ -196,25 +354,64 @@ fn write_function_statement(
     }
 }
 
-fn write_if_statement(out: &mut Writer, if_statement: &IfStatement, options: &FormatOptions) {
-    out.push_str("if (");
-    write_expression(out, &if_statement.condition, options);
-    out.push_str(") {");
-
-    if if_statement.body.is_empty() {
-        out.push_str("}");
-    } else {
-        out.push_str("\n");
-        out.increase_indentation_level();
-        for statement in &if_statement.body {
-            out.indent();
-            write_function_statement(out, statement, options);
-            out.push_str("\n");
+fn write_destructure_statement(
+    out: &mut Writer,
+    destructure: &DestructureStatement,
+    options: &FormatOptions,
+) {
+    out.push_str("const ");
+    match &destructure.pattern {
+        Params::Standard(names) => {
+            out.push('[');
+            for (i, name) in names.iter().enumerate() {
+                if i > 0 {
+                    out.push_str(", ");
+                }
+                out.push_str(&name.0);
+            }
+            out.push(']');
         }
-        out.decrease_indentation_level();
+        Params::DestructuredSingleton(entries) => write_object_pattern(out, entries, options),
+        Params::Rest(name) => {
+            out.push_str("[...");
+            out.push_str(&name.0);
+            out.push(']');
+        }
+    }
+    out.push_str(" = ");
+    write_expression(out, &destructure.value, options);
+    out.push(';');
+}
+
+fn write_switch_statement(out: &mut Writer, switch: &SwitchStatement, options: &FormatOptions) {
+    out.push_str("switch (");
+    write_expression(out, &switch.discriminant, options);
+    out.push_str(") {\n");
+    out.increase_indentation_level();
+    for case in &switch.cases {
         out.indent();
-        out.push_str("}");
+        out.push_str("case ");
+        write_expression(out, &case.test, options);
+        out.push_str(": ");
+        write_block(out, &case.body, options);
+        out.push('\n');
     }
+    if let Some(default) = &switch.default {
+        out.indent();
+        out.push_str("default: ");
+        write_block(out, default, options);
+        out.push('\n');
+    }
+    out.decrease_indentation_level();
+    out.indent();
+    out.push('}');
+}
+
+fn write_if_statement(out: &mut Writer, if_statement: &IfStatement, options: &FormatOptions) {
+    out.push_str("if (");
+    write_expression(out, &if_statement.condition, options);
+    out.push_str(") ");
+    write_block(out, &if_statement.body, options);
 }
 
 fn write_return_statement(out: &mut Writer, return_value: &Expression, options: &FormatOptions) {
@@ -230,28 +427,30 @@ fn write_throw_statement(out: &mut Writer, throw_value: &Expression, options: &F
 }
 
 fn write_binary_op(out: &mut Writer, binary_op: &BinaryOp, options: &FormatOptions) {
-    match binary_op.op {
-        BinaryOpKind::TripleEqual => {
-            out.push_str("(");
-            write_expression(out, &binary_op.left, options);
-            out.push_str(" === ");
-            write_expression(out, &binary_op.right, options);
-            out.push_str(")");
-        }
-        BinaryOpKind::Plus => {
-            out.push_str("(");
-            write_expression(out, &binary_op.left, options);
-            out.push_str(" + ");
-            write_expression(out, &binary_op.right, options);
-            out.push_str(")");
-        }
+    let infix = match binary_op.op {
         BinaryOpKind::Index => {
             write_expression(out, &binary_op.left, options);
             out.push_str("[");
             write_expression(out, &binary_op.right, options);
             out.push_str("]");
+            return;
         }
-    }
+        BinaryOpKind::TripleEqual => " === ",
+        BinaryOpKind::NotTripleEqual => " !== ",
+        BinaryOpKind::LessThan => " < ",
+        BinaryOpKind::GreaterThan => " > ",
+        BinaryOpKind::GreaterThanOrEqual => " >= ",
+        BinaryOpKind::Plus => " + ",
+        BinaryOpKind::Minus => " - ",
+        BinaryOpKind::Times => " * ",
+        BinaryOpKind::BitwiseAnd => " & ",
+        BinaryOpKind::RightShift => " >> ",
+    };
+    out.push_str("(");
+    write_expression(out, &binary_op.left, options);
+    out.push_str(infix);
+    write_expression(out, &binary_op.right, options);
+    out.push_str(")");
 }
 
 fn write_dot(out: &mut Writer, dot: &Dot, options: &FormatOptions) {
@@ -312,16 +511,211 @@ fn write_object(out: &mut Writer, object: &Object, options: &FormatOptions) {
     out.push_str(" }");
 }
 
+pub fn format_declaration_file(file: &DeclarationFile, options: &FormatOptions) -> String {
+    let mut w = Writer::with_options(options);
+    write_declaration_file(&mut w, file);
+    w.finish()
+}
+
+fn write_declaration_file(out: &mut Writer, file: &DeclarationFile) {
+    for (i, item) in file.items.iter().enumerate() {
+        write_declaration_item(out, item);
+        let is_followed_by_import = matches!(
+            (item, file.items.get(i + 1)),
+            (
+                DeclarationItem::Import(_) | DeclarationItem::ImportType(_),
+                Some(DeclarationItem::Import(_) | DeclarationItem::ImportType(_))
+            )
+        );
+        if is_followed_by_import {
+            out.push('\n');
+        } else {
+            out.push_str("\n\n");
+        }
+    }
+}
+
+fn write_declaration_item(out: &mut Writer, item: &DeclarationItem) {
+    match item {
+        DeclarationItem::Import(import) => {
+            out.push_str("import ");
+            write_import_clause(out, import);
+        }
+        DeclarationItem::ImportType(import) => {
+            out.push_str("import type ");
+            write_import_clause(out, import);
+        }
+        DeclarationItem::ExportFrom(import) => {
+            out.push_str("export ");
+            write_import_clause(out, import);
+        }
+        DeclarationItem::ExportType(alias) => {
+            out.push_str("export ");
+            write_type_alias(out, alias);
+        }
+        DeclarationItem::ExportConst(const_) => {
+            out.push_str("export ");
+            write_const_declaration(out, const_);
+        }
+        DeclarationItem::Type(alias) => write_type_alias(out, alias),
+        DeclarationItem::Const(const_) => write_const_declaration(out, const_),
+        DeclarationItem::Export(names) => {
+            out.push_str("export ");
+            write_aliased_names(out, names);
+            out.push(';');
+        }
+    }
+}
+
+fn write_type_alias(out: &mut Writer, alias: &TypeAlias) {
+    out.push_str("type ");
+    out.push_str(&alias.name.0);
+    write_generics(out, &alias.generics);
+    out.push_str(" = ");
+    write_ts_type(out, &alias.value);
+    out.push(';');
+}
+
+fn write_const_declaration(out: &mut Writer, const_: &ConstDeclaration) {
+    out.push_str("declare const ");
+    out.push_str(&const_.name.0);
+    out.push_str(": ");
+    write_ts_type(out, &const_.type_);
+    out.push(';');
+}
+
+fn write_generics(out: &mut Writer, generics: &[ValidJsIdentifierName]) {
+    if generics.is_empty() {
+        return;
+    }
+    out.push('<');
+    for (i, generic) in generics.iter().enumerate() {
+        if i > 0 {
+            out.push_str(", ");
+        }
+        out.push_str(&generic.0);
+    }
+    out.push('>');
+}
+
+fn write_ts_type(out: &mut Writer, type_: &TsType) {
+    match type_ {
+        TsType::Unknown => out.push_str("unknown"),
+        TsType::Never => out.push_str("never"),
+        TsType::Undefined => out.push_str("undefined"),
+        TsType::BigInt => out.push_str("bigint"),
+        TsType::String => out.push_str("string"),
+        TsType::StringLiteral(literal) => write_literal(out, &Literal::String(literal.clone())),
+        TsType::Reference(reference) => {
+            out.push_str(&reference.name.0);
+            if !reference.args.is_empty() {
+                out.push('<');
+                for (i, arg) in reference.args.iter().enumerate() {
+                    if i > 0 {
+                        out.push_str(", ");
+                    }
+                    write_ts_type(out, arg);
+                }
+                out.push('>');
+            }
+        }
+        TsType::Typeof(name) => {
+            out.push_str("typeof ");
+            out.push_str(&name.0);
+        }
+        TsType::Tuple(items) => {
+            out.push('[');
+            for (i, item) in items.iter().enumerate() {
+                if i > 0 {
+                    out.push_str(", ");
+                }
+                write_ts_type(out, item);
+            }
+            out.push(']');
+        }
+        TsType::Object(entries) => write_object_type(out, entries),
+        TsType::Union(members) => {
+            if members.is_empty() {
+                out.push_str("never");
+                return;
+            }
+            for (i, member) in members.iter().enumerate() {
+                if i > 0 {
+                    out.push_str(" | ");
+                }
+                // Function types must be parenthesized,
+                // since otherwise the union would be parsed
+                // as part of the function's return type.
+                if let TsType::Function(_) = member {
+                    out.push('(');
+                    write_ts_type(out, member);
+                    out.push(')');
+                } else {
+                    write_ts_type(out, member);
+                }
+            }
+        }
+        TsType::Function(function) => {
+            write_generics(out, &function.generics);
+            match &function.params {
+                TsParams::Standard(params) => {
+                    out.push('(');
+                    for (i, (name, param_type)) in params.iter().enumerate() {
+                        if i > 0 {
+                            out.push_str(", ");
+                        }
+                        out.push_str(&name.0);
+                        out.push_str(": ");
+                        write_ts_type(out, param_type);
+                    }
+                    out.push(')');
+                }
+                TsParams::DestructuredSingleton(entries) => {
+                    out.push_str("(args: ");
+                    write_object_type(out, entries);
+                    out.push(')');
+                }
+            }
+            out.push_str(" => ");
+            write_ts_type(out, &function.return_type);
+        }
+    }
+}
+
+fn write_object_type(out: &mut Writer, entries: &[ObjectTypeEntry]) {
+    if entries.is_empty() {
+        out.push_str("{}");
+        return;
+    }
+    out.push_str("{ ");
+    for (i, entry) in entries.iter().enumerate() {
+        if i > 0 {
+            out.push_str("; ");
+        }
+        out.push('"');
+        out.push_str(&escape_string_contents(&entry.key.0));
+        out.push('"');
+        if entry.is_optional {
+            out.push('?');
+        }
+        out.push_str(": ");
+        write_ts_type(out, &entry.value);
+    }
+    out.push_str(" }");
+}
+
 use writer::Writer;
 mod writer {
     use super::*;
 
-    /// A thin wrapper around `String` that also tracks indentation state.
+    /// A thin wrapper around `String` that also tracks indentation state
+    /// and source map mappings.
     #[derive(Clone, Debug)]
     pub struct Writer<'a> {
         raw: String,
         indentation_level: usize,
         options: &'a FormatOptions,
+        mappings: Vec<(usize, TextSpan)>,
     }
 
     impl<'a> Writer<'a> {
@@ -330,6 +724,7 @@ mod writer {
                 raw: String::new(),
                 indentation_level: 0,
                 options,
+                mappings: vec![],
             }
         }
     }
@@ -367,8 +762,19 @@ mod writer {
             }
         }
 
+        /// Maps the current end of the output to the start of the span.
+        pub fn add_mapping(&mut self, span: Option<TextSpan>) {
+            if let Some(span) = span {
+                self.mappings.push((self.raw.len(), span));
+            }
+        }
+
         pub fn finish(self) -> String {
             self.raw
         }
+
+        pub fn finish_with_mappings(self) -> (String, Vec<(usize, TextSpan)>) {
+            (self.raw, self.mappings)
+        }
     }
 }
@@ -0,0 +1,190 @@
+//! Replaces calls to small functions with the functions' bodies
+//! when `JavaScriptOptions::inline_small_functions` is on.
+
+use light::DbIndex;
+
+use crate::data::file_id::FileId;
+
+use super::code_gen_impl::*;
+use super::*;
+
+const MAX_INLINED_RETURN_VALUE_SIZE: usize = 16;
+
+/// If `inline_small_functions` is on and the value is an inlinable function
+/// (see `get_inlinable_function`), this records the function,
+/// so calls to it may be replaced with its return value.
+/// The `let` statement's name must already be pushed onto the context.
+pub(super) fn record_let_statement_value_if_inlinable(context: &mut Context, value: &Expression) {
+    if !context.inline_small_functions {
+        return;
+    }
+    let Some((params, return_value)) = get_inlinable_function(value) else {
+        return;
+    };
+    let function = InlinableFunction {
+        file_id: context.current_module,
+        params,
+        return_value,
+        references: context.references.clone(),
+    };
+    let level = context.index_to_level(DbIndex(0));
+    context.inlinable_functions.insert(level, function);
+}
+
+/// If the callee is an inlinable function declared in the current module
+/// (see `get_inlinable_function`),
+/// and every arg is a name or literal,
+/// this returns the function's return value with the args
+/// substituted for the params.
+/// Since evaluating such args has no effects,
+/// it doesn't matter how many times the return value uses them.
+pub(super) fn try_inline_call(
+    registry: &NodeRegistry,
+    context: &mut Context,
+    call: &light::Call,
+    args: &[Expression],
+) -> Option<Expression> {
+    let light::ExpressionId::Name(callee_id) = call.callee_id else {
+        return None;
+    };
+    let level = context.index_to_level(registry.get(callee_id).db_index);
+    let function = context.inlinable_functions.get(&level)?;
+    let are_args_atomic = args
+        .iter()
+        .all(|arg| matches!(arg, Expression::Identifier(_) | Expression::Literal(_)));
+    if function.file_id != context.current_module
+        || !are_args_atomic
+        || args.len() > function.params.len()
+    {
+        return None;
+    }
+
+    let mut inlined = function.return_value.clone();
+    substitute_args_for_params(&mut inlined, &function.params, args);
+    for reference in function.references.clone() {
+        if !context.references.contains(&reference) {
+            context.references.push(reference);
+        }
+    }
+    Some(inlined)
+}
+
+/// Missing args (i.e., trailing erased ones) are `undefined`.
+fn substitute_args_for_params(
+    expression: &mut Expression,
+    params: &[ValidJsIdentifierName],
+    args: &[Expression],
+) {
+    match expression {
+        Expression::Identifier(name) => {
+            if let Some(index) = params.iter().position(|param| param == name) {
+                *expression = args.get(index).cloned().unwrap_or_else(undefined);
+            }
+        }
+        Expression::Literal(_)
+        | Expression::Function(_)
+        | Expression::GeneratorFunction(_)
+        | Expression::Yield(_) => {}
+        Expression::Call(call) | Expression::New(call) => {
+            substitute_args_for_params(&mut call.callee, params, args);
+            for arg in &mut call.args {
+                substitute_args_for_params(arg, params, args);
+            }
+        }
+        Expression::BinaryOp(op) => {
+            substitute_args_for_params(&mut op.left, params, args);
+            substitute_args_for_params(&mut op.right, params, args);
+        }
+        Expression::Typeof(argument) => substitute_args_for_params(argument, params, args),
+        Expression::Dot(dot) => substitute_args_for_params(&mut dot.left, params, args),
+        Expression::Ternary(ternary) => {
+            substitute_args_for_params(&mut ternary.condition, params, args);
+            substitute_args_for_params(&mut ternary.true_body, params, args);
+            substitute_args_for_params(&mut ternary.false_body, params, args);
+        }
+        Expression::Array(array) => {
+            for item in &mut array.items {
+                substitute_args_for_params(item, params, args);
+            }
+        }
+        Expression::Object(object) => {
+            for entry in &mut object.entries {
+                substitute_args_for_params(&mut entry.value, params, args);
+            }
+        }
+    }
+}
+
+/// Returns the params and return value of the function
+/// if its body is a single `return` statement whose value
+/// has at most `MAX_INLINED_RETURN_VALUE_SIZE` nodes,
+/// declares no names (so the params are the only local names it uses),
+/// and doesn't call the function itself.
+fn get_inlinable_function(value: &Expression) -> Option<(Vec<ValidJsIdentifierName>, Expression)> {
+    let Expression::Function(function) = value else {
+        return None;
+    };
+    let Params::Standard(params) = &function.params else {
+        return None;
+    };
+    let [FunctionStatement::Return(return_value)] = &function.body[..] else {
+        return None;
+    };
+    let size = get_inlinable_expression_size(return_value, &function.name)?;
+    (size <= MAX_INLINED_RETURN_VALUE_SIZE).then(|| (params.clone(), return_value.clone()))
+}
+
+/// Returns `None` if the expression declares any names
+/// or refers to `fun_name`.
+fn get_inlinable_expression_size(
+    expression: &Expression,
+    fun_name: &ValidJsIdentifierName,
+) -> Option<usize> {
+    let size_of = |expression| get_inlinable_expression_size(expression, fun_name);
+    match expression {
+        Expression::Literal(_) => Some(1),
+        Expression::Identifier(name) => (name != fun_name).then_some(1),
+        Expression::Call(call) | Expression::New(call) => {
+            let mut size = 1 + size_of(&call.callee)?;
+            for arg in &call.args {
+                size += size_of(arg)?;
+            }
+            Some(size)
+        }
+        Expression::Function(_) | Expression::GeneratorFunction(_) | Expression::Yield(_) => None,
+        Expression::BinaryOp(op) => Some(1 + size_of(&op.left)? + size_of(&op.right)?),
+        Expression::Typeof(argument) => Some(1 + size_of(argument)?),
+        Expression::Dot(dot) => Some(1 + size_of(&dot.left)?),
+        Expression::Ternary(ternary) => Some(
+            1 + size_of(&ternary.condition)?
+                + size_of(&ternary.true_body)?
+                + size_of(&ternary.false_body)?,
+        ),
+        Expression::Array(array) => {
+            let mut size = 1;
+            for item in &array.items {
+                size += size_of(item)?;
+            }
+            Some(size)
+        }
+        Expression::Object(object) => {
+            let mut size = 1;
+            for entry in &object.entries {
+                size += size_of(&entry.value)?;
+            }
+            Some(size)
+        }
+    }
+}
+
+/// A function whose body is a single `return` statement
+/// (see `get_inlinable_function`).
+#[derive(Clone, Debug)]
+pub(super) struct InlinableFunction {
+    /// The module that declares the function.
+    file_id: FileId,
+    params: Vec<ValidJsIdentifierName>,
+    return_value: Expression,
+    /// The non-local names the return value refers to.
+    references: Vec<(NameOrigin, ValidJsIdentifierName)>,
+}
@@ -1,3 +1,5 @@
+use crate::data::text_span::TextSpan;
+
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub struct File {
     pub items: Vec<FileItem>,
@@ -5,7 +7,30 @@ pub struct File {
 
 #[derive(Clone, Debug, PartialEq, Eq, Hash)]
 pub enum FileItem {
+    Import(ImportStatement),
+    /// `export { names } from "specifier";`
+    ExportFrom(ImportStatement),
     Const(ConstStatement),
+    ExportConst(ConstStatement),
+    /// `export { names };`
+    Export(Vec<AliasedName>),
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct ImportStatement {
+    pub names: Vec<AliasedName>,
+    pub specifier: JsStringLiteral,
+}
+
+/// `name as alias`, or just `name` if the two are the same.
+/// In an import, `name` is the name the source module exports
+/// and `alias` is the local name.
+/// In an export, `name` is the local (or re-exported) name
+/// and `alias` is the exported name.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct AliasedName {
+    pub name: ValidJsIdentifierName,
+    pub alias: ValidJsIdentifierName,
 }
 
 #[derive(Clone, Debug, PartialEq, Eq, Hash)]
@@ -21,6 +46,11 @@ pub enum Expression {
     Call(Box<Call>),
     New(Box<Call>),
     Function(Box<Function>),
+    /// A `function*` expression.
+    GeneratorFunction(Box<Function>),
+    Yield(Box<Yield>),
+    /// `(typeof argument)`
+    Typeof(Box<Expression>),
     BinaryOp(Box<BinaryOp>),
     Dot(Box<Dot>),
     Ternary(Box<Ternary>),
@@ -35,6 +65,8 @@ pub struct ValidJsIdentifierName(pub String);
 pub enum Literal {
     Boolean(bool),
     Number(i32),
+    /// A `bigint` literal (e.g., `1n`).
+    BigInt(i128),
     String(JsStringLiteral),
 }
 
@@ -47,6 +79,9 @@ pub struct JsStringLiteral {
 pub struct Call {
     pub callee: Expression,
     pub args: Vec<Expression>,
+    /// The span of the Kantu code this call was generated from,
+    /// which the source map maps the call to.
+    pub span: Option<TextSpan>,
 }
 
 #[derive(Clone, Debug, PartialEq, Eq, Hash)]
@@ -54,12 +89,17 @@ pub struct Function {
     pub name: ValidJsIdentifierName,
     pub params: Params,
     pub body: Vec<FunctionStatement>,
+    /// The span of the Kantu code this function was generated from,
+    /// which the source map maps the function to.
+    pub span: Option<TextSpan>,
 }
 
 #[derive(Clone, Debug, PartialEq, Eq, Hash)]
 pub enum Params {
     Standard(Vec<ValidJsIdentifierName>),
     DestructuredSingleton(Vec<ObjectDestructureEntry>),
+    /// `...name`
+    Rest(ValidJsIdentifierName),
 }
 
 impl Params {
@@ -67,6 +107,7 @@ impl Params {
         match self {
             Params::Standard(params) => params.is_empty(),
             Params::DestructuredSingleton(entries) => entries.is_empty(),
+            Params::Rest(_) => false,
         }
     }
 }
@@ -75,22 +116,61 @@ impl Params {
 pub struct ObjectDestructureEntry {
     pub in_name: ValidJsIdentifierName,
     pub out_name: ValidJsIdentifierName,
+    pub default: Option<Expression>,
 }
 
 #[derive(Clone, Debug, PartialEq, Eq, Hash)]
 pub enum FunctionStatement {
     Const(ConstStatement),
+    /// Destructures the value into the params' names
+    /// (e.g., `const [a, b] = value;` for standard params).
+    ConstDestructure(DestructureStatement),
+    Let(ConstStatement),
+    Assign(Assignment),
+    Expression(Expression),
     If(IfStatement),
+    Switch(SwitchStatement),
+    /// `while (true) { ... }`
+    Loop(Vec<FunctionStatement>),
+    Continue,
     Return(Expression),
     Throw(Expression),
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct DestructureStatement {
+    pub pattern: Params,
+    pub value: Expression,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Assignment {
+    pub name: ValidJsIdentifierName,
+    pub value: Expression,
+}
+
 #[derive(Clone, Debug, PartialEq, Eq, Hash)]
 pub struct IfStatement {
     pub condition: Expression,
     pub body: Vec<FunctionStatement>,
 }
 
+/// Since every case body is a block, cases don't share bindings.
+/// Cases do fall through, so each case body should end with
+/// a `return`, `continue`, or `throw`.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct SwitchStatement {
+    pub discriminant: Expression,
+    pub cases: Vec<SwitchCase>,
+    pub default: Option<Vec<FunctionStatement>>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct SwitchCase {
+    pub test: Expression,
+    pub body: Vec<FunctionStatement>,
+}
+
 #[derive(Clone, Debug, PartialEq, Eq, Hash)]
 pub struct BinaryOp {
     pub op: BinaryOpKind,
@@ -101,10 +181,25 @@ pub struct BinaryOp {
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
 pub enum BinaryOpKind {
     TripleEqual,
+    NotTripleEqual,
+    LessThan,
+    GreaterThan,
+    GreaterThanOrEqual,
     Plus,
+    Minus,
+    Times,
+    BitwiseAnd,
+    RightShift,
     Index,
 }
 
+/// `(yield argument)`, or `(yield* argument)` if `is_delegate` is true.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Yield {
+    pub argument: Expression,
+    pub is_delegate: bool,
+}
+
 #[derive(Clone, Debug, PartialEq, Eq, Hash)]
 pub struct Dot {
     pub left: Expression,
@@ -1,4 +1,5 @@
 use crate::data::{
+    file_tree::FileTree,
     light_ast as light,
     node_registry::{
         ExpressionRef, FileItemNodeId, LabeledCallArgId, MatchCaseOutputId, NodeId, NodeRegistry,
@@ -7,33 +8,112 @@ use crate::data::{
     non_empty_vec::OptionalNonEmptyVecLen,
     text_span::*,
 };
-use crate::processing::generate_code::CompileTarget;
+use crate::processing::{
+    find_unreachable_items::UnreachableItems,
+    generate_code::CompileTarget,
+    type_check::{
+        BigIntAnalysis, BigIntFunction, BigIntOperation, BigIntType, BigIntVariant, ErasedArgs,
+        ErasureAnalysis, PrecomputedLets, PrecomputedValue,
+    },
+};
+
+use std::path::{Path, PathBuf};
 
 use js_ast::*;
+use source_map::*;
+use ts_ast::*;
 
 pub mod format;
 pub mod js_ast;
+pub mod source_map;
+pub mod ts_ast;
 
+mod arg_check_gen_impl;
+mod bigint_gen_impl;
 mod code_gen_impl;
+mod declaration_gen_impl;
+mod inline_gen_impl;
+mod stack_safe_gen_impl;
 
 #[derive(Clone, Debug)]
 pub struct JavaScript;
 
+#[derive(Clone, Debug, Default)]
+pub struct JavaScriptOptions {
+    /// The params, args, and matches to erase from the generated code.
+    /// If this is empty, nothing is erased.
+    pub erasure: ErasureAnalysis,
+    /// The types to represent as `bigint`s, along with the matches on them
+    /// and the functions to replace with native operations.
+    /// If this is empty, every type is represented as usual.
+    pub bigint: BigIntAnalysis,
+    /// If true, self tail calls are compiled to loops,
+    /// the remaining self calls are run on a heap-allocated stack,
+    /// and matches are compiled to `switch` statements,
+    /// so that deep recursion doesn't overflow the JS call stack.
+    pub stack_safe: bool,
+    /// The files that spans are resolved against,
+    /// so that runtime errors can display the locations
+    /// of the Kantu code responsible for them.
+    /// Locations in files missing from this map are displayed as
+    /// `<No span found>`.
+    pub source_files: SourceFiles,
+    /// The items to omit from the generated code.
+    /// They are still visited (but not emitted),
+    /// so that the names of the other items don't change.
+    /// If this is empty, every item is emitted.
+    pub unreachable_items: UnreachableItems,
+    /// The `let` statements whose values are emitted as literals.
+    /// If this is empty, every value is computed at runtime.
+    pub precomputed_lets: PrecomputedLets,
+    /// If true, calls to small non-recursive functions declared
+    /// in the same module are replaced with the functions' bodies
+    /// (if every arg is a name or literal).
+    pub inline_small_functions: bool,
+    /// If true, `index.js` wraps each exported function
+    /// with a function that checks the shapes of its args
+    /// (i.e., their variant tags, arities, and labels, recursively),
+    /// and throws an error naming the Kantu type and param
+    /// if an arg is malformed.
+    /// Calls between generated functions are not checked.
+    pub check_exported_args: bool,
+}
+
 #[derive(Clone, Debug)]
 pub enum CompileToJavaScriptError {}
 
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum CompileToJavaScriptWarning {
+    /// A fun calls itself from a fun nested in its body
+    /// while `JavaScriptOptions::stack_safe` is on.
+    /// Such a call is an ordinary JS call, so it still uses the JS call stack.
+    StackUnsafeSelfCall {
+        fun_id: NodeId<light::Fun>,
+        call_id: NodeId<light::Call>,
+    },
+}
+
+/// The generated files, with paths relative to the target directory.
+#[derive(Clone, Debug)]
+pub struct JavaScriptFiles {
+    pub js_files: Vec<(PathBuf, js_ast::File)>,
+    /// The TypeScript declarations of each JS file,
+    /// which are written next to it (e.g., `pack.d.ts` for `pack.js`).
+    pub declaration_files: Vec<(PathBuf, ts_ast::DeclarationFile)>,
+    pub warnings: Vec<CompileToJavaScriptWarning>,
+}
+
 impl CompileTarget for JavaScript {
-    type Options = ();
-    // TODO: Make this return a tree of files, instead of
-    // just a single file.
-    type Ok = js_ast::File;
+    type Options = JavaScriptOptions;
+    type Ok = JavaScriptFiles;
     type Error = CompileToJavaScriptError;
 
     fn generate_code_with_options(
         registry: &NodeRegistry,
+        file_tree: &FileTree,
         file_item_list_id: Option<NonEmptyListId<FileItemNodeId>>,
-        _options: Self::Options,
+        options: Self::Options,
     ) -> Result<Self::Ok, Self::Error> {
-        code_gen_impl::generate_code_with_options(registry, file_item_list_id)
+        code_gen_impl::generate_code_with_options(registry, file_tree, file_item_list_id, options)
     }
 }
@@ -0,0 +1,244 @@
+use crate::data::{file_id::FileId, text_span::*};
+
+use std::path::{Path, PathBuf};
+
+use rustc_hash::FxHashMap;
+
+/// A Kantu source file, which spans are resolved against.
+#[derive(Clone, Debug)]
+pub struct SourceFile {
+    /// The path of the file, relative to the pack directory
+    /// (i.e., the parent of the target directory).
+    /// For example, `src/mod.k`.
+    pub path: PathBuf,
+    pub src: String,
+}
+
+pub type SourceFiles = FxHashMap<FileId, SourceFile>;
+
+/// Returns the start of the span as `path:line:col`
+/// (e.g., `src/mod.k:3:4`), the same way the CLI displays locations,
+/// except that the path is relative to the pack directory.
+///
+/// Returns `None` if the span's file isn't in `source_files`.
+pub fn format_span_start(source_files: &SourceFiles, span: TextSpan) -> Option<String> {
+    let source_file = source_files.get(&span.file_id)?;
+    let start = TextCoord::new(&source_file.src, span.start)?;
+    Some(format!(
+        "{}:{}:{}",
+        url_path(&source_file.path),
+        start.line,
+        start.col
+    ))
+}
+
+/// The source map of `path` is written to `path` with `.map` appended
+/// (e.g., `pack/foo.js.map` for `pack/foo.js`).
+pub fn get_source_map_path(path: &Path) -> PathBuf {
+    let mut path = path.as_os_str().to_owned();
+    path.push(".map");
+    PathBuf::from(path)
+}
+
+/// Generates a version 3 source map for the generated file at `path`
+/// (relative to the target directory).
+/// Each mapping maps the byte index of `generated` it's paired with
+/// to the start of its span.
+/// Mappings must be sorted by their byte indices.
+/// Mappings whose spans belong to files missing from `source_files`
+/// are skipped.
+pub fn generate_source_map(
+    path: &Path,
+    generated: &str,
+    mappings: &[(usize, TextSpan)],
+    source_files: &SourceFiles,
+) -> String {
+    let mut sources: Vec<FileId> = vec![];
+    let mut line_starts: FxHashMap<FileId, Vec<usize>> = FxHashMap::default();
+    let mut encoded = String::new();
+
+    let mut generated_coord = Utf16Coord { line: 0, col: 0 };
+    let mut generated_index = 0;
+    let mut previous_generated_line = 0;
+    let mut previous_generated_col = 0;
+    let mut previous_source = 0;
+    let mut previous_original_line = 0;
+    let mut previous_original_col = 0;
+    let mut previous_generated_index = None;
+
+    for &(index, span) in mappings {
+        let Some(source_file) = source_files.get(&span.file_id) else {
+            continue;
+        };
+        // If several mappings start at the same index,
+        // the first one (i.e., the outermost one) wins.
+        if previous_generated_index == Some(index) {
+            continue;
+        }
+        previous_generated_index = Some(index);
+
+        generated_coord.advance(&generated[generated_index..index]);
+        generated_index = index;
+
+        let source = match sources.iter().position(|id| *id == span.file_id) {
+            Some(source) => source,
+            None => {
+                sources.push(span.file_id);
+                sources.len() - 1
+            }
+        };
+        let original_coord = {
+            let line_starts = line_starts
+                .entry(span.file_id)
+                .or_insert_with(|| get_line_starts(&source_file.src));
+            Utf16Coord::new(&source_file.src, line_starts, span.start.0)
+        };
+
+        if generated_coord.line == previous_generated_line {
+            if !encoded.is_empty() && !encoded.ends_with(';') {
+                encoded.push(',');
+            }
+        } else {
+            for _ in previous_generated_line..generated_coord.line {
+                encoded.push(';');
+            }
+            previous_generated_line = generated_coord.line;
+            previous_generated_col = 0;
+        }
+
+        encode_vlq(
+            &mut encoded,
+            generated_coord.col as isize - previous_generated_col as isize,
+        );
+        encode_vlq(&mut encoded, source as isize - previous_source as isize);
+        encode_vlq(
+            &mut encoded,
+            original_coord.line as isize - previous_original_line as isize,
+        );
+        encode_vlq(
+            &mut encoded,
+            original_coord.col as isize - previous_original_col as isize,
+        );
+        previous_generated_col = generated_coord.col;
+        previous_source = source;
+        previous_original_line = original_coord.line;
+        previous_original_col = original_coord.col;
+    }
+
+    // The sources are relative to the directory of the source map,
+    // which is `path.components().count() - 1` levels below
+    // the target directory.
+    // The target directory is in turn one level below the pack directory.
+    let pack_dir = "../".repeat(path.components().count());
+    let file_name = path
+        .file_name()
+        .map(|name| name.to_string_lossy().to_string())
+        .unwrap_or_default();
+    let source_paths = sources
+        .iter()
+        .map(|id| format!("{}{}", pack_dir, url_path(&source_files[id].path)));
+    let source_contents = sources.iter().map(|id| source_files[id].src.clone());
+
+    format!(
+        "{{\"version\":3,\"file\":{},\"sources\":[{}],\"sourcesContent\":[{}],\"names\":[],\"mappings\":{}}}\n",
+        json_string(&file_name),
+        source_paths
+            .map(|path| json_string(&path))
+            .collect::<Vec<_>>()
+            .join(","),
+        source_contents
+            .map(|src| json_string(&src))
+            .collect::<Vec<_>>()
+            .join(","),
+        json_string(&encoded),
+    )
+}
+
+/// A 0-indexed line, and a 0-indexed column measured in UTF-16 code units,
+/// which is how source maps measure columns.
+#[derive(Clone, Copy, Debug)]
+struct Utf16Coord {
+    line: usize,
+    col: usize,
+}
+
+impl Utf16Coord {
+    fn new(src: &str, line_starts: &[usize], index: usize) -> Self {
+        let line = match line_starts.binary_search(&index) {
+            Ok(line) => line,
+            Err(next_line) => next_line - 1,
+        };
+        let col = src[line_starts[line]..index]
+            .chars()
+            .map(char::len_utf16)
+            .sum();
+        Utf16Coord { line, col }
+    }
+
+    fn advance(&mut self, text: &str) {
+        for c in text.chars() {
+            if c == '\n' {
+                self.line += 1;
+                self.col = 0;
+            } else {
+                self.col += c.len_utf16();
+            }
+        }
+    }
+}
+
+fn get_line_starts(src: &str) -> Vec<usize> {
+    std::iter::once(0)
+        .chain(src.match_indices('\n').map(|(i, _)| i + 1))
+        .collect()
+}
+
+/// Appends the base64 VLQ encoding of `value`.
+fn encode_vlq(out: &mut String, value: isize) {
+    const BASE64: &[u8] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+
+    // The sign is stored in the least significant bit.
+    let mut remaining = if value < 0 {
+        (value.unsigned_abs() << 1) | 1
+    } else {
+        value.unsigned_abs() << 1
+    };
+    loop {
+        let mut digit = remaining & 0b11111;
+        remaining >>= 5;
+        if remaining > 0 {
+            // Continuation bit
+            digit |= 0b100000;
+        }
+        out.push(BASE64[digit] as char);
+        if remaining == 0 {
+            break;
+        }
+    }
+}
+
+fn json_string(unescaped: &str) -> String {
+    let mut out = String::with_capacity(unescaped.len() + 2);
+    out.push('"');
+    for c in unescaped.chars() {
+        match c {
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            '\n' => out.push_str("\\n"),
+            '\r' => out.push_str("\\r"),
+            '\t' => out.push_str("\\t"),
+            c if (c as u32) < 0x20 => out.push_str(&format!("\\u{:04x}", c as u32)),
+            c => out.push(c),
+        }
+    }
+    out.push('"');
+    out
+}
+
+/// Joins the components of `path` with `/`, regardless of the platform.
+fn url_path(path: &Path) -> String {
+    path.components()
+        .map(|component| component.as_os_str().to_string_lossy())
+        .collect::<Vec<_>>()
+        .join("/")
+}
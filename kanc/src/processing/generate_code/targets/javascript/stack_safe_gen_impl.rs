@@ -0,0 +1,624 @@
+//! Generates the code for funs when `JavaScriptOptions::stack_safe` is on,
+//! so that deep recursion doesn't overflow the JS call stack.
+
+use light::{DbIndex, DbLevel};
+
+use super::bigint_gen_impl::*;
+use super::code_gen_impl::*;
+use super::*;
+
+/// Stack-safe funs (see `generate_code_for_stack_safe_fun`) compile
+/// non-tail self calls to `yield`s of the args.
+/// This runs such a fun's generator, keeping the pending calls
+/// on a heap-allocated stack instead of the JS call stack:
+/// ```js
+/// const run_recursion = function run_recursion(generator, args) {
+///     const stack = [(generator.apply)(undefined, args)];
+///     let input = undefined;
+///     while (true) {
+///         const step = ((stack[(stack.length - 1)]).next)(input);
+///         if (step.done) {
+///             (stack.pop)();
+///             if ((stack.length === 0)) {
+///                 return step.value;
+///             }
+///             input = step.value;
+///             continue;
+///         }
+///         (stack.push)((generator.apply)(undefined, step.value));
+///         input = undefined;
+///     }
+/// };
+/// ```
+pub(super) fn generate_code_for_recursion_runner() -> Vec<ConstStatement> {
+    let name = |s: &str| ValidJsIdentifierName(s.to_string());
+    let identifier = |s: &str| Expression::Identifier(name(s));
+    let dot = |left: Expression, right: &str| {
+        Expression::Dot(Box::new(Dot {
+            left,
+            right: name(right),
+        }))
+    };
+    let method_call = |object: Expression, method: &str, args: Vec<Expression>| {
+        Expression::Call(Box::new(Call {
+            callee: dot(object, method),
+            args,
+            span: None,
+        }))
+    };
+    let start_call = |args: Expression| {
+        method_call(
+            identifier(RECURSION_RUNNER_PARAM0_NAME),
+            "apply",
+            vec![undefined(), args],
+        )
+    };
+    let stack_length = || dot(identifier("stack"), "length");
+
+    let runner_name = name(RECURSION_RUNNER_NAME);
+    vec![ConstStatement {
+        name: runner_name.clone(),
+        value: Expression::Function(Box::new(Function {
+            name: runner_name,
+            params: Params::Standard(vec![
+                name(RECURSION_RUNNER_PARAM0_NAME),
+                name(RECURSION_RUNNER_PARAM1_NAME),
+            ]),
+            body: vec![
+                FunctionStatement::Const(ConstStatement {
+                    name: name("stack"),
+                    value: Expression::Array(Box::new(Array {
+                        items: vec![start_call(identifier(RECURSION_RUNNER_PARAM1_NAME))],
+                    })),
+                }),
+                FunctionStatement::Let(ConstStatement {
+                    name: name("input"),
+                    value: undefined(),
+                }),
+                FunctionStatement::Loop(vec![
+                    FunctionStatement::Const(ConstStatement {
+                        name: name("step"),
+                        value: method_call(
+                            binary_op(
+                                identifier("stack"),
+                                BinaryOpKind::Index,
+                                binary_op(
+                                    stack_length(),
+                                    BinaryOpKind::Minus,
+                                    Expression::Literal(Literal::Number(1)),
+                                ),
+                            ),
+                            "next",
+                            vec![identifier("input")],
+                        ),
+                    }),
+                    FunctionStatement::If(IfStatement {
+                        condition: dot(identifier("step"), "done"),
+                        body: vec![
+                            FunctionStatement::Expression(method_call(
+                                identifier("stack"),
+                                "pop",
+                                vec![],
+                            )),
+                            FunctionStatement::If(IfStatement {
+                                condition: binary_op(
+                                    stack_length(),
+                                    BinaryOpKind::TripleEqual,
+                                    Expression::Literal(Literal::Number(0)),
+                                ),
+                                body: vec![FunctionStatement::Return(dot(
+                                    identifier("step"),
+                                    "value",
+                                ))],
+                            }),
+                            FunctionStatement::Assign(Assignment {
+                                name: name("input"),
+                                value: dot(identifier("step"), "value"),
+                            }),
+                            FunctionStatement::Continue,
+                        ],
+                    }),
+                    FunctionStatement::Expression(method_call(
+                        identifier("stack"),
+                        "push",
+                        vec![start_call(dot(identifier("step"), "value"))],
+                    )),
+                    FunctionStatement::Assign(Assignment {
+                        name: name("input"),
+                        value: undefined(),
+                    }),
+                ]),
+            ],
+            span: None,
+        })),
+    }]
+}
+
+/// Compiles self tail calls to loop iterations,
+/// and the remaining self calls to `yield`s that `run_recursion` handles
+/// (see `generate_code_for_recursion_runner`),
+/// so that deep recursion doesn't overflow the JS call stack.
+///
+/// For example,
+/// ```kantu
+/// fun len(-l: NatList, acc: Nat): Nat {
+///     match l {
+///         nil => acc,
+///         cons(_, tail) => len(tail, Nat.succ(acc)),
+///     }
+/// }
+/// ```
+/// compiles to
+/// ```js
+/// function len(...temp_0) {
+///     while (true) {
+///         const [l, acc] = temp_0;
+///         const temp_1 = l;
+///         switch (temp_1[0]) {
+///             case "nil": {
+///                 return acc;
+///             }
+///             case "cons": {
+///                 const _ = temp_1[1];
+///                 const tail = temp_1[2];
+///                 temp_0 = [tail, Nat_succ(acc)];
+///                 continue;
+///             }
+///             default: {
+///                 return unreachable("...");
+///             }
+///         }
+///     }
+/// }
+/// ```
+/// If the fun also has non-tail self calls, the function above
+/// becomes a generator, and we return a wrapper that passes it
+/// to `run_recursion`.
+///
+/// Self calls from funs nested in the body can't be compiled this way,
+/// since the nested fun may be called after the outer fun returns
+/// (or never).
+/// They are ordinary calls, so we emit a warning for each of them.
+///
+/// See `generate_code_for_params_and_leave_params_in_context`
+/// for the meaning of `should_omit_erased_params`.
+pub(super) fn generate_code_for_stack_safe_fun(
+    registry: &NodeRegistry,
+    context: &mut Context,
+    fun: &light::Fun,
+    should_omit_erased_params: bool,
+) -> Result<Expression, CompileToJavaScriptError> {
+    let self_calls = get_self_call_positions(registry, fun);
+    for call_id in &self_calls.nested_call_ids {
+        let warning = CompileToJavaScriptWarning::StackUnsafeSelfCall {
+            fun_id: fun.id,
+            call_id: *call_id,
+        };
+        if !context.warnings.contains(&warning) {
+            context.warnings.push(warning);
+        }
+    }
+    // Nested funs can't make tail calls to the outer fun,
+    // or yield from its generator.
+    let outer_recursion = context.recursion.take();
+
+    let param_arity = fun.param_list_id.len();
+    let params = generate_code_for_params_and_leave_params_in_context(
+        registry,
+        context,
+        fun.param_list_id,
+        should_omit_erased_params,
+    )?;
+    let fun_js_name = push_fun_name(registry, context, fun, should_omit_erased_params);
+    let loop_args = if self_calls.has_tail {
+        Some(LoopArgs {
+            js_name: context.get_disposable_name(),
+            is_labeled: matches!(params, Params::DestructuredSingleton(_)),
+        })
+    } else {
+        None
+    };
+    let generator_js_name = if self_calls.has_non_tail {
+        Some(context.get_disposable_name())
+    } else {
+        None
+    };
+    context.recursion = Some(RecursionFrame {
+        fun_level: context.index_to_level(DbIndex(0)),
+        loop_args: loop_args.clone(),
+        is_generator: generator_js_name.is_some(),
+    });
+    let body = generate_code_for_tail_position(registry, context, fun.body_id, true);
+    context.recursion = outer_recursion;
+    context.pop_n(param_arity + 1);
+    let body = body?;
+
+    let (params, body) = match loop_args {
+        None => (params, body),
+        Some(loop_args) => {
+            let mut loop_body = Vec::with_capacity(body.len() + 1);
+            loop_body.push(FunctionStatement::ConstDestructure(DestructureStatement {
+                pattern: params,
+                value: Expression::Identifier(loop_args.js_name.clone()),
+            }));
+            loop_body.extend(body);
+            let params = if loop_args.is_labeled {
+                Params::Standard(vec![loop_args.js_name])
+            } else {
+                Params::Rest(loop_args.js_name)
+            };
+            (params, vec![FunctionStatement::Loop(loop_body)])
+        }
+    };
+
+    let Some(generator_js_name) = generator_js_name else {
+        return Ok(Expression::Function(Box::new(Function {
+            name: fun_js_name,
+            params,
+            body,
+            span: fun.span,
+        })));
+    };
+    let args_js_name = context.get_disposable_name();
+    let generator = Expression::GeneratorFunction(Box::new(Function {
+        name: generator_js_name,
+        params,
+        body,
+        span: fun.span,
+    }));
+    Ok(Expression::Function(Box::new(Function {
+        name: fun_js_name,
+        params: Params::Rest(args_js_name.clone()),
+        body: vec![FunctionStatement::Return(Expression::Call(Box::new(
+            Call {
+                callee: Expression::Identifier(context.reference_runtime(RECURSION_RUNNER_NAME)),
+                args: vec![generator, Expression::Identifier(args_js_name)],
+                span: None,
+            },
+        )))],
+        span: fun.span,
+    })))
+}
+
+/// Where a fun calls itself in its body.
+/// A call is in tail position if it's the body itself,
+/// or in tail position of a `check`, `rewrite`, or
+/// of a match case output of a match in tail position.
+/// Calls from nested funs aren't counted as tail or non-tail calls,
+/// since those are ordinary calls.
+#[derive(Clone, Debug, Default)]
+struct SelfCallPositions {
+    has_tail: bool,
+    has_non_tail: bool,
+    /// The self calls from nested funs.
+    nested_call_ids: Vec<NodeId<light::Call>>,
+}
+
+fn get_self_call_positions(registry: &NodeRegistry, fun: &light::Fun) -> SelfCallPositions {
+    fn visit(
+        registry: &NodeRegistry,
+        id: light::ExpressionId,
+        fun_index: usize,
+        is_tail: bool,
+        is_nested: bool,
+        out: &mut SelfCallPositions,
+    ) {
+        match registry.expression_ref(id) {
+            ExpressionRef::Name(_)
+            | ExpressionRef::Todo(_)
+            | ExpressionRef::Forall(_)
+            | ExpressionRef::Exists(_) => {}
+            ExpressionRef::Fun(nested) => {
+                // The nested fun's params and name are bound in its body.
+                let nested_fun_index = fun_index + nested.param_list_id.len() + 1;
+                visit(registry, nested.body_id, nested_fun_index, false, true, out);
+            }
+            ExpressionRef::Call(call) => {
+                let is_self_call = matches!(
+                    registry.expression_ref(call.callee_id),
+                    ExpressionRef::Name(name) if name.db_index.0 == fun_index
+                );
+                if !is_self_call {
+                    visit(registry, call.callee_id, fun_index, false, is_nested, out);
+                } else if is_nested {
+                    out.nested_call_ids.push(call.id);
+                } else if is_tail {
+                    out.has_tail = true;
+                } else {
+                    out.has_non_tail = true;
+                }
+                match call.arg_list_id {
+                    NonEmptyCallArgListId::Unlabeled(arg_list_id) => {
+                        for arg_id in registry.get_list(arg_list_id).iter() {
+                            visit(registry, *arg_id, fun_index, false, is_nested, out);
+                        }
+                    }
+                    NonEmptyCallArgListId::UniquelyLabeled(arg_list_id) => {
+                        for arg_id in registry.get_list(arg_list_id).iter() {
+                            if let LabeledCallArgId::Explicit { value_id, .. } = arg_id {
+                                visit(registry, *value_id, fun_index, false, is_nested, out);
+                            }
+                        }
+                    }
+                }
+            }
+            ExpressionRef::Match(match_) => {
+                visit(
+                    registry,
+                    match_.matchee_id,
+                    fun_index,
+                    false,
+                    is_nested,
+                    out,
+                );
+                for case_id in registry.get_possibly_empty_list(match_.case_list_id).iter() {
+                    let case = registry.get(*case_id);
+                    let explicit_arity = case
+                        .param_list_id
+                        .map(|list_id| list_id.explicit_len())
+                        .unwrap_or(0);
+                    if let MatchCaseOutputId::Some(output_id) = case.output_id {
+                        visit(
+                            registry,
+                            output_id,
+                            fun_index + explicit_arity,
+                            is_tail,
+                            is_nested,
+                            out,
+                        );
+                    }
+                }
+            }
+            ExpressionRef::Check(check) => visit(
+                registry,
+                check.output_id,
+                fun_index,
+                is_tail,
+                is_nested,
+                out,
+            ),
+            ExpressionRef::Rewrite(rewrite) => visit(
+                registry,
+                rewrite.body_id,
+                fun_index,
+                is_tail,
+                is_nested,
+                out,
+            ),
+            ExpressionRef::Projection(projection) => visit(
+                registry,
+                projection.record_id,
+                fun_index,
+                false,
+                is_nested,
+                out,
+            ),
+            ExpressionRef::Pair(pair) => {
+                visit(registry, pair.witness_id, fun_index, false, is_nested, out);
+                visit(registry, pair.proof_id, fun_index, false, is_nested, out);
+            }
+        }
+    }
+
+    let mut out = SelfCallPositions::default();
+    visit(registry, fun.body_id, 0, true, false, &mut out);
+    out
+}
+
+/// Returns statements that return the value of the expression.
+/// Matches are compiled to `switch` statements instead of
+/// immediately invoked functions.
+/// If `can_continue` is true, the expression is in tail position
+/// of the current fun, so self calls are compiled to loop iterations
+/// (see `RecursionFrame`).
+fn generate_code_for_tail_position(
+    registry: &NodeRegistry,
+    context: &mut Context,
+    id: light::ExpressionId,
+    can_continue: bool,
+) -> Result<Vec<FunctionStatement>, CompileToJavaScriptError> {
+    match registry.expression_ref(id) {
+        ExpressionRef::Check(check) => {
+            generate_code_for_tail_position(registry, context, check.output_id, can_continue)
+        }
+        ExpressionRef::Rewrite(rewrite) => {
+            generate_code_for_tail_position(registry, context, rewrite.body_id, can_continue)
+        }
+        ExpressionRef::Match(match_) => {
+            generate_code_for_match_statements(registry, context, match_, can_continue)
+        }
+        ExpressionRef::Call(call) if can_continue && is_self_call(registry, context, call) => {
+            let loop_args = context
+                .recursion
+                .as_ref()
+                .and_then(|recursion| recursion.loop_args.clone())
+                .expect("A fun with self tail calls should have loop args.");
+            let args = generate_code_for_call_args(registry, context, call)?;
+            let value = if loop_args.is_labeled {
+                args.into_iter()
+                    .next()
+                    .expect("Labeled args should be passed as a single object.")
+            } else {
+                Expression::Array(Box::new(Array { items: args }))
+            };
+            Ok(vec![
+                FunctionStatement::Assign(Assignment {
+                    name: loop_args.js_name,
+                    value,
+                }),
+                FunctionStatement::Continue,
+            ])
+        }
+        _ => Ok(vec![FunctionStatement::Return(
+            generate_code_for_expression(registry, context, id)?,
+        )]),
+    }
+}
+
+pub(super) fn is_self_call(registry: &NodeRegistry, context: &Context, call: &light::Call) -> bool {
+    let Some(recursion) = &context.recursion else {
+        return false;
+    };
+    match registry.expression_ref(call.callee_id) {
+        ExpressionRef::Name(name) => context.index_to_level(name.db_index) == recursion.fun_level,
+        _ => false,
+    }
+}
+
+/// Returns statements that return the value of the match.
+/// The matchee is compared to each case with a `switch` over its tag
+/// (or an `if` chain, if it's a `bigint`).
+/// If no case matches (which can only happen if a JS caller
+/// passed an illegal arg), the statements throw an explosion.
+pub(super) fn generate_code_for_match_statements(
+    registry: &NodeRegistry,
+    context: &mut Context,
+    match_: &light::Match,
+    can_continue: bool,
+) -> Result<Vec<FunctionStatement>, CompileToJavaScriptError> {
+    if context.erasure.is_match_erased(match_.id) {
+        // See `generate_code_for_erased_match`.
+        let case_ids = registry.get_possibly_empty_list(match_.case_list_id);
+        let Some(case_id) = case_ids.first() else {
+            return Ok(vec![FunctionStatement::Return(
+                generate_code_to_throw_explosion(context, match_.span),
+            )]);
+        };
+        let case = registry.get(*case_id);
+        let explicit_arity = case
+            .param_list_id
+            .map(|list_id| list_id.explicit_len())
+            .unwrap_or(0);
+        for _ in 0..explicit_arity {
+            context.try_push_name(ValidJsIdentifierName(UNDEFINED_NAME.to_string()));
+            context.mark_last_as_erased();
+        }
+        let output = generate_code_for_match_case_output_in_tail_position(
+            registry,
+            context,
+            case.output_id,
+            can_continue,
+        );
+        context.pop_n(explicit_arity);
+        return output;
+    }
+    let bigint_type = context.bigint.match_type(match_.id).cloned();
+
+    let matchee_temp_name = context.get_disposable_name();
+    let matchee = generate_code_for_expression(registry, context, match_.matchee_id)?;
+    let mut out = vec![FunctionStatement::Const(ConstStatement {
+        name: matchee_temp_name.clone(),
+        value: matchee,
+    })];
+    let case_ids = registry.get_possibly_empty_list(match_.case_list_id);
+    match bigint_type {
+        Some(bigint_type) => {
+            for case_id in case_ids {
+                let case = registry.get(*case_id);
+                let (condition, mut body) =
+                    generate_code_for_bigint_match_case_condition_and_params(
+                        registry,
+                        context,
+                        case,
+                        &matchee_temp_name,
+                        &bigint_type,
+                    );
+                let param_count = body.len();
+                let output = generate_code_for_match_case_output_in_tail_position(
+                    registry,
+                    context,
+                    case.output_id,
+                    can_continue,
+                );
+                context.pop_n(param_count);
+                body.extend(output?);
+                out.push(FunctionStatement::If(IfStatement { condition, body }));
+            }
+            out.push(FunctionStatement::Return(generate_code_to_throw_explosion(
+                context,
+                match_.span,
+            )));
+        }
+        None => {
+            let mut cases = Vec::with_capacity(case_ids.len());
+            for case_id in case_ids {
+                let case = registry.get(*case_id);
+                let explicit_arity = case
+                    .param_list_id
+                    .map(|list_id| list_id.explicit_len())
+                    .unwrap_or(0);
+                let mut body = generate_code_for_match_case_params(
+                    registry,
+                    context,
+                    case,
+                    &matchee_temp_name,
+                );
+                let output = generate_code_for_match_case_output_in_tail_position(
+                    registry,
+                    context,
+                    case.output_id,
+                    can_continue,
+                );
+                context.pop_n(explicit_arity);
+                body.extend(output?);
+                let case_js_name = registry.get(case.variant_name_id).name.preferred_js_name();
+                cases.push(SwitchCase {
+                    test: Expression::Literal(Literal::String(JsStringLiteral {
+                        unescaped: case_js_name.0,
+                    })),
+                    body,
+                });
+            }
+            out.push(FunctionStatement::Switch(SwitchStatement {
+                discriminant: binary_op(
+                    Expression::Identifier(matchee_temp_name),
+                    BinaryOpKind::Index,
+                    Expression::Literal(Literal::Number(0)),
+                ),
+                cases,
+                default: Some(vec![FunctionStatement::Return(
+                    generate_code_to_throw_explosion(context, match_.span),
+                )]),
+            }));
+        }
+    }
+    Ok(out)
+}
+
+fn generate_code_for_match_case_output_in_tail_position(
+    registry: &NodeRegistry,
+    context: &mut Context,
+    id: MatchCaseOutputId,
+    can_continue: bool,
+) -> Result<Vec<FunctionStatement>, CompileToJavaScriptError> {
+    match id {
+        MatchCaseOutputId::Some(id) => {
+            generate_code_for_tail_position(registry, context, id, can_continue)
+        }
+        MatchCaseOutputId::ImpossibilityClaim(kw_span) => Ok(vec![FunctionStatement::Return(
+            generate_code_to_throw_explosion(context, kw_span),
+        )]),
+    }
+}
+
+/// How a stack-safe fun compiles its self calls
+/// (see `generate_code_for_stack_safe_fun`).
+#[derive(Clone, Debug)]
+pub(super) struct RecursionFrame {
+    /// The level of the fun's name.
+    fun_level: DbLevel,
+    /// This is `Some` if self tail calls are compiled to loop iterations.
+    loop_args: Option<LoopArgs>,
+    /// If true, the fun body is a generator,
+    /// and the remaining self calls are compiled to `yield`s.
+    pub(super) is_generator: bool,
+}
+
+/// The variable that holds the args of the current loop iteration,
+/// in the form they would be passed to the fun
+/// (i.e., an array of the unlabeled args, or an object of the labeled args).
+#[derive(Clone, Debug)]
+struct LoopArgs {
+    js_name: ValidJsIdentifierName,
+    is_labeled: bool,
+}
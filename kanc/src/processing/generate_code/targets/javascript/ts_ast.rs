@@ -0,0 +1,87 @@
+//! The AST of the TypeScript declaration (`.d.ts`) files
+//! we generate alongside the JavaScript files.
+//! We only support the small subset of TypeScript
+//! we need to describe the generated JavaScript.
+
+use super::js_ast::{AliasedName, ImportStatement, JsStringLiteral, ValidJsIdentifierName};
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct DeclarationFile {
+    pub items: Vec<DeclarationItem>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum DeclarationItem {
+    Import(ImportStatement),
+    ImportType(ImportStatement),
+    /// `export { names } from "specifier";`
+    ExportFrom(ImportStatement),
+    ExportType(TypeAlias),
+    ExportConst(ConstDeclaration),
+    Type(TypeAlias),
+    Const(ConstDeclaration),
+    /// `export { names };`
+    Export(Vec<AliasedName>),
+}
+
+/// `type name<generics> = value;`
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct TypeAlias {
+    pub name: ValidJsIdentifierName,
+    pub generics: Vec<ValidJsIdentifierName>,
+    pub value: TsType,
+}
+
+/// `declare const name: type_;`
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct ConstDeclaration {
+    pub name: ValidJsIdentifierName,
+    pub type_: TsType,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum TsType {
+    Unknown,
+    Never,
+    Undefined,
+    String,
+    BigInt,
+    StringLiteral(JsStringLiteral),
+    /// A type alias or generic, possibly with type arguments.
+    Reference(Box<TypeReference>),
+    /// `typeof name`
+    Typeof(ValidJsIdentifierName),
+    Tuple(Vec<TsType>),
+    Object(Vec<ObjectTypeEntry>),
+    Union(Vec<TsType>),
+    Function(Box<FunctionType>),
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct TypeReference {
+    pub name: ValidJsIdentifierName,
+    pub args: Vec<TsType>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct ObjectTypeEntry {
+    pub key: ValidJsIdentifierName,
+    pub is_optional: bool,
+    pub value: TsType,
+}
+
+/// `<generics>(params) => return_type`
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct FunctionType {
+    pub generics: Vec<ValidJsIdentifierName>,
+    pub params: TsParams,
+    pub return_type: TsType,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum TsParams {
+    Standard(Vec<(ValidJsIdentifierName, TsType)>),
+    /// A single object param, which corresponds to
+    /// `js_ast::Params::DestructuredSingleton`.
+    DestructuredSingleton(Vec<ObjectTypeEntry>),
+}
@@ -1,6 +1,7 @@
 use crate::data::{
     text_span::*,
     token::{Token, TokenKind},
+    unsimplified_ast::NumberedTypeName,
 };
 
 #[derive(Clone, Debug)]
@@ -390,6 +391,7 @@ fn get_token_kind_of_special_non_underscore_character(c: char) -> Option<TokenKi
         '@' => Some(TokenKind::At),
         '=' => Some(TokenKind::Equal),
         '-' => Some(TokenKind::Dash),
+        '+' => Some(TokenKind::Plus),
         '?' => Some(TokenKind::Question),
         '/' => Some(TokenKind::Slash),
         '*' => Some(TokenKind::Star),
@@ -411,10 +413,7 @@ fn get_token_kind_of_non_underscore_keyword(s: &str) -> Option<TokenKind> {
         "type" => Some(TokenKind::TypeLowerCase),
         "let" => Some(TokenKind::Let),
         "Type" => Some(TokenKind::TypeTitleCase),
-        "Type0" => Some(TokenKind::Type0),
-        "Type1" => Some(TokenKind::Type1),
-        "Type2" => Some(TokenKind::Type2),
-        "Type3" => Some(TokenKind::Type3),
+        _ if NumberedTypeName::new(s).is_some() => Some(TokenKind::NumberedType),
         "fun" => Some(TokenKind::Fun),
         "match" => Some(TokenKind::Match),
         "forall" => Some(TokenKind::Forall),
@@ -422,10 +421,13 @@ fn get_token_kind_of_non_underscore_keyword(s: &str) -> Option<TokenKind> {
         "goal" => Some(TokenKind::Goal),
         "impossible" => Some(TokenKind::Impossible),
         "todo" => Some(TokenKind::Todo),
+        "rewrite" => Some(TokenKind::Rewrite),
 
         "struct" => Some(TokenKind::Struct),
-        "var" => Some(TokenKind::Var),
         "trait" => Some(TokenKind::Trait),
+        "instance" => Some(TokenKind::Instance),
+        "derive" => Some(TokenKind::Derive),
+        "var" => Some(TokenKind::Var),
 
         "pub" => Some(TokenKind::Pub),
         "prot" => Some(TokenKind::Prot),
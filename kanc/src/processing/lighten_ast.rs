@@ -54,6 +54,8 @@ pub fn register_type_statement(
         name_id,
         param_list_id,
         variant_list_id,
+        is_struct: unregistered.is_struct,
+        is_trait: unregistered.is_trait,
     })
 }
 
@@ -104,6 +106,7 @@ pub fn register_unlabeled_param(
     registry.add_and_overwrite_id(UnlabeledParam {
         id: dummy_id(),
         span: unregistered.span,
+        is_instance: unregistered.is_instance,
         is_dashed: unregistered.is_dashed,
         name_id,
         type_id,
@@ -117,13 +120,18 @@ pub fn register_labeled_param(
     let label_id = register_param_label(registry, unregistered.label);
     let name_id = register_identifier(registry, unregistered.name);
     let type_id = register_expression(registry, unregistered.type_);
+    let default_id = unregistered
+        .default
+        .map(|default| register_expression(registry, default));
     registry.add_and_overwrite_id(LabeledParam {
         id: dummy_id(),
         span: unregistered.span,
+        is_instance: unregistered.is_instance,
         label_id,
         is_dashed: unregistered.is_dashed,
         name_id,
         type_id,
+        default_id,
     })
 }
 
@@ -168,6 +176,7 @@ pub fn register_let_statement(
         transparency: unregistered.transparency,
         name_id,
         value_id,
+        is_instance: unregistered.is_instance,
     })
 }
 
@@ -204,6 +213,22 @@ pub fn register_expression(
             let id = register_check(registry, *unregistered);
             ExpressionId::Check(id)
         }
+        heavy::Expression::Rewrite(unregistered) => {
+            let id = register_rewrite(registry, *unregistered);
+            ExpressionId::Rewrite(id)
+        }
+        heavy::Expression::Projection(unregistered) => {
+            let id = register_projection(registry, *unregistered);
+            ExpressionId::Projection(id)
+        }
+        heavy::Expression::Exists(unregistered) => {
+            let id = register_exists(registry, *unregistered);
+            ExpressionId::Exists(id)
+        }
+        heavy::Expression::Pair(unregistered) => {
+            let id = register_pair(registry, *unregistered);
+            ExpressionId::Pair(id)
+        }
     }
 }
 
@@ -298,6 +323,9 @@ pub fn register_fun(registry: &mut NodeRegistry, unregistered: heavy::Fun) -> No
 
 pub fn register_match(registry: &mut NodeRegistry, unregistered: heavy::Match) -> NodeId<Match> {
     let matchee_id = register_expression(registry, unregistered.matchee);
+    let motive_id = unregistered
+        .motive
+        .map(|unregistered| register_match_motive(registry, unregistered));
     let case_ids: Vec<_> = unregistered
         .cases
         .into_iter()
@@ -308,10 +336,31 @@ pub fn register_match(registry: &mut NodeRegistry, unregistered: heavy::Match) -
         id: dummy_id(),
         span: unregistered.span,
         matchee_id,
+        motive_id,
         case_list_id,
     })
 }
 
+pub fn register_match_motive(
+    registry: &mut NodeRegistry,
+    unregistered: heavy::MatchMotive,
+) -> NodeId<MatchMotive> {
+    let matchee_name_id = register_identifier(registry, unregistered.matchee_name);
+    let matchee_type_name_id = register_name_expression(registry, unregistered.matchee_type_name);
+    let index_name_list_id = unregistered
+        .index_names
+        .map(|unregistered| register_identifiers(registry, unregistered));
+    let output_id = register_expression(registry, unregistered.output);
+    registry.add_and_overwrite_id(MatchMotive {
+        id: dummy_id(),
+        span: unregistered.span,
+        matchee_name_id,
+        matchee_type_name_id,
+        index_name_list_id,
+        output_id,
+    })
+}
+
 pub fn register_match_case(
     registry: &mut NodeRegistry,
     unregistered: heavy::MatchCase,
@@ -432,6 +481,60 @@ pub fn register_check(registry: &mut NodeRegistry, unregistered: heavy::Check) -
     })
 }
 
+pub fn register_rewrite(
+    registry: &mut NodeRegistry,
+    unregistered: heavy::Rewrite,
+) -> NodeId<Rewrite> {
+    let eq_type_name_id = register_name_expression(registry, unregistered.eq_type_name);
+    let eq_refl_name_id = register_name_expression(registry, unregistered.eq_refl_name);
+    let proof_id = register_expression(registry, unregistered.proof);
+    let body_id = register_expression(registry, unregistered.body);
+    registry.add_and_overwrite_id(Rewrite {
+        id: dummy_id(),
+        span: unregistered.span,
+        eq_type_name_id,
+        eq_refl_name_id,
+        proof_id,
+        body_id,
+    })
+}
+
+pub fn register_projection(
+    registry: &mut NodeRegistry,
+    unregistered: heavy::Projection,
+) -> NodeId<Projection> {
+    let record_id = register_expression(registry, unregistered.record);
+    let field_name_id = register_identifier(registry, unregistered.field_name);
+    registry.add_and_overwrite_id(Projection {
+        id: dummy_id(),
+        span: unregistered.span,
+        record_id,
+        field_name_id,
+    })
+}
+
+pub fn register_exists(registry: &mut NodeRegistry, unregistered: heavy::Exists) -> NodeId<Exists> {
+    let param_id = register_unlabeled_param(registry, unregistered.param);
+    let output_id = register_expression(registry, unregistered.output);
+    registry.add_and_overwrite_id(Exists {
+        id: dummy_id(),
+        span: unregistered.span,
+        param_id,
+        output_id,
+    })
+}
+
+pub fn register_pair(registry: &mut NodeRegistry, unregistered: heavy::Pair) -> NodeId<Pair> {
+    let witness_id = register_expression(registry, unregistered.witness);
+    let proof_id = register_expression(registry, unregistered.proof);
+    registry.add_and_overwrite_id(Pair {
+        id: dummy_id(),
+        span: unregistered.span,
+        witness_id,
+        proof_id,
+    })
+}
+
 pub fn register_check_assertion(
     registry: &mut NodeRegistry,
     unregistered: heavy::CheckAssertion,
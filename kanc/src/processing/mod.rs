@@ -1,4 +1,5 @@
 pub mod bind_type_independent;
+pub mod find_unreachable_items;
 pub mod format_unsimplified;
 pub mod generate_code;
 pub mod lex;
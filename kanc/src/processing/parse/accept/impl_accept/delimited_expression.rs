@@ -6,6 +6,7 @@ impl Accept for UnfinishedDelimitedExpression {
             UnfinishedDelimitedExpression::Empty => match item {
                 FinishedStackItem::Token(token) => match token.kind {
                     TokenKind::TypeTitleCase
+                    | TokenKind::NumberedType
                     | TokenKind::Underscore
                     | TokenKind::Mod
                     | TokenKind::Super
@@ -42,6 +43,21 @@ impl Accept for UnfinishedDelimitedExpression {
                     TokenKind::Check => AcceptResult::Push(UnfinishedStackItem::Check(
                         UnfinishedCheck::Keyword(token),
                     )),
+                    TokenKind::Rewrite => AcceptResult::Push2(
+                        UnfinishedStackItem::Rewrite(UnfinishedRewrite::Keyword(token)),
+                        UnfinishedStackItem::UnfinishedDelimitedExpression(
+                            UnfinishedDelimitedExpression::Empty,
+                        ),
+                    ),
+                    TokenKind::Exists => AcceptResult::Push(UnfinishedStackItem::Exists(
+                        UnfinishedExists::Keyword(token),
+                    )),
+                    TokenKind::LParen => AcceptResult::Push2(
+                        UnfinishedStackItem::Pair(UnfinishedPair::LParen(token)),
+                        UnfinishedStackItem::UnfinishedDelimitedExpression(
+                            UnfinishedDelimitedExpression::Empty,
+                        ),
+                    ),
                     _other_token_kind => AcceptResult::Error(ParseError::unexpected_token(token)),
                 },
                 FinishedStackItem::DelimitedExpression(first_token, expression, end_delimiter) => {
@@ -99,6 +115,15 @@ impl Accept for UnfinishedDelimitedExpression {
                                     ),
                                 )
                             }
+                            operator_token_kind if is_operator_token_kind(operator_token_kind) => {
+                                *self = UnfinishedDelimitedExpression::InfixOperator {
+                                    first_token: first_token.clone(),
+                                    first_operand: expression.clone(),
+                                    rest: vec![],
+                                    operator_tokens: NonEmptyVec::singleton(token),
+                                };
+                                AcceptResult::ContinueToNextToken
+                            }
                             _other_token_kind => {
                                 AcceptResult::Error(ParseError::unexpected_token(token))
                             }
@@ -107,6 +132,80 @@ impl Accept for UnfinishedDelimitedExpression {
                     other_item => wrapped_unexpected_finished_item_err(&other_item),
                 }
             }
+            UnfinishedDelimitedExpression::InfixOperator {
+                first_token,
+                first_operand,
+                rest,
+                operator_tokens,
+            } => match item {
+                FinishedStackItem::Token(token)
+                    if is_operator_token_kind(token.kind)
+                        && are_tokens_adjacent(operator_tokens.last(), &token) =>
+                {
+                    operator_tokens.push(token);
+                    AcceptResult::ContinueToNextToken
+                }
+                FinishedStackItem::Token(token) => {
+                    let operator = operator_identifier(file_id, operator_tokens);
+                    *self = UnfinishedDelimitedExpression::InfixOperand {
+                        first_token: first_token.clone(),
+                        first_operand: first_operand.clone(),
+                        rest: std::mem::take(rest),
+                        operator,
+                    };
+                    AcceptResult::PushAndContinueReducingWithNewTop(
+                        UnfinishedStackItem::UnfinishedDelimitedExpression(
+                            UnfinishedDelimitedExpression::Empty,
+                        ),
+                        FinishedStackItem::Token(token),
+                    )
+                }
+                other_item => wrapped_unexpected_finished_item_err(&other_item),
+            },
+            UnfinishedDelimitedExpression::InfixOperand {
+                first_token,
+                first_operand,
+                rest,
+                operator,
+            } => match item {
+                FinishedStackItem::DelimitedExpression(_, operand, end_delimiter) => {
+                    let mut rest = std::mem::take(rest);
+                    match operand {
+                        // The operand's own chain holds the rest of this chain
+                        // (e.g., in `a + b * c`, the operand `b * c`),
+                        // so we flatten it into this chain.
+                        // Parenthesized chains are wrapped in `Expression::Parenthesized`,
+                        // so they are never flattened.
+                        Expression::InfixChain(operand_chain) => {
+                            let operand_chain = *operand_chain;
+                            rest.push(InfixChainLink {
+                                operator: operator.clone(),
+                                operand: operand_chain.first_operand,
+                            });
+                            rest.extend(operand_chain.rest);
+                        }
+                        operand => rest.push(InfixChainLink {
+                            operator: operator.clone(),
+                            operand,
+                        }),
+                    }
+                    let rest = NonEmptyVec::try_from(rest)
+                        .expect("rest should be non-empty, since we just pushed a link.");
+                    let span = first_operand
+                        .span()
+                        .inclusive_merge(rest.last().operand.span());
+                    AcceptResult::PopAndContinueReducing(FinishedStackItem::DelimitedExpression(
+                        first_token.clone(),
+                        Expression::InfixChain(Box::new(InfixChain {
+                            span,
+                            first_operand: first_operand.clone(),
+                            rest,
+                        })),
+                        end_delimiter,
+                    ))
+                }
+                other_item => wrapped_unexpected_finished_item_err(&other_item),
+            },
         }
     }
 }
@@ -0,0 +1,59 @@
+use super::*;
+
+impl Accept for UnfinishedExists {
+    fn accept(&mut self, item: FinishedStackItem, file_id: FileId) -> AcceptResult {
+        match self {
+            UnfinishedExists::Keyword(exists_kw) => match item {
+                FinishedStackItem::Token(token) => match token.kind {
+                    TokenKind::LParen => {
+                        AcceptResult::Push(UnfinishedStackItem::Params(UnfinishedParams {
+                            first_token: token.clone(),
+                            maximum_dashed_params_allowed: 0,
+                            pending_tilde: None,
+                            pending_dash: None,
+                            params: vec![],
+                        }))
+                    }
+                    _other_token_kind => AcceptResult::Error(ParseError::unexpected_token(token)),
+                },
+                FinishedStackItem::Params(_, params) => {
+                    *self = UnfinishedExists::Params(exists_kw.clone(), params);
+                    AcceptResult::ContinueToNextToken
+                }
+                other_item => wrapped_unexpected_finished_item_err(&other_item),
+            },
+            UnfinishedExists::Params(exists_kw, params) => match item {
+                FinishedStackItem::Token(token) => match token.kind {
+                    TokenKind::LCurly => {
+                        AcceptResult::Push(UnfinishedStackItem::UnfinishedDelimitedExpression(
+                            UnfinishedDelimitedExpression::Empty,
+                        ))
+                    }
+                    _other_token_kind => AcceptResult::Error(ParseError::unexpected_token(token)),
+                },
+                FinishedStackItem::DelimitedExpression(_, expression, end_delimiter) => {
+                    match end_delimiter.raw().kind {
+                        TokenKind::RCurly => AcceptResult::PopAndContinueReducing(
+                            FinishedStackItem::UndelimitedExpression(
+                                exists_kw.clone(),
+                                Expression::Exists(Box::new(Exists {
+                                    span: span_range_including_end(
+                                        file_id,
+                                        exists_kw,
+                                        end_delimiter.raw(),
+                                    ),
+                                    params: params.clone(),
+                                    output: expression,
+                                })),
+                            ),
+                        ),
+                        _other_end_delimiter => AcceptResult::Error(ParseError::unexpected_token(
+                            end_delimiter.into_raw(),
+                        )),
+                    }
+                }
+                other_item => wrapped_unexpected_finished_item_err(&other_item),
+            },
+        }
+    }
+}
@@ -59,7 +59,23 @@ impl Accept for UnfinishedFile {
                         },
                     ))
                 }
-                TokenKind::Let => {
+                TokenKind::Struct | TokenKind::Trait => {
+                    let is_trait = token.kind == TokenKind::Trait;
+                    let visibility = self
+                        .pending_visibility
+                        .take()
+                        .map(|visibility| visibility.finalize(file_id));
+                    let first_token = visibility.as_ref().map(get_pub_kw_token).unwrap_or(token);
+                    AcceptResult::Push(UnfinishedStackItem::Struct(
+                        UnfinishedStructStatement::Keyword {
+                            first_token,
+                            visibility,
+                            is_trait,
+                        },
+                    ))
+                }
+                TokenKind::Let | TokenKind::Instance => {
+                    let is_instance = token.kind == TokenKind::Instance;
                     let visibility = self
                         .pending_visibility
                         .take()
@@ -68,8 +84,22 @@ impl Accept for UnfinishedFile {
                     AcceptResult::Push(UnfinishedStackItem::Let(UnfinishedLetStatement::Keyword {
                         first_token,
                         visibility,
+                        is_instance,
                     }))
                 }
+                TokenKind::Notation => {
+                    let visibility = self
+                        .pending_visibility
+                        .take()
+                        .map(|visibility| visibility.finalize(file_id));
+                    let first_token = visibility.as_ref().map(get_pub_kw_token).unwrap_or(token);
+                    AcceptResult::Push(UnfinishedStackItem::Notation(
+                        UnfinishedNotationStatement::Keyword {
+                            first_token,
+                            visibility,
+                        },
+                    ))
+                }
                 TokenKind::Eoi => {
                     let file_span = {
                         let first_span = self.items.first().map(|item| item.span()).unwrap_or_else(
@@ -131,10 +161,18 @@ impl Accept for UnfinishedFile {
                 self.items.push(FileItem::Type(type_));
                 AcceptResult::ContinueToNextToken
             }
+            FinishedStackItem::Struct(_, struct_) => {
+                self.items.push(FileItem::Struct(struct_));
+                AcceptResult::ContinueToNextToken
+            }
             FinishedStackItem::Let(_, let_) => {
                 self.items.push(FileItem::Let(let_));
                 AcceptResult::ContinueToNextToken
             }
+            FinishedStackItem::Notation(_, notation) => {
+                self.items.push(FileItem::Notation(notation));
+                AcceptResult::ContinueToNextToken
+            }
             other_item => wrapped_unexpected_finished_item_err(&other_item),
         }
     }
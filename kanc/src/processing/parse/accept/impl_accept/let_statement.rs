@@ -12,8 +12,9 @@ impl Accept for UnfinishedLetStatement {
                         };
                         AcceptResult::ContinueToNextToken
                     }
-                    TokenKind::Let => {
+                    TokenKind::Let | TokenKind::Instance => {
                         *self = UnfinishedLetStatement::Keyword {
+                            is_instance: token.kind == TokenKind::Instance,
                             first_token: token,
                             visibility: None,
                         };
@@ -41,10 +42,11 @@ impl Accept for UnfinishedLetStatement {
                             AcceptResult::Error(ParseError::unexpected_token(token))
                         }
                     }
-                    TokenKind::Let => {
+                    TokenKind::Let | TokenKind::Instance => {
                         *self = UnfinishedLetStatement::Keyword {
                             first_token: first_token.clone(),
                             visibility: Some(visibility.clone().finalize(file_id)),
+                            is_instance: token.kind == TokenKind::Instance,
                         };
                         AcceptResult::ContinueToNextToken
                     }
@@ -75,6 +77,7 @@ impl Accept for UnfinishedLetStatement {
             UnfinishedLetStatement::Keyword {
                 first_token,
                 visibility,
+                is_instance,
             } => match item {
                 FinishedStackItem::Token(token) => match token.kind {
                     TokenKind::LParen => AcceptResult::PushAndContinueReducingWithNewTop(
@@ -93,6 +96,7 @@ impl Accept for UnfinishedLetStatement {
                             visibility: visibility.clone(),
                             transparency: None,
                             name,
+                            is_instance: *is_instance,
                         };
                         AcceptResult::ContinueToNextToken
                     }
@@ -103,6 +107,7 @@ impl Accept for UnfinishedLetStatement {
                         first_token: first_token.clone(),
                         visibility: visibility.clone(),
                         transparency,
+                        is_instance: *is_instance,
                     };
                     AcceptResult::ContinueToNextToken
                 }
@@ -113,6 +118,7 @@ impl Accept for UnfinishedLetStatement {
                 first_token,
                 visibility,
                 transparency,
+                is_instance,
             } => match item {
                 FinishedStackItem::Token(token) => match token.kind {
                     TokenKind::StandardIdentifier => {
@@ -125,6 +131,7 @@ impl Accept for UnfinishedLetStatement {
                             visibility: visibility.clone(),
                             transparency: Some(transparency.clone()),
                             name,
+                            is_instance: *is_instance,
                         };
                         AcceptResult::ContinueToNextToken
                     }
@@ -138,6 +145,7 @@ impl Accept for UnfinishedLetStatement {
                 visibility,
                 transparency,
                 name,
+                is_instance,
             } => match item {
                 FinishedStackItem::Token(token) => match token.kind {
                     TokenKind::Equal => {
@@ -162,6 +170,7 @@ impl Accept for UnfinishedLetStatement {
                                     transparency: transparency.clone(),
                                     name: name.clone(),
                                     value: expression,
+                                    is_instance: *is_instance,
                                 },
                             ))
                         }
@@ -7,7 +7,101 @@ impl Accept for UnfinishedMatch {
                 FinishedStackItem::DelimitedExpression(_, expression, end_delimiter) => {
                     match end_delimiter.raw().kind {
                         TokenKind::LCurly => {
-                            *self = UnfinishedMatch::Cases(match_kw.clone(), expression, vec![]);
+                            *self =
+                                UnfinishedMatch::Cases(match_kw.clone(), expression, None, vec![]);
+                            AcceptResult::ContinueToNextToken
+                        }
+                        TokenKind::As => {
+                            *self = UnfinishedMatch::MotiveAsKeyword(
+                                match_kw.clone(),
+                                expression,
+                                end_delimiter.into_raw(),
+                            );
+                            AcceptResult::ContinueToNextToken
+                        }
+                        _other_end_delimiter => AcceptResult::Error(ParseError::unexpected_token(
+                            end_delimiter.into_raw(),
+                        )),
+                    }
+                }
+                other_item => wrapped_unexpected_finished_item_err(&other_item),
+            },
+            UnfinishedMatch::MotiveAsKeyword(match_kw, matchee, as_kw) => match item {
+                FinishedStackItem::Token(token) => match token.kind {
+                    TokenKind::StandardIdentifier | TokenKind::Underscore => {
+                        *self = UnfinishedMatch::MotiveMatcheeName(
+                            match_kw.clone(),
+                            matchee.clone(),
+                            as_kw.clone(),
+                            Identifier {
+                                span: span_single(file_id, &token),
+                                name: IdentifierName::new(token.content),
+                            },
+                        );
+                        AcceptResult::ContinueToNextToken
+                    }
+                    _other_token_kind => AcceptResult::Error(ParseError::unexpected_token(token)),
+                },
+                other_item => wrapped_unexpected_finished_item_err(&other_item),
+            },
+            UnfinishedMatch::MotiveMatcheeName(match_kw, matchee, as_kw, matchee_name) => {
+                match item {
+                    FinishedStackItem::Token(token) => match token.kind {
+                        TokenKind::Colon => {
+                            AcceptResult::Push(UnfinishedStackItem::UnfinishedDelimitedExpression(
+                                UnfinishedDelimitedExpression::Empty,
+                            ))
+                        }
+                        _other_token_kind => {
+                            AcceptResult::Error(ParseError::unexpected_token(token))
+                        }
+                    },
+                    FinishedStackItem::DelimitedExpression(_, expression, end_delimiter) => {
+                        match end_delimiter.raw().kind {
+                            TokenKind::FatArrow => {
+                                *self = UnfinishedMatch::MotiveMatcheeType(
+                                    match_kw.clone(),
+                                    matchee.clone(),
+                                    as_kw.clone(),
+                                    matchee_name.clone(),
+                                    expression,
+                                );
+                                AcceptResult::Push(
+                                    UnfinishedStackItem::UnfinishedDelimitedExpression(
+                                        UnfinishedDelimitedExpression::Empty,
+                                    ),
+                                )
+                            }
+                            _other_end_delimiter => AcceptResult::Error(
+                                ParseError::unexpected_token(end_delimiter.into_raw()),
+                            ),
+                        }
+                    }
+                    other_item => wrapped_unexpected_finished_item_err(&other_item),
+                }
+            }
+            UnfinishedMatch::MotiveMatcheeType(
+                match_kw,
+                matchee,
+                as_kw,
+                matchee_name,
+                matchee_type,
+            ) => match item {
+                FinishedStackItem::DelimitedExpression(_, expression, end_delimiter) => {
+                    match end_delimiter.raw().kind {
+                        TokenKind::LCurly => {
+                            let motive = MatchMotive {
+                                span: span_range_excluding_end(file_id, as_kw, end_delimiter.raw()),
+                                matchee_name: matchee_name.clone(),
+                                matchee_type: matchee_type.clone(),
+                                output: expression,
+                            };
+                            *self = UnfinishedMatch::Cases(
+                                match_kw.clone(),
+                                matchee.clone(),
+                                Some(motive),
+                                vec![],
+                            );
                             AcceptResult::ContinueToNextToken
                         }
                         _other_end_delimiter => AcceptResult::Error(ParseError::unexpected_token(
@@ -17,7 +111,7 @@ impl Accept for UnfinishedMatch {
                 }
                 other_item => wrapped_unexpected_finished_item_err(&other_item),
             },
-            UnfinishedMatch::Cases(match_kw, matchee, cases) => match item {
+            UnfinishedMatch::Cases(match_kw, matchee, motive, cases) => match item {
                 FinishedStackItem::Token(token) => match token.kind {
                     TokenKind::StandardIdentifier => {
                         AcceptResult::Push(UnfinishedStackItem::MatchCase(
@@ -27,12 +121,24 @@ impl Accept for UnfinishedMatch {
                             }),
                         ))
                     }
+                    // A pair pattern (e.g., `(w, p) => ...`) is treated
+                    // as a case for a variant named `pair`.
+                    TokenKind::LParen => AcceptResult::Push(UnfinishedStackItem::MatchCase(
+                        UnfinishedMatchCase::ParamsInProgress(
+                            Identifier {
+                                span: span_single(file_id, &token),
+                                name: IdentifierName::new(PAIR_VARIANT_NAME.to_string()),
+                            },
+                            vec![],
+                        ),
+                    )),
                     TokenKind::RCurly => AcceptResult::PopAndContinueReducing(
                         FinishedStackItem::UndelimitedExpression(
                             match_kw.clone(),
                             Expression::Match(Box::new(Match {
                                 span: span_range_including_end(file_id, &match_kw, &token),
                                 matchee: matchee.clone(),
+                                motive: motive.clone(),
                                 cases: cases.clone(),
                             })),
                         ),
@@ -53,6 +159,7 @@ impl Accept for UnfinishedMatch {
                                         end_delimiter.raw(),
                                     ),
                                     matchee: matchee.clone(),
+                                    motive: motive.clone(),
                                     cases: cases.clone(),
                                 })),
                             ),
@@ -10,6 +10,7 @@ mod delimited_goal_kw_or_expression;
 mod delimited_impossible_kw_or_expression;
 mod delimited_question_mark_or_expression;
 mod dot;
+mod exists;
 mod file;
 mod forall;
 mod fun;
@@ -18,9 +19,14 @@ mod match_;
 mod match_case;
 mod match_case_param;
 mod mod_statement;
+mod notation_statement;
+mod pair;
 mod param;
 mod params;
 mod parenthesized_mod_scope_modifier;
+mod parenthesized_operator;
+mod rewrite;
+mod struct_statement;
 mod triple_dot;
 mod type_statement;
 mod use_statement;
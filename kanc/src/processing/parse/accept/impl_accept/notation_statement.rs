@@ -0,0 +1,223 @@
+use super::*;
+
+impl Accept for UnfinishedNotationStatement {
+    fn accept(&mut self, item: FinishedStackItem, file_id: FileId) -> AcceptResult {
+        match self {
+            UnfinishedNotationStatement::Empty => match item {
+                FinishedStackItem::Token(token) => match token.kind {
+                    TokenKind::Pub => {
+                        *self = UnfinishedNotationStatement::ExplicitVisibility {
+                            first_token: token.clone(),
+                            visibility: PendingPubClause::PubKw(token),
+                        };
+                        AcceptResult::ContinueToNextToken
+                    }
+                    TokenKind::Notation => {
+                        *self = UnfinishedNotationStatement::Keyword {
+                            first_token: token,
+                            visibility: None,
+                        };
+                        AcceptResult::ContinueToNextToken
+                    }
+                    _other_token_kind => AcceptResult::Error(ParseError::unexpected_token(token)),
+                },
+                other_item => wrapped_unexpected_finished_item_err(&other_item),
+            },
+
+            UnfinishedNotationStatement::ExplicitVisibility {
+                first_token,
+                visibility,
+            } => match item {
+                FinishedStackItem::Token(token) => match token.kind {
+                    TokenKind::LParen => {
+                        if let PendingPubClause::PubKw(_) = visibility {
+                            AcceptResult::PushAndContinueReducingWithNewTop(
+                                UnfinishedStackItem::ParenthesizedModScopeModifier(
+                                    UnfinishedParenthesizedModScopeModifier::Empty,
+                                ),
+                                FinishedStackItem::Token(token),
+                            )
+                        } else {
+                            AcceptResult::Error(ParseError::unexpected_token(token))
+                        }
+                    }
+                    TokenKind::Notation => {
+                        *self = UnfinishedNotationStatement::Keyword {
+                            first_token: first_token.clone(),
+                            visibility: Some(visibility.clone().finalize(file_id)),
+                        };
+                        AcceptResult::ContinueToNextToken
+                    }
+                    _other_token_kind => AcceptResult::Error(ParseError::unexpected_token(token)),
+                },
+                FinishedStackItem::ParenthesizedModScopeModifier(
+                    parenthesized_mod_scope_modifier_first_token,
+                    modifier,
+                ) => {
+                    if let PendingPubClause::PubKw(pub_kw_token) = visibility {
+                        *visibility = PendingPubClause::Finished(PubClause {
+                            span: span_single(file_id, pub_kw_token).inclusive_merge(modifier.span),
+                            scope_modifier: Some(modifier),
+                        });
+                        AcceptResult::ContinueToNextToken
+                    } else {
+                        wrapped_unexpected_finished_item_err(
+                            &FinishedStackItem::ParenthesizedModScopeModifier(
+                                parenthesized_mod_scope_modifier_first_token,
+                                modifier,
+                            ),
+                        )
+                    }
+                }
+                other_item => wrapped_unexpected_finished_item_err(&other_item),
+            },
+
+            UnfinishedNotationStatement::Keyword {
+                first_token,
+                visibility,
+            } => match item {
+                FinishedStackItem::Token(token) if token.kind == TokenKind::LParen => {
+                    AcceptResult::PushAndContinueReducingWithNewTop(
+                        UnfinishedStackItem::ParenthesizedOperator(
+                            UnfinishedParenthesizedOperator::Empty,
+                        ),
+                        FinishedStackItem::Token(token),
+                    )
+                }
+                FinishedStackItem::ParenthesizedOperator(_, operator) => {
+                    *self = UnfinishedNotationStatement::Operator {
+                        first_token: first_token.clone(),
+                        visibility: visibility.clone(),
+                        operator,
+                    };
+                    AcceptResult::ContinueToNextToken
+                }
+                other_item => wrapped_unexpected_finished_item_err(&other_item),
+            },
+
+            UnfinishedNotationStatement::Operator {
+                first_token,
+                visibility,
+                operator,
+            } => match item {
+                FinishedStackItem::Token(token) if token.kind == TokenKind::Equal => {
+                    *self = UnfinishedNotationStatement::Equal {
+                        first_token: first_token.clone(),
+                        visibility: visibility.clone(),
+                        operator: operator.clone(),
+                    };
+                    AcceptResult::Push(UnfinishedStackItem::UnfinishedDelimitedExpression(
+                        UnfinishedDelimitedExpression::Empty,
+                    ))
+                }
+                other_item => wrapped_unexpected_finished_item_err(&other_item),
+            },
+
+            UnfinishedNotationStatement::Equal {
+                first_token,
+                visibility,
+                operator,
+            } => match item {
+                FinishedStackItem::DelimitedExpression(_, function, end_delimiter) => {
+                    match end_delimiter.raw().kind {
+                        TokenKind::Comma => {
+                            *self = UnfinishedNotationStatement::Function {
+                                first_token: first_token.clone(),
+                                visibility: visibility.clone(),
+                                operator: operator.clone(),
+                                function,
+                            };
+                            AcceptResult::ContinueToNextToken
+                        }
+                        _other_end_delimiter => AcceptResult::Error(ParseError::unexpected_token(
+                            end_delimiter.into_raw(),
+                        )),
+                    }
+                }
+                other_item => wrapped_unexpected_finished_item_err(&other_item),
+            },
+
+            UnfinishedNotationStatement::Function {
+                first_token,
+                visibility,
+                operator,
+                function,
+            } => match item {
+                FinishedStackItem::Token(token) if token.kind == TokenKind::StandardIdentifier => {
+                    let precedence = Identifier {
+                        span: span_single(file_id, &token),
+                        name: IdentifierName::new(token.content),
+                    };
+                    *self = UnfinishedNotationStatement::Precedence {
+                        first_token: first_token.clone(),
+                        visibility: visibility.clone(),
+                        operator: operator.clone(),
+                        function: function.clone(),
+                        precedence,
+                        has_trailing_comma: false,
+                    };
+                    AcceptResult::ContinueToNextToken
+                }
+                other_item => wrapped_unexpected_finished_item_err(&other_item),
+            },
+
+            UnfinishedNotationStatement::Precedence {
+                first_token,
+                visibility,
+                operator,
+                function,
+                precedence,
+                has_trailing_comma,
+            } => match item {
+                FinishedStackItem::Token(token)
+                    if token.kind == TokenKind::Comma && !*has_trailing_comma =>
+                {
+                    *has_trailing_comma = true;
+                    AcceptResult::ContinueToNextToken
+                }
+                FinishedStackItem::Token(token)
+                    if token.kind == TokenKind::StandardIdentifier && *has_trailing_comma =>
+                {
+                    let associativity = Identifier {
+                        span: span_single(file_id, &token),
+                        name: IdentifierName::new(token.content),
+                    };
+                    *self = UnfinishedNotationStatement::Associativity {
+                        first_token: first_token.clone(),
+                        visibility: visibility.clone(),
+                        operator: operator.clone(),
+                        function: function.clone(),
+                        precedence: precedence.clone(),
+                        associativity,
+                    };
+                    AcceptResult::ContinueToNextToken
+                }
+                other_item => wrapped_unexpected_finished_item_err(&other_item),
+            },
+
+            UnfinishedNotationStatement::Associativity {
+                first_token,
+                visibility,
+                operator,
+                function,
+                precedence,
+                associativity,
+            } => match item {
+                FinishedStackItem::Token(token) if token.kind == TokenKind::Semicolon => {
+                    AcceptResult::PopAndContinueReducing(FinishedStackItem::Notation(
+                        first_token.clone(),
+                        NotationStatement {
+                            span: span_range_including_end(file_id, first_token, &token),
+                            visibility: visibility.clone(),
+                            operator: operator.clone(),
+                            function: function.clone(),
+                            precedence: precedence.clone(),
+                            associativity: associativity.clone(),
+                        },
+                    ))
+                }
+                other_item => wrapped_unexpected_finished_item_err(&other_item),
+            },
+        }
+    }
+}
@@ -0,0 +1,61 @@
+use super::*;
+
+impl Accept for UnfinishedPair {
+    fn accept(&mut self, item: FinishedStackItem, file_id: FileId) -> AcceptResult {
+        match self {
+            UnfinishedPair::LParen(lparen) => match item {
+                FinishedStackItem::DelimitedExpression(_, witness, end_delimiter) => {
+                    match end_delimiter.raw().kind {
+                        TokenKind::Comma => {
+                            *self = UnfinishedPair::Witness(lparen.clone(), witness);
+                            AcceptResult::Push(UnfinishedStackItem::UnfinishedDelimitedExpression(
+                                UnfinishedDelimitedExpression::Empty,
+                            ))
+                        }
+                        TokenKind::RParen => AcceptResult::PopAndContinueReducing(
+                            FinishedStackItem::UndelimitedExpression(
+                                lparen.clone(),
+                                Expression::Parenthesized(Box::new(Parenthesized {
+                                    span: span_range_including_end(
+                                        file_id,
+                                        lparen,
+                                        end_delimiter.raw(),
+                                    ),
+                                    expression: witness,
+                                })),
+                            ),
+                        ),
+                        _other_end_delimiter => AcceptResult::Error(ParseError::unexpected_token(
+                            end_delimiter.into_raw(),
+                        )),
+                    }
+                }
+                other_item => wrapped_unexpected_finished_item_err(&other_item),
+            },
+            UnfinishedPair::Witness(lparen, witness) => match item {
+                FinishedStackItem::DelimitedExpression(_, proof, end_delimiter) => {
+                    match end_delimiter.raw().kind {
+                        TokenKind::RParen => AcceptResult::PopAndContinueReducing(
+                            FinishedStackItem::UndelimitedExpression(
+                                lparen.clone(),
+                                Expression::Pair(Box::new(Pair {
+                                    span: span_range_including_end(
+                                        file_id,
+                                        lparen,
+                                        end_delimiter.raw(),
+                                    ),
+                                    witness: witness.clone(),
+                                    proof,
+                                })),
+                            ),
+                        ),
+                        _other_end_delimiter => AcceptResult::Error(ParseError::unexpected_token(
+                            end_delimiter.into_raw(),
+                        )),
+                    }
+                }
+                other_item => wrapped_unexpected_finished_item_err(&other_item),
+            },
+        }
+    }
+}
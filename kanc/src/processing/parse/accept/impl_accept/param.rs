@@ -4,12 +4,25 @@ impl Accept for UnfinishedParam {
     fn accept(&mut self, item: FinishedStackItem, file_id: FileId) -> AcceptResult {
         match self {
             UnfinishedParam::NoIdentifier {
+                pending_instance,
                 pending_tilde,
                 pending_dash,
                 is_dash_allowed,
             } => {
                 match item {
                     FinishedStackItem::Token(token) => match token.kind {
+                        TokenKind::Instance => {
+                            // The `instance` keyword must come first.
+                            if pending_instance.is_some()
+                                || pending_tilde.is_some()
+                                || pending_dash.is_some()
+                            {
+                                AcceptResult::Error(ParseError::unexpected_token(token))
+                            } else {
+                                *pending_instance = Some(token);
+                                AcceptResult::ContinueToNextToken
+                            }
+                        }
                         TokenKind::Tilde => {
                             if pending_dash.is_some() {
                                 // A tilde can never come after a dash.
@@ -23,7 +36,11 @@ impl Accept for UnfinishedParam {
                             }
                         }
                         TokenKind::Dash => {
-                            if *is_dash_allowed && pending_dash.is_none() {
+                            // Instance params cannot be decreasing params.
+                            if *is_dash_allowed
+                                && pending_dash.is_none()
+                                && pending_instance.is_none()
+                            {
                                 *pending_dash = Some(token);
                                 AcceptResult::ContinueToNextToken
                             } else {
@@ -36,13 +53,18 @@ impl Accept for UnfinishedParam {
                                 name: IdentifierName::new(token.content.clone()),
                             };
 
+                            let pending_instance = pending_instance.take();
                             let pending_tilde = pending_tilde.take();
                             let pending_dash = pending_dash.take();
+                            let is_instance = pending_instance.is_some();
                             let is_tilded = pending_tilde.is_some();
                             let is_dashed = pending_dash.is_some();
                             *self = UnfinishedParam::FirstIdentifier {
-                                first_token: pending_tilde
-                                    .unwrap_or_else(|| pending_dash.unwrap_or_else(|| token)),
+                                first_token: pending_instance.unwrap_or_else(|| {
+                                    pending_tilde
+                                        .unwrap_or_else(|| pending_dash.unwrap_or_else(|| token))
+                                }),
+                                is_instance,
                                 is_tilded,
                                 is_dashed,
                                 is_dash_allowed: *is_dash_allowed,
@@ -58,6 +80,7 @@ impl Accept for UnfinishedParam {
             }
             UnfinishedParam::FirstIdentifier {
                 first_token,
+                is_instance,
                 is_tilded,
                 is_dashed,
                 is_dash_allowed,
@@ -73,6 +96,7 @@ impl Accept for UnfinishedParam {
                         if !*is_tilded && !*is_dashed {
                             *self = UnfinishedParam::ExplicitLabel {
                                 first_token: first_token.clone(),
+                                is_instance: *is_instance,
                                 is_dashed: false,
                                 is_dash_allowed: *is_dash_allowed,
                                 label: name_or_label.clone(),
@@ -85,6 +109,26 @@ impl Accept for UnfinishedParam {
                     _other_token_kind => AcceptResult::Error(ParseError::unexpected_token(token)),
                 },
                 FinishedStackItem::DelimitedExpression(_, expression, end_delimiter) => {
+                    let label = if *is_tilded {
+                        Some(ParamLabel::Implicit)
+                    } else {
+                        None
+                    };
+                    if end_delimiter.raw().kind == TokenKind::Equal {
+                        *self = UnfinishedParam::Default {
+                            first_token: first_token.clone(),
+                            is_instance: *is_instance,
+                            label,
+                            is_dashed: *is_dashed,
+                            name: name_or_label.clone(),
+                            type_: expression,
+                        };
+                        return AcceptResult::Push(
+                            UnfinishedStackItem::UnfinishedDelimitedExpression(
+                                UnfinishedDelimitedExpression::Empty,
+                            ),
+                        );
+                    }
                     AcceptResult::PopAndContinueReducing(FinishedStackItem::Param(
                         first_token.clone(),
                         Param {
@@ -93,14 +137,12 @@ impl Accept for UnfinishedParam {
                                 &first_token,
                                 end_delimiter.raw(),
                             ),
-                            label: if *is_tilded {
-                                Some(ParamLabel::Implicit)
-                            } else {
-                                None
-                            },
+                            is_instance: *is_instance,
+                            label,
                             is_dashed: *is_dashed,
                             name: name_or_label.clone(),
                             type_: expression,
+                            default: None,
                         },
                         end_delimiter,
                     ))
@@ -110,13 +152,14 @@ impl Accept for UnfinishedParam {
 
             UnfinishedParam::ExplicitLabel {
                 first_token,
+                is_instance,
                 is_dashed,
                 is_dash_allowed,
                 label,
             } => match item {
                 FinishedStackItem::Token(token) => match token.kind {
                     TokenKind::Dash => {
-                        let is_dash_forbidden = !*is_dash_allowed;
+                        let is_dash_forbidden = !*is_dash_allowed || *is_instance;
                         if *is_dashed || is_dash_forbidden {
                             AcceptResult::Error(ParseError::unexpected_token(token))
                         } else {
@@ -131,6 +174,7 @@ impl Accept for UnfinishedParam {
                         };
                         *self = UnfinishedParam::ExplicitLabelAndName {
                             first_token: first_token.clone(),
+                            is_instance: *is_instance,
                             is_dashed: *is_dashed,
                             label: label.clone(),
                             name,
@@ -144,6 +188,7 @@ impl Accept for UnfinishedParam {
 
             UnfinishedParam::ExplicitLabelAndName {
                 first_token,
+                is_instance,
                 is_dashed,
                 label,
                 name,
@@ -157,6 +202,22 @@ impl Accept for UnfinishedParam {
                     _other_token_kind => AcceptResult::Error(ParseError::unexpected_token(token)),
                 },
                 FinishedStackItem::DelimitedExpression(_, expression, end_delimiter) => {
+                    let label = Some(ParamLabel::Explicit(label.clone()));
+                    if end_delimiter.raw().kind == TokenKind::Equal {
+                        *self = UnfinishedParam::Default {
+                            first_token: first_token.clone(),
+                            is_instance: *is_instance,
+                            label,
+                            is_dashed: *is_dashed,
+                            name: name.clone(),
+                            type_: expression,
+                        };
+                        return AcceptResult::Push(
+                            UnfinishedStackItem::UnfinishedDelimitedExpression(
+                                UnfinishedDelimitedExpression::Empty,
+                            ),
+                        );
+                    }
                     AcceptResult::PopAndContinueReducing(FinishedStackItem::Param(
                         first_token.clone(),
                         Param {
@@ -165,10 +226,42 @@ impl Accept for UnfinishedParam {
                                 &first_token,
                                 end_delimiter.raw(),
                             ),
-                            label: Some(ParamLabel::Explicit(label.clone())),
+                            is_instance: *is_instance,
+                            label,
                             is_dashed: *is_dashed,
                             name: name.clone(),
                             type_: expression,
+                            default: None,
+                        },
+                        end_delimiter,
+                    ))
+                }
+                other_item => wrapped_unexpected_finished_item_err(&other_item),
+            },
+
+            UnfinishedParam::Default {
+                first_token,
+                is_instance,
+                label,
+                is_dashed,
+                name,
+                type_,
+            } => match item {
+                FinishedStackItem::DelimitedExpression(_, expression, end_delimiter) => {
+                    AcceptResult::PopAndContinueReducing(FinishedStackItem::Param(
+                        first_token.clone(),
+                        Param {
+                            span: span_range_excluding_end(
+                                file_id,
+                                first_token,
+                                end_delimiter.raw(),
+                            ),
+                            is_instance: *is_instance,
+                            label: label.clone(),
+                            is_dashed: *is_dashed,
+                            name: name.clone(),
+                            type_: type_.clone(),
+                            default: Some(expression),
                         },
                         end_delimiter,
                     ))
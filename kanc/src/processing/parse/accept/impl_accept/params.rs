@@ -4,7 +4,8 @@ impl Accept for UnfinishedParams {
     fn accept(&mut self, item: FinishedStackItem, _: FileId) -> AcceptResult {
         match item {
             FinishedStackItem::Token(token) => match token.kind {
-                TokenKind::Tilde
+                TokenKind::Instance
+                | TokenKind::Tilde
                 | TokenKind::Dash
                 | TokenKind::StandardIdentifier
                 | TokenKind::Underscore => AcceptResult::PushAndContinueReducingWithNewTop(
@@ -14,7 +15,8 @@ impl Accept for UnfinishedParams {
                         // This is because we're repushing the token onto the stack,
                         // so passing in `Some(token)` would be double counting it.
                         pending_tilde: None,
-                        // The same goes for `pending_dash`.
+                        // The same goes for `pending_instance` and `pending_dash`.
+                        pending_instance: None,
                         pending_dash: None,
                         is_dash_allowed: self.maximum_dashed_params_allowed > 0,
                     }),
@@ -0,0 +1,46 @@
+use super::*;
+
+impl Accept for UnfinishedParenthesizedOperator {
+    fn accept(&mut self, item: FinishedStackItem, file_id: FileId) -> AcceptResult {
+        match self {
+            UnfinishedParenthesizedOperator::Empty => match item {
+                FinishedStackItem::Token(token) if token.kind == TokenKind::LParen => {
+                    *self = UnfinishedParenthesizedOperator::LParen(token);
+                    AcceptResult::ContinueToNextToken
+                }
+                other_item => wrapped_unexpected_finished_item_err(&other_item),
+            },
+
+            UnfinishedParenthesizedOperator::LParen(l_paren_token) => match item {
+                FinishedStackItem::Token(token) if is_operator_token_kind(token.kind) => {
+                    *self = UnfinishedParenthesizedOperator::Operator {
+                        l_paren_token: l_paren_token.clone(),
+                        operator_tokens: NonEmptyVec::singleton(token),
+                    };
+                    AcceptResult::ContinueToNextToken
+                }
+                other_item => wrapped_unexpected_finished_item_err(&other_item),
+            },
+
+            UnfinishedParenthesizedOperator::Operator {
+                l_paren_token,
+                operator_tokens,
+            } => match item {
+                FinishedStackItem::Token(token)
+                    if is_operator_token_kind(token.kind)
+                        && are_tokens_adjacent(operator_tokens.last(), &token) =>
+                {
+                    operator_tokens.push(token);
+                    AcceptResult::ContinueToNextToken
+                }
+                FinishedStackItem::Token(token) if token.kind == TokenKind::RParen => {
+                    AcceptResult::PopAndContinueReducing(FinishedStackItem::ParenthesizedOperator(
+                        l_paren_token.clone(),
+                        operator_identifier(file_id, operator_tokens),
+                    ))
+                }
+                other_item => wrapped_unexpected_finished_item_err(&other_item),
+            },
+        }
+    }
+}
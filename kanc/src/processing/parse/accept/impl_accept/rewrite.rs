@@ -0,0 +1,41 @@
+use super::*;
+
+impl Accept for UnfinishedRewrite {
+    fn accept(&mut self, item: FinishedStackItem, file_id: FileId) -> AcceptResult {
+        match self {
+            UnfinishedRewrite::Keyword(rewrite_kw) => match item {
+                FinishedStackItem::DelimitedExpression(_, proof, end_delimiter)
+                    if ExpressionEndDelimiter::is_in_kw(end_delimiter.raw()) =>
+                {
+                    *self = UnfinishedRewrite::Proof(rewrite_kw.clone(), proof);
+                    AcceptResult::Push(UnfinishedStackItem::UnfinishedDelimitedExpression(
+                        UnfinishedDelimitedExpression::Empty,
+                    ))
+                }
+                FinishedStackItem::DelimitedExpression(_, _, end_delimiter) => {
+                    AcceptResult::Error(ParseError::unexpected_token(end_delimiter.into_raw()))
+                }
+                other_item => wrapped_unexpected_finished_item_err(&other_item),
+            },
+            UnfinishedRewrite::Proof(rewrite_kw, proof) => match item {
+                FinishedStackItem::DelimitedExpression(_, body, end_delimiter) => {
+                    let span = TextSpan {
+                        file_id,
+                        start: rewrite_kw.start_index,
+                        end: body.span().end,
+                    };
+                    AcceptResult::PopAndContinueReducing(FinishedStackItem::DelimitedExpression(
+                        rewrite_kw.clone(),
+                        Expression::Rewrite(Box::new(Rewrite {
+                            span,
+                            proof: proof.clone(),
+                            body,
+                        })),
+                        end_delimiter,
+                    ))
+                }
+                other_item => wrapped_unexpected_finished_item_err(&other_item),
+            },
+        }
+    }
+}
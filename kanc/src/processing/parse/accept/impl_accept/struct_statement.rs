@@ -0,0 +1,271 @@
+use super::*;
+
+impl Accept for UnfinishedStructStatement {
+    fn accept(&mut self, item: FinishedStackItem, file_id: FileId) -> AcceptResult {
+        match self {
+            UnfinishedStructStatement::Empty => match item {
+                FinishedStackItem::Token(token) => match token.kind {
+                    TokenKind::Pub => {
+                        *self = UnfinishedStructStatement::ExplicitVisibility {
+                            first_token: token.clone(),
+                            visibility: PendingPubClause::PubKw(token),
+                        };
+                        AcceptResult::ContinueToNextToken
+                    }
+                    TokenKind::Struct | TokenKind::Trait => {
+                        *self = UnfinishedStructStatement::Keyword {
+                            is_trait: token.kind == TokenKind::Trait,
+                            first_token: token,
+                            visibility: None,
+                        };
+                        AcceptResult::ContinueToNextToken
+                    }
+                    _other_token_kind => AcceptResult::Error(ParseError::unexpected_token(token)),
+                },
+                other_item => wrapped_unexpected_finished_item_err(&other_item),
+            },
+
+            UnfinishedStructStatement::ExplicitVisibility {
+                first_token,
+                visibility,
+            } => match item {
+                FinishedStackItem::Token(token) => match token.kind {
+                    TokenKind::LParen => {
+                        if let PendingPubClause::PubKw(_) = visibility {
+                            AcceptResult::PushAndContinueReducingWithNewTop(
+                                UnfinishedStackItem::ParenthesizedModScopeModifier(
+                                    UnfinishedParenthesizedModScopeModifier::Empty,
+                                ),
+                                FinishedStackItem::Token(token),
+                            )
+                        } else {
+                            AcceptResult::Error(ParseError::unexpected_token(token))
+                        }
+                    }
+                    TokenKind::Struct | TokenKind::Trait => {
+                        *self = UnfinishedStructStatement::Keyword {
+                            first_token: first_token.clone(),
+                            visibility: Some(visibility.clone().finalize(file_id)),
+                            is_trait: token.kind == TokenKind::Trait,
+                        };
+                        AcceptResult::ContinueToNextToken
+                    }
+                    _other_token_kind => AcceptResult::Error(ParseError::unexpected_token(token)),
+                },
+                FinishedStackItem::ParenthesizedModScopeModifier(
+                    parenthesized_mod_scope_modifier_first_token,
+                    modifier,
+                ) => {
+                    if let PendingPubClause::PubKw(pub_kw_token) = visibility {
+                        *visibility = PendingPubClause::Finished(PubClause {
+                            span: span_single(file_id, pub_kw_token).inclusive_merge(modifier.span),
+                            scope_modifier: Some(modifier),
+                        });
+                        AcceptResult::ContinueToNextToken
+                    } else {
+                        wrapped_unexpected_finished_item_err(
+                            &FinishedStackItem::ParenthesizedModScopeModifier(
+                                parenthesized_mod_scope_modifier_first_token,
+                                modifier,
+                            ),
+                        )
+                    }
+                }
+                other_item => wrapped_unexpected_finished_item_err(&other_item),
+            },
+
+            UnfinishedStructStatement::Keyword {
+                first_token,
+                visibility,
+                is_trait,
+            } => match item {
+                FinishedStackItem::Token(token) => match token.kind {
+                    TokenKind::StandardIdentifier => {
+                        let name = Identifier {
+                            span: span_single(file_id, &token),
+                            name: IdentifierName::new(token.content.clone()),
+                        };
+                        *self = UnfinishedStructStatement::Name {
+                            first_token: first_token.clone(),
+                            visibility: visibility.clone(),
+                            name,
+                            is_trait: *is_trait,
+                        };
+                        AcceptResult::ContinueToNextToken
+                    }
+                    _other_token_kind => AcceptResult::Error(ParseError::unexpected_token(token)),
+                },
+                other_item => wrapped_unexpected_finished_item_err(&other_item),
+            },
+
+            UnfinishedStructStatement::Name {
+                first_token,
+                visibility,
+                name,
+                is_trait,
+            } => match item {
+                FinishedStackItem::Token(token) => match token.kind {
+                    TokenKind::LParen => {
+                        AcceptResult::Push(UnfinishedStackItem::Params(UnfinishedParams {
+                            first_token: token,
+                            maximum_dashed_params_allowed: 0,
+                            pending_tilde: None,
+                            pending_dash: None,
+                            params: vec![],
+                        }))
+                    }
+                    TokenKind::LCurly => {
+                        *self = UnfinishedStructStatement::Fields {
+                            first_token: first_token.clone(),
+                            visibility: visibility.clone(),
+                            name: name.clone(),
+                            params: None,
+                            fields: vec![],
+                            is_trait: *is_trait,
+                        };
+                        AcceptResult::ContinueToNextToken
+                    }
+                    _other_token_kind => AcceptResult::Error(ParseError::unexpected_token(token)),
+                },
+                FinishedStackItem::Params(_, params) => {
+                    *self = UnfinishedStructStatement::Params {
+                        first_token: first_token.clone(),
+                        visibility: visibility.clone(),
+                        name: name.clone(),
+                        params: Some(params),
+                        is_trait: *is_trait,
+                    };
+                    AcceptResult::ContinueToNextToken
+                }
+                other_item => wrapped_unexpected_finished_item_err(&other_item),
+            },
+
+            UnfinishedStructStatement::Params {
+                first_token,
+                visibility,
+                name,
+                params,
+                is_trait,
+            } => match item {
+                FinishedStackItem::Token(token) => match token.kind {
+                    TokenKind::LCurly => {
+                        *self = UnfinishedStructStatement::Fields {
+                            first_token: first_token.clone(),
+                            visibility: visibility.clone(),
+                            name: name.clone(),
+                            params: params.clone(),
+                            fields: vec![],
+                            is_trait: *is_trait,
+                        };
+                        AcceptResult::ContinueToNextToken
+                    }
+                    _other_token_kind => AcceptResult::Error(ParseError::unexpected_token(token)),
+                },
+                other_item => wrapped_unexpected_finished_item_err(&other_item),
+            },
+
+            UnfinishedStructStatement::Fields {
+                first_token,
+                visibility,
+                name,
+                params,
+                fields,
+                is_trait,
+            } => match item {
+                FinishedStackItem::Token(token) => match token.kind {
+                    TokenKind::StandardIdentifier => {
+                        let field_name = Identifier {
+                            span: span_single(file_id, &token),
+                            name: IdentifierName::new(token.content),
+                        };
+                        *self = UnfinishedStructStatement::FieldName {
+                            first_token: first_token.clone(),
+                            visibility: visibility.clone(),
+                            name: name.clone(),
+                            params: params.clone(),
+                            fields: fields.clone(),
+                            field_name,
+                            is_trait: *is_trait,
+                        };
+                        AcceptResult::ContinueToNextToken
+                    }
+                    TokenKind::RCurly => {
+                        AcceptResult::PopAndContinueReducing(FinishedStackItem::Struct(
+                            first_token.clone(),
+                            StructStatement {
+                                span: span_range_including_end(file_id, first_token, &token),
+                                visibility: visibility.clone(),
+                                name: name.clone(),
+                                params: params.clone(),
+                                fields: fields.clone(),
+                                is_trait: *is_trait,
+                            },
+                        ))
+                    }
+                    _other_token_kind => AcceptResult::Error(ParseError::unexpected_token(token)),
+                },
+                other_item => wrapped_unexpected_finished_item_err(&other_item),
+            },
+
+            UnfinishedStructStatement::FieldName {
+                first_token,
+                visibility,
+                name,
+                params,
+                fields,
+                field_name,
+                is_trait,
+            } => match item {
+                FinishedStackItem::Token(token) => match token.kind {
+                    TokenKind::Colon => {
+                        AcceptResult::Push(UnfinishedStackItem::UnfinishedDelimitedExpression(
+                            UnfinishedDelimitedExpression::Empty,
+                        ))
+                    }
+                    _other_token_kind => AcceptResult::Error(ParseError::unexpected_token(token)),
+                },
+                FinishedStackItem::DelimitedExpression(_, type_, end_delimiter) => {
+                    fields.push(StructField {
+                        span: field_name.span.inclusive_merge(type_.span()),
+                        name: field_name.clone(),
+                        type_,
+                    });
+                    match end_delimiter.raw().kind {
+                        TokenKind::Comma => {
+                            *self = UnfinishedStructStatement::Fields {
+                                first_token: first_token.clone(),
+                                visibility: visibility.clone(),
+                                name: name.clone(),
+                                params: params.clone(),
+                                fields: fields.clone(),
+                                is_trait: *is_trait,
+                            };
+                            AcceptResult::ContinueToNextToken
+                        }
+                        TokenKind::RCurly => {
+                            AcceptResult::PopAndContinueReducing(FinishedStackItem::Struct(
+                                first_token.clone(),
+                                StructStatement {
+                                    span: span_range_including_end(
+                                        file_id,
+                                        first_token,
+                                        end_delimiter.raw(),
+                                    ),
+                                    visibility: visibility.clone(),
+                                    name: name.clone(),
+                                    params: params.clone(),
+                                    fields: fields.clone(),
+                                    is_trait: *is_trait,
+                                },
+                            ))
+                        }
+                        _other_end_delimiter => AcceptResult::Error(ParseError::unexpected_token(
+                            end_delimiter.into_raw(),
+                        )),
+                    }
+                }
+                other_item => wrapped_unexpected_finished_item_err(&other_item),
+            },
+        }
+    }
+}
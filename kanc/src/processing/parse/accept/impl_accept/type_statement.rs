@@ -109,12 +109,23 @@ impl Accept for UnfinishedTypeStatement {
                             params: vec![],
                         }))
                     }
+                    TokenKind::Derive => {
+                        *self = UnfinishedTypeStatement::DeriveKw {
+                            first_token: first_token.clone(),
+                            visibility: visibility.clone(),
+                            name: name.clone(),
+                            params: None,
+                            derive_kw: token,
+                        };
+                        AcceptResult::ContinueToNextToken
+                    }
                     TokenKind::LCurly => {
                         *self = UnfinishedTypeStatement::Variants {
                             first_token: first_token.clone(),
                             visibility: visibility.clone(),
                             name: name.clone(),
                             params: None,
+                            derive: None,
                             variants: vec![],
                         };
                         AcceptResult::ContinueToNextToken
@@ -127,6 +138,7 @@ impl Accept for UnfinishedTypeStatement {
                         visibility: visibility.clone(),
                         name: name.clone(),
                         params: Some(params),
+                        derive: None,
                     };
                     AcceptResult::ContinueToNextToken
                 }
@@ -138,14 +150,26 @@ impl Accept for UnfinishedTypeStatement {
                 visibility,
                 name,
                 params,
+                derive,
             } => match item {
                 FinishedStackItem::Token(token) => match token.kind {
+                    TokenKind::Derive if derive.is_none() => {
+                        *self = UnfinishedTypeStatement::DeriveKw {
+                            first_token: first_token.clone(),
+                            visibility: visibility.clone(),
+                            name: name.clone(),
+                            params: params.clone(),
+                            derive_kw: token,
+                        };
+                        AcceptResult::ContinueToNextToken
+                    }
                     TokenKind::LCurly => {
                         *self = UnfinishedTypeStatement::Variants {
                             first_token: first_token.clone(),
                             visibility: visibility.clone(),
                             name: name.clone(),
                             params: params.clone(),
+                            derive: derive.clone(),
                             variants: vec![],
                         };
                         AcceptResult::ContinueToNextToken
@@ -155,11 +179,79 @@ impl Accept for UnfinishedTypeStatement {
                 other_item => wrapped_unexpected_finished_item_err(&other_item),
             },
 
+            UnfinishedTypeStatement::DeriveKw {
+                first_token,
+                visibility,
+                name,
+                params,
+                derive_kw,
+            } => match item {
+                FinishedStackItem::Token(token) => match token.kind {
+                    TokenKind::LParen => {
+                        *self = UnfinishedTypeStatement::DeriveNames {
+                            first_token: first_token.clone(),
+                            visibility: visibility.clone(),
+                            name: name.clone(),
+                            params: params.clone(),
+                            derive_kw: derive_kw.clone(),
+                            names: vec![],
+                            expects_name: true,
+                        };
+                        AcceptResult::ContinueToNextToken
+                    }
+                    _other_token_kind => AcceptResult::Error(ParseError::unexpected_token(token)),
+                },
+                other_item => wrapped_unexpected_finished_item_err(&other_item),
+            },
+
+            UnfinishedTypeStatement::DeriveNames {
+                first_token,
+                visibility,
+                name,
+                params,
+                derive_kw,
+                names,
+                expects_name,
+            } => match item {
+                FinishedStackItem::Token(token) => match token.kind {
+                    TokenKind::StandardIdentifier if *expects_name => {
+                        names.push(Identifier {
+                            span: span_single(file_id, &token),
+                            name: IdentifierName::new(token.content),
+                        });
+                        *expects_name = false;
+                        AcceptResult::ContinueToNextToken
+                    }
+                    TokenKind::Comma if !*expects_name => {
+                        *expects_name = true;
+                        AcceptResult::ContinueToNextToken
+                    }
+                    TokenKind::RParen if !names.is_empty() => {
+                        let derive = DeriveClause {
+                            span: span_range_including_end(file_id, derive_kw, &token),
+                            names: NonEmptyVec::try_from(names.clone())
+                                .expect("names should be non-empty"),
+                        };
+                        *self = UnfinishedTypeStatement::Params {
+                            first_token: first_token.clone(),
+                            visibility: visibility.clone(),
+                            name: name.clone(),
+                            params: params.clone(),
+                            derive: Some(derive),
+                        };
+                        AcceptResult::ContinueToNextToken
+                    }
+                    _other_token_kind => AcceptResult::Error(ParseError::unexpected_token(token)),
+                },
+                other_item => wrapped_unexpected_finished_item_err(&other_item),
+            },
+
             UnfinishedTypeStatement::Variants {
                 first_token,
                 visibility,
                 name,
                 params,
+                derive,
                 variants,
             } => match item {
                 FinishedStackItem::Token(token) => match token.kind {
@@ -177,6 +269,7 @@ impl Accept for UnfinishedTypeStatement {
                                 visibility: visibility.clone(),
                                 name: name.clone(),
                                 params: params.clone(),
+                                derive: derive.clone(),
                                 variants: variants.clone(),
                             },
                         ))
@@ -199,6 +292,7 @@ impl Accept for UnfinishedTypeStatement {
                                     visibility: visibility.clone(),
                                     name: name.clone(),
                                     params: params.clone(),
+                                    derive: derive.clone(),
                                     variants: variants.clone(),
                                 },
                             ))
@@ -190,6 +190,14 @@ impl Accept for UnfinishedUseStatement {
                         other_components.push(component);
                         AcceptResult::ContinueToNextToken
                     }
+                    TokenKind::LParen if *has_trailing_dot => {
+                        AcceptResult::PushAndContinueReducingWithNewTop(
+                            UnfinishedStackItem::ParenthesizedOperator(
+                                UnfinishedParenthesizedOperator::Empty,
+                            ),
+                            FinishedStackItem::Token(token),
+                        )
+                    }
                     TokenKind::Star if *has_trailing_dot => {
                         *self = UnfinishedUseStatement::FinishedImportModifier {
                             first_token: first_token.clone(),
@@ -206,6 +214,16 @@ impl Accept for UnfinishedUseStatement {
 
                     _other_token_kind => AcceptResult::Error(ParseError::unexpected_token(token)),
                 },
+                FinishedStackItem::ParenthesizedOperator(_, operator) if *has_trailing_dot => {
+                    other_components.push(operator);
+                    *self = UnfinishedUseStatement::Operator {
+                        first_token: first_token.clone(),
+                        visibility: visibility.clone(),
+                        first_component: first_component.clone(),
+                        other_components: other_components.clone(),
+                    };
+                    AcceptResult::ContinueToNextToken
+                }
                 other_item => wrapped_unexpected_finished_item_err(&other_item),
             },
 
@@ -254,6 +272,27 @@ impl Accept for UnfinishedUseStatement {
                 }
                 other_item => wrapped_unexpected_finished_item_err(&other_item),
             },
+
+            UnfinishedUseStatement::Operator {
+                first_token,
+                visibility,
+                first_component,
+                other_components,
+            } => match item {
+                FinishedStackItem::Token(token) if token.kind == TokenKind::Semicolon => {
+                    AcceptResult::PopAndContinueReducing(FinishedStackItem::Use(
+                        first_token.clone(),
+                        UseStatement {
+                            span: span_range_including_end(file_id, first_token, &token),
+                            visibility: visibility.clone(),
+                            first_component: first_component.clone(),
+                            other_components: other_components.clone(),
+                            import_modifier: None,
+                        },
+                    ))
+                }
+                other_item => wrapped_unexpected_finished_item_err(&other_item),
+            },
         }
     }
 }
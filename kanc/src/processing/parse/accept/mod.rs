@@ -28,7 +28,10 @@ impl Accept for UnfinishedStackItem {
             UnfinishedStackItem::Use(use_) => use_.accept(item, file_id),
             UnfinishedStackItem::Mod(mod_) => mod_.accept(item, file_id),
             UnfinishedStackItem::Type(type_) => type_.accept(item, file_id),
+            UnfinishedStackItem::Struct(struct_) => struct_.accept(item, file_id),
             UnfinishedStackItem::Let(let_) => let_.accept(item, file_id),
+            UnfinishedStackItem::Notation(notation) => notation.accept(item, file_id),
+            UnfinishedStackItem::ParenthesizedOperator(operator) => operator.accept(item, file_id),
             UnfinishedStackItem::Params(params) => params.accept(item, file_id),
             UnfinishedStackItem::Param(param) => param.accept(item, file_id),
             UnfinishedStackItem::Variant(variant) => variant.accept(item, file_id),
@@ -44,6 +47,9 @@ impl Accept for UnfinishedStackItem {
             }
             UnfinishedStackItem::Forall(forall) => forall.accept(item, file_id),
             UnfinishedStackItem::Check(check) => check.accept(item, file_id),
+            UnfinishedStackItem::Rewrite(rewrite) => rewrite.accept(item, file_id),
+            UnfinishedStackItem::Exists(exists) => exists.accept(item, file_id),
+            UnfinishedStackItem::Pair(pair) => pair.accept(item, file_id),
             UnfinishedStackItem::CheckAssertions(assertions) => assertions.accept(item, file_id),
             UnfinishedStackItem::CheckAssertion(assertion) => assertion.accept(item, file_id),
             UnfinishedStackItem::UnfinishedDelimitedGoalKwOrExpression(expression) => {
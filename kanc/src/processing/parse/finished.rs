@@ -28,11 +28,26 @@ pub enum FinishedStackItem {
         Token,
         TypeStatement,
     ),
+    Struct(
+        /// First token
+        Token,
+        StructStatement,
+    ),
     Let(
         /// First token
         Token,
         LetStatement,
     ),
+    Notation(
+        /// First token
+        Token,
+        NotationStatement,
+    ),
+    ParenthesizedOperator(
+        /// First token ("(")
+        Token,
+        Identifier,
+    ),
     Params(
         /// First token ("(")
         Token,
@@ -125,6 +140,8 @@ impl ExpressionEndDelimiter {
             TokenKind::Comma
                 | TokenKind::Semicolon
                 | TokenKind::Colon
+                | TokenKind::As
+                | TokenKind::FatArrow
                 | TokenKind::Equal
                 | TokenKind::LCurly
                 | TokenKind::RCurly
@@ -133,8 +150,17 @@ impl ExpressionEndDelimiter {
         )
     }
 
+    /// `in` is not a keyword (so it can still be used as an identifier),
+    /// but it ends any expression it directly follows.
+    /// This lets us parse `rewrite h in expr`.
+    pub fn is_in_kw(token: &Token) -> bool {
+        token.kind == TokenKind::StandardIdentifier && token.content == "in"
+    }
+
     pub fn try_new(token: Token) -> Result<Self, Token> {
-        if ExpressionEndDelimiter::is_end_delimiter(token.kind) {
+        if ExpressionEndDelimiter::is_end_delimiter(token.kind)
+            || ExpressionEndDelimiter::is_in_kw(&token)
+        {
             Ok(Self(token))
         } else {
             Err(token)
@@ -161,7 +187,10 @@ impl FinishedStackItem {
             FinishedStackItem::Use(token, _) => &token,
             FinishedStackItem::Mod(token, _) => &token,
             FinishedStackItem::Type(token, _) => &token,
+            FinishedStackItem::Struct(token, _) => &token,
             FinishedStackItem::Let(token, _) => &token,
+            FinishedStackItem::Notation(token, _) => token,
+            FinishedStackItem::ParenthesizedOperator(token, _) => token,
             FinishedStackItem::Params(token, _) => &token,
             FinishedStackItem::Param(token, _, _) => &token,
             FinishedStackItem::Variant(token, _, _) => &token,
@@ -45,6 +45,7 @@ impl Parse for Expression {
 impl Parse for Param {
     fn initial_stack(_: FileId, _: &Token) -> Vec<UnfinishedStackItem> {
         vec![UnfinishedStackItem::Param(UnfinishedParam::NoIdentifier {
+            pending_instance: None,
             pending_tilde: None,
             pending_dash: None,
             is_dash_allowed: true,
@@ -125,6 +126,21 @@ impl Parse for ModStatement {
     }
 }
 
+impl Parse for StructStatement {
+    fn initial_stack(_: FileId, _: &Token) -> Vec<UnfinishedStackItem> {
+        vec![UnfinishedStackItem::Struct(
+            UnfinishedStructStatement::Empty,
+        )]
+    }
+
+    fn finish(bottom_item: FinishedStackItem) -> Result<Self, ParseError> {
+        match bottom_item {
+            FinishedStackItem::Struct(_, struct_statement) => Ok(struct_statement),
+            other_item => Err(unexpected_finished_item_err(&other_item)),
+        }
+    }
+}
+
 impl Parse for TypeStatement {
     fn initial_stack(_: FileId, _: &Token) -> Vec<UnfinishedStackItem> {
         vec![UnfinishedStackItem::Type(UnfinishedTypeStatement::Empty)]
@@ -150,3 +166,18 @@ impl Parse for LetStatement {
         }
     }
 }
+
+impl Parse for NotationStatement {
+    fn initial_stack(_: FileId, _: &Token) -> Vec<UnfinishedStackItem> {
+        vec![UnfinishedStackItem::Notation(
+            UnfinishedNotationStatement::Empty,
+        )]
+    }
+
+    fn finish(bottom_item: FinishedStackItem) -> Result<Self, ParseError> {
+        match bottom_item {
+            FinishedStackItem::Notation(_, notation_statement) => Ok(notation_statement),
+            other_item => Err(unexpected_finished_item_err(&other_item)),
+        }
+    }
+}
@@ -167,6 +167,40 @@ fn get_n_from_super_n_token(token: &Token) -> Option<NonZeroUsize> {
     }
 }
 
+/// Returns whether the token can be part of an operator
+/// (e.g., the `+` in `a + b`, or the `<` and `>` in `a <> b`).
+/// `=` is deliberately excluded, since it already delimits expressions.
+fn is_operator_token_kind(kind: TokenKind) -> bool {
+    matches!(
+        kind,
+        TokenKind::Plus
+            | TokenKind::Dash
+            | TokenKind::Star
+            | TokenKind::Slash
+            | TokenKind::LAngle
+            | TokenKind::RAngle
+    )
+}
+
+/// Returns whether `next` starts right where `prev` ends
+/// (i.e., there is no whitespace or comment between them).
+fn are_tokens_adjacent(prev: &Token, next: &Token) -> bool {
+    prev.start_index.0 + prev.content.len() == next.start_index.0
+}
+
+/// An operator is represented as an identifier whose name
+/// is the concatenation of its tokens (e.g., `<>`).
+fn operator_identifier(file_id: FileId, operator_tokens: &NonEmptyVec<Token>) -> Identifier {
+    let name: String = operator_tokens
+        .iter()
+        .map(|token| token.content.as_str())
+        .collect();
+    Identifier {
+        span: span_range_including_end(file_id, operator_tokens.first(), operator_tokens.last()),
+        name: IdentifierName::new(name),
+    }
+}
+
 /// Panics if the name is not `IdentifierName::Standard(_)`.
 fn token_from_standard_identifier(identifier: &Identifier) -> Token {
     Token {
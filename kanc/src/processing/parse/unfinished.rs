@@ -7,7 +7,10 @@ pub enum UnfinishedStackItem {
     Use(UnfinishedUseStatement),
     Mod(UnfinishedModStatement),
     Type(UnfinishedTypeStatement),
+    Struct(UnfinishedStructStatement),
     Let(UnfinishedLetStatement),
+    Notation(UnfinishedNotationStatement),
+    ParenthesizedOperator(UnfinishedParenthesizedOperator),
     Params(UnfinishedParams),
     Param(UnfinishedParam),
     Variant(UnfinishedVariant),
@@ -19,6 +22,9 @@ pub enum UnfinishedStackItem {
     Match(UnfinishedMatch),
     Forall(UnfinishedForall),
     Check(UnfinishedCheck),
+    Rewrite(UnfinishedRewrite),
+    Exists(UnfinishedExists),
+    Pair(UnfinishedPair),
     CheckAssertions(UnfinishedCheckAssertions),
     CheckAssertion(UnfinishedCheckAssertion),
     Dot(UnfinishedDot),
@@ -99,6 +105,14 @@ pub enum UnfinishedUseStatement {
         other_components: Vec<Identifier>,
         import_modifier: WildcardOrAlternateName,
     },
+    /// The last component is a parenthesized operator
+    /// (e.g., `use foo.(+);`).
+    Operator {
+        first_token: Token,
+        visibility: Option<PubClause>,
+        first_component: UseStatementFirstComponent,
+        other_components: Vec<Identifier>,
+    },
 }
 
 #[derive(Clone, Debug)]
@@ -140,16 +154,78 @@ pub enum UnfinishedTypeStatement {
         visibility: Option<PubClause>,
         name: Identifier,
         params: Option<NonEmptyVec<Param>>,
+        derive: Option<DeriveClause>,
+    },
+    DeriveKw {
+        first_token: Token,
+        visibility: Option<PubClause>,
+        name: Identifier,
+        params: Option<NonEmptyVec<Param>>,
+        derive_kw: Token,
+    },
+    DeriveNames {
+        first_token: Token,
+        visibility: Option<PubClause>,
+        name: Identifier,
+        params: Option<NonEmptyVec<Param>>,
+        derive_kw: Token,
+        names: Vec<Identifier>,
+        expects_name: bool,
     },
     Variants {
         first_token: Token,
         visibility: Option<PubClause>,
         name: Identifier,
         params: Option<NonEmptyVec<Param>>,
+        derive: Option<DeriveClause>,
         variants: Vec<Variant>,
     },
 }
 
+#[derive(Clone, Debug)]
+pub enum UnfinishedStructStatement {
+    Empty,
+    ExplicitVisibility {
+        first_token: Token,
+        visibility: PendingPubClause,
+    },
+    Keyword {
+        first_token: Token,
+        visibility: Option<PubClause>,
+        is_trait: bool,
+    },
+    Name {
+        first_token: Token,
+        visibility: Option<PubClause>,
+        name: Identifier,
+        is_trait: bool,
+    },
+    Params {
+        first_token: Token,
+        visibility: Option<PubClause>,
+        name: Identifier,
+        params: Option<NonEmptyVec<Param>>,
+        is_trait: bool,
+    },
+    Fields {
+        first_token: Token,
+        visibility: Option<PubClause>,
+        name: Identifier,
+        params: Option<NonEmptyVec<Param>>,
+        fields: Vec<StructField>,
+        is_trait: bool,
+    },
+    FieldName {
+        first_token: Token,
+        visibility: Option<PubClause>,
+        name: Identifier,
+        params: Option<NonEmptyVec<Param>>,
+        fields: Vec<StructField>,
+        field_name: Identifier,
+        is_trait: bool,
+    },
+}
+
 #[derive(Clone, Debug)]
 pub enum UnfinishedLetStatement {
     Empty,
@@ -160,17 +236,75 @@ pub enum UnfinishedLetStatement {
     Keyword {
         first_token: Token,
         visibility: Option<PubClause>,
+        is_instance: bool,
     },
     ExplicitTransparency {
         first_token: Token,
         visibility: Option<PubClause>,
         transparency: ParenthesizedModScopeModifier,
+        is_instance: bool,
     },
     Name {
         first_token: Token,
         visibility: Option<PubClause>,
         transparency: Option<ParenthesizedModScopeModifier>,
         name: Identifier,
+        is_instance: bool,
+    },
+}
+
+#[derive(Clone, Debug)]
+pub enum UnfinishedNotationStatement {
+    Empty,
+    ExplicitVisibility {
+        first_token: Token,
+        visibility: PendingPubClause,
+    },
+    Keyword {
+        first_token: Token,
+        visibility: Option<PubClause>,
+    },
+    Operator {
+        first_token: Token,
+        visibility: Option<PubClause>,
+        operator: Identifier,
+    },
+    Equal {
+        first_token: Token,
+        visibility: Option<PubClause>,
+        operator: Identifier,
+    },
+    Function {
+        first_token: Token,
+        visibility: Option<PubClause>,
+        operator: Identifier,
+        function: Expression,
+    },
+    Precedence {
+        first_token: Token,
+        visibility: Option<PubClause>,
+        operator: Identifier,
+        function: Expression,
+        precedence: Identifier,
+        has_trailing_comma: bool,
+    },
+    Associativity {
+        first_token: Token,
+        visibility: Option<PubClause>,
+        operator: Identifier,
+        function: Expression,
+        precedence: Identifier,
+        associativity: Identifier,
+    },
+}
+
+#[derive(Clone, Debug)]
+pub enum UnfinishedParenthesizedOperator {
+    Empty,
+    LParen(Token),
+    Operator {
+        l_paren_token: Token,
+        operator_tokens: NonEmptyVec<Token>,
     },
 }
 
@@ -186,12 +320,14 @@ pub struct UnfinishedParams {
 #[derive(Clone, Debug)]
 pub enum UnfinishedParam {
     NoIdentifier {
+        pending_instance: Option<Token>,
         pending_tilde: Option<Token>,
         pending_dash: Option<Token>,
         is_dash_allowed: bool,
     },
     FirstIdentifier {
         first_token: Token,
+        is_instance: bool,
         is_tilded: bool,
         is_dashed: bool,
         is_dash_allowed: bool,
@@ -199,16 +335,26 @@ pub enum UnfinishedParam {
     },
     ExplicitLabel {
         first_token: Token,
+        is_instance: bool,
         is_dashed: bool,
         is_dash_allowed: bool,
         label: Identifier,
     },
     ExplicitLabelAndName {
         first_token: Token,
+        is_instance: bool,
         is_dashed: bool,
         label: Identifier,
         name: Identifier,
     },
+    Default {
+        first_token: Token,
+        is_instance: bool,
+        label: Option<ParamLabel>,
+        is_dashed: bool,
+        name: Identifier,
+        type_: Expression,
+    },
 }
 
 #[derive(Clone, Debug)]
@@ -222,6 +368,21 @@ pub enum UnfinishedVariant {
 pub enum UnfinishedDelimitedExpression {
     Empty,
     WaitingForEndDelimiter(Token, Expression),
+    /// We are reading an operator's tokens
+    /// (e.g., the `+` in `a + b`).
+    InfixOperator {
+        first_token: Token,
+        first_operand: Expression,
+        rest: Vec<InfixChainLink>,
+        operator_tokens: NonEmptyVec<Token>,
+    },
+    /// We are waiting for the operand to the right of `operator`.
+    InfixOperand {
+        first_token: Token,
+        first_operand: Expression,
+        rest: Vec<InfixChainLink>,
+        operator: Identifier,
+    },
 }
 
 #[derive(Clone, Debug)]
@@ -248,7 +409,10 @@ pub enum UnfinishedFun {
 #[derive(Clone, Debug)]
 pub enum UnfinishedMatch {
     Keyword(Token),
-    Cases(Token, Expression, Vec<MatchCase>),
+    MotiveAsKeyword(Token, Expression, Token),
+    MotiveMatcheeName(Token, Expression, Token, Identifier),
+    MotiveMatcheeType(Token, Expression, Token, Identifier, Expression),
+    Cases(Token, Expression, Option<MatchMotive>, Vec<MatchCase>),
 }
 
 #[derive(Clone, Debug)]
@@ -263,6 +427,24 @@ pub enum UnfinishedCheck {
     Assertions(Token, NonEmptyVec<CheckAssertion>),
 }
 
+#[derive(Clone, Debug)]
+pub enum UnfinishedRewrite {
+    Keyword(Token),
+    Proof(Token, Expression),
+}
+
+#[derive(Clone, Debug)]
+pub enum UnfinishedExists {
+    Keyword(Token),
+    Params(Token, NonEmptyVec<Param>),
+}
+
+#[derive(Clone, Debug)]
+pub enum UnfinishedPair {
+    LParen(Token),
+    Witness(Token, Expression),
+}
+
 #[derive(Clone, Debug)]
 pub struct UnfinishedCheckAssertions {
     pub first_token: Token,
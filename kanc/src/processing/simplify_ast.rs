@@ -12,7 +12,9 @@ pub enum SimplifyAstError {
 
     HeterogeneousParams(NonEmptyVec<ust::Param>),
     UnderscoreParamLabel(ust::Param),
-    DuplicateParamLabel(ust::Param, ust::Param),
+    DuplicateParamLabel(Box<ust::Param>, Box<ust::Param>),
+    UnlabeledParamDefault(ust::Param),
+    InstanceParamDefault(ust::Param),
 
     HeterogeneousCallArgs(NonEmptyVec<ust::CallArg>),
     UnderscoreCallArgLabel(ust::CallArg),
@@ -21,6 +23,16 @@ pub enum SimplifyAstError {
     HeterogeneousMatchCaseParams(NonEmptyVec<ust::MatchCaseParam>),
     UnderscoreMatchCaseParamLabel(ust::MatchCaseParam),
     DuplicateMatchCaseParamLabel(ust::MatchCaseParam, ust::MatchCaseParam),
+
+    IllegalMatchMotiveMatcheeType(ust::Expression),
+
+    LabeledExistsParam(ust::Param),
+
+    UnknownDerive(ust::Identifier),
+
+    IllegalNotationFunction(ust::Expression),
+    UnknownNotationPrecedence(ust::Identifier),
+    UnknownNotationAssociativity(ust::Identifier),
 }
 
 pub fn simplify_file(unsimplified: ust::File) -> Result<File, SimplifyAstError> {
@@ -51,7 +63,13 @@ fn simplify_file_item(unsimplified: ust::FileItem) -> Result<FileItem, SimplifyA
         ust::FileItem::Use(unsimplified) => simplify_use_statement(unsimplified)?,
         ust::FileItem::Mod(unsimplified) => FileItem::Mod(simplify_mod_statement(unsimplified)?),
         ust::FileItem::Type(unsimplified) => FileItem::Type(simplify_type_statement(unsimplified)?),
+        ust::FileItem::Struct(unsimplified) => {
+            FileItem::Struct(simplify_struct_statement(unsimplified)?)
+        }
         ust::FileItem::Let(unsimplified) => FileItem::Let(simplify_let_statement(unsimplified)?),
+        ust::FileItem::Notation(unsimplified) => {
+            FileItem::Notation(simplify_notation_statement(unsimplified)?)
+        }
     })
 }
 
@@ -133,10 +151,80 @@ fn simplify_type_statement(
         visibility: unsimplified.visibility,
         name: unsimplified.name,
         params: simplify_optional_params(unsimplified.params)?,
+        derives: simplify_optional_derive_clause(unsimplified.derive)?,
         variants: vec_result_map(unsimplified.variants, simplify_variant)?,
     })
 }
 
+fn simplify_optional_derive_clause(
+    unsimplified: Option<ust::DeriveClause>,
+) -> Result<Vec<Derive>, SimplifyAstError> {
+    let Some(unsimplified) = unsimplified else {
+        return Ok(vec![]);
+    };
+    vec_result_map(unsimplified.names.to_vec(), simplify_derive)
+}
+
+fn simplify_derive(name: ust::Identifier) -> Result<Derive, SimplifyAstError> {
+    let kind = match name.name.src_str() {
+        "eq" => DeriveKind::Eq,
+        _ => return Err(SimplifyAstError::UnknownDerive(name)),
+    };
+    Ok(Derive {
+        span: name.span,
+        kind,
+    })
+}
+
+fn simplify_struct_statement(
+    unsimplified: ust::StructStatement,
+) -> Result<StructStatement, SimplifyAstError> {
+    validate_struct_constructor_param_labels(&unsimplified)?;
+    Ok(StructStatement {
+        span: unsimplified.span,
+        visibility: unsimplified.visibility,
+        name: unsimplified.name,
+        params: simplify_optional_params(unsimplified.params)?,
+        fields: vec_result_map(unsimplified.fields, simplify_struct_field)?,
+        is_trait: unsimplified.is_trait,
+    })
+}
+
+/// The constructor of a struct is labeled by the struct's params
+/// followed by its fields, so these labels must all be distinct.
+fn validate_struct_constructor_param_labels(
+    unsimplified: &ust::StructStatement,
+) -> Result<(), SimplifyAstError> {
+    let struct_params = unsimplified.params.iter().flat_map(|params| {
+        params.iter().cloned().map(|param| ust::Param {
+            label: Some(param.label.unwrap_or(ParamLabel::Implicit)),
+            ..param
+        })
+    });
+    let field_params = unsimplified.fields.iter().map(|field| ust::Param {
+        span: field.span,
+        is_instance: false,
+        label: Some(ParamLabel::Implicit),
+        is_dashed: false,
+        name: field.name.clone(),
+        type_: field.type_.clone(),
+        default: None,
+    });
+    let constructor_params: Vec<ust::Param> = struct_params.chain(field_params).collect();
+    for param in &constructor_params {
+        validate_param_label_is_not_underscore(param)?;
+    }
+    validate_there_are_no_duplicate_param_labels(&constructor_params)
+}
+
+fn simplify_struct_field(unsimplified: ust::StructField) -> Result<StructField, SimplifyAstError> {
+    Ok(StructField {
+        span: unsimplified.span,
+        name: unsimplified.name,
+        type_: simplify_expression(unsimplified.type_)?,
+    })
+}
+
 fn simplify_optional_params(
     unsimplified: Option<NonEmptyVec<ust::Param>>,
 ) -> Result<Option<NonEmptyParamVec>, SimplifyAstError> {
@@ -153,21 +241,26 @@ fn simplify_params(
 
     validate_param_label_is_not_underscore(&last)?;
 
-    if let Some(label) = last.label {
+    if let Some(label) = last.label.clone() {
+        let default = simplify_labeled_param_default(&last)?;
         let last = LabeledParam {
             span: last.span,
+            is_instance: last.is_instance,
             label,
             is_dashed: last.is_dashed,
             name: last.name,
             type_: simplify_expression(last.type_)?,
+            default,
         };
         let remaining = simplify_params_but_require_labels(remaining, &hetero_err)?;
         Ok(NonEmptyParamVec::UniquelyLabeled(NonEmptyVec::from_pushed(
             remaining, last,
         )))
     } else {
+        validate_unlabeled_param_has_no_default(&last)?;
         let last = UnlabeledParam {
             span: last.span,
+            is_instance: last.is_instance,
             is_dashed: last.is_dashed,
             name: last.name,
             type_: simplify_expression(last.type_)?,
@@ -190,8 +283,8 @@ fn validate_there_are_no_duplicate_param_labels(
         };
         if let Some(existing_param_with_same_name) = seen.get(&label_name).copied() {
             return Err(SimplifyAstError::DuplicateParamLabel(
-                param.clone(),
-                existing_param_with_same_name.clone(),
+                Box::new(param.clone()),
+                Box::new(existing_param_with_same_name.clone()),
             ));
         }
         seen.insert(label_name, param);
@@ -225,13 +318,16 @@ fn simplify_param_but_require_label(
 ) -> Result<LabeledParam, SimplifyAstError> {
     validate_param_label_is_not_underscore(&unsimplified)?;
 
-    if let Some(label) = unsimplified.label {
+    if let Some(label) = unsimplified.label.clone() {
+        let default = simplify_labeled_param_default(&unsimplified)?;
         Ok(LabeledParam {
             span: unsimplified.span,
+            is_instance: unsimplified.is_instance,
             label,
             is_dashed: unsimplified.is_dashed,
             name: unsimplified.name,
             type_: simplify_expression(unsimplified.type_)?,
+            default,
         })
     } else {
         Err(hetero_err.clone())
@@ -247,8 +343,10 @@ fn simplify_param_but_forbid_label(
     if let Some(_) = unsimplified.label {
         Err(hetero_err.clone())
     } else {
+        validate_unlabeled_param_has_no_default(&unsimplified)?;
         Ok(UnlabeledParam {
             span: unsimplified.span,
+            is_instance: unsimplified.is_instance,
             is_dashed: unsimplified.is_dashed,
             name: unsimplified.name,
             type_: simplify_expression(unsimplified.type_)?,
@@ -268,6 +366,30 @@ fn validate_param_label_is_not_underscore(param: &ust::Param) -> Result<(), Simp
     }
 }
 
+/// Only labeled params may have defaults, since only labeled args
+/// can be omitted.
+/// Instance params are excluded, since their omitted args are
+/// resolved from the instances in scope.
+fn simplify_labeled_param_default(
+    param: &ust::Param,
+) -> Result<Option<Expression>, SimplifyAstError> {
+    let Some(default) = &param.default else {
+        return Ok(None);
+    };
+    if param.is_instance {
+        return Err(SimplifyAstError::InstanceParamDefault(param.clone()));
+    }
+    Ok(Some(simplify_expression(default.clone())?))
+}
+
+fn validate_unlabeled_param_has_no_default(param: &ust::Param) -> Result<(), SimplifyAstError> {
+    if param.default.is_some() {
+        Err(SimplifyAstError::UnlabeledParamDefault(param.clone()))
+    } else {
+        Ok(())
+    }
+}
+
 fn simplify_variant(unsimplified: ust::Variant) -> Result<Variant, SimplifyAstError> {
     Ok(Variant {
         span: unsimplified.span,
@@ -286,6 +408,51 @@ fn simplify_let_statement(
         transparency: unsimplified.transparency,
         name: unsimplified.name,
         value: simplify_expression(unsimplified.value)?,
+        is_instance: unsimplified.is_instance,
+    })
+}
+
+fn simplify_notation_statement(
+    unsimplified: ust::NotationStatement,
+) -> Result<NotationStatement, SimplifyAstError> {
+    let function = match unsimplified.function {
+        ust::Expression::Identifier(identifier) => simplify_identifier(identifier),
+        ust::Expression::Dot(dot) => simplify_dot(dot)?,
+        other => return Err(SimplifyAstError::IllegalNotationFunction(other)),
+    };
+    let Expression::Name(function) = function else {
+        panic!("Impossible: Identifiers and dots should always simplify to name expressions.");
+    };
+    Ok(NotationStatement {
+        span: unsimplified.span,
+        visibility: unsimplified.visibility,
+        operator: unsimplified.operator,
+        function,
+        precedence: simplify_notation_precedence(unsimplified.precedence)?,
+        associativity: simplify_notation_associativity(unsimplified.associativity)?,
+    })
+}
+
+fn simplify_notation_precedence(
+    name: ust::Identifier,
+) -> Result<NotationPrecedence, SimplifyAstError> {
+    Ok(match name.name.src_str() {
+        "compare" => NotationPrecedence::Compare,
+        "sum" => NotationPrecedence::Sum,
+        "product" => NotationPrecedence::Product,
+        "power" => NotationPrecedence::Power,
+        _ => return Err(SimplifyAstError::UnknownNotationPrecedence(name)),
+    })
+}
+
+fn simplify_notation_associativity(
+    name: ust::Identifier,
+) -> Result<NotationAssociativity, SimplifyAstError> {
+    Ok(match name.name.src_str() {
+        "left" => NotationAssociativity::Left,
+        "right" => NotationAssociativity::Right,
+        "none" => NotationAssociativity::None,
+        _ => return Err(SimplifyAstError::UnknownNotationAssociativity(name)),
     })
 }
 
@@ -299,6 +466,13 @@ fn simplify_expression(unsimplified: ust::Expression) -> Result<Expression, Simp
         ust::Expression::Match(unsimplified) => simplify_match(*unsimplified)?,
         ust::Expression::Forall(unsimplified) => simplify_forall(*unsimplified)?,
         ust::Expression::Check(unsimplified) => simplify_check(*unsimplified)?,
+        ust::Expression::Rewrite(unsimplified) => simplify_rewrite(*unsimplified)?,
+        ust::Expression::Exists(unsimplified) => simplify_exists(*unsimplified)?,
+        ust::Expression::Pair(unsimplified) => simplify_pair(*unsimplified)?,
+        ust::Expression::InfixChain(unsimplified) => simplify_infix_chain(*unsimplified)?,
+        ust::Expression::Parenthesized(unsimplified) => {
+            simplify_expression(unsimplified.expression)?
+        }
     })
 }
 
@@ -469,10 +643,56 @@ fn simplify_match(unsimplified: ust::Match) -> Result<Expression, SimplifyAstErr
     Ok(Expression::Match(Box::new(Match {
         span: unsimplified.span,
         matchee: simplify_expression(unsimplified.matchee)?,
+        motive: unsimplified.motive.map(simplify_match_motive).transpose()?,
         cases: vec_result_map(unsimplified.cases, simplify_match_case)?,
     })))
 }
 
+fn simplify_match_motive(unsimplified: ust::MatchMotive) -> Result<MatchMotive, SimplifyAstError> {
+    let (matchee_type_name, index_names) =
+        simplify_match_motive_matchee_type(unsimplified.matchee_type)?;
+    Ok(MatchMotive {
+        span: unsimplified.span,
+        matchee_name: unsimplified.matchee_name,
+        matchee_type_name,
+        index_names,
+        output: simplify_expression(unsimplified.output)?,
+    })
+}
+
+fn simplify_match_motive_matchee_type(
+    unsimplified: ust::Expression,
+) -> Result<(NameExpression, Option<NonEmptyVec<Identifier>>), SimplifyAstError> {
+    let illegal_err = SimplifyAstError::IllegalMatchMotiveMatcheeType(unsimplified.clone());
+    let (callee, args) = match unsimplified {
+        ust::Expression::Call(call) => {
+            let call = *call;
+            (call.callee, Some(call.args))
+        }
+        other => (other, None),
+    };
+
+    let matchee_type_name = match callee {
+        ust::Expression::Identifier(identifier) => simplify_identifier(identifier),
+        ust::Expression::Dot(dot) => simplify_dot(dot)?,
+        _ => return Err(illegal_err),
+    };
+    let Expression::Name(matchee_type_name) = matchee_type_name else {
+        panic!("Impossible: Identifiers and dots should always simplify to name expressions.");
+    };
+
+    let index_names = args
+        .map(|args| {
+            args.try_into_mapped(|arg| match (arg.label, arg.value) {
+                (None, ust::Expression::Identifier(identifier)) => Ok(identifier),
+                _ => Err(illegal_err.clone()),
+            })
+        })
+        .transpose()?;
+
+    Ok((matchee_type_name, index_names))
+}
+
 fn simplify_match_case(unsimplified: ust::MatchCase) -> Result<MatchCase, SimplifyAstError> {
     Ok(MatchCase {
         span: unsimplified.span,
@@ -644,6 +864,90 @@ fn simplify_check(unsimplified: ust::Check) -> Result<Expression, SimplifyAstErr
     })))
 }
 
+fn simplify_rewrite(unsimplified: ust::Rewrite) -> Result<Expression, SimplifyAstError> {
+    Ok(Expression::Rewrite(Box::new(Rewrite {
+        span: unsimplified.span,
+        proof: simplify_expression(unsimplified.proof)?,
+        body: simplify_expression(unsimplified.body)?,
+    })))
+}
+
+/// An `exists` with multiple params is simplified into nested
+/// single-param `exists` expressions.
+/// For example, `exists(a: A, b: B) { P }` becomes
+/// `exists(a: A) { exists(b: B) { P } }`.
+fn simplify_exists(unsimplified: ust::Exists) -> Result<Expression, SimplifyAstError> {
+    if let Some(labeled_param) = unsimplified
+        .params
+        .iter()
+        .find(|param| param.label.is_some())
+    {
+        return Err(SimplifyAstError::LabeledExistsParam(labeled_param.clone()));
+    }
+
+    let span = unsimplified.span;
+    let mut output = simplify_expression(unsimplified.output)?;
+    let (mut remaining, mut last) = unsimplified.params.into_popped();
+    loop {
+        validate_unlabeled_param_has_no_default(&last)?;
+        let param = UnlabeledParam {
+            span: last.span,
+            is_instance: last.is_instance,
+            is_dashed: last.is_dashed,
+            name: last.name,
+            type_: simplify_expression(last.type_)?,
+        };
+        let Some(next_last) = remaining.pop() else {
+            return Ok(Expression::Exists(Box::new(Exists {
+                span,
+                param,
+                output,
+            })));
+        };
+        let inner_span = TextSpan {
+            file_id: span.file_id,
+            start: param.span.start,
+            end: span.end,
+        };
+        output = Expression::Exists(Box::new(Exists {
+            span: inner_span,
+            param,
+            output,
+        }));
+        last = next_last;
+    }
+}
+
+fn simplify_pair(unsimplified: ust::Pair) -> Result<Expression, SimplifyAstError> {
+    Ok(Expression::Pair(Box::new(Pair {
+        span: unsimplified.span,
+        witness: simplify_expression(unsimplified.witness)?,
+        proof: simplify_expression(unsimplified.proof)?,
+    })))
+}
+
+fn simplify_infix_chain(unsimplified: ust::InfixChain) -> Result<Expression, SimplifyAstError> {
+    Ok(Expression::InfixChain(Box::new(InfixChain {
+        span: unsimplified.span,
+        first_operand: simplify_infix_operand(unsimplified.first_operand)?,
+        rest: unsimplified
+            .rest
+            .try_into_mapped(|link| -> Result<_, SimplifyAstError> {
+                Ok(InfixChainLink {
+                    operator: link.operator,
+                    operand: simplify_infix_operand(link.operand)?,
+                })
+            })?,
+    })))
+}
+
+fn simplify_infix_operand(unsimplified: ust::Expression) -> Result<InfixOperand, SimplifyAstError> {
+    Ok(InfixOperand {
+        span: unsimplified.span(),
+        expression: simplify_expression(unsimplified)?,
+    })
+}
+
 fn simplify_check_assertion(
     unsimplified: ust::CheckAssertion,
 ) -> Result<CheckAssertion, SimplifyAstError> {
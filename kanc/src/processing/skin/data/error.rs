@@ -5,12 +5,17 @@ use crate::{
     processing::{lex::LexError, parse::ParseError},
 };
 
+use super::options::TraceCheckLocation;
+
 use std::path::PathBuf;
 
 #[derive(Debug)]
 pub enum InvalidCliArgsError {
     UnrecognizedFlag(String),
     MissingFlagValue(String),
+    ExpectedPositiveIntegerFlagValue(String, String),
+    MalformedTraceCheckLocation(String),
+    TraceCheckLocationNotFound(TraceCheckLocation),
     MutuallyExclusiveFlagsBothProvided(String, String),
     PackYsclPathDidNotEndWithPackYscl(PathBuf),
     SingleFilePathDidNotHaveKExtension(PathBuf),
@@ -37,7 +42,22 @@ pub enum InvalidCompilerOptionsError {
         key: String,
         value: yscl::prelude::Node,
     },
+    ExpectedListButGot {
+        key: String,
+        value: yscl::prelude::Node,
+    },
+    ExpectedMapButGot {
+        key: String,
+        value: yscl::prelude::Node,
+    },
+    UnrecognizedBigIntOperation {
+        key: String,
+    },
     IllegalKantuVersion(String),
+    IllegalName {
+        key: String,
+        value: String,
+    },
 }
 
 #[derive(Debug)]
@@ -75,5 +95,6 @@ pub enum WriteTargetFilesError {
     CannotRemoveTargetDir(PathBuf, std::io::Error),
     TargetDirExistsButIsNotDir(PathBuf),
     CannotCreateTargetDir(PathBuf, std::io::Error),
+    CannotCreateDir(PathBuf, std::io::Error),
     CannotWriteFile(PathBuf, std::io::Error),
 }
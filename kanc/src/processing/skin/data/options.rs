@@ -1,8 +1,31 @@
+use crate::{
+    data::{non_empty_vec::NonEmptyVec, simplified_ast::IdentifierName, text_span::TextCoord},
+    processing::{
+        bind_type_independent::{DesignatedBool, DesignatedEq},
+        type_check::BigIntOperation,
+    },
+};
+
 use std::path::PathBuf;
 
 #[derive(Clone, Debug)]
 pub struct CliOptions {
     pub pack_abs_path: PackPath,
+    pub show_normal_form_cache_stats: bool,
+    pub max_reduction_steps: Option<usize>,
+    pub profile_eval: bool,
+    pub trace_check: Option<TraceCheckLocation>,
+    pub stack_safe: bool,
+    pub optimize: bool,
+    pub check_args: bool,
+}
+
+/// The location of a check assertion whose reduction steps
+/// should be traced.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct TraceCheckLocation {
+    pub abs_path: PathBuf,
+    pub coord: TextCoord,
 }
 
 #[derive(Clone, Debug)]
@@ -18,6 +41,35 @@ pub struct CompilerOptions {
     pub kantu_version: KantuVersion,
     pub target_dir: PathBuf,
     pub show_db_indices: bool,
+    pub show_normal_form_cache_stats: bool,
+    pub max_reduction_steps: usize,
+    pub profile_eval: bool,
+    pub trace_check: Option<TraceCheckLocation>,
+    /// If true, the generated JavaScript doesn't overflow the call stack
+    /// on deep recursion.
+    pub stack_safe: bool,
+    /// If true, closed `let` values are precomputed,
+    /// and small functions are inlined.
+    pub optimize: bool,
+    /// If true, the exported JavaScript functions check their args,
+    /// and throw descriptive errors if the args are malformed.
+    pub check_args: bool,
+    /// Set by the `eq_type` and `eq_refl` entries of `pack.yscl`.
+    pub designated_eq: Option<DesignatedEq>,
+    /// Set by the `bool_type`, `bool_true`, and `bool_false` entries
+    /// of `pack.yscl`.
+    pub designated_bool: Option<DesignatedBool>,
+    /// Set by the `erased_types` entry of `pack.yscl`.
+    /// Each name starts with `pack` (e.g., `pack.eq.Eq`).
+    pub erased_types: Vec<NonEmptyVec<IdentifierName>>,
+    /// Set by the `bigint_types` entry of `pack.yscl`.
+    pub bigint_types: Vec<NonEmptyVec<IdentifierName>>,
+    /// Set by the `bigint_functions` entry of `pack.yscl`.
+    pub bigint_functions: Vec<(BigIntOperation, NonEmptyVec<IdentifierName>)>,
+    /// Set by the `entry_points` entry of `pack.yscl`.
+    /// If this is nonempty, only the items reachable from
+    /// these `let`s are emitted.
+    pub entry_points: Vec<NonEmptyVec<IdentifierName>>,
 }
 
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
@@ -1,7 +1,7 @@
 use crate::data::{
     file_id::*,
     file_tree::FileTree,
-    unsimplified_ast::{IdentifierName, ModStatement},
+    unsimplified_ast::{IdentifierName, ModStatement, PubClause},
 };
 
 use rustc_hash::FxHashMap;
@@ -105,9 +105,16 @@ impl From<TempFileTree> for FileTree {
     fn from(temp: TempFileTree) -> Self {
         let mut out = FileTree::from_root(temp.root);
         for (parent_id, child_map) in temp.children {
-            for (name, (_, child_id)) in child_map {
+            for (name, (mod_statement, child_id)) in child_map {
                 out.add_child(parent_id, &name, child_id)
                     .expect("TempFileTree should never contain duplicate children.");
+                if mod_statement
+                    .visibility
+                    .as_ref()
+                    .is_some_and(PubClause::is_global)
+                {
+                    out.mark_as_globally_visible(child_id);
+                }
             }
         }
         out
@@ -13,13 +13,19 @@ use crate::{
         variant_return_type_validation_result::IllegalVariantReturnTypeError,
     },
     processing::{
+        find_unreachable_items::{FindUnreachableItemsError, UnreachableItems},
         format_unsimplified,
-        generate_code::targets::javascript::CompileToJavaScriptError,
+        generate_code::targets::javascript::{
+            CompileToJavaScriptError, CompileToJavaScriptWarning,
+        },
         lex::LexError,
         parse::ParseError,
         simplify_ast::SimplifyAstError,
         test_utils::{expand_lightened::expand_expression, format as format_bound},
-        type_check::{TypeCheckError, TypeCheckWarning},
+        type_check::{
+            BigIntOperation, ReductionProfile, ReductionTrace, ReductionTraceEventKind,
+            TypeCheckError, TypeCheckWarning,
+        },
     },
 };
 
@@ -49,6 +55,18 @@ impl FormatErrorForCli<()> for InvalidCliArgsError {
             InvalidCliArgsError::MissingFlagValue(flag) => {
                 format!("[E0101] Expected value after flag: {}", flag)
             }
+            InvalidCliArgsError::ExpectedPositiveIntegerFlagValue(flag, value) => {
+                format!("[E0107] Expected a positive integer after flag {flag}, but received: {value}")
+            }
+            InvalidCliArgsError::MalformedTraceCheckLocation(location) => {
+                let flag = super::parse_cli_args::flags::TRACE_CHECK;
+                format!("[E0108] Expected a location of the form <path>:<line>:<col> after flag {flag}, but received: {location}")
+            }
+            InvalidCliArgsError::TraceCheckLocationNotFound(location) => {
+                let flag = super::parse_cli_args::flags::TRACE_CHECK;
+                let location_display = flc_display(&location.abs_path, location.coord);
+                format!("[E0109] The location passed to {flag} ({location_display}) does not refer to a character in any of the pack's files.")
+            }
             InvalidCliArgsError::CannotFindImplicitPackYsclPath => {
                 "[E0102] Cannot find pack.yscl in current working directory or any of its ancestors."
                     .to_string()
@@ -133,6 +151,33 @@ impl FormatErrorForCli<()> for InvalidCompilerOptionsError {
                     r#"[E0205] Illegal type for entry `{key}` in pack.yscl. Expected "true" or "false", got {value_display}."#
                 )
             }
+            InvalidCompilerOptionsError::IllegalName { key, value } => {
+                format!(
+                    "[E0206] Illegal name {value:?} for entry `{key}` in pack.yscl. Type names (e.g., in `eq_type`, `erased_types`, or `bigint_types`) must be dot-separated paths starting with `pack` (e.g., \"pack.Eq\"), and variant names (e.g., in `eq_refl`) must be single identifiers (e.g., \"refl\")."
+                )
+            }
+            InvalidCompilerOptionsError::ExpectedListButGot { key, value } => {
+                let value_display = yscl_node_display(value);
+                format!(
+                    "[E0207] Illegal type for entry `{key}` in pack.yscl. Expected a list, got {value_display}."
+                )
+            }
+            InvalidCompilerOptionsError::ExpectedMapButGot { key, value } => {
+                let value_display = yscl_node_display(value);
+                format!(
+                    "[E0208] Illegal type for entry `{key}` in pack.yscl. Expected a map, got {value_display}."
+                )
+            }
+            InvalidCompilerOptionsError::UnrecognizedBigIntOperation { key } => {
+                let operations_display = BigIntOperation::ALL
+                    .iter()
+                    .map(|operation| format!("`{}`", operation.key()))
+                    .collect::<Vec<_>>()
+                    .join(", ");
+                format!(
+                    "[E0209] Unrecognized operation `{key}` in the `bigint_functions` entry of pack.yscl. The supported operations are {operations_display}."
+                )
+            }
         }
     }
 }
@@ -334,6 +379,14 @@ impl<'a> FormatErrorForCli<&'a FilePathMap> for SimplifyAstError {
                 let loc2 = format_span_start(param2.span, file_path_map);
                 format!("[E0403] Multiple parameters have the label {name}. The first is at {loc1}. The second is at {loc2}.")
             }
+            SimplifyAstError::UnlabeledParamDefault(param) => {
+                let loc = format_span_start(param.span, file_path_map);
+                format!("[E0413] Only labeled parameters can have default values. There is an unlabeled parameter with a default value at {loc}.")
+            }
+            SimplifyAstError::InstanceParamDefault(param) => {
+                let loc = format_span_start(param.span, file_path_map);
+                format!("[E0414] Instance parameters cannot have default values, since their arguments are resolved from the instances in scope. There is an instance parameter with a default value at {loc}.")
+            }
 
             SimplifyAstError::HeterogeneousCallArgs(args) => {
                 let is_first_labeled = args[0].label.is_some();
@@ -394,6 +447,53 @@ impl<'a> FormatErrorForCli<&'a FilePathMap> for SimplifyAstError {
                 let loc2 = format_span_start(param2.span, file_path_map);
                 format!("[E0409] Multiple match case parameters have the label {name}. The first is at {loc1}. The second is at {loc2}.")
             }
+
+            SimplifyAstError::IllegalMatchMotiveMatcheeType(expr) => {
+                let loc = format_span_start(expr.span(), file_path_map);
+                let i0 = " ".repeat(INDENT_SIZE_IN_SPACES);
+                let formatted_type = format_unsimplified::format_expression(
+                    expr,
+                    1,
+                    &format_unsimplified::FormatOptions {
+                        ident_size_in_spaces: INDENT_SIZE_IN_SPACES,
+                    },
+                );
+                format!("[E0410] Illegal matchee type in match motive. The matchee type must be either a type name (e.g., `Nat`) or a type name applied to unlabeled identifiers (e.g., `Eq(_, a, b)`). At {loc} the following matchee type has been found:\n{i0}{formatted_type}")
+            }
+
+            SimplifyAstError::LabeledExistsParam(param) => {
+                let loc = format_span_start(param.span, file_path_map);
+                format!("[E0411] An `exists` parameter cannot be labeled. There is a labeled `exists` parameter at {loc}.")
+            }
+
+            SimplifyAstError::UnknownDerive(name) => {
+                let loc = format_span_start(name.span, file_path_map);
+                let name_display = name.name.src_str();
+                format!("[E0412] Cannot derive `{name_display}` at {loc}. Currently, the only derivable item is `eq`.")
+            }
+
+            SimplifyAstError::IllegalNotationFunction(expr) => {
+                let loc = format_span_start(expr.span(), file_path_map);
+                let i0 = " ".repeat(INDENT_SIZE_IN_SPACES);
+                let formatted_function = format_unsimplified::format_expression(
+                    expr,
+                    1,
+                    &format_unsimplified::FormatOptions {
+                        ident_size_in_spaces: INDENT_SIZE_IN_SPACES,
+                    },
+                );
+                format!("[E0415] Illegal notation function. A notation's function must be a name (e.g., `add` or `nat.add`). At {loc} the following function has been found:\n{i0}{formatted_function}")
+            }
+            SimplifyAstError::UnknownNotationPrecedence(name) => {
+                let loc = format_span_start(name.span, file_path_map);
+                let name_display = name.name.src_str();
+                format!("[E0416] Unknown notation precedence `{name_display}` at {loc}. The precedences (from loosest to tightest) are `compare`, `sum`, `product`, and `power`.")
+            }
+            SimplifyAstError::UnknownNotationAssociativity(name) => {
+                let loc = format_span_start(name.span, file_path_map);
+                let name_display = name.name.src_str();
+                format!("[E0417] Unknown notation associativity `{name_display}` at {loc}. The associativities are `left`, `right`, and `none`.")
+            }
         }
     }
 }
@@ -556,6 +656,59 @@ impl<'a> FormatErrorForCli<(&'a FilePathMap, &'a FileTree)> for BindError {
                     r#"[E0510] The transparency modifier at `{loc}` has a transparency of "{transp_display}", which is not a subset of the associated visibility (`{vis_display}`). An item's transparency must be a subset of its visibility."#
                 )
             }
+
+            BindError::RewriteWithoutDesignatedEq(RewriteWithoutDesignatedEqError { rewrite }) => {
+                let loc = format_span_start(rewrite.span, file_path_map);
+                format!(
+                    r#"[E0511] The `rewrite` expression at {loc} cannot be used, since the pack does not designate an equality type. To designate one, add `eq_type` and `eq_refl` entries to pack.yscl."#
+                )
+            }
+
+            BindError::DeriveWithoutDesignatedBool(DeriveWithoutDesignatedBoolError { derive }) => {
+                let loc = format_span_start(derive.span, file_path_map);
+                format!(
+                    r#"[E0512] The `derive` at {loc} cannot be used, since the pack does not designate a boolean type. To designate one, add `bool_type`, `bool_true`, and `bool_false` entries to pack.yscl."#
+                )
+            }
+
+            BindError::CannotDeriveEqForIndexedVariant(CannotDeriveEqForIndexedVariantError {
+                derive,
+                variant_name,
+                return_type,
+            }) => {
+                let derive_loc = format_span_start(derive.span, file_path_map);
+                let variant_name_display = variant_name.name.src_str();
+                let return_type_loc = format_span_start(return_type.span(), file_path_map);
+                format!(
+                    r#"[E0513] Cannot derive `eq` at {derive_loc}, since the variant `{variant_name_display}` has an indexed return type (at {return_type_loc}). A derived `eq` requires every variant's return type to apply the type to distinct variant params (e.g., `List(T)`)."#
+                )
+            }
+
+            BindError::CannotDeriveEqForField(CannotDeriveEqForFieldError {
+                derive,
+                field_name,
+                field_type,
+            }) => {
+                let derive_loc = format_span_start(derive.span, file_path_map);
+                let field_name_display = field_name.name.src_str();
+                let field_type_loc = format_span_start(field_type.span(), file_path_map);
+                format!(
+                    r#"[E0514] Cannot derive `eq` at {derive_loc}, since the field `{field_name_display}` has a type (at {field_type_loc}) that a derived `eq` cannot compare. A derived `eq` can only compare fields whose type is a `Type` param, the type itself, or a type name without args (e.g., `Nat`), and whose type does not mention an earlier field."#
+                )
+            }
+
+            BindError::AmbiguousInfixOperators(AmbiguousInfixOperatorsError {
+                left_operator,
+                right_operator,
+            }) => {
+                let left_loc = format_span_start(left_operator.span, file_path_map);
+                let left_display = left_operator.name.src_str();
+                let right_loc = format_span_start(right_operator.span, file_path_map);
+                let right_display = right_operator.name.src_str();
+                format!(
+                    r#"[E0515] The operators `{left_display}` (at {left_loc}) and `{right_display}` (at {right_loc}) have the same precedence, but they cannot be chained, since at least one of them is non-associative or they associate in different directions. Add parentheses to disambiguate."#
+                )
+            }
         }
     }
 }
@@ -581,6 +734,34 @@ impl<'a> FormatErrorForCli<&'a NodeRegistry> for TypePositivityError {
     }
 }
 
+impl<'a> FormatErrorForCli<(&'a FilePathMap, &'a NodeRegistry)> for FindUnreachableItemsError {
+    fn format_for_cli(&self, (file_path_map, registry): (&FilePathMap, &NodeRegistry)) -> String {
+        match self {
+            FindUnreachableItemsError::UndeclaredEntryPoint { name_components } => {
+                let name_display = name_components
+                    .iter()
+                    .map(|component| component.src_str())
+                    .collect::<Vec<_>>()
+                    .join(".");
+                format!("[E0900] The item `{name_display}` is listed in `entry_points` in pack.yscl, but no such item is declared.")
+            }
+
+            FindUnreachableItemsError::TypeEntryPoint {
+                name_components,
+                type_id,
+            } => {
+                let name_display = name_components
+                    .iter()
+                    .map(|component| component.src_str())
+                    .collect::<Vec<_>>()
+                    .join(".");
+                let loc = format_optional_span_start(registry.get(*type_id).span, file_path_map);
+                format!("[E0901] The item `{name_display}` at {loc} is listed in `entry_points` in pack.yscl, but it is a type. Every entry point must be a `let`.")
+            }
+        }
+    }
+}
+
 impl<'a>
     FormatErrorForCli<(
         &'a CompilerOptions,
@@ -599,9 +780,9 @@ impl<'a>
         ),
     ) -> String {
         match self {
-            TypeCheckError::ExpectedTermOfTypeType0OrType1 {
+            TypeCheckError::ExpectedTermOfTypeUniverse {
                 expression_id,
-                non_type0_or_type1_type_id,
+                non_universe_type_id,
             } => {
                 let loc = format_optional_span_start(
                     registry.expression_ref(*expression_id).span(),
@@ -610,11 +791,11 @@ impl<'a>
                 let indented_expr_display =
                     format_expression_with_one_indent(*expression_id, options, registry);
                 let indented_type_display = format_expression_with_one_indent(
-                    non_type0_or_type1_type_id.raw(),
+                    non_universe_type_id.raw(),
                     options,
                     registry,
                 );
-                format!("[E2000] Expected the term at {loc} to either be `Type` or some term of type `Type`. However, the expression was\n{indented_expr_display}\nand its type was\n{indented_type_display}.")
+                format!("[E2000] Expected the term at {loc} to be a type (i.e., a term whose type is a universe, such as `Type` or `Type1`). However, the expression was\n{indented_expr_display}\nand its type was\n{indented_type_display}.")
             }
 
             TypeCheckError::IllegalCallee {
@@ -910,6 +1091,214 @@ impl<'a>
                 let let_vis_display = mod_scope_display(let_vis.0, file_tree);
                 format!("[E2020] The `let` statement at {loc} has the type\n{indented_type_display}\nThis type expression contains the name `{name_display}`, which has a visibility of `{name_vis_display}`. This is illegal, since the `let` statement has a visibility of `{let_vis_display}`.")
             }
+
+            TypeCheckError::ReductionStepLimitExceeded {
+                expression_id,
+                unfolding_let_id,
+                max_reduction_steps,
+            } => {
+                let loc = format_optional_span_start(
+                    registry.expression_ref(*expression_id).span(),
+                    file_path_map,
+                );
+                let indented_expression_display =
+                    format_expression_with_one_indent(*expression_id, options, registry);
+                let unfolding_display = match unfolding_let_id {
+                    Some(let_id) => {
+                        let let_statement = registry.get(*let_id);
+                        let let_loc = format_optional_span_start(let_statement.span, file_path_map);
+                        let let_name = registry.get(let_statement.name_id).name.src_str();
+                        format!(" (while reducing the body of `{let_name}`, which is defined at {let_loc})")
+                    }
+                    None => "".to_string(),
+                };
+                let flag = super::parse_cli_args::flags::MAX_REDUCTION_STEPS;
+                format!("[E2021] Exceeded the limit of {max_reduction_steps} reduction steps{unfolding_display} while normalizing the expression\n{indented_expression_display}\nat {loc}.\nIf this expression really does need more steps, you can raise the limit with `{flag}`.")
+            }
+
+            TypeCheckError::MatchMotiveAdtMismatch {
+                motive_id,
+                matchee_type_id,
+            } => {
+                let motive = registry.get(*motive_id);
+                let loc = format_optional_span_start(motive.span, file_path_map);
+                let indented_motive_type_display = format_expression_with_one_indent(
+                    ExpressionId::Name(motive.matchee_type_name_id),
+                    options,
+                    registry,
+                );
+                let indented_matchee_type_display =
+                    format_expression_with_one_indent(matchee_type_id.raw(), options, registry);
+                format!("[E2022] The match motive at {loc} expects the matchee to have a type of\n{indented_motive_type_display}\nbut the matchee actually has a type of\n{indented_matchee_type_display}")
+            }
+
+            TypeCheckError::WrongNumberOfMatchMotiveIndices {
+                motive_id,
+                expected,
+                actual,
+            } => {
+                let loc = format_optional_span_start(registry.get(*motive_id).span, file_path_map);
+                let actual_pluralizer = pluralizing_s(*actual);
+                let expected_pluralizer = pluralizing_s(*expected);
+                format!("[E2023] The match motive at {loc} has {actual} index name{actual_pluralizer}, but the matchee's type has {expected} parameter{expected_pluralizer}. A match motive must have exactly one index name per parameter.")
+            }
+
+            TypeCheckError::CannotInferTypeOfRewrite(rewrite_id) => {
+                let loc = format_optional_span_start(registry.get(*rewrite_id).span, file_path_map);
+                format!("[E2024] Cannot infer the type of a `rewrite` expression at {loc}. A `rewrite` expression can only appear where its expected type is known (e.g., as a function body or a call argument).")
+            }
+
+            TypeCheckError::RewriteProofIsNotAnEquality {
+                rewrite_id,
+                proof_type_id,
+            } => {
+                let rewrite = registry.get(*rewrite_id);
+                let loc = format_optional_span_start(rewrite.span, file_path_map);
+                let indented_eq_type_display = format_expression_with_one_indent(
+                    ExpressionId::Name(rewrite.eq_type_name_id),
+                    options,
+                    registry,
+                );
+                let indented_proof_type_display =
+                    format_expression_with_one_indent(proof_type_id.raw(), options, registry);
+                format!("[E2025] The proof of the `rewrite` expression at {loc} has type\n{indented_proof_type_display}\nbut it must have a type of the form `Eq(..., left, right)`, where `Eq` is the pack's designated equality type\n{indented_eq_type_display}")
+            }
+
+            TypeCheckError::IllegalDesignatedEqType { rewrite_id } => {
+                let rewrite = registry.get(*rewrite_id);
+                let loc = format_optional_span_start(rewrite.span, file_path_map);
+                let indented_eq_type_display = format_expression_with_one_indent(
+                    ExpressionId::Name(rewrite.eq_type_name_id),
+                    options,
+                    registry,
+                );
+                format!("[E2026] The `rewrite` expression at {loc} uses the pack's designated equality type\n{indented_eq_type_display}\nbut that type is not an equality type. The designated equality type must have exactly one variant (the designated `eq_refl` variant), and the last two arguments of that variant's return type must be identical.")
+            }
+
+            TypeCheckError::ProjectionRecordIsNotAStruct {
+                projection_id,
+                record_type_id,
+            } => {
+                let projection = registry.get(*projection_id);
+                let loc = format_optional_span_start(projection.span, file_path_map);
+                let field_name = registry.get(projection.field_name_id).name.src_str();
+                let indented_record_type_display =
+                    format_expression_with_one_indent(record_type_id.raw(), options, registry);
+                format!("[E2027] Cannot project the field `{field_name}` at {loc}, because the projected expression has type\n{indented_record_type_display}\nwhich is not a `struct` type.")
+            }
+
+            TypeCheckError::StructHasNoSuchField {
+                projection_id,
+                record_type_id,
+            } => {
+                let projection = registry.get(*projection_id);
+                let loc = format_optional_span_start(projection.span, file_path_map);
+                let field_name = registry.get(projection.field_name_id).name.src_str();
+                let indented_record_type_display =
+                    format_expression_with_one_indent(record_type_id.raw(), options, registry);
+                format!("[E2028] Cannot project the field `{field_name}` at {loc}, because the struct type\n{indented_record_type_display}\nhas no field with that name.")
+            }
+
+            TypeCheckError::IllegalExistsMatchCases { match_id } => {
+                let loc = format_optional_span_start(registry.get(*match_id).span, file_path_map);
+                format!("[E2029] The match expression at {loc} has a matchee of an `exists` type, so it must have exactly one case, of the form `(witness, proof) => ...`.")
+            }
+
+            TypeCheckError::ExistsMatchHasMotive { motive_id } => {
+                let loc = format_optional_span_start(registry.get(*motive_id).span, file_path_map);
+                format!("[E2030] The match motive at {loc} belongs to a match expression whose matchee has an `exists` type. Match motives are only supported for matchees of `type` types.")
+            }
+
+            TypeCheckError::InstanceTypeIsNotATrait {
+                expression_id,
+                type_id,
+            } => {
+                let loc = format_optional_span_start(
+                    registry.expression_ref(*expression_id).span(),
+                    file_path_map,
+                );
+                let indented_type_display =
+                    format_expression_with_one_indent(type_id.raw(), options, registry);
+                format!("[E2031] The instance at {loc} has type\n{indented_type_display}\nbut every instance must have a trait type (i.e., a type declared with `trait`, applied to its args).")
+            }
+
+            TypeCheckError::MissingInstance {
+                call_id,
+                instance_type_id,
+            } => {
+                let loc = format_optional_span_start(registry.get(*call_id).span, file_path_map);
+                let indented_type_display =
+                    format_expression_with_one_indent(instance_type_id.raw(), options, registry);
+                format!("[E2032] The call at {loc} omits an instance arg of type\n{indented_type_display}\nbut there is no instance of that type in scope.")
+            }
+
+            TypeCheckError::AmbiguousInstance {
+                call_id,
+                instance_type_id,
+                candidate_list_id,
+            } => {
+                let loc = format_optional_span_start(registry.get(*call_id).span, file_path_map);
+                let indented_type_display =
+                    format_expression_with_one_indent(instance_type_id.raw(), options, registry);
+                let candidates_display = registry
+                    .get_list(*candidate_list_id)
+                    .iter()
+                    .map(|candidate_id| {
+                        format_expression_with_one_indent(*candidate_id, options, registry)
+                    })
+                    .collect::<Vec<_>>()
+                    .join("\n");
+                format!("[E2033] The call at {loc} omits an instance arg of type\n{indented_type_display}\nbut there are multiple instances of that type in scope:\n{candidates_display}\nPlease supply the instance arg explicitly.")
+            }
+
+            TypeCheckError::IllegalErasedType { type_statement_id } => {
+                let type_statement = registry.get(*type_statement_id);
+                let loc = format_optional_span_start(type_statement.span, file_path_map);
+                let name_display = registry.get(type_statement.name_id).name.src_str();
+                format!("[E2034] The type `{name_display}` at {loc} is listed in `erased_types` in pack.yscl, but it has more than one variant. Erased types must have at most one variant.")
+            }
+
+            TypeCheckError::UndeclaredErasedType { name_components } => {
+                let name_display = name_components
+                    .iter()
+                    .map(|component| component.src_str())
+                    .collect::<Vec<_>>()
+                    .join(".");
+                format!("[E2035] The type `{name_display}` is listed in `erased_types` in pack.yscl, but no such type is declared.")
+            }
+
+            TypeCheckError::IllegalBigIntType { type_statement_id } => {
+                let type_statement = registry.get(*type_statement_id);
+                let loc = format_optional_span_start(type_statement.span, file_path_map);
+                let name_display = registry.get(type_statement.name_id).name.src_str();
+                format!("[E2036] The type `{name_display}` at {loc} is listed in `bigint_types` in pack.yscl, but it does not have the shape of a natural number, positive number, or integer type. A BigInt type must have no params and exactly two variants, which must be either `zero` and `succ(n: Self)`, `one` and `extend(left: Self, right: Bit)`, `zero` and `pos(p: Pos)`, or `neg(p: Pos)` and `nat(n: Nat)` (in any order and with any names), where `Bit` has exactly two nullary variants, and `Pos` and `Nat` are BigInt types listed earlier in `bigint_types`.")
+            }
+
+            TypeCheckError::IllegalBigIntFunction {
+                let_statement_id,
+                operation,
+            } => {
+                let let_statement = registry.get(*let_statement_id);
+                let loc = format_optional_span_start(let_statement.span, file_path_map);
+                let name_display = registry.get(let_statement.name_id).name.src_str();
+                let key = operation.key();
+                let (output_display, bool_note) = match operation {
+                    BigIntOperation::Add | BigIntOperation::Mul => ("T", ""),
+                    BigIntOperation::Eq | BigIntOperation::Lt => {
+                        ("Bool", ", and `Bool` is the type designated by `bool_type`")
+                    }
+                };
+                format!("[E2037] The function `{name_display}` at {loc} is listed under `{key}` in the `bigint_functions` entry of pack.yscl, so it must have a type of the form `forall(a: T, b: T) {{ {output_display} }}`, where `T` is a type listed in `bigint_types`{bool_note}.")
+            }
+
+            TypeCheckError::UndeclaredBigIntItem { name_components } => {
+                let name_display = name_components
+                    .iter()
+                    .map(|component| component.src_str())
+                    .collect::<Vec<_>>()
+                    .join(".");
+                format!("[E2038] The item `{name_display}` is listed in `bigint_types` or `bigint_functions` in pack.yscl, but no such item is declared.")
+            }
         }
     }
 }
@@ -974,6 +1363,22 @@ impl<'a> FormatErrorForCli<&'a NodeRegistry> for CompileToJavaScriptError {
     }
 }
 
+impl<'a> FormatErrorForCli<(&'a FilePathMap, &'a NodeRegistry)> for CompileToJavaScriptWarning {
+    fn format_for_cli(&self, (file_path_map, registry): (&FilePathMap, &NodeRegistry)) -> String {
+        match self {
+            CompileToJavaScriptWarning::StackUnsafeSelfCall { fun_id, call_id } => {
+                let fun = registry.get(*fun_id);
+                let name = registry.get(fun.name_id).name.src_str();
+                let call_loc =
+                    format_optional_span_start(registry.get(*call_id).span, file_path_map);
+                let fun_loc = format_optional_span_start(fun.span, file_path_map);
+                let flag = super::parse_cli_args::flags::STACK_SAFE;
+                format!("[W3000] The call at {call_loc} calls `{name}` (declared at {fun_loc}) from a nested fun, so it uses the JavaScript call stack even with {flag}. Deep recursion through it may overflow the stack.")
+            }
+        }
+    }
+}
+
 impl<'a> FormatErrorForCli<&'a NodeRegistry> for TypeCheckWarning {
     fn format_for_cli(&self, _registry: &NodeRegistry) -> String {
         // TODO: Improve error message formatting.
@@ -988,6 +1393,134 @@ impl<'a> FormatErrorForCli<&'a NodeRegistry> for WriteTargetFilesError {
     }
 }
 
+/// Lists the `let` statements that took the most reduction steps.
+pub fn format_reduction_profile_for_cli(
+    profile: &ReductionProfile,
+    registry: &NodeRegistry,
+    file_path_map: &FilePathMap,
+) -> String {
+    const MAX_LISTED_LETS: usize = 20;
+
+    let lets = profile.lets_by_descending_steps();
+    let i0 = " ".repeat(INDENT_SIZE_IN_SPACES);
+    let steps_width = lets
+        .first()
+        .map(|(_, steps)| steps.to_string().len())
+        .unwrap_or(0);
+    let mut out = format!(
+        "Reduction steps by `let` statement (top {} of {}):\n",
+        lets.len().min(MAX_LISTED_LETS),
+        lets.len()
+    );
+    for (let_id, steps) in lets.into_iter().take(MAX_LISTED_LETS) {
+        let let_statement = registry.get(let_id);
+        let name = registry.get(let_statement.name_id).name.src_str();
+        let loc = format_optional_span_start(let_statement.span, file_path_map);
+        out.push_str(&format!("{i0}{steps:>steps_width$}  {name} ({loc})\n"));
+    }
+    out.push_str(&format!(
+        "Total reduction steps: {}\n",
+        profile.total_steps()
+    ));
+    out
+}
+
+/// Lists the reduction steps taken while normalizing
+/// the comparees of the traced check assertion.
+pub fn format_reduction_trace_for_cli(
+    trace: Option<&ReductionTrace>,
+    options: &CompilerOptions,
+    registry: &NodeRegistry,
+    file_path_map: &FilePathMap,
+) -> String {
+    let Some(trace) = trace else {
+        let location_display = options
+            .trace_check
+            .as_ref()
+            .map(|location| flc_display(&location.abs_path, location.coord))
+            .unwrap_or_else(|| "<LOCATION_NOT_FOUND>".to_string());
+        return format!(
+            "No check assertion was found at {location_display}, so nothing was traced.\n"
+        );
+    };
+
+    let i0 = " ".repeat(INDENT_SIZE_IN_SPACES);
+    let loc = format_optional_span_start(registry.get(trace.assertion_id).span, file_path_map);
+    let mut out = format!("Reduction trace for the check assertion at {loc}:\n");
+    if trace.normalizations.is_empty() {
+        out.push_str("No comparees were normalized.\n");
+        return out;
+    }
+
+    for normalization in &trace.normalizations {
+        let steps = normalization
+            .events
+            .iter()
+            .filter(|event| {
+                matches!(
+                    event.kind,
+                    ReductionTraceEventKind::Beta(_) | ReductionTraceEventKind::MatchIota(_)
+                )
+            })
+            .count();
+        out.push_str(&format!(
+            "Normalizing\n{}\ntook {steps} reduction step{}:\n",
+            format_expression_with_one_indent(normalization.expression_id, options, registry),
+            pluralizing_s(steps),
+        ));
+        for event in &normalization.events {
+            let indent = i0.repeat(event.depth + 1);
+            let event_display = match event.kind {
+                ReductionTraceEventKind::Delta(let_id) => {
+                    let name = registry.get(registry.get(let_id).name_id).name.src_str();
+                    format!("delta: unfolded `{name}`")
+                }
+                ReductionTraceEventKind::Beta(fun_name_id) => {
+                    let name = registry.get(fun_name_id).name.src_str();
+                    format!("beta: applied `{name}`")
+                }
+                ReductionTraceEventKind::MatchIota(variant_name_id) => {
+                    let name = registry.get(variant_name_id).name.src_str();
+                    format!("match-iota: selected the `{name}` case")
+                }
+                ReductionTraceEventKind::ProjectionIota(field_name_id) => {
+                    let name = registry.get(field_name_id).name.src_str();
+                    format!("projection-iota: selected the `{name}` field")
+                }
+                ReductionTraceEventKind::DeltaBlockedByTransparency(let_id) => {
+                    let name = registry.get(registry.get(let_id).name_id).name.src_str();
+                    format!("blocked: did not unfold `{name}` because it is not transparent enough")
+                }
+                ReductionTraceEventKind::BetaBlockedByDecreasingArg(fun_name_id) => {
+                    let name = registry.get(fun_name_id).name.src_str();
+                    format!("blocked: did not apply `{name}` because its decreasing argument is not a variant")
+                }
+            };
+            out.push_str(&format!("{indent}{event_display}\n"));
+        }
+        match normalization.normal_form_id {
+            Some(normal_form_id) => out.push_str(&format!(
+                "and produced the normal form\n{}\n",
+                format_expression_with_one_indent(normal_form_id.raw(), options, registry),
+            )),
+            None => out.push_str("and did not finish.\n"),
+        }
+    }
+    out
+}
+
+/// Reports how many items were omitted from the generated code
+/// because they were unreachable from the entry points.
+pub fn format_unreachable_item_counts_for_cli(unreachable_items: &UnreachableItems) -> String {
+    let type_count = unreachable_items.type_count();
+    let let_count = unreachable_items.let_count();
+    format!(
+        "Removed {type_count} unreachable type{} and {let_count} unreachable let{}.\n",
+        pluralizing_s(type_count),
+        pluralizing_s(let_count)
+    )
+}
+
 fn flc_display(path: &Path, coord: TextCoord) -> String {
     format!("{}:{}:{}", path.display(), coord.line, coord.col)
 }
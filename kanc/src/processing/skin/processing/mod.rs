@@ -2,5 +2,7 @@ pub mod format;
 pub mod parse_cli_args;
 pub mod read_compiler_options;
 pub mod read_kantu_files;
+pub mod read_source_files;
+pub mod resolve_trace_check_location;
 pub mod test_utils;
 pub mod write_target_files;
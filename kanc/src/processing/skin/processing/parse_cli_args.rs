@@ -1,5 +1,7 @@
 use super::super::data::prelude::*;
 
+use crate::data::text_span::TextCoord;
+
 use std::path::{Path, PathBuf};
 
 use path_clean::PathClean;
@@ -7,12 +9,26 @@ use path_clean::PathClean;
 pub mod flags {
     pub const PACK_YSCL: &str = "--pack";
     pub const SINGLE_FILE: &str = "--file";
+    pub const SHOW_NORMAL_FORM_CACHE_STATS: &str = "--show-normal-form-cache-stats";
+    pub const MAX_REDUCTION_STEPS: &str = "--max-reduction-steps";
+    pub const PROFILE_EVAL: &str = "--profile-eval";
+    pub const TRACE_CHECK: &str = "--trace-check";
+    pub const STACK_SAFE: &str = "--stack-safe";
+    pub const OPTIMIZE: &str = "--optimize";
+    pub const CHECK_ARGS: &str = "--check-args";
 }
 
 pub fn parse_args(args: &[String]) -> Result<CliOptions, InvalidCliArgsError> {
     let mut remaining = args.iter().skip(1);
     let mut pack_yscl_path: Option<String> = None;
     let mut single_file_path: Option<String> = None;
+    let mut show_normal_form_cache_stats = false;
+    let mut max_reduction_steps: Option<usize> = None;
+    let mut profile_eval = false;
+    let mut trace_check: Option<String> = None;
+    let mut stack_safe = false;
+    let mut optimize = false;
+    let mut check_args = false;
 
     while let Some(arg) = remaining.next() {
         if arg == flags::PACK_YSCL {
@@ -31,6 +47,40 @@ pub fn parse_args(args: &[String]) -> Result<CliOptions, InvalidCliArgsError> {
                     flags::SINGLE_FILE.to_string(),
                 ));
             }
+        } else if arg == flags::SHOW_NORMAL_FORM_CACHE_STATS {
+            show_normal_form_cache_stats = true;
+        } else if arg == flags::MAX_REDUCTION_STEPS {
+            if let Some(value) = remaining.next() {
+                match value.parse::<usize>() {
+                    Ok(steps) if steps > 0 => max_reduction_steps = Some(steps),
+                    _ => {
+                        return Err(InvalidCliArgsError::ExpectedPositiveIntegerFlagValue(
+                            flags::MAX_REDUCTION_STEPS.to_string(),
+                            value.clone(),
+                        ));
+                    }
+                }
+            } else {
+                return Err(InvalidCliArgsError::MissingFlagValue(
+                    flags::MAX_REDUCTION_STEPS.to_string(),
+                ));
+            }
+        } else if arg == flags::PROFILE_EVAL {
+            profile_eval = true;
+        } else if arg == flags::TRACE_CHECK {
+            if let Some(location) = remaining.next() {
+                trace_check = Some(location.clone());
+            } else {
+                return Err(InvalidCliArgsError::MissingFlagValue(
+                    flags::TRACE_CHECK.to_string(),
+                ));
+            }
+        } else if arg == flags::STACK_SAFE {
+            stack_safe = true;
+        } else if arg == flags::OPTIMIZE {
+            optimize = true;
+        } else if arg == flags::CHECK_ARGS {
+            check_args = true;
         } else {
             return Err(InvalidCliArgsError::UnrecognizedFlag(arg.clone()));
         }
@@ -96,7 +146,46 @@ pub fn parse_args(args: &[String]) -> Result<CliOptions, InvalidCliArgsError> {
         }
     };
 
-    Ok(CliOptions { pack_abs_path })
+    let trace_check = trace_check
+        .map(|location| parse_trace_check_location(&location, &abs_cwd))
+        .transpose()?;
+
+    Ok(CliOptions {
+        pack_abs_path,
+        show_normal_form_cache_stats,
+        max_reduction_steps,
+        profile_eval,
+        trace_check,
+        stack_safe,
+        optimize,
+        check_args,
+    })
+}
+
+/// Parses a location of the form `<path>:<line>:<col>`
+/// (i.e., the same form used to display locations).
+fn parse_trace_check_location(
+    location: &str,
+    abs_cwd: &Path,
+) -> Result<TraceCheckLocation, InvalidCliArgsError> {
+    let malformed = || InvalidCliArgsError::MalformedTraceCheckLocation(location.to_string());
+
+    let mut parts = location.rsplitn(3, ':');
+    let col = parts.next().and_then(|col| col.parse::<usize>().ok());
+    let line = parts.next().and_then(|line| line.parse::<usize>().ok());
+    let path = parts.next().filter(|path| !path.is_empty());
+    let (Some(path), Some(line), Some(col)) = (path, line, col) else {
+        return Err(malformed());
+    };
+    if line == 0 {
+        return Err(malformed());
+    }
+
+    let p = PathBuf::from(path);
+    Ok(TraceCheckLocation {
+        abs_path: if p.is_absolute() { p } else { abs_cwd.join(p) }.clean(),
+        coord: TextCoord { line, col },
+    })
 }
 
 fn get_default_pack_yscl_path(abs_cwd: &Path) -> Option<PathBuf> {
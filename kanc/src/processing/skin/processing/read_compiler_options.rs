@@ -1,5 +1,14 @@
 use super::super::data::prelude::*;
 
+use crate::{
+    data::{non_empty_vec::NonEmptyVec, simplified_ast::IdentifierName, token::TokenKind},
+    processing::{
+        bind_type_independent::{DesignatedBool, DesignatedEq},
+        lex::lex,
+        type_check::{BigIntOperation, DEFAULT_MAX_REDUCTION_STEPS},
+    },
+};
+
 use std::{fs, path::Path};
 
 use yscl::{prelude::parse_doc, tree as yt};
@@ -7,19 +16,38 @@ use yscl::{prelude::parse_doc, tree as yt};
 mod pack_keys {
     pub const VERSION: &str = "kantu_version";
     pub const SHOW_DB_INDICES: &str = "show_db_indices";
+    pub const EQ_TYPE: &str = "eq_type";
+    pub const EQ_REFL: &str = "eq_refl";
+    pub const BOOL_TYPE: &str = "bool_type";
+    pub const BOOL_TRUE: &str = "bool_true";
+    pub const BOOL_FALSE: &str = "bool_false";
+    pub const ERASED_TYPES: &str = "erased_types";
+    pub const BIGINT_TYPES: &str = "bigint_types";
+    pub const BIGINT_FUNCTIONS: &str = "bigint_functions";
+    pub const ENTRY_POINTS: &str = "entry_points";
 }
 
 pub fn read_compiler_options(
     options: &CliOptions,
 ) -> Result<CompilerOptions, InvalidCompilerOptionsError> {
-    match &options.pack_abs_path {
+    let mut compiler_options = match &options.pack_abs_path {
         PackPath::SingleFile(single_file_abs_path) => {
             read_compiler_options_from_single_file_path(single_file_abs_path)
         }
         PackPath::PackYscl(pack_yscl_abs_path) => {
             read_compiler_options_from_pack_yscl_path(pack_yscl_abs_path)
         }
+    }?;
+    compiler_options.show_normal_form_cache_stats = options.show_normal_form_cache_stats;
+    if let Some(max_reduction_steps) = options.max_reduction_steps {
+        compiler_options.max_reduction_steps = max_reduction_steps;
     }
+    compiler_options.profile_eval = options.profile_eval;
+    compiler_options.trace_check = options.trace_check.clone();
+    compiler_options.stack_safe = options.stack_safe;
+    compiler_options.optimize = options.optimize;
+    compiler_options.check_args = options.check_args;
+    Ok(compiler_options)
 }
 
 fn read_compiler_options_from_single_file_path(
@@ -38,6 +66,19 @@ fn get_default_options(
             .with_file_name("target")
             .with_extension(""),
         show_db_indices: true,
+        show_normal_form_cache_stats: false,
+        max_reduction_steps: DEFAULT_MAX_REDUCTION_STEPS,
+        profile_eval: false,
+        trace_check: None,
+        stack_safe: false,
+        optimize: false,
+        check_args: false,
+        designated_eq: None,
+        designated_bool: None,
+        erased_types: vec![],
+        bigint_types: vec![],
+        bigint_functions: vec![],
+        entry_points: vec![],
     })
 }
 
@@ -62,7 +103,9 @@ fn build_options(
 ) -> Result<CompilerOptions, InvalidCompilerOptionsError> {
     let kantu_version = get_required_str_entry(pack, pack_keys::VERSION)?;
     let Some(kantu_version) = KantuVersion::new(&kantu_version) else {
-        return Err(InvalidCompilerOptionsError::IllegalKantuVersion(kantu_version));
+        return Err(InvalidCompilerOptionsError::IllegalKantuVersion(
+            kantu_version,
+        ));
     };
 
     let target_dir = pack_yscl_abs_path
@@ -93,14 +136,211 @@ fn build_options(
         }
         None => false,
     };
+
+    let designated_eq = get_designated_eq(pack)?;
+    let designated_bool = get_designated_bool(pack)?;
+    let erased_types = get_name_list(pack, pack_keys::ERASED_TYPES)?;
+    let bigint_types = get_name_list(pack, pack_keys::BIGINT_TYPES)?;
+    let bigint_functions = get_bigint_functions(pack)?;
+    let entry_points = get_name_list(pack, pack_keys::ENTRY_POINTS)?;
+
     Ok(CompilerOptions {
         pack_abs_path: PackPath::PackYscl(pack_yscl_abs_path.to_owned()),
         kantu_version,
         target_dir,
         show_db_indices,
+        show_normal_form_cache_stats: false,
+        max_reduction_steps: DEFAULT_MAX_REDUCTION_STEPS,
+        profile_eval: false,
+        trace_check: None,
+        stack_safe: false,
+        optimize: false,
+        check_args: false,
+        designated_eq,
+        designated_bool,
+        erased_types,
+        bigint_types,
+        bigint_functions,
+        entry_points,
     })
 }
 
+/// The `eq_type` and `eq_refl` entries must either
+/// both be present or both be absent.
+fn get_designated_eq(pack: &yt::Map) -> Result<Option<DesignatedEq>, InvalidCompilerOptionsError> {
+    if pack.get(pack_keys::EQ_TYPE).is_none() && pack.get(pack_keys::EQ_REFL).is_none() {
+        return Ok(None);
+    }
+
+    let eq_type = get_required_str_entry(pack, pack_keys::EQ_TYPE)?;
+    let eq_refl = get_required_str_entry(pack, pack_keys::EQ_REFL)?;
+
+    let type_name_components = parse_pack_relative_name(&eq_type).ok_or_else(|| {
+        InvalidCompilerOptionsError::IllegalName {
+            key: pack_keys::EQ_TYPE.to_string(),
+            value: eq_type.clone(),
+        }
+    })?;
+    let refl_variant_name = parse_identifier_name(&eq_refl).ok_or_else(|| {
+        InvalidCompilerOptionsError::IllegalName {
+            key: pack_keys::EQ_REFL.to_string(),
+            value: eq_refl.clone(),
+        }
+    })?;
+
+    Ok(Some(DesignatedEq {
+        type_name_components,
+        refl_variant_name,
+    }))
+}
+
+/// The `bool_type`, `bool_true`, and `bool_false` entries must either
+/// all be present or all be absent.
+fn get_designated_bool(
+    pack: &yt::Map,
+) -> Result<Option<DesignatedBool>, InvalidCompilerOptionsError> {
+    if pack.get(pack_keys::BOOL_TYPE).is_none()
+        && pack.get(pack_keys::BOOL_TRUE).is_none()
+        && pack.get(pack_keys::BOOL_FALSE).is_none()
+    {
+        return Ok(None);
+    }
+
+    let bool_type = get_required_str_entry(pack, pack_keys::BOOL_TYPE)?;
+    let bool_true = get_required_str_entry(pack, pack_keys::BOOL_TRUE)?;
+    let bool_false = get_required_str_entry(pack, pack_keys::BOOL_FALSE)?;
+
+    let type_name_components = parse_pack_relative_name(&bool_type).ok_or_else(|| {
+        InvalidCompilerOptionsError::IllegalName {
+            key: pack_keys::BOOL_TYPE.to_string(),
+            value: bool_type.clone(),
+        }
+    })?;
+    let true_variant_name = parse_identifier_name(&bool_true).ok_or_else(|| {
+        InvalidCompilerOptionsError::IllegalName {
+            key: pack_keys::BOOL_TRUE.to_string(),
+            value: bool_true.clone(),
+        }
+    })?;
+    let false_variant_name = parse_identifier_name(&bool_false).ok_or_else(|| {
+        InvalidCompilerOptionsError::IllegalName {
+            key: pack_keys::BOOL_FALSE.to_string(),
+            value: bool_false.clone(),
+        }
+    })?;
+
+    Ok(Some(DesignatedBool {
+        type_name_components,
+        true_variant_name,
+        false_variant_name,
+    }))
+}
+
+/// The `bigint_functions` entry is optional.
+/// If present, it must be a map from operations (e.g., `add`)
+/// to lists of paths.
+fn get_bigint_functions(
+    pack: &yt::Map,
+) -> Result<Vec<(BigIntOperation, NonEmptyVec<IdentifierName>)>, InvalidCompilerOptionsError> {
+    let Some(value) = pack.get(pack_keys::BIGINT_FUNCTIONS) else {
+        return Ok(vec![]);
+    };
+    let yt::NodeRef::Map(map) = value.as_ref() else {
+        return Err(InvalidCompilerOptionsError::ExpectedMapButGot {
+            key: pack_keys::BIGINT_FUNCTIONS.to_string(),
+            value: value.clone(),
+        });
+    };
+    let mut out = vec![];
+    for entry in &map.entries {
+        let Some(operation) = BigIntOperation::ALL
+            .into_iter()
+            .find(|operation| operation.key() == entry.key.as_ref())
+        else {
+            return Err(InvalidCompilerOptionsError::UnrecognizedBigIntOperation {
+                key: entry.key.as_ref().to_string(),
+            });
+        };
+        let key = format!("{}.{}", pack_keys::BIGINT_FUNCTIONS, operation.key());
+        let names = get_name_list_from_value(&entry.value, &key)?;
+        out.extend(names.into_iter().map(|name| (operation, name)));
+    }
+    Ok(out)
+}
+
+/// The entry with the given key is optional.
+/// If present, it must be a list of paths.
+fn get_name_list(
+    pack: &yt::Map,
+    key: &str,
+) -> Result<Vec<NonEmptyVec<IdentifierName>>, InvalidCompilerOptionsError> {
+    match pack.get(key) {
+        Some(value) => get_name_list_from_value(value, key),
+        None => Ok(vec![]),
+    }
+}
+
+fn get_name_list_from_value(
+    value: &yt::Node,
+    key: &str,
+) -> Result<Vec<NonEmptyVec<IdentifierName>>, InvalidCompilerOptionsError> {
+    let yt::NodeRef::List(list) = value.as_ref() else {
+        return Err(InvalidCompilerOptionsError::ExpectedListButGot {
+            key: key.to_string(),
+            value: value.clone(),
+        });
+    };
+    list.elements
+        .iter()
+        .map(|element| {
+            let yt::Node::Atom(atom) = element else {
+                return Err(InvalidCompilerOptionsError::ExpectedAtomButGotCollection {
+                    key: key.to_string(),
+                    collection: element.clone(),
+                });
+            };
+            parse_pack_relative_name(&atom.value).ok_or_else(|| {
+                InvalidCompilerOptionsError::IllegalName {
+                    key: key.to_string(),
+                    value: atom.value.clone(),
+                }
+            })
+        })
+        .collect()
+}
+
+/// Parses a dot-separated name that starts with `pack`
+/// (e.g., `pack.eq.Eq`).
+fn parse_pack_relative_name(s: &str) -> Option<NonEmptyVec<IdentifierName>> {
+    let mut components = s.split('.');
+    if components.next() != Some("pack") {
+        return None;
+    }
+    let rest = components
+        .map(parse_identifier_name)
+        .collect::<Option<Vec<_>>>()?;
+    if rest.is_empty() {
+        return None;
+    }
+    let mut out = NonEmptyVec::singleton(IdentifierName::new("pack".to_string()));
+    out.extend(rest);
+    Some(out)
+}
+
+fn parse_identifier_name(s: &str) -> Option<IdentifierName> {
+    let tokens = lex(s).ok()?;
+    match tokens.as_slice() {
+        [identifier, eoi]
+            if identifier.kind == TokenKind::StandardIdentifier
+                && identifier.content == s
+                && eoi.kind == TokenKind::Eoi =>
+        {
+            Some(IdentifierName::new(s.to_string()))
+        }
+        _ => None,
+    }
+}
+
 fn get_required_str_entry(
     pack: &yt::Map,
     key: &str,
@@ -0,0 +1,34 @@
+use super::super::data::prelude::*;
+
+use crate::{
+    data::file_id::*,
+    processing::generate_code::targets::javascript::source_map::{SourceFile, SourceFiles},
+};
+
+use std::{fs, path::PathBuf};
+
+use rustc_hash::FxHashMap;
+
+/// Reads the files in the file path map, so that the generated code
+/// can resolve spans into locations.
+/// Paths are made relative to the pack directory
+/// (i.e., the parent of the target directory),
+/// so the generated code doesn't depend on where the pack is.
+pub fn read_source_files(
+    options: &CompilerOptions,
+    file_path_map: &FxHashMap<FileId, PathBuf>,
+) -> SourceFiles {
+    let pack_dir = options.target_dir.parent();
+    file_path_map
+        .iter()
+        .map(|(&file_id, abs_path)| {
+            let src = fs::read_to_string(abs_path)
+                .expect("[E9900] File path held in file path map should be valid.");
+            let path = pack_dir
+                .and_then(|pack_dir| abs_path.strip_prefix(pack_dir).ok())
+                .unwrap_or(abs_path)
+                .to_path_buf();
+            (file_id, SourceFile { path, src })
+        })
+        .collect()
+}
@@ -0,0 +1,32 @@
+use super::super::data::prelude::*;
+
+use crate::data::{file_id::*, text_span::*};
+
+use std::{fs, path::PathBuf};
+
+use rustc_hash::FxHashMap;
+
+/// Converts the `--trace-check` location (if any) into a span
+/// that the type checker can compare against check assertion spans.
+pub fn resolve_trace_check_location(
+    options: &CompilerOptions,
+    file_path_map: &FxHashMap<FileId, PathBuf>,
+) -> Result<Option<TextSpan>, InvalidCliArgsError> {
+    let Some(location) = &options.trace_check else {
+        return Ok(None);
+    };
+
+    let not_found = || InvalidCliArgsError::TraceCheckLocationNotFound(location.clone());
+    let file_id = file_path_map
+        .iter()
+        .find(|(_, path)| **path == location.abs_path)
+        .map(|(&file_id, _)| file_id)
+        .ok_or_else(not_found)?;
+    let src = fs::read_to_string(&location.abs_path).map_err(|_| not_found())?;
+    let index = location.coord.byte_index(&src).ok_or_else(not_found)?;
+    Ok(Some(TextSpan {
+        file_id,
+        start: index,
+        end: index,
+    }))
+}
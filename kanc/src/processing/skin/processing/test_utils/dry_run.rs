@@ -1,15 +1,26 @@
 use crate::{
     data::node_registry::NodeRegistry,
     processing::{
-        bind_type_independent::bind_files,
-        generate_code::{targets::javascript::JavaScript, CompileTarget},
+        bind_type_independent::{bind_files_with_options, BindOptions},
+        find_unreachable_items::find_unreachable_items,
+        generate_code::{
+            targets::javascript::{JavaScript, JavaScriptOptions},
+            CompileTarget,
+        },
         lighten_ast::register_file_items,
         simplify_ast::simplify_file,
         skin::processing::{
-            format::FormatErrorForCli, parse_cli_args::parse_args,
-            read_compiler_options::read_compiler_options, read_kantu_files::read_kantu_files,
+            format::{
+                format_reduction_profile_for_cli, format_reduction_trace_for_cli,
+                format_unreachable_item_counts_for_cli, FormatErrorForCli,
+            },
+            parse_cli_args::parse_args,
+            read_compiler_options::read_compiler_options,
+            read_kantu_files::read_kantu_files,
+            read_source_files::read_source_files,
+            resolve_trace_check_location::resolve_trace_check_location,
         },
-        type_check::type_check_file_items,
+        type_check::{type_check_file_items_with_options, TypeCheckOptions},
         validate_fun_recursion::validate_fun_recursion_in_file_items,
         validate_type_positivity::validate_type_positivity_in_file_items,
         validate_variant_return_types::validate_variant_return_types_in_file_items,
@@ -22,13 +33,23 @@ pub fn run_pipeline_without_writing_files(args: &[String]) -> Result<String, Str
     let options = parse_args(&args).fmt_err(())?;
     let options = read_compiler_options(&options).fmt_err(())?;
     let (files, file_tree, file_path_map) = read_kantu_files(&options).fmt_err(())?;
+    let source_files = read_source_files(&options, &file_path_map);
+    let traced_check_span = resolve_trace_check_location(&options, &file_path_map).fmt_err(())?;
     let files = files
         .into_iter()
         .map(|file| simplify_file(file))
         .collect::<Result<Vec<_>, _>>()
         .fmt_err(&file_path_map)?;
-    let file_items =
-        bind_files(file_tree.root(), files, &file_tree).fmt_err((&file_path_map, &file_tree))?;
+    let file_items = bind_files_with_options(
+        file_tree.root(),
+        files,
+        &file_tree,
+        BindOptions {
+            designated_eq: options.designated_eq.clone(),
+            designated_bool: options.designated_bool.clone(),
+        },
+    )
+    .fmt_err((&file_path_map, &file_tree))?;
     let mut registry = NodeRegistry::empty();
     let file_item_list_id = register_file_items(&mut registry, file_items);
 
@@ -40,10 +61,46 @@ pub fn run_pipeline_without_writing_files(args: &[String]) -> Result<String, Str
     let file_item_list_id =
         validate_type_positivity_in_file_items(&mut registry, file_item_list_id)
             .fmt_err(&registry)?;
-    let warnings = type_check_file_items(&file_tree, &mut registry, file_item_list_id)
-        .fmt_err((&options, &file_path_map, &file_tree, &registry))?;
-    let _js_file =
-        JavaScript::generate_code(&registry, file_item_list_id.raw()).fmt_err(&registry)?;
+    let type_check_output = type_check_file_items_with_options(
+        &file_tree,
+        &mut registry,
+        file_item_list_id,
+        TypeCheckOptions {
+            max_reduction_steps: Some(options.max_reduction_steps),
+            traced_check_span,
+            erased_types: options.erased_types.clone(),
+            bigint_types: options.bigint_types.clone(),
+            bigint_functions: options.bigint_functions.clone(),
+            designated_bool: options.designated_bool.clone(),
+            precompute_lets: options.optimize,
+            ..TypeCheckOptions::default()
+        },
+    )
+    .fmt_err((&options, &file_path_map, &file_tree, &registry))?;
+    let warnings = type_check_output.warnings;
+    let unreachable_items = find_unreachable_items(
+        &registry,
+        &file_tree,
+        file_item_list_id.raw(),
+        &options.entry_points,
+    )
+    .fmt_err((&file_path_map, &registry))?;
+    let _js_file = JavaScript::generate_code_with_options(
+        &registry,
+        &file_tree,
+        file_item_list_id.raw(),
+        JavaScriptOptions {
+            erasure: type_check_output.erasure,
+            bigint: type_check_output.bigint,
+            stack_safe: options.stack_safe,
+            source_files,
+            unreachable_items: unreachable_items.clone(),
+            precomputed_lets: type_check_output.precomputed_lets,
+            inline_small_functions: options.optimize,
+            check_exported_args: options.check_args,
+        },
+    )
+    .fmt_err(&registry)?;
 
     if warnings.is_empty() {
         out.push_str("Compiled successfully.\n");
@@ -54,6 +111,35 @@ pub fn run_pipeline_without_writing_files(args: &[String]) -> Result<String, Str
         }
     }
 
+    if !options.entry_points.is_empty() {
+        out.push_str(&format_unreachable_item_counts_for_cli(&unreachable_items));
+    }
+
+    if options.show_normal_form_cache_stats {
+        let stats = type_check_output.normal_form_cache_stats;
+        out.push_str(&format!(
+            "Normal form cache: {} hits, {} misses.\n",
+            stats.hits, stats.misses
+        ));
+    }
+
+    if options.profile_eval {
+        out.push_str(&format_reduction_profile_for_cli(
+            &type_check_output.reduction_profile,
+            &registry,
+            &file_path_map,
+        ));
+    }
+
+    if options.trace_check.is_some() {
+        out.push_str(&format_reduction_trace_for_cli(
+            type_check_output.reduction_trace.as_ref(),
+            &options,
+            &registry,
+            &file_path_map,
+        ));
+    }
+
     out.push_str(&format!(
         "Skipped writing output files, but would have tried writing them to {}.\n",
         options.target_dir.display()
@@ -24,6 +24,11 @@ pub fn write_target_files(
 
     for (rel_path, content) in files {
         let abs_path = options.target_dir.join(rel_path);
+        if let Some(parent) = abs_path.parent() {
+            fs::create_dir_all(parent).map_err(|raw_err| {
+                WriteTargetFilesError::CannotCreateDir(parent.to_path_buf(), raw_err)
+            })?;
+        }
         fs::write(&abs_path, content)
             .map_err(|raw_err| WriteTargetFilesError::CannotWriteFile(abs_path, raw_err))?;
     }
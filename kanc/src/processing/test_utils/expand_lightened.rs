@@ -43,6 +43,8 @@ pub fn expand_type_statement(
         name,
         params,
         variants,
+        is_struct: light.is_struct,
+        is_trait: light.is_trait,
     }
 }
 
@@ -90,6 +92,7 @@ pub fn expand_unlabeled_param(
     let type_ = expand_expression(registry, light.type_id);
     UnlabeledParam {
         span: light.span,
+        is_instance: light.is_instance,
         is_dashed: light.is_dashed,
         name,
         type_,
@@ -113,12 +116,17 @@ pub fn expand_labeled_param(
     let label = expand_param_label(registry, light.label_id);
     let name = expand_identifier(registry, light.name_id);
     let type_ = expand_expression(registry, light.type_id);
+    let default = light
+        .default_id
+        .map(|default_id| expand_expression(registry, default_id));
     LabeledParam {
         span: light.span,
+        is_instance: light.is_instance,
         label,
         is_dashed: light.is_dashed,
         name,
         type_,
+        default,
     }
 }
 
@@ -171,6 +179,7 @@ pub fn expand_let_statement(
         transparency: light.transparency,
         name,
         value,
+        is_instance: light.is_instance,
     }
 }
 
@@ -185,6 +194,16 @@ pub fn expand_expression(registry: &NodeRegistry, id: light::ExpressionId) -> Ex
             Expression::Forall(Box::new(expand_forall(registry, id)))
         }
         light::ExpressionId::Check(id) => Expression::Check(Box::new(expand_check(registry, id))),
+        light::ExpressionId::Rewrite(id) => {
+            Expression::Rewrite(Box::new(expand_rewrite(registry, id)))
+        }
+        light::ExpressionId::Projection(id) => {
+            Expression::Projection(Box::new(expand_projection(registry, id)))
+        }
+        light::ExpressionId::Exists(id) => {
+            Expression::Exists(Box::new(expand_exists(registry, id)))
+        }
+        light::ExpressionId::Pair(id) => Expression::Pair(Box::new(expand_pair(registry, id))),
     }
 }
 
@@ -288,14 +307,35 @@ pub fn expand_fun(registry: &NodeRegistry, id: NodeId<light::Fun>) -> Fun {
 pub fn expand_match(registry: &NodeRegistry, id: NodeId<light::Match>) -> Match {
     let light = registry.get(id);
     let matchee = expand_expression(registry, light.matchee_id);
+    let motive = light
+        .motive_id
+        .map(|motive_id| expand_match_motive(registry, motive_id));
     let cases = expand_optional_match_case_list(registry, light.case_list_id).into_possibly_empty();
     Match {
         span: light.span,
         matchee,
+        motive,
         cases,
     }
 }
 
+pub fn expand_match_motive(registry: &NodeRegistry, id: NodeId<light::MatchMotive>) -> MatchMotive {
+    let light = registry.get(id);
+    let matchee_name = expand_identifier(registry, light.matchee_name_id);
+    let matchee_type_name = expand_name_expression(registry, light.matchee_type_name_id);
+    let index_names = light
+        .index_name_list_id
+        .map(|list_id| expand_identifier_list(registry, list_id));
+    let output = expand_expression(registry, light.output_id);
+    MatchMotive {
+        span: light.span,
+        matchee_name,
+        matchee_type_name,
+        index_names,
+        output,
+    }
+}
+
 pub fn expand_optional_match_case_list(
     registry: &NodeRegistry,
     id: Option<NonEmptyListId<NodeId<light::MatchCase>>>,
@@ -411,6 +451,54 @@ pub fn expand_check(registry: &NodeRegistry, id: NodeId<light::Check>) -> Check
     }
 }
 
+pub fn expand_rewrite(registry: &NodeRegistry, id: NodeId<light::Rewrite>) -> Rewrite {
+    let light = registry.get(id);
+    let eq_type_name = expand_name_expression(registry, light.eq_type_name_id);
+    let eq_refl_name = expand_name_expression(registry, light.eq_refl_name_id);
+    let proof = expand_expression(registry, light.proof_id);
+    let body = expand_expression(registry, light.body_id);
+    Rewrite {
+        span: light.span,
+        eq_type_name,
+        eq_refl_name,
+        proof,
+        body,
+    }
+}
+
+pub fn expand_projection(registry: &NodeRegistry, id: NodeId<light::Projection>) -> Projection {
+    let light = registry.get(id);
+    let record = expand_expression(registry, light.record_id);
+    let field_name = expand_identifier(registry, light.field_name_id);
+    Projection {
+        span: light.span,
+        record,
+        field_name,
+    }
+}
+
+pub fn expand_exists(registry: &NodeRegistry, id: NodeId<light::Exists>) -> Exists {
+    let light = registry.get(id);
+    let param = expand_unlabeled_param(registry, light.param_id);
+    let output = expand_expression(registry, light.output_id);
+    Exists {
+        span: light.span,
+        param,
+        output,
+    }
+}
+
+pub fn expand_pair(registry: &NodeRegistry, id: NodeId<light::Pair>) -> Pair {
+    let light = registry.get(id);
+    let witness = expand_expression(registry, light.witness_id);
+    let proof = expand_expression(registry, light.proof_id);
+    Pair {
+        span: light.span,
+        witness,
+        proof,
+    }
+}
+
 pub fn expand_check_assertion_list(
     registry: &NodeRegistry,
     id: NonEmptyListId<NodeId<light::CheckAssertion>>,
@@ -36,6 +36,10 @@ pub fn format_expression(
         Expression::Match(match_) => format_match(match_, indent_level, options),
         Expression::Forall(forall) => format_forall(forall, indent_level, options),
         Expression::Check(check) => format_check(check, indent_level, options),
+        Expression::Rewrite(rewrite) => format_rewrite(rewrite, indent_level, options),
+        Expression::Projection(projection) => format_projection(projection, indent_level, options),
+        Expression::Exists(exists) => format_exists(exists, indent_level, options),
+        Expression::Pair(pair) => format_pair(pair, indent_level, options),
     }
 }
 
@@ -161,9 +165,11 @@ pub fn format_unlabeled_param(
     indent_level: usize,
     options: &FormatOptions,
 ) -> String {
+    let is_instance = if param.is_instance { "instance " } else { "" };
     let is_dashed = if param.is_dashed { "-" } else { "" };
     format!(
-        "{}{}: {}",
+        "{}{}{}: {}",
+        is_instance,
         is_dashed,
         format_ident(&param.name),
         format_expression(&param.type_, indent_level, options)
@@ -179,13 +185,20 @@ pub fn format_labeled_param(
         ParamLabel::Explicit(ident) => format_ident(ident),
         ParamLabel::Implicit => "",
     };
+    let is_instance = if param.is_instance { "instance " } else { "" };
     let is_dashed = if param.is_dashed { "-" } else { "" };
+    let default = match &param.default {
+        Some(default) => format!(" = {}", format_expression(default, indent_level, options)),
+        None => "".to_string(),
+    };
     format!(
-        "{}~{}{}: {}",
+        "{}{}~{}{}: {}{}",
+        is_instance,
         explicit_label,
         is_dashed,
         format_ident(&param.name),
-        format_expression(&param.type_, indent_level, options)
+        format_expression(&param.type_, indent_level, options),
+        default
     )
 }
 
@@ -205,14 +218,47 @@ pub fn format_match(match_: &Match, indent_level: usize, options: &FormatOptions
         })
         .collect::<Vec<_>>()
         .join("\n");
+    let motive = match &match_.motive {
+        Some(motive) => format!(" {}", format_match_motive(motive, indent_level, options)),
+        None => "".to_string(),
+    };
     format!(
-        "match {} {{\n{}\n{}}}",
+        "match {}{} {{\n{}\n{}}}",
         try_oneline_with_multi_parens(&matchee, indent_level, options),
+        motive,
         cases,
         &i0
     )
 }
 
+pub fn format_match_motive(
+    motive: &MatchMotive,
+    indent_level: usize,
+    options: &FormatOptions,
+) -> String {
+    let matchee_name = format_ident(&motive.matchee_name);
+    let matchee_type_name = format_name(&motive.matchee_type_name, indent_level, options);
+    let index_names = match &motive.index_names {
+        Some(index_names) => format!(
+            "({})",
+            index_names
+                .iter()
+                .map(format_ident)
+                .collect::<Vec<_>>()
+                .join(", ")
+        ),
+        None => "".to_string(),
+    };
+    let output = format_expression(&motive.output, indent_level + 1, options);
+    format!(
+        "as {}: {}{} => {}",
+        matchee_name,
+        matchee_type_name,
+        index_names,
+        try_oneline_with_multi_parens(&output, indent_level, options)
+    )
+}
+
 fn try_oneline_with_multi_parens(s: &str, indent_level: usize, options: &FormatOptions) -> String {
     let i0 = indent(indent_level, options);
     let i1 = indent(indent_level + 1, options);
@@ -329,6 +375,38 @@ pub fn format_check(check: &Check, indent_level: usize, options: &FormatOptions)
     format!("case {} {{\n{}{}\n{}}}", assertions, &i1, output, &i0,)
 }
 
+pub fn format_rewrite(rewrite: &Rewrite, indent_level: usize, options: &FormatOptions) -> String {
+    let proof = format_expression(&rewrite.proof, indent_level, options);
+    let body = format_expression(&rewrite.body, indent_level, options);
+    format!("rewrite {} in {}", proof, body)
+}
+
+pub fn format_projection(
+    projection: &Projection,
+    indent_level: usize,
+    options: &FormatOptions,
+) -> String {
+    let record = format_expression(&projection.record, indent_level, options);
+    format!("{}.{}", record, format_ident(&projection.field_name))
+}
+
+pub fn format_exists(exists: &Exists, indent_level: usize, options: &FormatOptions) -> String {
+    let i0 = indent(indent_level, options);
+    let i1 = indent(indent_level + 1, options);
+    let param = format_unlabeled_param(&exists.param, indent_level + 1, options);
+    let output = format_expression(&exists.output, indent_level + 1, options);
+    format!(
+        "exists (\n{}{},\n{}) {{\n{}{}\n{}}}",
+        &i1, param, &i0, &i1, output, &i0
+    )
+}
+
+pub fn format_pair(pair: &Pair, indent_level: usize, options: &FormatOptions) -> String {
+    let witness = format_expression(&pair.witness, indent_level, options);
+    let proof = format_expression(&pair.proof, indent_level, options);
+    format!("({}, {})", witness, proof)
+}
+
 pub fn format_check_assertions(
     assertions: &[CheckAssertion],
     indent_level: usize,
@@ -0,0 +1,570 @@
+use super::*;
+
+use rustc_hash::FxHashMap;
+
+/// The types that code generators represent as arbitrary-precision
+/// integers (e.g., JavaScript `bigint`s) instead of as ADT values,
+/// along with the matches on them and the functions that code generators
+/// replace with native operations.
+///
+/// A designated type must have no params, and exactly two variants,
+/// whose params must be unlabeled.
+/// The variants must take one of the following shapes
+/// (in either order):
+/// 1. Unary naturals: `zero` and `succ(n: Self)`,
+///    which represent `0` and `n + 1`.
+/// 2. Binary positives: `one` and `extend(left: Self, right: Bit)`,
+///    which represent `1` and `2 * left + right`,
+///    where `Bit` is a type with no params and two nullary variants
+///    (the first of which represents `0`, and the second `1`).
+/// 3. Naturals: `zero` and `pos(p: P)`,
+///    which represent `0` and `p`,
+///    where `P` is a designated positive type (i.e., of shape 2).
+/// 4. Integers: `neg(p: P)` and `nat(n: N)`,
+///    which represent `-p` and `n`,
+///    where `P` is a designated positive type,
+///    and `N` is a designated natural type (i.e., of shape 1 or 3).
+///
+/// The names of the variants don't matter.
+#[derive(Clone, Debug, Default)]
+pub struct BigIntAnalysis {
+    types: FxHashMap<NodeId<TypeStatement>, BigIntType>,
+    matches: FxHashMap<NodeId<Match>, NodeId<TypeStatement>>,
+    functions: FxHashMap<NodeId<LetStatement>, BigIntFunction>,
+}
+
+#[derive(Clone, Debug)]
+pub struct BigIntType {
+    /// The variants, in declaration order.
+    pub variants: Vec<(IdentifierName, BigIntVariant)>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum BigIntVariant {
+    /// A nullary variant that represents `0`.
+    Zero,
+    /// A nullary variant that represents `1`.
+    One,
+    /// `succ(n)` represents `n + 1`.
+    Succ,
+    /// `extend(left, right)` represents `2 * left + right`.
+    /// The first bit variant represents `0`, and the second `1`.
+    Extend {
+        bit_variant_names: [IdentifierName; 2],
+    },
+    /// `pos(n)` represents `n`, where `n` belongs to another designated type.
+    /// If `is_positive` is true, `n` is positive.
+    /// Otherwise, `n` is nonnegative.
+    Embed { is_positive: bool },
+    /// `neg(n)` represents `-n`, where `n` is positive.
+    Negate,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum BigIntOperation {
+    Add,
+    Mul,
+    Eq,
+    Lt,
+}
+
+impl BigIntOperation {
+    pub const ALL: [BigIntOperation; 4] = [
+        BigIntOperation::Add,
+        BigIntOperation::Mul,
+        BigIntOperation::Eq,
+        BigIntOperation::Lt,
+    ];
+
+    /// The key of the operation in the `bigint_functions` entry of `pack.yscl`.
+    pub fn key(self) -> &'static str {
+        match self {
+            BigIntOperation::Add => "add",
+            BigIntOperation::Mul => "mul",
+            BigIntOperation::Eq => "eq",
+            BigIntOperation::Lt => "lt",
+        }
+    }
+}
+
+/// A function of type `forall(a: T, b: T) { T }` (for `add` and `mul`)
+/// or `forall(a: T, b: T) { Bool }` (for `eq` and `lt`),
+/// where `T` is a designated BigInt type,
+/// and `Bool` is the pack's designated bool type.
+#[derive(Clone, Debug)]
+pub struct BigIntFunction {
+    pub operation: BigIntOperation,
+    /// The variants of `Bool` that represent `true` and `false`, in that order.
+    /// This is `None` if the function doesn't return a `Bool`.
+    pub bool_variant_names: Option<[IdentifierName; 2]>,
+}
+
+impl BigIntAnalysis {
+    pub fn type_(&self, type_id: NodeId<TypeStatement>) -> Option<&BigIntType> {
+        self.types.get(&type_id)
+    }
+
+    /// Returns the type of the matchee if it is a designated BigInt type.
+    pub fn match_type(&self, match_id: NodeId<Match>) -> Option<&BigIntType> {
+        self.matches
+            .get(&match_id)
+            .and_then(|type_id| self.types.get(type_id))
+    }
+
+    pub fn function(&self, let_id: NodeId<LetStatement>) -> Option<&BigIntFunction> {
+        self.functions.get(&let_id)
+    }
+}
+
+impl BigIntType {
+    pub fn variant(&self, name: &IdentifierName) -> Option<&BigIntVariant> {
+        self.variants
+            .iter()
+            .find(|(variant_name, _)| variant_name == name)
+            .map(|(_, variant)| variant)
+    }
+
+    fn is_positive(&self) -> bool {
+        self.variants
+            .iter()
+            .any(|(_, variant)| *variant == BigIntVariant::One)
+    }
+
+    fn is_natural(&self) -> bool {
+        self.variants
+            .iter()
+            .any(|(_, variant)| *variant == BigIntVariant::Zero)
+    }
+}
+
+/// The types and functions designated by the `bigint_types` and
+/// `bigint_functions` entries of `pack.yscl`,
+/// along with the designated bool type
+/// (which BigInt comparisons return).
+#[derive(Clone, Debug)]
+pub(super) struct DesignatedBigInts {
+    /// Each name starts with `pack` (e.g., `pack.num.Nat`).
+    type_names: Vec<NonEmptyVec<IdentifierName>>,
+    /// The context level and ID of the type each name refers to,
+    /// or `None` if the type has not been declared yet.
+    types: Vec<Option<(DbLevel, NodeId<TypeStatement>)>>,
+    function_names: Vec<(BigIntOperation, NonEmptyVec<IdentifierName>)>,
+    is_function_declared: Vec<bool>,
+    bool: Option<DesignatedBool>,
+    bool_level: Option<DbLevel>,
+}
+
+impl DesignatedBigInts {
+    pub(super) fn new(
+        type_names: Vec<NonEmptyVec<IdentifierName>>,
+        function_names: Vec<(BigIntOperation, NonEmptyVec<IdentifierName>)>,
+        bool: Option<DesignatedBool>,
+    ) -> Self {
+        let types = vec![None; type_names.len()];
+        let is_function_declared = vec![false; function_names.len()];
+        Self {
+            type_names,
+            types,
+            function_names,
+            is_function_declared,
+            bool,
+            bool_level: None,
+        }
+    }
+
+    fn type_id_at_level(&self, level: DbLevel) -> Option<NodeId<TypeStatement>> {
+        self.types
+            .iter()
+            .flatten()
+            .find(|(type_level, _)| *type_level == level)
+            .map(|(_, type_id)| *type_id)
+    }
+}
+
+/// If the type statement is the designated bool type
+/// or a designated BigInt type, this records it.
+/// The type statement's type constructor and variants must be
+/// the last entries of the context.
+pub(in crate::processing::type_check) fn record_type_statement_if_designated_by_bigints(
+    state: &mut State,
+    type_statement_id: NodeId<TypeStatement>,
+) -> Result<(), Tainted<TypeCheckError>> {
+    let type_statement = state.registry.get(type_statement_id).clone();
+    let Some(span) = type_statement.span else {
+        return Ok(());
+    };
+    let variant_count = type_statement.variant_list_id.len();
+    let level = DbLevel(state.context.len() - 1 - variant_count);
+    let type_name = &state.registry.get(type_statement.name_id).name;
+
+    if let Some(designated_bool) = &state.designated_bigints.bool {
+        let variant_names: Vec<&IdentifierName> = state
+            .registry
+            .get_possibly_empty_list(type_statement.variant_list_id)
+            .iter()
+            .map(|variant_id| {
+                &state
+                    .registry
+                    .get(state.registry.get(*variant_id).name_id)
+                    .name
+            })
+            .collect();
+        if is_path_of_item(
+            state.file_tree,
+            span.file_id,
+            type_name,
+            &designated_bool.type_name_components,
+        ) && variant_names.contains(&&designated_bool.true_variant_name)
+            && variant_names.contains(&&designated_bool.false_variant_name)
+        {
+            state.designated_bigints.bool_level = Some(level);
+        }
+    }
+
+    let Some(designation_index) = state
+        .designated_bigints
+        .type_names
+        .iter()
+        .position(|name| is_path_of_item(state.file_tree, span.file_id, type_name, name))
+    else {
+        return Ok(());
+    };
+
+    let Some(bigint_type) = get_bigint_type(state, &type_statement, level) else {
+        return tainted_err(TypeCheckError::IllegalBigIntType { type_statement_id });
+    };
+    state.bigint.types.insert(type_statement_id, bigint_type);
+    state.designated_bigints.types[designation_index] = Some((level, type_statement_id));
+    Ok(())
+}
+
+/// Returns `None` if the type doesn't have any of the shapes
+/// described in `BigIntAnalysis`.
+fn get_bigint_type(
+    state: &mut State,
+    type_statement: &TypeStatement,
+    level: DbLevel,
+) -> Option<BigIntType> {
+    if type_statement.param_list_id.is_some() {
+        return None;
+    }
+    let variant_ids = state
+        .registry
+        .get_possibly_empty_list(type_statement.variant_list_id)
+        .to_vec();
+    let [first_id, second_id] = variant_ids[..] else {
+        return None;
+    };
+    let first = get_variant_param_type_levels(state, first_id, level, 0)?;
+    let second = get_variant_param_type_levels(state, second_id, level, 1)?;
+    let (first, second) = match get_bigint_variants(state, &first, &second, level) {
+        Some(variants) => variants,
+        None => {
+            let (second, first) = get_bigint_variants(state, &second, &first, level)?;
+            (first, second)
+        }
+    };
+    Some(BigIntType {
+        variants: vec![
+            (
+                state
+                    .registry
+                    .get(state.registry.get(first_id).name_id)
+                    .name
+                    .clone(),
+                first,
+            ),
+            (
+                state
+                    .registry
+                    .get(state.registry.get(second_id).name_id)
+                    .name
+                    .clone(),
+                second,
+            ),
+        ],
+    })
+}
+
+/// Returns the variants if `left` and `right` (which are the levels
+/// of the variants' param types) match one of the shapes
+/// described in `BigIntAnalysis`, in that order.
+fn get_bigint_variants(
+    state: &State,
+    left: &[DbLevel],
+    right: &[DbLevel],
+    level: DbLevel,
+) -> Option<(BigIntVariant, BigIntVariant)> {
+    match (left, right) {
+        ([], [param]) if *param == level => Some((BigIntVariant::Zero, BigIntVariant::Succ)),
+        ([], [param]) if get_designated_bigint_type(state, *param)?.is_positive() => Some((
+            BigIntVariant::Zero,
+            BigIntVariant::Embed { is_positive: true },
+        )),
+        ([], [left_param, right_param]) if *left_param == level => {
+            let bit_variant_names = get_bit_variant_names(state, *right_param)?;
+            Some((
+                BigIntVariant::One,
+                BigIntVariant::Extend { bit_variant_names },
+            ))
+        }
+        ([left_param], [right_param])
+            if get_designated_bigint_type(state, *left_param)?.is_positive()
+                && get_designated_bigint_type(state, *right_param)?.is_natural() =>
+        {
+            Some((
+                BigIntVariant::Negate,
+                BigIntVariant::Embed { is_positive: false },
+            ))
+        }
+        _ => None,
+    }
+}
+
+fn get_designated_bigint_type<'a>(state: &'a State, level: DbLevel) -> Option<&'a BigIntType> {
+    let type_id = state.designated_bigints.type_id_at_level(level)?;
+    state.bigint.types.get(&type_id)
+}
+
+/// Returns the levels of the types of the variant's params,
+/// or `None` if any of the params is labeled,
+/// or has a type that isn't the name of a type declared
+/// before the variant.
+///
+/// `variant_index` is the index of the variant within its type statement.
+fn get_variant_param_type_levels(
+    state: &State,
+    variant_id: NodeId<Variant>,
+    type_level: DbLevel,
+    variant_index: usize,
+) -> Option<Vec<DbLevel>> {
+    let variant = state.registry.get(variant_id);
+    let Some(param_list_id) = variant.param_list_id else {
+        return Some(vec![]);
+    };
+    let NonEmptyParamListId::Unlabeled(param_list_id) = param_list_id else {
+        return None;
+    };
+    // When the variant was type checked, the context contained
+    // the type constructor, the preceding variants,
+    // and the preceding params.
+    let variant_context_len = type_level.0 + 1 + variant_index;
+    state
+        .registry
+        .get_list(param_list_id)
+        .iter()
+        .enumerate()
+        .map(|(param_index, param_id)| {
+            let type_id = state.registry.get(*param_id).type_id;
+            let ExpressionId::Name(name_id) = type_id else {
+                return None;
+            };
+            let level = get_level(state, name_id, variant_context_len + param_index)?;
+            if level > type_level {
+                return None;
+            }
+            Some(resolve_aliases(state, level))
+        })
+        .collect()
+}
+
+/// If the entry at `level` is an alias of a name,
+/// this returns the level that name (ultimately) refers to.
+/// Otherwise, this returns `level`.
+fn resolve_aliases(state: &State, level: DbLevel) -> DbLevel {
+    match state.context.get_local_definition(level) {
+        ContextEntryDefinition::Alias { value_id, .. } => match value_id.raw() {
+            ExpressionId::Name(name_id) => match get_level(state, name_id, level.0) {
+                Some(value_level) => resolve_aliases(state, value_level),
+                None => level,
+            },
+            _ => level,
+        },
+        _ => level,
+    }
+}
+
+/// Returns the names of the variants of the type at `level`,
+/// if it has no params and exactly two nullary variants.
+fn get_bit_variant_names(state: &State, level: DbLevel) -> Option<[IdentifierName; 2]> {
+    let ContextEntryDefinition::Adt {
+        variant_name_list_id,
+        ..
+    } = state.context.get_local_definition(level)
+    else {
+        return None;
+    };
+    let variant_name_ids = state.registry.get_possibly_empty_list(variant_name_list_id);
+    let [zero_name_id, one_name_id] = variant_name_ids[..] else {
+        return None;
+    };
+    // The type constructor and the variants must all be nullary,
+    // and the variants follow the type constructor in the context.
+    let is_nullary = |level: DbLevel| {
+        !matches!(
+            state.context.get_local_type(level).raw(),
+            ExpressionId::Forall(_)
+        )
+    };
+    if !(is_nullary(level) && is_nullary(DbLevel(level.0 + 1)) && is_nullary(DbLevel(level.0 + 2)))
+    {
+        return None;
+    }
+    Some([
+        state.registry.get(zero_name_id).name.clone(),
+        state.registry.get(one_name_id).name.clone(),
+    ])
+}
+
+/// If the let statement is a designated BigInt function,
+/// this verifies its type and records it.
+/// `type_id` must be expressed relative to the current context
+/// (i.e., the context the let statement is about to be pushed onto).
+pub(in crate::processing::type_check) fn record_let_statement_if_designated_bigint_function(
+    state: &mut State,
+    let_statement_id: NodeId<LetStatement>,
+    type_id: NormalFormId,
+) -> Result<(), Tainted<TypeCheckError>> {
+    let let_statement = state.registry.get(let_statement_id).clone();
+    let Some(span) = let_statement.span else {
+        return Ok(());
+    };
+    let let_name = &state.registry.get(let_statement.name_id).name;
+    let Some(designation_index) = state
+        .designated_bigints
+        .function_names
+        .iter()
+        .position(|(_, name)| is_path_of_item(state.file_tree, span.file_id, let_name, name))
+    else {
+        return Ok(());
+    };
+    let operation = state.designated_bigints.function_names[designation_index].0;
+
+    let Some(bool_variant_names) =
+        get_bigint_function_bool_variant_names(state, type_id, operation)
+    else {
+        return tainted_err(TypeCheckError::IllegalBigIntFunction {
+            let_statement_id,
+            operation,
+        });
+    };
+    state.bigint.functions.insert(
+        let_statement_id,
+        BigIntFunction {
+            operation,
+            bool_variant_names,
+        },
+    );
+    state.designated_bigints.is_function_declared[designation_index] = true;
+    Ok(())
+}
+
+/// Returns `None` if the type doesn't have the form
+/// described in `BigIntFunction`.
+/// Otherwise, returns `BigIntFunction::bool_variant_names`.
+fn get_bigint_function_bool_variant_names(
+    state: &State,
+    type_id: NormalFormId,
+    operation: BigIntOperation,
+) -> Option<Option<[IdentifierName; 2]>> {
+    let ExpressionId::Forall(forall_id) = type_id.raw() else {
+        return None;
+    };
+    let forall = state.registry.get(forall_id);
+    let NonEmptyParamListId::Unlabeled(param_list_id) = forall.param_list_id else {
+        return None;
+    };
+    let param_ids = state.registry.get_list(param_list_id);
+    let [first_param_id, second_param_id] = param_ids[..] else {
+        return None;
+    };
+
+    let context_len = state.context.len();
+    let get_type_level = |type_id: ExpressionId, context_len: usize| match type_id {
+        ExpressionId::Name(name_id) => get_level(state, name_id, context_len),
+        _ => None,
+    };
+    let first_level = get_type_level(state.registry.get(first_param_id).type_id, context_len)?;
+    let second_level =
+        get_type_level(state.registry.get(second_param_id).type_id, context_len + 1)?;
+    let output_level = get_type_level(forall.output_id, context_len + 2)?;
+
+    state.designated_bigints.type_id_at_level(first_level)?;
+    if second_level != first_level {
+        return None;
+    }
+    match operation {
+        BigIntOperation::Add | BigIntOperation::Mul => {
+            (output_level == first_level).then_some(None)
+        }
+        BigIntOperation::Eq | BigIntOperation::Lt => {
+            if Some(output_level) != state.designated_bigints.bool_level {
+                return None;
+            }
+            // The bool level is only set if there is a designated bool.
+            let designated_bool = state.designated_bigints.bool.as_ref()?;
+            Some(Some([
+                designated_bool.true_variant_name.clone(),
+                designated_bool.false_variant_name.clone(),
+            ]))
+        }
+    }
+}
+
+pub(in crate::processing::type_check) fn verify_every_designated_bigint_was_declared(
+    designated: &DesignatedBigInts,
+) -> Result<(), TypeCheckError> {
+    for (name, type_) in designated.type_names.iter().zip(&designated.types) {
+        if type_.is_none() {
+            return Err(TypeCheckError::UndeclaredBigIntItem {
+                name_components: name.clone(),
+            });
+        }
+    }
+    for ((_, name), is_declared) in designated
+        .function_names
+        .iter()
+        .zip(&designated.is_function_declared)
+    {
+        if !is_declared {
+            return Err(TypeCheckError::UndeclaredBigIntItem {
+                name_components: name.clone(),
+            });
+        }
+    }
+    Ok(())
+}
+
+pub(in crate::processing::type_check) fn record_match_bigint_type(
+    state: &mut State,
+    match_id: NodeId<Match>,
+    matchee_type: &NormalFormAdtExpression,
+) {
+    let type_dbi = state.registry.get(matchee_type.type_name_id).db_index;
+    let type_level = state.context.index_to_level(type_dbi);
+    match state.designated_bigints.type_id_at_level(type_level) {
+        Some(type_id) => {
+            state.bigint.matches.insert(match_id, type_id);
+        }
+        None => {
+            state.bigint.matches.remove(&match_id);
+        }
+    }
+}
+
+/// Like `copy_call_arg_erasure`, but for the matchee types
+/// recorded by `record_match_bigint_type`.
+pub(super) fn copy_match_bigint_type(
+    bigint: &mut BigIntAnalysis,
+    from: NodeId<Match>,
+    to: NodeId<Match>,
+) {
+    match bigint.matches.get(&from).copied() {
+        Some(type_id) => {
+            bigint.matches.insert(to, type_id);
+        }
+        None => {
+            bigint.matches.remove(&to);
+        }
+    }
+}
@@ -1,7 +1,5 @@
 use super::*;
 
-const NUMBER_OF_BUILTIN_ENTRIES: usize = 2;
-
 #[derive(Debug, Clone)]
 pub struct Context {
     /// Each type in the stack is expressed "locally" (i.e., relative
@@ -12,6 +10,8 @@ pub struct Context {
     /// Thus, if `local_type_stack.len() == 3`, for example, then the global De Bruijn index for `local_type_stack[1]` is `2`.
     ///
     /// If an illustration would help, consider the following:
+    /// For simplicity, this illustration pretends that
+    /// `Type1` is the limit universe.
     /// ```text
     /// Type1: DNE
     /// Type0: Type1
@@ -41,6 +41,15 @@ pub struct Context {
     /// ```
     ///
     local_type_stack: Vec<ContextEntry>,
+    /// The levels and names of the entries that are instances
+    /// (i.e., `instance` statements and instance params),
+    /// in ascending order of level.
+    instances: Vec<(DbLevel, NodeId<Identifier>)>,
+    normal_form_cache: NormalFormCache,
+    /// The level of the largest universe (see `with_builtins`).
+    /// The stack starts with one entry for each universe
+    /// (`Type0` through the limit universe).
+    limit_universe_level: usize,
 }
 
 #[derive(Clone, Debug)]
@@ -55,11 +64,18 @@ pub enum ContextEntryDefinition {
         value_id: NormalFormId,
         visibility: Visibility,
         transparency: Transparency,
+        let_id: NodeId<LetStatement>,
     },
     /// Algebraic data type
     Adt {
         variant_name_list_id: Option<NonEmptyListId<NodeId<Identifier>>>,
         visibility: Visibility,
+        /// Whether the ADT was declared with a `struct` statement.
+        /// If so, it has exactly one variant, whose params are all labeled.
+        is_struct: bool,
+        /// Whether the ADT was declared with a `trait` statement.
+        /// If so, `is_struct` is also `true`.
+        is_trait: bool,
     },
     Variant {
         name_id: NodeId<Identifier>,
@@ -68,51 +84,53 @@ pub enum ContextEntryDefinition {
     Uninterpreted,
 }
 
-const TYPE1_LEVEL: DbLevel = DbLevel(0);
-const TYPE0_LEVEL: DbLevel = DbLevel(1);
-
 impl Context {
     pub fn with_builtins(registry: &mut NodeRegistry) -> Self {
-        // We should will never retrieve the type of `Type1`, since it is undefined.
+        // The limit universe has no type, so it cannot be named in
+        // source code.
+        // We make it two levels above the largest universe the program names,
+        // so that the type of every named universe (and every type that
+        // can be built from them) is an ordinary universe.
+        let limit_universe_level = registry.max_universe_level() + 2;
+
+        // The universes are stored from largest to smallest,
+        // so the type of each universe (i.e., the next largest universe)
+        // is always the entry directly before it.
+        // Thus, its local type is always `DbIndex(0)`.
+        //
+        // We should will never retrieve the type of the limit universe,
+        // since it is undefined.
         // However, we need to store _some_ object in the stack, so that the indices
         // of the other types are correct.
-        let type1_entry = {
-            let dummy_type1_type_id = NormalFormId::unchecked_new(ExpressionId::Name(
-                add_name_expression_and_overwrite_component_ids(
-                    registry,
-                    NonEmptyVec::singleton(Identifier {
-                        id: dummy_id(),
-                        name: IdentifierName::Reserved(ReservedIdentifierName::Type2),
-                        span: None,
-                    }),
-                    DbIndex(0),
-                ),
-            ));
-            ContextEntry {
-                type_id: dummy_type1_type_id,
-                definition: ContextEntryDefinition::Uninterpreted,
-            }
-        };
-        let type0_entry = {
-            let type0_type_id = NormalFormId::unchecked_new(ExpressionId::Name(
-                add_name_expression_and_overwrite_component_ids(
-                    registry,
-                    NonEmptyVec::singleton(Identifier {
-                        id: dummy_id(),
-                        name: IdentifierName::Reserved(ReservedIdentifierName::Type1),
-                        span: None,
-                    }),
-                    DbIndex(0),
-                ),
-            ));
-            ContextEntry {
-                type_id: type0_type_id,
-                definition: ContextEntryDefinition::Uninterpreted,
-            }
-        };
-        let builtins: [ContextEntry; NUMBER_OF_BUILTIN_ENTRIES] = [type1_entry, type0_entry];
+        // So, we simply (and arbitrarily) store a self-reference.
+        let builtins = (0..=limit_universe_level)
+            .rev()
+            .map(|universe_level| {
+                let type_name = ReservedIdentifierName::universe(
+                    (universe_level + 1).min(limit_universe_level),
+                );
+                let type_id = NormalFormId::unchecked_new(ExpressionId::Name(
+                    add_name_expression_and_overwrite_component_ids(
+                        registry,
+                        NonEmptyVec::singleton(Identifier {
+                            id: dummy_id(),
+                            name: IdentifierName::Reserved(type_name),
+                            span: None,
+                        }),
+                        DbIndex(0),
+                    ),
+                ));
+                ContextEntry {
+                    type_id,
+                    definition: ContextEntryDefinition::Uninterpreted,
+                }
+            })
+            .collect();
         Self {
-            local_type_stack: builtins.to_vec(),
+            local_type_stack: builtins,
+            instances: vec![],
+            normal_form_cache: NormalFormCache::default(),
+            limit_universe_level,
         }
     }
 }
@@ -127,7 +145,7 @@ impl Context {
                 self.len()
             );
         }
-        self.local_type_stack.truncate(self.len() - n);
+        self.truncate(self.len() - n);
     }
 
     /// We effectively return `()`, but the reason we use the `Result` type we is to
@@ -140,6 +158,26 @@ impl Context {
     pub fn len(&self) -> usize {
         self.local_type_stack.len()
     }
+
+    /// Marks the last entry as an instance, making it
+    /// a candidate for instance resolution.
+    ///
+    /// Panics if the context only contains builtins.
+    pub fn mark_last_as_instance(&mut self, name_id: NodeId<Identifier>) {
+        if self.len() <= self.number_of_builtin_entries() {
+            panic!("Tried to mark a builtin entry as an instance");
+        }
+        self.instances.push((DbLevel(self.len() - 1), name_id));
+    }
+
+    /// Returns the De Bruijn indices and names of all instances
+    /// in the context, from least recently pushed to most recently pushed.
+    pub fn instances(&self) -> Vec<(DbIndex, NodeId<Identifier>)> {
+        self.instances
+            .iter()
+            .map(|(level, name_id)| (self.level_to_index(*level), *name_id))
+            .collect()
+    }
 }
 
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
@@ -211,18 +249,44 @@ impl Context {
             );
         }
         self.local_type_stack.truncate(new_len);
+        self.instances.retain(|(level, _)| level.0 < new_len);
+        self.normal_form_cache
+            .invalidate_contexts_longer_than(new_len);
     }
 }
 
 impl Context {
-    /// Returns the De Bruijn index of the `Type0` expression.
-    pub fn type0_dbi(&self) -> DbIndex {
-        self.level_to_index(TYPE0_LEVEL)
+    pub fn limit_universe_level(&self) -> usize {
+        self.limit_universe_level
+    }
+
+    fn number_of_builtin_entries(&self) -> usize {
+        self.limit_universe_level + 1
+    }
+
+    /// Returns the De Bruijn index of the universe at `universe_level`.
+    ///
+    /// Panics if `universe_level > self.limit_universe_level()`.
+    pub fn universe_dbi(&self, universe_level: usize) -> DbIndex {
+        let limit = self.limit_universe_level;
+        if universe_level > limit {
+            panic!(
+                "Universe level {} exceeds the limit universe level ({})",
+                universe_level, limit
+            );
+        }
+        self.level_to_index(DbLevel(limit - universe_level))
     }
 
-    /// Returns the De Bruijn index of the `Type1` expression.
-    pub fn type1_dbi(&self) -> DbIndex {
-        self.level_to_index(TYPE1_LEVEL)
+    /// Returns the level of the universe `index` refers to,
+    /// or `None` if `index` does not refer to a universe.
+    pub fn universe_level(&self, index: DbIndex) -> Option<usize> {
+        let level = self.index_to_level(index);
+        if level.0 < self.number_of_builtin_entries() {
+            Some(self.limit_universe_level - level.0)
+        } else {
+            None
+        }
     }
 }
 
@@ -238,10 +302,10 @@ impl Context {
 
 impl Context {
     pub fn get_type(&self, index: DbIndex, registry: &mut NodeRegistry) -> NormalFormId {
-        let level = self.index_to_level(index);
-        if level == TYPE1_LEVEL {
-            panic!("Type1 has no type. We may add support for infinite type hierarchies in the future. However, for now, Type1 is the \"limit\" type.");
+        if self.universe_level(index) == Some(self.limit_universe_level) {
+            panic!("The limit universe has no type. We may add support for infinite type hierarchies in the future. However, for now, the limit universe is the largest type.");
         }
+        let level = self.index_to_level(index);
         let out = self.local_type_stack[level.0]
             .type_id
             .upshift(index.0 + 1, registry);
@@ -259,6 +323,29 @@ impl Context {
             .upshift(index.0 + 1, registry)
     }
 
+    /// Returns the definition of the entry at `level`,
+    /// expressed locally (i.e., relative to the entry's position in the stack).
+    /// Unlike `get_definition`, this does not need to register any
+    /// shifted nodes.
+    pub fn get_local_definition(&self, level: DbLevel) -> ContextEntryDefinition {
+        self.local_type_stack[level.0].definition
+    }
+
+    /// Returns the type of the entry at `level`,
+    /// expressed locally (see `get_local_definition`).
+    pub fn get_local_type(&self, level: DbLevel) -> NormalFormId {
+        self.local_type_stack[level.0].type_id
+    }
+
+    pub fn get_cached_normal_form(&self, key: NormalFormCacheKey) -> Option<NormalFormId> {
+        self.normal_form_cache.get(self.len(), key)
+    }
+
+    pub fn cache_normal_form(&mut self, key: NormalFormCacheKey, normal_form_id: NormalFormId) {
+        self.normal_form_cache
+            .insert(self.len(), key, normal_form_id);
+    }
+
     pub fn get_visibility(&self, index: DbIndex) -> Visibility {
         let level = self.index_to_level(index);
         let definition = self.local_type_stack[level.0].definition;
@@ -278,7 +365,7 @@ impl SubstituteInPlaceAndGetNoOpStatus for Context {
         state: &mut ContextlessState,
     ) -> WasSyntacticNoOp {
         let mut was_no_op = WasSyntacticNoOp(true);
-        for i in NUMBER_OF_BUILTIN_ENTRIES..self.len() {
+        for i in self.number_of_builtin_entries()..self.len() {
             let level = DbLevel(i);
             was_no_op &= self.subst_entry_in_place(level, substitution, state);
         }
@@ -293,6 +380,9 @@ impl Context {
         substitution: Substitution,
         state: &mut ContextlessState,
     ) -> WasSyntacticNoOp {
+        // Any context that contains the entry at `level` could be affected.
+        self.normal_form_cache
+            .invalidate_contexts_longer_than(level.0);
         self.subst_entry_type_id_in_place(level, substitution, state)
             & self.subst_entry_definition_in_place(level, substitution, state)
     }
@@ -325,6 +415,14 @@ impl Context {
                     warnings: state.warnings,
                     required_transparency_for_substitution: state
                         .required_transparency_for_substitution,
+                    evaluator: state.evaluator,
+                    normal_form_cache_stats: state.normal_form_cache_stats,
+                    reduction_tracker: state.reduction_tracker,
+                    erasure: state.erasure,
+                    designated_erased_types: state.designated_erased_types,
+                    bigint: state.bigint,
+                    designated_bigints: state.designated_bigints,
+                    precomputation: state.precomputation,
                     context: &mut context,
                 },
                 substituted_type_id,
@@ -348,6 +446,7 @@ impl Context {
                 value_id,
                 visibility,
                 transparency,
+                let_id,
             } => {
                 let substituted = value_id
                     .raw()
@@ -367,12 +466,21 @@ impl Context {
                                 warnings: state.warnings,
                                 required_transparency_for_substitution: state
                                     .required_transparency_for_substitution,
+                                evaluator: state.evaluator,
+                                normal_form_cache_stats: state.normal_form_cache_stats,
+                                reduction_tracker: state.reduction_tracker,
+                                erasure: state.erasure,
+                                designated_erased_types: state.designated_erased_types,
+                                bigint: state.bigint,
+                                designated_bigints: state.designated_bigints,
+                                precomputation: state.precomputation,
                                 context: &mut context,
                             },
                             substituted,
                         ),
                         visibility,
                         transparency,
+                        let_id,
                     }
                 };
                 (new_definition, was_no_op)
@@ -393,6 +501,14 @@ impl Context {
     pub(crate) fn clone_slice(&self, excl_upper_bound: DbLevel) -> Context {
         Context {
             local_type_stack: self.local_type_stack[0..excl_upper_bound.0].to_vec(),
+            instances: self
+                .instances
+                .iter()
+                .copied()
+                .filter(|(level, _)| level.0 < excl_upper_bound.0)
+                .collect(),
+            normal_form_cache: NormalFormCache::default(),
+            limit_universe_level: self.limit_universe_level,
         }
     }
 }
@@ -0,0 +1,86 @@
+use super::*;
+
+/// Returns `true` if a term of type `left` can be used
+/// wherever a term of type `right` is expected.
+///
+/// This is the case if the two types are equal (up to eta-conversion),
+/// or if both are universes and `left` is not larger than `right`
+/// (e.g., `Type0` is a subtype of `Type1`).
+/// Foralls are covariant in their outputs, so, for example,
+/// `forall(n: Nat) { Type0 }` is a subtype of `forall(n: Nat) { Type1 }`.
+/// Exists types are covariant in both their param types and their outputs.
+pub(super) fn is_left_type_subtype_of_right_type(
+    state: &mut State,
+    left: NormalFormId,
+    right: NormalFormId,
+) -> bool {
+    is_left_subtype_of_right_at_depth(state, left.raw(), right.raw(), 0)
+}
+
+/// `depth` is the number of forall (or exists) params `left` and `right` are nested in.
+/// These params are _not_ in the context, so we need to account for them
+/// when looking up universes.
+fn is_left_subtype_of_right_at_depth(
+    state: &mut State,
+    left: ExpressionId,
+    right: ExpressionId,
+    depth: usize,
+) -> bool {
+    if are_normal_forms_eta_equal_at_depth(
+        state,
+        NormalFormId::unchecked_new(left),
+        NormalFormId::unchecked_new(right),
+        depth,
+    ) {
+        return true;
+    }
+
+    match (left, right) {
+        (ExpressionId::Name(left_id), ExpressionId::Name(right_id)) => {
+            let left_level = get_universe_level_at_depth(state, left_id, depth);
+            let right_level = get_universe_level_at_depth(state, right_id, depth);
+            match (left_level, right_level) {
+                (Some(left_level), Some(right_level)) => left_level <= right_level,
+                _ => false,
+            }
+        }
+        (ExpressionId::Forall(left_id), ExpressionId::Forall(right_id)) => {
+            let left = state.registry.get(left_id).clone();
+            let right = state.registry.get(right_id).clone();
+            are_param_lists_eta_equal(state, left.param_list_id, right.param_list_id, depth)
+                && is_left_subtype_of_right_at_depth(
+                    state,
+                    left.output_id,
+                    right.output_id,
+                    depth + left.param_list_id.len(),
+                )
+        }
+        (ExpressionId::Exists(left_id), ExpressionId::Exists(right_id)) => {
+            let left = state.registry.get(left_id).clone();
+            let right = state.registry.get(right_id).clone();
+            let left_param_type_id = state.registry.get(left.param_id).type_id;
+            let right_param_type_id = state.registry.get(right.param_id).type_id;
+            is_left_subtype_of_right_at_depth(state, left_param_type_id, right_param_type_id, depth)
+                && is_left_subtype_of_right_at_depth(
+                    state,
+                    left.output_id,
+                    right.output_id,
+                    depth + 1,
+                )
+        }
+        _ => false,
+    }
+}
+
+fn get_universe_level_at_depth(
+    state: &State,
+    name_id: NodeId<NameExpression>,
+    depth: usize,
+) -> Option<usize> {
+    let db_index = state.registry.get(name_id).db_index;
+    if db_index.0 < depth {
+        // The name refers to a forall (or exists) param.
+        return None;
+    }
+    state.context.universe_level(DbIndex(db_index.0 - depth))
+}
@@ -0,0 +1,588 @@
+use super::*;
+
+use crate::data::file_id::FileId;
+
+use rustc_hash::{FxHashMap, FxHashSet};
+
+/// The params, args, and matches that only matter during type checking,
+/// so code generators can erase them.
+///
+/// A param is erased if its type is
+/// 1. a universe (e.g., `T: Type`),
+/// 2. a `forall` that ends in a universe (e.g., `F: forall(T: Type) { Type }`), or
+/// 3. a designated erased type, possibly applied to args
+///    (e.g., `h: Eq(Nat, a, b)`, if `pack.yscl` designates `Eq`).
+///
+/// An arg is erased if the corresponding param of the callee's type
+/// is erased (after substituting the earlier args).
+/// A match is erased if its matchee has a designated erased type.
+/// Since designated erased types have at most one variant,
+/// an erased match has at most one case.
+///
+/// Every arg that corresponds to an erased param is erased,
+/// but since an arg's erasure depends on the earlier args,
+/// the converse doesn't hold.
+/// Furthermore, since the callee of a call may only be known at runtime,
+/// a callee cannot tell which of its args were erased by the caller,
+/// and vice versa.
+/// Thus, code generators may only omit an erased param
+/// (other than a trailing one) if every call omits the corresponding arg
+/// (e.g., if every caller knows the callee statically).
+/// Otherwise, they should reserve a position for it, and should not assume
+/// anything about the value passed in an erased position.
+#[derive(Clone, Debug, Default)]
+pub struct ErasureAnalysis {
+    erased_unlabeled_params: FxHashSet<NodeId<UnlabeledParam>>,
+    erased_labeled_params: FxHashSet<NodeId<LabeledParam>>,
+    erased_call_args: FxHashMap<NodeId<Call>, ErasedArgs>,
+    erased_matches: FxHashSet<NodeId<Match>>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum ErasedArgs {
+    /// Whether each arg is erased, in order.
+    Unlabeled(Vec<bool>),
+    /// The labels of the erased args.
+    Labeled(Vec<IdentifierName>),
+}
+
+impl ErasureAnalysis {
+    pub fn is_unlabeled_param_erased(&self, param_id: NodeId<UnlabeledParam>) -> bool {
+        self.erased_unlabeled_params.contains(&param_id)
+    }
+
+    pub fn is_labeled_param_erased(&self, param_id: NodeId<LabeledParam>) -> bool {
+        self.erased_labeled_params.contains(&param_id)
+    }
+
+    /// Returns `None` if none of the call's args are erased.
+    pub fn erased_args(&self, call_id: NodeId<Call>) -> Option<&ErasedArgs> {
+        self.erased_call_args.get(&call_id)
+    }
+
+    pub fn is_match_erased(&self, match_id: NodeId<Match>) -> bool {
+        self.erased_matches.contains(&match_id)
+    }
+}
+
+/// The types designated by the `erased_types` entry of `pack.yscl`.
+#[derive(Clone, Debug)]
+pub(super) struct DesignatedErasedTypes {
+    /// Each name starts with `pack` (e.g., `pack.eq.Eq`).
+    names: Vec<NonEmptyVec<IdentifierName>>,
+    /// The context level of the type each name refers to,
+    /// or `None` if the type has not been declared yet.
+    levels: Vec<Option<DbLevel>>,
+}
+
+impl DesignatedErasedTypes {
+    pub(super) fn new(names: Vec<NonEmptyVec<IdentifierName>>) -> Self {
+        let levels = vec![None; names.len()];
+        Self { names, levels }
+    }
+
+    fn contains_level(&self, level: DbLevel) -> bool {
+        self.levels.contains(&Some(level))
+    }
+}
+
+/// If the type statement (whose type constructor must be
+/// the last entry of the context) is a designated erased type,
+/// this records its level.
+pub(in crate::processing::type_check) fn record_type_statement_if_designated_erased_type(
+    state: &mut State,
+    type_statement_id: NodeId<TypeStatement>,
+) -> Result<(), Tainted<TypeCheckError>> {
+    let type_statement = state.registry.get(type_statement_id).clone();
+    let Some(span) = type_statement.span else {
+        return Ok(());
+    };
+    let type_name = &state.registry.get(type_statement.name_id).name;
+    let Some(designation_index) = state
+        .designated_erased_types
+        .names
+        .iter()
+        .position(|name| is_path_of_item(state.file_tree, span.file_id, type_name, name))
+    else {
+        return Ok(());
+    };
+
+    if type_statement.variant_list_id.len() > 1 {
+        return tainted_err(TypeCheckError::IllegalErasedType { type_statement_id });
+    }
+
+    let level = DbLevel(state.context.len() - 1);
+    state.designated_erased_types.levels[designation_index] = Some(level);
+    Ok(())
+}
+
+/// Returns whether `path` (e.g., `pack.eq.Eq`) is the path of
+/// the item named `item_name` that is declared in the given file.
+pub(crate) fn is_path_of_item(
+    file_tree: &FileTree,
+    file_id: FileId,
+    item_name: &IdentifierName,
+    path: &NonEmptyVec<IdentifierName>,
+) -> bool {
+    let mut expected = vec![item_name.src_str()];
+    let mut current = file_id;
+    while let Some((parent, label)) = file_tree.parent_and_label(current) {
+        expected.push(label.src_str());
+        current = parent;
+    }
+    expected.push("pack");
+    expected.reverse();
+
+    path.iter().map(IdentifierName::src_str).eq(expected)
+}
+
+pub(in crate::processing::type_check) fn verify_every_designated_erased_type_was_declared(
+    designated: &DesignatedErasedTypes,
+) -> Result<(), TypeCheckError> {
+    for (name, level) in designated.names.iter().zip(&designated.levels) {
+        if level.is_none() {
+            return Err(TypeCheckError::UndeclaredErasedType {
+                name_components: name.clone(),
+            });
+        }
+    }
+    Ok(())
+}
+
+/// `normalized_type_id` must be expressed relative to the current context
+/// (i.e., the context the param is about to be pushed onto).
+pub(in crate::processing::type_check) fn record_unlabeled_param_erasure(
+    state: &mut State,
+    param_id: NodeId<UnlabeledParam>,
+    normalized_type_id: NormalFormId,
+) {
+    if is_erased_type(state, normalized_type_id.raw(), state.context.len()) {
+        state.erasure.erased_unlabeled_params.insert(param_id);
+    }
+}
+
+/// `normalized_type_id` must be expressed relative to the current context
+/// (i.e., the context the param is about to be pushed onto).
+pub(in crate::processing::type_check) fn record_labeled_param_erasure(
+    state: &mut State,
+    param_id: NodeId<LabeledParam>,
+    normalized_type_id: NormalFormId,
+) {
+    if is_erased_type(state, normalized_type_id.raw(), state.context.len()) {
+        state.erasure.erased_labeled_params.insert(param_id);
+    }
+}
+
+/// `substituted_param_type_ids` must be expressed relative to the current context.
+pub(in crate::processing::type_check) fn record_call_arg_erasure(
+    state: &mut State,
+    call_id: NodeId<Call>,
+    callee_type_param_list_id: NonEmptyParamListId,
+    substituted_param_type_ids: &[NormalFormId],
+) {
+    let context_len = state.context.len();
+    let is_erased: Vec<bool> = substituted_param_type_ids
+        .iter()
+        .map(|type_id| is_erased_type(state, type_id.raw(), context_len))
+        .collect();
+    if !is_erased.contains(&true) {
+        state.erasure.erased_call_args.remove(&call_id);
+        return;
+    }
+
+    let erased_args = match callee_type_param_list_id {
+        NonEmptyParamListId::Unlabeled(_) => ErasedArgs::Unlabeled(is_erased),
+        NonEmptyParamListId::UniquelyLabeled(param_list_id) => ErasedArgs::Labeled(
+            state
+                .registry
+                .get_list(param_list_id)
+                .iter()
+                .zip(is_erased)
+                .filter(|(_, is_erased)| *is_erased)
+                .map(|(param_id, _)| {
+                    let label_id = state.registry.get(*param_id).label_identifier_id();
+                    state.registry.get(label_id).name.clone()
+                })
+                .collect(),
+        ),
+    };
+    state.erasure.erased_call_args.insert(call_id, erased_args);
+}
+
+/// If a call's args were misordered, the type checker checks
+/// a reordered copy of the call instead.
+/// Since misordered args must be labeled, the copy's erased labels
+/// also apply to the original call.
+pub(in crate::processing::type_check) fn copy_call_arg_erasure(
+    state: &mut State,
+    from: NodeId<Call>,
+    to: NodeId<Call>,
+) {
+    match state.erasure.erased_call_args.get(&from).cloned() {
+        Some(erased_args) => {
+            state.erasure.erased_call_args.insert(to, erased_args);
+        }
+        None => {
+            state.erasure.erased_call_args.remove(&to);
+        }
+    }
+}
+
+pub(in crate::processing::type_check) fn record_match_erasure(
+    state: &mut State,
+    match_id: NodeId<Match>,
+    matchee_type: &NormalFormAdtExpression,
+) {
+    let type_dbi = state.registry.get(matchee_type.type_name_id).db_index;
+    let type_level = state.context.index_to_level(type_dbi);
+    if state.designated_erased_types.contains_level(type_level) {
+        state.erasure.erased_matches.insert(match_id);
+    }
+}
+
+/// Returns whether a param of the given type is erased.
+/// The type must be a normal form, expressed relative to a context
+/// with `context_len` entries.
+/// If `context_len` exceeds the length of the actual context,
+/// the excess entries are treated as (non-erased) bound variables
+/// (e.g., the params of an enclosing `forall`).
+fn is_erased_type(state: &State, type_id: ExpressionId, context_len: usize) -> bool {
+    match type_id {
+        ExpressionId::Name(name_id) => {
+            let Some(level) = get_level(state, name_id, context_len) else {
+                return false;
+            };
+            is_universe_level(state, level)
+                || state.designated_erased_types.contains_level(level)
+                || match state.context.get_local_definition(level) {
+                    ContextEntryDefinition::Alias { value_id, .. } => {
+                        is_erased_type(state, value_id.raw(), level.0)
+                    }
+                    _ => false,
+                }
+        }
+        ExpressionId::Call(call_id) => match state.registry.get(call_id).callee_id {
+            ExpressionId::Name(name_id) => {
+                is_designated_erased_type_name(state, name_id, context_len)
+            }
+            _ => false,
+        },
+        ExpressionId::Forall(forall_id) => {
+            let forall = state.registry.get(forall_id);
+            is_universe_or_forall_ending_in_universe(
+                state,
+                forall.output_id,
+                context_len + forall.param_list_id.len(),
+            )
+        }
+        _ => false,
+    }
+}
+
+fn is_designated_erased_type_name(
+    state: &State,
+    name_id: NodeId<NameExpression>,
+    context_len: usize,
+) -> bool {
+    let Some(level) = get_level(state, name_id, context_len) else {
+        return false;
+    };
+    state.designated_erased_types.contains_level(level)
+        || match state.context.get_local_definition(level) {
+            ContextEntryDefinition::Alias { value_id, .. } => match value_id.raw() {
+                ExpressionId::Name(value_name_id) => {
+                    is_designated_erased_type_name(state, value_name_id, level.0)
+                }
+                _ => false,
+            },
+            _ => false,
+        }
+}
+
+fn is_universe_or_forall_ending_in_universe(
+    state: &State,
+    type_id: ExpressionId,
+    context_len: usize,
+) -> bool {
+    match type_id {
+        ExpressionId::Name(name_id) => {
+            let Some(level) = get_level(state, name_id, context_len) else {
+                return false;
+            };
+            is_universe_level(state, level)
+                || match state.context.get_local_definition(level) {
+                    ContextEntryDefinition::Alias { value_id, .. } => {
+                        is_universe_or_forall_ending_in_universe(state, value_id.raw(), level.0)
+                    }
+                    _ => false,
+                }
+        }
+        ExpressionId::Forall(forall_id) => {
+            let forall = state.registry.get(forall_id);
+            is_universe_or_forall_ending_in_universe(
+                state,
+                forall.output_id,
+                context_len + forall.param_list_id.len(),
+            )
+        }
+        _ => false,
+    }
+}
+
+/// Returns `None` if the name refers to a bound variable
+/// that is not in the actual context.
+pub(super) fn get_level(
+    state: &State,
+    name_id: NodeId<NameExpression>,
+    context_len: usize,
+) -> Option<DbLevel> {
+    let index = state.registry.get(name_id).db_index;
+    let level = DbLevel(context_len - index.0 - 1);
+    if level.0 < state.context.len() {
+        Some(level)
+    } else {
+        None
+    }
+}
+
+fn is_universe_level(state: &State, level: DbLevel) -> bool {
+    state
+        .context
+        .universe_level(state.context.level_to_index(level))
+        .is_some()
+}
+
+/// The type checker checks some match case outputs as
+/// shifted copies of the original outputs.
+/// This copies the erasure (and BigInt matchee type) of each node
+/// in the copy to the corresponding node in the original.
+pub(in crate::processing::type_check) fn copy_erasure_to_original(
+    state: &mut State,
+    copy_id: ExpressionId,
+    original_id: ExpressionId,
+) {
+    if copy_id == original_id {
+        return;
+    }
+    let registry = &*state.registry;
+    let erasure = &mut *state.erasure;
+    let bigint = &mut *state.bigint;
+    copy_expression_erasure(registry, erasure, bigint, copy_id, original_id);
+}
+
+fn copy_expression_erasure(
+    registry: &NodeRegistry,
+    erasure: &mut ErasureAnalysis,
+    bigint: &mut BigIntAnalysis,
+    copy_id: ExpressionId,
+    original_id: ExpressionId,
+) {
+    match (copy_id, original_id) {
+        (ExpressionId::Call(copy_id), ExpressionId::Call(original_id)) => {
+            let copy = registry.get(copy_id);
+            let original = registry.get(original_id);
+            copy_expression_erasure(
+                registry,
+                erasure,
+                bigint,
+                copy.callee_id,
+                original.callee_id,
+            );
+            match (copy.arg_list_id, original.arg_list_id) {
+                (
+                    NonEmptyCallArgListId::Unlabeled(copy_arg_list_id),
+                    NonEmptyCallArgListId::Unlabeled(original_arg_list_id),
+                ) => {
+                    let copy_arg_ids = registry.get_list(copy_arg_list_id);
+                    let original_arg_ids = registry.get_list(original_arg_list_id);
+                    // If the type checker filled in omitted args,
+                    // the args no longer correspond, so we don't erase any.
+                    if copy_arg_ids.len() != original_arg_ids.len() {
+                        return;
+                    }
+                    for (copy_arg_id, original_arg_id) in
+                        copy_arg_ids.iter().zip(original_arg_ids.iter())
+                    {
+                        copy_expression_erasure(
+                            registry,
+                            erasure,
+                            bigint,
+                            *copy_arg_id,
+                            *original_arg_id,
+                        );
+                    }
+                }
+                (
+                    NonEmptyCallArgListId::UniquelyLabeled(copy_arg_list_id),
+                    NonEmptyCallArgListId::UniquelyLabeled(original_arg_list_id),
+                ) => {
+                    let copy_arg_ids = registry.get_list(copy_arg_list_id);
+                    for original_arg_id in registry.get_list(original_arg_list_id).iter() {
+                        let label = &registry.get(original_arg_id.label_id()).name;
+                        if let Some(copy_arg_id) = copy_arg_ids
+                            .iter()
+                            .find(|copy_arg_id| &registry.get(copy_arg_id.label_id()).name == label)
+                        {
+                            copy_expression_erasure(
+                                registry,
+                                erasure,
+                                bigint,
+                                copy_arg_id.value_id(),
+                                original_arg_id.value_id(),
+                            );
+                        }
+                    }
+                }
+                _ => return,
+            }
+            match erasure.erased_call_args.get(&copy_id).cloned() {
+                Some(erased_args) => {
+                    erasure.erased_call_args.insert(original_id, erased_args);
+                }
+                None => {
+                    erasure.erased_call_args.remove(&original_id);
+                }
+            }
+        }
+        (ExpressionId::Fun(copy_id), ExpressionId::Fun(original_id)) => {
+            let copy = registry.get(copy_id);
+            let original = registry.get(original_id);
+            copy_param_erasure(
+                registry,
+                erasure,
+                bigint,
+                copy.param_list_id,
+                original.param_list_id,
+            );
+            copy_expression_erasure(registry, erasure, bigint, copy.body_id, original.body_id);
+        }
+        (ExpressionId::Match(copy_id), ExpressionId::Match(original_id)) => {
+            let copy = registry.get(copy_id);
+            let original = registry.get(original_id);
+            if erasure.erased_matches.contains(&copy_id) {
+                erasure.erased_matches.insert(original_id);
+            }
+            copy_match_bigint_type(bigint, copy_id, original_id);
+            copy_expression_erasure(
+                registry,
+                erasure,
+                bigint,
+                copy.matchee_id,
+                original.matchee_id,
+            );
+            let copy_case_ids = registry.get_possibly_empty_list(copy.case_list_id);
+            let original_case_ids = registry.get_possibly_empty_list(original.case_list_id);
+            for (copy_case_id, original_case_id) in copy_case_ids.iter().zip(original_case_ids) {
+                let copy_output_id = registry.get(*copy_case_id).output_id;
+                let original_output_id = registry.get(*original_case_id).output_id;
+                if let (
+                    MatchCaseOutputId::Some(copy_output_id),
+                    MatchCaseOutputId::Some(original_output_id),
+                ) = (copy_output_id, original_output_id)
+                {
+                    copy_expression_erasure(
+                        registry,
+                        erasure,
+                        bigint,
+                        copy_output_id,
+                        original_output_id,
+                    );
+                }
+            }
+        }
+        (ExpressionId::Check(copy_id), ExpressionId::Check(original_id)) => {
+            let copy_output_id = registry.get(copy_id).output_id;
+            let original_output_id = registry.get(original_id).output_id;
+            copy_expression_erasure(
+                registry,
+                erasure,
+                bigint,
+                copy_output_id,
+                original_output_id,
+            );
+        }
+        (ExpressionId::Rewrite(copy_id), ExpressionId::Rewrite(original_id)) => {
+            let copy_body_id = registry.get(copy_id).body_id;
+            let original_body_id = registry.get(original_id).body_id;
+            copy_expression_erasure(registry, erasure, bigint, copy_body_id, original_body_id);
+        }
+        (ExpressionId::Projection(copy_id), ExpressionId::Projection(original_id)) => {
+            let copy_record_id = registry.get(copy_id).record_id;
+            let original_record_id = registry.get(original_id).record_id;
+            copy_expression_erasure(
+                registry,
+                erasure,
+                bigint,
+                copy_record_id,
+                original_record_id,
+            );
+        }
+        (ExpressionId::Pair(copy_id), ExpressionId::Pair(original_id)) => {
+            let copy = registry.get(copy_id);
+            let original = registry.get(original_id);
+            copy_expression_erasure(
+                registry,
+                erasure,
+                bigint,
+                copy.witness_id,
+                original.witness_id,
+            );
+            copy_expression_erasure(registry, erasure, bigint, copy.proof_id, original.proof_id);
+        }
+        // The remaining expressions have no runtime subexpressions.
+        _ => {}
+    }
+}
+
+fn copy_param_erasure(
+    registry: &NodeRegistry,
+    erasure: &mut ErasureAnalysis,
+    bigint: &mut BigIntAnalysis,
+    copy_param_list_id: NonEmptyParamListId,
+    original_param_list_id: NonEmptyParamListId,
+) {
+    match (copy_param_list_id, original_param_list_id) {
+        (
+            NonEmptyParamListId::Unlabeled(copy_param_list_id),
+            NonEmptyParamListId::Unlabeled(original_param_list_id),
+        ) => {
+            let copy_param_ids = registry.get_list(copy_param_list_id);
+            let original_param_ids = registry.get_list(original_param_list_id);
+            for (copy_param_id, original_param_id) in
+                copy_param_ids.iter().zip(original_param_ids.iter())
+            {
+                if erasure.erased_unlabeled_params.contains(copy_param_id) {
+                    erasure.erased_unlabeled_params.insert(*original_param_id);
+                }
+            }
+        }
+        (
+            NonEmptyParamListId::UniquelyLabeled(copy_param_list_id),
+            NonEmptyParamListId::UniquelyLabeled(original_param_list_id),
+        ) => {
+            let copy_param_ids = registry.get_list(copy_param_list_id);
+            let original_param_ids = registry.get_list(original_param_list_id);
+            for (copy_param_id, original_param_id) in
+                copy_param_ids.iter().zip(original_param_ids.iter())
+            {
+                if erasure.erased_labeled_params.contains(copy_param_id) {
+                    erasure.erased_labeled_params.insert(*original_param_id);
+                }
+                let copy_default_id = registry.get(*copy_param_id).default_id;
+                let original_default_id = registry.get(*original_param_id).default_id;
+                if let (Some(copy_default_id), Some(original_default_id)) =
+                    (copy_default_id, original_default_id)
+                {
+                    copy_expression_erasure(
+                        registry,
+                        erasure,
+                        bigint,
+                        copy_default_id,
+                        original_default_id,
+                    );
+                }
+            }
+        }
+        _ => {}
+    }
+}
@@ -0,0 +1,435 @@
+use super::*;
+
+/// Returns `true` if the two normal forms are equal
+/// up to eta-conversion.
+/// For example, `f` is considered equal to `fun _(x: A): B { f(x) }`,
+/// and a struct value `s` is considered equal to `S.new(~a: s.a, ~b: s.b)`.
+///
+/// Apart from eta-conversion, this considers the same things
+/// insignificant as `NodeEqualityChecker::eq` does
+/// (e.g., spans, names, param dashes, and match case order).
+pub(super) fn are_normal_forms_eta_equal(
+    state: &mut State,
+    left: NormalFormId,
+    right: NormalFormId,
+) -> bool {
+    are_normal_forms_eta_equal_at_depth(state, left, right, 0)
+}
+
+/// `depth` is the number of binders `left` and `right` are nested in.
+/// These binders are _not_ in the context, so we need to account for them
+/// when looking up struct constructors.
+pub(super) fn are_normal_forms_eta_equal_at_depth(
+    state: &mut State,
+    left: NormalFormId,
+    right: NormalFormId,
+    depth: usize,
+) -> bool {
+    are_expressions_eta_equal(state, left.raw(), right.raw(), depth)
+}
+
+fn are_expressions_eta_equal(
+    state: &mut State,
+    left: ExpressionId,
+    right: ExpressionId,
+    depth: usize,
+) -> bool {
+    // Fast path: most comparisons are between
+    // terms that are already syntactically equal.
+    if state.equality_checker.eq(left, right, state.registry) {
+        return true;
+    }
+
+    match (left, right) {
+        (ExpressionId::Fun(left_id), ExpressionId::Fun(right_id)) => {
+            are_funs_structurally_eta_equal(state, left_id, right_id, depth)
+                || is_fun_eta_expansion_of(state, left_id, right, depth)
+                || is_fun_eta_expansion_of(state, right_id, left, depth)
+        }
+        (ExpressionId::Fun(left_id), _) => is_fun_eta_expansion_of(state, left_id, right, depth),
+        (_, ExpressionId::Fun(right_id)) => is_fun_eta_expansion_of(state, right_id, left, depth),
+
+        (ExpressionId::Call(left_id), ExpressionId::Call(right_id)) => {
+            let left_call = state.registry.get(left_id).clone();
+            let right_call = state.registry.get(right_id).clone();
+            (are_expressions_eta_equal(state, left_call.callee_id, right_call.callee_id, depth)
+                && are_call_arg_lists_eta_equal(
+                    state,
+                    left_call.arg_list_id,
+                    right_call.arg_list_id,
+                    depth,
+                ))
+                || is_struct_eta_expansion_of(state, left_id, right, depth)
+                || is_struct_eta_expansion_of(state, right_id, left, depth)
+        }
+        (ExpressionId::Call(left_id), _) => {
+            is_struct_eta_expansion_of(state, left_id, right, depth)
+        }
+        (_, ExpressionId::Call(right_id)) => {
+            is_struct_eta_expansion_of(state, right_id, left, depth)
+        }
+
+        (ExpressionId::Match(left_id), ExpressionId::Match(right_id)) => {
+            are_matches_eta_equal(state, left_id, right_id, depth)
+        }
+        (ExpressionId::Forall(left_id), ExpressionId::Forall(right_id)) => {
+            let left = state.registry.get(left_id).clone();
+            let right = state.registry.get(right_id).clone();
+            are_param_lists_eta_equal(state, left.param_list_id, right.param_list_id, depth)
+                && are_expressions_eta_equal(
+                    state,
+                    left.output_id,
+                    right.output_id,
+                    depth + left.param_list_id.len(),
+                )
+        }
+        (ExpressionId::Exists(left_id), ExpressionId::Exists(right_id)) => {
+            let left = state.registry.get(left_id).clone();
+            let right = state.registry.get(right_id).clone();
+            let left_param_type_id = state.registry.get(left.param_id).type_id;
+            let right_param_type_id = state.registry.get(right.param_id).type_id;
+            are_expressions_eta_equal(state, left_param_type_id, right_param_type_id, depth)
+                && are_expressions_eta_equal(state, left.output_id, right.output_id, depth + 1)
+        }
+        (ExpressionId::Pair(left_id), ExpressionId::Pair(right_id)) => {
+            let left = state.registry.get(left_id).clone();
+            let right = state.registry.get(right_id).clone();
+            are_expressions_eta_equal(state, left.witness_id, right.witness_id, depth)
+                && are_expressions_eta_equal(state, left.proof_id, right.proof_id, depth)
+        }
+        (ExpressionId::Projection(left_id), ExpressionId::Projection(right_id)) => {
+            let left = state.registry.get(left_id).clone();
+            let right = state.registry.get(right_id).clone();
+            state.registry.get(left.field_name_id).name
+                == state.registry.get(right.field_name_id).name
+                && are_expressions_eta_equal(state, left.record_id, right.record_id, depth)
+        }
+
+        // Names and `todo`s have no subterms, so if they are not
+        // syntactically equal, they are not equal at all.
+        _ => false,
+    }
+}
+
+fn are_funs_structurally_eta_equal(
+    state: &mut State,
+    left_id: NodeId<Fun>,
+    right_id: NodeId<Fun>,
+    depth: usize,
+) -> bool {
+    let left = state.registry.get(left_id).clone();
+    let right = state.registry.get(right_id).clone();
+    let arity = left.param_list_id.len();
+    are_param_lists_eta_equal(state, left.param_list_id, right.param_list_id, depth)
+        && are_expressions_eta_equal(
+            state,
+            left.return_type_id,
+            right.return_type_id,
+            depth + arity,
+        )
+        && are_expressions_eta_equal(state, left.body_id, right.body_id, depth + arity + 1)
+}
+
+/// Returns `true` if `fun_id` is equal to `fun _(x1: A1, ..., xn: An): B { other(x1, ..., xn) }`
+/// (with labeled args if the fun has labeled params).
+fn is_fun_eta_expansion_of(
+    state: &mut State,
+    fun_id: NodeId<Fun>,
+    other: ExpressionId,
+    depth: usize,
+) -> bool {
+    let fun = state.registry.get(fun_id).clone();
+    let arity = fun.param_list_id.len();
+    // Inside the fun's body, the params are at indices `arity..=1`,
+    // and the fun itself is at index 0.
+    let shifted_other = other.upshift(arity + 1, state.registry);
+    let arg_list_id = match fun.param_list_id {
+        NonEmptyParamListId::Unlabeled(param_list_id) => {
+            let param_ids = state.registry.get_list(param_list_id).to_non_empty_vec();
+            let arg_ids = param_ids.enumerate_into_mapped(|(param_index, param_id)| {
+                let name_id = state.registry.get(param_id).name_id;
+                ExpressionId::Name(add_name_expression(
+                    state.registry,
+                    NonEmptyVec::singleton(name_id),
+                    DbIndex(arity - param_index),
+                ))
+            });
+            NonEmptyCallArgListId::Unlabeled(state.registry.add_list(arg_ids))
+        }
+        NonEmptyParamListId::UniquelyLabeled(param_list_id) => {
+            let param_ids = state.registry.get_list(param_list_id).to_non_empty_vec();
+            let arg_ids = param_ids.enumerate_into_mapped(|(param_index, param_id)| {
+                let param = state.registry.get(param_id);
+                let label_id = param.label_identifier_id();
+                let name_id = param.name_id;
+                LabeledCallArgId::Explicit {
+                    label_id,
+                    value_id: ExpressionId::Name(add_name_expression(
+                        state.registry,
+                        NonEmptyVec::singleton(name_id),
+                        DbIndex(arity - param_index),
+                    )),
+                }
+            });
+            NonEmptyCallArgListId::UniquelyLabeled(state.registry.add_list(arg_ids))
+        }
+    };
+    let expanded_body_id = ExpressionId::Call(state.registry.add_and_overwrite_id(Call {
+        id: dummy_id(),
+        span: None,
+        callee_id: shifted_other,
+        arg_list_id,
+    }));
+    are_expressions_eta_equal(state, fun.body_id, expanded_body_id, depth + arity + 1)
+}
+
+pub(super) fn are_param_lists_eta_equal(
+    state: &mut State,
+    left: NonEmptyParamListId,
+    right: NonEmptyParamListId,
+    depth: usize,
+) -> bool {
+    match (left, right) {
+        (NonEmptyParamListId::Unlabeled(left), NonEmptyParamListId::Unlabeled(right)) => {
+            let left = state.registry.get_list(left).to_vec();
+            let right = state.registry.get_list(right).to_vec();
+            left.len() == right.len()
+                && left
+                    .into_iter()
+                    .zip(right)
+                    .enumerate()
+                    .all(|(param_index, (left, right))| {
+                        let left_type_id = state.registry.get(left).type_id;
+                        let right_type_id = state.registry.get(right).type_id;
+                        are_expressions_eta_equal(
+                            state,
+                            left_type_id,
+                            right_type_id,
+                            depth + param_index,
+                        )
+                    })
+        }
+        (
+            NonEmptyParamListId::UniquelyLabeled(left),
+            NonEmptyParamListId::UniquelyLabeled(right),
+        ) => {
+            let left = state.registry.get_list(left).to_vec();
+            let right = state.registry.get_list(right).to_vec();
+            left.len() == right.len()
+                && left
+                    .into_iter()
+                    .zip(right)
+                    .enumerate()
+                    .all(|(param_index, (left, right))| {
+                        let left = state.registry.get(left).clone();
+                        let right = state.registry.get(right).clone();
+                        state.registry.get(left.label_identifier_id()).name
+                            == state.registry.get(right.label_identifier_id()).name
+                            && are_expressions_eta_equal(
+                                state,
+                                left.type_id,
+                                right.type_id,
+                                depth + param_index,
+                            )
+                    })
+        }
+        _ => false,
+    }
+}
+
+fn are_call_arg_lists_eta_equal(
+    state: &mut State,
+    left: NonEmptyCallArgListId,
+    right: NonEmptyCallArgListId,
+    depth: usize,
+) -> bool {
+    match (left, right) {
+        (NonEmptyCallArgListId::Unlabeled(left), NonEmptyCallArgListId::Unlabeled(right)) => {
+            let left = state.registry.get_list(left).to_vec();
+            let right = state.registry.get_list(right).to_vec();
+            left.len() == right.len()
+                && left
+                    .into_iter()
+                    .zip(right)
+                    .all(|(left, right)| are_expressions_eta_equal(state, left, right, depth))
+        }
+        (
+            NonEmptyCallArgListId::UniquelyLabeled(left),
+            NonEmptyCallArgListId::UniquelyLabeled(right),
+        ) => {
+            let left = state.registry.get_list(left).to_vec();
+            let right = state.registry.get_list(right).to_vec();
+            // Labeled args are unordered, so we pair them up by label.
+            left.len() == right.len()
+                && left.into_iter().all(|left| {
+                    let label_name = &state.registry.get(left.label_id()).name;
+                    let Some(right) = right
+                        .iter()
+                        .find(|right| &state.registry.get(right.label_id()).name == label_name)
+                        .copied()
+                    else {
+                        return false;
+                    };
+                    are_expressions_eta_equal(state, left.value_id(), right.value_id(), depth)
+                })
+        }
+        _ => false,
+    }
+}
+
+fn are_matches_eta_equal(
+    state: &mut State,
+    left_id: NodeId<Match>,
+    right_id: NodeId<Match>,
+    depth: usize,
+) -> bool {
+    let left = state.registry.get(left_id).clone();
+    let right = state.registry.get(right_id).clone();
+    if !are_expressions_eta_equal(state, left.matchee_id, right.matchee_id, depth) {
+        return false;
+    }
+
+    let left_case_ids = state
+        .registry
+        .get_possibly_empty_list(left.case_list_id)
+        .to_vec();
+    let right_case_ids = state
+        .registry
+        .get_possibly_empty_list(right.case_list_id)
+        .to_vec();
+    // Match cases are unordered, so we pair them up by variant name.
+    left_case_ids.len() == right_case_ids.len()
+        && left_case_ids.into_iter().all(|left_case_id| {
+            let left_case = state.registry.get(left_case_id).clone();
+            let variant_name = &state.registry.get(left_case.variant_name_id).name;
+            let Some(right_case) = right_case_ids
+                .iter()
+                .map(|&right_case_id| state.registry.get(right_case_id))
+                .find(|right_case| {
+                    &state.registry.get(right_case.variant_name_id).name == variant_name
+                })
+                .cloned()
+            else {
+                return false;
+            };
+            if left_case.param_list_id.len() != right_case.param_list_id.len() {
+                return false;
+            }
+            match (left_case.output_id, right_case.output_id) {
+                (
+                    MatchCaseOutputId::Some(left_output_id),
+                    MatchCaseOutputId::Some(right_output_id),
+                ) => are_expressions_eta_equal(
+                    state,
+                    left_output_id,
+                    right_output_id,
+                    depth + left_case.param_list_id.len(),
+                ),
+                (
+                    MatchCaseOutputId::ImpossibilityClaim(_),
+                    MatchCaseOutputId::ImpossibilityClaim(_),
+                ) => true,
+                _ => false,
+            }
+        })
+}
+
+/// Returns `true` if `call_id` is equal to
+/// `S.new(~T1: t1, ..., ~f1: other.f1, ..., ~fn: other.fn)`,
+/// where `S` is a struct with params `T1, ...` and fields `f1, ..., fn`
+/// (and `n` is at least 1).
+fn is_struct_eta_expansion_of(
+    state: &mut State,
+    call_id: NodeId<Call>,
+    other: ExpressionId,
+    depth: usize,
+) -> bool {
+    let call = state.registry.get(call_id).clone();
+    let (ExpressionId::Name(callee_id), NonEmptyCallArgListId::UniquelyLabeled(arg_list_id)) =
+        (call.callee_id, call.arg_list_id)
+    else {
+        return false;
+    };
+    let Some(field_label_ids) = get_struct_constructor_field_label_ids(state, callee_id, depth)
+    else {
+        return false;
+    };
+    if field_label_ids.is_empty() {
+        return false;
+    }
+
+    let arg_ids = state.registry.get_list(arg_list_id).to_vec();
+    field_label_ids.into_iter().all(|field_label_id| {
+        let field_name = &state.registry.get(field_label_id).name;
+        let Some(arg_id) = arg_ids
+            .iter()
+            .find(|arg_id| &state.registry.get(arg_id.label_id()).name == field_name)
+            .copied()
+        else {
+            return false;
+        };
+        let ExpressionId::Projection(projection_id) = arg_id.value_id() else {
+            return false;
+        };
+        let projection = state.registry.get(projection_id).clone();
+        state.registry.get(projection.field_name_id).name == state.registry.get(field_label_id).name
+            && are_expressions_eta_equal(state, projection.record_id, other, depth)
+    })
+}
+
+/// If the name refers to the constructor of a struct,
+/// this returns the labels of the struct's fields
+/// (i.e., the constructor's labels, minus the ones that
+/// correspond to the struct's params).
+fn get_struct_constructor_field_label_ids(
+    state: &mut State,
+    name_id: NodeId<NameExpression>,
+    depth: usize,
+) -> Option<Vec<NodeId<Identifier>>> {
+    let db_index = state.registry.get(name_id).db_index;
+    // Names with indices less than `depth` refer to binders
+    // that are not in the context (and are therefore not constructors).
+    let constructor_dbi = DbIndex(db_index.0.checked_sub(depth)?);
+    let ContextEntryDefinition::Variant { .. } = state
+        .context
+        .get_definition(constructor_dbi, state.registry)
+    else {
+        return None;
+    };
+    // A struct's constructor is the entry directly after the struct's type.
+    let type_dbi = DbIndex(constructor_dbi.0 + 1);
+    if type_dbi.0 >= state.context.len() {
+        return None;
+    }
+    let ContextEntryDefinition::Adt {
+        is_struct: true, ..
+    } = state.context.get_definition(type_dbi, state.registry)
+    else {
+        return None;
+    };
+
+    let type_arity = match state.context.get_type(type_dbi, state.registry).raw() {
+        ExpressionId::Forall(forall_id) => state.registry.get(forall_id).param_list_id.len(),
+        _ => 0,
+    };
+    let ExpressionId::Forall(constructor_type_id) = state
+        .context
+        .get_type(constructor_dbi, state.registry)
+        .raw()
+    else {
+        return Some(vec![]);
+    };
+    let NonEmptyParamListId::UniquelyLabeled(param_list_id) =
+        state.registry.get(constructor_type_id).param_list_id
+    else {
+        return None;
+    };
+    let param_ids = state.registry.get_list(param_list_id).to_vec();
+    Some(
+        param_ids
+            .into_iter()
+            .skip(type_arity)
+            .map(|param_id| state.registry.get(param_id).label_identifier_id())
+            .collect(),
+    )
+}
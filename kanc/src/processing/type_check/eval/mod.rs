@@ -0,0 +1,95 @@
+use super::*;
+
+mod nbe;
+
+pub use reduction_trace::*;
+mod reduction_trace;
+
+pub use reduction_tracker::ReductionProfile;
+pub(super) use reduction_tracker::ReductionTracker;
+mod reduction_tracker;
+
+mod substitution;
+
+/// The strategy used to compute normal forms.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Evaluator {
+    /// Evaluates expressions into closures over environments,
+    /// and then reads the result back into a normal form.
+    #[default]
+    NormalizationByEvaluation,
+    /// Repeatedly substitutes arguments into registry nodes
+    /// (shifting De Bruijn indices as needed).
+    Substitution,
+}
+
+pub(super) fn evaluate_well_typed_expression(state: &mut State, id: ExpressionId) -> NormalFormId {
+    let key = NormalFormCacheKey {
+        expression_id: state
+            .equality_checker
+            .default_sensitive_semantic_id(id, state.registry),
+        required_transparency_for_substitution: state.required_transparency_for_substitution,
+    };
+    if let Some(cached) = state.context.get_cached_normal_form(key) {
+        state.normal_form_cache_stats.hits += 1;
+        return cached;
+    }
+    state.normal_form_cache_stats.misses += 1;
+
+    let normal_form_id = evaluate_well_typed_expression_without_cache(state, id);
+    // If we ran out of reduction steps, the result is not
+    // necessarily a normal form, so we must not cache it.
+    if !state.reduction_tracker.is_limit_exceeded() {
+        state.context.cache_normal_form(key, normal_form_id);
+    }
+    normal_form_id
+}
+
+/// Like `evaluate_well_typed_expression`, except that if a check assertion
+/// is being traced, this records every reduction step taken.
+/// In that case, the normal form cache is bypassed
+/// (otherwise, a cache hit would hide the steps).
+pub(super) fn evaluate_well_typed_comparee(state: &mut State, id: ExpressionId) -> NormalFormId {
+    if !state.reduction_tracker.is_tracing_check_assertion() {
+        return evaluate_well_typed_expression(state, id);
+    }
+
+    state.reduction_tracker.begin_traced_normalization(id);
+    let normal_form_id = evaluate_well_typed_expression_without_cache(state, id);
+    state
+        .reduction_tracker
+        .end_traced_normalization(normal_form_id);
+    normal_form_id
+}
+
+/// Like `evaluate_well_typed_expression`, except that every alias may be
+/// unfolded (regardless of its transparency),
+/// and at most `max_steps` reduction steps may be taken.
+/// Returns `None` if the steps ran out.
+/// The steps don't count toward the reduction step limit or profile,
+/// and the result is not cached.
+pub(super) fn try_evaluate_well_typed_expression_speculatively(
+    state: &mut State,
+    id: ExpressionId,
+    max_steps: usize,
+) -> Option<NormalFormId> {
+    let required_transparency = state.required_transparency_for_substitution.take();
+    state
+        .reduction_tracker
+        .begin_speculative_normalization(max_steps);
+    let normal_form_id = evaluate_well_typed_expression_without_cache(state, id);
+    let is_complete = state.reduction_tracker.end_speculative_normalization();
+    state.required_transparency_for_substitution = required_transparency;
+    is_complete.then_some(normal_form_id)
+}
+
+fn evaluate_well_typed_expression_without_cache(
+    state: &mut State,
+    id: ExpressionId,
+) -> NormalFormId {
+    state.reduction_tracker.begin_normalization(id);
+    match state.evaluator {
+        Evaluator::NormalizationByEvaluation => nbe::evaluate_well_typed_expression(state, id),
+        Evaluator::Substitution => substitution::evaluate_well_typed_expression(state, id),
+    }
+}
@@ -0,0 +1,1040 @@
+//! A normalization-by-evaluation (NbE) evaluator.
+//!
+//! Instead of repeatedly substituting into (and shifting) registry nodes,
+//! we evaluate expressions into a semantic domain of [`Value`]s,
+//! where binders are represented by closures that capture an environment.
+//! Once evaluation is complete, we "read back" the value into
+//! a normal form, which is the only point where we register new nodes.
+//!
+//! The evaluator is meant to be a drop-in replacement for the
+//! substitution-based evaluator, so it follows the same rules:
+//! 1. An alias is only unfolded if its transparency is
+//!    at least as permissive as `required_transparency_for_substitution`.
+//! 2. A function with a decreasing (i.e., dashed) parameter is only
+//!    applied if the argument for that parameter is a variant expression.
+//!    Otherwise, the call is stuck.
+//! 3. The value of an alias is (re-)evaluated under the current
+//!    transparency requirement, rather than the requirement
+//!    it was originally normalized under.
+
+use super::*;
+
+use std::rc::Rc;
+
+use rustc_hash::FxHashMap;
+
+#[derive(Debug)]
+struct NbeState<'a, 'b> {
+    raw: &'a mut ContextlessState<'b>,
+    context: &'b Context,
+    /// Caches the values of the aliases unfolded
+    /// so far, keyed by their level in `context`.
+    alias_values: FxHashMap<usize, Value>,
+}
+
+#[derive(Clone, Debug)]
+enum Value {
+    Neutral(Rc<Neutral>),
+    Fun(Rc<Closure<NodeId<Fun>>>),
+    Forall(Rc<Closure<NodeId<Forall>>>),
+    Exists(Rc<Closure<NodeId<Exists>>>),
+    Pair(Rc<PairValue>),
+}
+
+#[derive(Debug)]
+struct PairValue {
+    witness: Value,
+    proof: Value,
+}
+
+#[derive(Debug)]
+enum Neutral {
+    Var {
+        level: DbLevel,
+        component_list_id: NonEmptyListId<NodeId<Identifier>>,
+    },
+    Todo(NodeId<TodoExpression>),
+    Call {
+        callee: Value,
+        args: ArgValues,
+    },
+    Match {
+        matchee: Value,
+        env: Env,
+        motive_id: Option<NodeId<MatchMotive>>,
+        case_list_id: Option<NonEmptyListId<NodeId<MatchCase>>>,
+    },
+    Projection {
+        record: Value,
+        field_name_id: NodeId<Identifier>,
+    },
+    /// A rewrite whose proof is not (yet) `refl`.
+    /// Like a match on the proof, it must stay stuck,
+    /// since the equality it rewrites along may be absurd.
+    Rewrite {
+        proof: Value,
+        env: Env,
+        rewrite_id: NodeId<Rewrite>,
+    },
+}
+
+#[derive(Clone, Debug)]
+enum ArgValues {
+    Unlabeled(NonEmptyVec<Value>),
+    UniquelyLabeled(NonEmptyVec<LabeledArgValue>),
+}
+
+#[derive(Clone, Debug)]
+struct LabeledArgValue {
+    label_id: NodeId<Identifier>,
+    value: Value,
+    /// Implicitly labeled args (e.g., `f(:x)`) are
+    /// syntactically distinct from their explicit
+    /// counterparts (e.g., `f(x: x)`), so we need to
+    /// remember which kind of arg we are dealing with.
+    /// This is only true if the arg's value is
+    /// an uninterpreted variable.
+    is_implicit: bool,
+}
+
+#[derive(Debug)]
+struct Closure<T> {
+    env: Env,
+    id: T,
+}
+
+/// Every name with a De Bruijn index less than `locals.len()`
+/// refers to an entry of `locals`.
+/// Every other name refers to one of the first
+/// `outer_len` entries of the context.
+#[derive(Clone, Debug)]
+struct Env {
+    outer_len: usize,
+    /// The last local has De Bruijn index 0.
+    locals: Vec<Local>,
+}
+
+#[derive(Clone, Debug)]
+struct Local {
+    value: Value,
+    /// `true` if the local is a parameter that was bound
+    /// to an argument (or a match case parameter that was bound
+    /// to a matchee argument), and `false` if the local is
+    /// an uninterpreted variable introduced during read-back.
+    was_substituted: bool,
+}
+
+impl Env {
+    fn empty(outer_len: usize) -> Self {
+        Self {
+            outer_len,
+            locals: vec![],
+        }
+    }
+
+    fn push(&mut self, value: Value, was_substituted: bool) {
+        self.locals.push(Local {
+            value,
+            was_substituted,
+        });
+    }
+
+    fn get_local(&self, db_index: DbIndex) -> Option<&Local> {
+        let len = self.locals.len();
+        if db_index.0 < len {
+            Some(&self.locals[len - 1 - db_index.0])
+        } else {
+            None
+        }
+    }
+
+    fn outer_level(&self, db_index: DbIndex) -> DbLevel {
+        DbLevel(self.outer_len - 1 - (db_index.0 - self.locals.len()))
+    }
+}
+
+pub(super) fn evaluate_well_typed_expression(state: &mut State, id: ExpressionId) -> NormalFormId {
+    let (context, mut contextless) = state.detach_context();
+    let context_len = context.len();
+    let mut state = NbeState {
+        raw: &mut contextless,
+        context,
+        alias_values: FxHashMap::default(),
+    };
+    let value = eval(&mut state, &Env::empty(context_len), id);
+    // Read-back only registers span-less nodes, but their leaves
+    // (e.g., identifiers) may still have spans, so we strip them
+    // once at the end (rather than once per registered node,
+    // which would take quadratic time for deeply nested values).
+    read_back(&mut state, context_len, &value).without_spans(state.raw.registry)
+}
+
+fn var(level: DbLevel, component_list_id: NonEmptyListId<NodeId<Identifier>>) -> Value {
+    Value::Neutral(Rc::new(Neutral::Var {
+        level,
+        component_list_id,
+    }))
+}
+
+fn fresh_var(state: &mut NbeState, level: usize, name_id: NodeId<Identifier>) -> Value {
+    let component_list_id = state.raw.registry.add_list(NonEmptyVec::singleton(name_id));
+    var(DbLevel(level), component_list_id)
+}
+
+fn eval(state: &mut NbeState, env: &Env, id: ExpressionId) -> Value {
+    match id {
+        ExpressionId::Name(name_id) => eval_name_expression(state, env, name_id),
+        ExpressionId::Todo(todo_id) => Value::Neutral(Rc::new(Neutral::Todo(todo_id))),
+        ExpressionId::Call(call_id) => eval_call(state, env, call_id),
+        ExpressionId::Fun(fun_id) => Value::Fun(Rc::new(Closure {
+            env: env.clone(),
+            id: fun_id,
+        })),
+        ExpressionId::Match(match_id) => eval_match(state, env, match_id),
+        ExpressionId::Forall(forall_id) => Value::Forall(Rc::new(Closure {
+            env: env.clone(),
+            id: forall_id,
+        })),
+        ExpressionId::Check(check_id) => {
+            let output_id = state.raw.registry.get(check_id).output_id;
+            eval(state, env, output_id)
+        }
+        ExpressionId::Rewrite(rewrite_id) => eval_rewrite(state, env, rewrite_id),
+        ExpressionId::Projection(projection_id) => eval_projection(state, env, projection_id),
+        ExpressionId::Exists(exists_id) => Value::Exists(Rc::new(Closure {
+            env: env.clone(),
+            id: exists_id,
+        })),
+        ExpressionId::Pair(pair_id) => {
+            let pair = state.raw.registry.get(pair_id).clone();
+            let witness = eval(state, env, pair.witness_id);
+            let proof = eval(state, env, pair.proof_id);
+            Value::Pair(Rc::new(PairValue { witness, proof }))
+        }
+    }
+}
+
+fn eval_name_expression(state: &mut NbeState, env: &Env, name_id: NodeId<NameExpression>) -> Value {
+    let name = state.raw.registry.get(name_id);
+    let (db_index, component_list_id) = (name.db_index, name.component_list_id);
+    if let Some(local) = env.get_local(db_index) {
+        return local.value.clone();
+    }
+
+    let level = env.outer_level(db_index);
+    try_unfold_alias(state, level).unwrap_or_else(|| var(level, component_list_id))
+}
+
+/// Returns `None` if the entry at `level` is not an alias,
+/// or if it is an alias that is not transparent enough
+/// to be unfolded.
+fn try_unfold_alias(state: &mut NbeState, level: DbLevel) -> Option<Value> {
+    let ContextEntryDefinition::Alias {
+        value_id,
+        transparency,
+        let_id,
+        ..
+    } = state.context.get_local_definition(level)
+    else {
+        return None;
+    };
+
+    let can_substitute =
+        if let Some(required_transparency) = state.raw.required_transparency_for_substitution {
+            is_left_at_least_as_permissive_as_right(
+                state.raw.file_tree,
+                transparency.0,
+                required_transparency.0,
+            )
+        } else {
+            true
+        };
+    if !can_substitute {
+        state
+            .raw
+            .reduction_tracker
+            .record_trace_event(ReductionTraceEventKind::DeltaBlockedByTransparency(let_id));
+        return None;
+    }
+
+    state
+        .raw
+        .reduction_tracker
+        .record_trace_event(ReductionTraceEventKind::Delta(let_id));
+    if let Some(value) = state.alias_values.get(&level.0) {
+        return Some(value.clone());
+    }
+    // The alias value is expressed relative to its position
+    // in the context, so only the entries before it are visible.
+    let value = eval(state, &Env::empty(level.0), value_id.raw());
+    if let Value::Fun(fun) = &value {
+        let fun_name_id = state.raw.registry.get(fun.id).name_id;
+        state
+            .raw
+            .reduction_tracker
+            .note_fun_unfolded_from_let(fun_name_id, let_id);
+    }
+    state.alias_values.insert(level.0, value.clone());
+    Some(value)
+}
+
+fn eval_call(state: &mut NbeState, env: &Env, call_id: NodeId<Call>) -> Value {
+    let call = state.raw.registry.get(call_id).clone();
+    let callee = eval(state, env, call.callee_id);
+    let args = eval_call_arg_list(state, env, call.arg_list_id);
+    apply(state, callee, args)
+}
+
+fn eval_call_arg_list(
+    state: &mut NbeState,
+    env: &Env,
+    arg_list_id: NonEmptyCallArgListId,
+) -> ArgValues {
+    match arg_list_id {
+        NonEmptyCallArgListId::Unlabeled(arg_list_id) => ArgValues::Unlabeled(
+            state
+                .raw
+                .registry
+                .get_list(arg_list_id)
+                .to_non_empty_vec()
+                .into_mapped(|arg_id| eval(state, env, arg_id)),
+        ),
+        NonEmptyCallArgListId::UniquelyLabeled(arg_list_id) => ArgValues::UniquelyLabeled(
+            state
+                .raw
+                .registry
+                .get_list(arg_list_id)
+                .to_non_empty_vec()
+                .into_mapped(|arg_id| eval_labeled_call_arg(state, env, arg_id)),
+        ),
+    }
+}
+
+fn eval_labeled_call_arg(
+    state: &mut NbeState,
+    env: &Env,
+    arg_id: LabeledCallArgId,
+) -> LabeledArgValue {
+    match arg_id {
+        LabeledCallArgId::Implicit {
+            label_id,
+            db_index,
+            value_id,
+        } => {
+            if let Some(local) = env.get_local(db_index) {
+                return LabeledArgValue {
+                    label_id,
+                    value: local.value.clone(),
+                    is_implicit: !local.was_substituted,
+                };
+            }
+
+            let level = env.outer_level(db_index);
+            if let Some(value) = try_unfold_alias(state, level) {
+                return LabeledArgValue {
+                    label_id,
+                    value,
+                    is_implicit: false,
+                };
+            }
+
+            let component_list_id = state.raw.registry.get(value_id).component_list_id;
+            LabeledArgValue {
+                label_id,
+                value: var(level, component_list_id),
+                is_implicit: true,
+            }
+        }
+        LabeledCallArgId::Explicit { label_id, value_id } => LabeledArgValue {
+            label_id,
+            value: eval(state, env, value_id),
+            is_implicit: false,
+        },
+    }
+}
+
+fn apply(state: &mut NbeState, callee: Value, args: ArgValues) -> Value {
+    let fun = match &callee {
+        Value::Fun(fun) => fun.clone(),
+        Value::Neutral(_) => return Value::Neutral(Rc::new(Neutral::Call { callee, args })),
+        Value::Forall(_) => panic!("A well-typed Call cannot have a Forall as its callee."),
+        Value::Exists(_) => panic!("A well-typed Call cannot have an Exists as its callee."),
+        Value::Pair(_) => panic!("A well-typed Call cannot have a Pair as its callee."),
+    };
+
+    let fun_node = state.raw.registry.get(fun.id).clone();
+    let Some(ordered_args) = order_args_if_fun_can_be_applied(state, fun_node.param_list_id, &args)
+    else {
+        state.raw.reduction_tracker.record_trace_event(
+            ReductionTraceEventKind::BetaBlockedByDecreasingArg(fun_node.name_id),
+        );
+        return Value::Neutral(Rc::new(Neutral::Call { callee, args }));
+    };
+    if !state
+        .raw
+        .reduction_tracker
+        .try_step(ReductionTraceEventKind::Beta(fun_node.name_id))
+    {
+        return Value::Neutral(Rc::new(Neutral::Call { callee, args }));
+    }
+
+    let mut env = fun.env.clone();
+    for arg in ordered_args {
+        env.push(arg, true);
+    }
+    env.push(callee, true);
+    let previous_let_id = state.raw.reduction_tracker.enter_fun_body(fun_node.name_id);
+    let output = eval(state, &env, fun_node.body_id);
+    state.raw.reduction_tracker.exit_fun_body(previous_let_id);
+    output
+}
+
+/// Returns the args in param order, or `None` if the function
+/// cannot be applied because the argument corresponding to the
+/// decreasing parameter is not a variant expression.
+/// In the latter case, applying the function could
+/// result in infinite expansion.
+fn order_args_if_fun_can_be_applied(
+    state: &mut NbeState,
+    param_list_id: NonEmptyParamListId,
+    args: &ArgValues,
+) -> Option<Vec<Value>> {
+    let (ordered_args, decreasing_param_index) = match (param_list_id, args) {
+        (NonEmptyParamListId::Unlabeled(param_list_id), ArgValues::Unlabeled(args)) => {
+            let decreasing_param_index = state
+                .raw
+                .registry
+                .get_list(param_list_id)
+                .iter()
+                .position(|&param_id| state.raw.registry.get(param_id).is_dashed);
+            (args.iter().cloned().collect::<Vec<_>>(), decreasing_param_index)
+        }
+        (NonEmptyParamListId::UniquelyLabeled(param_list_id), ArgValues::UniquelyLabeled(args)) => {
+            let param_ids = state.raw.registry.get_list(param_list_id).to_non_empty_vec();
+            let mut decreasing_param_index = None;
+            let ordered_args = param_ids
+                .iter()
+                .enumerate()
+                .map(|(param_index, &param_id)| {
+                    let param = state.raw.registry.get(param_id);
+                    if param.is_dashed {
+                        decreasing_param_index = Some(param_index);
+                    }
+                    let param_label_name =
+                        &state.raw.registry.get(param.label_identifier_id()).name;
+                    args.iter()
+                        .find(|arg| &state.raw.registry.get(arg.label_id).name == param_label_name)
+                        .expect("A well-typed labeled Call should have a labeled arg corresponding to each param label.")
+                        .value
+                        .clone()
+                })
+                .collect();
+            (ordered_args, decreasing_param_index)
+        }
+        _ => panic!("A well-typed Call should have labeled args if and only if its callee has labeled params."),
+    };
+
+    if let Some(decreasing_param_index) = decreasing_param_index {
+        try_as_variant(state, &ordered_args[decreasing_param_index])?;
+    }
+
+    Some(ordered_args)
+}
+
+/// If the value is a variant expression, this returns
+/// the variant's name and args.
+fn try_as_variant<'v>(
+    state: &NbeState,
+    value: &'v Value,
+) -> Option<(NodeId<Identifier>, Option<&'v ArgValues>)> {
+    let Value::Neutral(neutral) = value else {
+        return None;
+    };
+    match &**neutral {
+        Neutral::Var { level, .. } => {
+            get_variant_name_id(state, *level).map(|name_id| (name_id, None))
+        }
+        Neutral::Call {
+            callee: Value::Neutral(callee),
+            args,
+        } => match &**callee {
+            Neutral::Var { level, .. } => {
+                get_variant_name_id(state, *level).map(|name_id| (name_id, Some(args)))
+            }
+            _ => None,
+        },
+        _ => None,
+    }
+}
+
+fn get_variant_name_id(state: &NbeState, level: DbLevel) -> Option<NodeId<Identifier>> {
+    if level.0 >= state.context.len() {
+        // Variables introduced during read-back are uninterpreted.
+        return None;
+    }
+    match state.context.get_local_definition(level) {
+        ContextEntryDefinition::Variant { name_id, .. } => Some(name_id),
+        _ => None,
+    }
+}
+
+fn eval_match(state: &mut NbeState, env: &Env, match_id: NodeId<Match>) -> Value {
+    let match_ = state.raw.registry.get(match_id).clone();
+    let matchee = eval(state, env, match_.matchee_id);
+
+    if let Value::Pair(pair) = &matchee {
+        if let Some(output) = try_eval_pair_match(state, env, &match_, pair) {
+            return output;
+        }
+    }
+
+    let (variant_name_id, matchee_args) = match try_as_variant(state, &matchee) {
+        Some((variant_name_id, matchee_args))
+            if state
+                .raw
+                .reduction_tracker
+                .try_step(ReductionTraceEventKind::MatchIota(variant_name_id)) =>
+        {
+            (variant_name_id, matchee_args)
+        }
+        _ => {
+            return Value::Neutral(Rc::new(Neutral::Match {
+                matchee,
+                env: env.clone(),
+                motive_id: match_.motive_id,
+                case_list_id: match_.case_list_id,
+            }))
+        }
+    };
+
+    let variant_name = &state.raw.registry.get(variant_name_id).name;
+    let case = state
+        .raw
+        .registry
+        .get_possibly_empty_list(match_.case_list_id)
+        .iter()
+        .map(|&case_id| state.raw.registry.get(case_id))
+        .find(|case| &state.raw.registry.get(case.variant_name_id).name == variant_name)
+        .expect("Impossible: Cannot find matching MatchCase in well-typed Match expression.")
+        .clone();
+
+    let mut case_env = env.clone();
+    match (matchee_args, case.param_list_id) {
+        (None, None) => {}
+        (
+            Some(ArgValues::Unlabeled(matchee_args)),
+            Some(NonEmptyMatchCaseParamListId::Unlabeled(_)),
+        ) => {
+            for arg in matchee_args.iter() {
+                case_env.push(arg.clone(), true);
+            }
+        }
+        (
+            Some(ArgValues::UniquelyLabeled(matchee_args)),
+            Some(NonEmptyMatchCaseParamListId::UniquelyLabeled {
+                param_list_id: explicit_param_list_id,
+                triple_dot: _,
+            }),
+        ) => {
+            let explicit_param_ids = state
+                .raw
+                .registry
+                .get_possibly_empty_list(explicit_param_list_id)
+                .to_vec();
+            for explicit_param_id in explicit_param_ids {
+                let label_id = state
+                    .raw
+                    .registry
+                    .get(explicit_param_id)
+                    .label_identifier_id();
+                let label_name = &state.raw.registry.get(label_id).name;
+                let arg = matchee_args
+                    .iter()
+                    .find(|arg| &state.raw.registry.get(arg.label_id).name == label_name)
+                    .expect("Impossible: well-typed Match expression has a case param with no corresponding matchee arg.");
+                case_env.push(arg.value.clone(), true);
+            }
+        }
+        _ => panic!("Impossible: a well-typed Match expression has a labeledness mismatch."),
+    }
+
+    match case.output_id {
+        MatchCaseOutputId::Some(output_id) => eval(state, &case_env, output_id),
+        MatchCaseOutputId::ImpossibilityClaim(kw_span) => {
+            panic!("Impossible: A well-typed Match expression ended up evaluating to one of the cases that was supposedly impossible. `impossible` keyword span: {:?}", kw_span)
+        }
+    }
+}
+
+/// A well-typed match on a pair has exactly one case,
+/// whose two params are bound to the pair's witness and proof.
+/// Returns `None` if the reduction step limit was reached.
+fn try_eval_pair_match(
+    state: &mut NbeState,
+    env: &Env,
+    match_: &Match,
+    pair: &PairValue,
+) -> Option<Value> {
+    let case_id = *state
+        .raw
+        .registry
+        .get_possibly_empty_list(match_.case_list_id)
+        .first()
+        .expect("Impossible: a well-typed match on a pair should have exactly one case.");
+    let case = state.raw.registry.get(case_id).clone();
+    if !state
+        .raw
+        .reduction_tracker
+        .try_step(ReductionTraceEventKind::MatchIota(case.variant_name_id))
+    {
+        return None;
+    }
+
+    let mut case_env = env.clone();
+    case_env.push(pair.witness.clone(), true);
+    case_env.push(pair.proof.clone(), true);
+    match case.output_id {
+        MatchCaseOutputId::Some(output_id) => Some(eval(state, &case_env, output_id)),
+        MatchCaseOutputId::ImpossibilityClaim(kw_span) => {
+            panic!("Impossible: A well-typed match on a pair cannot have an impossible case. `impossible` keyword span: {:?}", kw_span)
+        }
+    }
+}
+
+/// A rewrite behaves like `match proof { refl(...) => body }`.
+/// Since the designated `Eq` type has exactly one variant
+/// (the designated `refl`), the rewrite reduces to its body
+/// if and only if the proof is a variant expression.
+fn eval_rewrite(state: &mut NbeState, env: &Env, rewrite_id: NodeId<Rewrite>) -> Value {
+    let rewrite = state.raw.registry.get(rewrite_id).clone();
+    let proof = eval(state, env, rewrite.proof_id);
+
+    match try_as_variant(state, &proof) {
+        Some((variant_name_id, _))
+            if state
+                .raw
+                .reduction_tracker
+                .try_step(ReductionTraceEventKind::MatchIota(variant_name_id)) =>
+        {
+            eval(state, env, rewrite.body_id)
+        }
+        _ => Value::Neutral(Rc::new(Neutral::Rewrite {
+            proof,
+            env: env.clone(),
+            rewrite_id,
+        })),
+    }
+}
+
+fn eval_projection(state: &mut NbeState, env: &Env, projection_id: NodeId<Projection>) -> Value {
+    let projection = state.raw.registry.get(projection_id).clone();
+    let record = eval(state, env, projection.record_id);
+
+    let field_value = match try_as_variant(state, &record) {
+        Some((_, Some(ArgValues::UniquelyLabeled(args)))) => {
+            let field_name = &state.raw.registry.get(projection.field_name_id).name;
+            args.iter()
+                .find(|arg| &state.raw.registry.get(arg.label_id).name == field_name)
+                .map(|arg| arg.value.clone())
+        }
+        _ => None,
+    };
+    if let Some(field_value) = field_value {
+        state
+            .raw
+            .reduction_tracker
+            .record_trace_event(ReductionTraceEventKind::ProjectionIota(
+                projection.field_name_id,
+            ));
+        return field_value;
+    }
+
+    Value::Neutral(Rc::new(Neutral::Projection {
+        record,
+        field_name_id: projection.field_name_id,
+    }))
+}
+
+/// `depth` is the number of entries in scope
+/// (i.e., the context length plus the number of
+/// variables introduced during read-back so far).
+fn read_back(state: &mut NbeState, depth: usize, value: &Value) -> NormalFormId {
+    match value {
+        Value::Neutral(neutral) => read_back_neutral(state, depth, neutral),
+        Value::Fun(fun) => read_back_fun(state, depth, fun),
+        Value::Forall(forall) => read_back_forall(state, depth, forall),
+        Value::Exists(exists) => read_back_exists(state, depth, exists),
+        Value::Pair(pair) => read_back_pair(state, depth, pair),
+    }
+}
+
+fn read_back_neutral(state: &mut NbeState, depth: usize, neutral: &Neutral) -> NormalFormId {
+    match neutral {
+        Neutral::Var {
+            level,
+            component_list_id,
+        } => {
+            let name_id = state.raw.registry.add_and_overwrite_id(NameExpression {
+                id: dummy_id(),
+                span: None,
+                component_list_id: *component_list_id,
+                db_index: DbIndex(depth - 1 - level.0),
+            });
+            NormalFormId::unchecked_new(ExpressionId::Name(name_id))
+        }
+        Neutral::Todo(todo_id) => NormalFormId::unchecked_new(ExpressionId::Todo(*todo_id)),
+        Neutral::Call { callee, args } => {
+            let callee_id = read_back(state, depth, callee);
+            let arg_list_id = read_back_args(state, depth, args);
+            let call_id = state.raw.registry.add_and_overwrite_id(Call {
+                id: dummy_id(),
+                span: None,
+                callee_id: callee_id.raw(),
+                arg_list_id,
+            });
+            NormalFormId::unchecked_new(ExpressionId::Call(call_id))
+        }
+        Neutral::Match {
+            matchee,
+            env,
+            motive_id,
+            case_list_id,
+        } => {
+            let matchee_id = read_back(state, depth, matchee);
+            let motive_id =
+                motive_id.map(|motive_id| read_back_match_motive(state, depth, env, motive_id));
+            let case_list_id = case_list_id.map(|case_list_id| {
+                let case_ids = state.raw.registry.get_list(case_list_id).to_non_empty_vec();
+                let case_ids = case_ids
+                    .into_mapped(|case_id| read_back_match_case(state, depth, env, case_id));
+                state.raw.registry.add_list(case_ids)
+            });
+            let match_id = state.raw.registry.add_and_overwrite_id(Match {
+                id: dummy_id(),
+                span: None,
+                matchee_id: matchee_id.raw(),
+                motive_id,
+                case_list_id,
+            });
+            NormalFormId::unchecked_new(ExpressionId::Match(match_id))
+        }
+        Neutral::Projection {
+            record,
+            field_name_id,
+        } => {
+            let record_id = read_back(state, depth, record);
+            let projection_id = state.raw.registry.add_and_overwrite_id(Projection {
+                id: dummy_id(),
+                span: None,
+                record_id: record_id.raw(),
+                field_name_id: *field_name_id,
+            });
+            NormalFormId::unchecked_new(ExpressionId::Projection(projection_id))
+        }
+        Neutral::Rewrite {
+            proof,
+            env,
+            rewrite_id,
+        } => {
+            let rewrite = state.raw.registry.get(*rewrite_id).clone();
+            let eq_type_name_id = read_back_name(state, depth, env, rewrite.eq_type_name_id);
+            let eq_refl_name_id = read_back_name(state, depth, env, rewrite.eq_refl_name_id);
+            let proof_id = read_back(state, depth, proof);
+            let body = eval(state, env, rewrite.body_id);
+            let body_id = read_back(state, depth, &body);
+            let rewrite_id = state.raw.registry.add_and_overwrite_id(Rewrite {
+                id: dummy_id(),
+                span: None,
+                eq_type_name_id,
+                eq_refl_name_id,
+                proof_id: proof_id.raw(),
+                body_id: body_id.raw(),
+            });
+            NormalFormId::unchecked_new(ExpressionId::Rewrite(rewrite_id))
+        }
+    }
+}
+
+/// Reads back a name that must refer to a type or variant
+/// (e.g., a match motive's matchee type, or a rewrite's designated `Eq`).
+fn read_back_name(
+    state: &mut NbeState,
+    depth: usize,
+    env: &Env,
+    name_id: NodeId<NameExpression>,
+) -> NodeId<NameExpression> {
+    let value = eval(state, env, ExpressionId::Name(name_id));
+    let ExpressionId::Name(name_id) = read_back(state, depth, &value).raw() else {
+        panic!("Impossible: A type or variant name should evaluate to itself.");
+    };
+    name_id
+}
+
+fn read_back_args(state: &mut NbeState, depth: usize, args: &ArgValues) -> NonEmptyCallArgListId {
+    match args {
+        ArgValues::Unlabeled(args) => {
+            let arg_ids = args
+                .as_non_empty_slice()
+                .to_mapped(|arg| read_back(state, depth, arg).raw());
+            NonEmptyCallArgListId::Unlabeled(state.raw.registry.add_list(arg_ids))
+        }
+        ArgValues::UniquelyLabeled(args) => {
+            let arg_ids = args.as_non_empty_slice().to_mapped(|arg| {
+                let value_id = read_back(state, depth, &arg.value).raw();
+                match (arg.is_implicit, value_id) {
+                    (true, ExpressionId::Name(name_id)) => {
+                        let db_index = state.raw.registry.get(name_id).db_index;
+                        LabeledCallArgId::implicit(arg.label_id, db_index, state.raw.registry)
+                    }
+                    _ => LabeledCallArgId::Explicit {
+                        label_id: arg.label_id,
+                        value_id,
+                    },
+                }
+            });
+            NonEmptyCallArgListId::UniquelyLabeled(state.raw.registry.add_list(arg_ids))
+        }
+    }
+}
+
+fn read_back_match_motive(
+    state: &mut NbeState,
+    depth: usize,
+    env: &Env,
+    motive_id: NodeId<MatchMotive>,
+) -> NodeId<MatchMotive> {
+    let motive = state.raw.registry.get(motive_id).clone();
+
+    let matchee_type_name_id = read_back_name(state, depth, env, motive.matchee_type_name_id);
+
+    let mut param_name_ids: Vec<NodeId<Identifier>> = state
+        .raw
+        .registry
+        .get_possibly_empty_list(motive.index_name_list_id)
+        .to_vec();
+    param_name_ids.push(motive.matchee_name_id);
+
+    let mut motive_env = env.clone();
+    for (i, &param_name_id) in param_name_ids.iter().enumerate() {
+        let param = fresh_var(state, depth + i, param_name_id);
+        motive_env.push(param, false);
+    }
+    let output = eval(state, &motive_env, motive.output_id);
+    let output_id = read_back(state, depth + param_name_ids.len(), &output).raw();
+
+    state.raw.registry.add_and_overwrite_id(MatchMotive {
+        id: dummy_id(),
+        span: None,
+        matchee_name_id: motive.matchee_name_id,
+        matchee_type_name_id,
+        index_name_list_id: motive.index_name_list_id,
+        output_id,
+    })
+}
+
+fn read_back_match_case(
+    state: &mut NbeState,
+    depth: usize,
+    env: &Env,
+    case_id: NodeId<MatchCase>,
+) -> NodeId<MatchCase> {
+    let case = state.raw.registry.get(case_id).clone();
+    let param_name_ids: Vec<NodeId<Identifier>> = match case.param_list_id {
+        None => vec![],
+        Some(NonEmptyMatchCaseParamListId::Unlabeled(param_list_id)) => {
+            state.raw.registry.get_list(param_list_id).to_vec()
+        }
+        Some(NonEmptyMatchCaseParamListId::UniquelyLabeled { param_list_id, .. }) => state
+            .raw
+            .registry
+            .get_possibly_empty_list(param_list_id)
+            .to_vec()
+            .into_iter()
+            .map(|param_id| state.raw.registry.get(param_id).name_id)
+            .collect(),
+    };
+
+    let output_id = match case.output_id {
+        MatchCaseOutputId::Some(output_id) => {
+            let mut case_env = env.clone();
+            for (i, &param_name_id) in param_name_ids.iter().enumerate() {
+                let param = fresh_var(state, depth + i, param_name_id);
+                case_env.push(param, false);
+            }
+            let output = eval(state, &case_env, output_id);
+            MatchCaseOutputId::Some(read_back(state, depth + param_name_ids.len(), &output).raw())
+        }
+        MatchCaseOutputId::ImpossibilityClaim(kw_span) => {
+            MatchCaseOutputId::ImpossibilityClaim(kw_span)
+        }
+    };
+
+    state.raw.registry.add_and_overwrite_id(MatchCase {
+        id: dummy_id(),
+        span: None,
+        variant_name_id: case.variant_name_id,
+        param_list_id: case.param_list_id,
+        output_id,
+    })
+}
+
+fn read_back_fun(state: &mut NbeState, depth: usize, fun: &Closure<NodeId<Fun>>) -> NormalFormId {
+    let fun_node = state.raw.registry.get(fun.id).clone();
+    let mut env = fun.env.clone();
+    let param_list_id =
+        read_back_params_and_leave_in_env(state, depth, &mut env, fun_node.param_list_id);
+    let param_arity = fun_node.param_list_id.len();
+
+    let return_type = eval(state, &env, fun_node.return_type_id);
+    let return_type_id = read_back(state, depth + param_arity, &return_type);
+
+    let fun_var = fresh_var(state, depth + param_arity, fun_node.name_id);
+    env.push(fun_var, false);
+    let body = eval(state, &env, fun_node.body_id);
+    let body_id = read_back(state, depth + param_arity + 1, &body);
+
+    NormalFormId::unchecked_new(ExpressionId::Fun(state.raw.registry.add_and_overwrite_id(
+        Fun {
+            id: dummy_id(),
+            span: None,
+            name_id: fun_node.name_id,
+            param_list_id,
+            return_type_id: return_type_id.raw(),
+            body_id: body_id.raw(),
+        },
+    )))
+}
+
+fn read_back_forall(
+    state: &mut NbeState,
+    depth: usize,
+    forall: &Closure<NodeId<Forall>>,
+) -> NormalFormId {
+    let forall_node = state.raw.registry.get(forall.id).clone();
+    let mut env = forall.env.clone();
+    let param_list_id =
+        read_back_params_and_leave_in_env(state, depth, &mut env, forall_node.param_list_id);
+    let param_arity = forall_node.param_list_id.len();
+
+    let output = eval(state, &env, forall_node.output_id);
+    let output_id = read_back(state, depth + param_arity, &output);
+
+    NormalFormId::unchecked_new(ExpressionId::Forall(
+        state.raw.registry.add_and_overwrite_id(Forall {
+            id: dummy_id(),
+            span: None,
+            param_list_id,
+            output_id: output_id.raw(),
+        }),
+    ))
+}
+
+fn read_back_exists(
+    state: &mut NbeState,
+    depth: usize,
+    exists: &Closure<NodeId<Exists>>,
+) -> NormalFormId {
+    let exists_node = state.raw.registry.get(exists.id).clone();
+    let param = state.raw.registry.get(exists_node.param_id).clone();
+    let mut env = exists.env.clone();
+
+    let param_type = eval(state, &env, param.type_id);
+    let param_type_id = read_back(state, depth, &param_type);
+    let param_id = state.raw.registry.add_and_overwrite_id(UnlabeledParam {
+        id: dummy_id(),
+        span: None,
+        is_instance: param.is_instance,
+        is_dashed: param.is_dashed,
+        name_id: param.name_id,
+        type_id: param_type_id.raw(),
+    });
+
+    let param_var = fresh_var(state, depth, param.name_id);
+    env.push(param_var, false);
+    let output = eval(state, &env, exists_node.output_id);
+    let output_id = read_back(state, depth + 1, &output);
+
+    NormalFormId::unchecked_new(ExpressionId::Exists(
+        state.raw.registry.add_and_overwrite_id(Exists {
+            id: dummy_id(),
+            span: None,
+            param_id,
+            output_id: output_id.raw(),
+        }),
+    ))
+}
+
+fn read_back_pair(state: &mut NbeState, depth: usize, pair: &PairValue) -> NormalFormId {
+    let witness_id = read_back(state, depth, &pair.witness);
+    let proof_id = read_back(state, depth, &pair.proof);
+    NormalFormId::unchecked_new(ExpressionId::Pair(state.raw.registry.add_and_overwrite_id(
+        Pair {
+            id: dummy_id(),
+            span: None,
+            witness_id: witness_id.raw(),
+            proof_id: proof_id.raw(),
+        },
+    )))
+}
+
+fn read_back_params_and_leave_in_env(
+    state: &mut NbeState,
+    depth: usize,
+    env: &mut Env,
+    param_list_id: NonEmptyParamListId,
+) -> NonEmptyParamListId {
+    match param_list_id {
+        NonEmptyParamListId::Unlabeled(param_list_id) => {
+            let param_ids = state
+                .raw
+                .registry
+                .get_list(param_list_id)
+                .to_non_empty_vec();
+            let normalized_param_ids = param_ids.enumerate_into_mapped(|(i, param_id)| {
+                let param = state.raw.registry.get(param_id).clone();
+                let param_type = eval(state, env, param.type_id);
+                let type_id = read_back(state, depth + i, &param_type);
+                let param_var = fresh_var(state, depth + i, param.name_id);
+                env.push(param_var, false);
+                state.raw.registry.add_and_overwrite_id(UnlabeledParam {
+                    id: dummy_id(),
+                    span: None,
+                    is_instance: param.is_instance,
+                    is_dashed: param.is_dashed,
+                    name_id: param.name_id,
+                    type_id: type_id.raw(),
+                })
+            });
+            NonEmptyParamListId::Unlabeled(state.raw.registry.add_list(normalized_param_ids))
+        }
+        NonEmptyParamListId::UniquelyLabeled(param_list_id) => {
+            let param_ids = state
+                .raw
+                .registry
+                .get_list(param_list_id)
+                .to_non_empty_vec();
+            let normalized_param_ids = param_ids.enumerate_into_mapped(|(i, param_id)| {
+                let param = state.raw.registry.get(param_id).clone();
+                let param_type = eval(state, env, param.type_id);
+                let type_id = read_back(state, depth + i, &param_type);
+                let default_id = param.default_id.map(|default_id| {
+                    let default = eval(state, env, default_id);
+                    read_back(state, depth + i, &default).raw()
+                });
+                let param_var = fresh_var(state, depth + i, param.name_id);
+                env.push(param_var, false);
+                state.raw.registry.add_and_overwrite_id(LabeledParam {
+                    id: dummy_id(),
+                    span: None,
+                    is_instance: param.is_instance,
+                    label_id: param.label_id,
+                    is_dashed: param.is_dashed,
+                    name_id: param.name_id,
+                    type_id: type_id.raw(),
+                    default_id,
+                })
+            });
+            NonEmptyParamListId::UniquelyLabeled(state.raw.registry.add_list(normalized_param_ids))
+        }
+    }
+}
@@ -0,0 +1,47 @@
+use super::*;
+
+/// The reduction steps the evaluator took while normalizing
+/// the comparees of a single check assertion.
+#[derive(Clone, Debug)]
+pub struct ReductionTrace {
+    pub assertion_id: NodeId<CheckAssertion>,
+    pub normalizations: Vec<TracedNormalization>,
+}
+
+#[derive(Clone, Debug)]
+pub struct TracedNormalization {
+    pub expression_id: ExpressionId,
+    /// This is `None` if the normalization did not finish
+    /// (e.g., because the reduction step limit was exceeded).
+    pub normal_form_id: Option<NormalFormId>,
+    pub events: Vec<ReductionTraceEvent>,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ReductionTraceEvent {
+    /// The number of function bodies being reduced
+    /// when this event occurred.
+    pub depth: usize,
+    pub kind: ReductionTraceEventKind,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ReductionTraceEventKind {
+    /// A `let` alias was replaced by its value.
+    Delta(NodeId<LetStatement>),
+    /// A function (identified by its name) was applied to its arguments.
+    Beta(NodeId<Identifier>),
+    /// A match expression was replaced by the case
+    /// for the given variant.
+    MatchIota(NodeId<Identifier>),
+    /// A field projection (identified by its field name)
+    /// was replaced by the corresponding constructor argument.
+    ProjectionIota(NodeId<Identifier>),
+    /// A `let` alias was not replaced by its value because
+    /// it is not transparent enough.
+    DeltaBlockedByTransparency(NodeId<LetStatement>),
+    /// A function (identified by its name) was not applied because
+    /// the argument corresponding to its decreasing parameter
+    /// is not a variant expression.
+    BetaBlockedByDecreasingArg(NodeId<Identifier>),
+}
@@ -0,0 +1,274 @@
+use super::*;
+
+use rustc_hash::FxHashMap;
+
+/// Counts the reduction steps (i.e., function applications
+/// and match case selections) taken by the evaluator,
+/// enforces the per-normalization step limit,
+/// attributes each step to a `let` statement,
+/// and (if requested) traces the steps taken while normalizing
+/// the comparees of a selected check assertion.
+///
+/// A step is attributed to the innermost `let` whose function
+/// body is being reduced.
+/// If no such `let` exists, the step is attributed to the `let`
+/// statement currently being type checked (if any).
+#[derive(Debug)]
+pub(in crate::processing::type_check) struct ReductionTracker {
+    max_steps_per_normalization: Option<usize>,
+    profile: ReductionProfile,
+    limit_exceeded_error: Option<TypeCheckError>,
+
+    /// The `let` statement currently being type checked, if any.
+    checked_let_id: Option<NodeId<LetStatement>>,
+    /// Maps the name of every function that has been
+    /// unfolded from an alias to the alias's `let` statement.
+    let_ids_by_fun_name_id: FxHashMap<NodeId<Identifier>, NodeId<LetStatement>>,
+
+    normalized_expression_id: Option<ExpressionId>,
+    steps_in_current_normalization: usize,
+    unfolding_let_id: Option<NodeId<LetStatement>>,
+    fun_body_depth: usize,
+    /// If this is `Some`, the current normalization is speculative
+    /// (see `begin_speculative_normalization`).
+    speculative_max_steps: Option<usize>,
+    did_speculation_run_out_of_steps: bool,
+
+    /// The check assertion whose span contains this span is traced.
+    traced_check_span: Option<TextSpan>,
+    trace: Option<ReductionTrace>,
+    is_tracing_check_assertion: bool,
+    traced_normalization: Option<TracedNormalization>,
+}
+
+/// The number of reduction steps attributed to each `let` statement.
+#[derive(Clone, Debug, Default)]
+pub struct ReductionProfile {
+    steps_by_let_id: FxHashMap<NodeId<LetStatement>, usize>,
+    unattributed_steps: usize,
+}
+
+impl ReductionProfile {
+    pub fn total_steps(&self) -> usize {
+        self.unattributed_steps + self.steps_by_let_id.values().sum::<usize>()
+    }
+
+    /// Returns the `let` statements that took at least one step,
+    /// sorted by the number of steps (most steps first).
+    pub fn lets_by_descending_steps(&self) -> Vec<(NodeId<LetStatement>, usize)> {
+        let mut entries: Vec<_> = self
+            .steps_by_let_id
+            .iter()
+            .map(|(&let_id, &steps)| (let_id, steps))
+            .collect();
+        entries.sort_unstable_by(|(a_id, a_steps), (b_id, b_steps)| {
+            b_steps.cmp(a_steps).then(a_id.raw.cmp(&b_id.raw))
+        });
+        entries
+    }
+
+    fn record_step(&mut self, let_id: Option<NodeId<LetStatement>>) {
+        if let Some(let_id) = let_id {
+            *self.steps_by_let_id.entry(let_id).or_insert(0) += 1;
+        } else {
+            self.unattributed_steps += 1;
+        }
+    }
+}
+
+impl ReductionTracker {
+    pub fn new(
+        max_steps_per_normalization: Option<usize>,
+        traced_check_span: Option<TextSpan>,
+    ) -> Self {
+        Self {
+            max_steps_per_normalization,
+            profile: ReductionProfile::default(),
+            limit_exceeded_error: None,
+            checked_let_id: None,
+            let_ids_by_fun_name_id: FxHashMap::default(),
+            normalized_expression_id: None,
+            steps_in_current_normalization: 0,
+            unfolding_let_id: None,
+            fun_body_depth: 0,
+            speculative_max_steps: None,
+            did_speculation_run_out_of_steps: false,
+            traced_check_span,
+            trace: None,
+            is_tracing_check_assertion: false,
+            traced_normalization: None,
+        }
+    }
+
+    pub fn set_checked_let_id(&mut self, let_id: Option<NodeId<LetStatement>>) {
+        self.checked_let_id = let_id;
+    }
+
+    /// Returns `true` if the assertion was selected to be traced.
+    /// In that case, call `end_check_assertion_trace` once
+    /// the assertion's comparees are normalized.
+    ///
+    /// Only the first selected assertion is traced.
+    pub fn begin_check_assertion_trace_if_selected(
+        &mut self,
+        assertion_id: NodeId<CheckAssertion>,
+        assertion_span: Option<TextSpan>,
+    ) -> bool {
+        let (Some(selected), Some(assertion_span)) = (self.traced_check_span, assertion_span)
+        else {
+            return false;
+        };
+        let is_selected = self.trace.is_none()
+            && selected.file_id == assertion_span.file_id
+            && assertion_span.start <= selected.start
+            && selected.end <= assertion_span.end;
+        if is_selected {
+            self.trace = Some(ReductionTrace {
+                assertion_id,
+                normalizations: vec![],
+            });
+            self.is_tracing_check_assertion = true;
+        }
+        is_selected
+    }
+
+    pub fn end_check_assertion_trace(&mut self) {
+        self.is_tracing_check_assertion = false;
+    }
+
+    pub fn is_tracing_check_assertion(&self) -> bool {
+        self.is_tracing_check_assertion
+    }
+
+    pub fn take_trace(&mut self) -> Option<ReductionTrace> {
+        self.trace.take()
+    }
+
+    pub fn into_profile_and_limit_exceeded_error(
+        self,
+    ) -> (ReductionProfile, Option<TypeCheckError>) {
+        (self.profile, self.limit_exceeded_error)
+    }
+
+    /// Once the step limit has been exceeded, no more steps are
+    /// allowed (not even in subsequent normalizations).
+    /// This way, type checking can finish quickly,
+    /// after which the limit error is reported.
+    pub fn is_limit_exceeded(&self) -> bool {
+        self.limit_exceeded_error.is_some()
+    }
+
+    pub(super) fn begin_normalization(&mut self, id: ExpressionId) {
+        self.normalized_expression_id = Some(id);
+        self.steps_in_current_normalization = 0;
+        self.unfolding_let_id = None;
+        self.fun_body_depth = 0;
+    }
+
+    /// Until `end_speculative_normalization` is called,
+    /// each normalization may take at most `max_steps` steps.
+    /// Running out of steps is not an error,
+    /// and the steps are not recorded in the profile.
+    pub(super) fn begin_speculative_normalization(&mut self, max_steps: usize) {
+        self.speculative_max_steps = Some(max_steps);
+        self.did_speculation_run_out_of_steps = false;
+    }
+
+    /// Returns `true` if the speculative normalizations
+    /// didn't run out of steps.
+    pub(super) fn end_speculative_normalization(&mut self) -> bool {
+        self.speculative_max_steps = None;
+        !self.did_speculation_run_out_of_steps && !self.is_limit_exceeded()
+    }
+
+    pub(super) fn begin_traced_normalization(&mut self, id: ExpressionId) {
+        self.traced_normalization = Some(TracedNormalization {
+            expression_id: id,
+            normal_form_id: None,
+            events: vec![],
+        });
+    }
+
+    pub(super) fn end_traced_normalization(&mut self, normal_form_id: NormalFormId) {
+        let Some(mut normalization) = self.traced_normalization.take() else {
+            return;
+        };
+        if !self.is_limit_exceeded() {
+            normalization.normal_form_id = Some(normal_form_id);
+        }
+        if let Some(trace) = &mut self.trace {
+            trace.normalizations.push(normalization);
+        }
+    }
+
+    /// Does nothing unless a normalization is being traced.
+    pub(super) fn record_trace_event(&mut self, kind: ReductionTraceEventKind) {
+        if let Some(normalization) = &mut self.traced_normalization {
+            normalization.events.push(ReductionTraceEvent {
+                depth: self.fun_body_depth,
+                kind,
+            });
+        }
+    }
+
+    /// Returns `true` if the caller may perform the reduction step
+    /// described by `kind`.
+    /// Otherwise, the caller must leave the redex unreduced.
+    pub(super) fn try_step(&mut self, kind: ReductionTraceEventKind) -> bool {
+        if self.is_limit_exceeded() {
+            return false;
+        }
+        if let Some(max_steps) = self.speculative_max_steps {
+            if self.steps_in_current_normalization >= max_steps {
+                self.did_speculation_run_out_of_steps = true;
+                return false;
+            }
+            self.steps_in_current_normalization += 1;
+            return true;
+        }
+        if let Some(max_steps) = self.max_steps_per_normalization {
+            if self.steps_in_current_normalization >= max_steps {
+                self.limit_exceeded_error = Some(TypeCheckError::ReductionStepLimitExceeded {
+                    expression_id: self
+                        .normalized_expression_id
+                        .expect("A normalization should be in progress."),
+                    unfolding_let_id: self.unfolding_let_id,
+                    max_reduction_steps: max_steps,
+                });
+                return false;
+            }
+        }
+        self.steps_in_current_normalization += 1;
+        self.profile
+            .record_step(self.unfolding_let_id.or(self.checked_let_id));
+        self.record_trace_event(kind);
+        true
+    }
+
+    pub(super) fn note_fun_unfolded_from_let(
+        &mut self,
+        fun_name_id: NodeId<Identifier>,
+        let_id: NodeId<LetStatement>,
+    ) {
+        self.let_ids_by_fun_name_id.insert(fun_name_id, let_id);
+    }
+
+    /// Call this before reducing the body of the function named `fun_name_id`.
+    /// Pass the returned value to `exit_fun_body` once the body is reduced.
+    pub(super) fn enter_fun_body(
+        &mut self,
+        fun_name_id: NodeId<Identifier>,
+    ) -> Option<NodeId<LetStatement>> {
+        let previous = self.unfolding_let_id;
+        self.fun_body_depth += 1;
+        if let Some(&let_id) = self.let_ids_by_fun_name_id.get(&fun_name_id) {
+            self.unfolding_let_id = Some(let_id);
+        }
+        previous
+    }
+
+    pub(super) fn exit_fun_body(&mut self, previous: Option<NodeId<LetStatement>>) {
+        self.unfolding_let_id = previous;
+        self.fun_body_depth -= 1;
+    }
+}
@@ -49,7 +49,10 @@ pub(super) fn evaluate_well_typed_expression(state: &mut State, id: ExpressionId
             extra_entries_in_context: 0,
         },
     };
-    evaluate_expression(&mut state, id)
+    // Normal forms are cached by semantic ID (which ignores spans),
+    // so we strip spans to ensure that a cached normal form is
+    // syntactically identical to a freshly computed one.
+    evaluate_expression(&mut state, id).without_spans(state.raw.registry)
 }
 
 // As a convention, since every function in this module
@@ -67,6 +70,10 @@ fn evaluate_expression(state: &mut EvalState, id: ExpressionId) -> NormalFormId
         ExpressionId::Match(match_id) => evaluate_match(state, match_id),
         ExpressionId::Forall(forall_id) => evaluate_forall(state, forall_id),
         ExpressionId::Check(check_id) => evaluate_check(state, check_id),
+        ExpressionId::Rewrite(rewrite_id) => evaluate_rewrite(state, rewrite_id),
+        ExpressionId::Projection(projection_id) => evaluate_projection(state, projection_id),
+        ExpressionId::Exists(exists_id) => evaluate_exists(state, exists_id),
+        ExpressionId::Pair(pair_id) => evaluate_pair(state, pair_id),
     }
 }
 
@@ -82,6 +89,7 @@ fn evaluate_name_expression(
         ContextEntryDefinition::Alias {
             value_id: alias_value_id,
             transparency,
+            let_id,
             ..
         } => {
             let can_substitute = if let Some(required_transparency) =
@@ -96,8 +104,13 @@ fn evaluate_name_expression(
                 true
             };
             if can_substitute {
-                alias_value_id
+                note_alias_unfolded(state, alias_value_id, let_id);
+                // The alias value was normalized under the alias's own
+                // transparency requirement, so there may be aliases
+                // in it that can be unfolded under the current one.
+                evaluate_expression(state, alias_value_id.raw())
             } else {
+                note_alias_unfolding_blocked(state, let_id);
                 NormalFormId::unchecked_new(ExpressionId::Name(name_id))
             }
         }
@@ -105,6 +118,8 @@ fn evaluate_name_expression(
         ContextEntryDefinition::Adt {
             variant_name_list_id: _,
             visibility: _,
+            is_struct: _,
+            is_trait: _,
         }
         | ContextEntryDefinition::Variant {
             name_id: _,
@@ -116,6 +131,31 @@ fn evaluate_name_expression(
     }
 }
 
+fn note_alias_unfolded(
+    state: &mut EvalState,
+    alias_value_id: NormalFormId,
+    let_id: NodeId<LetStatement>,
+) {
+    state
+        .raw
+        .reduction_tracker
+        .record_trace_event(ReductionTraceEventKind::Delta(let_id));
+    if let ExpressionId::Fun(fun_id) = alias_value_id.raw() {
+        let fun_name_id = state.raw.registry.get(fun_id).name_id;
+        state
+            .raw
+            .reduction_tracker
+            .note_fun_unfolded_from_let(fun_name_id, let_id);
+    }
+}
+
+fn note_alias_unfolding_blocked(state: &mut EvalState, let_id: NodeId<LetStatement>) {
+    state
+        .raw
+        .reduction_tracker
+        .record_trace_event(ReductionTraceEventKind::DeltaBlockedByTransparency(let_id));
+}
+
 fn evaluate_todo_expression(_: &mut EvalState, todo_id: NodeId<TodoExpression>) -> NormalFormId {
     // `todo` expressions are, by definition, normal forms.
     NormalFormId::unchecked_new(ExpressionId::Todo(todo_id))
@@ -146,7 +186,19 @@ fn evaluate_call(state: &mut EvalState, call_id: NodeId<Call>) -> NormalFormId {
 
     match normalized_callee_id.raw() {
         ExpressionId::Fun(fun_id) => {
-            if !can_fun_be_applied(state, fun_id, normalized_arg_list_id) {
+            let fun_name_id = state.raw.registry.get(fun_id).name_id;
+            let can_be_applied = can_fun_be_applied(state, fun_id, normalized_arg_list_id);
+            if !can_be_applied {
+                state.raw.reduction_tracker.record_trace_event(
+                    ReductionTraceEventKind::BetaBlockedByDecreasingArg(fun_name_id),
+                );
+            }
+            if !can_be_applied
+                || !state
+                    .raw
+                    .reduction_tracker
+                    .try_step(ReductionTraceEventKind::Beta(fun_name_id))
+            {
                 return register_normalized_nonsubstituted_call(
                     state.raw.registry,
                     normalized_callee_id,
@@ -265,11 +317,16 @@ fn evaluate_call(state: &mut EvalState, call_id: NodeId<Call>) -> NormalFormId {
 
             let body_id = fun.body_id.subst_all(&substitutions, state.raw);
             let shifted_body_id = body_id.downshift(param_arity + 1, state.raw.registry);
-            evaluate_expression(state, shifted_body_id)
+            let previous_let_id = state.raw.reduction_tracker.enter_fun_body(fun.name_id);
+            let output = evaluate_expression(state, shifted_body_id);
+            state.raw.reduction_tracker.exit_fun_body(previous_let_id);
+            output
         }
         ExpressionId::Name(_)
         | ExpressionId::Call(_)
         | ExpressionId::Match(_)
+        | ExpressionId::Projection(_)
+        | ExpressionId::Rewrite(_)
         | ExpressionId::Todo(_) => register_normalized_nonsubstituted_call(
             state.raw.registry,
             normalized_callee_id,
@@ -278,6 +335,12 @@ fn evaluate_call(state: &mut EvalState, call_id: NodeId<Call>) -> NormalFormId {
         ExpressionId::Forall(_) => {
             panic!("A well-typed Call cannot have a Forall as its callee.")
         }
+        ExpressionId::Exists(_) => {
+            panic!("A well-typed Call cannot have an Exists as its callee.")
+        }
+        ExpressionId::Pair(_) => {
+            panic!("A well-typed Call cannot have a Pair as its callee.")
+        }
         ExpressionId::Check(_) => {
             panic!("By definition, a check expression can never be a normal form.")
         }
@@ -431,6 +494,7 @@ fn evaluate_labeled_call_arg(state: &mut EvalState, arg_id: LabeledCallArgId) ->
             if let ContextEntryDefinition::Alias {
                 value_id: alias_value_id,
                 transparency,
+                let_id,
                 ..
             } = definition
             {
@@ -446,11 +510,13 @@ fn evaluate_labeled_call_arg(state: &mut EvalState, arg_id: LabeledCallArgId) ->
                     true
                 };
                 if can_substitute {
+                    note_alias_unfolded(state, alias_value_id, let_id);
                     return LabeledCallArgId::Explicit {
                         label_id,
                         value_id: alias_value_id.raw(),
                     };
                 }
+                note_alias_unfolding_blocked(state, let_id);
             }
             LabeledCallArgId::Implicit {
                 label_id,
@@ -539,6 +605,7 @@ fn normalize_unlabeled_params_as_much_as_possible_and_leave_in_context(
         state.raw.registry.add_and_overwrite_id(UnlabeledParam {
             id: dummy_id(),
             span: None,
+            is_instance: first_param.is_instance,
             is_dashed: first_param.is_dashed,
             name_id: first_param.name_id,
             type_id: normalized_param_type_id.raw(),
@@ -552,6 +619,7 @@ fn normalize_unlabeled_params_as_much_as_possible_and_leave_in_context(
         normalized_param_ids.push(state.raw.registry.add_and_overwrite_id(UnlabeledParam {
             id: dummy_id(),
             span: None,
+            is_instance: param.is_instance,
             is_dashed: param.is_dashed,
             name_id: param.name_id,
             type_id: normalized_param_type_id.raw(),
@@ -571,14 +639,19 @@ fn normalize_labeled_params_as_much_as_possible_and_leave_in_context(
     let normalized_first_param_id = {
         let first_param = state.raw.registry.get(first_param_id).clone();
         let normalized_param_type_id = evaluate_expression(state, first_param.type_id);
+        let normalized_default_id = first_param
+            .default_id
+            .map(|default_id| evaluate_expression(state, default_id).raw());
         state.context.push_uninterpreted();
         state.raw.registry.add_and_overwrite_id(LabeledParam {
             id: dummy_id(),
             span: None,
+            is_instance: first_param.is_instance,
             label_id: first_param.label_id,
             is_dashed: first_param.is_dashed,
             name_id: first_param.name_id,
             type_id: normalized_param_type_id.raw(),
+            default_id: normalized_default_id,
         })
     };
     let mut normalized_param_ids = NonEmptyVec::singleton(normalized_first_param_id);
@@ -586,13 +659,18 @@ fn normalize_labeled_params_as_much_as_possible_and_leave_in_context(
     for param_id in remaining_param_ids.iter().copied() {
         let param = state.raw.registry.get(param_id).clone();
         let normalized_param_type_id = evaluate_expression(state, param.type_id);
+        let normalized_default_id = param
+            .default_id
+            .map(|default_id| evaluate_expression(state, default_id).raw());
         normalized_param_ids.push(state.raw.registry.add_and_overwrite_id(LabeledParam {
             id: dummy_id(),
             span: None,
+            is_instance: param.is_instance,
             label_id: param.label_id,
             is_dashed: param.is_dashed,
             name_id: param.name_id,
             type_id: normalized_param_type_id.raw(),
+            default_id: normalized_default_id,
         }));
         state.context.push_uninterpreted();
     }
@@ -603,30 +681,46 @@ fn evaluate_match(state: &mut EvalState, match_id: NodeId<Match>) -> NormalFormI
     let match_ = state.raw.registry.get(match_id).clone();
     let normalized_matchee_id = evaluate_expression(state, match_.matchee_id);
 
+    if let ExpressionId::Pair(pair_id) = normalized_matchee_id.raw() {
+        if let Some(output_id) = try_evaluate_pair_match(state, &match_, pair_id) {
+            return output_id;
+        }
+    }
+
     let (normalized_matchee_variant_name_id, normalized_matchee_arg_list_id) =
-        if let Some((variant_name_id, arg_list_id)) =
-            try_as_variant_expression_with_node_registry_and_definition_getter(
-                state.raw.registry,
-                |db_index, registry| state.context.get_definition(db_index, registry),
-                normalized_matchee_id.raw(),
-            )
-        {
-            (variant_name_id, arg_list_id)
-        } else {
-            let normalized_case_list_id =
-                normalize_possibly_empty_match_case_list(state, match_.case_list_id);
-            return NormalFormId::unchecked_new(ExpressionId::Match(
-                state
+        match try_as_variant_expression_with_node_registry_and_definition_getter(
+            state.raw.registry,
+            |db_index, registry| state.context.get_definition(db_index, registry),
+            normalized_matchee_id.raw(),
+        ) {
+            Some((variant_name_id, arg_list_id))
+                if state
                     .raw
-                    .registry
-                    .add_and_overwrite_id(Match {
-                        id: dummy_id(),
-                        span: None,
-                        matchee_id: normalized_matchee_id.raw(),
-                        case_list_id: normalized_case_list_id,
-                    })
-                    .without_spans(state.raw.registry),
-            ));
+                    .reduction_tracker
+                    .try_step(ReductionTraceEventKind::MatchIota(variant_name_id)) =>
+            {
+                (variant_name_id, arg_list_id)
+            }
+            _ => {
+                let normalized_motive_id = match_
+                    .motive_id
+                    .map(|motive_id| normalize_match_motive(state, motive_id));
+                let normalized_case_list_id =
+                    normalize_possibly_empty_match_case_list(state, match_.case_list_id);
+                return NormalFormId::unchecked_new(ExpressionId::Match(
+                    state
+                        .raw
+                        .registry
+                        .add_and_overwrite_id(Match {
+                            id: dummy_id(),
+                            span: None,
+                            matchee_id: normalized_matchee_id.raw(),
+                            motive_id: normalized_motive_id,
+                            case_list_id: normalized_case_list_id,
+                        })
+                        .without_spans(state.raw.registry),
+                ));
+            }
         };
 
     let case_id = state
@@ -768,6 +862,100 @@ fn evaluate_match(state: &mut EvalState, match_id: NodeId<Match>) -> NormalFormI
     evaluate_expression(state, substituted_body)
 }
 
+/// A well-typed match on a pair has exactly one case,
+/// whose two params are bound to the pair's witness and proof.
+/// Returns `None` if the reduction step limit was reached.
+fn try_evaluate_pair_match(
+    state: &mut EvalState,
+    match_: &Match,
+    pair_id: NodeId<Pair>,
+) -> Option<NormalFormId> {
+    let case_id = *state
+        .raw
+        .registry
+        .get_possibly_empty_list(match_.case_list_id)
+        .first()
+        .expect("Impossible: a well-typed match on a pair should have exactly one case.");
+    let case = state.raw.registry.get(case_id).clone();
+    if !state
+        .raw
+        .reduction_tracker
+        .try_step(ReductionTraceEventKind::MatchIota(case.variant_name_id))
+    {
+        return None;
+    }
+
+    let Some(NonEmptyMatchCaseParamListId::Unlabeled(case_param_list_id)) = case.param_list_id
+    else {
+        panic!("Impossible: a well-typed match on a pair should have two unlabeled case params.");
+    };
+    let case_param_ids = state.raw.registry.get_list(case_param_list_id).to_vec();
+    let pair = state.raw.registry.get(pair_id).clone();
+    const CASE_ARITY: usize = 2;
+    let substitutions: Vec<Substitution> = case_param_ids
+        .iter()
+        .copied()
+        .zip([pair.witness_id, pair.proof_id])
+        .enumerate()
+        .map(|(param_index, (param_id, component_id))| {
+            let db_index = DbIndex(CASE_ARITY - param_index - 1);
+            // The components of a normal form pair are also normal forms.
+            let shifted_component_id =
+                NormalFormId::unchecked_new(component_id).upshift(CASE_ARITY, state.raw.registry);
+            Substitution {
+                from: ExpressionId::Name(add_name_expression(
+                    state.raw.registry,
+                    NonEmptyVec::singleton(param_id),
+                    db_index,
+                )),
+                to: shifted_component_id.raw(),
+            }
+        })
+        .collect();
+
+    let substituted_body = case
+        .output_id
+        .subst_all(&substitutions, state.raw)
+        .downshift(CASE_ARITY, state.raw.registry);
+    let substituted_body = match substituted_body {
+        MatchCaseOutputId::Some(id) => id,
+        MatchCaseOutputId::ImpossibilityClaim(kw_span) => {
+            panic!("Impossible: A well-typed match on a pair cannot have an impossible case. `impossible` keyword span: {:?}", kw_span)
+        }
+    };
+
+    Some(evaluate_expression(state, substituted_body))
+}
+
+fn normalize_match_motive(
+    state: &mut EvalState,
+    motive_id: NodeId<MatchMotive>,
+) -> NodeId<MatchMotive> {
+    let motive = state.raw.registry.get(motive_id).clone();
+
+    let ExpressionId::Name(normalized_matchee_type_name_id) =
+        evaluate_expression(state, ExpressionId::Name(motive.matchee_type_name_id)).raw()
+    else {
+        panic!("Impossible: The matchee type of a well-typed match motive should be an ADT name.");
+    };
+
+    let arity = motive.index_name_list_id.len() + 1;
+    for _ in 0..arity {
+        state.context.push_uninterpreted();
+    }
+    let normalized_output_id = evaluate_expression(state, motive.output_id);
+    state.context.pop_n(arity);
+
+    state.raw.registry.add_and_overwrite_id(MatchMotive {
+        id: dummy_id(),
+        span: None,
+        matchee_name_id: motive.matchee_name_id,
+        matchee_type_name_id: normalized_matchee_type_name_id,
+        index_name_list_id: motive.index_name_list_id,
+        output_id: normalized_output_id.raw(),
+    })
+}
+
 fn normalize_possibly_empty_match_case_list(
     state: &mut EvalState,
     list_id: Option<NonEmptyListId<NodeId<MatchCase>>>,
@@ -841,3 +1029,134 @@ fn evaluate_check(state: &mut EvalState, check_id: NodeId<Check>) -> NormalFormI
     let check = state.raw.registry.get(check_id);
     evaluate_expression(state, check.output_id)
 }
+
+/// A rewrite behaves like `match proof { refl(...) => body }`,
+/// so it only reduces once the proof is a variant expression
+/// (which must be the designated `refl`).
+fn evaluate_rewrite(state: &mut EvalState, rewrite_id: NodeId<Rewrite>) -> NormalFormId {
+    let rewrite = state.raw.registry.get(rewrite_id).clone();
+    let normalized_proof_id = evaluate_expression(state, rewrite.proof_id);
+
+    match try_as_variant_expression_with_node_registry_and_definition_getter(
+        state.raw.registry,
+        |db_index, registry| state.context.get_definition(db_index, registry),
+        normalized_proof_id.raw(),
+    ) {
+        Some((variant_name_id, _))
+            if state
+                .raw
+                .reduction_tracker
+                .try_step(ReductionTraceEventKind::MatchIota(variant_name_id)) =>
+        {
+            evaluate_expression(state, rewrite.body_id)
+        }
+        _ => {
+            let normalized_body_id = evaluate_expression(state, rewrite.body_id);
+            NormalFormId::unchecked_new(ExpressionId::Rewrite(
+                state
+                    .raw
+                    .registry
+                    .add_and_overwrite_id(Rewrite {
+                        id: dummy_id(),
+                        span: None,
+                        eq_type_name_id: rewrite.eq_type_name_id,
+                        eq_refl_name_id: rewrite.eq_refl_name_id,
+                        proof_id: normalized_proof_id.raw(),
+                        body_id: normalized_body_id.raw(),
+                    })
+                    .without_spans(state.raw.registry),
+            ))
+        }
+    }
+}
+
+fn evaluate_projection(state: &mut EvalState, projection_id: NodeId<Projection>) -> NormalFormId {
+    let projection = state.raw.registry.get(projection_id).clone();
+    let normalized_record_id = evaluate_expression(state, projection.record_id);
+
+    if let Some((_, Some(NonEmptyCallArgListId::UniquelyLabeled(arg_list_id)))) =
+        try_as_variant_expression_with_node_registry_and_definition_getter(
+            state.raw.registry,
+            |db_index, registry| state.context.get_definition(db_index, registry),
+            normalized_record_id.raw(),
+        )
+    {
+        let field_name = &state.raw.registry.get(projection.field_name_id).name;
+        let field_value_id = state
+            .raw
+            .registry
+            .get_list(arg_list_id)
+            .iter()
+            .find(|arg_id| &state.raw.registry.get(arg_id.label_id()).name == field_name)
+            .map(|arg_id| arg_id.value_id());
+        if let Some(field_value_id) = field_value_id {
+            state.raw.reduction_tracker.record_trace_event(
+                ReductionTraceEventKind::ProjectionIota(projection.field_name_id),
+            );
+            // The args of a normal form are themselves normal forms.
+            return NormalFormId::unchecked_new(field_value_id);
+        }
+    }
+
+    let normalized_projection_id = state
+        .raw
+        .registry
+        .add_and_overwrite_id(Projection {
+            id: dummy_id(),
+            span: None,
+            record_id: normalized_record_id.raw(),
+            field_name_id: projection.field_name_id,
+        })
+        .without_spans(state.raw.registry);
+    NormalFormId::unchecked_new(ExpressionId::Projection(normalized_projection_id))
+}
+
+fn evaluate_exists(state: &mut EvalState, exists_id: NodeId<Exists>) -> NormalFormId {
+    let exists = state.raw.registry.get(exists_id).clone();
+    let param = state.raw.registry.get(exists.param_id).clone();
+    let normalized_param_type_id = evaluate_expression(state, param.type_id);
+    let normalized_param_id = state.raw.registry.add_and_overwrite_id(UnlabeledParam {
+        id: dummy_id(),
+        span: None,
+        is_instance: param.is_instance,
+        is_dashed: param.is_dashed,
+        name_id: param.name_id,
+        type_id: normalized_param_type_id.raw(),
+    });
+
+    state.context.push_uninterpreted();
+    let normalized_output_id = evaluate_expression(state, exists.output_id);
+    state.context.pop_n(1);
+
+    NormalFormId::unchecked_new(ExpressionId::Exists(
+        state
+            .raw
+            .registry
+            .add_and_overwrite_id(Exists {
+                id: dummy_id(),
+                span: None,
+                param_id: normalized_param_id,
+                output_id: normalized_output_id.raw(),
+            })
+            .without_spans(state.raw.registry),
+    ))
+}
+
+fn evaluate_pair(state: &mut EvalState, pair_id: NodeId<Pair>) -> NormalFormId {
+    let pair = state.raw.registry.get(pair_id).clone();
+    let normalized_witness_id = evaluate_expression(state, pair.witness_id);
+    let normalized_proof_id = evaluate_expression(state, pair.proof_id);
+
+    NormalFormId::unchecked_new(ExpressionId::Pair(
+        state
+            .raw
+            .registry
+            .add_and_overwrite_id(Pair {
+                id: dummy_id(),
+                span: None,
+                witness_id: normalized_witness_id.raw(),
+                proof_id: normalized_proof_id.raw(),
+            })
+            .without_spans(state.raw.registry),
+    ))
+}
@@ -15,15 +15,16 @@ impl NormalFormId {
     }
 }
 
-pub(super) fn type0_expression(state: &mut State) -> NormalFormId {
+/// Panics if `level` exceeds the limit universe level.
+pub(super) fn universe_expression(state: &mut State, level: usize) -> NormalFormId {
     let name_id = add_name_expression_and_overwrite_component_ids(
         state.registry,
         NonEmptyVec::singleton(Identifier {
             id: dummy_id(),
-            name: IdentifierName::Reserved(ReservedIdentifierName::TypeTitleCase),
+            name: IdentifierName::Reserved(ReservedIdentifierName::universe(level)),
             span: None,
         }),
-        state.context.type0_dbi(),
+        state.context.universe_dbi(level),
     );
     NormalFormId::unchecked_new(ExpressionId::Name(name_id))
 }
@@ -94,24 +95,19 @@ impl PossiblyNullaryForall {
     }
 }
 
-pub(super) fn is_term_equal_to_type0_or_type1(state: &State, term: NormalFormId) -> bool {
+/// Returns the level of the universe `term` is equal to,
+/// or `None` if `term` is not a universe.
+pub(super) fn get_universe_level(state: &State, term: NormalFormId) -> Option<usize> {
     if let ExpressionId::Name(name_id) = term.raw() {
         let name = state.registry.get(name_id);
-        let i = name.db_index;
-        i == state.context.type0_dbi() || i == state.context.type1_dbi()
+        state.context.universe_level(name.db_index)
     } else {
-        false
+        None
     }
 }
 
-pub(super) fn is_term_equal_to_type1(state: &State, term: NormalFormId) -> bool {
-    if let ExpressionId::Name(name_id) = term.raw() {
-        let name = state.registry.get(name_id);
-        let i = name.db_index;
-        i == state.context.type1_dbi()
-    } else {
-        false
-    }
+pub(super) fn is_term_equal_to_limit_universe(state: &State, term: NormalFormId) -> bool {
+    get_universe_level(state, term) == Some(state.context.limit_universe_level())
 }
 
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
@@ -142,10 +138,29 @@ pub(super) fn get_rewritten_term_equality_status(
             Err(self::Exploded) => return RewrittenTermEqualityStatus::Exploded,
         };
 
-    if state
-        .equality_checker
-        .eq(left.raw(), right.raw(), state.registry)
-    {
+    if are_normal_forms_eta_equal(state, left, right) {
+        RewrittenTermEqualityStatus::Equal
+    } else {
+        RewrittenTermEqualityStatus::NotEqual
+    }
+}
+
+/// Like `get_rewritten_term_equality_status`, except that
+/// `left` only needs to be a subtype of `right` (rather than equal to it)
+/// to be considered `Equal`.
+/// See `is_left_type_subtype_of_right_type` for details.
+pub(super) fn get_rewritten_subtyping_status(
+    state: &mut State,
+    left: NormalFormId,
+    right: NormalFormId,
+) -> RewrittenTermEqualityStatus {
+    let ((left,), (right,)) =
+        match apply_substitutions_from_substitution_context(state, ((left,), (right,))) {
+            Ok(x) => x,
+            Err(self::Exploded) => return RewrittenTermEqualityStatus::Exploded,
+        };
+
+    if is_left_type_subtype_of_right_type(state, left, right) {
         RewrittenTermEqualityStatus::Equal
     } else {
         RewrittenTermEqualityStatus::NotEqual
@@ -210,6 +225,7 @@ pub(super) fn normalize_unlabeled_params_and_leave_params_in_context_dirty(
             let normalized_param_with_dummy_id = UnlabeledParam {
                 id: dummy_id(),
                 span: None,
+                is_instance: old_param.is_instance,
                 is_dashed: old_param.is_dashed,
                 name_id: old_param.name_id,
                 type_id,
@@ -237,14 +253,24 @@ pub(super) fn normalize_labeled_params_and_leave_params_in_context_dirty(
                 .get_type(DbIndex(0), state.registry)
                 .downshift(1, state.registry)
                 .raw();
+            // The default cannot refer to its own param,
+            // so we can evaluate it one level up and then downshift it.
+            let default_id = state.registry.get(param_id).default_id.map(|default_id| {
+                let shifted_default_id = default_id.upshift(1, state.registry);
+                evaluate_well_typed_expression(state, shifted_default_id)
+                    .downshift(1, state.registry)
+                    .raw()
+            });
             let old_param = state.registry.get(param_id);
             let normalized_param_with_dummy_id = LabeledParam {
                 id: dummy_id(),
                 span: None,
+                is_instance: old_param.is_instance,
                 label_id: old_param.label_id,
                 is_dashed: old_param.is_dashed,
                 name_id: old_param.name_id,
                 type_id,
+                default_id,
             };
             let normalized_id = state
                 .registry
@@ -451,6 +477,15 @@ fn is_left_inclusive_subterm_of_right(
                 return true;
             }
 
+            if let Some(right_motive_id) = right.motive_id {
+                let right_motive = state.registry.get(right_motive_id).clone();
+                let motive_arity = right_motive.index_name_list_id.len() + 1;
+                let shifted_left = left.upshift(motive_arity, state.registry);
+                if is_left_inclusive_subterm_of_right(state, shifted_left, right_motive.output_id) {
+                    return true;
+                }
+            }
+
             let right_case_ids = state
                 .registry
                 .get_possibly_empty_list(right.case_list_id)
@@ -504,6 +539,53 @@ fn is_left_inclusive_subterm_of_right(
                 return true;
             }
 
+            false
+        }
+        ExpressionId::Rewrite(right_id) => {
+            let right = state.registry.get(right_id).clone();
+
+            if is_left_inclusive_subterm_of_right(state, left, right.proof_id) {
+                return true;
+            }
+
+            if is_left_inclusive_subterm_of_right(state, left, right.body_id) {
+                return true;
+            }
+
+            false
+        }
+        ExpressionId::Projection(right_id) => {
+            let right = state.registry.get(right_id).clone();
+            is_left_inclusive_subterm_of_right(state, left, right.record_id)
+        }
+        ExpressionId::Exists(right_id) => {
+            let right = state.registry.get(right_id).clone();
+
+            let right_param_type_id = state.registry.get(right.param_id).type_id;
+            if is_left_inclusive_subterm_of_right(state, left, right_param_type_id) {
+                return true;
+            }
+
+            {
+                let shifted_left = left.upshift(1, state.registry);
+                if is_left_inclusive_subterm_of_right(state, shifted_left, right.output_id) {
+                    return true;
+                }
+            }
+
+            false
+        }
+        ExpressionId::Pair(right_id) => {
+            let right = state.registry.get(right_id).clone();
+
+            if is_left_inclusive_subterm_of_right(state, left, right.witness_id) {
+                return true;
+            }
+
+            if is_left_inclusive_subterm_of_right(state, left, right.proof_id) {
+                return true;
+            }
+
             false
         }
     }
@@ -874,7 +956,14 @@ fn expand_dynamic_substitution_shallow(
         expand_dynamic_normal_form_variant_substitution_shallow(state, left, right)
     }
     // TODO: Add else-ifs to handle cases Fun, Forall, etc.
-    else {
+    else if does_left_occur_strictly_under_right_constructors(state, d.0.raw(), d.1.raw())
+        || does_left_occur_strictly_under_right_constructors(state, d.1.raw(), d.0.raw())
+    {
+        // A term can never equal a constructor expression that strictly
+        // contains it (e.g., `n = s(n)`), since every value of an ADT
+        // is finite.
+        DynamicSubstitutionExpansionResult::Exploded
+    } else {
         // TODO: Refactor
         match get_concrete_substitution(state, d) {
             Some(conc_sub) => DynamicSubstitutionExpansionResult::ApplyConcrete(conc_sub),
@@ -1013,6 +1102,51 @@ fn expand_dynamic_normal_form_labeled_call_arg_list_substitution_shallow(
     DynamicSubstitutionExpansionResult::Replace(subs)
 }
 
+/// Returns true if `left` is equal to some argument of the
+/// top-level constructor (i.e., ADT or variant) of `right`,
+/// or if `left` occurs (by the same rule) in such an argument.
+///
+/// We only descend through constructors, since constructors are
+/// injective and disjoint.
+/// For example, `n` strictly occurs under the constructors of `s(s(n))`,
+/// but not under the constructors of `s(f(n))`,
+/// since `f(n)` may very well reduce to `o`.
+fn does_left_occur_strictly_under_right_constructors(
+    state: &mut State,
+    left: ExpressionId,
+    right: ExpressionId,
+) -> bool {
+    let right_arg_list_id = if let Some((_, arg_list_id)) = try_as_variant_expression(state, right)
+    {
+        arg_list_id
+    } else if let Some(adt) =
+        try_as_normal_form_adt_expression(state, NormalFormId::unchecked_new(right))
+    {
+        adt.arg_list_id
+    } else {
+        return false;
+    };
+    let Some(right_arg_list_id) = right_arg_list_id else {
+        return false;
+    };
+
+    let right_arg_value_ids: Vec<ExpressionId> = match right_arg_list_id {
+        NonEmptyCallArgListId::Unlabeled(id) => state.registry.get_list(id).to_vec(),
+        NonEmptyCallArgListId::UniquelyLabeled(id) => state
+            .registry
+            .get_list(id)
+            .iter()
+            .map(|arg_id| arg_id.value_id())
+            .collect(),
+    };
+    right_arg_value_ids.into_iter().any(|right_arg_value_id| {
+        state
+            .equality_checker
+            .eq(left, right_arg_value_id, state.registry)
+            || does_left_occur_strictly_under_right_constructors(state, left, right_arg_value_id)
+    })
+}
+
 /// Returns `None` if the dynamic substitution is a no-op.
 fn get_concrete_substitution(state: &mut State, d: DynamicSubstitution) -> Option<Substitution> {
     if d.0.raw() == d.1.raw() {
@@ -7,16 +7,44 @@ use crate::data::{
     text_span::*,
     type_positivity_validation_result::TypePositivityValidated,
 };
+use crate::processing::bind_type_independent::DesignatedBool;
+
+use bigint::*;
+pub use bigint::{BigIntAnalysis, BigIntFunction, BigIntOperation, BigIntType, BigIntVariant};
+mod bigint;
 
 use eval::*;
+pub use eval::{
+    Evaluator, ReductionProfile, ReductionTrace, ReductionTraceEvent, ReductionTraceEventKind,
+    TracedNormalization,
+};
 mod eval;
 
 use context::*;
 mod context;
 
+use cumulativity::*;
+mod cumulativity;
+
+pub(crate) use erasure::is_path_of_item;
+use erasure::*;
+pub use erasure::{ErasedArgs, ErasureAnalysis};
+mod erasure;
+
+use eta_equality::*;
+mod eta_equality;
+
 use misc::*;
 mod misc;
 
+pub use normal_form_cache::NormalFormCacheStats;
+use normal_form_cache::*;
+mod normal_form_cache;
+
+use precompute::*;
+pub use precompute::{PrecomputedLets, PrecomputedValue};
+mod precompute;
+
 use shift::*;
 mod shift;
 
@@ -26,8 +54,8 @@ mod substitute;
 use substitution_context::*;
 mod substitution_context;
 
-pub use type_check_node::type_check_file_items;
 use type_check_node::*;
+pub use type_check_node::{type_check_file_items, type_check_file_items_with_options};
 mod type_check_node;
 
 mod verify_expression_is_visibility;
@@ -36,11 +64,67 @@ use verify_expression_is_visibility::*;
 mod without_spans;
 use without_spans::*;
 
+#[derive(Clone, Debug)]
+pub struct TypeCheckOptions {
+    pub evaluator: Evaluator,
+    /// The maximum number of reduction steps that may be taken
+    /// while normalizing a single expression.
+    /// `None` means there is no limit.
+    pub max_reduction_steps: Option<usize>,
+    /// If this is `Some`, the reduction steps taken while normalizing
+    /// the comparees of the check assertion whose span contains
+    /// this span are recorded in `TypeCheckOutput::reduction_trace`.
+    pub traced_check_span: Option<TextSpan>,
+    /// The types whose terms are erased from generated code
+    /// (e.g., `pack.Eq`), as specified by `erased_types` in `pack.yscl`.
+    pub erased_types: Vec<NonEmptyVec<IdentifierName>>,
+    /// The types that code generators represent as BigInts
+    /// (e.g., `pack.num.Nat`), as specified by `bigint_types` in `pack.yscl`.
+    pub bigint_types: Vec<NonEmptyVec<IdentifierName>>,
+    /// The functions that code generators replace with native BigInt operations,
+    /// as specified by `bigint_functions` in `pack.yscl`.
+    pub bigint_functions: Vec<(BigIntOperation, NonEmptyVec<IdentifierName>)>,
+    /// The type that BigInt comparisons (i.e., `eq` and `lt`) return.
+    pub designated_bool: Option<DesignatedBool>,
+    /// If true, the values of closed `let` statements are precomputed
+    /// (see `PrecomputedLets`).
+    pub precompute_lets: bool,
+}
+
+pub const DEFAULT_MAX_REDUCTION_STEPS: usize = 1_000_000;
+
+impl Default for TypeCheckOptions {
+    fn default() -> Self {
+        Self {
+            evaluator: Evaluator::default(),
+            max_reduction_steps: Some(DEFAULT_MAX_REDUCTION_STEPS),
+            traced_check_span: None,
+            erased_types: vec![],
+            bigint_types: vec![],
+            bigint_functions: vec![],
+            designated_bool: None,
+            precompute_lets: false,
+        }
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct TypeCheckOutput {
+    pub warnings: Vec<TypeCheckWarning>,
+    pub normal_form_cache_stats: NormalFormCacheStats,
+    pub reduction_profile: ReductionProfile,
+    /// This is `None` if no check assertion was traced.
+    pub reduction_trace: Option<ReductionTrace>,
+    pub erasure: ErasureAnalysis,
+    pub bigint: BigIntAnalysis,
+    pub precomputed_lets: PrecomputedLets,
+}
+
 #[derive(Clone, Debug)]
 pub enum TypeCheckError {
-    ExpectedTermOfTypeType0OrType1 {
+    ExpectedTermOfTypeUniverse {
         expression_id: ExpressionId,
-        non_type0_or_type1_type_id: NormalFormId,
+        non_universe_type_id: NormalFormId,
     },
     IllegalCallee {
         callee_id: ExpressionId,
@@ -127,6 +211,81 @@ pub enum TypeCheckError {
         name_id: NodeId<NameExpression>,
         name_visibility: Visibility,
     },
+    ReductionStepLimitExceeded {
+        expression_id: ExpressionId,
+        /// The `let` statement whose function body was being
+        /// reduced when the limit was exceeded, if any.
+        unfolding_let_id: Option<NodeId<LetStatement>>,
+        max_reduction_steps: usize,
+    },
+    MatchMotiveAdtMismatch {
+        motive_id: NodeId<MatchMotive>,
+        matchee_type_id: NormalFormId,
+    },
+    WrongNumberOfMatchMotiveIndices {
+        motive_id: NodeId<MatchMotive>,
+        expected: usize,
+        actual: usize,
+    },
+    CannotInferTypeOfRewrite(NodeId<Rewrite>),
+    RewriteProofIsNotAnEquality {
+        rewrite_id: NodeId<Rewrite>,
+        proof_type_id: NormalFormId,
+    },
+    IllegalDesignatedEqType {
+        rewrite_id: NodeId<Rewrite>,
+    },
+    ProjectionRecordIsNotAStruct {
+        projection_id: NodeId<Projection>,
+        record_type_id: NormalFormId,
+    },
+    StructHasNoSuchField {
+        projection_id: NodeId<Projection>,
+        record_type_id: NormalFormId,
+    },
+    IllegalExistsMatchCases {
+        match_id: NodeId<Match>,
+    },
+    ExistsMatchHasMotive {
+        motive_id: NodeId<MatchMotive>,
+    },
+    InstanceTypeIsNotATrait {
+        /// The type of an instance param,
+        /// or the value of an `instance` statement.
+        expression_id: ExpressionId,
+        type_id: NormalFormId,
+    },
+    MissingInstance {
+        call_id: NodeId<Call>,
+        instance_type_id: NormalFormId,
+    },
+    AmbiguousInstance {
+        call_id: NodeId<Call>,
+        instance_type_id: NormalFormId,
+        candidate_list_id: NonEmptyListId<ExpressionId>,
+    },
+    /// Erased types must have at most one variant,
+    /// since matches on erased terms cannot inspect them.
+    IllegalErasedType {
+        type_statement_id: NodeId<TypeStatement>,
+    },
+    UndeclaredErasedType {
+        name_components: NonEmptyVec<IdentifierName>,
+    },
+    /// BigInt types must have one of the shapes described in `BigIntAnalysis`.
+    IllegalBigIntType {
+        type_statement_id: NodeId<TypeStatement>,
+    },
+    /// BigInt functions must have the types described in `BigIntFunction`.
+    IllegalBigIntFunction {
+        let_statement_id: NodeId<LetStatement>,
+        operation: BigIntOperation,
+    },
+    /// A type or function listed in `bigint_types` or `bigint_functions`
+    /// was never declared.
+    UndeclaredBigIntItem {
+        name_components: NonEmptyVec<IdentifierName>,
+    },
 }
 
 #[derive(Clone, Debug)]
@@ -139,7 +298,7 @@ pub enum TypeCheckWarning {
 #[derive(Clone, Debug)]
 pub enum TypeAssertionWarning {
     GoalLhs(NodeId<CheckAssertion>),
-    LhsTypeIsType1(NodeId<CheckAssertion>),
+    LhsTypeIsLimitUniverse(NodeId<CheckAssertion>),
     CompareeTypeCheckFailure(TypeCheckFailureReason),
     TypesDoNotMatch {
         left_id: ExpressionId,
@@ -180,6 +339,14 @@ struct State<'a> {
     warnings: &'a mut Vec<TypeCheckWarning>,
 
     required_transparency_for_substitution: Option<Transparency>,
+    evaluator: Evaluator,
+    normal_form_cache_stats: &'a mut NormalFormCacheStats,
+    reduction_tracker: &'a mut ReductionTracker,
+    erasure: &'a mut ErasureAnalysis,
+    designated_erased_types: &'a mut DesignatedErasedTypes,
+    bigint: &'a mut BigIntAnalysis,
+    designated_bigints: &'a mut DesignatedBigInts,
+    precomputation: &'a mut Precomputation,
 
     context: &'a mut Context,
 }
@@ -198,6 +365,14 @@ impl<'a> State<'_> {
             warnings: self.warnings,
 
             required_transparency_for_substitution: self.required_transparency_for_substitution,
+            evaluator: self.evaluator,
+            normal_form_cache_stats: self.normal_form_cache_stats,
+            reduction_tracker: self.reduction_tracker,
+            erasure: self.erasure,
+            designated_erased_types: self.designated_erased_types,
+            bigint: self.bigint,
+            designated_bigints: self.designated_bigints,
+            precomputation: self.precomputation,
         };
         (self.context, contextless)
     }
@@ -212,4 +387,12 @@ struct ContextlessState<'a> {
     warnings: &'a mut Vec<TypeCheckWarning>,
 
     required_transparency_for_substitution: Option<Transparency>,
+    evaluator: Evaluator,
+    normal_form_cache_stats: &'a mut NormalFormCacheStats,
+    reduction_tracker: &'a mut ReductionTracker,
+    erasure: &'a mut ErasureAnalysis,
+    designated_erased_types: &'a mut DesignatedErasedTypes,
+    bigint: &'a mut BigIntAnalysis,
+    designated_bigints: &'a mut DesignatedBigInts,
+    precomputation: &'a mut Precomputation,
 }
@@ -0,0 +1,60 @@
+use super::*;
+
+use crate::data::node_equality_checker::ExpressionSemanticId;
+
+use rustc_hash::FxHashMap;
+
+/// Memoizes the results of `evaluate_well_typed_expression`.
+///
+/// An expression's normal form depends on
+/// 1. the expression itself (up to semantic equality,
+///    except that labeled param defaults are significant),
+/// 2. the context it is evaluated in, and
+/// 3. the `required_transparency_for_substitution` (which determines
+///    which aliases may be unfolded).
+///
+/// The first and third are part of the key.
+/// The second is handled by storing one bucket per context length:
+/// the bucket at index `n` is only valid for as long as the bottom `n`
+/// entries of the context remain unchanged.
+/// Thus, whenever the context is popped, truncated, or modified in place,
+/// we discard every bucket that might depend on the removed (or modified) entries.
+#[derive(Clone, Debug, Default)]
+pub struct NormalFormCache {
+    buckets: Vec<FxHashMap<NormalFormCacheKey, NormalFormId>>,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct NormalFormCacheKey {
+    pub expression_id: ExpressionSemanticId,
+    pub required_transparency_for_substitution: Option<Transparency>,
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct NormalFormCacheStats {
+    pub hits: usize,
+    pub misses: usize,
+}
+
+impl NormalFormCache {
+    pub fn get(&self, context_len: usize, key: NormalFormCacheKey) -> Option<NormalFormId> {
+        self.buckets
+            .get(context_len)
+            .and_then(|bucket| bucket.get(&key))
+            .copied()
+    }
+
+    pub fn insert(&mut self, context_len: usize, key: NormalFormCacheKey, value: NormalFormId) {
+        if self.buckets.len() <= context_len {
+            self.buckets
+                .resize_with(context_len + 1, FxHashMap::default);
+        }
+        self.buckets[context_len].insert(key, value);
+    }
+
+    /// Discards every entry computed in a context
+    /// with more than `max_len` entries.
+    pub fn invalidate_contexts_longer_than(&mut self, max_len: usize) {
+        self.buckets.truncate(max_len + 1);
+    }
+}
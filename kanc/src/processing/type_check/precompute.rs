@@ -0,0 +1,345 @@
+use super::*;
+
+use crate::data::node_registry::{MatchCaseOutputId, NonEmptyCallArgListId, NonEmptyParamListId};
+
+use rustc_hash::{FxHashMap, FxHashSet};
+
+/// The `let` statements whose values the type checker computed ahead of time,
+/// so code generators can emit the values as literals
+/// instead of computing them at runtime.
+///
+/// A `let` is precomputed if its value normalizes
+/// (with every alias unfolded, regardless of transparency)
+/// to a tree of at most `MAX_PRECOMPUTED_VALUE_SIZE` variants
+/// within `MAX_PRECOMPUTATION_STEPS` reduction steps.
+///
+/// A `let` is never precomputed if evaluating it might reach a `todo`
+/// (either directly, or by referring to another `let` that might),
+/// so the `todo` still throws at runtime.
+/// Since a precomputed value takes no args,
+/// it cannot reach an impossible match case either.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct PrecomputedLets {
+    values: FxHashMap<NodeId<LetStatement>, PrecomputedValue>,
+}
+
+/// A variant applied to its args.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct PrecomputedValue {
+    pub type_id: NodeId<TypeStatement>,
+    pub variant_id: NodeId<Variant>,
+    /// The args, in the order of the variant's params
+    /// (even if the params are labeled).
+    /// An arg is `None` if its param is erased.
+    pub args: Vec<Option<PrecomputedValue>>,
+}
+
+impl PrecomputedLets {
+    pub fn value(&self, let_id: NodeId<LetStatement>) -> Option<&PrecomputedValue> {
+        self.values.get(&let_id)
+    }
+}
+
+const MAX_PRECOMPUTED_VALUE_SIZE: usize = 256;
+
+const MAX_PRECOMPUTATION_STEPS: usize = 100_000;
+
+/// Tracks what `PrecomputedLets` needs while the file items are type checked.
+#[derive(Clone, Debug)]
+pub(super) struct Precomputation {
+    is_enabled: bool,
+    /// The type statement and variant that each variant name belongs to.
+    variants_by_name_id: FxHashMap<NodeId<Identifier>, (NodeId<TypeStatement>, NodeId<Variant>)>,
+    /// The context levels of the `let` statements whose evaluation
+    /// might reach a `todo`.
+    todo_levels: FxHashSet<DbLevel>,
+    lets: PrecomputedLets,
+}
+
+impl Precomputation {
+    pub(super) fn new(is_enabled: bool) -> Self {
+        Self {
+            is_enabled,
+            variants_by_name_id: FxHashMap::default(),
+            todo_levels: FxHashSet::default(),
+            lets: PrecomputedLets::default(),
+        }
+    }
+
+    pub(super) fn into_precomputed_lets(self) -> PrecomputedLets {
+        self.lets
+    }
+}
+
+pub(in crate::processing::type_check) fn record_type_statement_variants_for_precomputation(
+    state: &mut State,
+    type_statement_id: NodeId<TypeStatement>,
+) {
+    if !state.precomputation.is_enabled {
+        return;
+    }
+    let type_statement = state.registry.get(type_statement_id);
+    for &variant_id in state
+        .registry
+        .get_possibly_empty_list(type_statement.variant_list_id)
+    {
+        let name_id = state.registry.get(variant_id).name_id;
+        state
+            .precomputation
+            .variants_by_name_id
+            .insert(name_id, (type_statement_id, variant_id));
+    }
+}
+
+/// If the `let` statement's value can be precomputed
+/// (see `PrecomputedLets`), this records the value.
+/// The `let` statement must not have been pushed onto the context yet.
+pub(in crate::processing::type_check) fn precompute_let_statement_if_possible(
+    state: &mut State,
+    let_statement_id: NodeId<LetStatement>,
+    type_id: NormalFormId,
+) {
+    if !state.precomputation.is_enabled {
+        return;
+    }
+
+    let value_id = state.registry.get(let_statement_id).value_id;
+    if might_reach_todo(state, value_id, 0) {
+        let level = DbLevel(state.context.len());
+        state.precomputation.todo_levels.insert(level);
+        return;
+    }
+    // Only terms of ADTs can be variant trees, so we don't waste
+    // reduction steps on functions or types.
+    if !is_adt(state, type_id) {
+        return;
+    }
+
+    let Some(normal_form_id) =
+        try_evaluate_well_typed_expression_speculatively(state, value_id, MAX_PRECOMPUTATION_STEPS)
+    else {
+        return;
+    };
+    let mut remaining_size = MAX_PRECOMPUTED_VALUE_SIZE;
+    if let Some(value) = get_precomputed_value(state, normal_form_id.raw(), &mut remaining_size) {
+        state
+            .precomputation
+            .lets
+            .values
+            .insert(let_statement_id, value);
+    }
+}
+
+/// Returns whether `type_id` is a type constructor, possibly applied to args.
+fn is_adt(state: &State, type_id: NormalFormId) -> bool {
+    let callee_id = match type_id.raw() {
+        ExpressionId::Call(call_id) => state.registry.get(call_id).callee_id,
+        other => other,
+    };
+    let ExpressionId::Name(name_id) = callee_id else {
+        return false;
+    };
+    let level = state
+        .context
+        .index_to_level(state.registry.get(name_id).db_index);
+    matches!(
+        state.context.get_local_definition(level),
+        ContextEntryDefinition::Adt { .. }
+    )
+}
+
+/// Returns `None` if the normal form is not a tree of variants,
+/// or if the tree has more than `remaining_size` nodes.
+fn get_precomputed_value(
+    state: &State,
+    id: ExpressionId,
+    remaining_size: &mut usize,
+) -> Option<PrecomputedValue> {
+    let (callee_id, arg_list_id) = match id {
+        ExpressionId::Name(_) => (id, None),
+        ExpressionId::Call(call_id) => {
+            let call = state.registry.get(call_id);
+            (call.callee_id, Some(call.arg_list_id))
+        }
+        _ => return None,
+    };
+    let ExpressionId::Name(name_id) = callee_id else {
+        return None;
+    };
+    let level = state
+        .context
+        .index_to_level(state.registry.get(name_id).db_index);
+    let ContextEntryDefinition::Variant {
+        name_id: variant_name_id,
+        ..
+    } = state.context.get_local_definition(level)
+    else {
+        return None;
+    };
+    let &(type_id, variant_id) = state
+        .precomputation
+        .variants_by_name_id
+        .get(&variant_name_id)?;
+    *remaining_size = remaining_size.checked_sub(1)?;
+
+    let param_list_id = state.registry.get(variant_id).param_list_id;
+    let mut args = vec![];
+    match (param_list_id, arg_list_id) {
+        (None, None) => {}
+        (
+            Some(NonEmptyParamListId::Unlabeled(param_list_id)),
+            Some(NonEmptyCallArgListId::Unlabeled(arg_list_id)),
+        ) => {
+            let param_ids = state.registry.get_list(param_list_id);
+            let arg_ids = state.registry.get_list(arg_list_id);
+            if param_ids.len() != arg_ids.len() {
+                return None;
+            }
+            for (&param_id, &arg_id) in param_ids.iter().zip(arg_ids.iter()) {
+                if state.erasure.is_unlabeled_param_erased(param_id) {
+                    args.push(None);
+                } else {
+                    args.push(Some(get_precomputed_value(state, arg_id, remaining_size)?));
+                }
+            }
+        }
+        (
+            Some(NonEmptyParamListId::UniquelyLabeled(param_list_id)),
+            Some(NonEmptyCallArgListId::UniquelyLabeled(arg_list_id)),
+        ) => {
+            let arg_ids = state.registry.get_list(arg_list_id);
+            for &param_id in state.registry.get_list(param_list_id).iter() {
+                if state.erasure.is_labeled_param_erased(param_id) {
+                    args.push(None);
+                    continue;
+                }
+                let label = &state
+                    .registry
+                    .get(state.registry.get(param_id).label_identifier_id())
+                    .name;
+                let arg_id = arg_ids
+                    .iter()
+                    .find(|arg_id| state.registry.get(arg_id.label_id()).name == *label)?;
+                args.push(Some(get_precomputed_value(
+                    state,
+                    arg_id.value_id(),
+                    remaining_size,
+                )?));
+            }
+        }
+        _ => return None,
+    }
+
+    Some(PrecomputedValue {
+        type_id,
+        variant_id,
+        args,
+    })
+}
+
+/// Returns whether evaluating the expression might reach a `todo`.
+/// Check assertions are ignored, since they are never evaluated.
+///
+/// `depth` is the number of entries bound between the context
+/// and the expression.
+fn might_reach_todo(state: &State, id: ExpressionId, depth: usize) -> bool {
+    let registry = &*state.registry;
+    match id {
+        ExpressionId::Name(id) => {
+            let db_index = registry.get(id).db_index;
+            db_index.0 >= depth
+                && state
+                    .precomputation
+                    .todo_levels
+                    .contains(&state.context.index_to_level(DbIndex(db_index.0 - depth)))
+        }
+        ExpressionId::Todo(_) => true,
+        ExpressionId::Call(id) => {
+            let call = registry.get(id);
+            might_reach_todo(state, call.callee_id, depth)
+                || match call.arg_list_id {
+                    NonEmptyCallArgListId::Unlabeled(id) => registry
+                        .get_list(id)
+                        .iter()
+                        .any(|&arg_id| might_reach_todo(state, arg_id, depth)),
+                    NonEmptyCallArgListId::UniquelyLabeled(id) => registry
+                        .get_list(id)
+                        .iter()
+                        .any(|arg_id| might_reach_todo(state, arg_id.value_id(), depth)),
+                }
+        }
+        ExpressionId::Fun(id) => {
+            let fun = registry.get(id);
+            let param_len = fun.param_list_id.len();
+            params_might_reach_todo(state, Some(fun.param_list_id), depth)
+                || might_reach_todo(state, fun.return_type_id, depth + param_len)
+                // The fun's own name is in scope in its body.
+                || might_reach_todo(state, fun.body_id, depth + param_len + 1)
+        }
+        ExpressionId::Match(id) => {
+            let match_ = registry.get(id);
+            might_reach_todo(state, match_.matchee_id, depth)
+                || registry
+                    .get_possibly_empty_list(match_.case_list_id)
+                    .iter()
+                    .any(|&case_id| {
+                        let case = registry.get(case_id);
+                        match case.output_id {
+                            MatchCaseOutputId::Some(output_id) => {
+                                might_reach_todo(state, output_id, depth + case.param_list_id.len())
+                            }
+                            MatchCaseOutputId::ImpossibilityClaim(_) => false,
+                        }
+                    })
+        }
+        ExpressionId::Forall(id) => {
+            let forall = registry.get(id);
+            params_might_reach_todo(state, Some(forall.param_list_id), depth)
+                || might_reach_todo(state, forall.output_id, depth + forall.param_list_id.len())
+        }
+        ExpressionId::Check(id) => might_reach_todo(state, registry.get(id).output_id, depth),
+        ExpressionId::Rewrite(id) => {
+            let rewrite = registry.get(id);
+            might_reach_todo(state, rewrite.proof_id, depth)
+                || might_reach_todo(state, rewrite.body_id, depth)
+        }
+        ExpressionId::Projection(id) => might_reach_todo(state, registry.get(id).record_id, depth),
+        ExpressionId::Exists(id) => {
+            let exists = registry.get(id);
+            might_reach_todo(state, registry.get(exists.param_id).type_id, depth)
+                || might_reach_todo(state, exists.output_id, depth + 1)
+        }
+        ExpressionId::Pair(id) => {
+            let pair = registry.get(id);
+            might_reach_todo(state, pair.witness_id, depth)
+                || might_reach_todo(state, pair.proof_id, depth)
+        }
+    }
+}
+
+fn params_might_reach_todo(state: &State, id: Option<NonEmptyParamListId>, depth: usize) -> bool {
+    let registry = &*state.registry;
+    match id {
+        None => false,
+        Some(NonEmptyParamListId::Unlabeled(id)) => {
+            registry
+                .get_list(id)
+                .iter()
+                .enumerate()
+                .any(|(param_index, &param_id)| {
+                    might_reach_todo(state, registry.get(param_id).type_id, depth + param_index)
+                })
+        }
+        Some(NonEmptyParamListId::UniquelyLabeled(id)) => registry
+            .get_list(id)
+            .iter()
+            .enumerate()
+            .any(|(param_index, &param_id)| {
+                let param = registry.get(param_id);
+                might_reach_todo(state, param.type_id, depth + param_index)
+                    || param.default_id.is_some_and(|default_id| {
+                        might_reach_todo(state, default_id, depth + param_index)
+                    })
+            }),
+    }
+}
@@ -47,15 +47,19 @@ impl ShiftDbIndices for ContextEntryDefinition {
                 value_id,
                 visibility,
                 transparency,
+                let_id,
             } => ContextEntryDefinition::Alias {
                 value_id: value_id.try_shift_with_cutoff(f, cutoff, registry)?,
                 visibility,
                 transparency,
+                let_id,
             },
 
             ContextEntryDefinition::Adt {
                 variant_name_list_id: _,
                 visibility: _,
+                is_struct: _,
+                is_trait: _,
             }
             | ContextEntryDefinition::Variant {
                 name_id: _,
@@ -142,6 +146,18 @@ impl ShiftDbIndices for ExpressionId {
             ExpressionId::Check(check_id) => {
                 ExpressionId::Check(check_id.try_shift_with_cutoff(f, cutoff, registry)?)
             }
+            ExpressionId::Rewrite(rewrite_id) => {
+                ExpressionId::Rewrite(rewrite_id.try_shift_with_cutoff(f, cutoff, registry)?)
+            }
+            ExpressionId::Projection(projection_id) => {
+                ExpressionId::Projection(projection_id.try_shift_with_cutoff(f, cutoff, registry)?)
+            }
+            ExpressionId::Exists(exists_id) => {
+                ExpressionId::Exists(exists_id.try_shift_with_cutoff(f, cutoff, registry)?)
+            }
+            ExpressionId::Pair(pair_id) => {
+                ExpressionId::Pair(pair_id.try_shift_with_cutoff(f, cutoff, registry)?)
+            }
         })
     }
 }
@@ -370,6 +386,7 @@ impl ShiftDbIndices for NodeId<UnlabeledParam> {
         Ok(registry.add_and_overwrite_id(UnlabeledParam {
             id: dummy_id(),
             span: param.span,
+            is_instance: param.is_instance,
             is_dashed: param.is_dashed,
             name_id: param.name_id,
             type_id: shifted_type_id,
@@ -407,13 +424,19 @@ impl ShiftDbIndices for NodeId<LabeledParam> {
     ) -> Result<Self, F::ShiftError> {
         let param = registry.get(self).clone();
         let shifted_type_id = param.type_id.try_shift_with_cutoff(f, cutoff, registry)?;
+        let shifted_default_id = param
+            .default_id
+            .map(|default_id| default_id.try_shift_with_cutoff(f, cutoff, registry))
+            .transpose()?;
         Ok(registry.add_and_overwrite_id(LabeledParam {
             id: dummy_id(),
             span: param.span,
+            is_instance: param.is_instance,
             label_id: param.label_id,
             is_dashed: param.is_dashed,
             name_id: param.name_id,
             type_id: shifted_type_id,
+            default_id: shifted_default_id,
         }))
     }
 }
@@ -431,6 +454,9 @@ impl ShiftDbIndices for NodeId<Match> {
         let shifted_matchee_id = match_
             .matchee_id
             .try_shift_with_cutoff(f, cutoff, registry)?;
+        let shifted_motive_id = match_
+            .motive_id
+            .try_shift_with_cutoff(f, cutoff, registry)?;
         let shifted_case_list_id = match_
             .case_list_id
             .try_shift_with_cutoff(f, cutoff, registry)?;
@@ -438,11 +464,41 @@ impl ShiftDbIndices for NodeId<Match> {
             id: dummy_id(),
             span: match_.span,
             matchee_id: shifted_matchee_id,
+            motive_id: shifted_motive_id,
             case_list_id: shifted_case_list_id,
         }))
     }
 }
 
+impl ShiftDbIndices for NodeId<MatchMotive> {
+    type Output = Self;
+
+    fn try_shift_with_cutoff<F: ShiftFn>(
+        self,
+        f: F,
+        cutoff: usize,
+        registry: &mut NodeRegistry,
+    ) -> Result<Self, F::ShiftError> {
+        let motive = registry.get(self).clone();
+        let arity = motive.index_name_list_id.len() + 1;
+        let shifted_matchee_type_name_id = motive
+            .matchee_type_name_id
+            .try_shift_with_cutoff(f, cutoff, registry)?;
+        let shifted_output_id =
+            motive
+                .output_id
+                .try_shift_with_cutoff(f, cutoff + arity, registry)?;
+        Ok(registry.add_and_overwrite_id(MatchMotive {
+            id: dummy_id(),
+            span: motive.span,
+            matchee_name_id: motive.matchee_name_id,
+            matchee_type_name_id: shifted_matchee_type_name_id,
+            index_name_list_id: motive.index_name_list_id,
+            output_id: shifted_output_id,
+        }))
+    }
+}
+
 impl ShiftDbIndices for NonEmptyListId<NodeId<MatchCase>> {
     type Output = Self;
 
@@ -555,6 +611,103 @@ impl ShiftDbIndices for NodeId<Check> {
     }
 }
 
+impl ShiftDbIndices for NodeId<Rewrite> {
+    type Output = Self;
+
+    fn try_shift_with_cutoff<F: ShiftFn>(
+        self,
+        f: F,
+        cutoff: usize,
+        registry: &mut NodeRegistry,
+    ) -> Result<Self, F::ShiftError> {
+        let rewrite = registry.get(self).clone();
+        let shifted_eq_type_name_id = rewrite
+            .eq_type_name_id
+            .try_shift_with_cutoff(f, cutoff, registry)?;
+        let shifted_eq_refl_name_id = rewrite
+            .eq_refl_name_id
+            .try_shift_with_cutoff(f, cutoff, registry)?;
+        let shifted_proof_id = rewrite
+            .proof_id
+            .try_shift_with_cutoff(f, cutoff, registry)?;
+        let shifted_body_id = rewrite.body_id.try_shift_with_cutoff(f, cutoff, registry)?;
+        Ok(registry.add_and_overwrite_id(Rewrite {
+            id: dummy_id(),
+            span: rewrite.span,
+            eq_type_name_id: shifted_eq_type_name_id,
+            eq_refl_name_id: shifted_eq_refl_name_id,
+            proof_id: shifted_proof_id,
+            body_id: shifted_body_id,
+        }))
+    }
+}
+
+impl ShiftDbIndices for NodeId<Projection> {
+    type Output = Self;
+
+    fn try_shift_with_cutoff<F: ShiftFn>(
+        self,
+        f: F,
+        cutoff: usize,
+        registry: &mut NodeRegistry,
+    ) -> Result<Self, F::ShiftError> {
+        let projection = registry.get(self).clone();
+        let shifted_record_id = projection
+            .record_id
+            .try_shift_with_cutoff(f, cutoff, registry)?;
+        Ok(registry.add_and_overwrite_id(Projection {
+            id: dummy_id(),
+            span: projection.span,
+            record_id: shifted_record_id,
+            field_name_id: projection.field_name_id,
+        }))
+    }
+}
+
+impl ShiftDbIndices for NodeId<Exists> {
+    type Output = Self;
+
+    fn try_shift_with_cutoff<F: ShiftFn>(
+        self,
+        f: F,
+        cutoff: usize,
+        registry: &mut NodeRegistry,
+    ) -> Result<Self, F::ShiftError> {
+        let exists = registry.get(self).clone();
+        let shifted_param_id = exists.param_id.try_shift_with_cutoff(f, cutoff, registry)?;
+        let shifted_output_id = exists
+            .output_id
+            .try_shift_with_cutoff(f, cutoff + 1, registry)?;
+        Ok(registry.add_and_overwrite_id(Exists {
+            id: dummy_id(),
+            span: exists.span,
+            param_id: shifted_param_id,
+            output_id: shifted_output_id,
+        }))
+    }
+}
+
+impl ShiftDbIndices for NodeId<Pair> {
+    type Output = Self;
+
+    fn try_shift_with_cutoff<F: ShiftFn>(
+        self,
+        f: F,
+        cutoff: usize,
+        registry: &mut NodeRegistry,
+    ) -> Result<Self, F::ShiftError> {
+        let pair = registry.get(self).clone();
+        let shifted_witness_id = pair.witness_id.try_shift_with_cutoff(f, cutoff, registry)?;
+        let shifted_proof_id = pair.proof_id.try_shift_with_cutoff(f, cutoff, registry)?;
+        Ok(registry.add_and_overwrite_id(Pair {
+            id: dummy_id(),
+            span: pair.span,
+            witness_id: shifted_witness_id,
+            proof_id: shifted_proof_id,
+        }))
+    }
+}
+
 impl ShiftDbIndices for NonEmptyListId<NodeId<CheckAssertion>> {
     type Output = Self;
 
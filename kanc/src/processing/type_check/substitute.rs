@@ -87,6 +87,18 @@ impl SubstituteWithoutRemovingSpans for ExpressionId {
             ExpressionId::Check(check_id) => {
                 check_id.subst_without_removing_spans(substitution, state)
             }
+            ExpressionId::Rewrite(rewrite_id) => {
+                rewrite_id.subst_without_removing_spans(substitution, state)
+            }
+            ExpressionId::Projection(projection_id) => {
+                projection_id.subst_without_removing_spans(substitution, state)
+            }
+            ExpressionId::Exists(exists_id) => {
+                exists_id.subst_without_removing_spans(substitution, state)
+            }
+            ExpressionId::Pair(pair_id) => {
+                pair_id.subst_without_removing_spans(substitution, state)
+            }
         }
     }
 }
@@ -352,6 +364,7 @@ impl SubstituteWithoutRemovingSpans for NodeId<UnlabeledParam> {
         state.registry.add_and_overwrite_id(UnlabeledParam {
             id: dummy_id(),
             span: None,
+            is_instance: param.is_instance,
             is_dashed: param.is_dashed,
             name_id: param.name_id,
             type_id: substituted_type_id,
@@ -390,13 +403,18 @@ impl SubstituteWithoutRemovingSpans for NodeId<LabeledParam> {
         let substituted_type_id = param
             .type_id
             .subst_without_removing_spans(substitution, state);
+        let substituted_default_id = param
+            .default_id
+            .map(|default_id| default_id.subst_without_removing_spans(substitution, state));
         state.registry.add_and_overwrite_id(LabeledParam {
             id: dummy_id(),
             span: None,
+            is_instance: param.is_instance,
             label_id: param.label_id,
             is_dashed: param.is_dashed,
             name_id: param.name_id,
             type_id: substituted_type_id,
+            default_id: substituted_default_id,
         })
     }
 }
@@ -419,6 +437,9 @@ impl SubstituteWithoutRemovingSpans for NodeId<Match> {
         let substituted_matchee_id = match_
             .matchee_id
             .subst_without_removing_spans(substitution, state);
+        let substituted_motive_id = match_
+            .motive_id
+            .subst_without_removing_spans(substitution, state);
         let substituted_case_list_id = match_
             .case_list_id
             .subst_without_removing_spans(substitution, state);
@@ -427,11 +448,38 @@ impl SubstituteWithoutRemovingSpans for NodeId<Match> {
             id: dummy_id(),
             span: None,
             matchee_id: substituted_matchee_id,
+            motive_id: substituted_motive_id,
             case_list_id: substituted_case_list_id,
         }))
     }
 }
 
+impl SubstituteWithoutRemovingSpans for NodeId<MatchMotive> {
+    type Output = NodeId<MatchMotive>;
+
+    fn subst_without_removing_spans(
+        self,
+        substitution: Substitution,
+        state: &mut ContextlessState,
+    ) -> Self::Output {
+        let motive = state.registry.get(self).clone();
+        // We don't substitute the matchee type name,
+        // since it must always remain the name of an ADT.
+        let substituted_output_id = motive.output_id.subst_without_removing_spans(
+            substitution.upshift(motive.index_name_list_id.len() + 1, state.registry),
+            state,
+        );
+        state.registry.add_and_overwrite_id(MatchMotive {
+            id: dummy_id(),
+            span: None,
+            matchee_name_id: motive.matchee_name_id,
+            matchee_type_name_id: motive.matchee_type_name_id,
+            index_name_list_id: motive.index_name_list_id,
+            output_id: substituted_output_id,
+        })
+    }
+}
+
 impl SubstituteWithoutRemovingSpans for NonEmptyListId<NodeId<MatchCase>> {
     type Output = Self;
 
@@ -555,6 +603,131 @@ impl SubstituteWithoutRemovingSpans for NodeId<Check> {
     }
 }
 
+impl SubstituteWithoutRemovingSpans for NodeId<Rewrite> {
+    type Output = ExpressionId;
+
+    fn subst_without_removing_spans(
+        self,
+        substitution: Substitution,
+        state: &mut ContextlessState,
+    ) -> Self::Output {
+        let top_level =
+            subst_if_equal_and_get_status(ExpressionId::Rewrite(self), substitution, state);
+        if let WasSyntacticNoOp(false) = top_level.1 {
+            return top_level.0;
+        }
+
+        let rewrite = state.registry.get(self).clone();
+        let substituted_proof_id = rewrite
+            .proof_id
+            .subst_without_removing_spans(substitution, state);
+        let substituted_body_id = rewrite
+            .body_id
+            .subst_without_removing_spans(substitution, state);
+
+        // The designated equality names always refer to the top-level
+        // `Eq` type and its `refl` variant, so we leave them untouched.
+        ExpressionId::Rewrite(state.registry.add_and_overwrite_id(Rewrite {
+            id: dummy_id(),
+            span: None,
+            eq_type_name_id: rewrite.eq_type_name_id,
+            eq_refl_name_id: rewrite.eq_refl_name_id,
+            proof_id: substituted_proof_id,
+            body_id: substituted_body_id,
+        }))
+    }
+}
+
+impl SubstituteWithoutRemovingSpans for NodeId<Projection> {
+    type Output = ExpressionId;
+
+    fn subst_without_removing_spans(
+        self,
+        substitution: Substitution,
+        state: &mut ContextlessState,
+    ) -> Self::Output {
+        let top_level =
+            subst_if_equal_and_get_status(ExpressionId::Projection(self), substitution, state);
+        if let WasSyntacticNoOp(false) = top_level.1 {
+            return top_level.0;
+        }
+
+        let projection = state.registry.get(self).clone();
+        let substituted_record_id = projection
+            .record_id
+            .subst_without_removing_spans(substitution, state);
+
+        ExpressionId::Projection(state.registry.add_and_overwrite_id(Projection {
+            id: dummy_id(),
+            span: None,
+            record_id: substituted_record_id,
+            field_name_id: projection.field_name_id,
+        }))
+    }
+}
+
+impl SubstituteWithoutRemovingSpans for NodeId<Exists> {
+    type Output = ExpressionId;
+
+    fn subst_without_removing_spans(
+        self,
+        substitution: Substitution,
+        state: &mut ContextlessState,
+    ) -> Self::Output {
+        let top_level =
+            subst_if_equal_and_get_status(ExpressionId::Exists(self), substitution, state);
+        if let WasSyntacticNoOp(false) = top_level.1 {
+            return top_level.0;
+        }
+
+        let exists = state.registry.get(self).clone();
+        let substituted_param_id = exists
+            .param_id
+            .subst_without_removing_spans(substitution, state);
+        let substituted_output_id = exists
+            .output_id
+            .subst_without_removing_spans(substitution.upshift(1, state.registry), state);
+
+        ExpressionId::Exists(state.registry.add_and_overwrite_id(Exists {
+            id: dummy_id(),
+            span: None,
+            param_id: substituted_param_id,
+            output_id: substituted_output_id,
+        }))
+    }
+}
+
+impl SubstituteWithoutRemovingSpans for NodeId<Pair> {
+    type Output = ExpressionId;
+
+    fn subst_without_removing_spans(
+        self,
+        substitution: Substitution,
+        state: &mut ContextlessState,
+    ) -> Self::Output {
+        let top_level =
+            subst_if_equal_and_get_status(ExpressionId::Pair(self), substitution, state);
+        if let WasSyntacticNoOp(false) = top_level.1 {
+            return top_level.0;
+        }
+
+        let pair = state.registry.get(self).clone();
+        let substituted_witness_id = pair
+            .witness_id
+            .subst_without_removing_spans(substitution, state);
+        let substituted_proof_id = pair
+            .proof_id
+            .subst_without_removing_spans(substitution, state);
+
+        ExpressionId::Pair(state.registry.add_and_overwrite_id(Pair {
+            id: dummy_id(),
+            span: None,
+            witness_id: substituted_witness_id,
+            proof_id: substituted_proof_id,
+        }))
+    }
+}
+
 impl SubstituteWithoutRemovingSpans for NonEmptyListId<NodeId<CheckAssertion>> {
     type Output = Self;
 
@@ -4,9 +4,13 @@ pub(in crate::processing::type_check) fn get_type_of_call_dirty(
     state: &mut State,
     call_id: NodeId<Call>,
 ) -> Result<NormalFormId, Tainted<TypeCheckError>> {
+    fill_in_omitted_args_dirty(state, call_id)?;
+
     if let Some(corrected) = correct_call_arg_order_dirty(state, call_id)? {
         // TODO: Emit warning about incorrect arg order.
-        return get_type_of_call_dirty(state, corrected);
+        let type_id = get_type_of_call_dirty(state, corrected)?;
+        copy_call_arg_erasure(state, corrected, call_id);
+        return Ok(type_id);
     }
 
     let call = state.registry.get(call_id).clone();
@@ -43,61 +47,27 @@ pub(in crate::processing::type_check) fn get_type_of_call_dirty(
     }
 
     let mut normalized_visited_arg_ids: Vec<NormalFormId> = Vec::with_capacity(arg_ids.len());
+    let mut substituted_param_type_ids: Vec<NormalFormId> = Vec::with_capacity(arg_ids.len());
 
     let (callee_type_param_name_ids, callee_type_param_type_ids) =
         get_names_and_types_of_params(state, callee_type.param_list_id);
     for (i, callee_type_param_type_id) in callee_type_param_type_ids.iter().copied().enumerate() {
-        let substituted_param_type_id = {
-            // This is safe because the param is the param of a normal
-            // form Forall node, which guarantees that its type is a
-            // normal form.
-            let unsubstituted = NormalFormId::unchecked_new(callee_type_param_type_id);
-            let substitutions: Vec<Substitution> = normalized_visited_arg_ids
-                .iter()
-                .copied()
-                .enumerate()
-                .map(|(j, normalized_arg_id)| {
-                    let db_index = DbIndex(i - j - 1);
-                    let param_name_id = callee_type_param_name_ids[j];
-                    Substitution {
-                        from: ExpressionId::Name(add_name_expression(
-                            state.registry,
-                            NonEmptyVec::singleton(param_name_id),
-                            db_index,
-                        )),
-                        to: normalized_arg_id.upshift(i, state.registry).raw(),
-                    }
-                })
-                .collect();
-            let substituted = unsubstituted
-                .raw()
-                .subst_all(&substitutions, &mut state.without_context())
-                .downshift(i, state.registry);
-            evaluate_well_typed_expression(state, substituted)
-        };
-
-        let arg_type_id =
-            get_type_of_expression_dirty(state, Some(substituted_param_type_id), arg_ids[i])?;
-
+        let substituted_param_type_id = get_substituted_param_type_id(
+            state,
+            callee_type_param_name_ids.as_ref(),
+            callee_type_param_type_id,
+            &normalized_visited_arg_ids,
+        );
+        verify_type_of_arg_dirty(state, arg_ids[i], substituted_param_type_id)?;
         normalized_visited_arg_ids.push(evaluate_well_typed_expression(state, arg_ids[i]));
-
-        let equality_status =
-            get_rewritten_term_equality_status(state, arg_type_id, substituted_param_type_id);
-
-        match equality_status {
-            RewrittenTermEqualityStatus::Equal => (),
-            RewrittenTermEqualityStatus::Exploded => {
-                return tainted_err(TypeCheckError::UnreachableExpression(arg_ids[i]));
-            }
-            RewrittenTermEqualityStatus::NotEqual => {
-                return tainted_err(TypeCheckError::TypeMismatch {
-                    expression_id: arg_ids[i],
-                    expected_type_id: substituted_param_type_id,
-                    actual_type_id: arg_type_id,
-                });
-            }
-        }
+        substituted_param_type_ids.push(substituted_param_type_id);
     }
+    record_call_arg_erasure(
+        state,
+        call_id,
+        callee_type.param_list_id,
+        &substituted_param_type_ids,
+    );
 
     let normalized_arg_ids = normalized_visited_arg_ids;
 
@@ -130,6 +100,286 @@ pub(in crate::processing::type_check) fn get_type_of_call_dirty(
     Ok(substituted_output_id)
 }
 
+/// If the call omits the args of some of its callee's instance params
+/// or defaulted params, this fills in those args and overwrites the call
+/// with a call that supplies every arg.
+/// Omitted instance args are resolved from the instances in scope,
+/// and omitted defaulted args are set to the param's default
+/// (with every earlier param replaced by its corresponding arg).
+///
+/// Args may be omitted in one of two ways:
+/// 1. If the args are unlabeled, every instance arg is omitted.
+/// 2. If the args are labeled, any instance arg or defaulted arg may be omitted.
+///
+/// If the call cannot be interpreted in either of these ways,
+/// it is left untouched, so that `get_type_of_call_dirty` can
+/// report the appropriate error.
+fn fill_in_omitted_args_dirty(
+    state: &mut State,
+    call_id: NodeId<Call>,
+) -> Result<(), Tainted<TypeCheckError>> {
+    let call = state.registry.get(call_id).clone();
+    let callee_type_id = get_type_of_expression_dirty(state, None, call.callee_id)?;
+    let ExpressionId::Forall(callee_type_id) = callee_type_id.raw() else {
+        return Ok(());
+    };
+    let callee_type = state.registry.get(callee_type_id).clone();
+    let Some(supplied_arg_indices) =
+        get_supplied_arg_indices(state, callee_type.param_list_id, call.arg_list_id)
+    else {
+        return Ok(());
+    };
+
+    let supplied_arg_ids: Vec<ExpressionId> = match call.arg_list_id {
+        NonEmptyCallArgListId::Unlabeled(arg_list_id) => {
+            state.registry.get_list(arg_list_id).to_vec()
+        }
+        NonEmptyCallArgListId::UniquelyLabeled(arg_list_id) => state
+            .registry
+            .get_list(arg_list_id)
+            .iter()
+            .map(|arg| arg.value_id())
+            .collect(),
+    };
+    let (param_name_ids, param_type_ids) =
+        get_names_and_types_of_params(state, callee_type.param_list_id);
+
+    let mut normalized_visited_arg_ids: Vec<NormalFormId> =
+        Vec::with_capacity(supplied_arg_indices.len());
+    let mut arg_ids: Vec<ExpressionId> = Vec::with_capacity(supplied_arg_indices.len());
+    for (i, param_type_id) in param_type_ids.iter().copied().enumerate() {
+        let substituted_param_type_id = get_substituted_param_type_id(
+            state,
+            param_name_ids.as_ref(),
+            param_type_id,
+            &normalized_visited_arg_ids,
+        );
+        let arg_id = match supplied_arg_indices[i] {
+            Some(arg_index) => {
+                let arg_id = supplied_arg_ids[arg_index];
+                verify_type_of_arg_dirty(state, arg_id, substituted_param_type_id)?;
+                arg_id
+            }
+            None => match get_param_default_id(state, callee_type.param_list_id, i) {
+                Some(default_id) => get_substituted_param_type_id(
+                    state,
+                    param_name_ids.as_ref(),
+                    default_id,
+                    &normalized_visited_arg_ids,
+                )
+                .raw(),
+                None => resolve_instance_dirty(state, call_id, substituted_param_type_id)?,
+            },
+        };
+        normalized_visited_arg_ids.push(evaluate_well_typed_expression(state, arg_id));
+        arg_ids.push(arg_id);
+    }
+
+    let arg_list_id = match (callee_type.param_list_id, call.arg_list_id) {
+        (_, NonEmptyCallArgListId::Unlabeled(_)) => {
+            NonEmptyCallArgListId::Unlabeled(state.registry.add_list(
+                NonEmptyVec::try_from(arg_ids).expect("callee should have at least one param"),
+            ))
+        }
+        (
+            NonEmptyParamListId::UniquelyLabeled(param_list_id),
+            NonEmptyCallArgListId::UniquelyLabeled(original_arg_list_id),
+        ) => {
+            let param_ids = state.registry.get_list(param_list_id).to_non_empty_vec();
+            let original_arg_ids = state
+                .registry
+                .get_list(original_arg_list_id)
+                .to_non_empty_vec();
+            let labeled_arg_ids =
+                param_ids.enumerate_into_mapped(|(i, param_id)| match supplied_arg_indices[i] {
+                    Some(arg_index) => original_arg_ids[arg_index],
+                    None => LabeledCallArgId::Explicit {
+                        label_id: state.registry.get(param_id).label_identifier_id(),
+                        value_id: arg_ids[i],
+                    },
+                });
+            NonEmptyCallArgListId::UniquelyLabeled(state.registry.add_list(labeled_arg_ids))
+        }
+        (NonEmptyParamListId::Unlabeled(_), NonEmptyCallArgListId::UniquelyLabeled(_)) => {
+            unreachable!("get_supplied_arg_indices should return None for labeledness mismatches")
+        }
+    };
+    state.registry.overwrite(
+        call_id,
+        Call {
+            id: dummy_id(),
+            span: call.span,
+            callee_id: call.callee_id,
+            arg_list_id,
+        },
+    );
+    Ok(())
+}
+
+/// For each param, returns the index of the corresponding arg,
+/// or `None` if the param is an instance param or defaulted param
+/// whose arg was omitted.
+///
+/// Returns `None` if no args were omitted,
+/// or if the call cannot be interpreted as omitting instance args.
+fn get_supplied_arg_indices(
+    state: &State,
+    param_list_id: NonEmptyParamListId,
+    arg_list_id: NonEmptyCallArgListId,
+) -> Option<Vec<Option<usize>>> {
+    match (param_list_id, arg_list_id) {
+        (
+            NonEmptyParamListId::Unlabeled(param_list_id),
+            NonEmptyCallArgListId::Unlabeled(arg_list_id),
+        ) => {
+            let param_ids = state.registry.get_list(param_list_id);
+            let instance_param_count = param_ids
+                .iter()
+                .filter(|param_id| state.registry.get(**param_id).is_instance)
+                .count();
+            if instance_param_count == 0
+                || arg_list_id.len.get() + instance_param_count != param_ids.len()
+            {
+                return None;
+            }
+            let mut next_arg_index = 0;
+            Some(
+                param_ids
+                    .iter()
+                    .map(|param_id| {
+                        if state.registry.get(*param_id).is_instance {
+                            None
+                        } else {
+                            next_arg_index += 1;
+                            Some(next_arg_index - 1)
+                        }
+                    })
+                    .collect(),
+            )
+        }
+        (
+            NonEmptyParamListId::UniquelyLabeled(param_list_id),
+            NonEmptyCallArgListId::UniquelyLabeled(arg_list_id),
+        ) => {
+            let param_ids = state.registry.get_list(param_list_id);
+            let arg_ids = state.registry.get_list(arg_list_id);
+            let param_label_names: Vec<&IdentifierName> = param_ids
+                .iter()
+                .map(|param_id| {
+                    let label_id = state.registry.get(*param_id).label_identifier_id();
+                    &state.registry.get(label_id).name
+                })
+                .collect();
+            let has_extraneous_arg = arg_ids.iter().any(|arg_id| {
+                let arg_label_name = &state.registry.get(arg_id.label_id()).name;
+                !param_label_names.contains(&arg_label_name)
+            });
+            if has_extraneous_arg {
+                return None;
+            }
+
+            let mut is_any_arg_omitted = false;
+            let mut supplied_arg_indices = Vec::with_capacity(param_ids.len());
+            for (param_id, param_label_name) in param_ids.iter().zip(param_label_names) {
+                let arg_index = arg_ids.iter().position(|arg_id| {
+                    &state.registry.get(arg_id.label_id()).name == param_label_name
+                });
+                if arg_index.is_none() {
+                    let param = state.registry.get(*param_id);
+                    if !param.is_instance && param.default_id.is_none() {
+                        return None;
+                    }
+                    is_any_arg_omitted = true;
+                }
+                supplied_arg_indices.push(arg_index);
+            }
+            if is_any_arg_omitted {
+                Some(supplied_arg_indices)
+            } else {
+                None
+            }
+        }
+        _ => None,
+    }
+}
+
+fn get_param_default_id(
+    state: &State,
+    param_list_id: NonEmptyParamListId,
+    param_index: usize,
+) -> Option<ExpressionId> {
+    match param_list_id {
+        NonEmptyParamListId::Unlabeled(_) => None,
+        NonEmptyParamListId::UniquelyLabeled(param_list_id) => {
+            let param_id = state.registry.get_list(param_list_id)[param_index];
+            state.registry.get(param_id).default_id
+        }
+    }
+}
+
+/// Returns the type of the param at index `normalized_visited_arg_ids.len()`,
+/// with every earlier param replaced by its corresponding arg.
+///
+/// This also works for the param's default (if any),
+/// since the default is in the same scope as the type.
+pub(in crate::processing::type_check) fn get_substituted_param_type_id(
+    state: &mut State,
+    param_name_ids: &[NodeId<Identifier>],
+    unsubstituted_param_type_id: ExpressionId,
+    normalized_visited_arg_ids: &[NormalFormId],
+) -> NormalFormId {
+    let i = normalized_visited_arg_ids.len();
+    // This is safe because the param is the param of a normal
+    // form Forall node, which guarantees that its type is a
+    // normal form.
+    let unsubstituted = NormalFormId::unchecked_new(unsubstituted_param_type_id);
+    let substitutions: Vec<Substitution> = normalized_visited_arg_ids
+        .iter()
+        .copied()
+        .enumerate()
+        .map(|(j, normalized_arg_id)| {
+            let db_index = DbIndex(i - j - 1);
+            let param_name_id = param_name_ids[j];
+            Substitution {
+                from: ExpressionId::Name(add_name_expression(
+                    state.registry,
+                    NonEmptyVec::singleton(param_name_id),
+                    db_index,
+                )),
+                to: normalized_arg_id.upshift(i, state.registry).raw(),
+            }
+        })
+        .collect();
+    let substituted = unsubstituted
+        .raw()
+        .subst_all(&substitutions, &mut state.without_context())
+        .downshift(i, state.registry);
+    evaluate_well_typed_expression(state, substituted)
+}
+
+pub(in crate::processing::type_check) fn verify_type_of_arg_dirty(
+    state: &mut State,
+    arg_id: ExpressionId,
+    substituted_param_type_id: NormalFormId,
+) -> Result<(), Tainted<TypeCheckError>> {
+    let arg_type_id = get_type_of_expression_dirty(state, Some(substituted_param_type_id), arg_id)?;
+
+    let equality_status =
+        get_rewritten_subtyping_status(state, arg_type_id, substituted_param_type_id);
+
+    match equality_status {
+        RewrittenTermEqualityStatus::Equal => Ok(()),
+        RewrittenTermEqualityStatus::Exploded => {
+            tainted_err(TypeCheckError::UnreachableExpression(arg_id))
+        }
+        RewrittenTermEqualityStatus::NotEqual => tainted_err(TypeCheckError::TypeMismatch {
+            expression_id: arg_id,
+            expected_type_id: substituted_param_type_id,
+            actual_type_id: arg_type_id,
+        }),
+    }
+}
+
 /// If the params and args are both labeled AND the label order is correct,
 /// this returns `Ok(None)`.
 /// Otherwise, it tries to return `Ok(Some(new_call_id))` where `new_call_id`
@@ -247,6 +497,10 @@ fn verify_every_param_has_a_corresponding_arg(
         .iter()
         .copied()
         .filter_map(|param_id| {
+            // Defaulted params never need a corresponding arg.
+            if state.registry.get(param_id).default_id.is_some() {
+                return None;
+            }
             let param_label_name_id = state.registry.get(param_id).label_identifier_id();
             let param_label_name: &IdentifierName = &state.registry.get(param_label_name_id).name;
 
@@ -42,7 +42,10 @@ fn get_check_assertion_warnings(
     assertion_id: NodeId<CheckAssertion>,
 ) -> Vec<TypeCheckWarning> {
     let assertion = state.registry.get(assertion_id).clone();
-    match assertion.kind {
+    let is_traced = state
+        .reduction_tracker
+        .begin_check_assertion_trace_if_selected(assertion.id, assertion.span);
+    let warnings = match assertion.kind {
         CheckAssertionKind::Type => {
             get_type_assertion_warnings(state, coercion_target_id, assertion)
                 .into_iter()
@@ -52,7 +55,11 @@ fn get_check_assertion_warnings(
         CheckAssertionKind::NormalForm => {
             get_normal_form_assertion_warnings(state, coercion_target_id, assertion)
         }
+    };
+    if is_traced {
+        state.reduction_tracker.end_check_assertion_trace();
     }
+    warnings
 }
 
 fn get_type_assertion_warnings(
@@ -101,7 +108,7 @@ fn get_non_goal_type_assertion_warnings(
             ),
         ) => {
             let normalized_right_expression_id =
-                evaluate_well_typed_expression(state, right_expression_id);
+                evaluate_well_typed_comparee(state, right_expression_id);
             match apply_substitutions_from_substitution_context(
                 state,
                 ((left_type_id,), (normalized_right_expression_id,)),
@@ -115,8 +122,8 @@ fn get_non_goal_type_assertion_warnings(
                     .is_equal_or_exploded()
                     {
                         vec![]
-                    } else if is_term_equal_to_type1(state, rewritten_left_type_id) {
-                        vec![TypeAssertionWarning::LhsTypeIsType1(assertion.id)]
+                    } else if is_term_equal_to_limit_universe(state, rewritten_left_type_id) {
+                        vec![TypeAssertionWarning::LhsTypeIsLimitUniverse(assertion.id)]
                     } else {
                         vec![TypeAssertionWarning::TypesDoNotMatch {
                             left_id: left_expression_id,
@@ -143,8 +150,8 @@ fn get_non_goal_type_assertion_warnings(
                     Ok(rewritten) => rewritten,
                     Err(Exploded) => (left_type_id,),
                 };
-            if is_term_equal_to_type1(state, rewritten_left_type_id) {
-                vec![TypeAssertionWarning::LhsTypeIsType1(assertion.id)]
+            if is_term_equal_to_limit_universe(state, rewritten_left_type_id) {
+                vec![TypeAssertionWarning::LhsTypeIsLimitUniverse(assertion.id)]
             } else {
                 vec![TypeAssertionWarning::TypesDoNotMatch {
                     left_id: left_expression_id,
@@ -231,7 +238,7 @@ fn get_goal_normal_form_assertion_warnings_given_goal_exists(
             _right_type_id,
         ) => {
             let normalized_right_expression_id =
-                evaluate_well_typed_expression(state, right_expression_id);
+                evaluate_well_typed_comparee(state, right_expression_id);
             match apply_substitutions_from_substitution_context(
                 state,
                 ((goal_id,), (normalized_right_expression_id,)),
@@ -309,9 +316,9 @@ fn get_non_goal_normal_form_assertion_warnings(
             ),
         ) => {
             let normalized_left_expression_id =
-                evaluate_well_typed_expression(state, left_expression_id);
+                evaluate_well_typed_comparee(state, left_expression_id);
             let normalized_right_expression_id =
-                evaluate_well_typed_expression(state, right_expression_id);
+                evaluate_well_typed_comparee(state, right_expression_id);
             match apply_substitutions_from_substitution_context(
                 state,
                 (
@@ -350,7 +357,7 @@ fn get_non_goal_normal_form_assertion_warnings(
             QuestionMarkOrPossiblyInvalidExpressionTypeCorrectness::QuestionMark,
         ) => {
             let normalized_left_expression_id =
-                evaluate_well_typed_expression(state, left_expression_id);
+                evaluate_well_typed_comparee(state, left_expression_id);
             let (rewritten_left_type_id,) = match apply_substitutions_from_substitution_context(
                 state,
                 (normalized_left_expression_id,),
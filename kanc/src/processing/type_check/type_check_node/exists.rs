@@ -0,0 +1,282 @@
+use super::*;
+
+pub(in crate::processing::type_check) fn get_type_of_exists_dirty(
+    state: &mut State,
+    exists_id: NodeId<Exists>,
+) -> Result<NormalFormId, Tainted<TypeCheckError>> {
+    let exists = state.registry.get(exists_id).clone();
+    type_check_unlabeled_param_dirty(state, exists.param_id)??;
+    let param_universe_level = get_max_param_universe_level_dirty(state, 1)?;
+
+    let output_type_id = get_type_of_expression_dirty(state, None, exists.output_id)?;
+    let Some(output_universe_level) = get_universe_level(state, output_type_id) else {
+        return tainted_err(TypeCheckError::ExpectedTermOfTypeUniverse {
+            expression_id: exists.output_id,
+            non_universe_type_id: output_type_id,
+        });
+    };
+
+    state.context.pop_n(1);
+
+    // Like a forall, an exists lives in the smallest universe
+    // that contains both its param type and its output type.
+    Ok(universe_expression(
+        state,
+        param_universe_level.max(output_universe_level),
+    ))
+}
+
+pub(in crate::processing::type_check) fn get_type_of_pair_dirty(
+    state: &mut State,
+    coercion_target_id: Option<NormalFormId>,
+    pair_id: NodeId<Pair>,
+) -> Result<NormalFormId, Tainted<TypeCheckError>> {
+    let pair = state.registry.get(pair_id).clone();
+
+    let expected_exists_id = match coercion_target_id.map(NormalFormId::raw) {
+        Some(ExpressionId::Exists(exists_id)) => exists_id,
+        _ => return get_type_of_pair_without_expected_exists_dirty(state, pair),
+    };
+    let expected_exists = state.registry.get(expected_exists_id).clone();
+
+    // This is safe because the param is the param of a normal
+    // form Exists node, which guarantees that its type is a
+    // normal form.
+    let witness_type_id =
+        NormalFormId::unchecked_new(state.registry.get(expected_exists.param_id).type_id);
+    verify_type_of_pair_component_dirty(state, pair.witness_id, witness_type_id)?;
+
+    let proof_type_id = {
+        let normalized_witness_id = evaluate_well_typed_expression(state, pair.witness_id);
+        let param_name_id = state.registry.get(expected_exists.param_id).name_id;
+        let substitution = Substitution {
+            from: ExpressionId::Name(add_name_expression(
+                state.registry,
+                NonEmptyVec::singleton(param_name_id),
+                DbIndex(0),
+            )),
+            to: normalized_witness_id.upshift(1, state.registry).raw(),
+        };
+        let substituted = expected_exists
+            .output_id
+            .subst(substitution, &mut state.without_context())
+            .downshift(1, state.registry);
+        evaluate_well_typed_expression(state, substituted)
+    };
+    verify_type_of_pair_component_dirty(state, pair.proof_id, proof_type_id)?;
+
+    Ok(coercion_target_id.expect("coercion_target_id should be Some if it is an Exists"))
+}
+
+fn verify_type_of_pair_component_dirty(
+    state: &mut State,
+    component_id: ExpressionId,
+    expected_type_id: NormalFormId,
+) -> Result<(), Tainted<TypeCheckError>> {
+    let component_type_id =
+        get_type_of_expression_dirty(state, Some(expected_type_id), component_id)?;
+    match get_rewritten_subtyping_status(state, component_type_id, expected_type_id) {
+        RewrittenTermEqualityStatus::Equal => Ok(()),
+        RewrittenTermEqualityStatus::Exploded => {
+            tainted_err(TypeCheckError::UnreachableExpression(component_id))
+        }
+        RewrittenTermEqualityStatus::NotEqual => tainted_err(TypeCheckError::TypeMismatch {
+            expression_id: component_id,
+            expected_type_id,
+            actual_type_id: component_type_id,
+        }),
+    }
+}
+
+/// If we don't know which `exists` type the pair should have,
+/// we infer the non-dependent type `exists(_: W) { P }`,
+/// where `W` and `P` are the types of the witness and proof,
+/// respectively.
+fn get_type_of_pair_without_expected_exists_dirty(
+    state: &mut State,
+    pair: Pair,
+) -> Result<NormalFormId, Tainted<TypeCheckError>> {
+    let witness_type_id = get_type_of_expression_dirty(state, None, pair.witness_id)?;
+    let proof_type_id = get_type_of_expression_dirty(state, None, pair.proof_id)?;
+
+    let underscore_id = state.registry.add_and_overwrite_id(Identifier {
+        id: dummy_id(),
+        span: None,
+        name: IdentifierName::Reserved(ReservedIdentifierName::Underscore),
+    });
+    let param_id = state.registry.add_and_overwrite_id(UnlabeledParam {
+        id: dummy_id(),
+        span: None,
+        is_instance: false,
+        is_dashed: false,
+        name_id: underscore_id,
+        type_id: witness_type_id.raw(),
+    });
+    let output_id = proof_type_id.upshift(1, state.registry).raw();
+    Ok(NormalFormId::unchecked_new(ExpressionId::Exists(
+        state
+            .registry
+            .add_and_overwrite_id(Exists {
+                id: dummy_id(),
+                span: None,
+                param_id,
+                output_id,
+            })
+            .without_spans(state.registry),
+    )))
+}
+
+/// Type checks a match whose matchee has an `exists` type.
+/// Such a match must have exactly one case, of the form
+/// `(witness, proof) => output`.
+pub(in crate::processing::type_check) fn get_type_of_exists_match_dirty(
+    state: &mut State,
+    coercion_target_id: Option<NormalFormId>,
+    match_id: NodeId<Match>,
+    matchee_type_id: NodeId<Exists>,
+) -> Result<NormalFormId, Tainted<TypeCheckError>> {
+    let match_ = state.registry.get(match_id).clone();
+    if let Some(motive_id) = match_.motive_id {
+        return tainted_err(TypeCheckError::ExistsMatchHasMotive { motive_id });
+    }
+
+    let Some(PairCase {
+        case_id,
+        witness_name_id,
+        proof_name_id,
+        output_id: case_output_id,
+    }) = get_single_pair_case(state, match_.case_list_id)
+    else {
+        return tainted_err(TypeCheckError::IllegalExistsMatchCases { match_id });
+    };
+
+    let normalized_matchee_id = evaluate_well_typed_expression(state, match_.matchee_id);
+    let matchee_type = state.registry.get(matchee_type_id).clone();
+
+    // This is safe because the param is the param of a normal
+    // form Exists node, which guarantees that its type is a
+    // normal form.
+    let witness_type_id =
+        NormalFormId::unchecked_new(state.registry.get(matchee_type.param_id).type_id);
+    state.context.push(ContextEntry {
+        type_id: witness_type_id,
+        definition: ContextEntryDefinition::Uninterpreted,
+    })?;
+    state.context.push(ContextEntry {
+        type_id: NormalFormId::unchecked_new(matchee_type.output_id),
+        definition: ContextEntryDefinition::Uninterpreted,
+    })?;
+
+    const CASE_ARITY: usize = 2;
+    let parameterized_matchee_id = {
+        let witness_id = ExpressionId::Name(add_name_expression(
+            state.registry,
+            NonEmptyVec::singleton(witness_name_id),
+            DbIndex(1),
+        ));
+        let proof_id = ExpressionId::Name(add_name_expression(
+            state.registry,
+            NonEmptyVec::singleton(proof_name_id),
+            DbIndex(0),
+        ));
+        NormalFormId::unchecked_new(ExpressionId::Pair(
+            state
+                .registry
+                .add_and_overwrite_id(Pair {
+                    id: dummy_id(),
+                    span: None,
+                    witness_id,
+                    proof_id,
+                })
+                .without_spans(state.registry),
+        ))
+    };
+
+    let original_coercion_target_id = coercion_target_id;
+    let coercion_target_id =
+        coercion_target_id.map(|target_id| target_id.upshift(CASE_ARITY, state.registry));
+    let normalized_matchee_id = normalized_matchee_id.upshift(CASE_ARITY, state.registry);
+
+    state.substitution_context.push(SubstitutionContextEntry {
+        context_len: state.context.len(),
+        unadjusted_substitutions: vec![DynamicSubstitution(
+            normalized_matchee_id,
+            parameterized_matchee_id,
+        )],
+    });
+
+    let output_type_id = get_type_of_expression_dirty(state, coercion_target_id, case_output_id)?;
+
+    if let Some(coercion_target_id) = coercion_target_id {
+        let equality_status =
+            get_rewritten_subtyping_status(state, output_type_id, coercion_target_id);
+
+        state.context.pop_n(CASE_ARITY);
+        state.substitution_context.pop();
+
+        return match equality_status {
+            RewrittenTermEqualityStatus::Equal => Ok(original_coercion_target_id
+                .expect("original_coercion_target_id must be Some if coercion_target_id is Some")),
+            RewrittenTermEqualityStatus::Exploded => {
+                tainted_err(TypeCheckError::UnreachableExpression(case_output_id))
+            }
+            RewrittenTermEqualityStatus::NotEqual => tainted_err(TypeCheckError::TypeMismatch {
+                expression_id: case_output_id,
+                actual_type_id: output_type_id,
+                expected_type_id: coercion_target_id,
+            }),
+        };
+    }
+
+    state.context.pop_n(CASE_ARITY);
+    state.substitution_context.pop();
+
+    match output_type_id.try_downshift(CASE_ARITY, state.registry) {
+        Ok(output_type_id) => Ok(output_type_id),
+        Err(_) => tainted_err(TypeCheckError::AmbiguousMatchCaseOutputType {
+            case_id,
+            non_shifted_output_type_id: output_type_id,
+        }),
+    }
+}
+
+struct PairCase {
+    case_id: NodeId<MatchCase>,
+    witness_name_id: NodeId<Identifier>,
+    proof_name_id: NodeId<Identifier>,
+    output_id: ExpressionId,
+}
+
+/// If the match has exactly one case, and that case is
+/// a (non-impossible) pair case with two unlabeled params,
+/// this returns that case.
+fn get_single_pair_case(
+    state: &State,
+    case_list_id: Option<NonEmptyListId<NodeId<MatchCase>>>,
+) -> Option<PairCase> {
+    let case_ids = state.registry.get_possibly_empty_list(case_list_id);
+    let [case_id] = case_ids else {
+        return None;
+    };
+    let case = state.registry.get(*case_id);
+    let variant_name = &state.registry.get(case.variant_name_id).name;
+    if variant_name.src_str() != PAIR_VARIANT_NAME {
+        return None;
+    }
+    let Some(NonEmptyMatchCaseParamListId::Unlabeled(param_list_id)) = case.param_list_id else {
+        return None;
+    };
+    let param_name_ids = state.registry.get_list(param_list_id).to_vec();
+    let [witness_name_id, proof_name_id] = param_name_ids[..] else {
+        return None;
+    };
+    let MatchCaseOutputId::Some(output_id) = case.output_id else {
+        return None;
+    };
+    Some(PairCase {
+        case_id: *case_id,
+        witness_name_id,
+        proof_name_id,
+        output_id,
+    })
+}
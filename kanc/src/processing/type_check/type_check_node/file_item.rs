@@ -4,6 +4,10 @@ pub(super) fn type_check_file_item_dirty(
     state: &mut State,
     item: FileItemNodeId,
 ) -> Result<PushWarning, Tainted<TypeCheckError>> {
+    state.reduction_tracker.set_checked_let_id(match item {
+        FileItemNodeId::Type(_) => None,
+        FileItemNodeId::Let(let_statement) => Some(let_statement),
+    });
     match item {
         FileItemNodeId::Type(type_statement) => {
             type_check_type_statement_dirty(state, type_statement)
@@ -19,15 +23,40 @@ pub(super) fn type_check_type_statement_dirty(
     let type_statement = state.registry.get(type_statement_id).clone();
     state.required_transparency_for_substitution = Some(Transparency(type_statement.visibility.0));
 
-    type_check_type_constructor_dirty(state, type_statement_id)??;
-
     let variant_ids = state
         .registry
         .get_possibly_empty_list(type_statement.variant_list_id)
         .to_vec();
-    for variant_id in variant_ids {
-        type_check_type_variant_dirty(state, variant_id, type_statement.visibility)??;
+
+    // A type must live in a universe at least as large as
+    // the universes of its variants' fields
+    // (see `get_variant_field_universe_level_dirty`).
+    // So, we start by assuming the type lives in `Type`,
+    // and if a field turns out to live in a larger universe,
+    // we check the type again under that universe
+    // (since the variants' fields may mention the type itself).
+    // The level only ever increases, and it is bounded by the
+    // limit universe level, so this terminates.
+    let mut universe_level = 0;
+    loop {
+        type_check_type_constructor_dirty(state, type_statement_id, universe_level)??;
+        record_type_statement_if_designated_erased_type(state, type_statement_id)?;
+
+        let mut max_field_universe_level = 0;
+        for &variant_id in &variant_ids {
+            let field_universe_level =
+                type_check_type_variant_dirty(state, variant_id, type_statement.visibility)??;
+            max_field_universe_level = max_field_universe_level.max(field_universe_level);
+        }
+
+        if max_field_universe_level <= universe_level {
+            break;
+        }
+        state.context.pop_n(variant_ids.len() + 1);
+        universe_level = max_field_universe_level;
     }
+    record_type_statement_if_designated_by_bigints(state, type_statement_id)?;
+    record_type_statement_variants_for_precomputation(state, type_statement_id);
 
     Ok(with_push_warning(()))
 }
@@ -35,6 +64,7 @@ pub(super) fn type_check_type_statement_dirty(
 pub(super) fn type_check_type_constructor_dirty(
     state: &mut State,
     type_statement_id: NodeId<TypeStatement>,
+    universe_level: usize,
 ) -> Result<PushWarning, Tainted<TypeCheckError>> {
     let type_statement = state.registry.get(type_statement_id).clone();
     let arity = type_statement.param_list_id.len();
@@ -47,7 +77,7 @@ pub(super) fn type_check_type_constructor_dirty(
             id: dummy_id(),
             span: None,
             param_list_id: normalized_param_list_id,
-            output_id: type0_expression(state).raw(),
+            output_id: universe_expression(state, universe_level).raw(),
         }
         .into_id(state.registry)
         .without_spans(state.registry),
@@ -69,6 +99,8 @@ pub(super) fn type_check_type_constructor_dirty(
         definition: ContextEntryDefinition::Adt {
             variant_name_list_id,
             visibility: type_statement.visibility,
+            is_struct: type_statement.is_struct,
+            is_trait: type_statement.is_trait,
         },
     }))
 }
@@ -79,18 +111,26 @@ pub(in crate::processing::type_check) fn type_check_unlabeled_param_dirty(
 ) -> Result<PushWarning, Tainted<TypeCheckError>> {
     let param = state.registry.get(param_id).clone();
     let param_type_type_id = get_type_of_expression_dirty(state, None, param.type_id)?;
-    if !is_term_equal_to_type0_or_type1(state, param_type_type_id) {
-        return tainted_err(TypeCheckError::ExpectedTermOfTypeType0OrType1 {
+    if get_universe_level(state, param_type_type_id).is_none() {
+        return tainted_err(TypeCheckError::ExpectedTermOfTypeUniverse {
             expression_id: param.type_id,
-            non_type0_or_type1_type_id: param_type_type_id,
+            non_universe_type_id: param_type_type_id,
         });
     }
 
     let normalized_type_id = evaluate_well_typed_expression(state, param.type_id);
-    Ok(state.context.push(ContextEntry {
+    if param.is_instance {
+        verify_instance_type_is_trait(state, param.type_id, normalized_type_id)?;
+    }
+    record_unlabeled_param_erasure(state, param_id, normalized_type_id);
+    let push_warning = state.context.push(ContextEntry {
         type_id: normalized_type_id,
         definition: ContextEntryDefinition::Uninterpreted,
-    }))
+    });
+    if param.is_instance {
+        state.context.mark_last_as_instance(param.name_id);
+    }
+    Ok(push_warning)
 }
 
 pub(in crate::processing::type_check) fn type_check_labeled_param_dirty(
@@ -99,31 +139,47 @@ pub(in crate::processing::type_check) fn type_check_labeled_param_dirty(
 ) -> Result<PushWarning, Tainted<TypeCheckError>> {
     let param = state.registry.get(param_id).clone();
     let param_type_type_id = get_type_of_expression_dirty(state, None, param.type_id)?;
-    if !is_term_equal_to_type0_or_type1(state, param_type_type_id) {
-        return tainted_err(TypeCheckError::ExpectedTermOfTypeType0OrType1 {
+    if get_universe_level(state, param_type_type_id).is_none() {
+        return tainted_err(TypeCheckError::ExpectedTermOfTypeUniverse {
             expression_id: param.type_id,
-            non_type0_or_type1_type_id: param_type_type_id,
+            non_universe_type_id: param_type_type_id,
         });
     }
 
     let normalized_type_id = evaluate_well_typed_expression(state, param.type_id);
-    Ok(state.context.push(ContextEntry {
+    if param.is_instance {
+        verify_instance_type_is_trait(state, param.type_id, normalized_type_id)?;
+    }
+    if let Some(default_id) = param.default_id {
+        verify_type_of_arg_dirty(state, default_id, normalized_type_id)?;
+    }
+    record_labeled_param_erasure(state, param_id, normalized_type_id);
+    let push_warning = state.context.push(ContextEntry {
         type_id: normalized_type_id,
         definition: ContextEntryDefinition::Uninterpreted,
-    }))
+    });
+    if param.is_instance {
+        state.context.mark_last_as_instance(param.name_id);
+    }
+    Ok(push_warning)
 }
 
+/// Returns the level of the largest universe that
+/// the variant's fields live in
+/// (see `get_variant_field_universe_level_dirty`).
 pub(super) fn type_check_type_variant_dirty(
     state: &mut State,
     variant_id: NodeId<Variant>,
     type_statement_visibility: Visibility,
-) -> Result<PushWarning, Tainted<TypeCheckError>> {
+) -> Result<WithPushWarning<usize>, Tainted<TypeCheckError>> {
     let variant = state.registry.get(variant_id).clone();
     let arity = variant.param_list_id.len();
     let normalized_param_list_id =
         normalize_optional_params_and_leave_params_in_context_dirty(state, variant.param_list_id)??;
     type_check_expression_dirty(state, None, variant.return_type_id)?;
     let return_type_id = evaluate_well_typed_expression(state, variant.return_type_id);
+    let field_universe_level =
+        get_variant_field_universe_level_dirty(state, variant_id, return_type_id)?;
     let type_id = NormalFormId::unchecked_new(
         PossiblyNullaryForall {
             id: dummy_id(),
@@ -135,13 +191,70 @@ pub(super) fn type_check_type_variant_dirty(
         .without_spans(state.registry),
     );
     state.context.pop_n(arity);
-    Ok(state.context.push(ContextEntry {
-        type_id,
-        definition: ContextEntryDefinition::Variant {
-            name_id: variant.name_id,
-            visibility: type_statement_visibility,
-        },
-    }))
+    Ok(state
+        .context
+        .push(ContextEntry {
+            type_id,
+            definition: ContextEntryDefinition::Variant {
+                name_id: variant.name_id,
+                visibility: type_statement_visibility,
+            },
+        })
+        .map(|()| field_universe_level))
+}
+
+/// Returns the level of the largest universe that the types
+/// of the variant's fields live in (or `0` if it has no fields).
+/// For example, the field `T: Type1` lives in `Type2`.
+///
+/// A field that is passed directly to the variant's return type
+/// (e.g., `T` in `refl(T: Type, t: T): Eq(T, t, t)`) is ignored,
+/// since it is already determined by its value's type.
+///
+/// This function must be called while the variant's params
+/// are in the context.
+fn get_variant_field_universe_level_dirty(
+    state: &mut State,
+    variant_id: NodeId<Variant>,
+    normalized_return_type_id: NormalFormId,
+) -> Result<usize, Tainted<TypeCheckError>> {
+    let Some(param_list_id) = state.registry.get(variant_id).param_list_id else {
+        return Ok(0);
+    };
+    let arity = param_list_id.len();
+    let return_type_arg_ids = try_as_normal_form_adt_expression(state, normalized_return_type_id)
+        .and_then(|adt| adt.arg_list_id)
+        .map(|arg_list_id| match arg_list_id {
+            NonEmptyCallArgListId::Unlabeled(id) => state.registry.get_list(id).to_vec(),
+            NonEmptyCallArgListId::UniquelyLabeled(id) => state
+                .registry
+                .get_list(id)
+                .iter()
+                .map(|arg_id| arg_id.value_id())
+                .collect(),
+        })
+        .unwrap_or_default();
+
+    let mut max_level = 0;
+    for field_index in 0..arity {
+        let field_dbi = DbIndex(arity - 1 - field_index);
+        let is_passed_to_return_type = return_type_arg_ids.iter().any(|&arg_id| {
+            matches!(
+                arg_id,
+                ExpressionId::Name(name_id) if state.registry.get(name_id).db_index == field_dbi
+            )
+        });
+        if is_passed_to_return_type {
+            continue;
+        }
+
+        let field_type_id = state.context.get_type(field_dbi, state.registry);
+        let field_type_type_id = get_type_of_expression_dirty(state, None, field_type_id.raw())?;
+        if let Some(level) = get_universe_level(state, field_type_type_id) {
+            max_level = max_level.max(level);
+        }
+    }
+    Ok(max_level)
 }
 
 pub(super) fn type_check_let_statement_dirty(
@@ -164,13 +277,25 @@ pub(super) fn type_check_let_statement_dirty(
         });
     }
 
+    if let_statement.is_instance {
+        verify_instance_type_is_trait(state, let_statement.value_id, type_id)?;
+    }
+
+    record_let_statement_if_designated_bigint_function(state, let_statement_id, type_id)?;
+
     let normalized_value_id = evaluate_well_typed_expression(state, let_statement.value_id);
-    Ok(state.context.push(ContextEntry {
+    precompute_let_statement_if_possible(state, let_statement_id, type_id);
+    let push_warning = state.context.push(ContextEntry {
         type_id,
         definition: ContextEntryDefinition::Alias {
             value_id: normalized_value_id,
             visibility: let_statement.visibility,
             transparency: let_statement.transparency,
+            let_id: let_statement_id,
         },
-    }))
+    });
+    if let_statement.is_instance {
+        state.context.mark_last_as_instance(let_statement.name_id);
+    }
+    Ok(push_warning)
 }
@@ -5,10 +5,37 @@ pub fn type_check_file_items(
     registry: &mut NodeRegistry,
     file_item_list_id: TypePositivityValidated<Option<NonEmptyListId<FileItemNodeId>>>,
 ) -> Result<Vec<TypeCheckWarning>, TypeCheckError> {
+    type_check_file_items_with_options(
+        file_tree,
+        registry,
+        file_item_list_id,
+        TypeCheckOptions::default(),
+    )
+    .map(|output| output.warnings)
+}
+
+pub fn type_check_file_items_with_options(
+    file_tree: &FileTree,
+    registry: &mut NodeRegistry,
+    file_item_list_id: TypePositivityValidated<Option<NonEmptyListId<FileItemNodeId>>>,
+    options: TypeCheckOptions,
+) -> Result<TypeCheckOutput, TypeCheckError> {
     let mut context = Context::with_builtins(registry);
     let mut substitution_context = SubstitutionContext::empty();
     let mut equality_checker = NodeEqualityChecker::new();
     let mut warnings = vec![];
+    let mut normal_form_cache_stats = NormalFormCacheStats::default();
+    let mut reduction_tracker =
+        ReductionTracker::new(options.max_reduction_steps, options.traced_check_span);
+    let mut erasure = ErasureAnalysis::default();
+    let mut designated_erased_types = DesignatedErasedTypes::new(options.erased_types);
+    let mut bigint = BigIntAnalysis::default();
+    let mut designated_bigints = DesignatedBigInts::new(
+        options.bigint_types,
+        options.bigint_functions,
+        options.designated_bool,
+    );
+    let mut precomputation = Precomputation::new(options.precompute_lets);
     let mut state = State {
         file_tree: &file_tree,
         substitution_context: &mut substitution_context,
@@ -16,11 +43,40 @@ pub fn type_check_file_items(
         equality_checker: &mut equality_checker,
         warnings: &mut warnings,
         required_transparency_for_substitution: None,
+        evaluator: options.evaluator,
+        normal_form_cache_stats: &mut normal_form_cache_stats,
+        reduction_tracker: &mut reduction_tracker,
+        erasure: &mut erasure,
+        designated_erased_types: &mut designated_erased_types,
+        bigint: &mut bigint,
+        designated_bigints: &mut designated_bigints,
+        precomputation: &mut precomputation,
         context: &mut context,
     };
 
-    untaint_err(&mut state, file_item_list_id, type_check_file_items_dirty)?;
-    Ok(warnings)
+    let result = untaint_err(&mut state, file_item_list_id, type_check_file_items_dirty);
+
+    let reduction_trace = reduction_tracker.take_trace();
+    // Once the reduction step limit is exceeded, the evaluator stops
+    // reducing, so any other error may just be a consequence of that.
+    let (reduction_profile, limit_exceeded_error) =
+        reduction_tracker.into_profile_and_limit_exceeded_error();
+    if let Some(err) = limit_exceeded_error {
+        return Err(err);
+    }
+    result?;
+    verify_every_designated_erased_type_was_declared(&designated_erased_types)?;
+    verify_every_designated_bigint_was_declared(&designated_bigints)?;
+
+    Ok(TypeCheckOutput {
+        warnings,
+        normal_form_cache_stats,
+        reduction_profile,
+        reduction_trace,
+        erasure,
+        bigint,
+        precomputed_lets: precomputation.into_precomputed_lets(),
+    })
 }
 
 pub(super) fn type_check_file_items_dirty(
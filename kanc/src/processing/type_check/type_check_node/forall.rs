@@ -5,18 +5,44 @@ pub(in crate::processing::type_check) fn get_type_of_forall_dirty(
     forall_id: NodeId<Forall>,
 ) -> Result<NormalFormId, Tainted<TypeCheckError>> {
     let forall = state.registry.get(forall_id).clone();
+    let arity = forall.param_list_id.len();
     let _param_list_id =
         normalize_params_and_leave_params_in_context_dirty(state, forall.param_list_id)??;
+    let max_param_universe_level = get_max_param_universe_level_dirty(state, arity)?;
 
     let output_type_id = get_type_of_expression_dirty(state, None, forall.output_id)?;
-    if !is_term_equal_to_type0_or_type1(state, output_type_id) {
-        return tainted_err(TypeCheckError::ExpectedTermOfTypeType0OrType1 {
+    let Some(output_universe_level) = get_universe_level(state, output_type_id) else {
+        return tainted_err(TypeCheckError::ExpectedTermOfTypeUniverse {
             expression_id: forall.output_id,
-            non_type0_or_type1_type_id: output_type_id,
+            non_universe_type_id: output_type_id,
         });
-    }
+    };
+
+    state.context.pop_n(arity);
 
-    state.context.pop_n(forall.param_list_id.len());
+    // A forall lives in the smallest universe that contains
+    // all of its param types and its output type.
+    Ok(universe_expression(
+        state,
+        max_param_universe_level.max(output_universe_level),
+    ))
+}
 
-    Ok(type0_expression(state))
+/// Returns the largest universe level among the types of the
+/// top `arity` context entries.
+/// The context entries must be the (already type checked) params
+/// of a forall (or an exists).
+pub(in crate::processing::type_check) fn get_max_param_universe_level_dirty(
+    state: &mut State,
+    arity: usize,
+) -> Result<usize, Tainted<TypeCheckError>> {
+    let mut max_level = 0;
+    for raw_index in 0..arity {
+        let param_type_id = state.context.get_type(DbIndex(raw_index), state.registry);
+        let param_type_type_id = get_type_of_expression_dirty(state, None, param_type_id.raw())?;
+        let level = get_universe_level(state, param_type_type_id)
+            .expect("The type of a param's type should have been checked to be a universe.");
+        max_level = max_level.max(level);
+    }
+    Ok(max_level)
 }
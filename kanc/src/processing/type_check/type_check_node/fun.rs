@@ -16,10 +16,10 @@ pub(in crate::processing::type_check) fn get_type_of_fun_dirty(
         normalize_params_and_leave_params_in_context_dirty(state, fun.param_list_id)??;
     {
         let return_type_type_id = get_type_of_expression_dirty(state, None, fun.return_type_id)?;
-        if !is_term_equal_to_type0_or_type1(state, return_type_type_id) {
-            return tainted_err(TypeCheckError::ExpectedTermOfTypeType0OrType1 {
+        if get_universe_level(state, return_type_type_id).is_none() {
+            return tainted_err(TypeCheckError::ExpectedTermOfTypeUniverse {
                 expression_id: fun.return_type_id,
-                non_type0_or_type1_type_id: return_type_type_id,
+                non_universe_type_id: return_type_type_id,
             });
         }
     }
@@ -60,7 +60,7 @@ pub(in crate::processing::type_check) fn get_type_of_fun_dirty(
         fun.body_id,
     )?;
 
-    let equality_status = get_rewritten_term_equality_status(
+    let equality_status = get_rewritten_subtyping_status(
         state,
         normalized_body_type_id,
         normalized_return_type_id_relative_to_body,
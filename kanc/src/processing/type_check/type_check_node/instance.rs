@@ -0,0 +1,94 @@
+use super::*;
+
+/// Returns an error unless `type_id` is a trait type
+/// (e.g., `Eq(Nat)`).
+pub(in crate::processing::type_check) fn verify_instance_type_is_trait(
+    state: &mut State,
+    expression_id: ExpressionId,
+    type_id: NormalFormId,
+) -> Result<(), Tainted<TypeCheckError>> {
+    if is_trait_type(state, type_id) {
+        Ok(())
+    } else {
+        tainted_err(TypeCheckError::InstanceTypeIsNotATrait {
+            expression_id,
+            type_id,
+        })
+    }
+}
+
+fn is_trait_type(state: &mut State, type_id: NormalFormId) -> bool {
+    let type_name_id = match type_id.raw() {
+        ExpressionId::Name(name_id) => name_id,
+        ExpressionId::Call(call_id) => match state.registry.get(call_id).callee_id {
+            ExpressionId::Name(name_id) => name_id,
+            _ => return false,
+        },
+        _ => return false,
+    };
+    let db_index = state.registry.get(type_name_id).db_index;
+    matches!(
+        state.context.get_definition(db_index, state.registry),
+        ContextEntryDefinition::Adt { is_trait: true, .. }
+    )
+}
+
+/// Returns a name expression that refers to the unique instance
+/// of type `instance_type_id`.
+/// Only instances that are visible from the call's module
+/// are candidates (just like names, instances cannot be
+/// used outside their visibility).
+pub(in crate::processing::type_check) fn resolve_instance_dirty(
+    state: &mut State,
+    call_id: NodeId<Call>,
+    instance_type_id: NormalFormId,
+) -> Result<ExpressionId, Tainted<TypeCheckError>> {
+    let call_site_mod = state
+        .registry
+        .get(call_id)
+        .span
+        .map(|span| ModScope::Mod(span.file_id));
+    let mut matching_instance_ids = vec![];
+    for (db_index, name_id) in state.context.instances() {
+        if let Some(call_site_mod) = call_site_mod {
+            let visibility = state.context.get_visibility(db_index);
+            if !is_left_at_least_as_permissive_as_right(
+                state.file_tree,
+                visibility.0,
+                call_site_mod,
+            ) {
+                continue;
+            }
+        }
+
+        let candidate_type_id = state.context.get_type(db_index, state.registry);
+        if let RewrittenTermEqualityStatus::Equal =
+            get_rewritten_subtyping_status(state, candidate_type_id, instance_type_id)
+        {
+            matching_instance_ids.push(ExpressionId::Name(add_name_expression(
+                state.registry,
+                NonEmptyVec::singleton(name_id),
+                db_index,
+            )));
+        }
+    }
+
+    match matching_instance_ids.as_slice() {
+        [] => tainted_err(TypeCheckError::MissingInstance {
+            call_id,
+            instance_type_id,
+        }),
+        [instance_id] => Ok(*instance_id),
+        _ => {
+            let candidate_list_id = state.registry.add_list(
+                NonEmptyVec::try_from(matching_instance_ids)
+                    .expect("there should be at least two candidates"),
+            );
+            tainted_err(TypeCheckError::AmbiguousInstance {
+                call_id,
+                instance_type_id,
+                candidate_list_id,
+            })
+        }
+    }
+}
@@ -7,6 +7,9 @@ pub(in crate::processing::type_check) fn get_type_of_match_dirty(
 ) -> Result<NormalFormId, Tainted<TypeCheckError>> {
     let match_ = state.registry.get(match_id).clone();
     let matchee_type_id = get_type_of_expression_dirty(state, None, match_.matchee_id)?;
+    if let ExpressionId::Exists(exists_id) = matchee_type_id.raw() {
+        return get_type_of_exists_match_dirty(state, coercion_target_id, match_id, exists_id);
+    }
     let matchee_type = if let Some(t) = try_as_normal_form_adt_expression(state, matchee_type_id) {
         t
     } else {
@@ -15,6 +18,8 @@ pub(in crate::processing::type_check) fn get_type_of_match_dirty(
             type_id: matchee_type_id,
         });
     };
+    record_match_erasure(state, match_id, &matchee_type);
+    record_match_bigint_type(state, match_id, &matchee_type);
     let normalized_matchee_id = evaluate_well_typed_expression(state, match_.matchee_id);
 
     verify_variant_to_case_bijection(
@@ -25,6 +30,17 @@ pub(in crate::processing::type_check) fn get_type_of_match_dirty(
     )
     .map_err(Tainted::new)?;
 
+    if let Some(motive_id) = match_.motive_id {
+        return get_type_of_match_with_motive_dirty(
+            state,
+            match_id,
+            motive_id,
+            normalized_matchee_id,
+            matchee_type_id,
+            matchee_type,
+        );
+    }
+
     let case_ids = state
         .registry
         .get_possibly_empty_list(match_.case_list_id)
@@ -85,6 +101,372 @@ pub(in crate::processing::type_check) fn get_type_of_match_dirty(
     }
 }
 
+/// A match motive whose output has been type checked and normalized.
+#[derive(Clone, Debug)]
+struct CheckedMatchMotive {
+    /// The names of the motive's params.
+    /// These are the index names, followed by the matchee name.
+    param_name_ids: Vec<NodeId<Identifier>>,
+    /// If the matchee type's params are labeled, these are the labels
+    /// (in the order the params are declared).
+    adt_param_label_ids: Option<NonEmptyVec<NodeId<Identifier>>>,
+    /// The normalized motive output.
+    /// This is under the motive's params.
+    output_id: NormalFormId,
+}
+
+fn get_type_of_match_with_motive_dirty(
+    state: &mut State,
+    match_id: NodeId<Match>,
+    motive_id: NodeId<MatchMotive>,
+    normalized_matchee_id: NormalFormId,
+    matchee_type_id: NormalFormId,
+    matchee_type: NormalFormAdtExpression,
+) -> Result<NormalFormId, Tainted<TypeCheckError>> {
+    let motive = check_match_motive_dirty(state, motive_id, matchee_type_id, matchee_type)?;
+
+    let matchee_index_ids =
+        get_adt_expression_index_ids(state, matchee_type, motive.adt_param_label_ids.as_ref());
+    let match_type_id =
+        instantiate_match_motive(state, &motive, 0, &matchee_index_ids, normalized_matchee_id);
+
+    let case_ids = state
+        .registry
+        .get_possibly_empty_list(state.registry.get(match_id).case_list_id)
+        .to_vec();
+    for case_id in case_ids {
+        match state.registry.get(case_id).output_id {
+            MatchCaseOutputId::Some(case_output_id) => {
+                verify_type_of_allegedly_non_impossible_match_case_against_motive_dirty(
+                    state,
+                    case_id,
+                    case_output_id,
+                    normalized_matchee_id,
+                    matchee_type_id,
+                    matchee_type,
+                    &motive,
+                )?;
+            }
+            MatchCaseOutputId::ImpossibilityClaim(_) => {
+                verify_allegedly_impossible_match_case_is_actually_impossible_dirty(
+                    state,
+                    case_id,
+                    normalized_matchee_id,
+                    matchee_type_id,
+                    matchee_type,
+                )?;
+            }
+        }
+    }
+
+    Ok(match_type_id)
+}
+
+fn check_match_motive_dirty(
+    state: &mut State,
+    motive_id: NodeId<MatchMotive>,
+    matchee_type_id: NormalFormId,
+    matchee_type: NormalFormAdtExpression,
+) -> Result<CheckedMatchMotive, Tainted<TypeCheckError>> {
+    let motive = state.registry.get(motive_id).clone();
+
+    let motive_adt_id = ExpressionId::Name(motive.matchee_type_name_id);
+    get_type_of_expression_dirty(state, None, motive_adt_id)?;
+    let normalized_motive_adt_id = evaluate_well_typed_expression(state, motive_adt_id);
+    let matchee_adt_db_index = state.registry.get(matchee_type.type_name_id).db_index;
+    let is_motive_adt_the_matchee_adt = match normalized_motive_adt_id.raw() {
+        ExpressionId::Name(name_id) => state.registry.get(name_id).db_index == matchee_adt_db_index,
+        _ => false,
+    };
+    if !is_motive_adt_the_matchee_adt {
+        return tainted_err(TypeCheckError::MatchMotiveAdtMismatch {
+            motive_id,
+            matchee_type_id,
+        });
+    }
+
+    let adt_type_id = state.context.get_type(matchee_adt_db_index, state.registry);
+    let (adt_param_label_ids, adt_param_type_ids) = match adt_type_id.raw() {
+        ExpressionId::Forall(forall_id) => {
+            let forall = state.registry.get(forall_id).clone();
+            let label_ids = match forall.param_list_id {
+                NonEmptyParamListId::Unlabeled(_) => None,
+                NonEmptyParamListId::UniquelyLabeled(param_list_id) => Some(
+                    state
+                        .registry
+                        .get_list(param_list_id)
+                        .to_mapped(|&param_id| state.registry.get(param_id).label_identifier_id()),
+                ),
+            };
+            let (_, type_ids) = get_names_and_types_of_params(state, forall.param_list_id);
+            (label_ids, type_ids.to_vec())
+        }
+        _ => (None, vec![]),
+    };
+
+    let index_name_ids = state
+        .registry
+        .get_possibly_empty_list(motive.index_name_list_id)
+        .to_vec();
+    if index_name_ids.len() != adt_param_type_ids.len() {
+        return tainted_err(TypeCheckError::WrongNumberOfMatchMotiveIndices {
+            motive_id,
+            expected: adt_param_type_ids.len(),
+            actual: index_name_ids.len(),
+        });
+    }
+
+    for &param_type_id in &adt_param_type_ids {
+        state.context.push(ContextEntry {
+            // This is safe because every param type of a normal form Forall
+            // is also a normal form itself.
+            type_id: NormalFormId::unchecked_new(param_type_id),
+            definition: ContextEntryDefinition::Uninterpreted,
+        })?;
+    }
+    let matchee_param_type_id = parameterized_adt_expression(
+        state,
+        matchee_type.type_name_id,
+        &index_name_ids,
+        adt_param_label_ids.as_ref(),
+    );
+    state.context.push(ContextEntry {
+        type_id: matchee_param_type_id,
+        definition: ContextEntryDefinition::Uninterpreted,
+    })?;
+
+    let output_type_id = get_type_of_expression_dirty(state, None, motive.output_id)?;
+    if get_universe_level(state, output_type_id).is_none() {
+        return tainted_err(TypeCheckError::ExpectedTermOfTypeUniverse {
+            expression_id: motive.output_id,
+            non_universe_type_id: output_type_id,
+        });
+    }
+    let normalized_output_id = evaluate_well_typed_expression(state, motive.output_id);
+    state.context.pop_n(index_name_ids.len() + 1);
+
+    let mut param_name_ids = index_name_ids;
+    param_name_ids.push(motive.matchee_name_id);
+    Ok(CheckedMatchMotive {
+        param_name_ids,
+        adt_param_label_ids,
+        output_id: normalized_output_id,
+    })
+}
+
+/// Returns `Adt(i1, ..., in)`, where `i1, ..., in` are the top `n`
+/// context entries.
+/// `adt_name_id` must be valid in the context _before_ the indices
+/// were pushed.
+fn parameterized_adt_expression(
+    state: &mut State,
+    adt_name_id: NodeId<NameExpression>,
+    index_name_ids: &[NodeId<Identifier>],
+    adt_param_label_ids: Option<&NonEmptyVec<NodeId<Identifier>>>,
+) -> NormalFormId {
+    let arity = index_name_ids.len();
+    let adt_name = state.registry.get(adt_name_id).clone();
+    let callee_id = add_name_expression(
+        state.registry,
+        state
+            .registry
+            .get_list(adt_name.component_list_id)
+            .to_non_empty_vec(),
+        DbIndex(adt_name.db_index.0 + arity),
+    );
+    let Ok(index_name_ids) = NonEmptyVec::try_from(index_name_ids.to_vec()) else {
+        return NormalFormId::unchecked_new(ExpressionId::Name(callee_id));
+    };
+
+    let arg_list_id = match adt_param_label_ids {
+        None => {
+            let arg_ids = index_name_ids.enumerate_into_mapped(|(index, index_name_id)| {
+                ExpressionId::Name(add_name_expression(
+                    state.registry,
+                    NonEmptyVec::singleton(index_name_id),
+                    DbIndex(arity - index - 1),
+                ))
+            });
+            NonEmptyCallArgListId::Unlabeled(state.registry.add_list(arg_ids))
+        }
+        Some(label_ids) => {
+            let arg_ids = index_name_ids.enumerate_into_mapped(|(index, index_name_id)| {
+                let label_id = label_ids[index];
+                let db_index = DbIndex(arity - index - 1);
+                let label_name: &IdentifierName = &state.registry.get(label_id).name;
+                let index_name: &IdentifierName = &state.registry.get(index_name_id).name;
+                if label_name == index_name {
+                    LabeledCallArgId::implicit(label_id, db_index, state.registry)
+                } else {
+                    let value_id = ExpressionId::Name(add_name_expression(
+                        state.registry,
+                        NonEmptyVec::singleton(index_name_id),
+                        db_index,
+                    ));
+                    LabeledCallArgId::Explicit { label_id, value_id }
+                }
+            });
+            NonEmptyCallArgListId::UniquelyLabeled(state.registry.add_list(arg_ids))
+        }
+    };
+    NormalFormId::unchecked_new(ExpressionId::Call(
+        state
+            .registry
+            .add_and_overwrite_id(Call {
+                id: dummy_id(),
+                span: None,
+                callee_id: ExpressionId::Name(callee_id),
+                arg_list_id,
+            })
+            .without_spans(state.registry),
+    ))
+}
+
+/// Returns the args of the provided ADT expression,
+/// in the order the ADT's params are declared.
+fn get_adt_expression_index_ids(
+    state: &State,
+    adt_expression: NormalFormAdtExpression,
+    adt_param_label_ids: Option<&NonEmptyVec<NodeId<Identifier>>>,
+) -> Vec<NormalFormId> {
+    match adt_expression.arg_list_id {
+        None => vec![],
+        Some(NonEmptyCallArgListId::Unlabeled(arg_list_id)) => state
+            .registry
+            .get_list(arg_list_id)
+            .iter()
+            .copied()
+            // This is safe because every arg of a normal form Call
+            // is also a normal form itself.
+            .map(NormalFormId::unchecked_new)
+            .collect(),
+        Some(NonEmptyCallArgListId::UniquelyLabeled(arg_list_id)) => {
+            let arg_ids = state.registry.get_list(arg_list_id).to_vec();
+            let label_ids = adt_param_label_ids
+                .expect("Impossible: A well-typed ADT expression with labeled args must have labeled params.");
+            label_ids
+                .iter()
+                .map(|&label_id| {
+                    let (_, arg_id) = get_arg_corresponding_to_label(state, label_id, &arg_ids)
+                        .expect("Impossible: A well-typed ADT expression should have an arg for every param.");
+                    NormalFormId::unchecked_new(arg_id.value_id())
+                })
+                .collect()
+        }
+    }
+}
+
+/// Substitutes the provided indices and matchee into the motive output.
+/// `extra_depth` is the number of context entries that have been
+/// pushed since the motive was checked (e.g., the params of a match case).
+fn instantiate_match_motive(
+    state: &mut State,
+    motive: &CheckedMatchMotive,
+    extra_depth: usize,
+    index_ids: &[NormalFormId],
+    matchee_id: NormalFormId,
+) -> NormalFormId {
+    let arity = motive.param_name_ids.len();
+    let unsubstituted =
+        motive
+            .output_id
+            .raw()
+            .upshift_with_cutoff(extra_depth, arity, state.registry);
+    let arg_ids: Vec<NormalFormId> = index_ids
+        .iter()
+        .copied()
+        .chain(std::iter::once(matchee_id))
+        .collect();
+    let substitutions: Vec<Substitution> = arg_ids
+        .iter()
+        .copied()
+        .enumerate()
+        .map(|(j, arg_id)| Substitution {
+            from: ExpressionId::Name(add_name_expression(
+                state.registry,
+                NonEmptyVec::singleton(motive.param_name_ids[j]),
+                DbIndex(arity - j - 1),
+            )),
+            to: arg_id.upshift(arity, state.registry).raw(),
+        })
+        .collect();
+    let substituted = unsubstituted
+        .subst_all(&substitutions, &mut state.without_context())
+        .downshift(arity, state.registry);
+    evaluate_well_typed_expression(state, substituted)
+}
+
+fn verify_type_of_allegedly_non_impossible_match_case_against_motive_dirty(
+    state: &mut State,
+    case_id: NodeId<MatchCase>,
+    case_output_id: ExpressionId,
+    normalized_matchee_id: NormalFormId,
+    matchee_type_id: NormalFormId,
+    matchee_type: NormalFormAdtExpression,
+    motive: &CheckedMatchMotive,
+) -> Result<(), Tainted<TypeCheckError>> {
+    let ParameterizedTerms {
+        matchee_id: parameterized_matchee_id,
+        matchee_type_id: parameterized_matchee_type_id,
+        case_output_substitutions,
+    } = add_case_params_to_context_and_parameterize_terms_dirty(state, case_id, matchee_type)??;
+    let variant_arity = case_output_substitutions.variant_arity;
+
+    let expected_type_id = {
+        let parameterized_matchee_type =
+            try_as_normal_form_adt_expression(state, parameterized_matchee_type_id)
+                .expect("Impossible: A variant's return type should always be an ADT expression.");
+        let index_ids = get_adt_expression_index_ids(
+            state,
+            parameterized_matchee_type,
+            motive.adt_param_label_ids.as_ref(),
+        );
+        instantiate_match_motive(
+            state,
+            motive,
+            variant_arity,
+            &index_ids,
+            parameterized_matchee_id,
+        )
+    };
+
+    let normalized_matchee_id = normalized_matchee_id.upshift(variant_arity, state.registry);
+    let matchee_type_id = matchee_type_id.upshift(variant_arity, state.registry);
+
+    state.substitution_context.push(SubstitutionContextEntry {
+        context_len: state.context.len(),
+        unadjusted_substitutions: vec![
+            DynamicSubstitution(normalized_matchee_id, parameterized_matchee_id),
+            DynamicSubstitution(matchee_type_id, parameterized_matchee_type_id),
+        ],
+    });
+
+    let shifted_output_id = apply_case_output_substitutions(
+        &mut state.without_context(),
+        case_output_id,
+        &case_output_substitutions,
+    );
+    let output_type_id =
+        get_type_of_expression_dirty(state, Some(expected_type_id), shifted_output_id)?;
+    copy_erasure_to_original(state, shifted_output_id, case_output_id);
+    let subtyping_status = get_rewritten_subtyping_status(state, output_type_id, expected_type_id);
+
+    state.context.pop_n(variant_arity);
+    state.substitution_context.pop();
+
+    match subtyping_status {
+        RewrittenTermEqualityStatus::Equal => Ok(()),
+        RewrittenTermEqualityStatus::Exploded => {
+            tainted_err(TypeCheckError::UnreachableExpression(case_output_id))
+        }
+        RewrittenTermEqualityStatus::NotEqual => tainted_err(TypeCheckError::TypeMismatch {
+            expression_id: case_output_id,
+            expected_type_id,
+            actual_type_id: output_type_id,
+        }),
+    }
+}
+
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
 enum MatchCaseOutputTypeCheckSuccess {
     NotObviouslyImpossible {
@@ -169,10 +551,11 @@ fn get_type_of_allegedly_non_impossible_match_case_dirty(
     );
     let output_type_id =
         get_type_of_expression_dirty(state, coercion_target_id, shifted_output_id)?;
+    copy_erasure_to_original(state, shifted_output_id, case_output_id);
 
     if let Some(coercion_target_id) = coercion_target_id {
         let equality_status =
-            get_rewritten_term_equality_status(state, output_type_id, coercion_target_id);
+            get_rewritten_subtyping_status(state, output_type_id, coercion_target_id);
 
         state.context.pop_n(variant_arity);
         state.substitution_context.pop();
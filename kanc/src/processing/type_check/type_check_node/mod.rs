@@ -27,6 +27,18 @@ mod forall;
 pub(in crate::processing::type_check) use check::*;
 mod check;
 
+pub(in crate::processing::type_check) use rewrite::*;
+mod rewrite;
+
+pub(in crate::processing::type_check) use projection::*;
+mod projection;
+
+pub(in crate::processing::type_check) use exists::*;
+mod exists;
+
+pub(in crate::processing::type_check) use instance::*;
+mod instance;
+
 fn type_check_expression_dirty(
     state: &mut State,
     coercion_target_id: Option<NormalFormId>,
@@ -70,5 +82,11 @@ pub(in crate::processing::type_check) fn get_type_of_expression_dirty(
         ExpressionId::Check(check) => {
             get_type_of_check_expression_dirty(state, coercion_target_id, check)
         }
+        ExpressionId::Rewrite(rewrite) => {
+            get_type_of_rewrite_dirty(state, coercion_target_id, rewrite)
+        }
+        ExpressionId::Projection(projection) => get_type_of_projection_dirty(state, projection),
+        ExpressionId::Exists(exists) => get_type_of_exists_dirty(state, exists),
+        ExpressionId::Pair(pair) => get_type_of_pair_dirty(state, coercion_target_id, pair),
     }
 }
@@ -0,0 +1,156 @@
+use super::*;
+
+pub(in crate::processing::type_check) fn get_type_of_projection_dirty(
+    state: &mut State,
+    projection_id: NodeId<Projection>,
+) -> Result<NormalFormId, Tainted<TypeCheckError>> {
+    let projection = state.registry.get(projection_id).clone();
+    let record_type_id = get_type_of_expression_dirty(state, None, projection.record_id)?;
+
+    let Some((record_type, constructor_param_list_id)) =
+        try_get_struct_constructor_params(state, record_type_id)
+    else {
+        return tainted_err(TypeCheckError::ProjectionRecordIsNotAStruct {
+            projection_id,
+            record_type_id,
+        });
+    };
+
+    let type_arity = record_type.arg_list_id.len();
+    let constructor_param_ids = constructor_param_list_id
+        .map(|id| state.registry.get_list(id).to_vec())
+        .unwrap_or_default();
+    let field_name = &state.registry.get(projection.field_name_id).name;
+    let Some(field_index) = constructor_param_ids
+        .iter()
+        .enumerate()
+        .skip(type_arity)
+        .find(|(_, &param_id)| {
+            let label_id = state.registry.get(param_id).label_identifier_id();
+            &state.registry.get(label_id).name == field_name
+        })
+        .map(|(param_index, _)| param_index)
+    else {
+        return tainted_err(TypeCheckError::StructHasNoSuchField {
+            projection_id,
+            record_type_id,
+        });
+    };
+
+    let normalized_record_id = evaluate_well_typed_expression(state, projection.record_id);
+
+    // Earlier constructor params are replaced by either
+    // the corresponding type args (for the struct's params)
+    // or by projections of the record (for the earlier fields).
+    // This mirrors how we substitute args into param types
+    // when type checking a call.
+    let substitutions: Vec<Substitution> = constructor_param_ids[..field_index]
+        .iter()
+        .copied()
+        .enumerate()
+        .map(|(j, param_id)| {
+            let param = state.registry.get(param_id).clone();
+            let label_id = param.label_identifier_id();
+            let value_id = if j < type_arity {
+                get_type_arg(state, record_type.arg_list_id, j, label_id)
+            } else {
+                ExpressionId::Projection(state.registry.add_and_overwrite_id(Projection {
+                    id: dummy_id(),
+                    span: None,
+                    record_id: normalized_record_id.raw(),
+                    field_name_id: label_id,
+                }))
+            };
+            let db_index = DbIndex(field_index - j - 1);
+            Substitution {
+                from: ExpressionId::Name(add_name_expression(
+                    state.registry,
+                    NonEmptyVec::singleton(param.name_id),
+                    db_index,
+                )),
+                to: value_id.upshift(field_index, state.registry),
+            }
+        })
+        .collect();
+
+    // This is safe because the param is the param of a normal
+    // form Forall node, which guarantees that its type is a
+    // normal form.
+    let unsubstituted = state
+        .registry
+        .get(constructor_param_ids[field_index])
+        .type_id;
+    let substituted = unsubstituted
+        .subst_all(&substitutions, &mut state.without_context())
+        .downshift(field_index, state.registry);
+    Ok(evaluate_well_typed_expression(state, substituted))
+}
+
+/// If the provided type is a struct type, this returns
+/// the type (as an ADT expression) and the params of the
+/// struct's constructor (or `None` if the constructor has
+/// no params).
+fn try_get_struct_constructor_params(
+    state: &mut State,
+    record_type_id: NormalFormId,
+) -> Option<(
+    NormalFormAdtExpression,
+    Option<NonEmptyListId<NodeId<LabeledParam>>>,
+)> {
+    let record_type = try_as_normal_form_adt_expression(state, record_type_id)?;
+    let type_dbi = state.registry.get(record_type.type_name_id).db_index;
+    let ContextEntryDefinition::Adt {
+        is_struct: true, ..
+    } = state.context.get_definition(type_dbi, state.registry)
+    else {
+        return None;
+    };
+
+    // A struct's single variant (i.e., its constructor) is
+    // the entry directly after the struct's type.
+    // If the constructor is not in the context yet
+    // (i.e., we are still checking the struct's fields),
+    // then the struct is not usable as a record yet.
+    let constructor_dbi = DbIndex(type_dbi.0.checked_sub(1)?);
+    let ContextEntryDefinition::Variant { .. } = state
+        .context
+        .get_definition(constructor_dbi, state.registry)
+    else {
+        return None;
+    };
+
+    let constructor_type_id = state.context.get_type(constructor_dbi, state.registry);
+    match constructor_type_id.raw() {
+        ExpressionId::Forall(forall_id) => match state.registry.get(forall_id).param_list_id {
+            NonEmptyParamListId::UniquelyLabeled(param_list_id) => {
+                Some((record_type, Some(param_list_id)))
+            }
+            NonEmptyParamListId::Unlabeled(_) => None,
+        },
+        _ => Some((record_type, None)),
+    }
+}
+
+fn get_type_arg(
+    state: &mut State,
+    arg_list_id: Option<NonEmptyCallArgListId>,
+    param_index: usize,
+    param_label_id: NodeId<Identifier>,
+) -> ExpressionId {
+    match arg_list_id {
+        Some(NonEmptyCallArgListId::Unlabeled(arg_list_id)) => {
+            state.registry.get_list(arg_list_id)[param_index]
+        }
+        Some(NonEmptyCallArgListId::UniquelyLabeled(arg_list_id)) => {
+            let param_label_name = &state.registry.get(param_label_id).name;
+            state
+                .registry
+                .get_list(arg_list_id)
+                .iter()
+                .find(|arg_id| &state.registry.get(arg_id.label_id()).name == param_label_name)
+                .expect("A well-typed struct type should have an arg for every struct param.")
+                .value_id()
+        }
+        None => panic!("A struct type with params should have args."),
+    }
+}
@@ -0,0 +1,179 @@
+use super::*;
+
+pub(in crate::processing::type_check) fn get_type_of_rewrite_dirty(
+    state: &mut State,
+    coercion_target_id: Option<NormalFormId>,
+    rewrite_id: NodeId<Rewrite>,
+) -> Result<NormalFormId, Tainted<TypeCheckError>> {
+    let Some(goal_id) = coercion_target_id else {
+        return tainted_err(TypeCheckError::CannotInferTypeOfRewrite(rewrite_id));
+    };
+    let rewrite = state.registry.get(rewrite_id).clone();
+
+    verify_designated_eq_is_equality(state, rewrite_id).map_err(Tainted::new)?;
+
+    let proof_type_id = get_type_of_expression_dirty(state, None, rewrite.proof_id)?;
+    let Some((left_id, right_id)) = try_get_equality_sides(state, &rewrite, proof_type_id) else {
+        return tainted_err(TypeCheckError::RewriteProofIsNotAnEquality {
+            rewrite_id,
+            proof_type_id,
+        });
+    };
+
+    // The goal (and the equality's sides) may mention terms that
+    // an enclosing `match` case has already refined (e.g., `a := s(a')`),
+    // so we need to apply those refinements before rewriting.
+    let ((refined_goal_id,), (left_id,), (right_id,)) =
+        match apply_substitutions_from_substitution_context(
+            state,
+            (
+                (goal_id,),
+                (NormalFormId::unchecked_new(left_id),),
+                (NormalFormId::unchecked_new(right_id),),
+            ),
+        ) {
+            Ok(x) => x,
+            Err(Exploded) => {
+                return tainted_err(TypeCheckError::UnreachableExpression(rewrite.body_id));
+            }
+        };
+
+    // If `proof: Eq(_, l, r)` and the goal is `G`, then the body
+    // must have the type `G[l := r]`.
+    let rewritten_goal_id = {
+        let substituted = refined_goal_id.raw().subst(
+            Substitution {
+                from: left_id.raw(),
+                to: right_id.raw(),
+            },
+            &mut state.without_context(),
+        );
+        evaluate_well_typed_expression(state, substituted)
+    };
+
+    let body_type_id =
+        get_type_of_expression_dirty(state, Some(rewritten_goal_id), rewrite.body_id)?;
+    match get_rewritten_subtyping_status(state, body_type_id, rewritten_goal_id) {
+        RewrittenTermEqualityStatus::Equal => Ok(goal_id),
+        RewrittenTermEqualityStatus::Exploded => {
+            tainted_err(TypeCheckError::UnreachableExpression(rewrite.body_id))
+        }
+        RewrittenTermEqualityStatus::NotEqual => tainted_err(TypeCheckError::TypeMismatch {
+            expression_id: rewrite.body_id,
+            expected_type_id: rewritten_goal_id,
+            actual_type_id: body_type_id,
+        }),
+    }
+}
+
+/// Returns the last two arguments of the proof's type,
+/// if the proof's type is an application of the designated `Eq` type.
+fn try_get_equality_sides(
+    state: &mut State,
+    rewrite: &Rewrite,
+    proof_type_id: NormalFormId,
+) -> Option<(ExpressionId, ExpressionId)> {
+    let adt = try_as_normal_form_adt_expression(state, proof_type_id)?;
+    let proof_type_dbi = state.registry.get(adt.type_name_id).db_index;
+    let eq_type_dbi = state.registry.get(rewrite.eq_type_name_id).db_index;
+    if proof_type_dbi != eq_type_dbi {
+        return None;
+    }
+    let arg_value_ids = get_arg_value_ids(state.registry, adt.arg_list_id?);
+    match arg_value_ids.as_slice() {
+        [.., left_id, right_id] => Some((*left_id, *right_id)),
+        _ => None,
+    }
+}
+
+/// We only accept a designated `Eq` type if it has exactly one
+/// variant (the designated `refl`) and the last two arguments of
+/// that variant's return type are identical.
+/// Otherwise, rewriting along it would be unsound.
+fn verify_designated_eq_is_equality(
+    state: &mut State,
+    rewrite_id: NodeId<Rewrite>,
+) -> Result<(), TypeCheckError> {
+    if is_designated_eq_an_equality(state, rewrite_id) {
+        Ok(())
+    } else {
+        Err(TypeCheckError::IllegalDesignatedEqType { rewrite_id })
+    }
+}
+
+fn is_designated_eq_an_equality(state: &mut State, rewrite_id: NodeId<Rewrite>) -> bool {
+    let rewrite = state.registry.get(rewrite_id).clone();
+
+    let eq_type_dbi = state.registry.get(rewrite.eq_type_name_id).db_index;
+    let ContextEntryDefinition::Adt {
+        variant_name_list_id,
+        ..
+    } = state.context.get_definition(eq_type_dbi, state.registry)
+    else {
+        return false;
+    };
+    let variant_name_ids = state
+        .registry
+        .get_possibly_empty_list(variant_name_list_id)
+        .to_vec();
+    let [variant_name_id] = variant_name_ids.as_slice() else {
+        return false;
+    };
+    let refl_name = {
+        let refl_component_list_id = state
+            .registry
+            .get(rewrite.eq_refl_name_id)
+            .component_list_id;
+        let last_component_id = *state
+            .registry
+            .get_list(refl_component_list_id)
+            .to_non_empty_vec()
+            .last();
+        state.registry.get(last_component_id).name.clone()
+    };
+    if state.registry.get(*variant_name_id).name != refl_name {
+        return false;
+    }
+
+    let refl_type_id = get_type_of_name(state, rewrite.eq_refl_name_id);
+    let ExpressionId::Forall(refl_type_id) = refl_type_id.raw() else {
+        return false;
+    };
+    let refl_type = state.registry.get(refl_type_id).clone();
+    let ExpressionId::Call(refl_output_id) = refl_type.output_id else {
+        return false;
+    };
+    let refl_output = state.registry.get(refl_output_id).clone();
+    // The output is under `refl`'s params, so we must upshift
+    // the `Eq` type name before comparing.
+    let shifted_eq_type_name_id = ExpressionId::Name(rewrite.eq_type_name_id)
+        .upshift(refl_type.param_list_id.len(), state.registry);
+    if !state.equality_checker.eq(
+        refl_output.callee_id,
+        shifted_eq_type_name_id,
+        state.registry,
+    ) {
+        return false;
+    }
+    let arg_value_ids = get_arg_value_ids(state.registry, refl_output.arg_list_id);
+    match arg_value_ids.as_slice() {
+        [.., left_id, right_id] => state
+            .equality_checker
+            .eq(*left_id, *right_id, state.registry),
+        _ => false,
+    }
+}
+
+fn get_arg_value_ids(
+    registry: &NodeRegistry,
+    arg_list_id: NonEmptyCallArgListId,
+) -> Vec<ExpressionId> {
+    match arg_list_id {
+        NonEmptyCallArgListId::Unlabeled(id) => registry.get_list(id).to_vec(),
+        NonEmptyCallArgListId::UniquelyLabeled(id) => registry
+            .get_list(id)
+            .iter()
+            .map(|arg_id| arg_id.value_id())
+            .collect(),
+    }
+}
@@ -62,6 +62,10 @@ fn verify_expression(
         ExpressionId::Match(id) => verify_match(state, id, perspective),
         ExpressionId::Forall(id) => verify_forall(state, id, perspective),
         ExpressionId::Check(id) => verify_check_expression(state, id, perspective),
+        ExpressionId::Rewrite(id) => verify_rewrite(state, id, perspective),
+        ExpressionId::Projection(id) => verify_projection(state, id, perspective),
+        ExpressionId::Exists(id) => verify_exists(state, id, perspective),
+        ExpressionId::Pair(id) => verify_pair(state, id, perspective),
     }
 }
 
@@ -212,10 +216,32 @@ fn verify_match(
 ) -> Result<(), (NodeId<NameExpression>, Visibility)> {
     let match_ = state.registry().get(id);
     verify_expression(state, match_.matchee_id, perspective)?;
+    if let Some(motive_id) = match_.motive_id {
+        verify_match_motive(state, motive_id, perspective)?;
+    }
     verify_optional_match_case_list(state, match_.case_list_id, perspective)?;
     Ok(())
 }
 
+fn verify_match_motive(
+    state: OffsetState,
+    id: NodeId<MatchMotive>,
+    perspective: Visibility,
+) -> Result<(), (NodeId<NameExpression>, Visibility)> {
+    let motive = state.registry().get(id);
+    verify_expression(
+        state,
+        ExpressionId::Name(motive.matchee_type_name_id),
+        perspective,
+    )?;
+    verify_expression(
+        state.extend(motive.index_name_list_id.len() + 1),
+        motive.output_id,
+        perspective,
+    )?;
+    Ok(())
+}
+
 fn verify_optional_match_case_list(
     state: OffsetState,
     list_id: Option<NonEmptyListId<NodeId<MatchCase>>>,
@@ -292,3 +318,46 @@ fn verify_check_expression(
     verify_expression(state, check.output_id, perspective)?;
     Ok(())
 }
+
+fn verify_rewrite(
+    state: OffsetState,
+    id: NodeId<Rewrite>,
+    perspective: Visibility,
+) -> Result<(), (NodeId<NameExpression>, Visibility)> {
+    let rewrite = state.registry().get(id);
+    verify_expression(state, rewrite.body_id, perspective)?;
+    Ok(())
+}
+
+fn verify_projection(
+    state: OffsetState,
+    id: NodeId<Projection>,
+    perspective: Visibility,
+) -> Result<(), (NodeId<NameExpression>, Visibility)> {
+    let projection = state.registry().get(id);
+    verify_expression(state, projection.record_id, perspective)?;
+    Ok(())
+}
+
+fn verify_exists(
+    state: OffsetState,
+    id: NodeId<Exists>,
+    perspective: Visibility,
+) -> Result<(), (NodeId<NameExpression>, Visibility)> {
+    let exists = state.registry().get(id);
+    let param = state.registry().get(exists.param_id);
+    verify_expression(state, param.type_id, perspective)?;
+    verify_expression(state.extend(1), exists.output_id, perspective)?;
+    Ok(())
+}
+
+fn verify_pair(
+    state: OffsetState,
+    id: NodeId<Pair>,
+    perspective: Visibility,
+) -> Result<(), (NodeId<NameExpression>, Visibility)> {
+    let pair = state.registry().get(id);
+    verify_expression(state, pair.witness_id, perspective)?;
+    verify_expression(state, pair.proof_id, perspective)?;
+    Ok(())
+}
@@ -42,6 +42,7 @@ impl WithoutSpans for NodeId<UnlabeledParam> {
         registry.add_and_overwrite_id(UnlabeledParam {
             id: dummy_id(),
             span: None,
+            is_instance: original.is_instance,
             is_dashed: original.is_dashed,
             name_id,
             type_id,
@@ -63,13 +64,18 @@ impl WithoutSpans for NodeId<LabeledParam> {
         let label_id = original.label_id.without_spans(registry);
         let name_id = original.name_id.without_spans(registry);
         let type_id = original.type_id.without_spans(registry);
+        let default_id = original
+            .default_id
+            .map(|default_id| default_id.without_spans(registry));
         registry.add_and_overwrite_id(LabeledParam {
             id: dummy_id(),
             span: None,
+            is_instance: original.is_instance,
             label_id,
             is_dashed: original.is_dashed,
             name_id,
             type_id,
+            default_id,
         })
     }
 }
@@ -110,6 +116,10 @@ impl WithoutSpans for ExpressionId {
             ExpressionId::Match(id) => ExpressionId::Match(id.without_spans(registry)),
             ExpressionId::Forall(id) => ExpressionId::Forall(id.without_spans(registry)),
             ExpressionId::Check(id) => ExpressionId::Check(id.without_spans(registry)),
+            ExpressionId::Rewrite(id) => ExpressionId::Rewrite(id.without_spans(registry)),
+            ExpressionId::Projection(id) => ExpressionId::Projection(id.without_spans(registry)),
+            ExpressionId::Exists(id) => ExpressionId::Exists(id.without_spans(registry)),
+            ExpressionId::Pair(id) => ExpressionId::Pair(id.without_spans(registry)),
         }
     }
 }
@@ -229,16 +239,36 @@ impl WithoutSpans for NodeId<Match> {
     fn without_spans(self, registry: &mut NodeRegistry) -> Self {
         let original = registry.get(self).clone();
         let matchee_id = original.matchee_id.without_spans(registry);
+        let motive_id = original.motive_id.without_spans(registry);
         let case_list_id = original.case_list_id.without_spans(registry);
         registry.add_and_overwrite_id(Match {
             id: dummy_id(),
             span: None,
             matchee_id,
+            motive_id,
             case_list_id,
         })
     }
 }
 
+impl WithoutSpans for NodeId<MatchMotive> {
+    fn without_spans(self, registry: &mut NodeRegistry) -> Self {
+        let original = registry.get(self).clone();
+        let matchee_name_id = original.matchee_name_id.without_spans(registry);
+        let matchee_type_name_id = original.matchee_type_name_id.without_spans(registry);
+        let index_name_list_id = original.index_name_list_id.without_spans(registry);
+        let output_id = original.output_id.without_spans(registry);
+        registry.add_and_overwrite_id(MatchMotive {
+            id: dummy_id(),
+            span: None,
+            matchee_name_id,
+            matchee_type_name_id,
+            index_name_list_id,
+            output_id,
+        })
+    }
+}
+
 impl WithoutSpans for NonEmptyListId<NodeId<MatchCase>> {
     fn without_spans(self, registry: &mut NodeRegistry) -> Self {
         let original = registry.get_list(self).to_non_empty_vec();
@@ -339,6 +369,66 @@ impl WithoutSpans for NodeId<Check> {
     }
 }
 
+impl WithoutSpans for NodeId<Rewrite> {
+    fn without_spans(self, registry: &mut NodeRegistry) -> Self {
+        let original = registry.get(self).clone();
+        let eq_type_name_id = original.eq_type_name_id.without_spans(registry);
+        let eq_refl_name_id = original.eq_refl_name_id.without_spans(registry);
+        let proof_id = original.proof_id.without_spans(registry);
+        let body_id = original.body_id.without_spans(registry);
+        registry.add_and_overwrite_id(Rewrite {
+            id: dummy_id(),
+            span: None,
+            eq_type_name_id,
+            eq_refl_name_id,
+            proof_id,
+            body_id,
+        })
+    }
+}
+
+impl WithoutSpans for NodeId<Projection> {
+    fn without_spans(self, registry: &mut NodeRegistry) -> Self {
+        let original = registry.get(self).clone();
+        let record_id = original.record_id.without_spans(registry);
+        let field_name_id = original.field_name_id.without_spans(registry);
+        registry.add_and_overwrite_id(Projection {
+            id: dummy_id(),
+            span: None,
+            record_id,
+            field_name_id,
+        })
+    }
+}
+
+impl WithoutSpans for NodeId<Exists> {
+    fn without_spans(self, registry: &mut NodeRegistry) -> Self {
+        let original = registry.get(self).clone();
+        let param_id = original.param_id.without_spans(registry);
+        let output_id = original.output_id.without_spans(registry);
+        registry.add_and_overwrite_id(Exists {
+            id: dummy_id(),
+            span: None,
+            param_id,
+            output_id,
+        })
+    }
+}
+
+impl WithoutSpans for NodeId<Pair> {
+    fn without_spans(self, registry: &mut NodeRegistry) -> Self {
+        let original = registry.get(self).clone();
+        let witness_id = original.witness_id.without_spans(registry);
+        let proof_id = original.proof_id.without_spans(registry);
+        registry.add_and_overwrite_id(Pair {
+            id: dummy_id(),
+            span: None,
+            witness_id,
+            proof_id,
+        })
+    }
+}
+
 impl WithoutSpans for NonEmptyListId<NodeId<CheckAssertion>> {
     fn without_spans(self, registry: &mut NodeRegistry) -> Self {
         let original = registry.get_list(self).to_non_empty_vec();
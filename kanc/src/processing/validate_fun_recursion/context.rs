@@ -23,14 +23,22 @@ pub enum ReferenceRestriction {
 
 #[derive(Clone, Copy, Debug)]
 pub enum IndexOrLabel {
-    Index(usize),
+    Index {
+        /// The index of the arg when every arg is supplied explicitly.
+        index: usize,
+        /// The index of the arg when every instance arg is omitted
+        /// (and thus supplied by instance resolution).
+        index_without_instance_args: usize,
+        arity: usize,
+    },
     LabelId(NodeId<Identifier>),
 }
 
 impl Context {
-    pub fn new() -> Self {
+    pub fn new(registry: &NodeRegistry) -> Self {
+        // One entry for each universe the program names.
         Self {
-            stack: vec![ContextEntry::NoInformation, ContextEntry::NoInformation],
+            stack: vec![ContextEntry::NoInformation; registry.max_universe_level() + 1],
         }
     }
 }
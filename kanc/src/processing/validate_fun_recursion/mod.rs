@@ -26,7 +26,7 @@ pub fn validate_fun_recursion_in_file_items(
 ) -> Result<FunRecursionValidated<Option<NonEmptyListId<FileItemNodeId>>>, IllegalFunRecursionError>
 {
     let file_item_list_id = file_item_list_id.raw();
-    let mut context = Context::new();
+    let mut context = Context::new(registry);
     let item_ids = registry
         .get_possibly_empty_list(file_item_list_id)
         .to_vec()
@@ -119,6 +119,8 @@ fn validate_fun_recursion_in_type_statement_dirty(
         name_id: type_statement.name_id,
         param_list_id,
         variant_list_id,
+        is_struct: type_statement.is_struct,
+        is_trait: type_statement.is_trait,
     }))
 }
 
@@ -178,6 +180,7 @@ fn validate_fun_recursion_in_let_statement_dirty(
         transparency: let_statement.transparency,
         name_id: let_statement.name_id,
         value_id,
+        is_instance: let_statement.is_instance,
     }))
 }
 
@@ -218,6 +221,17 @@ fn validate_fun_recursion_in_expression_dirty(
         ExpressionId::Check(id) => {
             validate_fun_recursion_in_check_dirty(context, registry, id).map(ExpressionId::Check)?
         }
+        ExpressionId::Rewrite(id) => validate_fun_recursion_in_rewrite_dirty(context, registry, id)
+            .map(ExpressionId::Rewrite)?,
+        ExpressionId::Projection(id) => {
+            validate_fun_recursion_in_projection_dirty(context, registry, id)
+                .map(ExpressionId::Projection)?
+        }
+        ExpressionId::Exists(id) => validate_fun_recursion_in_exists_dirty(context, registry, id)
+            .map(ExpressionId::Exists)?,
+        ExpressionId::Pair(id) => {
+            validate_fun_recursion_in_pair_dirty(context, registry, id).map(ExpressionId::Pair)?
+        }
     })
 }
 
@@ -287,10 +301,19 @@ fn is_call_restricted(
                         ..
                     } => match (arg_position, call.arg_list_id) {
                         (
-                            IndexOrLabel::Index(index_of_arg_that_must_be_substruct),
+                            IndexOrLabel::Index {
+                                index,
+                                index_without_instance_args,
+                                arity,
+                            },
                             NonEmptyCallArgListId::Unlabeled(arg_list_id),
                         ) => {
                             let arg_ids = registry.get_list(arg_list_id);
+                            let index_of_arg_that_must_be_substruct = if arg_ids.len() == arity {
+                                index
+                            } else {
+                                index_without_instance_args
+                            };
                             if index_of_arg_that_must_be_substruct < arg_ids.len() {
                                 let expected_substruct_id =
                                     arg_ids[index_of_arg_that_must_be_substruct];
@@ -521,9 +544,18 @@ fn validate_fun_recursion_in_fun_dirty(
                 Some(param_position) => {
                     let superstruct_db_index = DbIndex(param_ids.len() - param_position - 1);
                     let superstruct_db_level = context.index_to_level(superstruct_db_index);
+                    let number_of_preceding_instance_params = param_ids[..param_position]
+                        .iter()
+                        .filter(|param_id| registry.get(**param_id).is_instance)
+                        .count();
                     ReferenceRestriction::MustCallWithSubstruct {
                         superstruct_db_level,
-                        arg_position: IndexOrLabel::Index(param_position),
+                        arg_position: IndexOrLabel::Index {
+                            index: param_position,
+                            index_without_instance_args: param_position
+                                - number_of_preceding_instance_params,
+                            arity: param_ids.len(),
+                        },
                     }
                 }
                 None => ReferenceRestriction::CannotCall,
@@ -632,6 +664,7 @@ fn validate_fun_recursion_in_unlabeled_params_and_leave_in_context_dirty(
             Ok(registry.add_and_overwrite_id(UnlabeledParam {
                 id: dummy_id(),
                 span: param.span,
+                is_instance: param.is_instance,
                 name_id: param.name_id,
                 type_id,
                 is_dashed: param.is_dashed,
@@ -653,13 +686,21 @@ fn validate_fun_recursion_in_labeled_params_and_leave_in_context_dirty(
             let param = registry.get(param_id).clone();
             let type_id =
                 validate_fun_recursion_in_expression_dirty(context, registry, param.type_id)?;
+            let default_id = param
+                .default_id
+                .map(|default_id| {
+                    validate_fun_recursion_in_expression_dirty(context, registry, default_id)
+                })
+                .transpose()?;
             context.push(ContextEntry::NoInformation)?;
             Ok(registry.add_and_overwrite_id(LabeledParam {
                 id: dummy_id(),
                 span: param.span,
+                is_instance: param.is_instance,
                 label_id: param.label_id,
                 name_id: param.name_id,
                 type_id,
+                default_id,
                 is_dashed: param.is_dashed,
             }))
         })?;
@@ -682,6 +723,10 @@ fn validate_fun_recursion_in_match_dirty(
         }
         _ => None,
     };
+    let motive_id = match_
+        .motive_id
+        .map(|motive_id| validate_fun_recursion_in_match_motive_dirty(context, registry, motive_id))
+        .transpose()?;
 
     let case_ids = registry
         .get_possibly_empty_list(match_.case_list_id)
@@ -697,10 +742,38 @@ fn validate_fun_recursion_in_match_dirty(
         id: dummy_id(),
         span: match_.span,
         matchee_id,
+        motive_id,
         case_list_id,
     }))
 }
 
+fn validate_fun_recursion_in_match_motive_dirty(
+    context: &mut Context,
+    registry: &mut NodeRegistry,
+    motive_id: NodeId<MatchMotive>,
+) -> Result<NodeId<MatchMotive>, TaintedIllegalFunRecursionError> {
+    let motive = registry.get(motive_id).clone();
+    let arity = motive.index_name_list_id.len() + 1;
+
+    // We don't need to validate the matchee type name,
+    // since the type checker will verify that it refers to an ADT.
+    for _ in 0..arity {
+        context.push(ContextEntry::NoInformation)?;
+    }
+    let output_id =
+        validate_fun_recursion_in_expression_dirty(context, registry, motive.output_id)?;
+    context.pop_n(arity);
+
+    Ok(registry.add_and_overwrite_id(MatchMotive {
+        id: dummy_id(),
+        span: motive.span,
+        matchee_name_id: motive.matchee_name_id,
+        matchee_type_name_id: motive.matchee_type_name_id,
+        index_name_list_id: motive.index_name_list_id,
+        output_id,
+    }))
+}
+
 fn validate_fun_recursion_in_match_case_dirty(
     context: &mut Context,
     registry: &mut NodeRegistry,
@@ -800,6 +873,87 @@ fn validate_fun_recursion_in_check_dirty(
     }))
 }
 
+fn validate_fun_recursion_in_rewrite_dirty(
+    context: &mut Context,
+    registry: &mut NodeRegistry,
+    rewrite_id: NodeId<Rewrite>,
+) -> Result<NodeId<Rewrite>, TaintedIllegalFunRecursionError> {
+    let rewrite = registry.get(rewrite_id).clone();
+    let proof_id = validate_fun_recursion_in_expression_dirty(context, registry, rewrite.proof_id)?;
+    let body_id = validate_fun_recursion_in_expression_dirty(context, registry, rewrite.body_id)?;
+    Ok(registry.add_and_overwrite_id(Rewrite {
+        id: dummy_id(),
+        span: rewrite.span,
+        eq_type_name_id: rewrite.eq_type_name_id,
+        eq_refl_name_id: rewrite.eq_refl_name_id,
+        proof_id,
+        body_id,
+    }))
+}
+
+fn validate_fun_recursion_in_projection_dirty(
+    context: &mut Context,
+    registry: &mut NodeRegistry,
+    projection_id: NodeId<Projection>,
+) -> Result<NodeId<Projection>, TaintedIllegalFunRecursionError> {
+    let projection = registry.get(projection_id).clone();
+    let record_id =
+        validate_fun_recursion_in_expression_dirty(context, registry, projection.record_id)?;
+    Ok(registry.add_and_overwrite_id(Projection {
+        id: dummy_id(),
+        span: projection.span,
+        record_id,
+        field_name_id: projection.field_name_id,
+    }))
+}
+
+fn validate_fun_recursion_in_exists_dirty(
+    context: &mut Context,
+    registry: &mut NodeRegistry,
+    exists_id: NodeId<Exists>,
+) -> Result<NodeId<Exists>, TaintedIllegalFunRecursionError> {
+    let exists = registry.get(exists_id).clone();
+    let param = registry.get(exists.param_id).clone();
+    let type_id = validate_fun_recursion_in_expression_dirty(context, registry, param.type_id)?;
+    let param_id = registry.add_and_overwrite_id(UnlabeledParam {
+        id: dummy_id(),
+        span: param.span,
+        is_instance: param.is_instance,
+        name_id: param.name_id,
+        type_id,
+        is_dashed: param.is_dashed,
+    });
+
+    context.push(ContextEntry::NoInformation)?;
+    let output_id =
+        validate_fun_recursion_in_expression_dirty(context, registry, exists.output_id)?;
+    context.pop_n(1);
+
+    Ok(registry.add_and_overwrite_id(Exists {
+        id: dummy_id(),
+        span: exists.span,
+        param_id,
+        output_id,
+    }))
+}
+
+fn validate_fun_recursion_in_pair_dirty(
+    context: &mut Context,
+    registry: &mut NodeRegistry,
+    pair_id: NodeId<Pair>,
+) -> Result<NodeId<Pair>, TaintedIllegalFunRecursionError> {
+    let pair = registry.get(pair_id).clone();
+    let witness_id =
+        validate_fun_recursion_in_expression_dirty(context, registry, pair.witness_id)?;
+    let proof_id = validate_fun_recursion_in_expression_dirty(context, registry, pair.proof_id)?;
+    Ok(registry.add_and_overwrite_id(Pair {
+        id: dummy_id(),
+        span: pair.span,
+        witness_id,
+        proof_id,
+    }))
+}
+
 fn validate_fun_recursion_in_check_assertions_dirty(
     context: &mut Context,
     registry: &mut NodeRegistry,
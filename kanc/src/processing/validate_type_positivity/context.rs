@@ -1,7 +1,5 @@
 use super::*;
 
-const NUMBER_OF_BUILTIN_ENTRIES: usize = 2;
-
 #[derive(Debug, Clone)]
 pub struct Context {
     stack: Vec<ContextEntryDefinition>,
@@ -16,15 +14,11 @@ pub enum ContextEntryDefinition {
 }
 
 impl Context {
-    pub fn with_builtins() -> Self {
-        // We should will never retrieve the type of `Type1`, since it is undefined.
-        // However, we need to store _some_ object in the stack, so that the indices
-        // of the other types are correct.
-        let type1_def = ContextEntryDefinition::Uninterpreted;
-        let type0_def = ContextEntryDefinition::Uninterpreted;
-        let builtins: [ContextEntryDefinition; NUMBER_OF_BUILTIN_ENTRIES] = [type1_def, type0_def];
+    pub fn with_builtins(registry: &NodeRegistry) -> Self {
+        // One entry for each universe the program names.
+        // Universes are neither ADTs nor variants, so they are uninterpreted.
         Self {
-            stack: builtins.to_vec(),
+            stack: vec![ContextEntryDefinition::Uninterpreted; registry.max_universe_level() + 1],
         }
     }
 }
@@ -102,6 +102,16 @@ pub fn verify_that_target_does_not_appear_in_expression(
         ExpressionId::Check(id) => {
             verify_that_target_does_not_appear_in_check_expression(registry, id, target)
         }
+        ExpressionId::Rewrite(id) => {
+            verify_that_target_does_not_appear_in_rewrite(registry, id, target)
+        }
+        ExpressionId::Projection(id) => {
+            verify_that_target_does_not_appear_in_projection(registry, id, target)
+        }
+        ExpressionId::Exists(id) => {
+            verify_that_target_does_not_appear_in_exists(registry, id, target)
+        }
+        ExpressionId::Pair(id) => verify_that_target_does_not_appear_in_pair(registry, id, target),
     }
 }
 
@@ -197,6 +207,11 @@ pub fn verify_that_target_does_not_appear_in_match(
 ) -> Result<(), TypePositivityError> {
     let match_ = registry.get(id);
     verify_that_target_does_not_appear_in_expression(registry, match_.matchee_id, target)?;
+    verify_that_target_does_not_appear_in_optional_match_motive(
+        registry,
+        match_.motive_id,
+        target,
+    )?;
     verify_that_target_does_not_appear_in_any_match_case_output(
         registry,
         match_.case_list_id,
@@ -205,6 +220,24 @@ pub fn verify_that_target_does_not_appear_in_match(
     Ok(())
 }
 
+pub fn verify_that_target_does_not_appear_in_optional_match_motive(
+    registry: &NodeRegistry,
+    id: Option<NodeId<MatchMotive>>,
+    target: DbIndex,
+) -> Result<(), TypePositivityError> {
+    let Some(id) = id else {
+        return Ok(());
+    };
+    let motive = registry.get(id);
+    verify_that_target_does_not_appear_in_expression(
+        registry,
+        ExpressionId::Name(motive.matchee_type_name_id),
+        target,
+    )?;
+    let output_target = DbIndex(target.0 + motive.index_name_list_id.len() + 1);
+    verify_that_target_does_not_appear_in_expression(registry, motive.output_id, output_target)
+}
+
 pub fn verify_that_target_does_not_appear_in_any_match_case_output(
     registry: &NodeRegistry,
     id: Option<NonEmptyListId<NodeId<MatchCase>>>,
@@ -268,6 +301,52 @@ pub fn verify_that_target_does_not_appear_in_check_expression(
     verify_that_target_does_not_appear_in_expression(registry, check.output_id, target)
 }
 
+pub fn verify_that_target_does_not_appear_in_rewrite(
+    registry: &NodeRegistry,
+    id: NodeId<Rewrite>,
+    target: DbIndex,
+) -> Result<(), TypePositivityError> {
+    let rewrite = registry.get(id);
+    verify_that_target_does_not_appear_in_expression(registry, rewrite.proof_id, target)?;
+    verify_that_target_does_not_appear_in_expression(registry, rewrite.body_id, target)
+}
+
+pub fn verify_that_target_does_not_appear_in_projection(
+    registry: &NodeRegistry,
+    id: NodeId<Projection>,
+    target: DbIndex,
+) -> Result<(), TypePositivityError> {
+    let projection = registry.get(id);
+    verify_that_target_does_not_appear_in_expression(registry, projection.record_id, target)
+}
+
+pub fn verify_that_target_does_not_appear_in_exists(
+    registry: &NodeRegistry,
+    id: NodeId<Exists>,
+    target: DbIndex,
+) -> Result<(), TypePositivityError> {
+    let exists = registry.get(id);
+    let param = registry.get(exists.param_id);
+
+    verify_that_target_does_not_appear_in_expression(registry, param.type_id, target)?;
+
+    let output_target = DbIndex(target.0 + 1);
+    verify_that_target_does_not_appear_in_expression(registry, exists.output_id, output_target)?;
+
+    Ok(())
+}
+
+pub fn verify_that_target_does_not_appear_in_pair(
+    registry: &NodeRegistry,
+    id: NodeId<Pair>,
+    target: DbIndex,
+) -> Result<(), TypePositivityError> {
+    let pair = registry.get(id);
+    verify_that_target_does_not_appear_in_expression(registry, pair.witness_id, target)?;
+    verify_that_target_does_not_appear_in_expression(registry, pair.proof_id, target)?;
+    Ok(())
+}
+
 pub fn does_target_appear_in_expression(
     registry: &NodeRegistry,
     id: ExpressionId,
@@ -30,7 +30,7 @@ pub fn validate_type_positivity_in_file_items(
     file_item_list_id: FunRecursionValidated<Option<NonEmptyListId<FileItemNodeId>>>,
 ) -> Result<TypePositivityValidated<Option<NonEmptyListId<FileItemNodeId>>>, TypePositivityError> {
     let file_item_list_id = file_item_list_id.raw();
-    let mut context = Context::with_builtins();
+    let mut context = Context::with_builtins(registry);
     let mut cache = TrustCache::empty();
     let item_ids = registry.get_possibly_empty_list(file_item_list_id).to_vec();
     for &item_id in &item_ids {
@@ -127,6 +127,18 @@ fn validate_type_positivity_in_expression(
         ExpressionId::Check(check_id) => {
             validate_type_positivity_in_check_expression(context, cache, registry, check_id, target)
         }
+        ExpressionId::Rewrite(rewrite_id) => {
+            validate_type_positivity_in_rewrite(context, cache, registry, rewrite_id, target)
+        }
+        ExpressionId::Projection(projection_id) => {
+            verify_that_target_does_not_appear_in_projection(registry, projection_id, target)
+        }
+        ExpressionId::Exists(exists_id) => {
+            validate_type_positivity_in_exists(context, cache, registry, exists_id, target)
+        }
+        ExpressionId::Pair(pair_id) => {
+            verify_that_target_does_not_appear_in_pair(registry, pair_id, target)
+        }
     }
 }
 
@@ -198,6 +210,11 @@ fn validate_type_positivity_in_match(
     let match_ = registry.get(id);
 
     verify_that_target_does_not_appear_in_expression(registry, match_.matchee_id, target)?;
+    verify_that_target_does_not_appear_in_optional_match_motive(
+        registry,
+        match_.motive_id,
+        target,
+    )?;
 
     validate_type_positivity_in_match_case_outputs(
         context,
@@ -288,6 +305,35 @@ fn validate_type_positivity_in_forall(
     Ok(())
 }
 
+/// Unlike a `forall` parameter type, an `exists` parameter type
+/// is a positive position, so we recursively validate it.
+fn validate_type_positivity_in_exists(
+    context: &mut Context,
+    cache: &mut TrustCache,
+    registry: &NodeRegistry,
+    id: NodeId<Exists>,
+    target: DbIndex,
+) -> Result<(), TypePositivityError> {
+    let exists = registry.get(id);
+    let param = registry.get(exists.param_id);
+
+    validate_type_positivity_in_expression(context, cache, registry, param.type_id, target)?;
+    context.push_n_uninterpreted(1);
+
+    let output_target = DbIndex(target.0 + 1);
+    validate_type_positivity_in_expression(
+        context,
+        cache,
+        registry,
+        exists.output_id,
+        output_target,
+    )?;
+
+    context.pop_n(1);
+
+    Ok(())
+}
+
 fn validate_type_positivity_in_check_expression(
     context: &mut Context,
     cache: &mut TrustCache,
@@ -299,6 +345,18 @@ fn validate_type_positivity_in_check_expression(
     validate_type_positivity_in_expression(context, cache, registry, check.output_id, target)
 }
 
+fn validate_type_positivity_in_rewrite(
+    context: &mut Context,
+    cache: &mut TrustCache,
+    registry: &NodeRegistry,
+    id: NodeId<Rewrite>,
+    target: DbIndex,
+) -> Result<(), TypePositivityError> {
+    let rewrite = registry.get(id);
+    verify_that_target_does_not_appear_in_expression(registry, rewrite.proof_id, target)?;
+    validate_type_positivity_in_expression(context, cache, registry, rewrite.body_id, target)
+}
+
 fn verify_type_param_is_positive(
     context_not_including_current_type_statement: &mut Context,
     cache: &mut TrustCache,
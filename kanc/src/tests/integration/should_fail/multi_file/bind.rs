@@ -420,3 +420,73 @@ fn visibility_stricter_than_transparency() {
         },
     );
 }
+
+#[test]
+fn notation_priv() {
+    expect_bind_error(
+        ProjectPath {
+            callee_file_path: file!(),
+            checked_unadjusted_pack_yscl_path: checked_path!(
+                "../../../sample_code/should_fail/multi_file/bind/notation_priv/pack.yscl"
+            ),
+        },
+        |err| match err {
+            BindError::NameIsPrivate(NameIsPrivateError {
+                name_component,
+                required_visibility: _,
+                actual_visibility: _,
+            }) => {
+                assert_eq!("+", name_component.name.src_str());
+            }
+            _ => panic!("Unexpected error: {:?}", err),
+        },
+    );
+}
+
+#[test]
+fn leaky_notation() {
+    expect_bind_error(
+        ProjectPath {
+            callee_file_path: file!(),
+            checked_unadjusted_pack_yscl_path: checked_path!(
+                "../../../sample_code/should_fail/multi_file/bind/leaky_notation/pack.yscl"
+            ),
+        },
+        |err| match err {
+            BindError::CannotLeakPrivateName(CannotLeakPrivateNameError {
+                name_component,
+                required_visibility: _,
+                actual_visibility: _,
+            }) => {
+                assert_eq!("add", name_component.name.src_str());
+            }
+            _ => panic!("Unexpected error: {:?}", err),
+        },
+    );
+}
+
+#[test]
+fn notation_function_is_mod() {
+    expect_bind_error(
+        ProjectPath {
+            callee_file_path: file!(),
+            checked_unadjusted_pack_yscl_path: checked_path!(
+                "../../../sample_code/should_fail/multi_file/bind/notation_function_is_mod/pack.yscl"
+            ),
+        },
+        |err| match err {
+            BindError::ExpectedTermButNameRefersToMod(ExpectedTermButNameRefersToModError {
+                name_components,
+            }) => {
+                assert_eq!(
+                    vec!["nat"],
+                    name_components
+                        .iter()
+                        .map(|c| c.name.src_str())
+                        .collect::<Vec<_>>()
+                );
+            }
+            _ => panic!("Unexpected error: {:?}", err),
+        },
+    );
+}
@@ -27,6 +27,49 @@ fn illegal_dot_lhs() {
     });
 }
 
+#[test]
+fn illegal_match_motive_matchee_type() {
+    let src = include_str!(
+        "../../../sample_code/should_fail/single_file/ast_simplification/match_motive/illegal_matchee_type.k"
+    );
+    expect_simplification_error(src, |err| match err {
+        SimplifyAstError::IllegalMatchMotiveMatcheeType(matchee_type) => {
+            assert!(
+                matches!(matchee_type, ust::Expression::Call(_)),
+                "Unexpected matchee type {:?}",
+                matchee_type
+            );
+        }
+        other_err => panic!("Unexpected error: {:#?}", other_err),
+    });
+}
+
+#[test]
+fn labeled_exists_param() {
+    let src = include_str!(
+        "../../../sample_code/should_fail/single_file/ast_simplification/exists/labeled_param.k"
+    );
+    expect_simplification_error(src, |err| match err {
+        SimplifyAstError::LabeledExistsParam(param) => {
+            assert_eq!("n", param.name.name.src_str());
+        }
+        other_err => panic!("Unexpected error: {:#?}", other_err),
+    });
+}
+
+#[test]
+fn unknown_derive() {
+    let src = include_str!(
+        "../../../sample_code/should_fail/single_file/ast_simplification/derive/unknown_derive.k"
+    );
+    expect_simplification_error(src, |err| match err {
+        SimplifyAstError::UnknownDerive(name) => {
+            assert_eq!("ord", name.name.src_str());
+        }
+        other_err => panic!("Unexpected error: {:#?}", other_err),
+    });
+}
+
 mod labeled_params {
     use super::*;
 
@@ -136,6 +179,32 @@ mod labeled_params {
             });
         }
     }
+
+    mod default {
+        use super::*;
+
+        #[test]
+        fn unlabeled_param() {
+            let src = include_str!("../../../sample_code/should_fail/single_file/ast_simplification/labeled_params/default/unlabeled_param.k");
+            expect_simplification_error(src, |err| match err {
+                SimplifyAstError::UnlabeledParamDefault(param) => {
+                    assert_eq!("x", param.name.name.src_str());
+                }
+                other_err => panic!("Unexpected error: {:#?}", other_err),
+            });
+        }
+
+        #[test]
+        fn instance_param() {
+            let src = include_str!("../../../sample_code/should_fail/single_file/ast_simplification/labeled_params/default/instance_param.k");
+            expect_simplification_error(src, |err| match err {
+                SimplifyAstError::InstanceParamDefault(param) => {
+                    assert_eq!("d", param.name.name.src_str());
+                }
+                other_err => panic!("Unexpected error: {:#?}", other_err),
+            });
+        }
+    }
 }
 
 mod labeled_call_args {
@@ -329,3 +398,50 @@ mod labeled_match_case_params {
         }
     }
 }
+
+mod notation {
+    use super::*;
+
+    #[test]
+    fn illegal_function() {
+        let src = include_str!(
+            "../../../sample_code/should_fail/single_file/ast_simplification/notation/illegal_function.k"
+        );
+        expect_simplification_error(src, |err| match err {
+            SimplifyAstError::IllegalNotationFunction(function) => {
+                assert!(
+                    matches!(function, ust::Expression::Fun(_)),
+                    "Unexpected function {:?}",
+                    function
+                );
+            }
+            other_err => panic!("Unexpected error: {:#?}", other_err),
+        });
+    }
+
+    #[test]
+    fn unknown_precedence() {
+        let src = include_str!(
+            "../../../sample_code/should_fail/single_file/ast_simplification/notation/unknown_precedence.k"
+        );
+        expect_simplification_error(src, |err| match err {
+            SimplifyAstError::UnknownNotationPrecedence(precedence) => {
+                assert_eq!("addition", precedence.name.src_str());
+            }
+            other_err => panic!("Unexpected error: {:#?}", other_err),
+        });
+    }
+
+    #[test]
+    fn unknown_associativity() {
+        let src = include_str!(
+            "../../../sample_code/should_fail/single_file/ast_simplification/notation/unknown_associativity.k"
+        );
+        expect_simplification_error(src, |err| match err {
+            SimplifyAstError::UnknownNotationAssociativity(associativity) => {
+                assert_eq!("both", associativity.name.src_str());
+            }
+            other_err => panic!("Unexpected error: {:#?}", other_err),
+        });
+    }
+}
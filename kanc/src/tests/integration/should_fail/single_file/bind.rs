@@ -177,3 +177,64 @@ fn ref_underscore_fun() {
         include_str!("../../../sample_code/should_fail/single_file/bind/ref_underscore_fun.k");
     expect_underscore_not_found_error(src);
 }
+
+#[test]
+fn rewrite_without_designated_eq() {
+    let src = include_str!(
+        "../../../sample_code/should_fail/single_file/bind/rewrite_without_designated_eq.k"
+    );
+    expect_bind_error(src, |err| match err {
+        BindError::RewriteWithoutDesignatedEq(_) => {}
+        _ => panic!("Unexpected error: {:#?}", err),
+    });
+}
+
+#[test]
+fn derive_without_designated_bool() {
+    let src = include_str!(
+        "../../../sample_code/should_fail/single_file/bind/derive_without_designated_bool.k"
+    );
+    expect_bind_error(src, |err| match err {
+        BindError::DeriveWithoutDesignatedBool(_) => {}
+        _ => panic!("Unexpected error: {:#?}", err),
+    });
+}
+
+fn expect_ambiguous_infix_operators_error(
+    src: &str,
+    expected_left_operator: &str,
+    expected_right_operator: &str,
+) {
+    expect_bind_error(src, |err| match err {
+        BindError::AmbiguousInfixOperators(AmbiguousInfixOperatorsError {
+            left_operator,
+            right_operator,
+        }) => {
+            assert_eq!(expected_left_operator, left_operator.name.src_str());
+            assert_eq!(expected_right_operator, right_operator.name.src_str());
+        }
+        _ => panic!("Unexpected error: {:#?}", err),
+    });
+}
+
+#[test]
+fn chained_non_associative_operators() {
+    let src = include_str!(
+        "../../../sample_code/should_fail/single_file/bind/ambiguous_infix_operators.k"
+    );
+    expect_ambiguous_infix_operators_error(src, "<", "<");
+}
+
+#[test]
+fn mixed_associativity_operators() {
+    let src =
+        include_str!("../../../sample_code/should_fail/single_file/bind/mixed_associativity.k");
+    expect_ambiguous_infix_operators_error(src, "+", "<>");
+}
+
+#[test]
+fn ref_undeclared_operator() {
+    let src =
+        include_str!("../../../sample_code/should_fail/single_file/bind/ref_undeclared_operator.k");
+    expect_name_not_found_error(src, ["+"]);
+}
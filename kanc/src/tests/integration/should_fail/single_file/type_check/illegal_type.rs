@@ -2,9 +2,9 @@ use super::*;
 
 fn expect_illegal_type_error(src: &str, expected_illegal_type_src: &str) {
     expect_type_check_error(src, |registry, err| match err {
-        TypeCheckError::ExpectedTermOfTypeType0OrType1 {
+        TypeCheckError::ExpectedTermOfTypeUniverse {
             expression_id,
-            non_type0_or_type1_type_id: _,
+            non_universe_type_id: _,
         } => {
             let actual_src = format_expression(
                 &expand_expression(registry, expression_id),
@@ -70,3 +70,11 @@ fn multiple_missing_args() {
     );
     expect_missing_labeled_call_arg_type_error(src, ["g", "b"]);
 }
+
+#[test]
+fn missing_undefaulted_arg() {
+    let src = include_str!(
+        "../../../../sample_code/should_fail/single_file/type_check/labeled_call_args/missing_undefaulted_arg.k"
+    );
+    expect_missing_labeled_call_arg_type_error(src, ["step"]);
+}
@@ -27,3 +27,11 @@ fn match_case_incorrectly_marked_impossible() {
     );
     expect_match_case_incorrectly_marked_impossible_error(src, ".o => impossible,");
 }
+
+#[test]
+fn non_constructor_occurrence_marked_impossible() {
+    let src = include_str!(
+        "../../../../sample_code/should_fail/single_file/type_check/non_constructor_occurrence_marked_impossible.k"
+    );
+    expect_match_case_incorrectly_marked_impossible_error(src, ".refl(_) => impossible,");
+}
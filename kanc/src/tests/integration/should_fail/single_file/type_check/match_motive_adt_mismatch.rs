@@ -0,0 +1,35 @@
+use super::*;
+
+fn expect_match_motive_adt_mismatch_error(
+    src: &str,
+    expected_motive_matchee_name: &str,
+    expected_matchee_type_src: &str,
+) {
+    expect_type_check_error(src, |registry, err| match err {
+        TypeCheckError::MatchMotiveAdtMismatch {
+            motive_id,
+            matchee_type_id,
+        } => {
+            let motive = registry.get(motive_id);
+            assert_eq!(
+                expected_motive_matchee_name,
+                registry.get(motive.matchee_name_id).name.src_str()
+            );
+            let actual_matchee_type_src = format_expression(
+                &expand_expression(registry, matchee_type_id.raw()),
+                0,
+                &FORMAT_OPTIONS_FOR_COMPARISON,
+            );
+            assert_eq_up_to_white_space(expected_matchee_type_src, &actual_matchee_type_src);
+        }
+        _ => panic!("Unexpected error: {:#?}", err),
+    });
+}
+
+#[test]
+fn match_motive_adt_mismatch() {
+    let src = include_str!(
+        "../../../../sample_code/should_fail/single_file/type_check/match_motive_adt_mismatch.k"
+    );
+    expect_match_motive_adt_mismatch_error(src, "b'", "Bool");
+}
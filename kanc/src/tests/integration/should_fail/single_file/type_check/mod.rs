@@ -12,17 +12,28 @@ mod labeled_call_args;
 mod labeled_match_case_params;
 mod match_case_incorrectly_marked_impossible;
 mod match_case_param_labeledness_mismatch;
+mod match_motive_adt_mismatch;
 mod missing_match_case;
 mod non_adt_matchee;
+mod reduction_step_limit_exceeded;
 mod type_mismatch;
 mod universe_inconsistency;
 mod unreachable_expression;
 mod wrong_number_of_args;
 mod wrong_number_of_case_params;
+mod wrong_number_of_match_motive_indices;
 
 /// The job of `panicker` is to panic if the error is different than the expected
 /// error.
 fn expect_type_check_error(src: &str, panicker: impl Fn(&NodeRegistry, TypeCheckError)) {
+    expect_type_check_error_with_options(src, TypeCheckOptions::default(), panicker);
+}
+
+fn expect_type_check_error_with_options(
+    src: &str,
+    options: TypeCheckOptions,
+    panicker: impl Fn(&NodeRegistry, TypeCheckError),
+) {
     let file_id = FileId(0);
     let tokens = lex(src).expect("Lexing failed");
     let file = parse_file(tokens, file_id).expect("Parsing failed");
@@ -40,10 +51,11 @@ fn expect_type_check_error(src: &str, panicker: impl Fn(&NodeRegistry, TypeCheck
     let file_item_list_id =
         validate_type_positivity_in_file_items(&mut registry, file_item_list_id)
             .expect("Type positivity validation failed");
-    let err = type_check_file_items(
+    let err = type_check_file_items_with_options(
         &FileTree::from_root(file_id),
         &mut registry,
         file_item_list_id,
+        options,
     )
     .expect_err("Type checking unexpectedly succeeded");
     panicker(&registry, err);
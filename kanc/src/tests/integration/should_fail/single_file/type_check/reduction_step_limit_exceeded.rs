@@ -0,0 +1,36 @@
+use super::*;
+
+fn expect_reduction_step_limit_exceeded_error(evaluator: Evaluator) {
+    let src = include_str!(
+        "../../../../sample_code/should_fail/single_file/type_check/reduction_step_limit_exceeded.k"
+    );
+    let options = TypeCheckOptions {
+        evaluator,
+        max_reduction_steps: Some(200),
+        ..TypeCheckOptions::default()
+    };
+    expect_type_check_error_with_options(src, options, |registry, err| match err {
+        TypeCheckError::ReductionStepLimitExceeded {
+            unfolding_let_id,
+            max_reduction_steps,
+            ..
+        } => {
+            assert_eq!(200, max_reduction_steps);
+            let unfolding_let_id =
+                unfolding_let_id.expect("The limit should be exceeded while unfolding a `let`.");
+            let name_id = registry.get(unfolding_let_id).name_id;
+            assert_eq!("plus", registry.get(name_id).name.src_str());
+        }
+        _ => panic!("Unexpected error: {:#?}", err),
+    });
+}
+
+#[test]
+fn nbe() {
+    expect_reduction_step_limit_exceeded_error(Evaluator::NormalizationByEvaluation);
+}
+
+#[test]
+fn substitution() {
+    expect_reduction_step_limit_exceeded_error(Evaluator::Substitution);
+}
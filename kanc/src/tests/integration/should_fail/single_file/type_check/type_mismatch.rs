@@ -107,3 +107,32 @@ fn wrong_empty() {
     );
     expect_type_mismatch_error(src, "e1", "Empty2", "Empty1");
 }
+
+#[test]
+fn non_eta_expansion() {
+    let src = include_str!(
+        "../../../../sample_code/should_fail/single_file/type_check/type_mismatch/non_eta_expansion.k"
+    );
+    expect_type_mismatch_error(
+        src,
+        "Eq.refl(forall(n: Nat,) { Nat }, f,)",
+        "Eq(forall(n: Nat,) { Nat }, f, fun _(m: Nat,): Nat { f(Nat.s(m,),) },)",
+        "Eq(forall(n: Nat,) { Nat }, f, f,)",
+    );
+}
+
+#[test]
+fn universe_too_large() {
+    let src = include_str!(
+        "../../../../sample_code/should_fail/single_file/type_check/type_mismatch/universe_too_large.k"
+    );
+    expect_type_mismatch_error(src, "Type", "Type", "Type1");
+}
+
+#[test]
+fn ill_typed_param_default() {
+    let src = include_str!(
+        "../../../../sample_code/should_fail/single_file/type_check/type_mismatch/ill_typed_param_default.k"
+    );
+    expect_type_mismatch_error(src, "U2.u2", "U1", "U2");
+}
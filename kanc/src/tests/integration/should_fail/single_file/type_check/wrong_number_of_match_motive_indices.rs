@@ -0,0 +1,25 @@
+use super::*;
+
+fn expect_wrong_number_of_match_motive_indices_error(
+    src: &str,
+    expected_expected: usize,
+    expected_actual: usize,
+) {
+    expect_type_check_error(src, |_registry, err| match err {
+        TypeCheckError::WrongNumberOfMatchMotiveIndices {
+            expected, actual, ..
+        } => {
+            assert_eq!(expected_expected, expected);
+            assert_eq!(expected_actual, actual);
+        }
+        _ => panic!("Unexpected error: {:#?}", err),
+    });
+}
+
+#[test]
+fn wrong_number_of_match_motive_indices() {
+    let src = include_str!(
+        "../../../../sample_code/should_fail/single_file/type_check/wrong_number_of_match_motive_indices.k"
+    );
+    expect_wrong_number_of_match_motive_indices_error(src, 2, 1);
+}
@@ -0,0 +1,107 @@
+use super::*;
+
+use std::time::{Duration, Instant};
+
+fn type_check_and_time(project_path: ProjectPath, evaluator: Evaluator) -> (usize, Duration) {
+    let (files, file_tree) = get_files_and_file_tree(project_path);
+    let file_items = bind_files(file_tree.root(), files, &file_tree).expect("Binding failed");
+    let mut registry = NodeRegistry::empty();
+    let file_item_list_id = register_file_items(&mut registry, file_items);
+
+    let file_item_list_id =
+        validate_variant_return_types_in_file_items(&registry, file_item_list_id)
+            .expect("Variant return type validation failed");
+    let file_item_list_id = validate_fun_recursion_in_file_items(&mut registry, file_item_list_id)
+        .expect("Fun recursion validation failed");
+    let file_item_list_id = validate_type_positivity_in_file_items(&registry, file_item_list_id)
+        .expect("Type positivity validation failed");
+
+    let start = Instant::now();
+    let output = type_check_file_items_with_options(
+        &file_tree,
+        &mut registry,
+        file_item_list_id,
+        TypeCheckOptions {
+            evaluator,
+            ..TypeCheckOptions::default()
+        },
+    )
+    .expect("Type checking failed");
+    (output.warnings.len(), start.elapsed())
+}
+
+fn compare_evaluators(name: &str, project_path: ProjectPath) {
+    let (nbe_warning_count, nbe_duration) =
+        type_check_and_time(project_path, Evaluator::NormalizationByEvaluation);
+    let (substitution_warning_count, substitution_duration) =
+        type_check_and_time(project_path, Evaluator::Substitution);
+    assert_eq!(
+        nbe_warning_count, substitution_warning_count,
+        "The evaluators produced a different number of warnings for {}",
+        name
+    );
+    println!(
+        "{}: nbe = {:?}, substitution = {:?}",
+        name, nbe_duration, substitution_duration
+    );
+}
+
+// Run with `cargo test --release evaluator_benchmark -- --ignored --nocapture`.
+#[ignore]
+#[test]
+fn evaluator_benchmark() {
+    compare_evaluators(
+        "factorial",
+        ProjectPath {
+            callee_file_path: file!(),
+            checked_unadjusted_pack_yscl_path: checked_path!(
+                "../../../sample_code/should_succeed/multi_file/no_warnings/factorial/pack.yscl"
+            ),
+        },
+    );
+    compare_evaluators(
+        "plus_commutative",
+        ProjectPath {
+            callee_file_path: file!(),
+            checked_unadjusted_pack_yscl_path: checked_path!(
+                "../../../sample_code/should_succeed/multi_file/no_warnings/plus_commutative/pack.yscl"
+            ),
+        },
+    );
+    compare_evaluators(
+        "opaque_nat",
+        ProjectPath {
+            callee_file_path: file!(),
+            checked_unadjusted_pack_yscl_path: checked_path!(
+                "../../../sample_code/should_succeed/multi_file/no_warnings/opaque_nat/pack.yscl"
+            ),
+        },
+    );
+    compare_evaluators(
+        "identity_eq_transparent",
+        ProjectPath {
+            callee_file_path: file!(),
+            checked_unadjusted_pack_yscl_path: checked_path!(
+                "../../../sample_code/should_succeed/multi_file/no_warnings/identity_eq_transparent/pack.yscl"
+            ),
+        },
+    );
+    compare_evaluators(
+        "stdlib",
+        ProjectPath {
+            callee_file_path: file!(),
+            checked_unadjusted_pack_yscl_path: checked_path!(
+                "../../../sample_code/should_succeed/multi_file/no_warnings/stdlib/pack.yscl"
+            ),
+        },
+    );
+    compare_evaluators(
+        "pong",
+        ProjectPath {
+            callee_file_path: file!(),
+            checked_unadjusted_pack_yscl_path: checked_path!(
+                "../../../sample_code/should_succeed/multi_file/no_warnings/pong/pack.yscl"
+            ),
+        },
+    );
+}
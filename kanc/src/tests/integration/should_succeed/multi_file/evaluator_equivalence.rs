@@ -0,0 +1,277 @@
+use super::*;
+
+use crate::processing::type_check::PrecomputedLets;
+
+/// Returns the formatted warnings and the precomputed lets
+/// (i.e., the normal forms of the closed variant-valued lets).
+fn type_check_with_evaluator(
+    project_path: ProjectPath,
+    evaluator: Evaluator,
+) -> (Vec<String>, PrecomputedLets) {
+    let (files, file_tree) = get_files_and_file_tree(project_path);
+    let file_items = bind_files(file_tree.root(), files, &file_tree).expect("Binding failed");
+    let mut registry = NodeRegistry::empty();
+    let file_item_list_id = register_file_items(&mut registry, file_items);
+
+    let file_item_list_id =
+        validate_variant_return_types_in_file_items(&registry, file_item_list_id)
+            .expect("Variant return type validation failed");
+    let file_item_list_id = validate_fun_recursion_in_file_items(&mut registry, file_item_list_id)
+        .expect("Fun recursion validation failed");
+    let file_item_list_id = validate_type_positivity_in_file_items(&registry, file_item_list_id)
+        .expect("Type positivity validation failed");
+
+    let output = type_check_file_items_with_options(
+        &file_tree,
+        &mut registry,
+        file_item_list_id,
+        TypeCheckOptions {
+            evaluator,
+            ..TypeCheckOptions::default()
+        },
+    )
+    .expect("Type checking failed");
+    (
+        format_actual_warnings(&registry, &output.warnings),
+        output.precomputed_lets,
+    )
+}
+
+fn expect_evaluators_to_agree(project_path: ProjectPath) {
+    let (nbe_warnings, nbe_precomputed_lets) =
+        type_check_with_evaluator(project_path, Evaluator::NormalizationByEvaluation);
+    let (substitution_warnings, substitution_precomputed_lets) =
+        type_check_with_evaluator(project_path, Evaluator::Substitution);
+    assert_eq!(nbe_warnings, substitution_warnings);
+    assert_eq!(nbe_precomputed_lets, substitution_precomputed_lets);
+}
+
+#[test]
+fn alternate_name() {
+    expect_evaluators_to_agree(ProjectPath {
+        callee_file_path: file!(),
+        checked_unadjusted_pack_yscl_path: checked_path!(
+            "../../../sample_code/should_succeed/multi_file/no_warnings/alternate_name/pack.yscl"
+        ),
+    });
+}
+
+#[test]
+fn bigint() {
+    expect_evaluators_to_agree(ProjectPath {
+        callee_file_path: file!(),
+        checked_unadjusted_pack_yscl_path: checked_path!(
+            "../../../sample_code/should_succeed/multi_file/no_warnings/bigint/pack.yscl"
+        ),
+    });
+}
+
+#[test]
+fn check_args() {
+    expect_evaluators_to_agree(ProjectPath {
+        callee_file_path: file!(),
+        checked_unadjusted_pack_yscl_path: checked_path!(
+            "../../../sample_code/should_succeed/multi_file/no_warnings/check_args/pack.yscl"
+        ),
+    });
+}
+
+#[test]
+fn entry_points() {
+    expect_evaluators_to_agree(ProjectPath {
+        callee_file_path: file!(),
+        checked_unadjusted_pack_yscl_path: checked_path!(
+            "../../../sample_code/should_succeed/multi_file/no_warnings/entry_points/pack.yscl"
+        ),
+    });
+}
+
+#[test]
+fn erasure() {
+    expect_evaluators_to_agree(ProjectPath {
+        callee_file_path: file!(),
+        checked_unadjusted_pack_yscl_path: checked_path!(
+            "../../../sample_code/should_succeed/multi_file/no_warnings/erasure/pack.yscl"
+        ),
+    });
+}
+
+#[test]
+fn factorial() {
+    expect_evaluators_to_agree(ProjectPath {
+        callee_file_path: file!(),
+        checked_unadjusted_pack_yscl_path: checked_path!(
+            "../../../sample_code/should_succeed/multi_file/no_warnings/factorial/pack.yscl"
+        ),
+    });
+}
+
+#[test]
+fn identity_eq_transparent() {
+    expect_evaluators_to_agree(ProjectPath {
+        callee_file_path: file!(),
+        checked_unadjusted_pack_yscl_path: checked_path!(
+            "../../../sample_code/should_succeed/multi_file/no_warnings/identity_eq_transparent/pack.yscl"
+        ),
+    });
+}
+
+#[test]
+fn import_merging() {
+    expect_evaluators_to_agree(ProjectPath {
+        callee_file_path: file!(),
+        checked_unadjusted_pack_yscl_path: checked_path!(
+            "../../../sample_code/should_succeed/multi_file/no_warnings/import_merging/pack.yscl"
+        ),
+    });
+}
+
+#[test]
+fn js_exports() {
+    expect_evaluators_to_agree(ProjectPath {
+        callee_file_path: file!(),
+        checked_unadjusted_pack_yscl_path: checked_path!(
+            "../../../sample_code/should_succeed/multi_file/no_warnings/js_exports/pack.yscl"
+        ),
+    });
+}
+
+#[test]
+fn let_not_leaky_because_its_opaque() {
+    expect_evaluators_to_agree(ProjectPath {
+        callee_file_path: file!(),
+        checked_unadjusted_pack_yscl_path: checked_path!(
+            "../../../sample_code/should_succeed/multi_file/no_warnings/let_not_leaky_because_its_opaque/pack.yscl"
+        ),
+    });
+}
+
+#[test]
+fn no_clash_because_priv() {
+    expect_evaluators_to_agree(ProjectPath {
+        callee_file_path: file!(),
+        checked_unadjusted_pack_yscl_path: checked_path!(
+            "../../../sample_code/should_succeed/multi_file/no_warnings/no_clash_because_priv/pack.yscl"
+        ),
+    });
+}
+
+#[test]
+fn notation_arithmetic() {
+    expect_evaluators_to_agree(ProjectPath {
+        callee_file_path: file!(),
+        checked_unadjusted_pack_yscl_path: checked_path!(
+            "../../../sample_code/should_succeed/multi_file/no_warnings/notation_arithmetic/pack.yscl"
+        ),
+    });
+}
+
+#[test]
+fn notation_import() {
+    expect_evaluators_to_agree(ProjectPath {
+        callee_file_path: file!(),
+        checked_unadjusted_pack_yscl_path: checked_path!(
+            "../../../sample_code/should_succeed/multi_file/no_warnings/notation_import/pack.yscl"
+        ),
+    });
+}
+
+#[test]
+fn opaque_nat() {
+    expect_evaluators_to_agree(ProjectPath {
+        callee_file_path: file!(),
+        checked_unadjusted_pack_yscl_path: checked_path!(
+            "../../../sample_code/should_succeed/multi_file/no_warnings/opaque_nat/pack.yscl"
+        ),
+    });
+}
+
+#[test]
+fn optimize() {
+    expect_evaluators_to_agree(ProjectPath {
+        callee_file_path: file!(),
+        checked_unadjusted_pack_yscl_path: checked_path!(
+            "../../../sample_code/should_succeed/multi_file/no_warnings/optimize/pack.yscl"
+        ),
+    });
+}
+
+#[test]
+fn pack_relative_with_alias() {
+    expect_evaluators_to_agree(ProjectPath {
+        callee_file_path: file!(),
+        checked_unadjusted_pack_yscl_path: checked_path!(
+            "../../../sample_code/should_succeed/multi_file/no_warnings/pack_relative_with_alias/pack.yscl"
+        ),
+    });
+}
+
+#[test]
+fn plus_commutative() {
+    expect_evaluators_to_agree(ProjectPath {
+        callee_file_path: file!(),
+        checked_unadjusted_pack_yscl_path: checked_path!(
+            "../../../sample_code/should_succeed/multi_file/no_warnings/plus_commutative/pack.yscl"
+        ),
+    });
+}
+
+#[test]
+fn pong() {
+    expect_evaluators_to_agree(ProjectPath {
+        callee_file_path: file!(),
+        checked_unadjusted_pack_yscl_path: checked_path!(
+            "../../../sample_code/should_succeed/multi_file/no_warnings/pong/pack.yscl"
+        ),
+    });
+}
+
+#[test]
+fn source_map() {
+    expect_evaluators_to_agree(ProjectPath {
+        callee_file_path: file!(),
+        checked_unadjusted_pack_yscl_path: checked_path!(
+            "../../../sample_code/should_succeed/multi_file/no_warnings/source_map/pack.yscl"
+        ),
+    });
+}
+
+#[test]
+fn stack_safe() {
+    expect_evaluators_to_agree(ProjectPath {
+        callee_file_path: file!(),
+        checked_unadjusted_pack_yscl_path: checked_path!(
+            "../../../sample_code/should_succeed/multi_file/no_warnings/stack_safe/pack.yscl"
+        ),
+    });
+}
+
+#[test]
+fn stdlib() {
+    expect_evaluators_to_agree(ProjectPath {
+        callee_file_path: file!(),
+        checked_unadjusted_pack_yscl_path: checked_path!(
+            "../../../sample_code/should_succeed/multi_file/no_warnings/stdlib/pack.yscl"
+        ),
+    });
+}
+
+#[test]
+fn traits() {
+    expect_evaluators_to_agree(ProjectPath {
+        callee_file_path: file!(),
+        checked_unadjusted_pack_yscl_path: checked_path!(
+            "../../../sample_code/should_succeed/multi_file/no_warnings/traits/pack.yscl"
+        ),
+    });
+}
+
+#[test]
+fn ts_declarations() {
+    expect_evaluators_to_agree(ProjectPath {
+        callee_file_path: file!(),
+        checked_unadjusted_pack_yscl_path: checked_path!(
+            "../../../sample_code/should_succeed/multi_file/no_warnings/ts_declarations/pack.yscl"
+        ),
+    });
+}
@@ -1,3 +1,6 @@
 use super::*;
 
 mod no_warnings;
+
+mod evaluator_benchmark;
+mod evaluator_equivalence;
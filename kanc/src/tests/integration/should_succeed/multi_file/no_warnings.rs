@@ -1,6 +1,44 @@
 use super::*;
 
-fn expect_success_with_no_warnings(project_path: ProjectPath) {
+use crate::data::node_registry::NonEmptyListId;
+use crate::data::non_empty_vec::NonEmptyVec;
+use crate::processing::find_unreachable_items::find_unreachable_items;
+use crate::processing::generate_code::targets::javascript::{
+    format::{format_declaration_file, format_file, format_file_with_source_map, FormatOptions},
+    js_ast,
+    source_map::{SourceFile, SourceFiles},
+    CompileToJavaScriptWarning, JavaScriptFiles, JavaScriptOptions,
+};
+
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+fn expect_success_with_no_warnings(project_path: ProjectPath) -> JavaScriptFiles {
+    expect_success_with_no_warnings_and_options(
+        project_path,
+        TypeCheckOptions::default(),
+        |_, options| options,
+    )
+}
+
+/// What code generation takes, besides its options.
+struct CodeGenInput<'a> {
+    registry: &'a NodeRegistry,
+    file_tree: &'a FileTree,
+    file_item_list_id: Option<NonEmptyListId<FileItemNodeId>>,
+}
+
+/// Like `expect_success_with_no_warnings`, but type checks with
+/// `type_check_options`, and generates code with the options
+/// that `override_js_options` returns.
+/// `override_js_options` is passed the options that `kanc` uses
+/// by default (i.e., the type checker's erasure analysis,
+/// BigInt analysis, and precomputed lets).
+fn expect_success_with_no_warnings_and_options(
+    project_path: ProjectPath,
+    type_check_options: TypeCheckOptions,
+    override_js_options: impl FnOnce(&CodeGenInput, JavaScriptOptions) -> JavaScriptOptions,
+) -> JavaScriptFiles {
     let (files, file_tree) = get_files_and_file_tree(project_path);
     let file_items = bind_files(file_tree.root(), files, &file_tree).expect("Binding failed");
     let mut registry = NodeRegistry::empty();
@@ -11,14 +49,39 @@ fn expect_success_with_no_warnings(project_path: ProjectPath) {
             .expect("Variant return type validation failed");
     let file_item_list_id = validate_fun_recursion_in_file_items(&mut registry, file_item_list_id)
         .expect("Fun recursion validation failed");
-    let file_item_list_id =
-        validate_type_positivity_in_file_items(&mut registry, file_item_list_id)
-            .expect("Type positivity validation failed");
-    let warnings = type_check_file_items(&file_tree, &mut registry, file_item_list_id)
-        .expect("Type checking failed");
-    assert_eq!(0, warnings.len(), "One or more warnings were emitted");
-    let _js_ast = JavaScript::generate_code(&registry, file_item_list_id.raw())
-        .expect("Code generation failed");
+    let file_item_list_id = validate_type_positivity_in_file_items(&registry, file_item_list_id)
+        .expect("Type positivity validation failed");
+    let output = type_check_file_items_with_options(
+        &file_tree,
+        &mut registry,
+        file_item_list_id,
+        type_check_options,
+    )
+    .expect("Type checking failed");
+    assert_eq!(
+        0,
+        output.warnings.len(),
+        "One or more warnings were emitted"
+    );
+
+    let input = CodeGenInput {
+        registry: &registry,
+        file_tree: &file_tree,
+        file_item_list_id: file_item_list_id.raw(),
+    };
+    // Like `kanc`, we erase what the type checker found erasable,
+    // so the generated code has the same calling convention.
+    let options = override_js_options(
+        &input,
+        JavaScriptOptions {
+            erasure: output.erasure,
+            bigint: output.bigint,
+            precomputed_lets: output.precomputed_lets,
+            ..JavaScriptOptions::default()
+        },
+    );
+    JavaScript::generate_code_with_options(&registry, &file_tree, file_item_list_id.raw(), options)
+        .expect("Code generation failed")
 }
 
 #[test]
@@ -120,3 +183,877 @@ fn stdlib() {
         ),
     });
 }
+
+#[test]
+fn traits() {
+    expect_success_with_no_warnings(ProjectPath {
+        callee_file_path: file!(),
+        checked_unadjusted_pack_yscl_path: checked_path!(
+            "../../../sample_code/should_succeed/multi_file/no_warnings/traits/pack.yscl"
+        ),
+    });
+}
+
+#[test]
+fn notation_arithmetic() {
+    expect_success_with_no_warnings(ProjectPath {
+        callee_file_path: file!(),
+        checked_unadjusted_pack_yscl_path: checked_path!(
+            "../../../sample_code/should_succeed/multi_file/no_warnings/notation_arithmetic/pack.yscl"
+        ),
+    });
+}
+
+#[test]
+fn notation_import() {
+    expect_success_with_no_warnings(ProjectPath {
+        callee_file_path: file!(),
+        checked_unadjusted_pack_yscl_path: checked_path!(
+            "../../../sample_code/should_succeed/multi_file/no_warnings/notation_import/pack.yscl"
+        ),
+    });
+}
+
+#[test]
+fn js_exports() {
+    let js_files = expect_success_with_no_warnings(ProjectPath {
+        callee_file_path: file!(),
+        checked_unadjusted_pack_yscl_path: checked_path!(
+            "../../../sample_code/should_succeed/multi_file/no_warnings/js_exports/pack.yscl"
+        ),
+    })
+    .js_files;
+    let mut actual = get_exported_paths(get_js_file(&js_files, "index.js"));
+    actual.sort();
+    assert_eq!(
+        vec![
+            "pack.nat.Nat.o",
+            "pack.nat.Nat.s",
+            "pack.nat.two",
+            "pack.two"
+        ],
+        actual
+    );
+}
+
+#[test]
+fn js_module_files() {
+    let js_files = expect_success_with_no_warnings(ProjectPath {
+        callee_file_path: file!(),
+        checked_unadjusted_pack_yscl_path: checked_path!(
+            "../../../sample_code/should_succeed/multi_file/no_warnings/js_exports/pack.yscl"
+        ),
+    })
+    .js_files;
+    let mut paths: Vec<String> = js_files
+        .iter()
+        .map(|(path, _)| path.to_str().unwrap().replace('\\', "/"))
+        .collect();
+    paths.sort();
+    assert_eq!(
+        vec![
+            "index.js",
+            "pack.js",
+            "pack/math.js",
+            "pack/nat.js",
+            "runtime.js"
+        ],
+        paths
+    );
+
+    // `pack` and `pack.math` are mutually dependent,
+    // so `pack.js` holds the items of both,
+    // and `pack/math.js` re-exports its items from `pack.js`.
+    let math_items = &get_js_file(&js_files, "pack/math.js").items;
+    assert!(
+        matches!(&math_items[..], [js_ast::FileItem::ExportFrom(export)] if export.specifier.unescaped == "../pack.js"),
+        "Unexpected items: {:#?}",
+        math_items
+    );
+    let root_items = &get_js_file(&js_files, "pack.js").items;
+    assert!(
+        root_items.iter().all(|item| match item {
+            js_ast::FileItem::Import(import) => import.specifier.unescaped != "./pack/math.js",
+            _ => true,
+        }),
+        "Unexpected items: {:#?}",
+        root_items
+    );
+}
+
+#[test]
+fn js_module_export_names() {
+    let files = expect_success_with_no_warnings(ProjectPath {
+        callee_file_path: file!(),
+        checked_unadjusted_pack_yscl_path: checked_path!(
+            "../../../sample_code/should_succeed/multi_file/no_warnings/js_exports/pack.yscl"
+        ),
+    });
+
+    // `pack.nat.two` is declared first, so `pack.two` is disambiguated,
+    // but both modules export their items under their Kantu names.
+    let nat = get_formatted_js_file(&files, "pack/nat.js");
+    assert_contains_line(&nat, "export const two = Nat_s(Nat_s(Nat_o));");
+    let root = get_formatted_js_file(&files, "pack.js");
+    assert_contains_line(&root, "const two2 = Nat_s(Nat_s(Nat_o));");
+    assert_contains_line(&root, "export { two2 as two };");
+    let root_declarations = get_formatted_declaration_file(&files, "pack.d.ts");
+    assert_contains_line(&root_declarations, "declare const two2: Nat;");
+    assert_contains_line(&root_declarations, "export { two2 as two };");
+
+    // `pack.js` also holds the items of `pack.math`,
+    // which it exports under their JS names
+    // so that `pack/math.js` can re-export them under their Kantu names.
+    let math = get_formatted_js_file(&files, "pack/math.js");
+    assert_contains_line(
+        &math,
+        r#"export { double_ as double, six, two3 as two } from "../pack.js";"#,
+    );
+    let math_declarations = get_formatted_declaration_file(&files, "pack/math.d.ts");
+    assert_contains_line(
+        &math_declarations,
+        r#"export { double_ as double, six, two3 as two } from "../pack.js";"#,
+    );
+
+    let index = get_formatted_js_file(&files, "index.js");
+    assert_contains_line(
+        &index,
+        r#"import { Nat_o, Nat_s, two } from "./pack/nat.js";"#,
+    );
+    assert_contains_line(&index, r#"import { two as two2 } from "./pack.js";"#);
+
+    let output = run_with_node(
+        &files,
+        r#"
+import { two } from "./pack.js";
+import { two as nat_two } from "./pack/nat.js";
+
+console.log(JSON.stringify(two) === JSON.stringify(nat_two));
+"#,
+    );
+    if let Some(output) = output {
+        assert_eq!("true\n", output);
+    }
+}
+
+#[test]
+fn ts_declarations() {
+    let files = expect_success_with_no_warnings(ProjectPath {
+        callee_file_path: file!(),
+        checked_unadjusted_pack_yscl_path: checked_path!(
+            "../../../sample_code/should_succeed/multi_file/no_warnings/ts_declarations/pack.yscl"
+        ),
+    });
+    let mut paths: Vec<String> = files
+        .declaration_files
+        .iter()
+        .map(|(path, _)| path.to_str().unwrap().replace('\\', "/"))
+        .collect();
+    paths.sort();
+    assert_eq!(
+        vec![
+            "index.d.ts",
+            "pack.d.ts",
+            "pack/list.d.ts",
+            "pack/nat.d.ts",
+            "runtime.d.ts"
+        ],
+        paths
+    );
+
+    let list = get_formatted_declaration_file(&files, "pack/list.d.ts");
+    assert_contains_line(
+        &list,
+        r#"export type List<T> = ["nil", undefined] | ["cons", undefined, T, List<T>];"#,
+    );
+    assert_contains_line(
+        &list,
+        // The erased `T` is omitted.
+        "export declare const List_cons: <T>(car: T, cdr: List<T>) => List<T>;",
+    );
+
+    let nat = get_formatted_declaration_file(&files, "pack/nat.d.ts");
+    assert_contains_line(&nat, r#"export type Nat = ["o"] | ["s", Nat];"#);
+    assert_contains_line(&nat, "export declare const Nat_o: Nat;");
+    assert_contains_line(&nat, "export declare const plus: (a: Nat, b: Nat) => Nat;");
+
+    let root = get_formatted_declaration_file(&files, "pack.d.ts");
+    assert_contains_line(&root, r#"import type { Nat } from "./pack/nat.js";"#);
+    assert_contains_line(&root, r#"import type { List } from "./pack/list.js";"#);
+    assert_contains_line(
+        &root,
+        r#"export type Point = ["new_", { "x": Nat; "y": Nat }];"#,
+    );
+    assert_contains_line(&root, "export declare const x: (record: Point) => Nat;");
+    // The index `n` is erased.
+    assert_contains_line(
+        &root,
+        r#"export type Vec<T> = ["vnil", undefined] | ["vcons", undefined, Nat, T, Vec<T>];"#,
+    );
+    assert_contains_line(
+        &root,
+        "export declare const Vec_vcons: <T>(n: Nat, car: T, cdr: Vec<T>) => Vec<T>;",
+    );
+    assert_contains_line(
+        &root,
+        "export declare const map: <T, U>(l: List<T>, f: (x: T) => U) => List<U>;",
+    );
+    assert_contains_line(
+        &root,
+        r#"export declare const swap: <A, B>(p: ["pair", A, B]) => ["pair", B, A];"#,
+    );
+    assert_contains_line(
+        &root,
+        r#"export declare const move_right: (args: { "point": Point; "distance"?: Nat }) => Point;"#,
+    );
+    assert_contains_line(&root, "export declare const origin: Point;");
+}
+
+#[test]
+fn erasure() {
+    let erased_eq = ["pack", "eq", "Eq"].map(|s| IdentifierName::new(s.to_string()));
+    let files = expect_success_with_no_warnings_and_options(
+        ProjectPath {
+            callee_file_path: file!(),
+            checked_unadjusted_pack_yscl_path: checked_path!(
+                "../../../sample_code/should_succeed/multi_file/no_warnings/erasure/pack.yscl"
+            ),
+        },
+        TypeCheckOptions {
+            erased_types: vec![NonEmptyVec::try_from(erased_eq.to_vec()).unwrap()],
+            ..TypeCheckOptions::default()
+        },
+        |_, options| options,
+    );
+
+    let root = get_formatted_js_file(&files, "pack.js");
+    // Type args are erased, and omitted from calls to known functions.
+    assert_contains_line(
+        &root,
+        "export const List_cons = function List_cons(car, cdr) {",
+    );
+    // The fields keep their positions.
+    assert_contains_line(&root, r#"    return ["cons", undefined, car, cdr];"#);
+    // A constructor whose params are all erased is still a function.
+    assert_contains_line(&root, "export const List_nil = function List_nil() {");
+    assert_contains_line(&root, "            return List_cons(f(car), map(cdr, f));");
+    // Proofs are erased.
+    assert_contains_line(&root, "    return transport(plus(n, Nat_o), n, l);");
+    // Function values keep the positions of their erased params.
+    assert_contains_line(&root, "    return cons(undefined, Nat_o, List_nil());");
+    assert_contains_line(
+        &root,
+        "export const zeros = apply_cons(function temp_8(temp_9, temp_a, temp_b) {",
+    );
+    assert_contains_line(&root, "    return List_cons(temp_a, temp_b);");
+    assert_contains_line(&root, "export const prepend = List_cons;");
+    assert_contains_line(&root, "export const one_zero = prepend(Nat_o, List_nil());");
+    // Erased labeled args are omitted.
+    assert_contains_line(
+        &root,
+        "export const checked_length = function checked_length({l: l}) {",
+    );
+    assert_contains_line(
+        &root,
+        r#"export const len = checked_length({ "l": three_again });"#,
+    );
+
+    // A match on a proof is a no-op.
+    let eq = get_formatted_js_file(&files, "pack/eq.js");
+    assert_contains_line(
+        &eq,
+        "export const transport = function transport(x, y, px) {",
+    );
+    assert_contains_line(&eq, "    return px;");
+
+    let root_declarations = get_formatted_declaration_file(&files, "pack.d.ts");
+    assert_contains_line(
+        &root_declarations,
+        r#"export type List<T> = ["nil", undefined] | ["cons", undefined, T, List<T>];"#,
+    );
+    assert_contains_line(
+        &root_declarations,
+        "export declare const List_nil: <T>() => List<T>;",
+    );
+    assert_contains_line(
+        &root_declarations,
+        "export declare const List_cons: <T>(car: T, cdr: List<T>) => List<T>;",
+    );
+    assert_contains_line(
+        &root_declarations,
+        "export declare const apply_cons: (cons: <T>(T: undefined, car: T, cdr: List<T>) => List<T>) => List<Nat>;",
+    );
+    assert_contains_line(
+        &root_declarations,
+        "export declare const checked_length: <T>(args: { \"l\": List<T> }) => Nat;",
+    );
+
+    let output = run_with_node(
+        &files,
+        r#"
+import { zeros, len } from "./pack.js";
+
+console.log(JSON.stringify(zeros));
+console.log(JSON.stringify(len));
+"#,
+    );
+    if let Some(output) = output {
+        assert_eq!(
+            "[\"cons\",null,[\"o\"],[\"nil\",null]]\n[\"s\",[\"o\"]]\n",
+            output
+        );
+    }
+}
+
+#[test]
+fn bigint() {
+    let name = |components: &[&str]| {
+        NonEmptyVec::try_from(
+            components
+                .iter()
+                .map(|s| IdentifierName::new(s.to_string()))
+                .collect::<Vec<_>>(),
+        )
+        .unwrap()
+    };
+    let files = expect_success_with_no_warnings_and_options(
+        ProjectPath {
+            callee_file_path: file!(),
+            checked_unadjusted_pack_yscl_path: checked_path!(
+                "../../../sample_code/should_succeed/multi_file/no_warnings/bigint/pack.yscl"
+            ),
+        },
+        TypeCheckOptions {
+            bigint_types: vec![
+                name(&["pack", "Pos"]),
+                name(&["pack", "Nat"]),
+                name(&["pack", "Int"]),
+                name(&["pack", "UnaryNat"]),
+            ],
+            bigint_functions: vec![
+                (BigIntOperation::Add, name(&["pack", "pos", "add"])),
+                (BigIntOperation::Add, name(&["pack", "UnaryNat_add"])),
+                (BigIntOperation::Mul, name(&["pack", "pos", "mul"])),
+                (BigIntOperation::Eq, name(&["pack", "pos", "eq"])),
+                (BigIntOperation::Lt, name(&["pack", "pos", "lt"])),
+            ],
+            designated_bool: Some(DesignatedBool {
+                type_name_components: name(&["pack", "Bool"]),
+                true_variant_name: IdentifierName::new("true".to_string()),
+                false_variant_name: IdentifierName::new("false".to_string()),
+            }),
+            ..TypeCheckOptions::default()
+        },
+        |_, options| options,
+    );
+
+    let root = get_formatted_js_file(&files, "pack.js");
+    // Constructors build bigints.
+    assert_contains_line(&root, "export const Pos_one = 1n;");
+    assert_contains_line(&root, "    return ((2n * left) + ((right[0] === \"one\")");
+    assert_contains_line(&root, "export const Nat_zero = 0n;");
+    assert_contains_line(&root, "    return (0n - n);");
+    assert_contains_line(&root, "    return (n + 1n);");
+    // Matches compare the matchee to the bounds of each variant.
+    assert_contains_line(&root, "        if ((temp_c > 1n)) {");
+    assert_contains_line(&root, "            const left = (temp_c >> 1n);");
+    // Designated functions are replaced with native operations.
+    assert_contains_line(
+        &root,
+        "export const UnaryNat_add = function UnaryNat_add(a, b) {",
+    );
+    assert_contains_line(&root, "    return (a + b);");
+    assert_contains_line(&root, "    return (a * b);");
+    assert_contains_line(&root, "    return ((a < b)");
+    assert_contains_line(&root, "        ? [\"true_\"]");
+
+    let root_declarations = get_formatted_declaration_file(&files, "pack.d.ts");
+    assert_contains_line(&root_declarations, "export type Pos = bigint;");
+    assert_contains_line(&root_declarations, "export type Int = bigint;");
+    assert_contains_line(
+        &root_declarations,
+        "export declare const abs: (a: Int) => Nat;",
+    );
+}
+
+#[test]
+fn stack_safe() {
+    let files = expect_success_with_no_warnings_and_options(
+        ProjectPath {
+            callee_file_path: file!(),
+            checked_unadjusted_pack_yscl_path: checked_path!(
+                "../../../sample_code/should_succeed/multi_file/no_warnings/stack_safe/pack.yscl"
+            ),
+        },
+        TypeCheckOptions::default(),
+        |_, options| JavaScriptOptions {
+            stack_safe: true,
+            ..options
+        },
+    );
+
+    // `double_via_step` calls itself from a nested fun.
+    assert!(
+        matches!(
+            &files.warnings[..],
+            [CompileToJavaScriptWarning::StackUnsafeSelfCall { .. }]
+        ),
+        "Unexpected warnings: {:#?}",
+        files.warnings
+    );
+
+    let root = get_formatted_js_file(&files, "pack.js");
+    // Self tail calls become loop iterations.
+    assert_contains_line(&root, "    while (true) {");
+    assert_contains_line(&root, "                continue;");
+    // Matches become switches over the tag.
+    assert_contains_line(&root, "            case \"cons\": {");
+    // The remaining self calls are yielded to the runtime.
+    assert_contains_line(
+        &root,
+        "                return Nat_succ(Nat_succ((yield [n$u0027$])));",
+    );
+
+    let runtime = get_formatted_js_file(&files, "runtime.js");
+    assert_contains_line(
+        &runtime,
+        "export const run_recursion = function run_recursion(generator, args) {",
+    );
+    // Only arg checks use `invalid_arg_error`.
+    assert!(!runtime.contains("invalid_arg_error"));
+    assert!(!get_formatted_declaration_file(&files, "runtime.d.ts").contains("invalid_arg_error"));
+
+    // Each function recurses 100,000 times,
+    // which overflows the JS call stack unless the code is stack-safe.
+    let output = run_with_node(
+        &files,
+        r#"
+import { pack } from "./index.js";
+
+const { Nat, NatList } = pack;
+const to_number = (n) => {
+    let count = 0;
+    while (n[0] === "succ") {
+        n = n[1];
+        count++;
+    }
+    return count;
+};
+let n = Nat.zero;
+let nat_list = NatList.nil;
+for (let i = 0; i < 100000; i++) {
+    n = Nat.succ(n);
+    nat_list = NatList.cons(i % 2 === 0 ? Nat.zero : Nat.succ(Nat.zero), nat_list);
+}
+const list = pack.repeat(n, Nat.zero);
+console.log([
+    pack.double(n),
+    pack.len(list, Nat.zero),
+    pack.add({ a: n, b: n }),
+    pack.count_nonzero(nat_list),
+    pack.succ_each(n),
+].map(to_number).join(" "));
+"#,
+    );
+    if let Some(output) = output {
+        assert_eq!("200000 100000 200000 50000 100001\n", output);
+    }
+}
+
+#[test]
+fn source_map() {
+    let source_files: SourceFiles = [(FileId(0), "src/mod.k"), (FileId(1), "src/nat.k")]
+        .into_iter()
+        .map(|(file_id, path)| {
+            let abs_path = Path::new(file!())
+                .parent()
+                .unwrap()
+                .join("../../../sample_code/should_succeed/multi_file/no_warnings/source_map")
+                .join(path);
+            let src = std::fs::read_to_string(abs_path).expect("Failed to open file");
+            (
+                file_id,
+                SourceFile {
+                    path: PathBuf::from(path),
+                    src,
+                },
+            )
+        })
+        .collect();
+    let files = expect_success_with_no_warnings_and_options(
+        ProjectPath {
+            callee_file_path: file!(),
+            checked_unadjusted_pack_yscl_path: checked_path!(
+                "../../../sample_code/should_succeed/multi_file/no_warnings/source_map/pack.yscl"
+            ),
+        },
+        TypeCheckOptions::default(),
+        |_, options| JavaScriptOptions {
+            // Stack-safe matches throw if no case matches.
+            stack_safe: true,
+            source_files: source_files.clone(),
+            ..options
+        },
+    );
+
+    let format_options = FormatOptions { indentation: 4 };
+    let (root, root_source_map) = format_file_with_source_map(
+        get_js_file(&files.js_files, "pack.js"),
+        &format_options,
+        Path::new("pack.js"),
+        &source_files,
+    );
+    // Runtime errors display locations relative to the pack directory.
+    assert_contains_line(&root, "            return unreachable(\"src/mod.k:18:4\");");
+    assert_contains_line(&root, "//# sourceMappingURL=pack.js.map");
+    assert!(root_source_map
+        .starts_with("{\"version\":3,\"file\":\"pack.js\",\"sources\":[\"../src/mod.k\"],"));
+    // `pred` (line 4, column 15 of `mod.k`) is on line 4 of `pack.js`,
+    // and the `unreachable` call for its match (line 5, column 4) is on line 15.
+    assert!(root_source_map.contains("\"mappings\":\";;;oBAGe;;;;;;;;;;;mBACX;"));
+
+    let (_, nat_source_map) = format_file_with_source_map(
+        get_js_file(&files.js_files, "pack/nat.js"),
+        &format_options,
+        Path::new("pack/nat.js"),
+        &source_files,
+    );
+    assert!(nat_source_map
+        .starts_with("{\"version\":3,\"file\":\"nat.js\",\"sources\":[\"../../src/nat.k\"],"));
+}
+
+#[test]
+fn entry_points() {
+    let files = expect_success_with_no_warnings_and_options(
+        ProjectPath {
+            callee_file_path: file!(),
+            checked_unadjusted_pack_yscl_path: checked_path!(
+                "../../../sample_code/should_succeed/multi_file/no_warnings/entry_points/pack.yscl"
+            ),
+        },
+        TypeCheckOptions::default(),
+        |input, options| {
+            let main = ["pack", "main"].map(|s| IdentifierName::new(s.to_string()));
+            let unreachable_items = find_unreachable_items(
+                input.registry,
+                input.file_tree,
+                input.file_item_list_id,
+                &[NonEmptyVec::try_from(main.to_vec()).unwrap()],
+            )
+            .expect("Finding unreachable items failed");
+            // `Bool`, `is_zero`, `nat.times`, and `unused.triple`
+            assert_eq!(1, unreachable_items.type_count());
+            assert_eq!(3, unreachable_items.let_count());
+            JavaScriptOptions {
+                unreachable_items,
+                ..options
+            }
+        },
+    );
+
+    let root = get_formatted_js_file(&files, "pack.js");
+    assert!(!root.contains("Bool"));
+    assert!(!root.contains("is_zero"));
+    // The names of the remaining items are unaffected by the removed ones.
+    assert_contains_line(&root, "    return double_(double_(n));");
+    assert_contains_line(&root, "export const Tag_tag = [\"tag\"];");
+    assert_contains_line(&root, "export const main = function _2(_, n) {");
+    assert_contains_line(&root, "    return plus(quadruple(n), n);");
+
+    let nat = get_formatted_js_file(&files, "pack/nat.js");
+    assert!(nat.contains("export const plus = "));
+    assert!(!nat.contains("times"));
+
+    // Every item of `pack.unused` was removed, so it isn't written at all.
+    assert!(files
+        .js_files
+        .iter()
+        .all(|(path, _)| path.as_path() != Path::new("pack/unused.js")));
+
+    let index = get_formatted_js_file(&files, "index.js");
+    assert_contains_line(
+        &index,
+        "export const pack = { \"nat\": { \"Nat\": { \"zero\": Nat_zero, \"succ\": Nat_succ }, \"plus\": plus }, \"double\": double_, \"quadruple\": quadruple, \"Tag\": { \"tag\": Tag_tag }, \"main\": main };",
+    );
+}
+
+#[test]
+fn optimize() {
+    let pos = ["pack", "Pos"].map(|s| IdentifierName::new(s.to_string()));
+    let files = expect_success_with_no_warnings_and_options(
+        ProjectPath {
+            callee_file_path: file!(),
+            checked_unadjusted_pack_yscl_path: checked_path!(
+                "../../../sample_code/should_succeed/multi_file/no_warnings/optimize/pack.yscl"
+            ),
+        },
+        TypeCheckOptions {
+            bigint_types: vec![NonEmptyVec::try_from(pos.to_vec()).unwrap()],
+            precompute_lets: true,
+            ..TypeCheckOptions::default()
+        },
+        |_, options| JavaScriptOptions {
+            inline_small_functions: true,
+            ..options
+        },
+    );
+
+    let root = get_formatted_js_file(&files, "pack.js");
+    // Closed values are emitted as literals,
+    // with the same layout the constructors build.
+    assert_contains_line(&root, "export const pos13 = 13n;");
+    assert_contains_line(
+        &root,
+        "export const three = [\"succ\", [\"succ\", [\"succ\", [\"zero\"]]]];",
+    );
+    assert_contains_line(
+        &root,
+        "export const three_twice = [\"cons\", undefined, [\"succ\", [\"succ\", [\"succ\", [\"zero\"]]]], [\"cons\", undefined, [\"succ\", [\"succ\", [\"succ\", [\"zero\"]]]], [\"nil\", undefined]]];",
+    );
+    assert_contains_line(
+        &root,
+        "export const point_on_y_axis = [\"point\", { \"x\": [\"zero\"], \"y\": [\"succ\", [\"succ\", [\"zero\"]]] }];",
+    );
+    // Values that are too large are still computed at runtime.
+    assert_contains_line(
+        &root,
+        "export const four_hundred = times(times(succ2(three), succ2(three)), times(succ2(succ2(Nat_zero)), succ2(succ2(Nat_zero))));",
+    );
+    // Calls to small functions of the same module are inlined
+    // if their args are names or literals.
+    assert_contains_line(
+        &root,
+        "    return Point_point({ \"x\": Nat_zero, \"y\": n });",
+    );
+    assert_contains_line(&root, "    return add_two(succ2(n));");
+}
+
+#[test]
+fn check_args() {
+    let pos = ["pack", "Pos"].map(|s| IdentifierName::new(s.to_string()));
+    let files = expect_success_with_no_warnings_and_options(
+        ProjectPath {
+            callee_file_path: file!(),
+            checked_unadjusted_pack_yscl_path: checked_path!(
+                "../../../sample_code/should_succeed/multi_file/no_warnings/check_args/pack.yscl"
+            ),
+        },
+        TypeCheckOptions {
+            bigint_types: vec![NonEmptyVec::try_from(pos.to_vec()).unwrap()],
+            ..TypeCheckOptions::default()
+        },
+        |_, options| JavaScriptOptions {
+            check_exported_args: true,
+            ..options
+        },
+    );
+
+    let index = get_formatted_js_file(&files, "index.js");
+    assert_contains_line(
+        &index,
+        "import { invalid_arg_error } from \"./runtime.js\";",
+    );
+    // Exported functions are exported as wrappers that check their args.
+    // The other items are exported as usual.
+    assert_contains_line(
+        &index,
+        "export const pack = { \"nat\": { \"Nat\": { \"zero\": Nat_zero, \"succ\": Nat_succ }, \"plus\": plus_checked }, \"Bit\": { \"zero\": Bit_zero, \"one\": Bit_one }, \"Pos\": { \"one\": Pos_one, \"extend\": Pos_extend }, \"List\": { \"nil\": List_nil, \"cons\": List_cons }, \"Point\": { \"point\": Point_point }, \"double\": double__checked, \"length\": length_checked, \"sum\": sum_checked, \"norm1\": norm1_checked, \"pos_double\": pos_double_checked, \"apply\": apply_checked, \"shift\": shift_checked, \"six\": six };",
+    );
+    assert_contains_line(
+        &index,
+        "    check_arg(check_Nat, wrapped_args[0], \"argument `n` of `pack.double`\", \"n\");",
+    );
+    assert_contains_line(
+        &index,
+        "    return (double_.apply)(undefined, wrapped_args);",
+    );
+    // Type args (and values of types that are params) aren't checked.
+    // Since `length` omits its erased `T` param, `list` is its first arg.
+    assert_contains_line(
+        &index,
+        "    check_arg(check_List(undefined), wrapped_args[0], \"argument `list` of `pack.length`\", \"list\");",
+    );
+    // Type args are checked through the checkers passed to generic checkers.
+    assert_contains_line(
+        &index,
+        "    check_arg(check_List(check_Nat), wrapped_args[0], \"argument `list` of `pack.sum`\", \"list\");",
+    );
+    assert_contains_line(
+        &index,
+        "                    (pending.push)([type_arg0, value[2], entry, \"[2]\"]);",
+    );
+    // Fields of the same type are checked by the same checker,
+    // instead of a new checker per node.
+    assert_contains_line(
+        &index,
+        "                (pending.push)([checker, value[3], entry, \"[3]\"]);",
+    );
+    // Labeled fields are checked by key.
+    assert_contains_line(
+        &index,
+        "            (pending.push)([check_Nat, (value[1]).y, entry, \"[1].y\"]);",
+    );
+    // Args of params with defaults may be omitted.
+    assert_contains_line(&index, "    if (((wrapped_args[0]).by !== undefined)) {");
+    // BigInt types are checked as `bigint`s, so their fields aren't checked.
+    assert_contains_line(
+        &index,
+        "        return \"a `pack.Pos` (i.e., a positive `bigint`)\";",
+    );
+    assert!(!index.contains("check_Bit"));
+    // Private functions aren't exported, so they aren't wrapped.
+    assert!(!index.contains("triple"));
+
+    let runtime = get_formatted_js_file(&files, "runtime.js");
+    assert_contains_line(
+        &runtime,
+        "export const invalid_arg_error = function invalid_arg_error(invalid_arg, invalid_arg_path, invalid_arg_expected) {",
+    );
+    assert!(get_formatted_declaration_file(&files, "runtime.d.ts").contains("invalid_arg_error"));
+}
+
+#[test]
+fn check_args_with_stack_safe() {
+    let files = expect_success_with_no_warnings_and_options(
+        ProjectPath {
+            callee_file_path: file!(),
+            checked_unadjusted_pack_yscl_path: checked_path!(
+                "../../../sample_code/should_succeed/multi_file/no_warnings/check_args/pack.yscl"
+            ),
+        },
+        TypeCheckOptions::default(),
+        |_, options| JavaScriptOptions {
+            stack_safe: true,
+            check_exported_args: true,
+            ..options
+        },
+    );
+
+    // The args are 100,000 levels deep, which overflows the JS call stack
+    // unless both the functions and the checks are stack-safe.
+    let output = run_with_node(
+        &files,
+        r#"
+import { pack } from "./index.js";
+
+const { Nat } = pack.nat;
+let n = Nat.zero;
+let malformed = ["sucks", Nat.zero];
+for (let i = 0; i < 100000; i++) {
+    n = Nat.succ(n);
+    malformed = Nat.succ(malformed);
+}
+let count = 0;
+for (let m = pack.double(n); m[0] === "succ"; m = m[1]) {
+    count++;
+}
+console.log(count);
+try {
+    pack.double(malformed);
+} catch (e) {
+    console.log(e instanceof RangeError);
+    console.log(e.message === `Invalid argument \`n\` of \`pack.double\`: expected \`n${"[1]".repeat(100000)}\` to be a \`pack.nat.Nat\`.`);
+}
+"#,
+    );
+    if let Some(output) = output {
+        assert_eq!("200000\nfalse\ntrue\n", output);
+    }
+}
+
+/// Writes the files (along with `main.js`, whose source is `main_src`)
+/// to a temporary directory, and runs `main.js` with Node.js.
+/// Returns its stdout, or `None` if Node.js is not installed.
+fn run_with_node(files: &JavaScriptFiles, main_src: &str) -> Option<String> {
+    // Tests run in parallel, so each run gets its own directory.
+    static RUN_COUNT: AtomicUsize = AtomicUsize::new(0);
+    let run_id = RUN_COUNT.fetch_add(1, Ordering::Relaxed);
+    let dir = std::env::temp_dir().join(format!(
+        "kanc_run_with_node_{}_{}",
+        std::process::id(),
+        run_id
+    ));
+    let format_options = FormatOptions { indentation: 4 };
+    for (path, file) in &files.js_files {
+        let abs_path = dir.join(path);
+        std::fs::create_dir_all(abs_path.parent().unwrap()).expect("Failed to create dir");
+        std::fs::write(abs_path, format_file(file, &format_options)).expect("Failed to write file");
+    }
+    std::fs::write(dir.join("package.json"), r#"{ "type": "module" }"#)
+        .expect("Failed to write file");
+    std::fs::write(dir.join("main.js"), main_src).expect("Failed to write file");
+
+    let output = std::process::Command::new("node")
+        .arg("main.js")
+        .current_dir(&dir)
+        .output();
+    std::fs::remove_dir_all(&dir).expect("Failed to remove dir");
+    let output = match output {
+        Ok(output) => output,
+        Err(err) if err.kind() == std::io::ErrorKind::NotFound => {
+            eprintln!("Skipping the Node.js part of the test, since `node` was not found.");
+            return None;
+        }
+        Err(err) => panic!("Failed to run node: {:?}", err),
+    };
+    assert!(
+        output.status.success(),
+        "node failed:\n{}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+    Some(String::from_utf8(output.stdout).expect("node should print UTF-8"))
+}
+
+fn get_formatted_js_file(files: &JavaScriptFiles, path: &str) -> String {
+    format_file(
+        get_js_file(&files.js_files, path),
+        &FormatOptions { indentation: 4 },
+    )
+}
+
+fn get_formatted_declaration_file(files: &JavaScriptFiles, path: &str) -> String {
+    files
+        .declaration_files
+        .iter()
+        .find(|(file_path, _)| file_path.as_path() == std::path::Path::new(path))
+        .map(|(_, file)| format_declaration_file(file, &FormatOptions { indentation: 4 }))
+        .unwrap_or_else(|| panic!("Cannot find {}", path))
+}
+
+fn assert_contains_line(file: &str, line: &str) {
+    assert!(
+        file.lines().any(|actual| actual == line),
+        "Cannot find line:\n{}\nin:\n{}",
+        line,
+        file
+    );
+}
+
+fn get_js_file<'a>(js_files: &'a [(PathBuf, js_ast::File)], path: &str) -> &'a js_ast::File {
+    js_files
+        .iter()
+        .find(|(file_path, _)| file_path.as_path() == std::path::Path::new(path))
+        .map(|(_, file)| file)
+        .unwrap_or_else(|| panic!("Cannot find {}", path))
+}
+
+fn get_exported_paths(js_file: &js_ast::File) -> Vec<String> {
+    fn add_paths(out: &mut Vec<String>, prefix: &str, expression: &js_ast::Expression) {
+        match expression {
+            js_ast::Expression::Object(object) => {
+                for entry in &object.entries {
+                    add_paths(out, &format!("{}.{}", prefix, entry.key.0), &entry.value);
+                }
+            }
+            _ => out.push(prefix.to_string()),
+        }
+    }
+
+    let mut out = vec![];
+    for item in &js_file.items {
+        if let js_ast::FileItem::ExportConst(const_) = item {
+            add_paths(&mut out, &const_.name.0, &const_.value);
+        }
+    }
+    out
+}
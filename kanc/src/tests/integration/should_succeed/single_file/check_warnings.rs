@@ -22,8 +22,22 @@ fn type_of_type0() {
     let src = include_str!(
         "../../../sample_code/should_succeed/single_file/with_warnings/check/type_of_type0.k"
     );
-    let expected_warnings = vec![TypeAssertionLhsTypeIsType1 {
-        assertion_src: "Type: ?".to_string(),
+    let expected_warnings = vec![TypeAssertionTypeQuestionMark {
+        original_left_src: "Type".to_string(),
+        rewritten_left_type_src: "Type1".to_string(),
+    }];
+    expect_success_with_warnings(src, &expected_warnings);
+}
+
+#[test]
+fn type_of_type2() {
+    use TypeCheckWarningSummary::*;
+    let src = include_str!(
+        "../../../sample_code/should_succeed/single_file/with_warnings/check/type_of_type2.k"
+    );
+    let expected_warnings = vec![TypeAssertionTypeQuestionMark {
+        original_left_src: "Type2".to_string(),
+        rewritten_left_type_src: "Type3".to_string(),
     }];
     expect_success_with_warnings(src, &expected_warnings);
 }
@@ -158,3 +172,23 @@ fn mismatched_nf_comparees() {
     ];
     expect_success_with_warnings(src, &expected_warnings);
 }
+
+#[test]
+fn alias_normal_form() {
+    use TypeCheckWarningSummary::*;
+    let src = include_str!(
+        "../../../sample_code/should_succeed/single_file/with_warnings/check/alias_normal_form.k"
+    );
+    let expected_warnings = vec![
+        NormalFormAssertionCompareeQuestionMark {
+            original_left_src: "outer".to_string(),
+            rewritten_left_src: "Nat.o".to_string(),
+        },
+        NormalFormAssertionCompareeQuestionMark {
+            original_left_src: "identity(outer,)".to_string(),
+            rewritten_left_src: "Nat.o".to_string(),
+        },
+    ];
+    let warnings = expect_success_with_warnings(src, &expected_warnings);
+    assert_eq!(2, warnings.len());
+}
@@ -0,0 +1,127 @@
+use super::*;
+
+fn type_check_with_evaluator(src: &str, evaluator: Evaluator) -> Vec<String> {
+    let file_id = FileId(0);
+    let file_tree = FileTree::from_root(file_id);
+    let tokens = lex(src).expect("Lexing failed");
+    let file = parse_file(tokens, file_id).expect("Parsing failed");
+    let file = simplify_file(file).expect("AST Simplification failed");
+    let file_items = bind_files(file_id, vec![file], &file_tree).expect("Binding failed");
+    let mut registry = NodeRegistry::empty();
+    let file_item_list_id = register_file_items(&mut registry, file_items);
+
+    let file_item_list_id =
+        validate_variant_return_types_in_file_items(&registry, file_item_list_id)
+            .expect("Variant return type validation failed");
+    let file_item_list_id = validate_fun_recursion_in_file_items(&mut registry, file_item_list_id)
+        .expect("Fun recursion validation failed");
+    let file_item_list_id = validate_type_positivity_in_file_items(&registry, file_item_list_id)
+        .expect("Type positivity validation failed");
+    let output = type_check_file_items_with_options(
+        &file_tree,
+        &mut registry,
+        file_item_list_id,
+        TypeCheckOptions {
+            evaluator,
+            ..TypeCheckOptions::default()
+        },
+    )
+    .expect("Type checking failed");
+    format_actual_warnings(&registry, &output.warnings)
+}
+
+/// Since the check warnings include the normal forms
+/// of the comparees, this compares the normal forms as well.
+fn expect_evaluators_to_agree(src: &str) {
+    let nbe_warnings = type_check_with_evaluator(src, Evaluator::NormalizationByEvaluation);
+    let substitution_warnings = type_check_with_evaluator(src, Evaluator::Substitution);
+    assert_eq!(nbe_warnings, substitution_warnings);
+}
+
+#[test]
+fn alias_normal_form() {
+    let src = include_str!(
+        "../../../sample_code/should_succeed/single_file/with_warnings/check/alias_normal_form.k"
+    );
+    expect_evaluators_to_agree(src);
+}
+
+#[test]
+fn mismatched_nf_comparees() {
+    let src = include_str!(
+        "../../../sample_code/should_succeed/single_file/with_warnings/check/mismatched_nf_comparees.k"
+    );
+    expect_evaluators_to_agree(src);
+}
+
+#[test]
+fn mismatched_types() {
+    let src = include_str!(
+        "../../../sample_code/should_succeed/single_file/with_warnings/check/mismatched_types.k"
+    );
+    expect_evaluators_to_agree(src);
+}
+
+#[test]
+fn nf_goal_assertion_type_check_failure() {
+    let src = include_str!(
+        "../../../sample_code/should_succeed/single_file/with_warnings/check/nf_goal_assertion_type_check_failure.k"
+    );
+    expect_evaluators_to_agree(src);
+}
+
+#[test]
+fn nf_no_goal_exists() {
+    let src = include_str!(
+        "../../../sample_code/should_succeed/single_file/with_warnings/check/nf_no_goal_exists.k"
+    );
+    expect_evaluators_to_agree(src);
+}
+
+#[test]
+fn nf_non_goal_assertion_type_check_failure() {
+    let src = include_str!(
+        "../../../sample_code/should_succeed/single_file/with_warnings/check/nf_non_goal_assertion_type_check_failure.k"
+    );
+    expect_evaluators_to_agree(src);
+}
+
+#[test]
+fn reduction_trace() {
+    let src = include_str!(
+        "../../../sample_code/should_succeed/single_file/with_warnings/check/reduction_trace.k"
+    );
+    expect_evaluators_to_agree(src);
+}
+
+#[test]
+fn type_assertion_goal_lhs() {
+    let src = include_str!(
+        "../../../sample_code/should_succeed/single_file/with_warnings/check/type_assertion_goal_lhs.k"
+    );
+    expect_evaluators_to_agree(src);
+}
+
+#[test]
+fn type_assertion_type_check_failure() {
+    let src = include_str!(
+        "../../../sample_code/should_succeed/single_file/with_warnings/check/type_assertion_type_check_failure.k"
+    );
+    expect_evaluators_to_agree(src);
+}
+
+#[test]
+fn type_of_type0() {
+    let src = include_str!(
+        "../../../sample_code/should_succeed/single_file/with_warnings/check/type_of_type0.k"
+    );
+    expect_evaluators_to_agree(src);
+}
+
+#[test]
+fn type_of_type2() {
+    let src = include_str!(
+        "../../../sample_code/should_succeed/single_file/with_warnings/check/type_of_type2.k"
+    );
+    expect_evaluators_to_agree(src);
+}
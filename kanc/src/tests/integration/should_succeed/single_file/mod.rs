@@ -3,4 +3,5 @@ use super::*;
 mod no_warnings;
 
 mod check_warnings;
+mod evaluator_equivalence;
 mod todo_warnings;
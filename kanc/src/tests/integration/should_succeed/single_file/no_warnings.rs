@@ -29,6 +29,14 @@ fn match_explosion() {
     expect_success_with_no_warnings(src);
 }
 
+#[test]
+fn match_explosion_occurs_check() {
+    let src = include_str!(
+        "../../../sample_code/should_succeed/single_file/no_warnings/match_explosion_occurs_check.k"
+    );
+    expect_success_with_no_warnings(src);
+}
+
 #[test]
 fn coercionless_match() {
     let src = include_str!(
@@ -205,6 +213,75 @@ fn expect_success_with_no_warnings(src: &str) {
     )
     .expect("Type checking failed");
     assert_eq!(0, warnings.len(), "One or more warnings were emitted");
-    let _js_ast = JavaScript::generate_code(&registry, file_item_list_id.raw())
-        .expect("Code generation failed");
+    let _js_ast = JavaScript::generate_code(
+        &registry,
+        &FileTree::from_root(file_id),
+        file_item_list_id.raw(),
+    )
+    .expect("Code generation failed");
+}
+
+#[test]
+fn eta_conversion() {
+    let src = include_str!(
+        "../../../sample_code/should_succeed/single_file/no_warnings/eta_conversion.k"
+    );
+    expect_success_with_no_warnings(src);
+}
+
+#[test]
+fn universe_hierarchy() {
+    let src = include_str!(
+        "../../../sample_code/should_succeed/single_file/no_warnings/universe_hierarchy.k"
+    );
+    expect_success_with_no_warnings(src);
+}
+
+#[test]
+fn large_universes() {
+    let src = include_str!(
+        "../../../sample_code/should_succeed/single_file/no_warnings/large_universes.k"
+    );
+    expect_success_with_no_warnings(src);
+}
+
+#[test]
+fn match_motive() {
+    let src =
+        include_str!("../../../sample_code/should_succeed/single_file/no_warnings/match_motive.k");
+    expect_success_with_no_warnings(src);
+}
+
+#[test]
+fn struct_() {
+    let src = include_str!("../../../sample_code/should_succeed/single_file/no_warnings/struct.k");
+    expect_success_with_no_warnings(src);
+}
+
+#[test]
+fn exists_type() {
+    let src =
+        include_str!("../../../sample_code/should_succeed/single_file/no_warnings/exists_type.k");
+    expect_success_with_no_warnings(src);
+}
+
+#[test]
+fn trait_() {
+    let src = include_str!("../../../sample_code/should_succeed/single_file/no_warnings/trait.k");
+    expect_success_with_no_warnings(src);
+}
+
+#[test]
+fn default_params() {
+    let src = include_str!(
+        "../../../sample_code/should_succeed/single_file/no_warnings/default_params.k"
+    );
+    expect_success_with_no_warnings(src);
+}
+
+#[test]
+fn notations() {
+    let src =
+        include_str!("../../../sample_code/should_succeed/single_file/no_warnings/notations.k");
+    expect_success_with_no_warnings(src);
 }
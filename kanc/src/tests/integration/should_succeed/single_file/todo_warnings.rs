@@ -10,3 +10,70 @@ fn todo_expressions() {
     let actual_warnings = expect_success_with_warnings(src, &expected_warnings);
     assert_eq!(5, actual_warnings.len());
 }
+
+#[test]
+fn todo_is_not_precomputed() {
+    use crate::processing::{
+        find_unreachable_items::UnreachableItems,
+        generate_code::targets::javascript::{format, JavaScriptOptions},
+    };
+
+    let src = include_str!(
+        "../../../sample_code/should_succeed/single_file/with_warnings/precompute_todo.k"
+    );
+    let file_id = FileId(0);
+    let file_tree = FileTree::from_root(file_id);
+    let tokens = lex(src).expect("Lexing failed");
+    let file = parse_file(tokens, file_id).expect("Parsing failed");
+    let file = simplify_file(file).expect("AST Simplification failed");
+    let file_items = bind_files(file_id, vec![file], &file_tree).expect("Binding failed");
+    let mut registry = NodeRegistry::empty();
+    let file_item_list_id = register_file_items(&mut registry, file_items);
+
+    let file_item_list_id =
+        validate_variant_return_types_in_file_items(&registry, file_item_list_id)
+            .expect("Variant return type validation failed");
+    let file_item_list_id = validate_fun_recursion_in_file_items(&mut registry, file_item_list_id)
+        .expect("Fun recursion validation failed");
+    let file_item_list_id = validate_type_positivity_in_file_items(&registry, file_item_list_id)
+        .expect("Type positivity validation failed");
+    let output = type_check_file_items_with_options(
+        &file_tree,
+        &mut registry,
+        file_item_list_id,
+        TypeCheckOptions {
+            precompute_lets: true,
+            ..TypeCheckOptions::default()
+        },
+    )
+    .expect("Type checking failed");
+    assert_eq!(1, output.warnings.len());
+    let files = JavaScript::generate_code_with_options(
+        &registry,
+        &file_tree,
+        file_item_list_id.raw(),
+        JavaScriptOptions {
+            erasure: output.erasure,
+            bigint: output.bigint,
+            stack_safe: false,
+            source_files: Default::default(),
+            unreachable_items: UnreachableItems::default(),
+            precomputed_lets: output.precomputed_lets,
+            inline_small_functions: false,
+            check_exported_args: false,
+        },
+    )
+    .expect("Code generation failed");
+
+    let (_, js_file) = files
+        .js_files
+        .iter()
+        .find(|(path, _)| path.as_path() == std::path::Path::new("pack.js"))
+        .expect("Cannot find pack.js");
+    let root = format::format_file(js_file, &format::FormatOptions { indentation: 4 });
+    // Even though `todo` is discarded by `first`,
+    // evaluating `broken` (or anything that refers to it) must still throw.
+    assert!(root.contains("export const broken = first(Unit_c, unimplemented("));
+    assert!(root.contains("export const uses_broken = first(Unit_c, broken);"));
+    assert!(root.contains("export const fine = [\"c\"];"));
+}
@@ -11,7 +11,7 @@ pub use crate::data::{
 };
 pub use crate::processing::{
     bind_type_independent::bind_files,
-    bind_type_independent::{BindError, OwnedSymbolSource},
+    bind_type_independent::{BindError, DesignatedBool, OwnedSymbolSource},
     generate_code::{targets::javascript::JavaScript, CompileTarget},
     lex::lex,
     lex::LexError,
@@ -26,8 +26,9 @@ pub use crate::processing::{
     },
     type_check::TypeCheckError,
     type_check::{
-        type_check_file_items, NormalFormAssertionWarning, TypeAssertionWarning,
-        TypeCheckFailureReason, TypeCheckWarning,
+        type_check_file_items, type_check_file_items_with_options, BigIntOperation, Evaluator,
+        NormalFormAssertionWarning, TypeAssertionWarning, TypeCheckFailureReason, TypeCheckOptions,
+        TypeCheckWarning,
     },
     validate_fun_recursion::validate_fun_recursion_in_file_items,
     validate_type_positivity::validate_type_positivity_in_file_items,
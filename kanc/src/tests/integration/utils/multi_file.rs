@@ -67,10 +67,10 @@ fn parse_children_then_add(
         .iter()
         .find(|(file, _)| file.id == file_id)
         .expect("Invalid file_id");
-    let mod_names = get_mod_names(file);
+    let mods = get_mods(file);
     let file_path = file_path.clone();
 
-    for mod_name in &mod_names {
+    for (mod_name, is_globally_visible) in &mods {
         let child_file_id = get_unused_file_id(files);
 
         let (child_src, child_path) = {
@@ -102,6 +102,9 @@ fn parse_children_then_add(
         };
         tree.add_child(file_id, mod_name, child_file_id)
             .expect("Multiple modules with same name.");
+        if *is_globally_visible {
+            tree.mark_as_globally_visible(child_file_id);
+        }
         let child_file = lex_and_parse_file(&child_src, child_file_id);
         files.push((child_file, child_path));
 
@@ -114,12 +117,17 @@ fn get_unused_file_id(files: &[(simplified_ast::File, PathBuf)]) -> FileId {
     FileId(max_raw + 1)
 }
 
-fn get_mod_names(file: &simplified_ast::File) -> Vec<IdentifierName> {
-    let mut mod_names = vec![];
+/// Returns the name of each `mod`, and whether it is globally visible.
+fn get_mods(file: &simplified_ast::File) -> Vec<(IdentifierName, bool)> {
+    let mut mods = vec![];
     for item in &file.items {
         if let simplified_ast::FileItem::Mod(mod_) = item {
-            mod_names.push(mod_.name.name.clone());
+            let is_globally_visible = mod_
+                .visibility
+                .as_ref()
+                .is_some_and(simplified_ast::PubClause::is_global);
+            mods.push((mod_.name.name.clone(), is_globally_visible));
         }
     }
-    mod_names
+    mods
 }
@@ -33,8 +33,12 @@ pub fn expect_success_with_warnings(
     )
     .expect("Type checking failed");
     assert_expectations_match_actual_warnings(&registry, expected_warnings, &warnings);
-    let _js_ast = JavaScript::generate_code(&registry, file_item_list_id.raw())
-        .expect("Code generation failed");
+    let _js_ast = JavaScript::generate_code(
+        &registry,
+        &FileTree::from_root(file_id),
+        file_item_list_id.raw(),
+    )
+    .expect("Code generation failed");
     warnings
 }
 
@@ -43,7 +47,7 @@ pub enum TypeCheckWarningSummary {
     TypeAssertionGoalLhs {
         assertion_src: String,
     },
-    TypeAssertionLhsTypeIsType1 {
+    TypeAssertionLhsTypeIsLimitUniverse {
         assertion_src: String,
     },
     TypeAssertionCompareeTypeCheckFailure {
@@ -108,7 +112,7 @@ fn format_expected_warnings(
         .collect()
 }
 
-fn format_actual_warnings(
+pub fn format_actual_warnings(
     registry: &NodeRegistry,
     actual_warnings: &[TypeCheckWarning],
 ) -> Vec<String> {
@@ -278,9 +282,9 @@ fn format_expected_warning(_: &NodeRegistry, warning: &TypeCheckWarningSummary)
             )
         }
 
-        TypeCheckWarningSummary::TypeAssertionLhsTypeIsType1 { assertion_src } => {
+        TypeCheckWarningSummary::TypeAssertionLhsTypeIsLimitUniverse { assertion_src } => {
             format!(
-                "TypeAssertion::LhsTypeIsType1 {{\n    assertion: {},\n}}",
+                "TypeAssertion::LhsTypeIsLimitUniverse {{\n    assertion: {},\n}}",
                 indent_second_line_onward(assertion_src, 8),
             )
         }
@@ -390,8 +394,8 @@ fn summarize_type_assertion_warning(
                 ),
             }
         }
-        TypeAssertionWarning::LhsTypeIsType1(assertion_id) => {
-            TypeCheckWarningSummary::TypeAssertionLhsTypeIsType1 {
+        TypeAssertionWarning::LhsTypeIsLimitUniverse(assertion_id) => {
+            TypeCheckWarningSummary::TypeAssertionLhsTypeIsLimitUniverse {
                 assertion_src: format_check_assertion(
                     &expand_check_assertion(registry, *assertion_id),
                     0,
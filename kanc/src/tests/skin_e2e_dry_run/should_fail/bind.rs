@@ -0,0 +1,53 @@
+use super::*;
+
+#[test]
+fn cannot_derive_eq_for_indexed_variant_0513() {
+    let path = concat_paths(
+        file!(),
+        checked_path!(
+            "../../sample_code/should_fail/multi_file/bind/derive_eq/indexed_variant/pack.yscl"
+        ),
+    );
+    let output =
+        get_manifest_path_and_backslash_normalized_output(vec![DUMMY_EXEC_PATH, "--pack", &path]);
+    insta::assert_debug_snapshot!(output);
+}
+
+#[test]
+fn cannot_derive_eq_for_field_0514() {
+    let path = concat_paths(
+        file!(),
+        checked_path!(
+            "../../sample_code/should_fail/multi_file/bind/derive_eq/uncomparable_field/pack.yscl"
+        ),
+    );
+    let output =
+        get_manifest_path_and_backslash_normalized_output(vec![DUMMY_EXEC_PATH, "--pack", &path]);
+    insta::assert_debug_snapshot!(output);
+}
+
+#[test]
+fn ambiguous_infix_operators_0515() {
+    let path = concat_paths(
+        file!(),
+        checked_path!(
+            "../../sample_code/should_fail/multi_file/bind/ambiguous_infix_operators/pack.yscl"
+        ),
+    );
+    let output =
+        get_manifest_path_and_backslash_normalized_output(vec![DUMMY_EXEC_PATH, "--pack", &path]);
+    insta::assert_debug_snapshot!(output);
+}
+
+#[test]
+fn cannot_derive_eq_for_dependent_field_0514() {
+    let path = concat_paths(
+        file!(),
+        checked_path!(
+            "../../sample_code/should_fail/multi_file/bind/derive_eq/dependent_field/pack.yscl"
+        ),
+    );
+    let output =
+        get_manifest_path_and_backslash_normalized_output(vec![DUMMY_EXEC_PATH, "--pack", &path]);
+    insta::assert_debug_snapshot!(output);
+}
@@ -0,0 +1,27 @@
+use super::*;
+
+#[test]
+fn undeclared_entry_point_0900() {
+    let path = concat_paths(
+        file!(),
+        checked_path!(
+            "../../sample_code/should_fail/multi_file/entry_points/undeclared_entry_point/pack.yscl"
+        ),
+    );
+    let output =
+        get_manifest_path_and_backslash_normalized_output(vec![DUMMY_EXEC_PATH, "--pack", &path]);
+    insta::assert_debug_snapshot!(output);
+}
+
+#[test]
+fn type_entry_point_0901() {
+    let path = concat_paths(
+        file!(),
+        checked_path!(
+            "../../sample_code/should_fail/multi_file/entry_points/type_entry_point/pack.yscl"
+        ),
+    );
+    let output =
+        get_manifest_path_and_backslash_normalized_output(vec![DUMMY_EXEC_PATH, "--pack", &path]);
+    insta::assert_debug_snapshot!(output);
+}
@@ -1,4 +1,7 @@
 use super::*;
 
+mod bind;
+mod entry_points;
+mod pack_yscl;
 mod simplify_ast;
 mod type_check;
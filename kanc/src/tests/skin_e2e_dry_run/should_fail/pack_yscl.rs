@@ -0,0 +1,53 @@
+use super::*;
+
+#[test]
+fn illegal_eq_type_name_0206() {
+    let path = concat_paths(
+        file!(),
+        checked_path!(
+            "../../sample_code/should_fail/multi_file/pack_yscl/illegal_eq_type_name/pack.yscl"
+        ),
+    );
+    let output =
+        get_manifest_path_and_backslash_normalized_output(vec![DUMMY_EXEC_PATH, "--pack", &path]);
+    insta::assert_debug_snapshot!(output);
+}
+
+#[test]
+fn erased_types_not_a_list_0207() {
+    let path = concat_paths(
+        file!(),
+        checked_path!(
+            "../../sample_code/should_fail/multi_file/pack_yscl/erased_types_not_a_list/pack.yscl"
+        ),
+    );
+    let output =
+        get_manifest_path_and_backslash_normalized_output(vec![DUMMY_EXEC_PATH, "--pack", &path]);
+    insta::assert_debug_snapshot!(output);
+}
+
+#[test]
+fn bigint_functions_not_a_map_0208() {
+    let path = concat_paths(
+        file!(),
+        checked_path!(
+            "../../sample_code/should_fail/multi_file/pack_yscl/bigint_functions_not_a_map/pack.yscl"
+        ),
+    );
+    let output =
+        get_manifest_path_and_backslash_normalized_output(vec![DUMMY_EXEC_PATH, "--pack", &path]);
+    insta::assert_debug_snapshot!(output);
+}
+
+#[test]
+fn unrecognized_bigint_operation_0209() {
+    let path = concat_paths(
+        file!(),
+        checked_path!(
+            "../../sample_code/should_fail/multi_file/pack_yscl/unrecognized_bigint_operation/pack.yscl"
+        ),
+    );
+    let output =
+        get_manifest_path_and_backslash_normalized_output(vec![DUMMY_EXEC_PATH, "--pack", &path]);
+    insta::assert_debug_snapshot!(output);
+}
@@ -1,7 +1,7 @@
 use super::*;
 
 #[test]
-fn expected_term_of_type_type0_or_type1_2000() {
+fn expected_term_of_type_universe_2000() {
     let path = concat_paths(
         file!(),
         checked_path!(
@@ -309,3 +309,242 @@ fn let_statement_type_contains_private_name_2020() {
         get_manifest_path_and_backslash_normalized_output(vec![DUMMY_EXEC_PATH, "--pack", &path]);
     insta::assert_debug_snapshot!(output);
 }
+
+#[test]
+fn reduction_step_limit_exceeded_2021() {
+    let path = concat_paths(
+        file!(),
+        checked_path!(
+            "../../sample_code/should_fail/single_file/type_check/reduction_step_limit_exceeded.k"
+        ),
+    );
+    let output = get_manifest_path_and_backslash_normalized_output(vec![
+        DUMMY_EXEC_PATH,
+        "--file",
+        &path,
+        "--max-reduction-steps",
+        "200",
+    ]);
+    insta::assert_debug_snapshot!(output);
+}
+
+#[test]
+fn cannot_infer_type_of_rewrite_2024() {
+    let path = concat_paths(
+        file!(),
+        checked_path!(
+            "../../sample_code/should_fail/multi_file/type_check/rewrite/cannot_infer_type/pack.yscl"
+        ),
+    );
+    let output =
+        get_manifest_path_and_backslash_normalized_output(vec![DUMMY_EXEC_PATH, "--pack", &path]);
+    insta::assert_debug_snapshot!(output);
+}
+
+#[test]
+fn rewrite_proof_is_not_an_equality_2025() {
+    let path = concat_paths(
+        file!(),
+        checked_path!(
+            "../../sample_code/should_fail/multi_file/type_check/rewrite/proof_is_not_an_equality/pack.yscl"
+        ),
+    );
+    let output =
+        get_manifest_path_and_backslash_normalized_output(vec![DUMMY_EXEC_PATH, "--pack", &path]);
+    insta::assert_debug_snapshot!(output);
+}
+
+#[test]
+fn illegal_designated_eq_type_2026() {
+    let path = concat_paths(
+        file!(),
+        checked_path!(
+            "../../sample_code/should_fail/multi_file/type_check/rewrite/illegal_designated_eq_type/pack.yscl"
+        ),
+    );
+    let output =
+        get_manifest_path_and_backslash_normalized_output(vec![DUMMY_EXEC_PATH, "--pack", &path]);
+    insta::assert_debug_snapshot!(output);
+}
+
+#[test]
+fn rewrite_with_neutral_proof_is_stuck_2010() {
+    let path = concat_paths(
+        file!(),
+        checked_path!(
+            "../../sample_code/should_fail/multi_file/type_check/rewrite/stuck_on_neutral_proof/pack.yscl"
+        ),
+    );
+    let output =
+        get_manifest_path_and_backslash_normalized_output(vec![DUMMY_EXEC_PATH, "--pack", &path]);
+    insta::assert_debug_snapshot!(output);
+}
+
+#[test]
+fn projection_record_is_not_a_struct_2027() {
+    let path = concat_paths(
+        file!(),
+        checked_path!(
+            "../../sample_code/should_fail/multi_file/type_check/struct/projection_record_is_not_a_struct/pack.yscl"
+        ),
+    );
+    let output =
+        get_manifest_path_and_backslash_normalized_output(vec![DUMMY_EXEC_PATH, "--pack", &path]);
+    insta::assert_debug_snapshot!(output);
+}
+
+#[test]
+fn struct_has_no_such_field_2028() {
+    let path = concat_paths(
+        file!(),
+        checked_path!(
+            "../../sample_code/should_fail/multi_file/type_check/struct/no_such_field/pack.yscl"
+        ),
+    );
+    let output =
+        get_manifest_path_and_backslash_normalized_output(vec![DUMMY_EXEC_PATH, "--pack", &path]);
+    insta::assert_debug_snapshot!(output);
+}
+
+#[test]
+fn illegal_exists_match_cases_2029() {
+    let path = concat_paths(
+        file!(),
+        checked_path!(
+            "../../sample_code/should_fail/multi_file/type_check/exists/illegal_match_cases/pack.yscl"
+        ),
+    );
+    let output =
+        get_manifest_path_and_backslash_normalized_output(vec![DUMMY_EXEC_PATH, "--pack", &path]);
+    insta::assert_debug_snapshot!(output);
+}
+
+#[test]
+fn exists_match_has_motive_2030() {
+    let path = concat_paths(
+        file!(),
+        checked_path!(
+            "../../sample_code/should_fail/multi_file/type_check/exists/match_has_motive/pack.yscl"
+        ),
+    );
+    let output =
+        get_manifest_path_and_backslash_normalized_output(vec![DUMMY_EXEC_PATH, "--pack", &path]);
+    insta::assert_debug_snapshot!(output);
+}
+
+#[test]
+fn instance_type_is_not_a_trait_2031() {
+    let path = concat_paths(
+        file!(),
+        checked_path!(
+            "../../sample_code/should_fail/multi_file/type_check/trait/instance_type_is_not_a_trait/pack.yscl"
+        ),
+    );
+    let output =
+        get_manifest_path_and_backslash_normalized_output(vec![DUMMY_EXEC_PATH, "--pack", &path]);
+    insta::assert_debug_snapshot!(output);
+}
+
+#[test]
+fn missing_instance_2032() {
+    let path = concat_paths(
+        file!(),
+        checked_path!(
+            "../../sample_code/should_fail/multi_file/type_check/trait/missing_instance/pack.yscl"
+        ),
+    );
+    let output =
+        get_manifest_path_and_backslash_normalized_output(vec![DUMMY_EXEC_PATH, "--pack", &path]);
+    insta::assert_debug_snapshot!(output);
+}
+
+#[test]
+fn private_instance_2032() {
+    let path = concat_paths(
+        file!(),
+        checked_path!(
+            "../../sample_code/should_fail/multi_file/type_check/trait/private_instance/pack.yscl"
+        ),
+    );
+    let output =
+        get_manifest_path_and_backslash_normalized_output(vec![DUMMY_EXEC_PATH, "--pack", &path]);
+    insta::assert_debug_snapshot!(output);
+}
+
+#[test]
+fn ambiguous_instance_2033() {
+    let path = concat_paths(
+        file!(),
+        checked_path!(
+            "../../sample_code/should_fail/multi_file/type_check/trait/ambiguous_instance/pack.yscl"
+        ),
+    );
+    let output =
+        get_manifest_path_and_backslash_normalized_output(vec![DUMMY_EXEC_PATH, "--pack", &path]);
+    insta::assert_debug_snapshot!(output);
+}
+
+#[test]
+fn illegal_erased_type_2034() {
+    let path = concat_paths(
+        file!(),
+        checked_path!(
+            "../../sample_code/should_fail/multi_file/type_check/erasure/illegal_erased_type/pack.yscl"
+        ),
+    );
+    let output =
+        get_manifest_path_and_backslash_normalized_output(vec![DUMMY_EXEC_PATH, "--pack", &path]);
+    insta::assert_debug_snapshot!(output);
+}
+
+#[test]
+fn undeclared_erased_type_2035() {
+    let path = concat_paths(
+        file!(),
+        checked_path!(
+            "../../sample_code/should_fail/multi_file/type_check/erasure/undeclared_erased_type/pack.yscl"
+        ),
+    );
+    let output =
+        get_manifest_path_and_backslash_normalized_output(vec![DUMMY_EXEC_PATH, "--pack", &path]);
+    insta::assert_debug_snapshot!(output);
+}
+
+#[test]
+fn illegal_bigint_type_2036() {
+    let path = concat_paths(
+        file!(),
+        checked_path!(
+            "../../sample_code/should_fail/multi_file/type_check/bigint/illegal_bigint_type/pack.yscl"
+        ),
+    );
+    let output =
+        get_manifest_path_and_backslash_normalized_output(vec![DUMMY_EXEC_PATH, "--pack", &path]);
+    insta::assert_debug_snapshot!(output);
+}
+
+#[test]
+fn illegal_bigint_function_2037() {
+    let path = concat_paths(
+        file!(),
+        checked_path!(
+            "../../sample_code/should_fail/multi_file/type_check/bigint/illegal_bigint_function/pack.yscl"
+        ),
+    );
+    let output =
+        get_manifest_path_and_backslash_normalized_output(vec![DUMMY_EXEC_PATH, "--pack", &path]);
+    insta::assert_debug_snapshot!(output);
+}
+
+#[test]
+fn undeclared_bigint_item_2038() {
+    let path = concat_paths(
+        file!(),
+        checked_path!(
+            "../../sample_code/should_fail/multi_file/type_check/bigint/undeclared_bigint_item/pack.yscl"
+        ),
+    );
+    let output =
+        get_manifest_path_and_backslash_normalized_output(vec![DUMMY_EXEC_PATH, "--pack", &path]);
+    insta::assert_debug_snapshot!(output);
+}
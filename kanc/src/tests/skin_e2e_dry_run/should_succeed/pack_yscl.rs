@@ -12,3 +12,145 @@ fn plus_commutative() {
         get_manifest_path_and_backslash_normalized_output(vec![DUMMY_EXEC_PATH, "--pack", &path]);
     insta::assert_debug_snapshot!(output);
 }
+
+#[test]
+fn plus_commutative_with_normal_form_cache_stats() {
+    let path = concat_paths(
+        file!(),
+        checked_path!(
+            "../../sample_code/should_succeed/multi_file/no_warnings/plus_commutative/pack.yscl"
+        ),
+    );
+    let output = get_manifest_path_and_backslash_normalized_output(vec![
+        DUMMY_EXEC_PATH,
+        "--pack",
+        &path,
+        "--show-normal-form-cache-stats",
+    ]);
+    insta::assert_debug_snapshot!(output);
+}
+
+#[test]
+fn plus_commutative_with_eval_profile() {
+    let path = concat_paths(
+        file!(),
+        checked_path!(
+            "../../sample_code/should_succeed/multi_file/no_warnings/plus_commutative/pack.yscl"
+        ),
+    );
+    let output = get_manifest_path_and_backslash_normalized_output(vec![
+        DUMMY_EXEC_PATH,
+        "--pack",
+        &path,
+        "--profile-eval",
+    ]);
+    insta::assert_debug_snapshot!(output);
+}
+
+#[test]
+fn rewrite() {
+    let path = concat_paths(
+        file!(),
+        checked_path!("../../sample_code/should_succeed/multi_file/no_warnings/rewrite/pack.yscl"),
+    );
+    let output =
+        get_manifest_path_and_backslash_normalized_output(vec![DUMMY_EXEC_PATH, "--pack", &path]);
+    insta::assert_debug_snapshot!(output);
+}
+
+#[test]
+fn derive_eq() {
+    let path = concat_paths(
+        file!(),
+        checked_path!(
+            "../../sample_code/should_succeed/multi_file/no_warnings/derive_eq/pack.yscl"
+        ),
+    );
+    let output =
+        get_manifest_path_and_backslash_normalized_output(vec![DUMMY_EXEC_PATH, "--pack", &path]);
+    insta::assert_debug_snapshot!(output);
+}
+
+#[test]
+fn erasure() {
+    let path = concat_paths(
+        file!(),
+        checked_path!("../../sample_code/should_succeed/multi_file/no_warnings/erasure/pack.yscl"),
+    );
+    let output =
+        get_manifest_path_and_backslash_normalized_output(vec![DUMMY_EXEC_PATH, "--pack", &path]);
+    insta::assert_debug_snapshot!(output);
+}
+
+#[test]
+fn bigint() {
+    let path = concat_paths(
+        file!(),
+        checked_path!("../../sample_code/should_succeed/multi_file/no_warnings/bigint/pack.yscl"),
+    );
+    let output =
+        get_manifest_path_and_backslash_normalized_output(vec![DUMMY_EXEC_PATH, "--pack", &path]);
+    insta::assert_debug_snapshot!(output);
+}
+
+#[test]
+fn stack_safe() {
+    let path = concat_paths(
+        file!(),
+        checked_path!(
+            "../../sample_code/should_succeed/multi_file/no_warnings/stack_safe/pack.yscl"
+        ),
+    );
+    let output = get_manifest_path_and_backslash_normalized_output(vec![
+        DUMMY_EXEC_PATH,
+        "--pack",
+        &path,
+        "--stack-safe",
+    ]);
+    insta::assert_debug_snapshot!(output);
+}
+
+#[test]
+fn entry_points() {
+    let path = concat_paths(
+        file!(),
+        checked_path!(
+            "../../sample_code/should_succeed/multi_file/no_warnings/entry_points/pack.yscl"
+        ),
+    );
+    let output =
+        get_manifest_path_and_backslash_normalized_output(vec![DUMMY_EXEC_PATH, "--pack", &path]);
+    insta::assert_debug_snapshot!(output);
+}
+
+#[test]
+fn optimize() {
+    let path = concat_paths(
+        file!(),
+        checked_path!("../../sample_code/should_succeed/multi_file/no_warnings/optimize/pack.yscl"),
+    );
+    let output = get_manifest_path_and_backslash_normalized_output(vec![
+        DUMMY_EXEC_PATH,
+        "--pack",
+        &path,
+        "--optimize",
+    ]);
+    insta::assert_debug_snapshot!(output);
+}
+
+#[test]
+fn check_args() {
+    let path = concat_paths(
+        file!(),
+        checked_path!(
+            "../../sample_code/should_succeed/multi_file/no_warnings/check_args/pack.yscl"
+        ),
+    );
+    let output = get_manifest_path_and_backslash_normalized_output(vec![
+        DUMMY_EXEC_PATH,
+        "--pack",
+        &path,
+        "--check-args",
+    ]);
+    insta::assert_debug_snapshot!(output);
+}
@@ -10,3 +10,22 @@ fn hello_world() {
         get_manifest_path_and_backslash_normalized_output(vec![DUMMY_EXEC_PATH, "--file", &path]);
     insta::assert_debug_snapshot!(output);
 }
+
+#[test]
+fn reduction_trace() {
+    let path = concat_paths(
+        file!(),
+        checked_path!(
+            "../../sample_code/should_succeed/single_file/with_warnings/check/reduction_trace.k"
+        ),
+    );
+    let location = format!("{path}:17:11");
+    let output = get_manifest_path_and_backslash_normalized_output(vec![
+        DUMMY_EXEC_PATH,
+        "--file",
+        &path,
+        "--trace-check",
+        &location,
+    ]);
+    insta::assert_debug_snapshot!(output);
+}
@@ -392,3 +392,40 @@ fn check() {
         other => panic!("Unexpected expression {:?}", other),
     });
 }
+
+#[test]
+fn infix_chain() {
+    let src =
+        include_str!("../../sample_code/should_succeed/subterms/expressions/infix_chain.x.ksn");
+    expect_expression(src, |expression| match &expression {
+        Expression::InfixChain(chain) => {
+            assert_eq!(0, chain.span.start.0);
+            assert_eq!(src.trim_end().len(), chain.span.end.0);
+            assert!(matches!(&chain.first_operand, Expression::Dot(_)));
+
+            let operators: Vec<&IdentifierName> =
+                chain.rest.iter().map(|link| &link.operator.name).collect();
+            assert_eq!(
+                vec![
+                    &IdentifierName::new("+".to_string()),
+                    &IdentifierName::new("*".to_string()),
+                ],
+                operators
+            );
+
+            match &chain.rest.last().operand {
+                Expression::Parenthesized(parenthesized) => match &parenthesized.expression {
+                    Expression::InfixChain(inner) => {
+                        assert_eq!(
+                            &IdentifierName::new("<>".to_string()),
+                            &inner.rest.first().operator.name
+                        );
+                    }
+                    _ => panic!("Unexpected expression {:?}", expression),
+                },
+                _ => panic!("Unexpected expression {:?}", expression),
+            }
+        }
+        _ => panic!("Unexpected expression {:?}", expression),
+    });
+}
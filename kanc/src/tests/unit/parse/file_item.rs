@@ -10,6 +10,13 @@ fn expect_type_statement(src: &str, panicker: impl Fn(TypeStatement)) {
     panicker(file_item);
 }
 
+fn expect_notation_statement(src: &str, panicker: impl Fn(NotationStatement)) {
+    let file_id = FileId(0);
+    let tokens = lex(src).expect("Lexing failed");
+    let file_item = parse(tokens, file_id).expect("Parsing failed");
+    panicker(file_item);
+}
+
 fn expect_let_statement(src: &str, panicker: impl Fn(LetStatement)) {
     let file_id = FileId(0);
     let tokens = lex(src).expect("Lexing failed");
@@ -46,3 +53,32 @@ fn let_() {
         assert_eq!(&expected_name, &item.name.name);
     });
 }
+
+#[test]
+fn derive() {
+    let src = include_str!("../../sample_code/should_succeed/subterms/file_items/derive.fi.ksn");
+    expect_type_statement(src, |item| {
+        let derive = item.derive.expect("derive clause should be present");
+        let expected_name = IdentifierName::new("eq".to_string());
+        assert_eq!(1, derive.names.len());
+        assert_eq!(&expected_name, &derive.names.first().name);
+    });
+}
+
+#[test]
+fn notation() {
+    let src = include_str!("../../sample_code/should_succeed/subterms/file_items/notation.fi.ksn");
+    expect_notation_statement(src, |item| {
+        assert!(item.visibility.is_some());
+        assert_eq!(&IdentifierName::new("<>".to_string()), &item.operator.name);
+        assert!(matches!(&item.function, Expression::Dot(_)));
+        assert_eq!(
+            &IdentifierName::new("sum".to_string()),
+            &item.precedence.name
+        );
+        assert_eq!(
+            &IdentifierName::new("right".to_string()),
+            &item.associativity.name
+        );
+    });
+}
@@ -51,3 +51,25 @@ fn dashed_underscore() {
         assert!(param.is_dashed);
     });
 }
+
+#[test]
+fn instance() {
+    let src = include_str!("../../sample_code/should_succeed/subterms/params/instance.p.ksn");
+    expect_param(src, |param| {
+        let expected_name = IdentifierName::new("e".to_string());
+        assert_eq!(&expected_name, &param.name.name);
+        assert!(param.is_instance);
+        assert!(!param.is_dashed);
+    });
+}
+
+#[test]
+fn default() {
+    let src = include_str!("../../sample_code/should_succeed/subterms/params/default.p.ksn");
+    expect_param(src, |param| {
+        let expected_name = IdentifierName::new("c".to_string());
+        assert_eq!(&expected_name, &param.name.name);
+        assert!(param.is_dashed);
+        assert!(matches!(param.default, Some(Expression::Call(_))));
+    });
+}
@@ -74,7 +74,9 @@ impl DeepCheckSpans for FileItem {
             FileItem::Use(x) => x.deep_check_spans(src),
             FileItem::Mod(x) => x.deep_check_spans(src),
             FileItem::Type(x) => x.deep_check_spans(src),
+            FileItem::Struct(x) => x.deep_check_spans(src),
             FileItem::Let(x) => x.deep_check_spans(src),
+            FileItem::Notation(x) => x.deep_check_spans(src),
         }
     }
 }
@@ -205,10 +207,28 @@ impl DeepCheckChildSpans for TypeStatement {
         self.visibility.deep_check_spans(src);
         self.name.deep_check_spans(src);
         self.params.deep_check_spans(src);
+        self.derive.deep_check_spans(src);
         self.variants.deep_check_spans(src);
     }
 }
 
+impl ShallowCheckOwnSpan for DeriveClause {
+    fn shallow_check_own_span(&self, src: &str) {
+        let spanned_src = get_spanned_slice(src, self.span).expect("Span should be valid");
+        assert!(
+            spanned_src.starts_with("derive") && spanned_src.ends_with(')'),
+            "Unexpected derive clause span: {spanned_src:?}"
+        );
+    }
+}
+impl DeepCheckChildSpans for DeriveClause {
+    fn deep_check_child_spans(&self, src: &str) {
+        for name in &self.names {
+            name.deep_check_spans(src);
+        }
+    }
+}
+
 impl ShallowCheckOwnSpan for NonEmptyVec<Param> {
     fn shallow_check_own_span(&self, _src: &str) {
         // Do nothing, since `NonEmptyVec<Param>` doesn't have its own span.
@@ -225,6 +245,12 @@ impl DeepCheckChildSpans for NonEmptyVec<Param> {
 impl ShallowCheckOwnSpan for Identifier {
     fn shallow_check_own_span(&self, src: &str) {
         let spanned_src = get_spanned_slice(src, self.span).expect("Span should be valid");
+        if is_operator_name(&self.name) {
+            // Operators (e.g., `+`) are not expressions on their own,
+            // so we compare the source directly.
+            assert_eq!(self.name.src_str(), spanned_src);
+            return;
+        }
         let reconstructed: Expression = parse_str(spanned_src)
             .expect("Should be able to reconstruct a copy using the spanned slice.");
         assert_eq!(
@@ -239,6 +265,12 @@ impl DeepCheckChildSpans for Identifier {
     }
 }
 
+fn is_operator_name(name: &IdentifierName) -> bool {
+    name.src_str()
+        .chars()
+        .all(|c| matches!(c, '+' | '-' | '*' | '/' | '<' | '>'))
+}
+
 impl ShallowCheckOwnSpan for Param {
     fn shallow_check_own_span(&self, src: &str) {
         assert_reconstructed_equals_original_up_to_spans_and_file_ids(self, self.span, src);
@@ -248,6 +280,7 @@ impl DeepCheckChildSpans for Param {
     fn deep_check_child_spans(&self, src: &str) {
         self.name.deep_check_spans(src);
         self.type_.deep_check_spans(src);
+        self.default.deep_check_spans(src);
     }
 }
 
@@ -277,6 +310,45 @@ impl DeepCheckChildSpans for Variant {
     }
 }
 
+impl ShallowCheckOwnSpan for StructStatement {
+    fn shallow_check_own_span(&self, src: &str) {
+        assert_reconstructed_equals_original_up_to_spans_and_file_ids(self, self.span, src);
+    }
+}
+impl DeepCheckChildSpans for StructStatement {
+    fn deep_check_child_spans(&self, src: &str) {
+        self.visibility.deep_check_spans(src);
+        self.name.deep_check_spans(src);
+        self.params.deep_check_spans(src);
+        self.fields.deep_check_spans(src);
+    }
+}
+
+impl ShallowCheckOwnSpan for Vec<StructField> {
+    fn shallow_check_own_span(&self, _src: &str) {
+        // Do nothing, since `Vec<StructField>` doesn't have its own span.
+    }
+}
+impl DeepCheckChildSpans for Vec<StructField> {
+    fn deep_check_child_spans(&self, src: &str) {
+        for field in self {
+            field.deep_check_spans(src);
+        }
+    }
+}
+
+impl ShallowCheckOwnSpan for StructField {
+    fn shallow_check_own_span(&self, _src: &str) {
+        // Do nothing, since we haven't implemented `Parse` for `StructField`.
+    }
+}
+impl DeepCheckChildSpans for StructField {
+    fn deep_check_child_spans(&self, src: &str) {
+        self.name.deep_check_spans(src);
+        self.type_.deep_check_spans(src);
+    }
+}
+
 impl ShallowCheckOwnSpan for LetStatement {
     fn shallow_check_own_span(&self, src: &str) {
         assert_reconstructed_equals_original_up_to_spans_and_file_ids(self, self.span, src);
@@ -291,6 +363,21 @@ impl DeepCheckChildSpans for LetStatement {
     }
 }
 
+impl ShallowCheckOwnSpan for NotationStatement {
+    fn shallow_check_own_span(&self, src: &str) {
+        assert_reconstructed_equals_original_up_to_spans_and_file_ids(self, self.span, src);
+    }
+}
+impl DeepCheckChildSpans for NotationStatement {
+    fn deep_check_child_spans(&self, src: &str) {
+        self.visibility.deep_check_spans(src);
+        self.operator.deep_check_spans(src);
+        self.function.deep_check_spans(src);
+        self.precedence.deep_check_spans(src);
+        self.associativity.deep_check_spans(src);
+    }
+}
+
 impl ShallowCheckOwnSpan for Expression {
     fn shallow_check_own_span(&self, src: &str) {
         assert_reconstructed_equals_original_up_to_spans_and_file_ids(self, self.span(), src);
@@ -309,6 +396,11 @@ impl DeepCheckChildSpans for Expression {
             Expression::Match(match_) => match_.deep_check_child_spans(src),
             Expression::Forall(forall) => forall.deep_check_child_spans(src),
             Expression::Check(check) => check.deep_check_child_spans(src),
+            Expression::Rewrite(rewrite) => rewrite.deep_check_child_spans(src),
+            Expression::Exists(exists) => exists.deep_check_child_spans(src),
+            Expression::Pair(pair) => pair.deep_check_child_spans(src),
+            Expression::InfixChain(chain) => chain.deep_check_child_spans(src),
+            Expression::Parenthesized(parenthesized) => parenthesized.deep_check_child_spans(src),
         }
     }
 }
@@ -453,6 +545,43 @@ impl DeepCheckChildSpans for Check {
     }
 }
 
+impl DeepCheckChildSpans for Rewrite {
+    fn deep_check_child_spans(&self, src: &str) {
+        self.proof.deep_check_spans(src);
+        self.body.deep_check_spans(src);
+    }
+}
+
+impl DeepCheckChildSpans for Exists {
+    fn deep_check_child_spans(&self, src: &str) {
+        self.params.deep_check_spans(src);
+        self.output.deep_check_spans(src);
+    }
+}
+
+impl DeepCheckChildSpans for Pair {
+    fn deep_check_child_spans(&self, src: &str) {
+        self.witness.deep_check_spans(src);
+        self.proof.deep_check_spans(src);
+    }
+}
+
+impl DeepCheckChildSpans for InfixChain {
+    fn deep_check_child_spans(&self, src: &str) {
+        self.first_operand.deep_check_spans(src);
+        for link in &self.rest {
+            link.operator.deep_check_spans(src);
+            link.operand.deep_check_spans(src);
+        }
+    }
+}
+
+impl DeepCheckChildSpans for Parenthesized {
+    fn deep_check_child_spans(&self, src: &str) {
+        self.expression.deep_check_spans(src);
+    }
+}
+
 impl ShallowCheckOwnSpan for NonEmptyVec<CheckAssertion> {
     fn shallow_check_own_span(&self, _src: &str) {
         // Do nothing, since `NonEmptyVec<CheckAssertion>` doesn't have its own span.
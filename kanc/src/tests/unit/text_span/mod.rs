@@ -138,3 +138,10 @@ fn component_kw_in_dot_lhs() {
     );
     verify_that_spans_are_correct(src);
 }
+
+#[test]
+fn derive() {
+    let src =
+        include_str!("../../sample_code/should_succeed/multi_file/no_warnings/derive_eq/src/mod.k");
+    verify_that_spans_are_correct(src);
+}
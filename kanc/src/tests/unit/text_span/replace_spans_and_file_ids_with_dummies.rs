@@ -49,7 +49,13 @@ impl ReplaceSpansAndFileIdsWithDummies for FileItem {
             FileItem::Use(item) => FileItem::Use(item.replace_spans_and_file_ids_with_dummies()),
             FileItem::Mod(item) => FileItem::Mod(item.replace_spans_and_file_ids_with_dummies()),
             FileItem::Type(item) => FileItem::Type(item.replace_spans_and_file_ids_with_dummies()),
+            FileItem::Struct(item) => {
+                FileItem::Struct(item.replace_spans_and_file_ids_with_dummies())
+            }
             FileItem::Let(item) => FileItem::Let(item.replace_spans_and_file_ids_with_dummies()),
+            FileItem::Notation(item) => {
+                FileItem::Notation(item.replace_spans_and_file_ids_with_dummies())
+            }
         }
     }
 }
@@ -129,17 +135,31 @@ impl ReplaceSpansAndFileIdsWithDummies for TypeStatement {
         let visibility = self.visibility.replace_spans_and_file_ids_with_dummies();
         let name = self.name.replace_spans_and_file_ids_with_dummies();
         let params = self.params.replace_spans_and_file_ids_with_dummies();
+        let derive = self.derive.replace_spans_and_file_ids_with_dummies();
         let variants = self.variants.replace_spans_and_file_ids_with_dummies();
         Self {
             span: dummy_span(),
             visibility,
             name,
             params,
+            derive,
             variants,
         }
     }
 }
 
+impl ReplaceSpansAndFileIdsWithDummies for DeriveClause {
+    fn replace_spans_and_file_ids_with_dummies(self) -> Self {
+        let names = self
+            .names
+            .into_mapped(Identifier::replace_spans_and_file_ids_with_dummies);
+        Self {
+            span: dummy_span(),
+            names,
+        }
+    }
+}
+
 impl ReplaceSpansAndFileIdsWithDummies for PubClause {
     fn replace_spans_and_file_ids_with_dummies(self) -> Self {
         let scope_modifier = self
@@ -188,12 +208,15 @@ impl ReplaceSpansAndFileIdsWithDummies for Param {
         let label = self.label.replace_spans_and_file_ids_with_dummies();
         let name = self.name.replace_spans_and_file_ids_with_dummies();
         let type_ = self.type_.replace_spans_and_file_ids_with_dummies();
+        let default = self.default.replace_spans_and_file_ids_with_dummies();
         Self {
             span: dummy_span(),
+            is_instance: self.is_instance,
             label,
             is_dashed: self.is_dashed,
             name,
             type_,
+            default,
         }
     }
 }
@@ -223,6 +246,35 @@ impl ReplaceSpansAndFileIdsWithDummies for Variant {
     }
 }
 
+impl ReplaceSpansAndFileIdsWithDummies for StructStatement {
+    fn replace_spans_and_file_ids_with_dummies(self) -> Self {
+        let visibility = self.visibility.replace_spans_and_file_ids_with_dummies();
+        let name = self.name.replace_spans_and_file_ids_with_dummies();
+        let params = self.params.replace_spans_and_file_ids_with_dummies();
+        let fields = self.fields.replace_spans_and_file_ids_with_dummies();
+        Self {
+            span: dummy_span(),
+            visibility,
+            name,
+            params,
+            fields,
+            is_trait: self.is_trait,
+        }
+    }
+}
+
+impl ReplaceSpansAndFileIdsWithDummies for StructField {
+    fn replace_spans_and_file_ids_with_dummies(self) -> Self {
+        let name = self.name.replace_spans_and_file_ids_with_dummies();
+        let type_ = self.type_.replace_spans_and_file_ids_with_dummies();
+        Self {
+            span: dummy_span(),
+            name,
+            type_,
+        }
+    }
+}
+
 impl ReplaceSpansAndFileIdsWithDummies for LetStatement {
     fn replace_spans_and_file_ids_with_dummies(self) -> Self {
         let visibility = self.visibility.replace_spans_and_file_ids_with_dummies();
@@ -235,6 +287,25 @@ impl ReplaceSpansAndFileIdsWithDummies for LetStatement {
             transparency,
             name,
             value,
+            is_instance: self.is_instance,
+        }
+    }
+}
+
+impl ReplaceSpansAndFileIdsWithDummies for NotationStatement {
+    fn replace_spans_and_file_ids_with_dummies(self) -> Self {
+        let visibility = self.visibility.replace_spans_and_file_ids_with_dummies();
+        let operator = self.operator.replace_spans_and_file_ids_with_dummies();
+        let function = self.function.replace_spans_and_file_ids_with_dummies();
+        let precedence = self.precedence.replace_spans_and_file_ids_with_dummies();
+        let associativity = self.associativity.replace_spans_and_file_ids_with_dummies();
+        Self {
+            span: dummy_span(),
+            visibility,
+            operator,
+            function,
+            precedence,
+            associativity,
         }
     }
 }
@@ -273,6 +344,21 @@ impl ReplaceSpansAndFileIdsWithDummies for Expression {
             Expression::Check(check) => {
                 Expression::Check(Box::new(check.replace_spans_and_file_ids_with_dummies()))
             }
+            Expression::Rewrite(rewrite) => {
+                Expression::Rewrite(Box::new(rewrite.replace_spans_and_file_ids_with_dummies()))
+            }
+            Expression::Exists(exists) => {
+                Expression::Exists(Box::new(exists.replace_spans_and_file_ids_with_dummies()))
+            }
+            Expression::InfixChain(chain) => {
+                Expression::InfixChain(Box::new(chain.replace_spans_and_file_ids_with_dummies()))
+            }
+            Expression::Parenthesized(parenthesized) => Expression::Parenthesized(Box::new(
+                parenthesized.replace_spans_and_file_ids_with_dummies(),
+            )),
+            Expression::Pair(pair) => {
+                Expression::Pair(Box::new(pair.replace_spans_and_file_ids_with_dummies()))
+            }
         }
     }
 }
@@ -338,15 +424,31 @@ impl ReplaceSpansAndFileIdsWithDummies for Fun {
 impl ReplaceSpansAndFileIdsWithDummies for Match {
     fn replace_spans_and_file_ids_with_dummies(self) -> Self {
         let matchee = self.matchee.replace_spans_and_file_ids_with_dummies();
+        let motive = self.motive.replace_spans_and_file_ids_with_dummies();
         let cases = self.cases.replace_spans_and_file_ids_with_dummies();
         Self {
             span: dummy_span(),
             matchee,
+            motive,
             cases,
         }
     }
 }
 
+impl ReplaceSpansAndFileIdsWithDummies for MatchMotive {
+    fn replace_spans_and_file_ids_with_dummies(self) -> Self {
+        let matchee_name = self.matchee_name.replace_spans_and_file_ids_with_dummies();
+        let matchee_type = self.matchee_type.replace_spans_and_file_ids_with_dummies();
+        let output = self.output.replace_spans_and_file_ids_with_dummies();
+        Self {
+            span: dummy_span(),
+            matchee_name,
+            matchee_type,
+            output,
+        }
+    }
+}
+
 impl ReplaceSpansAndFileIdsWithDummies for MatchCase {
     fn replace_spans_and_file_ids_with_dummies(self) -> Self {
         let variant_name = self.variant_name.replace_spans_and_file_ids_with_dummies();
@@ -418,6 +520,67 @@ impl ReplaceSpansAndFileIdsWithDummies for Check {
     }
 }
 
+impl ReplaceSpansAndFileIdsWithDummies for Rewrite {
+    fn replace_spans_and_file_ids_with_dummies(self) -> Self {
+        let proof = self.proof.replace_spans_and_file_ids_with_dummies();
+        let body = self.body.replace_spans_and_file_ids_with_dummies();
+        Self {
+            span: dummy_span(),
+            proof,
+            body,
+        }
+    }
+}
+
+impl ReplaceSpansAndFileIdsWithDummies for Exists {
+    fn replace_spans_and_file_ids_with_dummies(self) -> Self {
+        let params = self.params.replace_spans_and_file_ids_with_dummies();
+        let output = self.output.replace_spans_and_file_ids_with_dummies();
+        Self {
+            span: dummy_span(),
+            params,
+            output,
+        }
+    }
+}
+
+impl ReplaceSpansAndFileIdsWithDummies for Pair {
+    fn replace_spans_and_file_ids_with_dummies(self) -> Self {
+        let witness = self.witness.replace_spans_and_file_ids_with_dummies();
+        let proof = self.proof.replace_spans_and_file_ids_with_dummies();
+        Self {
+            span: dummy_span(),
+            witness,
+            proof,
+        }
+    }
+}
+
+impl ReplaceSpansAndFileIdsWithDummies for InfixChain {
+    fn replace_spans_and_file_ids_with_dummies(self) -> Self {
+        let first_operand = self.first_operand.replace_spans_and_file_ids_with_dummies();
+        let rest = self.rest.into_mapped(|link| InfixChainLink {
+            operator: link.operator.replace_spans_and_file_ids_with_dummies(),
+            operand: link.operand.replace_spans_and_file_ids_with_dummies(),
+        });
+        Self {
+            span: dummy_span(),
+            first_operand,
+            rest,
+        }
+    }
+}
+
+impl ReplaceSpansAndFileIdsWithDummies for Parenthesized {
+    fn replace_spans_and_file_ids_with_dummies(self) -> Self {
+        let expression = self.expression.replace_spans_and_file_ids_with_dummies();
+        Self {
+            span: dummy_span(),
+            expression,
+        }
+    }
+}
+
 impl ReplaceSpansAndFileIdsWithDummies for NonEmptyVec<CheckAssertion> {
     fn replace_spans_and_file_ids_with_dummies(self) -> Self {
         self.into_mapped(CheckAssertion::replace_spans_and_file_ids_with_dummies)